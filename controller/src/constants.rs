@@ -1,9 +1,12 @@
-/// The WAD unit
-pub const WAD: u64 = 1_000_000_000_000_000_000;
+/// The WAD unit, defined at the math crate
+pub use math::constants::WAD;
 
 /// The maximum collateral factor allowed (90%)
 pub const MAX_COLLATERAL_FACTOR: u64 = 900_000_000_000_000_000;
 
+/// The maximum account leverage implied by the maximum collateral factor in wad (10x), i.e. 1 / (1 - MAX_COLLATERAL_FACTOR)
+pub const MAX_LEVERAGE: u64 = 10_000_000_000_000_000_000;
+
 /// The maximum number of markets an account can enter
 pub const MAX_MARKETS_PER_ACCOUNT: usize = 8;
 
@@ -19,8 +22,56 @@ pub const MAX_SLIPPAGE: u64 = 100_000_000_000_000_000;
 /// The maximum premium for boosting rewards
 pub const MAX_PREMIUM: u64 = 100_000_000_000_000_000;
 
+/// The health factor below which an account status is reported as a warning (1.1)
+pub const WARNING_HEALTH_FACTOR: u64 = 1_100_000_000_000_000_000;
+
 /// The required time delay for collateral factor decreases (1 day)
 pub const TIMELOCK_COLLATERAL_FACTOR_DECREASE: u64 = 1 * 24 * 60 * 60;
 
+/// The required time delay for maximum markets per account decreases (1 day)
+pub const TIMELOCK_MAX_MARKETS_PER_ACCOUNT_DECREASE: u64 = 1 * 24 * 60 * 60;
+
+/// The required time delay for forcing the removal of a booster observer (3 days)
+pub const TIMELOCK_FORCE_CLEAR_BOOSTER_OBSERVER: u64 = 3 * 24 * 60 * 60;
+
 /// The maximum decrease on collateral factor allowed (10%)
 pub const MAX_COLLATERAL_FACTOR_DECREASE: u64 = 100_000_000_000_000_000;
+
+/// The amount of seconds in a day
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The maximum number of market changes notified to the booster observer in a single call
+pub const MAX_MARKET_CHANGES_PER_NOTIFICATION: usize = 100;
+
+/// The maximum duration of an interest accrual freeze (7 days)
+pub const MAX_INTEREST_FREEZE_DURATION: u64 = 7 * 24 * 60 * 60;
+
+/// The denominator of xExchange pairs fees
+pub const XEXCHANGE_MAX_FEE_PERCENT: u64 = 100_000;
+
+/// The maximum length of a market display symbol
+pub const MAX_DISPLAY_SYMBOL_LENGTH: usize = 16;
+
+/// The length of a market icon URI hash (sha256)
+pub const ICON_URI_HASH_LENGTH: usize = 32;
+
+/// The maximum number of decimals of a market underlying
+pub const MAX_UNDERLYING_DECIMALS: u8 = 18;
+
+/// The maximum number of liquidations that can be performed in a single batch
+pub const MAX_LIQUIDATIONS_PER_BATCH: usize = 20;
+
+/// The maximum liquidation priority window (1 hour)
+pub const MAX_LIQUIDATION_PRIORITY_WINDOW: u64 = 60 * 60;
+
+/// The period a liquidator bond remains locked and slashable after unbonding (7 days)
+pub const LIQUIDATOR_UNBONDING_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// The ticker of EGLD
+pub const EGLD_TICKER: &[u8] = b"EGLD";
+
+/// The number of decimals of EGLD
+pub const EGLD_DECIMALS: u8 = 18;
+
+/// The prefix of the number of decimals entry returned by the ESDT system smart contract token properties
+pub const NUM_DECIMALS_PREFIX: &[u8] = b"NumDecimals-";
@@ -5,12 +5,15 @@ multiversx_sc::imports!();
 pub mod controller_proxy;
 
 pub use admin;
+pub use math;
+pub use pausable;
 
 pub mod constants;
 pub mod errors;
 pub mod events;
 pub mod governance;
 pub mod guardian;
+pub mod liquidators;
 pub mod market;
 pub mod policies;
 pub mod proxies;
@@ -24,7 +27,7 @@ pub mod storage;
 /// Handles the control (i.e. checks) for virtually all interactions with the protocol.
 ///
 #[multiversx_sc::contract]
-pub trait Controller: admin::AdminModule + events::EventModule + governance::GovernanceModule + guardian::GuardianModule + market::MarketModule + policies::PolicyModule + proxies::ProxyModule + rewards::RewardsModule + risk_profile::RiskProfileModule + shared::SharedModule + storage::StorageModule {
+pub trait Controller: admin::AdminModule + math::MathModule + pausable::PausableModule + pausable::PausableViewModule + events::EventModule + governance::GovernanceModule + guardian::GuardianModule + liquidators::LiquidatorsModule + market::MarketModule + policies::PolicyModule + proxies::ProxyModule + rewards::RewardsModule + risk_profile::RiskProfileModule + shared::SharedModule + storage::StorageModule {
     /// Initializes the contract with an optional admin address.
     ///
     /// # Arguments:
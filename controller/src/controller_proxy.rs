@@ -148,6 +148,94 @@ where
             .original_result()
     }
 
+    /// Pauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the pause guardian.
+    /// - Pausing an already paused contract has no effect.
+    ///
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Unpausing a contract that is not paused has no effect.
+    ///
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    /// Sets the pause guardian of the contract.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_pause_guardian` - The address of the new pause guardian.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Cannot be called if the pause guardian is read from another contract.
+    ///
+    pub fn set_pause_guardian<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_pause_guardian: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPauseGuardian")
+            .argument(&new_pause_guardian)
+            .original_result()
+    }
+
+    /// Returns the address of the pause guardian, if one has been set.
+    ///
+    pub fn get_pause_guardian_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseGuardian")
+            .original_result()
+    }
+
+    /// Stores the address of the contract from which the pause guardian is read, if any.
+    pub fn pause_guardian_source(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseGuardianSource")
+            .original_result()
+    }
+
+    /// Returns whether the contract is paused.
+    ///
+    pub fn is_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
     /// Incorporates a money market in a list of accepted money markets (a whitelist). This action will add support for the
     /// provided money market.
     ///
@@ -158,18 +246,137 @@ where
     /// # Notes:
     ///
     /// - Can only be called by the admin.
-    /// - The provided address must be a valid money market smart contract.
+    /// - The provided address must be a valid money market smart contract, other than a position market (which must be
+    ///   supported via `supportPositionMarket`).
     /// - The money market should not has already been supported in the past.
+    /// - Can be paid with the money market underlying, in which case it is deposited as an initial seed and the minted
+    ///   Hatom tokens are locked forever in the controller (dead shares).
+    /// - If seeding is required, a non-zero underlying payment must be provided.
     ///
     pub fn support_market<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("supportMarket")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Incorporates a position market in the list of accepted money markets (a whitelist). A position market is a
+    /// supply-only market whose collateral tokens are meta-ESDT positions (e.g. staked LP positions), which are accounted
+    /// per nonce.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the position market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a valid position market smart contract.
+    /// - The position market should not has already been supported in the past.
+    /// - Position markets cannot be seeded, given that they cannot be minted.
+    ///
+    pub fn support_position_market<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("supportMarket")
+            .raw_call("supportPositionMarket")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Sets whether an initial seed deposit is required when supporting a new money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `seed_required` - Whether the initial seed deposit is required or not.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn set_seed_required<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        seed_required: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSeedRequired")
+            .argument(&seed_required)
+            .original_result()
+    }
+
+    /// Sets the listing metadata of a given money market, such that frontends and aggregators can display it without
+    /// depending on an off-chain configuration.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `display_symbol` - The symbol used to display the money market.
+    /// - `underlying_decimals` - The number of decimals of the money market underlying.
+    /// - `icon_uri_hash` - The sha256 hash of the money market icon URI. Can be empty.
+    /// - `listed_at` - The listing timestamp. If zero, the current timestamp is used.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - Overrides any previous metadata.
+    ///
+    pub fn set_market_metadata<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u8>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        money_market: Arg0,
+        display_symbol: Arg1,
+        underlying_decimals: Arg2,
+        icon_uri_hash: Arg3,
+        listed_at: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketMetadata")
+            .argument(&money_market)
+            .argument(&display_symbol)
+            .argument(&underlying_decimals)
+            .argument(&icon_uri_hash)
+            .argument(&listed_at)
+            .original_result()
+    }
+
+    /// Clears the listing metadata of a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn clear_market_metadata<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearMarketMetadata")
             .argument(&money_market)
             .original_result()
     }
@@ -183,7 +390,8 @@ where
     /// # Notes:
     ///
     /// - Can only be called by the admin.
-    /// - Must be higher than the current maximum.
+    /// - Must be higher than the current maximum. Decreases are handled by `decreaseMaxMarketsPerAccount`.
+    /// - Clears any scheduled decrease.
     ///
     pub fn set_max_markets_per_account<
         Arg0: ProxyArg<usize>,
@@ -198,6 +406,49 @@ where
             .original_result()
     }
 
+    /// Schedules a decrease of the maximum number of money markets that can be entered per account, which applies after a
+    /// timelock.
+    ///
+    /// # Arguments:
+    ///
+    /// - `next_max_markets_per_account` - The next maximum number of money markets that can be entered per account.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Must be lower than the current maximum and higher than zero.
+    /// - Only applies to new market entries. Accounts that already entered more markets keep them, but cannot enter new
+    ///   ones until they are below the new maximum.
+    /// - Replaces any previously scheduled decrease.
+    ///
+    pub fn decrease_max_markets_per_account<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        next_max_markets_per_account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("decreaseMaxMarketsPerAccount")
+            .argument(&next_max_markets_per_account)
+            .original_result()
+    }
+
+    /// Cancels a scheduled decrease of the maximum number of money markets that can be entered per account.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn cancel_max_markets_per_account_decrease(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelMaxMarketsPerAccountDecrease")
+            .original_result()
+    }
+
     /// Sets the collateral factors or loan to values for a given money market.
     ///
     /// # Arguments:
@@ -258,6 +509,61 @@ where
             .original_result()
     }
 
+    /// Sets a pricing Oracle smart contract address for a given money market, which overrides the global pricing Oracle
+    /// for that market's underlying.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `new_price_oracle` - The address of the pricing oracle smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a valid oracle smart contract.
+    /// - The new oracle must be able to price the money market underlying.
+    ///
+    pub fn set_market_price_oracle<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+        new_price_oracle: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMarketPriceOracle")
+            .argument(&money_market)
+            .argument(&new_price_oracle)
+            .original_result()
+    }
+
+    /// Removes the pricing Oracle smart contract address of a given money market, such that the global pricing Oracle is
+    /// used again for that market's underlying.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The global oracle must be able to price the money market underlying.
+    ///
+    pub fn remove_market_price_oracle<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeMarketPriceOracle")
+            .argument(&money_market)
+            .original_result()
+    }
+
     /// Sets a liquidity cap for a given money market.
     ///
     /// # Arguments:
@@ -314,1403 +620,4059 @@ where
             .original_result()
     }
 
-    /// Sets the maximum amount of rewards batches per money market.
+    /// Sets a collateral cap for a given money market, i.e. the maximum amount of underlying that can be deposited as
+    /// collateral into the controller, regardless of the money market liquidity.
     ///
     /// # Arguments:
     ///
     /// - `money_market` - The address of the money market smart contract.
-    /// - `new_max_rewards_batches` - The new maximum amount of rewards batches.
+    /// - `new_collateral_cap` - The new collateral cap, in underlying units.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
     /// - The provided address must be a whitelisted money market.
+    /// - Lowering the cap below the current collateral does not affect existing deposits, but prevents new ones.
     ///
-    pub fn set_max_rewards_batches<
+    pub fn set_collateral_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<usize>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-        new_max_rewards_batches: Arg1,
+        new_collateral_cap: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setMaxRewardsBatches")
+            .raw_call("setCollateralCap")
             .argument(&money_market)
-            .argument(&new_max_rewards_batches)
+            .argument(&new_collateral_cap)
             .original_result()
     }
 
-    /// Sets the maximum slippage allowed for configuration swaps.
+    /// Sets the maximum amount of underlying that can be borrowed from a given money market in a single transaction,
+    /// regardless of the account borrowing limits. It throttles giant borrows that might stress the oracle and the market
+    /// liquidity within a single block.
     ///
     /// # Arguments:
     ///
-    /// - `new_max_slippage` - The new maximum slippage allowed.
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `new_max_borrow_per_tx` - The new maximum borrow amount per transaction.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
     ///
-    pub fn set_max_slippage<
-        Arg0: ProxyArg<BigUint<Env::Api>>,
+    pub fn set_max_borrow_per_tx<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        new_max_slippage: Arg0,
+        money_market: Arg0,
+        new_max_borrow_per_tx: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setMaxSlippage")
-            .argument(&new_max_slippage)
+            .raw_call("setMaxBorrowPerTx")
+            .argument(&money_market)
+            .argument(&new_max_borrow_per_tx)
             .original_result()
     }
 
-    /// Adds a rewards batch to the specified money market. EGLD or ESDT tokens are supported.
+    /// Removes the maximum borrow size per transaction of a given money market, such that borrows are only bounded by the
+    /// account borrowing limits and the market caps.
     ///
     /// # Arguments:
     ///
     /// - `money_market` - The address of the money market smart contract.
-    /// - `market_type` - Distribute rewards for suppliers (`Supply`) or lenders (`Borrows`).
-    /// - `period` - The period of time in seconds in which rewards are distributed.
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or rewards manager.
-    /// - The provided address must be whitelisted money market.
-    /// - Should be paid with the rewards token.
+    /// - Can only be called by the admin.
     ///
-    pub fn set_rewards_batch<
+    pub fn remove_max_borrow_per_tx<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<MarketType>,
-        Arg2: ProxyArg<u64>,
     >(
         self,
         money_market: Arg0,
-        market_type: Arg1,
-        period: Arg2,
-    ) -> TxTypedCall<Env, From, To, (), Gas, usize> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("setRewardsBatch")
+            .payment(NotPayable)
+            .raw_call("removeMaxBorrowPerTx")
             .argument(&money_market)
-            .argument(&market_type)
-            .argument(&period)
             .original_result()
     }
 
-    /// Adds an amount of reward token to an existing rewards batch maintaining the same speed.
+    /// Sets a borrow cap for a given account at a given money market, i.e. the maximum amount of underlying the account can
+    /// owe to the money market, on top of the money market borrow cap.
     ///
     /// # Arguments:
     ///
     /// - `money_market` - The address of the money market smart contract.
-    /// - `batch_id` - the rewards batch identifier
+    /// - `account` - The address of the account.
+    /// - `new_account_borrow_cap` - The new account borrow cap.
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or rewards manager.
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - Lowering the cap below the current account borrows does not affect existing borrows, but prevents new ones.
     ///
-    pub fn add_rewards_batch<
+    pub fn set_account_borrow_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-        batch_id: Arg1,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        account: Arg1,
+        new_account_borrow_cap: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("addRewardsBatch")
+            .payment(NotPayable)
+            .raw_call("setAccountBorrowCap")
             .argument(&money_market)
-            .argument(&batch_id)
+            .argument(&account)
+            .argument(&new_account_borrow_cap)
             .original_result()
     }
 
-    /// Cancel a specified rewards batch. Remaining tokens are sent back to a beneficiary.
+    /// Removes the borrow cap of a given account at a given money market, such that only the money market caps apply.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - the address of the money market smart contract.
-    /// - `batch_id` - the rewards batch identifier
-    /// - `opt_to` - the beneficiary address for the remaining tokens (optional)
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The address of the account.
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or rewards manager.
-    /// - The caller is selected if no beneficiary is given.
+    /// - Can only be called by the admin.
     ///
-    pub fn cancel_rewards_batch<
+    pub fn remove_account_borrow_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<usize>,
-        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-        batch_id: Arg1,
-        opt_to: Arg2,
+        account: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("cancelRewardsBatch")
+            .raw_call("removeAccountBorrowCap")
             .argument(&money_market)
-            .argument(&batch_id)
-            .argument(&opt_to)
+            .argument(&account)
             .original_result()
     }
 
-    /// Removes a specified rewards batch from the array of rewards batches iff it has been fully distributed.
+    /// Sets a utilization cap for a given money market, i.e. the maximum ratio between borrows and liquidity beyond which
+    /// new borrows are rejected.
     ///
-    /// # Arguments
+    /// # Arguments:
     ///
-    /// - `money_market` - the address of the money market smart contract.
-    /// - `batch_id` - the rewards batch identifier
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `new_utilization_cap` - The new utilization cap in wad.
     ///
-    /// # Notes
+    /// # Notes:
     ///
-    /// - can be called by anyone
-    /// - takes into consideration possible rounding errors but it is conservative
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - The utilization cap cannot exceed 100%.
     ///
-    pub fn remove_rewards_batch<
+    pub fn set_utilization_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<usize>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-        batch_id: Arg1,
+        new_utilization_cap: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("removeRewardsBatch")
+            .raw_call("setUtilizationCap")
             .argument(&money_market)
-            .argument(&batch_id)
+            .argument(&new_utilization_cap)
             .original_result()
     }
 
-    /// Removes a specified rewards batch from the array of rewards batches iff it has been fully distributed within a given
-    /// tolerance amount.
+    /// Assigns a money market to a cap group, i.e. a group of money markets with correlated risk that share a combined
+    /// borrow cap.
     ///
-    /// # Arguments
+    /// # Arguments:
     ///
-    /// - `money_market` - the address of the money market smart contract.
-    /// - `batch_id` - the rewards batch identifier
-    /// - `tolerance` - the tolerance in wad, such that 1 wad = 100%.
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `group_id` - The cap group identifier, which starts at 1. If zero, the money market is removed from its cap group.
     ///
-    /// # Notes
+    /// # Notes:
     ///
-    /// - can only be called by the admin or rewards manager.
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - A money market can only belong to a single cap group.
     ///
-    pub fn admin_remove_rewards_batch<
+    pub fn set_market_cap_group<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<usize>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-        batch_id: Arg1,
-        tolerance: Arg2,
+        group_id: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("adminRemoveRewardsBatch")
+            .raw_call("setMarketCapGroup")
             .argument(&money_market)
-            .argument(&batch_id)
-            .argument(&tolerance)
+            .argument(&group_id)
             .original_result()
     }
 
-    /// Updates a given rewards batch based on a new speed. The new speed of rewards also changes the remaining distribution
-    /// time period.
-    ///
+    /// Sets a combined borrow cap for a given cap group.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - the address of the money market smart contract.
-    /// - `batch_id` - The rewards batch identifier.
-    /// - `new_speed` - The new speed of rewards in wad.
+    /// - `group_id` - The cap group identifier, which starts at 1.
+    /// - `new_borrow_cap` - The new combined borrow cap in EGLD and in wad.
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or rewards manager.
+    /// - Can only be called by the admin.
+    /// - Borrows from all the money markets in the cap group are valued using the price oracle.
     ///
-    pub fn update_rewards_batch_speed<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<usize>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
+    pub fn set_cap_group_borrow_cap<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        money_market: Arg0,
-        batch_id: Arg1,
-        new_speed: Arg2,
+        group_id: Arg0,
+        new_borrow_cap: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("updateRewardsBatchSpeed")
-            .argument(&money_market)
-            .argument(&batch_id)
-            .argument(&new_speed)
+            .raw_call("setCapGroupBorrowCap")
+            .argument(&group_id)
+            .argument(&new_borrow_cap)
             .original_result()
     }
 
-    /// Updates a given rewards batch based on a new period. The new period also changes the speed of rewards.
-    ///
+    /// Flags or unflags a money market as deprecated. Temporary borrow pauses never deprecate a money market by themselves.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - the address of the money market smart contract.
-    /// - `batch_id` - The rewards batch identifier.
-    /// - `new_dt` - The new period.
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `deprecated` - Whether the money market is deprecated.
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or rewards manager.
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - A flagged money market is only considered deprecated once its collateral factor is zero, its borrows are paused
+    ///   and its reserve factor is 100%.
     ///
-    pub fn update_rewards_batch_remaining_period<
+    pub fn set_deprecated<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<usize>,
-        Arg2: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
     >(
         self,
         money_market: Arg0,
-        batch_id: Arg1,
-        new_dt: Arg2,
+        deprecated: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("updateRewardsBatchRemainingPeriod")
+            .raw_call("setDeprecated")
             .argument(&money_market)
-            .argument(&batch_id)
-            .argument(&new_dt)
+            .argument(&deprecated)
             .original_result()
     }
 
-    /// Claims the undistributed rewards for a given rewards token.
+    /// Pushes the Controller-derived values of the given money markets to them, e.g. to backfill money markets supported
+    /// before they mirrored the Controller.
     ///
     /// # Arguments:
     ///
-    /// - `rewards_token_id` - the rewards token identifier
+    /// - `money_markets` - The money market addresses to sync. If empty, all whitelisted markets will be used.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
-    /// - The rewards token must have undistributed rewards.
-    /// - Undistributed rewards might originate at markets without collateral or borrows, or because of truncation errors.
+    /// - Position markets and USH money markets do not mirror the Controller and are skipped.
     ///
-    pub fn claim_undistributed_rewards<
-        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    pub fn sync_market_mirrors<
+        Arg0: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
     >(
         self,
-        rewards_token_id: Arg0,
+        money_markets: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("claimUndistributedRewards")
-            .argument(&rewards_token_id)
+            .raw_call("syncMarketMirrors")
+            .argument(&money_markets)
             .original_result()
     }
 
-    /// Adds support for boosting rewards batches by converting the rewards batch tokens into Hatom's governance tokens with
-    /// a premium.
+    /// Sets the maximum amount of rewards batches per money market.
     ///
     /// # Arguments:
     ///
-    /// - `governance_token_id` - the governance token identifier
-    /// - `egld_wrapper` - the address of the EGLD wrapper smart contract
-    /// - `router` - the address of the router smart contract
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `new_max_rewards_batches` - The new maximum amount of rewards batches.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
     ///
-    pub fn support_rewards_batch_boosting<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    pub fn set_max_rewards_batches<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
     >(
         self,
-        governance_token_id: Arg0,
-        egld_wrapper: Arg1,
-        router: Arg2,
+        money_market: Arg0,
+        new_max_rewards_batches: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("supportRewardsBatchBoosting")
-            .argument(&governance_token_id)
-            .argument(&egld_wrapper)
-            .argument(&router)
+            .raw_call("setMaxRewardsBatches")
+            .argument(&money_market)
+            .argument(&new_max_rewards_batches)
             .original_result()
     }
 
-    /// Enables support for boosting rewards batches.
+    /// Sets the maximum slippage allowed for configuration swaps.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_max_slippage` - The new maximum slippage allowed.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
     ///
-    pub fn enable_rewards_batch_boosting(
+    pub fn set_max_slippage<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
+        new_max_slippage: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("enableRewardsBatchBoosting")
+            .raw_call("setMaxSlippage")
+            .argument(&new_max_slippage)
             .original_result()
     }
 
-    /// Disables support for boosting rewards batches.
+    /// Supports a token for borrow repayments with automatic swap, i.e. a token that can be swapped into the borrow market
+    /// underlying at the `repayWithAnyToken` endpoint.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
+    /// - The price oracle must be able to price the token, as swaps are checked against oracle values.
     ///
-    pub fn disable_rewards_batch_boosting(
+    pub fn support_repay_swap_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
         self,
+        token_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("disableRewardsBatchBoosting")
+            .raw_call("supportRepaySwapToken")
+            .argument(&token_id)
             .original_result()
     }
 
-    /// Boosts the rewards of a given rewards token by converting the rewards tokens into Hatom's governance token with a
-    /// premium.
+    /// Removes the support of a token for borrow repayments with automatic swap.
     ///
     /// # Arguments:
     ///
-    /// - `premium` - the premium in wad, such that 1 wad = 100%.
-    /// - `fwd_swap_amount` - the amount of tokens to swap.
-    /// - `fwd_swap_path` - the swap path to convert the rewards batch tokens into Hatom's governance tokens.
+    /// - `token_id` - The token identifier.
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or rewards manager.
-    /// - If rewards token is EGLD, swaps will add a EGLD => WEGLD step first. Also, the swap path needs to use the WEGLD
-    ///   token identifier.
+    /// - Can only be called by the admin.
     ///
-    pub fn boost_rewards<
-        Arg0: ProxyArg<BigUint<Env::Api>>,
-        Arg1: ProxyArg<BigUint<Env::Api>>,
-        Arg2: ProxyArg<ManagedVec<Env::Api, SwapStep<Env::Api>>>,
+    pub fn unsupport_repay_swap_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
     >(
         self,
-        premium: Arg0,
-        fwd_swap_amount: Arg1,
-        fwd_swap_path: Arg2,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("boostRewards")
-            .argument(&premium)
-            .argument(&fwd_swap_amount)
-            .argument(&fwd_swap_path)
+            .payment(NotPayable)
+            .raw_call("unsupportRepaySwapToken")
+            .argument(&token_id)
             .original_result()
     }
 
-    /// Updates the premium of a given booster and, if a payment is provided, adds it to the booster's amount.
+    /// Adds a rewards batch to the specified money market. EGLD or ESDT tokens are supported.
     ///
     /// # Arguments:
     ///
-    /// - `rewards_token_id` - the rewards token identifier for which we wish to update its booster.
-    /// - `premium` - the premium in wad, such that 1 wad = 100%.
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `market_type` - Distribute rewards for suppliers (`Supply`) or lenders (`Borrows`).
+    /// - `period` - The period of time in seconds in which rewards are distributed.
+    /// - `opt_account_cap` - An optional maximum amount of rewards a single account can earn from the batch.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin or rewards manager.
-    /// - Cannot change the swap path. That requires canceling the booster and creating a new one.
+    /// - The provided address must be whitelisted money market.
+    /// - Should be paid with the rewards token, which can be EGLD, a fungible ESDT or a Meta-ESDT.
+    /// - A Meta-ESDT rewards token is bound to the nonce of its first rewards batch, such that all its rewards batches must
+    ///   be paid with that same nonce. Meta-ESDT rewards cannot be boosted nor swapped.
+    /// - Rewards above the per-account cap are kept as undistributed rewards.
+    /// - If the number of decimals of an ESDT rewards token has not been resolved yet, it is queried asynchronously from the
+    ///   ESDT system smart contract at the end of the call. Hence, the rewards batch identifier is not returned but emitted
+    ///   at the `set_rewards_batch_event` event.
     ///
-    pub fn update_booster<
-        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<BigUint<Env::Api>>,
+    pub fn set_rewards_batch<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<MarketType>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
-        rewards_token_id: Arg0,
-        premium: Arg1,
+        money_market: Arg0,
+        market_type: Arg1,
+        period: Arg2,
+        opt_account_cap: Arg3,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .raw_call("updateBooster")
-            .argument(&rewards_token_id)
-            .argument(&premium)
+            .raw_call("setRewardsBatch")
+            .argument(&money_market)
+            .argument(&market_type)
+            .argument(&period)
+            .argument(&opt_account_cap)
             .original_result()
     }
 
-    /// Cancels a given booster and sends the remaining tokens back to the caller.
+    /// Adds an amount of reward token to an existing rewards batch maintaining the same speed.
     ///
     /// # Arguments:
     ///
-    /// - `rewards_token_id` - the rewards token identifier for which we wish to cancel its booster.
-    /// - `opt_to` - the beneficiary address for the remaining tokens (optional).
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `batch_id` - the rewards batch identifier
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
     ///
-    pub fn cancel_booster<
-        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    pub fn add_rewards_batch<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
     >(
         self,
-        rewards_token_id: Arg0,
-        opt_to: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        money_market: Arg0,
+        batch_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("cancelBooster")
-            .argument(&rewards_token_id)
-            .argument(&opt_to)
+            .raw_call("addRewardsBatch")
+            .argument(&money_market)
+            .argument(&batch_id)
             .original_result()
     }
 
-    /// Updates the collateral or account tokens of a given account in a given money market, which is useful at liquidations.
-    /// The general idea is that the account is removing collateral, which should update the total collateral tokens and the
-    /// account's collateral tokens.
+    /// Cancel a specified rewards batch. Remaining tokens are sent back to a beneficiary.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `account` - The address of the account we wish to update.
-    /// - `tokens` - The number of Hatom's tokens to set as collateral.
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_id` - the rewards batch identifier
+    /// - `opt_to` - the beneficiary address for the remaining tokens (optional)
     ///
     /// # Notes:
     ///
-    /// - Can only be called by a whitelisted money market.
-    /// - The provided address must be a whitelisted money market.
-    /// - Makes sure the mappers `account_markets` and `market_members` remain updated.
+    /// - Can only be called by the admin or rewards manager.
+    /// - The caller is selected if no beneficiary is given.
     ///
-    pub fn set_account_collateral_tokens<
+    pub fn cancel_rewards_batch<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         money_market: Arg0,
-        account: Arg1,
-        new_tokens: Arg2,
+        batch_id: Arg1,
+        opt_to: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setAccountTokens")
+            .raw_call("cancelRewardsBatch")
             .argument(&money_market)
-            .argument(&account)
-            .argument(&new_tokens)
+            .argument(&batch_id)
+            .argument(&opt_to)
             .original_result()
     }
 
-    /// Sets the Rewards Manager of the protocol.
+    /// Removes a specified rewards batch from the array of rewards batches iff it has been fully distributed.
     ///
-    /// # Arguments:
+    /// # Arguments
     ///
-    /// - `new_rewards_manager` - The address of the new Rewards Manager.
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_id` - the rewards batch identifier
     ///
-    /// # Notes:
+    /// # Notes
     ///
-    /// - Can only be called by the admin.
+    /// - can be called by anyone
+    /// - takes into consideration possible rounding errors but it is conservative
     ///
-    pub fn set_rewards_manager<
+    pub fn remove_rewards_batch<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
     >(
         self,
-        new_rewards_manager: Arg0,
+        money_market: Arg0,
+        batch_id: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setRewardsManager")
-            .argument(&new_rewards_manager)
+            .raw_call("removeRewardsBatch")
+            .argument(&money_market)
+            .argument(&batch_id)
             .original_result()
     }
 
-    /// Sets the Guardian of the protocol.
+    /// Removes a specified rewards batch from the array of rewards batches iff it has been fully distributed within a given
+    /// tolerance amount.
     ///
-    /// # Arguments:
+    /// # Arguments
     ///
-    /// - `new_pause_guardian` - The address of the new Guardian.
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_id` - the rewards batch identifier
+    /// - `tolerance` - the tolerance in wad, such that 1 wad = 100%.
     ///
-    /// # Notes:
+    /// # Notes
     ///
-    /// - Can only be called by the admin.
+    /// - can only be called by the admin or rewards manager.
     ///
-    pub fn set_pause_guardian<
+    pub fn admin_remove_rewards_batch<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        new_pause_guardian: Arg0,
+        money_market: Arg0,
+        batch_id: Arg1,
+        tolerance: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setPauseGuardian")
-            .argument(&new_pause_guardian)
+            .raw_call("adminRemoveRewardsBatch")
+            .argument(&money_market)
+            .argument(&batch_id)
+            .argument(&tolerance)
             .original_result()
     }
 
-    /// Sets a Rewards Booster smart contract as an observer, i.e. as a contract that is notified when accounts deposit or
-    /// withdraw collateral from markets. The name Booster Observer is used to reference the Rewards Booster smart contract.
+    /// Updates a given rewards batch based on a new speed. The new speed of rewards also changes the remaining distribution
+    /// time period.
+    ///
     ///
     /// # Arguments:
     ///
-    /// - `new_booster_observer` - the rewards booster smart contract address.
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_id` - The rewards batch identifier.
+    /// - `new_speed` - The new speed of rewards in wad.
     ///
-    /// # Notes
+    /// # Notes:
     ///
-    /// - can only be called by the admin
-    /// - `new_booster_observer` must be a rewards booster smart contract
-    /// - `new_booster_observer` must not have been already used as a rewards booster
+    /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
     ///
-    pub fn set_booster_observer<
+    pub fn update_rewards_batch_speed<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        new_booster_observer: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
-        self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("setBoosterObserver")
-            .argument(&new_booster_observer)
-            .original_result()
-    }
-
-    /// Removes Rewards Booster smart contract from being an observer. From this point onwards, this smart contract will not
-    /// be notified of any market change.
-    ///
-    pub fn clear_booster_observer(
-        self,
+        money_market: Arg0,
+        batch_id: Arg1,
+        new_speed: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("clearBoosterObserver")
+            .raw_call("updateRewardsBatchSpeed")
+            .argument(&money_market)
+            .argument(&batch_id)
+            .argument(&new_speed)
             .original_result()
     }
 
-    /// Sets a USH Money Market smart contract as an observer, i.e. as a contract that is notified when accounts deposit or
-    /// withdraw collateral from markets. The name USH Market Observer is used to reference the USH Money Market smart
-    /// contract.
+    /// Denominates a given rewards batch in USD, i.e. sets a USD value per day to be distributed instead of a fixed amount of
+    /// tokens per second. The speed of rewards is recomputed using the oracle price of the rewards token right away and every
+    /// time the rewards batch is repriced using `repriceRewardsBatch`, which also changes the remaining distribution time
+    /// period.
     ///
     /// # Arguments:
     ///
-    /// - `new_ush_market_observer` - The USH Money Market smart contract address.
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_id` - The rewards batch identifier.
+    /// - `usd_per_day` - The USD value per day in wad. If zero, the rewards batch is denominated in tokens again, keeping
+    ///   its current speed.
     ///
-    /// # Notes
+    /// # Notes:
     ///
-    /// - can only be called by the admin
-    /// - `new_ush_market_observer` must have been already whitelisted as a money market
-    /// - `new_ush_market_observer` must not have been already used as a USH market observer
+    /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
+    /// - Rewards batches states updates never reprice rewards batches, such that they do not depend on the price oracle.
     ///
-    pub fn set_ush_market_observer<
+    pub fn set_rewards_batch_usd_per_day<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        new_ush_market_observer: Arg0,
+        money_market: Arg0,
+        batch_id: Arg1,
+        usd_per_day: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setUshMarketObserver")
-            .argument(&new_ush_market_observer)
+            .raw_call("setRewardsBatchUsdPerDay")
+            .argument(&money_market)
+            .argument(&batch_id)
+            .argument(&usd_per_day)
             .original_result()
     }
 
-    /// Clears the USH Market smart contract from being an observer. From this point onwards, this smart contract will not be
-    /// notified of any market change.
+    /// Updates a given rewards batch based on a new period. The new period also changes the speed of rewards.
     ///
-    pub fn clear_ush_market_observer(
-        self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
-        self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("clearUshMarketObserver")
-            .original_result()
-    }
-
-    /// Changes the minting status for a specific money market.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_id` - The rewards batch identifier.
+    /// - `new_dt` - The new period.
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or the Guardian.
+    /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
     ///
-    pub fn pause_mint<
+    pub fn update_rewards_batch_remaining_period<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<bool>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<u64>,
     >(
         self,
         money_market: Arg0,
-        pause: Arg1,
+        batch_id: Arg1,
+        new_dt: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("pauseMint")
+            .raw_call("updateRewardsBatchRemainingPeriod")
             .argument(&money_market)
-            .argument(&pause)
+            .argument(&batch_id)
+            .argument(&new_dt)
             .original_result()
     }
 
-    /// Changes the borrowing status for a specific money market.
+    /// Merges several rewards batches from the same money market, rewards token and market type into a single rewards batch.
+    /// The remaining rewards of all merged batches are consolidated into the first batch, whose speed and end time are
+    /// recomputed such that all remaining rewards are distributed by the latest end time among the merged batches.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_ids` - the rewards batch identifiers, where the first one is the batch that absorbs the others.
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or the Guardian.
+    /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
+    /// - Rewards batches denominated in USD cannot be merged.
+    /// - Merged batches stop accruing rewards and are removed if they have been fully distributed. Otherwise, they are kept
+    ///   until all accounts have been distributed their rewards and can be removed with `removeRewardsBatch`.
     ///
-    pub fn pause_borrow<
+    pub fn merge_rewards_batches<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<bool>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, usize>>,
     >(
         self,
         money_market: Arg0,
-        pause: Arg1,
+        batch_ids: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("pauseBorrow")
+            .raw_call("mergeRewardsBatches")
             .argument(&money_market)
-            .argument(&pause)
+            .argument(&batch_ids)
             .original_result()
     }
 
-    /// Changes the seizing status for a specific money market.
+    /// Claims the undistributed rewards for a given rewards token.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `rewards_token_id` - the rewards token identifier
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or the Guardian.
+    /// - Can only be called by the admin.
+    /// - The rewards token must have undistributed rewards.
+    /// - Undistributed rewards might originate at markets without collateral or borrows, or because of truncation errors.
     ///
-    pub fn pause_seize<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<bool>,
+    pub fn claim_undistributed_rewards<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        money_market: Arg0,
-        pause: Arg1,
+        rewards_token_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("pauseSeize")
-            .argument(&money_market)
-            .argument(&pause)
+            .raw_call("claimUndistributedRewards")
+            .argument(&rewards_token_id)
             .original_result()
     }
 
-    /// Changes the seizing status (required for liquidations) for all money markets.
+    /// Rescues tokens sent by mistake to the controller.
     ///
     /// # Arguments:
     ///
-    /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `token_id` - the identifier of the token to rescue
+    /// - `amount` - the amount of tokens to rescue
+    /// - `to` - the beneficiary address
     ///
     /// # Notes:
     ///
-    /// - Can only be called by the admin or the Guardian.
+    /// - Can only be called by the admin.
+    /// - Hatom's tokens, rewards tokens (active or past), boosted rewards tokens and the governance token cannot be rescued.
+    /// - EGLD bonded by liquidators cannot be rescued.
     ///
-    pub fn pause_global_seize<
-        Arg0: ProxyArg<bool>,
+    pub fn rescue_token<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        pause: Arg0,
+        token_id: Arg0,
+        amount: Arg1,
+        to: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("pauseGlobalSeize")
-            .argument(&pause)
+            .raw_call("rescueToken")
+            .argument(&token_id)
+            .argument(&amount)
+            .argument(&to)
             .original_result()
     }
 
-    /// Payable endpoint used to enter to a one or many markets, i.e. provide collateral for sender liquidity calculations.
-    /// The sender can perform multiple calls to keep adding more collateral.
+    /// Adds support for boosting rewards batches by converting the rewards batch tokens into Hatom's governance tokens with
+    /// a premium.
     ///
     /// # Arguments:
     ///
-    /// - `opt_account` - If given, the collateral will be deposited on the name of this account. Can only be performed by a
-    ///   whitelisted money market.
+    /// - `governance_token_id` - the governance token identifier
+    /// - `egld_wrapper` - the address of the EGLD wrapper smart contract
+    /// - `router` - the address of the router smart contract
     ///
     /// # Notes:
     ///
-    /// - Must be paid with one or many valid ESDT Hatom tokens
+    /// - Can only be called by the admin.
     ///
-    pub fn enter_markets<
-        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    pub fn support_rewards_batch_boosting<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        opt_account: Arg0,
-    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        governance_token_id: Arg0,
+        egld_wrapper: Arg1,
+        router: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("enterMarkets")
-            .argument(&opt_account)
+            .payment(NotPayable)
+            .raw_call("supportRewardsBatchBoosting")
+            .argument(&governance_token_id)
+            .argument(&egld_wrapper)
+            .argument(&router)
             .original_result()
     }
 
-    /// Exits a given amount of tokens from a given money market, i.e. removes the caller's deposited collateral for
-    /// liquidity computations. If the amount of tokens is not specified, all the position is removed.
+    /// Enables support for boosting rewards batches.
     ///
-    /// # Arguments:
+    /// # Notes:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `opt_tokens` - If given, the amount of collateral tokens to remove.
+    /// - Can only be called by the admin.
+    ///
+    pub fn enable_rewards_batch_boosting(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("enableRewardsBatchBoosting")
+            .original_result()
+    }
+
+    /// Disables support for boosting rewards batches.
     ///
     /// # Notes:
     ///
-    /// - The provided address must be a whitelisted money market.
-    /// - The caller must have collateral in the corresponding money market.
-    /// - The amount of tokens to withdraw should not exceed the current deposited amount.
-    /// - The caller must be providing the necessary collateral for any outstanding borrows.
+    /// - Can only be called by the admin.
     ///
-    pub fn exit_market<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
-    >(
+    pub fn disable_rewards_batch_boosting(
         self,
-        money_market: Arg0,
-        opt_tokens: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("exitMarket")
-            .argument(&money_market)
-            .argument(&opt_tokens)
+            .raw_call("disableRewardsBatchBoosting")
             .original_result()
     }
 
-    /// Exits a given amount of tokens from a given money market, i.e. removes the caller's deposited collateral for liquidity
-    /// computations and redeems the corresponding amount of tokens.
+    /// Boosts the rewards of a given rewards token by converting the rewards tokens into Hatom's governance token with a
+    /// premium.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `opt_tokens` - If given, the amount of collateral tokens to remove.
-    /// - `opt_underlying_amount` - An optional amount of underlying asset to receive back in exchange for the paid Hatom's
-    ///   tokens.
+    /// - `premium` - the premium in wad, such that 1 wad = 100%.
+    /// - `fwd_swap_amount` - the amount of tokens to swap.
+    /// - `fwd_swap_path` - the swap path to convert the rewards batch tokens into Hatom's governance tokens.
     ///
     /// # Notes:
     ///
-    /// - The provided address must be a whitelisted money market.
-    /// - The caller must have collateral in the corresponding money market.
-    /// - The amount of tokens to withdraw should not exceed the current deposited amount.
+    /// - Can only be called by the admin or rewards manager.
+    /// - If rewards token is EGLD, swaps will use WEGLD from the buffer or add a EGLD => WEGLD step first. Also, the swap path
+    ///   needs to use the WEGLD token identifier. The resulting WEGLD is kept in the buffer until it is paid out.
+    /// - The price oracle must support the governance token. Each swap leg cannot lose more than the maximum slippage as
+    ///   compared to oracle values.
     ///
-    pub fn exit_market_and_redeem<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<Option<BigUint<Env::Api>>>,
-        Arg2: ProxyArg<Option<BigUint<Env::Api>>>,
+    pub fn boost_rewards<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedVec<Env::Api, SwapStep<Env::Api>>>,
     >(
         self,
-        money_market: Arg0,
-        opt_tokens: Arg1,
-        opt_underlying_amount: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<EgldOrEsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        premium: Arg0,
+        fwd_swap_amount: Arg1,
+        fwd_swap_path: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("exitMarketAndRedeem")
-            .argument(&money_market)
-            .argument(&opt_tokens)
-            .argument(&opt_underlying_amount)
+            .raw_call("boostRewards")
+            .argument(&premium)
+            .argument(&fwd_swap_amount)
+            .argument(&fwd_swap_path)
             .original_result()
     }
 
-    /// Removes an account from the given money market when the account has no collateral and no outstanding borrow in the
-    /// given money market.
+    /// Updates the premium of a given booster and, if a payment is provided, adds it to the booster's amount.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `opt_account` - If given, the address of the account to remove. If not given, the caller's address is used.
+    /// - `rewards_token_id` - the rewards token identifier for which we wish to update its booster.
+    /// - `premium` - the premium in wad, such that 1 wad = 100%.
     ///
-    pub fn remove_account_market<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or rewards manager.
+    /// - Cannot change the swap path. Use `updateBoosterSwapPath` instead.
+    ///
+    pub fn update_booster<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        money_market: Arg0,
-        opt_account: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        rewards_token_id: Arg0,
+        premium: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
-            .raw_call("removeAccountMarket")
-            .argument(&money_market)
-            .argument(&opt_account)
+            .raw_call("updateBooster")
+            .argument(&rewards_token_id)
+            .argument(&premium)
             .original_result()
     }
 
-    /// Checks whether minting is allowed at a specified money market.
+    /// Updates the swap path of a given booster, preserving its premium and distribution accounting.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
+    /// - `rewards_token_id` - the rewards token identifier for which we wish to update its booster swap path.
+    /// - `swap_amount` - the amount of rewards tokens used to validate the new swap path.
+    /// - `new_swap_path` - the new swap path to convert the rewards batch tokens into Hatom's governance tokens.
     ///
     /// # Notes:
     ///
-    /// - It does not depend on the account that intends to mint.
-    /// - Fails with panic and a clear error message or returns true.
+    /// - Can only be called by the admin or rewards manager.
+    /// - Boosting must be active.
+    /// - The swap amount cannot exceed the booster amount left.
+    /// - The new swap path is dry run against the current xExchange pools reserves, such that no tokens are swapped. It
+    ///   must be healthy and its expected output cannot lose more than the maximum slippage as compared to oracle values.
+    /// - If rewards token is EGLD, the swap path needs to use the WEGLD token identifier.
     ///
-    pub fn mint_allowed<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    pub fn update_booster_swap_path<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedVec<Env::Api, SwapStep<Env::Api>>>,
     >(
         self,
-        money_market: Arg0,
-        amount: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        rewards_token_id: Arg0,
+        swap_amount: Arg1,
+        new_swap_path: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("mintAllowed")
-            .argument(&money_market)
-            .argument(&amount)
+            .raw_call("updateBoosterSwapPath")
+            .argument(&rewards_token_id)
+            .argument(&swap_amount)
+            .argument(&new_swap_path)
             .original_result()
     }
 
-    /// Checks whether an account (redeemer) should be allowed to withdraw a given amount of Hatom tokens from a given
-    /// market, i.e. withdraw collateral.
+    /// Cancels a given booster and sends the remaining tokens back to the caller.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `redeemer` - The account that intends to withdraw the tokens.
-    /// - `tokens` - The amount of Hatom tokens to withdraw.
+    /// - `rewards_token_id` - the rewards token identifier for which we wish to cancel its booster.
+    /// - `opt_to` - the beneficiary address for the remaining tokens (optional).
     ///
     /// # Notes:
     ///
-    /// - This function is not used when redeeming at a money market, it is only used when redeeming (exiting the market) at
-    ///   the controller.
-    /// - A simulation of the resulting risk profile is performed.
-    /// - Fails with panic and a clear error message, returns false if redeemer would become risky or true if she remains
-    ///   solvent.
+    /// - Can only be called by the admin or rewards manager.
     ///
-    pub fn redeem_allowed<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
+    pub fn cancel_booster<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
-        money_market: Arg0,
-        redeemer: Arg1,
-        tokens: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        rewards_token_id: Arg0,
+        opt_to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("redeemAllowed")
-            .argument(&money_market)
-            .argument(&redeemer)
-            .argument(&tokens)
+            .raw_call("cancelBooster")
+            .argument(&rewards_token_id)
+            .argument(&opt_to)
             .original_result()
     }
 
-    /// Checks whether an account (borrower) should be allowed to take a borrow of a given amount on a given money market.
+    /// Updates the collateral or account tokens of a given account in a given money market, which is useful at liquidations.
+    /// The general idea is that the account is removing collateral, which should update the total collateral tokens and the
+    /// account's collateral tokens.
     ///
     /// # Arguments:
     ///
     /// - `money_market` - The address of the money market smart contract.
-    /// - `borrower` - The account that intends to take a borrow.
-    /// - `amount` - The amount of underlying to borrow.
+    /// - `account` - The address of the account we wish to update.
+    /// - `tokens` - The number of Hatom's tokens to set as collateral.
     ///
     /// # Notes:
     ///
-    /// - Fails with panic and a clear error message, returns false if borrower would become risky or true if she remains
-    ///   solvent.
+    /// - Can only be called by a whitelisted money market.
+    /// - The provided address must be a whitelisted money market, other than a position market.
+    /// - Makes sure the mappers `account_markets` and `market_members` remain updated.
     ///
-    pub fn borrow_allowed<
+    pub fn set_account_collateral_tokens<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
         Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-        borrower: Arg1,
-        amount: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        account: Arg1,
+        new_tokens: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("borrowAllowed")
+            .raw_call("setAccountTokens")
             .argument(&money_market)
-            .argument(&borrower)
-            .argument(&amount)
+            .argument(&account)
+            .argument(&new_tokens)
             .original_result()
     }
 
-    /// Checks whether repaying a borrow is allowed at a specified money market.
+    /// Seizes collateral positions from a borrower in favor of a liquidator at the calling position market. The seized
+    /// meta-ESDT positions are sent to the liquidator.
     ///
     /// # Arguments:
     ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `borrower` - The address of the borrower.
+    /// - `borrower` - The address of the account having collateral seized.
+    /// - `liquidator` - The address of the account receiving the seized positions.
+    /// - `tokens` - The amount of collateral tokens to seize.
     ///
     /// # Notes:
     ///
-    /// - It does not depend on the account that intends to repay the borrow.
+    /// - Can only be called by a whitelisted position market.
+    /// - Positions are seized in the order they have been deposited by the borrower.
     ///
-    pub fn repay_borrow_allowed<
+    pub fn seize_positions<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        money_market: Arg0,
-        borrower: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        borrower: Arg0,
+        liquidator: Arg1,
+        tokens: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, EsdtTokenPayment<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("repayBorrowAllowed")
-            .argument(&money_market)
+            .raw_call("seizePositions")
             .argument(&borrower)
+            .argument(&liquidator)
+            .argument(&tokens)
             .original_result()
     }
 
-    /// Checks whether a liquidation is allowed or not to happen, repaying a borrow at a given money market and seizing
-    /// collateral at the same or another specified money market.
+    /// Sets the Rewards Manager of the protocol.
     ///
     /// # Arguments:
     ///
-    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
-    /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
-    /// - `borrower` - The address of the borrower.
-    /// - `amount` - The amount of underlying being repaid by the liquidator.
+    /// - `new_rewards_manager` - The address of the new Rewards Manager.
     ///
     /// # Notes:
     ///
-    /// - Borrows at deprecated markets can be fully repaid (the close factor does not play any role).
-    /// - Fails with panic and a clear error message, returns false if the borrower cannot be liquidated (i.e. the borrower
-    ///   is solvent) or true if the liquidation can be performed (i.e. the borrower is risky and repayment amount does not
-    ///   exceeds its maximum allowed).
+    /// - Can only be called by the admin.
     ///
-    pub fn liquidate_borrow_allowed<
+    pub fn set_rewards_manager<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg3: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        borrow_market: Arg0,
-        collateral_market: Arg1,
-        borrower: Arg2,
-        amount: Arg3,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        new_rewards_manager: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("liquidateBorrowAllowed")
-            .argument(&borrow_market)
-            .argument(&collateral_market)
-            .argument(&borrower)
-            .argument(&amount)
+            .raw_call("setRewardsManager")
+            .argument(&new_rewards_manager)
             .original_result()
     }
 
-    /// Checks whether seizing is or not allowed.
+    /// Sets a Rewards Booster smart contract as an observer, i.e. as a contract that is notified when accounts deposit or
+    /// withdraw collateral from markets. The name Booster Observer is used to reference the Rewards Booster smart contract.
     ///
     /// # Arguments:
     ///
-    /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
-    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
-    /// - `borrower` - The address of the borrower.
-    /// - `_liquidator` - The address of the liquidator (legacy).
+    /// - `new_booster_observer` - the rewards booster smart contract address.
     ///
-    /// # Notes:
+    /// # Notes
     ///
-    /// - Money markets should be whitelisted and share the same Controller.
+    /// - can only be called by the admin
+    /// - `new_booster_observer` must be a rewards booster smart contract
+    /// - `new_booster_observer` must not have been already used as a rewards booster
     ///
-    pub fn seize_allowed<
+    pub fn set_booster_observer<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        collateral_market: Arg0,
-        borrow_market: Arg1,
-        borrower: Arg2,
-        _liquidator: Arg3,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        new_booster_observer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("seizeAllowed")
-            .argument(&collateral_market)
-            .argument(&borrow_market)
-            .argument(&borrower)
-            .argument(&_liquidator)
+            .raw_call("setBoosterObserver")
+            .argument(&new_booster_observer)
             .original_result()
     }
 
-    /// Updates rewards batches states.
+    /// Removes Rewards Booster smart contract from being an observer. From this point onwards, this smart contract will not
+    /// be notified of any market change.
+    ///
+    pub fn clear_booster_observer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearBoosterObserver")
+            .original_result()
+    }
+
+    /// Schedules the forced removal of the current booster observer, which can be performed after a timelock using
+    /// `forceClearBoosterObserver`. This is an escape hatch for booster observers that cannot be finalized anymore.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Replaces any previously scheduled forced removal.
+    ///
+    pub fn schedule_force_clear_booster_observer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("scheduleForceClearBoosterObserver")
+            .original_result()
+    }
+
+    /// Cancels the scheduled forced removal of the booster observer.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn cancel_force_clear_booster_observer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelForceClearBoosterObserver")
+            .original_result()
+    }
+
+    /// Forcibly removes the booster observer without requiring it to be finalized. From this point onwards, this smart
+    /// contract will not be notified of any market change.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Must have been scheduled for the current booster observer and its timelock must have elapsed.
+    /// - Market changes pending to be notified to the booster observer are dropped.
+    ///
+    pub fn force_clear_booster_observer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("forceClearBoosterObserver")
+            .original_result()
+    }
+
+    /// Sets a USH Money Market smart contract as an observer, i.e. as a contract that is notified when accounts deposit or
+    /// withdraw collateral from markets. The name USH Market Observer is used to reference the USH Money Market smart
+    /// contract.
     ///
     /// # Arguments:
     ///
-    /// - `supply` - Whether or not to update supply rewards.
-    /// - `borrow` - Whether or not to update borrow rewards..
-    /// - `money_markets` - The money market addresses to update rewards in. If empty, all whitelisted markets will be used.
+    /// - `new_ush_market_observer` - The USH Money Market smart contract address.
     ///
-    pub fn update_rewards_batches_state<
-        Arg0: ProxyArg<bool>,
-        Arg1: ProxyArg<bool>,
-        Arg2: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `new_ush_market_observer` must have been already whitelisted as a money market
+    /// - `new_ush_market_observer` must not have been already used as a USH market observer
+    ///
+    pub fn set_ush_market_observer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        supply: Arg0,
-        borrow: Arg1,
-        money_markets: Arg2,
+        new_ush_market_observer: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("updateRewardsBatchesState")
-            .argument(&supply)
-            .argument(&borrow)
-            .argument(&money_markets)
+            .raw_call("setUshMarketObserver")
+            .argument(&new_ush_market_observer)
             .original_result()
     }
 
-    /// Distributes caller or specified accounts rewards from supply and/or borrow markets, at specific money markets.
+    /// Clears the USH Market smart contract from being an observer. From this point onwards, this smart contract will not be
+    /// notified of any market change.
+    ///
+    pub fn clear_ush_market_observer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearUshMarketObserver")
+            .original_result()
+    }
+
+    /// Sets the minimum amount of seconds between two consecutive protocol heartbeats.
     ///
     /// # Arguments:
     ///
-    /// - `supply` - Whether or not to distribute supply rewards.
-    /// - `borrow` - Whether or not to distribute borrow rewards.
-    /// - `money_markets` - The money market addresses to distribute rewards in. If empty, all whitelisted markets will be
-    ///   used.
-    /// - `accounts` - The addresses to distribute rewards for. If empty, the caller will be used.
+    /// - `new_interval` - The new heartbeat interval in seconds.
     ///
-    pub fn distribute_rewards<
-        Arg0: ProxyArg<bool>,
-        Arg1: ProxyArg<bool>,
-        Arg2: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
-        Arg3: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn set_heartbeat_interval<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        supply: Arg0,
-        borrow: Arg1,
-        money_markets: Arg2,
-        accounts: Arg3,
+        new_interval: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("distributeRewards")
-            .argument(&supply)
-            .argument(&borrow)
-            .argument(&money_markets)
-            .argument(&accounts)
+            .raw_call("setHeartbeatInterval")
+            .argument(&new_interval)
             .original_result()
     }
 
-    /// Claims caller or specified accounts rewards from supply and/or borrow markets, at specific money markets.
+    /// Restricts or opens bulk enumeration views, i.e. views that page over many accounts such as market members, accounts
+    /// joined within a time range or borrow snapshots at money markets.
     ///
     /// # Arguments:
     ///
-    /// - `boost` - Whether or not to boost rewards whenever possible.
-    /// - `supply` - Whether or not to claim supply rewards.
-    /// - `borrow` - Whether or not to claim borrow rewards.
-    /// - `money_markets` - The money market addresses to claim rewards in. If empty, all whitelisted markets will be used.
-    /// - `accounts` - The addresses to claim rewards for. If empty, the caller will be used.
-    /// - `opt_min_boosted_rewards_out`: An optional minimum amount of boosted rewards out.
+    /// - `restricted` - Whether bulk enumeration views are restricted to registered readers.
     ///
-    pub fn claim_rewards<
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Single account views always remain public.
+    ///
+    pub fn set_enumeration_restricted<
         Arg0: ProxyArg<bool>,
+    >(
+        self,
+        restricted: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setEnumerationRestricted")
+            .argument(&restricted)
+            .original_result()
+    }
+
+    /// Registers a smart contract as a reader of bulk enumeration views.
+    ///
+    /// # Arguments:
+    ///
+    /// - `reader` - The address of the reader smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn register_reader<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        reader: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerReader")
+            .argument(&reader)
+            .original_result()
+    }
+
+    /// Unregisters a reader of bulk enumeration views.
+    ///
+    /// # Arguments:
+    ///
+    /// - `reader` - The address of the reader smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn unregister_reader<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        reader: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unregisterReader")
+            .argument(&reader)
+            .original_result()
+    }
+
+    /// Changes the minting status for a specific money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `opt_reason` - An optional pause reason, which can only be an oracle incident. Defaults to an admin or a guardian
+    ///   pause, depending on the caller. Ignored when unpausing.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    ///
+    pub fn pause_mint<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<bool>,
-        Arg2: ProxyArg<bool>,
-        Arg3: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
-        Arg4: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
-        Arg5: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<PauseReason>>,
     >(
         self,
-        boost: Arg0,
-        supply: Arg1,
-        borrow: Arg2,
-        money_markets: Arg3,
-        accounts: Arg4,
-        opt_min_boosted_rewards_out: Arg5,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, EgldOrEsdtTokenPayment<Env::Api>>>> {
+        money_market: Arg0,
+        pause: Arg1,
+        opt_reason: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("claimRewards")
-            .argument(&boost)
-            .argument(&supply)
-            .argument(&borrow)
-            .argument(&money_markets)
-            .argument(&accounts)
-            .argument(&opt_min_boosted_rewards_out)
+            .raw_call("pauseMint")
+            .argument(&money_market)
+            .argument(&pause)
+            .argument(&opt_reason)
             .original_result()
     }
 
-    /// Sends all rewards from all rewards batches for the given money markets to the given account.
+    /// Changes the borrowing status for a specific money market.
     ///
     /// # Arguments:
     ///
-    /// - `boost`: Whether to boost the rewards or not.
-    /// - `supply` - Whether or not to claim supply rewards.
-    /// - `borrow` - Whether or not to claim borrow rewards.
-    /// - `tokens`: An array of rewards tokens.
-    /// - `money_markets`: An array of money market addresses in which the rewards distribution will be done.
-    /// - `accounts`: An array of account addresses.
-    /// - `opt_min_boosted_rewards_out`: An optional minimum amount of boosted rewards out.
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `opt_reason` - An optional pause reason, which can only be an oracle incident. Defaults to an admin or a guardian
+    ///   pause, depending on the caller. Ignored when unpausing.
     ///
     /// # Notes:
     ///
-    /// - If `boost` is enabled, then the rewards will be boosted using the rewards booster.
-    /// - If no money markets are specified, then all whitelisted money markets will be used.
-    /// - If a provided money market does not have any batch for the rewards tokens, then it will be ignored.
-    /// - If no accounts are provided, then only the caller will claim his rewards.
+    /// - Can only be called by the admin or the Guardian.
     ///
-    pub fn claim_rewards_tokens<
-        Arg0: ProxyArg<bool>,
+    pub fn pause_borrow<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<bool>,
-        Arg2: ProxyArg<bool>,
-        Arg3: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
-        Arg4: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
-        Arg5: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
-        Arg6: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<PauseReason>>,
     >(
         self,
-        boost: Arg0,
-        supply: Arg1,
-        borrow: Arg2,
-        tokens: Arg3,
-        money_markets: Arg4,
-        accounts: Arg5,
-        opt_min_boosted_rewards_out: Arg6,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, EgldOrEsdtTokenPayment<Env::Api>>>> {
+        money_market: Arg0,
+        pause: Arg1,
+        opt_reason: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("claimRewardsTokens")
-            .argument(&boost)
-            .argument(&supply)
-            .argument(&borrow)
-            .argument(&tokens)
-            .argument(&money_markets)
-            .argument(&accounts)
-            .argument(&opt_min_boosted_rewards_out)
+            .raw_call("pauseBorrow")
+            .argument(&money_market)
+            .argument(&pause)
+            .argument(&opt_reason)
+            .original_result()
+    }
+
+    /// Changes the seizing status for a specific money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `opt_reason` - An optional pause reason, which can only be an oracle incident. Defaults to an admin or a guardian
+    ///   pause, depending on the caller. Ignored when unpausing.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    ///
+    pub fn pause_seize<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+        Arg2: ProxyArg<OptionalValue<PauseReason>>,
+    >(
+        self,
+        money_market: Arg0,
+        pause: Arg1,
+        opt_reason: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseSeize")
+            .argument(&money_market)
+            .argument(&pause)
+            .argument(&opt_reason)
+            .original_result()
+    }
+
+    /// Changes the seizing status (required for liquidations) for all money markets.
+    ///
+    /// # Arguments:
+    ///
+    /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `opt_reason` - An optional pause reason, which can only be an oracle incident. Defaults to an admin or a guardian
+    ///   pause, depending on the caller. Ignored when unpausing.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    ///
+    pub fn pause_global_seize<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<OptionalValue<PauseReason>>,
+    >(
+        self,
+        pause: Arg0,
+        opt_reason: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseGlobalSeize")
+            .argument(&pause)
+            .argument(&opt_reason)
+            .original_result()
+    }
+
+    /// Freezes interest accrual in all money markets for a bounded period of time. During the freeze, money markets keep
+    /// advancing their accrual timestamps but borrowers are not charged any interest.
+    ///
+    /// # Arguments:
+    ///
+    /// - `duration` - The freeze duration in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    /// - The duration cannot exceed `MAX_INTEREST_FREEZE_DURATION`.
+    /// - Interest is accrued in all money markets before the freeze starts.
+    /// - Cannot be called while another freeze is in place.
+    ///
+    pub fn freeze_interest_accrual<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        duration: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("freezeInterestAccrual")
+            .argument(&duration)
+            .original_result()
+    }
+
+    /// Lifts an ongoing interest accrual freeze before its expiration.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    ///
+    pub fn unfreeze_interest_accrual(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unfreezeInterestAccrual")
+            .original_result()
+    }
+
+    /// Pauses rewards distribution at a given money market, i.e. freezes its rewards batches indexes and the claims of the
+    /// rewards distributed at the money market. Meant to be used when a mispriced rewards batch or a booster bug is
+    /// discovered.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    /// - Rewards batches are updated up to this point before pausing. While paused, neither their indexes nor their last
+    ///   update times are advanced.
+    /// - Rewards distributed to accounts at the money market while paused are held for them instead of being claimable.
+    /// - While paused, rewards batches at the money market can be created or cancelled but not otherwise modified.
+    ///
+    pub fn pause_rewards_distribution<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseRewardsDistribution")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Resumes rewards distribution at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The paused period is not distributed, i.e. its rewards are kept as undistributed rewards.
+    /// - Rewards held for accounts while paused become claimable the next time rewards are distributed to them.
+    ///
+    pub fn resume_rewards_distribution<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resumeRewardsDistribution")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Sets the liquidation priority window, i.e. the amount of seconds after an account has been detected as liquidatable
+    /// during which only bonded liquidators can liquidate it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_window` - The new liquidation priority window in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - A zero window disables the priority window, i.e. liquidations are open to everyone.
+    /// - The window cannot exceed `MAX_LIQUIDATION_PRIORITY_WINDOW`.
+    ///
+    pub fn set_liquidation_priority_window<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        new_window: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLiquidationPriorityWindow")
+            .argument(&new_window)
+            .original_result()
+    }
+
+    /// Sets the minimum EGLD bond required to become a bonded liquidator.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_min_bond` - The new minimum bond in EGLD.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Bonded liquidators whose bond falls below the new minimum keep their status until they are slashed or unbond.
+    ///
+    pub fn set_min_liquidator_bond<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_min_bond: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinLiquidatorBond")
+            .argument(&new_min_bond)
+            .original_result()
+    }
+
+    /// Bonds EGLD in order to become a bonded liquidator, which gives exclusive access to liquidations during the liquidation
+    /// priority window. The caller can perform multiple calls to keep adding to its bond.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with EGLD.
+    /// - The resulting bond must be at least the minimum liquidator bond.
+    /// - Cannot be called while unbonding.
+    ///
+    pub fn bond_liquidator(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("bondLiquidator")
+            .original_result()
+    }
+
+    /// Gives up the bonded liquidator status and starts the unbonding period, after which the bond can be withdrawn.
+    ///
+    /// # Notes:
+    ///
+    /// - The bond remains slashable during the unbonding period.
+    ///
+    pub fn unbond_liquidator(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unbondLiquidator")
+            .original_result()
+    }
+
+    /// Withdraws the remaining bond of the caller once its unbonding period has elapsed.
+    ///
+    pub fn withdraw_liquidator_bond(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawLiquidatorBond")
+            .original_result()
+    }
+
+    /// Slashes the bond of a liquidator that has been griefing, e.g. by holding liquidations back during the liquidation
+    /// priority window. The slashed EGLD is sent to the admin.
+    ///
+    /// # Arguments:
+    ///
+    /// - `liquidator` - The address of the liquidator.
+    /// - `amount` - The amount of EGLD to slash.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Liquidators whose bond falls below the minimum liquidator bond lose their bonded status.
+    ///
+    pub fn slash_liquidator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        liquidator: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("slashLiquidator")
+            .argument(&liquidator)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Flags a given account as liquidatable, which starts its liquidation priority window. If the account is no longer
+    /// liquidatable, a previous detection is cleared instead. Returns whether the account is liquidatable.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone, e.g. by an unbonded liquidator in order to start the priority window of an account that
+    ///   has not been detected yet.
+    /// - Detections are also recorded by liquidations and cleared whenever the account is found solvent by a borrow or a
+    ///   redeem, such that stale detections do not outlive the account shortfall.
+    ///
+    pub fn flag_liquidatable_account<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("flagLiquidatableAccount")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Checks whether a given liquidator can liquidate a given borrower at a given borrow market, considering the liquidation
+    /// priority window. Bonded liquidators can always liquidate, while everyone else must wait for the priority window to
+    /// elapse since the borrower has been flagged as liquidatable.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `borrower` - The address of the borrower.
+    /// - `liquidator` - The address of the liquidator.
+    ///
+    /// # Notes:
+    ///
+    /// - Borrows at deprecated markets are not subject to the priority window.
+    /// - Liquidations are open to everyone while there are no bonded liquidators.
+    /// - Accounts that have not been detected yet can be flagged using `flagLiquidatableAccount`.
+    ///
+    pub fn is_liquidation_open<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrow_market: Arg0,
+        borrower: Arg1,
+        liquidator: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isLiquidationOpen")
+            .argument(&borrow_market)
+            .argument(&borrower)
+            .argument(&liquidator)
+            .original_result()
+    }
+
+    /// Payable endpoint used to enter to a one or many markets, i.e. provide collateral for sender liquidity calculations.
+    /// The sender can perform multiple calls to keep adding more collateral.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_account` - If given, the collateral will be deposited on the name of this account. Can only be performed by a
+    ///   whitelisted money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with one or many valid ESDT Hatom tokens and/or native EGLD.
+    /// - Native EGLD is minted at the EGLD money market (or, if there is none, wrapped and minted at the WEGLD money market)
+    ///   and the resulting Hatom tokens are deposited as collateral.
+    ///
+    pub fn enter_markets<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_account: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("enterMarkets")
+            .argument(&opt_account)
+            .original_result()
+    }
+
+    /// Opts in or out the caller from automatically entering markets with freshly minted Hatom tokens, i.e. whenever the
+    /// caller mints at a money market, the minted tokens are deposited as collateral in the same transaction instead of
+    /// being sent to the caller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `enabled` - Whether or not minted tokens should automatically enter the market.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts are opted out by default.
+    /// - Minting fails if the account cannot enter the market, e.g. if it has reached the maximum number of markets.
+    ///
+    pub fn set_auto_enter_markets<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAutoEnterMarkets")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Opts in or out the caller from receiving native EGLD instead of WEGLD, i.e. whenever the caller receives the
+    /// underlying of a WEGLD money market redeemed through the controller or claims WEGLD rewards, the WEGLD is unwrapped in
+    /// the same transaction.
+    ///
+    /// # Arguments:
+    ///
+    /// - `enabled` - Whether or not WEGLD should be unwrapped into native EGLD.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts are opted out by default.
+    ///
+    pub fn set_native_egld<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setNativeEgld")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Exits a given amount of tokens from a given money market, i.e. removes the caller's deposited collateral for
+    /// liquidity computations. If the amount of tokens is not specified, all the position is removed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `opt_tokens` - If given, the amount of collateral tokens to remove.
+    ///
+    /// # Notes:
+    ///
+    /// - The provided address must be a whitelisted money market.
+    /// - The caller must have collateral in the corresponding money market.
+    /// - The amount of tokens to withdraw should not exceed the current deposited amount.
+    /// - The caller must be providing the necessary collateral for any outstanding borrows.
+    /// - At position markets, meta-ESDT positions are returned in the order they have been deposited.
+    ///
+    pub fn exit_market<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        money_market: Arg0,
+        opt_tokens: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("exitMarket")
+            .argument(&money_market)
+            .argument(&opt_tokens)
+            .original_result()
+    }
+
+    /// Exits a given amount of tokens from a given money market, i.e. removes the caller's deposited collateral for liquidity
+    /// computations and redeems the corresponding amount of tokens.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `opt_tokens` - If given, the amount of collateral tokens to remove.
+    /// - `opt_underlying_amount` - An optional amount of underlying asset to receive back in exchange for the paid Hatom's
+    ///   tokens.
+    /// - `opt_to` - An optional recipient of the underlying asset. Defaults to the caller.
+    /// - `opt_min_underlying_out` - An optional minimum amount of underlying to be received, protecting the redeemer against
+    ///   unexpected exchange rate movements.
+    ///
+    /// # Notes:
+    ///
+    /// - The provided address must be a whitelisted money market.
+    /// - The caller must have collateral in the corresponding money market.
+    /// - The amount of tokens to withdraw should not exceed the current deposited amount.
+    /// - Remainder Hatom's tokens, if any, are always sent back to the caller.
+    /// - Not supported by position markets.
+    /// - WEGLD is unwrapped into native EGLD if the recipient has opted in via `setNativeEgld`.
+    ///
+    pub fn exit_market_and_redeem<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<Option<BigUint<Env::Api>>>,
+        Arg2: ProxyArg<Option<BigUint<Env::Api>>>,
+        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+        Arg4: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        money_market: Arg0,
+        opt_tokens: Arg1,
+        opt_underlying_amount: Arg2,
+        opt_to: Arg3,
+        opt_min_underlying_out: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<EgldOrEsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("exitMarketAndRedeem")
+            .argument(&money_market)
+            .argument(&opt_tokens)
+            .argument(&opt_underlying_amount)
+            .argument(&opt_to)
+            .argument(&opt_min_underlying_out)
+            .original_result()
+    }
+
+    /// Repays an outstanding borrow using a token different from the borrow market underlying. The paid tokens are swapped
+    /// into the underlying at xExchange and the resulting amount is used to repay the borrow.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the borrow money market smart contract.
+    /// - `swap_path` - The swap path used to convert the paid tokens into the underlying. If the underlying is EGLD, the swap
+    ///   path needs to use the WEGLD token identifier.
+    /// - `min_underlying_out` - The minimum amount of underlying to be obtained from the swap.
+    /// - `opt_borrower` - An optional address to repay on behalf of this account. Defaults to the caller.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with a single ESDT token supported for repayments with automatic swap.
+    /// - The provided address must be a whitelisted money market, other than a position market.
+    /// - The swap cannot lose more than the maximum slippage as compared to oracle values.
+    /// - Paying WEGLD to an EGLD market requires no swap, i.e. the swap path can be empty.
+    /// - The swapped underlying can be higher than the outstanding borrow. In such case, the remainder is returned to the
+    ///   caller in underlying.
+    ///
+    pub fn repay_with_any_token<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedVec<Env::Api, SwapStep<Env::Api>>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        money_market: Arg0,
+        swap_path: Arg1,
+        min_underlying_out: Arg2,
+        opt_borrower: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("repayWithAnyToken")
+            .argument(&money_market)
+            .argument(&swap_path)
+            .argument(&min_underlying_out)
+            .argument(&opt_borrower)
+            .original_result()
+    }
+
+    /// Performs multiple liquidations in a single call. Each liquidation repays a borrow at a given borrow market and seizes
+    /// collateral at a given collateral market, with the controller acting as the liquidator on behalf of the caller.
+    /// Liquidations that cannot be performed are skipped and reported instead of reverting the whole call.
+    ///
+    /// # Arguments:
+    ///
+    /// - `liquidations` - Tuples of borrower, borrow market, collateral market and underlying repayment amount.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlyings of the borrow markets, either EGLD or one or many ESDT tokens. Repayments are
+    ///   taken from the payment of the corresponding underlying, in order.
+    /// - Repayments are clamped to the maximum allowed by the close factor.
+    /// - Skipped liquidations are those that fail the controller checks beforehand, which mirror the ones performed by the
+    ///   money markets, e.g. a solvent borrower or a missing payment.
+    /// - Any other failure, such as a missing oracle price or a failure within a money market, reverts the whole call, i.e.
+    ///   beyond the mirrored checks the batch is all-or-nothing.
+    /// - Liquidations are subject to the liquidation priority window for the caller.
+    /// - Collateral at position markets cannot be seized in batches.
+    /// - Market changes are notified at once to a booster observer that supports batched notifications.
+    /// - The seized Hatom tokens and any remaining payment are sent to the caller.
+    ///
+    pub fn liquidate_accounts<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue4<ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, ManagedAddress<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        liquidations: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValueEncoded<Env::Api, LiquidationOutcome<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("liquidateAccounts")
+            .argument(&liquidations)
+            .original_result()
+    }
+
+    /// Claims the caller rewards for the given rewards tokens, swaps them into the underlying of a given money market (if
+    /// needed), mints and deposits the resulting Hatom tokens as collateral, all in a single call.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract in which rewards are collateralized.
+    /// - `min_tokens_out` - The minimum amount of Hatom tokens to be deposited as collateral.
+    /// - `swaps` - Pairs of rewards token identifiers and swap paths used to convert them into the underlying. If empty, only
+    ///   rewards paid in the underlying are collateralized.
+    ///
+    /// # Notes:
+    ///
+    /// - The provided address must be a whitelisted money market, other than a position market.
+    /// - A rewards token that is the underlying, or its wrapped or unwrapped EGLD version, requires no swap, i.e. its swap
+    ///   path can be empty. Otherwise, swap paths need to use the WEGLD token identifier instead of EGLD.
+    /// - Each swap cannot lose more than the maximum slippage as compared to oracle values.
+    /// - Meta-ESDT rewards tokens cannot be swapped.
+    /// - Rewards held while rewards distribution is paused at a money market are not claimed.
+    ///
+    pub fn claim_rewards_and_collateralize<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, ManagedVec<Env::Api, SwapStep<Env::Api>>>>>,
+    >(
+        self,
+        money_market: Arg0,
+        min_tokens_out: Arg1,
+        swaps: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRewardsAndCollateralize")
+            .argument(&money_market)
+            .argument(&min_tokens_out)
+            .argument(&swaps)
+            .original_result()
+    }
+
+    /// Registers an account as a member of the calling money market, such that a borrow imported during a code migration is
+    /// accounted for in risk checks and liquidations.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The address of the borrower.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by a whitelisted money market.
+    /// - Accounts that are already members of the money market are left untouched.
+    /// - The maximum number of markets per account is not enforced, such that an import can never be reverted by an
+    ///   account that already holds positions in many markets.
+    ///
+    pub fn register_borrower<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrower: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerBorrower")
+            .argument(&borrower)
+            .original_result()
+    }
+
+    /// Removes an account from the given money market when the account has no collateral and no outstanding borrow in the
+    /// given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `opt_account` - If given, the address of the account to remove. If not given, the caller's address is used.
+    ///
+    pub fn remove_account_market<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        money_market: Arg0,
+        opt_account: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeAccountMarket")
+            .argument(&money_market)
+            .argument(&opt_account)
+            .original_result()
+    }
+
+    /// Prunes an orphaned account from the members of a given money market, i.e. an account that has no collateral and no
+    /// outstanding borrow in the given money market but is still tracked as a member.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The address of the account to prune.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - The provided address must be a whitelisted money market.
+    /// - Fails if the account is not a member or if it has collateral or an outstanding borrow in the given money market.
+    ///
+    pub fn prune_market_member<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+        account: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pruneMarketMember")
+            .argument(&money_market)
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Checks whether minting is allowed at a specified money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - It does not depend on the account that intends to mint.
+    /// - Fails with panic and a clear error message or returns true.
+    ///
+    pub fn mint_allowed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("mintAllowed")
+            .argument(&money_market)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Checks whether an account (redeemer) should be allowed to withdraw a given amount of Hatom tokens from a given
+    /// market, i.e. withdraw collateral.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `redeemer` - The account that intends to withdraw the tokens.
+    /// - `tokens` - The amount of Hatom tokens to withdraw.
+    ///
+    /// # Notes:
+    ///
+    /// - This function is not used when redeeming at a money market, it is only used when redeeming (exiting the market) at
+    ///   the controller.
+    /// - A simulation of the resulting risk profile is performed.
+    /// - Fails with panic and a clear error message, returns false if redeemer would become risky or true if she remains
+    ///   solvent.
+    ///
+    pub fn redeem_allowed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+        redeemer: Arg1,
+        tokens: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("redeemAllowed")
+            .argument(&money_market)
+            .argument(&redeemer)
+            .argument(&tokens)
+            .original_result()
+    }
+
+    /// Checks whether an account (borrower) should be allowed to take a borrow of a given amount on a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `borrower` - The account that intends to take a borrow.
+    /// - `amount` - The amount of underlying to borrow.
+    ///
+    /// # Notes:
+    ///
+    /// - Fails with panic and a clear error message, returns false if borrower would become risky or true if she remains
+    ///   solvent.
+    ///
+    pub fn borrow_allowed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+        borrower: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("borrowAllowed")
+            .argument(&money_market)
+            .argument(&borrower)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Checks whether repaying a borrow is allowed at a specified money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `borrower` - The address of the borrower.
+    ///
+    /// # Notes:
+    ///
+    /// - It does not depend on the account that intends to repay the borrow.
+    ///
+    pub fn repay_borrow_allowed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+        borrower: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("repayBorrowAllowed")
+            .argument(&money_market)
+            .argument(&borrower)
+            .original_result()
+    }
+
+    /// Records that the caller money market has just accrued interest, which allows money markets to tell idle markets apart
+    /// from chain wide accrual gaps without iterating over all markets.
+    ///
+    /// # Arguments:
+    ///
+    /// - `abnormal_accrual_gap` - Whether the money market has found an abnormal accrual gap at this accrual.
+    ///
+    /// # Notes:
+    ///
+    /// - Calls from addresses that are not whitelisted money markets are ignored.
+    ///
+    pub fn record_accrual<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        abnormal_accrual_gap: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recordAccrual")
+            .argument(&abnormal_accrual_gap)
+            .original_result()
+    }
+
+    /// Computes the maximum amount of underlying that can be repaid in a single liquidation of a given borrower at a given
+    /// money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `borrower` - The address of the borrower.
+    ///
+    /// # Notes:
+    ///
+    /// - Borrows at deprecated markets can be fully repaid (the close factor does not play any role).
+    /// - Money markets use this amount to clamp liquidation repayments and refund the excess to the liquidator.
+    /// - Interest is accrued at the borrow market first, such that the amount is computed from the current borrow balance
+    ///   (bounded by the market total borrows, as repayments are).
+    ///
+    pub fn get_max_liquidation_repayment<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrow_market: Arg0,
+        borrower: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxLiquidationRepayment")
+            .argument(&borrow_market)
+            .argument(&borrower)
+            .original_result()
+    }
+
+    /// Checks whether a liquidation is allowed or not to happen, repaying a borrow at a given money market and seizing
+    /// collateral at the same or another specified money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
+    /// - `borrower` - The address of the borrower.
+    /// - `amount` - The amount of underlying being repaid by the liquidator.
+    ///
+    /// # Notes:
+    ///
+    /// - Borrows at deprecated markets can be fully repaid (the close factor does not play any role).
+    /// - Fails with panic and a clear error message, returns false if the borrower cannot be liquidated (i.e. the borrower
+    ///   is solvent) or true if the liquidation can be performed (i.e. the borrower is risky and repayment amount does not
+    ///   exceeds its maximum allowed).
+    ///
+    pub fn liquidate_borrow_allowed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        borrow_market: Arg0,
+        collateral_market: Arg1,
+        borrower: Arg2,
+        amount: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("liquidateBorrowAllowed")
+            .argument(&borrow_market)
+            .argument(&collateral_market)
+            .argument(&borrower)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Checks whether a liquidation performed by a given liquidator would be allowed, running the same checks as
+    /// `liquidateBorrowAllowed` and `seizeAllowed` without failing. Returns the outcome of the checks.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
+    /// - `borrower` - The address of the borrower.
+    /// - `liquidator` - The address of the liquidator.
+    /// - `amount` - The amount of underlying being repaid by the liquidator.
+    ///
+    /// # Notes:
+    ///
+    /// - The repayment amount is not clamped to the maximum liquidation repayment, which is up to the caller.
+    /// - As in `liquidateBorrowAllowed`, the first detection of a liquidatable borrower starts the liquidation priority
+    ///   window, which is why this is an endpoint.
+    ///
+    pub fn check_liquidate_borrow<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        borrow_market: Arg0,
+        collateral_market: Arg1,
+        borrower: Arg2,
+        liquidator: Arg3,
+        amount: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DryRunLiquidation> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("checkLiquidateBorrow")
+            .argument(&borrow_market)
+            .argument(&collateral_market)
+            .argument(&borrower)
+            .argument(&liquidator)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Checks whether seizing is or not allowed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `borrower` - The address of the borrower.
+    /// - `liquidator` - The address of the liquidator.
+    ///
+    /// # Notes:
+    ///
+    /// - Money markets should be whitelisted and share the same Controller.
+    /// - Only bonded liquidators can seize during the liquidation priority window. Batch liquidations, in which the
+    ///   Controller acts as the liquidator, check the priority window beforehand.
+    ///
+    pub fn seize_allowed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        collateral_market: Arg0,
+        borrow_market: Arg1,
+        borrower: Arg2,
+        liquidator: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("seizeAllowed")
+            .argument(&collateral_market)
+            .argument(&borrow_market)
+            .argument(&borrower)
+            .argument(&liquidator)
+            .original_result()
+    }
+
+    /// Updates rewards batches states.
+    ///
+    /// # Arguments:
+    ///
+    /// - `supply` - Whether or not to update supply rewards.
+    /// - `borrow` - Whether or not to update borrow rewards..
+    /// - `money_markets` - The money market addresses to update rewards in. If empty, all whitelisted markets will be used.
+    ///
+    pub fn update_rewards_batches_state<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<bool>,
+        Arg2: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        supply: Arg0,
+        borrow: Arg1,
+        money_markets: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateRewardsBatchesState")
+            .argument(&supply)
+            .argument(&borrow)
+            .argument(&money_markets)
+            .original_result()
+    }
+
+    /// Reprices a rewards batch denominated in USD using the current oracle price of its rewards token. The rewards batches
+    /// of the money market are updated up to this point first, such that the elapsed period is distributed at the previous
+    /// speed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `batch_id` - The rewards batch identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - Cannot be called while rewards distribution is paused at the money market.
+    /// - If the rewards token cannot be priced, the rewards batch keeps its current speed.
+    ///
+    pub fn reprice_rewards_batch<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        money_market: Arg0,
+        batch_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("repriceRewardsBatch")
+            .argument(&money_market)
+            .argument(&batch_id)
+            .original_result()
+    }
+
+    /// Takes a snapshot of the supply and borrow rewards batches indexes at the given money markets, which can be used to
+    /// anchor off-chain rewards computations (such as airdrops) to on-chain verifiable values.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_markets` - The money market addresses to snapshot. If empty, all whitelisted markets will be used.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the rewards manager.
+    /// - Rewards batches states are updated before their indexes are copied, such that they are always in the current wad *
+    ///   wad * wad precision, which is stored together with the snapshot.
+    /// - Snapshot IDs start at 1.
+    ///
+    pub fn take_rewards_snapshot<
+        Arg0: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        money_markets: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("takeRewardsSnapshot")
+            .argument(&money_markets)
+            .original_result()
+    }
+
+    /// Returns the precision of the rewards batches indexes at a given rewards snapshot, i.e. the unit of its indexes.
+    ///
+    /// # Arguments:
+    ///
+    /// - `snapshot_id` - The rewards snapshot ID.
+    ///
+    /// # Notes:
+    ///
+    /// - Snapshots taken before rewards indexes were migrated keep their legacy wad * wad precision.
+    ///
+    pub fn get_rewards_snapshot_precision<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        snapshot_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsSnapshotPrecision")
+            .argument(&snapshot_id)
+            .original_result()
+    }
+
+    /// Migrates the rewards batches indexes at the given money markets from the legacy wad * wad precision to the current
+    /// wad * wad * wad precision. Accounts indexes are migrated lazily, the next time rewards are distributed to them.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_markets` - The money market addresses to migrate. If empty, all whitelisted markets will be used.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the rewards manager.
+    /// - Money markets are also migrated automatically the first time their rewards batches are updated.
+    /// - Rewards snapshots taken before the migration keep their legacy precision, as reported by `getRewardsSnapshotPrecision`.
+    ///
+    pub fn migrate_rewards_indexes<
+        Arg0: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        money_markets: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrateRewardsIndexes")
+            .argument(&money_markets)
+            .original_result()
+    }
+
+    /// Distributes caller or specified accounts rewards from supply and/or borrow markets, at specific money markets.
+    ///
+    /// # Arguments:
+    ///
+    /// - `supply` - Whether or not to distribute supply rewards.
+    /// - `borrow` - Whether or not to distribute borrow rewards.
+    /// - `money_markets` - The money market addresses to distribute rewards in. If empty, all whitelisted markets will be
+    ///   used.
+    /// - `accounts` - The addresses to distribute rewards for. If empty, the caller will be used.
+    ///
+    pub fn distribute_rewards<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<bool>,
+        Arg2: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg3: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        supply: Arg0,
+        borrow: Arg1,
+        money_markets: Arg2,
+        accounts: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("distributeRewards")
+            .argument(&supply)
+            .argument(&borrow)
+            .argument(&money_markets)
+            .argument(&accounts)
+            .original_result()
+    }
+
+    /// Grants or revokes the caller consent for rewards claims initiated by third parties (i.e. the admin or the rewards
+    /// manager) on its behalf.
+    ///
+    /// # Arguments:
+    ///
+    /// - `consent` - Whether or not third parties are allowed to claim rewards on behalf of the caller.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts consent by default.
+    ///
+    pub fn set_rewards_auto_claim_consent<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        consent: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRewardsAutoClaimConsent")
+            .argument(&consent)
+            .original_result()
+    }
+
+    /// Checks whether a given account consents to rewards claims initiated by third parties on its behalf.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    pub fn has_rewards_auto_claim_consent<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasRewardsAutoClaimConsent")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Estimates the complexity of claiming the rewards of a given account, such that wallets can set sensible gas limits.
+    /// Returns the number of markets the account belongs to, the number of rewards batches at those markets and the number
+    /// of rewards tokens with non-zero accrued rewards.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    /// # Notes:
+    ///
+    /// - Claims should be restricted to the account markets. Otherwise, all whitelisted markets are traversed.
+    /// - Rewards not yet distributed to the account are not considered in the number of rewards tokens.
+    ///
+    pub fn estimate_claim_complexity<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<usize, usize, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("estimateClaimComplexity")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Claims caller or specified accounts rewards from supply and/or borrow markets, at specific money markets.
+    ///
+    /// # Arguments:
+    ///
+    /// - `boost` - Whether or not to boost rewards whenever possible.
+    /// - `supply` - Whether or not to claim supply rewards.
+    /// - `borrow` - Whether or not to claim borrow rewards.
+    /// - `money_markets` - The money market addresses to claim rewards in. If empty, all whitelisted markets will be used.
+    /// - `accounts` - The addresses to claim rewards for. If empty, the caller will be used.
+    /// - `opt_min_boosted_rewards_out`: An optional minimum amount of boosted rewards out.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts that have not granted their consent for rewards claims initiated by third parties are skipped.
+    /// - WEGLD rewards are unwrapped into native EGLD for accounts that have opted in via `setNativeEgld`.
+    ///
+    pub fn claim_rewards<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<bool>,
+        Arg2: ProxyArg<bool>,
+        Arg3: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg4: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg5: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        boost: Arg0,
+        supply: Arg1,
+        borrow: Arg2,
+        money_markets: Arg3,
+        accounts: Arg4,
+        opt_min_boosted_rewards_out: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, EgldOrEsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRewards")
+            .argument(&boost)
+            .argument(&supply)
+            .argument(&borrow)
+            .argument(&money_markets)
+            .argument(&accounts)
+            .argument(&opt_min_boosted_rewards_out)
+            .original_result()
+    }
+
+    /// Sends all rewards from all rewards batches for the given money markets to the given account.
+    ///
+    /// # Arguments:
+    ///
+    /// - `boost`: Whether to boost the rewards or not.
+    /// - `supply` - Whether or not to claim supply rewards.
+    /// - `borrow` - Whether or not to claim borrow rewards.
+    /// - `tokens`: An array of rewards tokens.
+    /// - `money_markets`: An array of money market addresses in which the rewards distribution will be done.
+    /// - `accounts`: An array of account addresses.
+    /// - `opt_min_boosted_rewards_out`: An optional minimum amount of boosted rewards out.
+    ///
+    /// # Notes:
+    ///
+    /// - If `boost` is enabled, then the rewards will be boosted using the rewards booster.
+    /// - If no money markets are specified, then all whitelisted money markets will be used.
+    /// - If a provided money market does not have any batch for the rewards tokens, then it will be ignored.
+    /// - If no accounts are provided, then only the caller will claim his rewards.
+    /// - Accounts that have not granted their consent for rewards claims initiated by third parties are skipped.
+    /// - WEGLD rewards are unwrapped into native EGLD for accounts that have opted in via `setNativeEgld`.
+    ///
+    pub fn claim_rewards_tokens<
+        Arg0: ProxyArg<bool>,
+        Arg1: ProxyArg<bool>,
+        Arg2: ProxyArg<bool>,
+        Arg3: ProxyArg<ManagedVec<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>>,
+        Arg4: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg5: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg6: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        boost: Arg0,
+        supply: Arg1,
+        borrow: Arg2,
+        tokens: Arg3,
+        money_markets: Arg4,
+        accounts: Arg5,
+        opt_min_boosted_rewards_out: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, EgldOrEsdtTokenPayment<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRewardsTokens")
+            .argument(&boost)
+            .argument(&supply)
+            .argument(&borrow)
+            .argument(&tokens)
+            .argument(&money_markets)
+            .argument(&accounts)
+            .argument(&opt_min_boosted_rewards_out)
+            .original_result()
+    }
+
+    /// Returns the metadata of all registered rewards tokens.
+    ///
+    pub fn get_rewards_tokens_metadata(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, RewardsTokenMetadata<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsTokensMetadata")
+            .original_result()
+    }
+
+    /// Dry runs the swap path of a given rewards booster against the current xExchange pools reserves, such that dead or
+    /// migrated pools can be detected before boosted claims start failing.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The boosted rewards token identifier.
+    /// - `opt_amount_in` - An optional amount of rewards tokens to swap. Defaults to the amount left at the booster.
+    ///
+    /// # Notes:
+    ///
+    /// - A step is broken if its pair is not active, does not trade its input and output tokens, has no reserves or does
+    ///   not connect with the previous step. In such case, the index of the first broken step is reported.
+    /// - The price impact is the relative difference, in wad, between the output at spot prices and the expected output,
+    ///   including the pools fees.
+    ///
+    pub fn verify_booster_path<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        token_id: Arg0,
+        opt_amount_in: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BoosterPathReport<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("verifyBoosterPath")
+            .argument(&token_id)
+            .argument(&opt_amount_in)
+            .original_result()
+    }
+
+    /// Exposes a single time step of a rewards batch index for a synthetic scenario, such that the index math can be
+    /// verified against independent implementations. See `compute_rewards_index_step` for more details.
+    ///
+    /// # Notes:
+    ///
+    /// - Only exported by the testing build of the contract.
+    ///
+    pub fn test_compute_rewards_index_step<
+        Arg0: ProxyArg<MarketType>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        market_type: Arg0,
+        speed: Arg1,
+        dt: Arg2,
+        total: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue3<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("testComputeRewardsIndexStep")
+            .argument(&market_type)
+            .argument(&speed)
+            .argument(&dt)
+            .argument(&total)
+            .original_result()
+    }
+
+    /// Exposes the rewards accrued by an account for a synthetic scenario, such that the index math can be verified against
+    /// independent implementations. See `compute_account_accrued_rewards` for more details.
+    ///
+    /// # Notes:
+    ///
+    /// - Only exported by the testing build of the contract.
+    ///
+    pub fn test_compute_account_accrued_rewards<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+        account_index: Arg1,
+        rewards_index: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("testComputeAccountAccruedRewards")
+            .argument(&amount)
+            .argument(&account_index)
+            .argument(&rewards_index)
+            .original_result()
+    }
+
+    /// Checks whether an account is risky or not by computing its current risk profile.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    ///
+    pub fn is_risky<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRisky")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Gets the status of a given account together with its health factor, i.e. its collateral value weighted by collateral
+    /// factors over its borrowed value, in wad. This is a minimal and stable interface intended for external protocols that
+    /// integrate Hatom positions.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    ///
+    /// # Notes:
+    ///
+    /// - The health factor is `None` if the account has no borrows, in which case the account is `Healthy`.
+    /// - The account is `Liquidatable` if and only if its health factor is below 1 (in wad), which matches the liquidation
+    ///   policy of the Controller.
+    /// - The account is `Warning` if its health factor is below `WARNING_HEALTH_FACTOR`.
+    ///
+    pub fn get_account_status<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<AccountStatus, Option<BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountStatus")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Computes the leverage of a given account in wad, defined as its total supplied value over its net equity, i.e. its
+    /// total supplied value minus its total borrowed value. Supplied value is not weighted by collateral factors.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `None` if the account net equity is zero or negative.
+    /// - An account without borrows has a leverage of 1 (in wad).
+    ///
+    pub fn get_account_leverage<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountLeverage")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Gets the health breakdown of a given account, i.e. for each of its markets the collateral value, the borrow value and
+    /// the effective collateral factor, together with its aggregated liquidity or shortfall. Values are computed exactly as
+    /// in the Controller risk checks.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    ///
+    /// # Notes:
+    ///
+    /// - Collateral values are not weighted by collateral factors, whereas the total collateral is.
+    /// - The effective collateral factor is the USH borrower collateral factor if the account borrows USH.
+    /// - Prices are retrieved from the price oracle and account snapshots from the money markets, which is why this is an
+    ///   endpoint.
+    ///
+    pub fn get_account_health<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AccountHealth<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountHealth")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Performs a risk profile simulation for a given account, considering its current opened positions and simulating
+    /// either redeeming or borrowing (or both) in a given money market. The money market for the simulation must be already
+    /// included as an account market. Otherwise, the simulation will not be performed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    /// - `this_money_market` - The money market address used for the borrow or redeem simulation (or both).
+    /// - `redeem_tokens` - The amount of Hatom tokens to be redeemed for underlying at `this_money_market`.
+    /// - `borrow_amount` - The amount of underlying to be borrowed at `this_money_market`.
+    /// - `lazy` - If true, the simulation will return a solvent risk profile with a dummy liquidity if the account is not a
+    ///   borrower. If false, the simulation will be fully performed, even if it is not a borrower (i.e. Solvent by
+    ///   definition).
+    ///
+    pub fn simulate_risk_profile<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<bool>,
+    >(
+        self,
+        account: Arg0,
+        this_money_market: Arg1,
+        redeem_tokens: Arg2,
+        borrow_amount: Arg3,
+        lazy: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RiskProfile<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("simulateRiskProfile")
+            .argument(&account)
+            .argument(&this_money_market)
+            .argument(&redeem_tokens)
+            .argument(&borrow_amount)
+            .argument(&lazy)
+            .original_result()
+    }
+
+    /// Simulates a price shock on the underlying of a given money market and computes which of its members would be
+    /// liquidatable under the shocked price, together with their aggregated shortfall. All other prices remain constant.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The money market whose underlying price is shocked.
+    /// - `price_delta_bps` - The price change in bps, e.g. -2000 for a 20% price drop.
+    /// - `from` - The zero based position of the first market member in the page.
+    /// - `size` - The maximum number of market members in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Only members of the given money market are checked, i.e. accounts that entered it as collateral or borrowers.
+    /// - Accounts that are already liquidatable are also reported.
+    /// - Prices are retrieved from the price oracle and account snapshots from the money markets, which is why this is an
+    ///   endpoint.
+    /// - Restricted to registered readers if bulk enumeration views are restricted.
+    ///
+    pub fn simulate_price_shock<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<i64>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<usize>,
+    >(
+        self,
+        money_market: Arg0,
+        price_delta_bps: Arg1,
+        from: Arg2,
+        size: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PriceShockReport<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("simulatePriceShock")
+            .argument(&money_market)
+            .argument(&price_delta_bps)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// A utility function to highlight that this smart contract is a Controller.
+    ///
+    pub fn is_controller(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isController")
+            .original_result()
+    }
+
+    /// Checks whether the specified money market address has already been whitelisted.
+    ///
+    /// # Arguments:
+    ///
+    /// - `sc_address` - The address of the money market to check.
+    ///
+    pub fn is_whitelisted_money_market<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isWhitelistedMoneyMarket")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Checks whether the specified token identifier has already been whitelisted.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier to check.
+    ///
+    pub fn is_whitelisted_token_id<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isWhitelistedTokenId")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Checks whether the specified address is a Rewards Booster observer.
+    ///
+    /// # Arguments:
+    ///
+    /// - `sc_address` - The address of the market observer to check.
+    ///
+    pub fn is_booster_observer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isBoosterObserver")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Checks whether the specified address is a USH Market observer.
+    ///
+    /// # Arguments:
+    ///
+    /// - `sc_address` - The address of the market observer to check.
+    ///
+    pub fn is_ush_market_observer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        sc_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isUshMarketObserver")
+            .argument(&sc_address)
+            .original_result()
+    }
+
+    /// Checks whether the specified money market is deprecated. A money market is deprecated only if it has been explicitly
+    /// flagged as such by governance and, in addition, its collateral factor is zero, its borrows are paused and its reserve
+    /// factor is 100%.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market to check.
+    ///
+    pub fn is_deprecated<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isDeprecated")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Checks whether rewards distribution has been paused at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market.
+    ///
+    pub fn is_rewards_distribution_paused<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRewardsDistributionPaused")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets a whitelist or set of supported money market addresses as an array.
+    ///
+    pub fn get_whitelisted_markets(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWhitelistedMarkets")
+            .original_result()
+    }
+
+    /// Gets the listing metadata of all whitelisted money markets that have it, as pairs of money market addresses and
+    /// metadata.
+    ///
+    pub fn get_markets_metadata(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, MarketMetadata<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketsMetadata")
+            .original_result()
+    }
+
+    /// Verifies the wiring invariants of the controller and returns a structured report, such that post-upgrade smoke tests
+    /// can be run on-chain. The following invariants are checked:
+    ///
+    /// - The price oracle has been set.
+    /// - The maximum number of markets per account has been set.
+    /// - Every whitelisted money market points back to this controller.
+    /// - The identifiers of every whitelisted money market are stored, match the ones reported by the money market and map
+    ///   back to it.
+    ///
+    pub fn verify_deployment(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DeploymentReport<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("verifyDeployment")
+            .original_result()
+    }
+
+    /// Emits a compact snapshot of the protocol, i.e. the liquidity, total borrows and total reserves of every whitelisted
+    /// money market, such that lightweight indexers can track high level statistics without processing every user event.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone, typically a keeper, but at most once per heartbeat interval.
+    /// - Money market values are the ones stored up to their last interaction that accrued interest.
+    ///
+    pub fn emit_heartbeat(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emitHeartbeat")
+            .original_result()
+    }
+
+    /// Gets the set of addresses that belong to a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Restricted to registered readers if bulk enumeration views are restricted.
+    ///
+    pub fn get_market_members<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketMembers")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the the set of money markets addresses in which the account has entered as an array. An account is considered to
+    /// be in the market if it has deposited collateral or took a borrow. Currently, after a borrow is fully repaid, the
+    /// account is still considered to be in the market.
+    ///
+    pub fn get_account_markets<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountMarkets")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Applies a scheduled decrease of the maximum number of money markets that can be entered per account if its timelock
+    /// has elapsed and returns the up to date maximum.
+    ///
+    pub fn update_and_get_max_markets_per_account(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateAndGetMaxMarketsPerAccount")
+            .original_result()
+    }
+
+    /// Gets the maximum collateral factor allowed
+    ///
+    pub fn get_max_collateral_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxCollateralFactor")
+            .original_result()
+    }
+
+    /// Gets the maximum account leverage implied by the maximum collateral factor, in wad.
+    ///
+    pub fn get_max_leverage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxLeverage")
+            .original_result()
+    }
+
+    /// Gets the amount of Hatom tokens deposited as collateral for a given money market and account.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The account we wish to analyze.
+    ///
+    pub fn get_account_collateral_tokens<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+        account: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountTokens")
+            .argument(&money_market)
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Gets the total amount of collateral tokens deposited into the controller for a specific money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market for which to retrieve the total collateral tokens.
+    ///
+    /// # Notes:
+    ///
+    /// - If the market has no collateral, returns 0.
+    ///
+    pub fn get_total_collateral_tokens<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalCollateralTokens")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the meta-ESDT positions deposited as collateral by a given account into a given position market, as pairs of
+    /// position nonces and amounts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the position market smart contract.
+    /// - `account` - The account we wish to analyze.
+    ///
+    pub fn get_account_collateral_positions<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+        account: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountPositions")
+            .argument(&money_market)
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Gets the up to date collateral factor for a specified money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    pub fn update_and_get_collateral_factor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateAndGetCollateralFactor")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the up to date USH borrower collateral factor for a specified money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    pub fn update_and_get_ush_borrower_collateral_factor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateAndGetUshBorrowerCollateralFactor")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Updates the collateral factors if possible and returns their updated values.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    pub fn update_and_get_collateral_factors<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (BigUint<Env::Api>, BigUint<Env::Api>)> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateAndGetCollateralFactors")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the remaining room below the liquidity cap for a given money market, i.e. the liquidity cap minus the current
+    /// liquidity, or `None` if the money market is uncapped.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - The headroom is zero if the liquidity cap has already been reached.
+    /// - Mints succeed only if the new liquidity remains strictly below the cap.
+    ///
+    pub fn get_liquidity_cap_headroom<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidityCapHeadroom")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the remaining room below the borrow cap for a given money market, i.e. the borrow cap minus the current total
+    /// borrows, or `None` if the money market is uncapped.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - The headroom is zero if the borrow cap has already been reached.
+    /// - Borrows succeed only if the new total borrows remain strictly below the cap.
+    ///
+    pub fn get_borrow_cap_headroom<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowCapHeadroom")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the combined total borrows of all the money markets in a given cap group, in EGLD and in wad.
+    ///
+    /// # Arguments:
+    ///
+    /// - `group_id` - The cap group identifier.
+    ///
+    pub fn get_cap_group_borrows<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        group_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCapGroupBorrows")
+            .argument(&group_id)
+            .original_result()
+    }
+
+    /// Gets the current minting status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - By default, mint is active (returns the first enum value).
+    ///
+    pub fn get_mint_status<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintStatus")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the current borrowing status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - By default, borrow is active (returns the first enum value).
+    ///
+    pub fn get_borrow_status<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowStatus")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the current seizing status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - By default, seize is active (returns the first enum value).
+    ///
+    pub fn get_seize_status<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSeizeStatus")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the current global seizing status at a given money market.
+    ///
+    /// # Notes:
+    ///
+    /// - By default, global seize is active (returns the first enum value).
+    ///
+    pub fn get_global_seize_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGlobalSeizeStatus")
+            .original_result()
+    }
+
+    /// Gets the reason behind the current minting status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `NotPaused` if mint is active.
+    /// - Pauses set before reasons were tracked are reported as admin pauses.
+    /// - Pauses at a money market flagged as deprecated are reported as deprecations, unless they are guardian pauses or
+    ///   oracle incidents.
+    ///
+    pub fn get_mint_pause_reason<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PauseReason> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintPauseReason")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the reason behind the current borrowing status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `NotPaused` if borrow is active.
+    /// - Pauses set before reasons were tracked are reported as admin pauses.
+    /// - Pauses at a money market flagged as deprecated are reported as deprecations, unless they are guardian pauses or
+    ///   oracle incidents.
+    ///
+    pub fn get_borrow_pause_reason<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PauseReason> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowPauseReason")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the reason behind the current seizing status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `NotPaused` if seize is active.
+    /// - Pauses set before reasons were tracked are reported as admin pauses.
+    /// - Pauses at a money market flagged as deprecated are reported as deprecations, unless they are guardian pauses or
+    ///   oracle incidents.
+    ///
+    pub fn get_seize_pause_reason<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PauseReason> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSeizePauseReason")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the reason behind the current global seizing status.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `NotPaused` if global seize is active.
+    /// - Pauses set before reasons were tracked are reported as admin pauses.
+    ///
+    pub fn get_global_seize_pause_reason(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PauseReason> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGlobalSeizePauseReason")
+            .original_result()
+    }
+
+    /// Checks whether interest accrual is currently frozen in all money markets.
+    ///
+    pub fn is_interest_accrual_frozen(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isInterestAccrualFrozen")
+            .original_result()
+    }
+
+    /// Gets the accrued rewards for a given account's address and rewards token ID.
+    ///
+    /// # Arguments:
+    ///
+    /// - `supplier` - A reference to a `ManagedAddress` representing the supplier's address.
+    /// - `rewards_token_id` - A reference to an `EgldOrEsdtTokenIdentifier` representing the rewards token's ID.
+    ///
+    pub fn get_account_accrued_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        supplier: Arg0,
+        rewards_token_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountAccruedRewards")
+            .argument(&supplier)
+            .argument(&rewards_token_id)
+            .original_result()
+    }
+
+    /// Gets the accrued rewards for a given account's address for all the rewards tokens ever earned by the account.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The address of the account.
+    ///
+    /// # Notes:
+    ///
+    /// - Rewards tokens are tracked from the first distribution to the account after this view was introduced.
+    ///
+    pub fn get_account_all_accrued_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountAllAccruedRewards")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Gets a page of the accrued rewards for a given account's address, in the same order as `getAccountRewardsTokens`.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The address of the account.
+    /// - `from` - The zero based position of the first rewards token in the page.
+    /// - `size` - The maximum number of rewards tokens in the page.
+    ///
+    pub fn get_account_accrued_rewards_page<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        account: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountAccruedRewardsPage")
+            .argument(&account)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Returns the number of accounts that have entered any market since first interactions are recorded.
+    ///
+    pub fn get_joined_accounts_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getJoinedAccountsCount")
+            .original_result()
+    }
+
+    /// Gets a page of the accounts that entered any market for the first time within a given time range, together with
+    /// their first interaction timestamp and in chronological order.
+    ///
+    /// # Arguments:
+    ///
+    /// - `start` - The start of the time range (inclusive).
+    /// - `end` - The end of the time range (exclusive).
+    /// - `from` - The zero based position of the first account in the page, relative to the first account in the range.
+    /// - `size` - The maximum number of accounts in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts that entered a market before first interactions were recorded are not included.
+    /// - Restricted to registered readers if bulk enumeration views are restricted.
+    ///
+    pub fn get_accounts_joined_between<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<usize>,
+    >(
+        self,
+        start: Arg0,
+        end: Arg1,
+        from: Arg2,
+        size: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountsJoinedBetween")
+            .argument(&start)
+            .argument(&end)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Gets the price of the underlying of a given money market in EGLD and in wad, as reported by the price oracle.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    pub fn get_money_market_underlying_price<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnderlyingPrice")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Gets the aggregated supplied value, borrowed value and reserves across all whitelisted money markets, first expressed
+    /// in EGLD and then in USD, all of them in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - The supplied value of a money market is its liquidity, i.e. cash plus borrows minus reserves.
+    /// - Prices are retrieved from the price oracle, which is why this is an endpoint.
+    ///
+    pub fn get_protocol_totals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue6<BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolTotals")
+            .original_result()
+    }
+
+    /// Gets the rewards emission rate of all active rewards batches in USD per day (in wad), broken down by money market and
+    /// market type (supply or borrow).
+    ///
+    /// # Notes:
+    ///
+    /// - A rewards batch is active if it has already started and has not ended yet.
+    /// - Money markets and market types without active rewards batches are omitted.
+    /// - Prices are retrieved from the price oracle, which is why this is an endpoint.
+    ///
+    pub fn get_global_emission_rate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, MarketType, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGlobalEmissionRate")
+            .original_result()
+    }
+
+    /// Whitelisted money markets can burn their own tokens deposited at the controller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier for the Hatom token.
+    /// - `tokens` - The amount of tokens to be burnt.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by a whitelisted money market.
+    /// - A money market can only burn Hatom tokens corresponding to their own token type.
+    /// - There is no need to update the total collateral tokens for the money market because it is assumed that they have
+    ///   already exited the market and are being redeemed.
+    ///
+    pub fn burn_tokens<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        tokens: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("burnTokens")
+            .argument(&token_id)
+            .argument(&tokens)
+            .original_result()
+    }
+
+    /// Whitelisted money markets can transfer their own tokens to a given account.
+    ///
+    /// # Arguments:
+    ///
+    /// - `to` - The address of the account to which the tokens will be transferred.
+    /// - `token_payment` - The token payment to be transferred.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by a whitelisted money market.
+    /// - A money market can only transfer Hatom tokens corresponding to their own token type.
+    /// - There is no need to update the total collateral tokens for the money market because it is assumed that they have
+    ///   already exited the market and are being transferred.
+    ///
+    pub fn transfer_tokens<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenPayment<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+        token_payment: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transferTokens")
+            .argument(&to)
+            .argument(&token_payment)
+            .original_result()
+    }
+
+    /// Computes the amount of Hatom tokens to be seized given an underlying repayment amount performed by the liquidator.
+    /// Takes into consideration the liquidation incentive, such that the liquidator gets tokens at a discount.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
+    /// - `amount` - The amount of underlying being repaid by the liquidator.
+    ///
+    pub fn tokens_to_seize<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        borrow_market: Arg0,
+        collateral_market: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("tokensToSeize")
+            .argument(&borrow_market)
+            .argument(&collateral_market)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Notifies pending market changes to the booster observer in a single batch, as supported by its batched notifications
+    /// interface (version 3).
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_max_changes` - An optional maximum number of market changes to notify. Defaults to
+    ///   `MAX_MARKET_CHANGES_PER_NOTIFICATION`.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - Market changes are notified in the same order they happened.
+    /// - Only bulk operations queue market changes, which are notified at their end up to the maximum per notification.
+    /// - Returns the number of notified market changes and the number of market changes still pending, such that callers
+    ///   know whether they need to notify again.
+    ///
+    pub fn notify_pending_market_changes<
+        Arg0: ProxyArg<OptionalValue<usize>>,
+    >(
+        self,
+        opt_max_changes: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<usize, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("notifyPendingMarketChanges")
+            .argument(&opt_max_changes)
+            .original_result()
+    }
+
+    /// Returns the number of market changes pending to be notified to the booster observer.
+    ///
+    pub fn get_pending_market_changes_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingMarketChangesCount")
+            .original_result()
+    }
+
+    /// Stores the rewards manager address.
+    pub fn rewards_manager(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsManager")
+            .original_result()
+    }
+
+    /// Stores a whitelisted market address given a token identifier.
+    pub fn money_markets<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMoneyMarketByTokenId")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stores both the underlying identifier and the token identifier associated to a whitelisted money market.
+    pub fn identifiers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (EgldOrEsdtTokenIdentifier<Env::Api>, TokenIdentifier<Env::Api>)> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getIdentifiersByMoneyMarket")
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Stores whether an initial seed deposit is required when supporting a new money market.
+    pub fn seed_required(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isSeedRequired")
             .original_result()
     }
 
-    /// Checks whether an account is risky or not by computing its current risk profile.
-    ///
-    /// # Arguments:
-    ///
-    /// - `account` - The account we wish to analyze.
-    ///
-    pub fn is_risky<
+    /// Stores the amount of Hatom tokens minted as initial seed for a given money market, which are locked forever in the
+    /// controller.
+    pub fn market_seed_tokens<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        account: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isRisky")
-            .argument(&account)
+            .raw_call("getMarketSeedTokens")
+            .argument(&money_market)
             .original_result()
     }
 
-    /// Performs a risk profile simulation for a given account, considering its current opened positions and simulating
-    /// either redeeming or borrowing (or both) in a given money market. The money market for the simulation must be already
-    /// included as an account market. Otherwise, the simulation will not be performed.
-    ///
-    /// # Arguments:
-    ///
-    /// - `account` - The account we wish to analyze.
-    /// - `this_money_market` - The money market address used for the borrow or redeem simulation (or both).
-    /// - `redeem_tokens` - The amount of Hatom tokens to be redeemed for underlying at `this_money_market`.
-    /// - `borrow_amount` - The amount of underlying to be borrowed at `this_money_market`.
-    /// - `lazy` - If true, the simulation will return a solvent risk profile with a dummy liquidity if the account is not a
-    ///   borrower. If false, the simulation will be fully performed, even if it is not a borrower (i.e. Solvent by
-    ///   definition).
-    ///
-    pub fn simulate_risk_profile<
+    /// Stores the timestamp at which a given account entered any market for the first time.
+    pub fn account_first_interaction<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
-        Arg3: ProxyArg<BigUint<Env::Api>>,
-        Arg4: ProxyArg<bool>,
     >(
         self,
         account: Arg0,
-        this_money_market: Arg1,
-        redeem_tokens: Arg2,
-        borrow_amount: Arg3,
-        lazy: Arg4,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RiskProfile<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("simulateRiskProfile")
+            .raw_call("getAccountFirstInteraction")
             .argument(&account)
-            .argument(&this_money_market)
-            .argument(&redeem_tokens)
-            .argument(&borrow_amount)
-            .argument(&lazy)
             .original_result()
     }
 
-    /// A utility function to highlight that this smart contract is a Controller.
-    ///
-    pub fn is_controller(
+    /// Stores the maximum amount of markets an account can enter at any given point in time.
+    pub fn max_markets_per_account(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isController")
+            .raw_call("getMaxMarketsPerAccount")
             .original_result()
     }
 
-    /// Checks whether the specified money market address has already been whitelisted.
-    ///
-    /// # Arguments:
-    ///
-    /// - `sc_address` - The address of the money market to check.
-    ///
-    pub fn is_whitelisted_money_market<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-    >(
+    /// Stores the next maximum amount of markets an account can enter, together with the timestamp from which it applies.
+    pub fn next_max_markets_per_account(
         self,
-        sc_address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (u64, usize)> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isWhitelistedMoneyMarket")
-            .argument(&sc_address)
+            .raw_call("getNextMaxMarketsPerAccount")
             .original_result()
     }
 
-    /// Checks whether the specified token identifier has already been whitelisted.
-    ///
-    /// # Arguments:
-    ///
-    /// - `token_id` - The token identifier to check.
-    ///
-    pub fn is_whitelisted_token_id<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-    >(
+    /// Stores the price oracle smart contract address.
+    pub fn price_oracle(
         self,
-        token_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isWhitelistedTokenId")
-            .argument(&token_id)
+            .raw_call("getPriceOracle")
             .original_result()
     }
 
-    /// Checks whether the specified address is a Rewards Booster observer.
-    ///
-    /// # Arguments:
-    ///
-    /// - `sc_address` - The address of the market observer to check.
-    ///
-    pub fn is_booster_observer<
+    /// Stores the price oracle smart contract address that overrides the global price oracle for a given money market.
+    pub fn market_price_oracle<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        sc_address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isBoosterObserver")
-            .argument(&sc_address)
+            .raw_call("getMarketPriceOracle")
+            .argument(&money_market)
             .original_result()
     }
 
-    /// Checks whether the specified address is a USH Market observer.
-    ///
-    /// # Arguments:
-    ///
-    /// - `sc_address` - The address of the market observer to check.
-    ///
-    pub fn is_ush_market_observer<
+    /// Stores the collateral factor for each money market.
+    pub fn collateral_factor<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        sc_address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isUshMarketObserver")
-            .argument(&sc_address)
+            .raw_call("getCollateralFactor")
+            .argument(&money_market)
             .original_result()
     }
 
-    /// Checks whether the specified money market is deprecated.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market to check.
-    ///
-    pub fn is_deprecated<
+    /// Stores the collateral factor for each money market taken into consideration if the account has borrowed USH.
+    pub fn ush_borrower_collateral_factor<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isDeprecated")
+            .raw_call("getUshBorrowerCollateralFactor")
             .argument(&money_market)
             .original_result()
     }
 
-    /// Gets a whitelist or set of supported money market addresses as an array.
-    ///
-    pub fn get_whitelisted_markets(
+    /// Stores the next collateral factors for each money market.
+    pub fn next_collateral_factors<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedAddress<Env::Api>>> {
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (u64, BigUint<Env::Api>, BigUint<Env::Api>)> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getWhitelistedMarkets")
+            .raw_call("getNextCollateralFactor")
+            .argument(&money_market)
             .original_result()
     }
 
-    /// Gets the the set of money markets addresses in which the account has entered as an array. An account is considered to
-    /// be in the market if it has deposited collateral or took a borrow. Currently, after a borrow is fully repaid, the
-    /// account is still considered to be in the market.
-    ///
-    pub fn get_account_markets<
+    /// Stores whether a given account has opted in to automatically enter markets with freshly minted Hatom tokens.
+    pub fn auto_enter_markets<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         account: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ManagedAddress<Env::Api>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccountMarkets")
+            .raw_call("hasAutoEnterMarkets")
             .argument(&account)
             .original_result()
     }
 
-    /// Gets the maximum collateral factor allowed
-    ///
-    pub fn get_max_collateral_factor(
+    /// Stores whether a given account has opted in to receive native EGLD instead of WEGLD from redemptions and claims.
+    pub fn native_egld<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMaxCollateralFactor")
+            .raw_call("hasNativeEgld")
+            .argument(&account)
             .original_result()
     }
 
-    /// Gets the amount of Hatom tokens deposited as collateral for a given money market and account.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market smart contract.
-    /// - `account` - The account we wish to analyze.
-    ///
-    pub fn get_account_collateral_tokens<
+    /// Stores whether a given money market is a position market, i.e. a supply-only market whose collateral tokens are
+    /// meta-ESDT positions.
+    pub fn position_market<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-        account: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccountTokens")
+            .raw_call("isPositionMarket")
             .argument(&money_market)
-            .argument(&account)
             .original_result()
     }
 
-    /// Gets the total amount of collateral tokens deposited into the controller for a specific money market.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market for which to retrieve the total collateral tokens.
-    ///
-    /// # Notes:
-    ///
-    /// - If the market has no collateral, returns 0.
-    ///
-    pub fn get_total_collateral_tokens<
+    /// A supported money market might have a liquidity cap, which is stored here.
+    pub fn liquidity_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
@@ -1718,18 +4680,13 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTotalCollateralTokens")
+            .raw_call("getLiquidityCap")
             .argument(&money_market)
             .original_result()
     }
 
-    /// Gets the up to date collateral factor for a specified money market.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market smart contract.
-    ///
-    pub fn update_and_get_collateral_factor<
+    /// A supported money market might have a borrowing cap, which is stored here.
+    pub fn borrow_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
@@ -1737,18 +4694,14 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("updateAndGetCollateralFactor")
+            .raw_call("getBorrowCap")
             .argument(&money_market)
             .original_result()
     }
 
-    /// Gets the up to date USH borrower collateral factor for a specified money market.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market smart contract.
-    ///
-    pub fn update_and_get_ush_borrower_collateral_factor<
+    /// A supported money market might have a collateral cap, i.e. a maximum amount of underlying that can be deposited as
+    /// collateral, which is stored here.
+    pub fn collateral_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
@@ -1756,446 +4709,464 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("updateAndGetUshBorrowerCollateralFactor")
+            .raw_call("getCollateralCap")
             .argument(&money_market)
             .original_result()
     }
 
-    /// Updates the collateral factors if possible and returns their updated values.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market smart contract.
-    ///
-    pub fn update_and_get_collateral_factors<
+    /// A supported money market might have a maximum borrow size, i.e. a maximum amount of underlying that can be borrowed
+    /// in a single transaction, which is stored here.
+    pub fn max_borrow_per_tx<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (BigUint<Env::Api>, BigUint<Env::Api>)> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("updateAndGetCollateralFactors")
+            .raw_call("getMaxBorrowPerTx")
             .argument(&money_market)
             .original_result()
     }
 
-    /// Gets the current minting status at a given money market.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market smart contract.
-    ///
-    /// # Notes:
-    ///
-    /// - By default, mint is active (returns the first enum value).
-    ///
-    pub fn get_mint_status<
+    /// An account might have a borrow cap at a given money market, i.e. a maximum amount of underlying it can owe, which is
+    /// stored here.
+    pub fn account_borrow_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+        account: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMintStatus")
+            .raw_call("getAccountBorrowCap")
             .argument(&money_market)
+            .argument(&account)
             .original_result()
     }
 
-    /// Gets the current borrowing status at a given money market.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market smart contract.
-    ///
-    /// # Notes:
-    ///
-    /// - By default, borrow is active (returns the first enum value).
-    ///
-    pub fn get_borrow_status<
+    /// A supported money market might have a utilization cap in wad, beyond which new borrows are rejected.
+    pub fn utilization_cap<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getBorrowStatus")
+            .raw_call("getUtilizationCap")
             .argument(&money_market)
             .original_result()
     }
 
-    /// Gets the current seizing status at a given money market.
-    ///
-    /// # Arguments:
-    ///
-    /// - `money_market` - The address of the money market smart contract.
-    ///
-    /// # Notes:
-    ///
-    /// - By default, seize is active (returns the first enum value).
-    ///
-    pub fn get_seize_status<
+    /// Stores the cap group a given money market belongs to, if any. Cap groups start at 1.
+    pub fn market_cap_group<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getSeizeStatus")
+            .raw_call("getMarketCapGroup")
             .argument(&money_market)
             .original_result()
     }
 
-    /// Gets the current global seizing status at a given money market.
-    ///
-    /// # Notes:
-    ///
-    /// - By default, global seize is active (returns the first enum value).
-    ///
-    pub fn get_global_seize_status(
+    /// Stores the money markets that belong to a given cap group.
+    pub fn cap_group_markets<
+        Arg0: ProxyArg<usize>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+        group_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getGlobalSeizeStatus")
+            .raw_call("getCapGroupMarkets")
+            .argument(&group_id)
             .original_result()
     }
 
-    /// Gets the accrued rewards for a given account's address and rewards token ID.
-    ///
-    /// # Arguments:
-    ///
-    /// - `supplier` - A reference to a `ManagedAddress` representing the supplier's address.
-    /// - `rewards_token_id` - A reference to an `EgldOrEsdtTokenIdentifier` representing the rewards token's ID.
-    ///
-    pub fn get_account_accrued_rewards<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    /// A cap group might have a combined borrow cap in EGLD and in wad, which is stored here.
+    pub fn cap_group_borrow_cap<
+        Arg0: ProxyArg<usize>,
     >(
         self,
-        supplier: Arg0,
-        rewards_token_id: Arg1,
+        group_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccountAccruedRewards")
-            .argument(&supplier)
-            .argument(&rewards_token_id)
+            .raw_call("getCapGroupBorrowCap")
+            .argument(&group_id)
             .original_result()
     }
 
-    /// Whitelisted money markets can burn their own tokens deposited at the controller.
-    ///
-    /// # Arguments:
-    ///
-    /// - `token_id` - The token identifier for the Hatom token.
-    /// - `tokens` - The amount of tokens to be burnt.
-    ///
-    /// # Notes:
-    ///
-    /// - Can only be called by a whitelisted money market.
-    /// - A money market can only burn Hatom tokens corresponding to their own token type.
-    /// - There is no need to update the total collateral tokens for the money market because it is assumed that they have
-    ///   already exited the market and are being redeemed.
-    ///
-    pub fn burn_tokens<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
-        Arg1: ProxyArg<BigUint<Env::Api>>,
+    /// Stores whether a money market has been explicitly flagged as deprecated by governance.
+    pub fn deprecated<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        token_id: Arg0,
-        tokens: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("burnTokens")
-            .argument(&token_id)
-            .argument(&tokens)
+            .raw_call("getDeprecatedFlag")
+            .argument(&money_market)
             .original_result()
     }
 
-    /// Whitelisted money markets can transfer their own tokens to a given account.
-    ///
-    /// # Arguments:
-    ///
-    /// - `to` - The address of the account to which the tokens will be transferred.
-    /// - `token_payment` - The token payment to be transferred.
-    ///
-    /// # Notes:
-    ///
-    /// - Can only be called by a whitelisted money market.
-    /// - A money market can only transfer Hatom tokens corresponding to their own token type.
-    /// - There is no need to update the total collateral tokens for the money market because it is assumed that they have
-    ///   already exited the market and are being transferred.
-    ///
-    pub fn transfer_tokens<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<EsdtTokenPayment<Env::Api>>,
-    >(
+    /// Stores the timestamp until which interest accrual is frozen in all money markets.
+    pub fn interest_frozen_until(
         self,
-        to: Arg0,
-        token_payment: Arg1,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("transferTokens")
-            .argument(&to)
-            .argument(&token_payment)
+            .raw_call("getInterestFrozenUntil")
             .original_result()
     }
 
-    /// Computes the amount of Hatom tokens to be seized given an underlying repayment amount performed by the liquidator.
-    /// Takes into consideration the liquidation incentive, such that the liquidator gets tokens at a discount.
-    ///
-    /// # Arguments:
-    ///
-    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
-    /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
-    /// - `amount` - The amount of underlying being repaid by the liquidator.
-    ///
-    pub fn tokens_to_seize<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
-    >(
+    /// Stores the latest timestamp at which any money market has accrued interest.
+    pub fn latest_accrual_timestamp(
         self,
-        borrow_market: Arg0,
-        collateral_market: Arg1,
-        amount: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("tokensToSeize")
-            .argument(&borrow_market)
-            .argument(&collateral_market)
-            .argument(&amount)
+            .raw_call("getLatestAccrualTimestamp")
             .original_result()
     }
 
-    /// Stores the guardian address.
-    pub fn pause_guardian(
+    /// Stores the latest timestamp at which any money market has found an abnormal accrual gap.
+    pub fn latest_accrual_gap_timestamp(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPauseGuardian")
+            .raw_call("getLatestAccrualGapTimestamp")
             .original_result()
     }
 
-    /// Stores the rewards manager address.
-    pub fn rewards_manager(
+    /// Stores the set of money markets at which rewards distribution has been paused.
+    pub fn rewards_paused_markets(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getRewardsManager")
+            .raw_call("getRewardsPausedMarkets")
             .original_result()
     }
 
-    /// Stores a whitelisted market address given a token identifier.
-    pub fn money_markets<
-        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    /// Stores the amount of rewards of a given rewards token distributed to a given account at a given money market while
+    /// rewards distribution was paused, which are held until it is resumed.
+    pub fn account_held_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        token_id: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        money_market: Arg0,
+        account: Arg1,
+        rewards_token_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMoneyMarketByTokenId")
-            .argument(&token_id)
+            .raw_call("getAccountHeldRewards")
+            .argument(&money_market)
+            .argument(&account)
+            .argument(&rewards_token_id)
             .original_result()
     }
 
-    /// Stores both the underlying identifier and the token identifier associated to a whitelisted money market.
-    pub fn identifiers<
+    /// Stores the set of rewards token identifiers ever earned by a given account.
+    pub fn account_rewards_tokens<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (EgldOrEsdtTokenIdentifier<Env::Api>, TokenIdentifier<Env::Api>)> {
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getIdentifiersByMoneyMarket")
-            .argument(&money_market)
+            .raw_call("getAccountRewardsTokens")
+            .argument(&account)
             .original_result()
     }
 
-    /// Stores the set of addresses that belong to a given money market.
-    pub fn market_members<
+    /// Stores the rewards index for a given account and rewards batch in the specified money market, in wad * wad * wad.
+    pub fn account_batch_rewards_index<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        batch_id: Arg1,
+        account: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMarketMembers")
+            .raw_call("getAccountRewardsIndex")
             .argument(&money_market)
+            .argument(&batch_id)
+            .argument(&account)
             .original_result()
     }
 
-    /// Stores the maximum amount of markets an account can enter at any given point in time.
-    pub fn max_markets_per_account(
+    /// Stores whether the rewards batches indexes of a given money market have been migrated to the current precision.
+    pub fn rewards_indexes_migrated<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMaxMarketsPerAccount")
+            .raw_call("areRewardsIndexesMigrated")
+            .argument(&money_market)
             .original_result()
     }
 
-    /// Stores the price oracle smart contract address.
-    pub fn price_oracle(
+    /// Stores the maximum amount of rewards a single account can earn from a given rewards batch, if any.
+    pub fn rewards_batch_account_cap<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        money_market: Arg0,
+        batch_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getPriceOracle")
+            .raw_call("getRewardsBatchAccountCap")
+            .argument(&money_market)
+            .argument(&batch_id)
             .original_result()
     }
 
-    /// Stores the collateral factor for each money market.
-    pub fn collateral_factor<
+    /// Stores the USD value per day in wad distributed by a given rewards batch, if it is denominated in USD.
+    pub fn rewards_batch_usd_per_day<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
     >(
         self,
         money_market: Arg0,
+        batch_id: Arg1,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getCollateralFactor")
+            .raw_call("getRewardsBatchUsdPerDay")
             .argument(&money_market)
+            .argument(&batch_id)
             .original_result()
     }
 
-    /// Stores the collateral factor for each money market taken into consideration if the account has borrowed USH.
-    pub fn ush_borrower_collateral_factor<
+    /// Stores the amount of rewards earned by a given account from a capped rewards batch.
+    pub fn account_batch_rewards<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
+        batch_id: Arg1,
+        account: Arg2,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUshBorrowerCollateralFactor")
+            .raw_call("getAccountBatchRewards")
             .argument(&money_market)
+            .argument(&batch_id)
+            .argument(&account)
             .original_result()
     }
 
-    /// Stores the next collateral factors for each money market.
-    pub fn next_collateral_factors<
+    /// Stores the ID of the next rewards batch in the specified money market.
+    pub fn next_rewards_batch_id<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (u64, BigUint<Env::Api>, BigUint<Env::Api>)> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getNextCollateralFactor")
+            .raw_call("getNextRewardsBatchId")
             .argument(&money_market)
             .original_result()
     }
 
-    /// A supported money market might have a liquidity cap, which is stored here.
-    pub fn liquidity_cap<
+    /// Stores the maximum amount of batches allowed per market.
+    pub fn max_rewards_batches<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getLiquidityCap")
+            .raw_call("getMaxRewardsBatchesPerMarket")
             .argument(&money_market)
             .original_result()
     }
 
-    /// A supported money market might have a borrowing cap, which is stored here.
-    pub fn borrow_cap<
+    /// Stores the maximum allowed slippage.
+    pub fn max_slippage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxSlippage")
+            .original_result()
+    }
+
+    /// Stores the list of rewards batches in the specified money market.
+    pub fn rewards_batches<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, RewardsBatch<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getBorrowCap")
+            .raw_call("getRewardsBatches")
             .argument(&money_market)
             .original_result()
     }
 
-    /// Stores the rewards index for a given account and rewards token in the specified money market.
-    pub fn account_batch_rewards_index<
+    /// Stores the ID of the last rewards snapshot taken, if any (zero otherwise).
+    pub fn last_rewards_snapshot_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastRewardsSnapshotId")
+            .original_result()
+    }
+
+    /// Stores the timestamp at which a given rewards snapshot was taken.
+    pub fn rewards_snapshot_timestamp<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        snapshot_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsSnapshotTimestamp")
+            .argument(&snapshot_id)
+            .original_result()
+    }
+
+    /// Stores the rewards batches indexes of a given money market at a given rewards snapshot.
+    pub fn rewards_snapshot<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        snapshot_id: Arg0,
+        money_market: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, RewardsIndexSnapshot<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsSnapshot")
+            .argument(&snapshot_id)
+            .argument(&money_market)
+            .original_result()
+    }
+
+    /// Stores the metadata of a given rewards token, registered when it is first used in a rewards batch.
+    pub fn rewards_token_metadata<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, RewardsTokenMetadata<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsTokenMetadata")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stores the listing metadata of a given money market, used by frontends and aggregators.
+    pub fn market_metadata<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<usize>,
-        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         money_market: Arg0,
-        batch_id: Arg1,
-        account: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MarketMetadata<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccountRewardsIndex")
+            .raw_call("getMarketMetadata")
             .argument(&money_market)
-            .argument(&batch_id)
-            .argument(&account)
             .original_result()
     }
 
-    /// Stores the ID of the next rewards batch in the specified money market.
-    pub fn next_rewards_batch_id<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    /// Stores the set of tokens that have ever been used as rewards, which might still be owed to accounts.
+    pub fn rewards_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsTokens")
+            .original_result()
+    }
+
+    /// Stores the nonce of a given Meta-ESDT rewards token. Fungible rewards tokens have nonce zero.
+    pub fn rewards_token_nonce<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getNextRewardsBatchId")
-            .argument(&money_market)
+            .raw_call("getRewardsTokenNonce")
+            .argument(&token_id)
             .original_result()
     }
 
-    /// Stores the maximum amount of batches allowed per market.
-    pub fn max_rewards_batches<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    /// Stores the number of rewards batches ever funded with a given rewards token.
+    pub fn rewards_batches_funded_count<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
     >(
         self,
-        money_market: Arg0,
+        token_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMaxRewardsBatchesPerMarket")
-            .argument(&money_market)
+            .raw_call("getRewardsBatchesFundedCount")
+            .argument(&token_id)
             .original_result()
     }
 
-    /// Stores the maximum allowed slippage.
-    pub fn max_slippage(
+    /// Stores the total amount of a given rewards token ever funded into rewards batches.
+    pub fn rewards_batches_funded_amount<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
         self,
+        token_id: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMaxSlippage")
+            .raw_call("getRewardsBatchesFundedAmount")
+            .argument(&token_id)
             .original_result()
     }
 
-    /// Stores the list of rewards batches in the specified money market.
-    pub fn rewards_batches<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    /// Stores the amount of a given rewards token funded into rewards batches at a given epoch.
+    pub fn rewards_batches_funded_amount_at<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
     >(
         self,
-        money_market: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, RewardsBatch<Env::Api>>> {
+        token_id: Arg0,
+        epoch: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getRewardsBatches")
-            .argument(&money_market)
+            .raw_call("getRewardsBatchesFundedAmountAt")
+            .argument(&token_id)
+            .argument(&epoch)
             .original_result()
     }
 
@@ -2213,6 +5184,23 @@ where
             .original_result()
     }
 
+    /// Stores the nonce of the rewards token of a given rewards batch, which is only non-zero for Meta-ESDT rewards tokens.
+    pub fn rewards_batch_nonce<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        money_market: Arg0,
+        batch_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardsBatchNonce")
+            .argument(&money_market)
+            .argument(&batch_id)
+            .original_result()
+    }
+
     /// Stores the current position of a rewards batch in the specified money market at the corresponding VecMapper.
     pub fn rewards_batch_position<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
@@ -2244,6 +5232,37 @@ where
             .original_result()
     }
 
+    /// Stores the total amount of governance tokens ever paid out by boosting a given rewards token.
+    pub fn boosted_governance_tokens<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBoostedGovernanceTokens")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stores the amount of governance tokens paid out by boosting a given rewards token at a given epoch.
+    pub fn boosted_governance_tokens_at<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token_id: Arg0,
+        epoch: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBoostedGovernanceTokensAt")
+            .argument(&token_id)
+            .argument(&epoch)
+            .original_result()
+    }
+
     /// Stores wrapped EGLD smart contract address.
     pub fn egld_wrapper(
         self,
@@ -2264,6 +5283,16 @@ where
             .original_result()
     }
 
+    /// Stores the amount of WEGLD held by the controller on behalf of EGLD rewards, which is only unwrapped on payouts.
+    pub fn wegld_buffer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWegldBuffer")
+            .original_result()
+    }
+
     /// Stores the governance token identifier.
     pub fn governance_token_id(
         self,
@@ -2284,6 +5313,16 @@ where
             .original_result()
     }
 
+    /// Stores the set of tokens that can be swapped into a borrow market underlying to repay a borrow.
+    pub fn repay_swap_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRepaySwapTokens")
+            .original_result()
+    }
+
     /// Stores the boosting state.
     pub fn boosting_state(
         self,
@@ -2314,6 +5353,16 @@ where
             .original_result()
     }
 
+    /// Stores the timestamp from which the given booster observer can be forcibly cleared, if scheduled.
+    pub fn next_force_clear_booster_observer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (u64, ManagedAddress<Env::Api>)> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextForceClearBoosterObserver")
+            .original_result()
+    }
+
     /// Stores the USH Money Market observer.
     pub fn ush_market_observer(
         self,
@@ -2323,6 +5372,133 @@ where
             .raw_call("getUshMarketObserver")
             .original_result()
     }
+
+    /// Stores the minimum amount of seconds between two consecutive protocol heartbeats.
+    pub fn heartbeat_interval(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHeartbeatInterval")
+            .original_result()
+    }
+
+    /// Stores the timestamp of the last protocol heartbeat.
+    pub fn last_heartbeat_timestamp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastHeartbeatTimestamp")
+            .original_result()
+    }
+
+    /// Stores whether bulk enumeration views are restricted to registered readers.
+    pub fn enumeration_restricted(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isEnumerationRestricted")
+            .original_result()
+    }
+
+    /// Stores whether a given smart contract is a registered reader of bulk enumeration views.
+    pub fn registered_reader<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        reader: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isRegisteredReader")
+            .argument(&reader)
+            .original_result()
+    }
+
+    /// Stores the amount of seconds after an account has been detected as liquidatable during which only bonded liquidators
+    /// can liquidate it.
+    pub fn liquidation_priority_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationPriorityWindow")
+            .original_result()
+    }
+
+    /// Stores the minimum EGLD bond required to become a bonded liquidator.
+    pub fn min_liquidator_bond(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinLiquidatorBond")
+            .original_result()
+    }
+
+    /// Stores the EGLD bond of a given liquidator.
+    pub fn liquidator_bond<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        liquidator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidatorBond")
+            .argument(&liquidator)
+            .original_result()
+    }
+
+    /// Stores the total EGLD bonded by liquidators, which is never available as EGLD rewards or for rescue.
+    pub fn total_liquidator_bonds(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalLiquidatorBonds")
+            .original_result()
+    }
+
+    /// Stores the set of bonded liquidators.
+    pub fn bonded_liquidators(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBondedLiquidators")
+            .original_result()
+    }
+
+    /// Stores the timestamp from which the bond of an unbonding liquidator can be withdrawn.
+    pub fn liquidator_unbonding<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        liquidator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidatorUnbondingTimestamp")
+            .argument(&liquidator)
+            .original_result()
+    }
+
+    /// Stores the timestamp at which a given account has been first detected as liquidatable.
+    pub fn liquidatable_since<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidatableSince")
+            .argument(&account)
+            .original_result()
+    }
 }
 
 #[type_abi]
@@ -2374,6 +5550,58 @@ where
     pub output_token_id: TokenIdentifier<Api>,
 }
 
+/// The reason behind a paused status. Admin and guardian pauses are temporary, whereas deprecation pauses are expected to be
+/// permanent since the money market is being wound down. Deprecation pauses are derived from the deprecated flag of the
+/// money market and cannot be set explicitly.
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub enum PauseReason {
+    NotPaused,
+    AdminPause,
+    GuardianPause,
+    OracleIncident,
+    Deprecation,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RewardsTokenMetadata<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub ticker: ManagedBuffer<Api>,
+    pub decimals: u8,
+    pub decimals_resolved: bool,
+    pub registered_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BoosterPathReport<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ok: bool,
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub amount_in: BigUint<Api>,
+    pub expected_amount_out: BigUint<Api>,
+    pub spot_amount_out: BigUint<Api>,
+    pub price_impact: BigUint<Api>,
+    pub within_max_slippage: bool,
+    pub broken_step: Option<usize>,
+}
+
+/// The status of an account as exposed to external protocols. An account is `Liquidatable` if its health factor is below 1,
+/// `Warning` if it is below the warning health factor and `Healthy` otherwise, including accounts without borrows.
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub enum AccountStatus {
+    Healthy,
+    Warning,
+    Liquidatable,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, PartialEq, Clone, Debug)]
 pub enum RiskProfile<Api>
@@ -2384,6 +5612,34 @@ where
     RiskyOrInsolvent(BigUint<Api>),
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, Clone)]
+pub struct PriceShockReport<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub money_market: ManagedAddress<Api>,
+    pub price_delta_bps: i64,
+    pub shocked_price: BigUint<Api>,
+    pub accounts_checked: usize,
+    pub liquidatable_accounts: ManagedVec<Api, ManagedAddress<Api>>,
+    pub total_shortfall: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct DeploymentReport<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ok: bool,
+    pub price_oracle_set: bool,
+    pub max_markets_per_account_set: bool,
+    pub markets_checked: usize,
+    pub markets_with_wrong_controller: ManagedVec<Api, ManagedAddress<Api>>,
+    pub markets_with_inconsistent_identifiers: ManagedVec<Api, ManagedAddress<Api>>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
 pub enum Status {
@@ -2391,9 +5647,89 @@ pub enum Status {
     Paused,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
+pub struct RewardsIndexSnapshot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub batch_id: usize,
+    pub market_type: MarketType,
+    pub token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub index: BigUint<Api>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
 pub enum State {
     Inactive,
     Active,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MarketMetadata<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub display_symbol: ManagedBuffer<Api>,
+    pub underlying_decimals: u8,
+    pub icon_uri_hash: ManagedBuffer<Api>,
+    pub listed_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct LiquidationOutcome<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub borrower: ManagedAddress<Api>,
+    pub borrow_market: ManagedAddress<Api>,
+    pub collateral_market: ManagedAddress<Api>,
+    pub liquidated: bool,
+    pub repayment: BigUint<Api>,
+    pub seized_tokens: BigUint<Api>,
+    pub reason: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
+pub struct MarketHealth<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub money_market: ManagedAddress<Api>,
+    pub collateral_value: BigUint<Api>,
+    pub borrow_value: BigUint<Api>,
+    pub collateral_factor: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Clone)]
+pub struct AccountHealth<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub markets: ManagedVec<Api, MarketHealth<Api>>,
+    pub total_collateral: BigUint<Api>,
+    pub total_borrow: BigUint<Api>,
+    pub liquidity: BigUint<Api>,
+    pub shortfall: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum DryRunLiquidation {
+    Allowed,
+    ZeroAmount,
+    SelfLiquidation,
+    NonWhitelistedMarket,
+    GlobalSeizePaused,
+    SeizePaused,
+    LiquidationPriorityWindow,
+    RepaymentExceedsTotalBorrow,
+    BorrowerNotLiquidatable,
+    TooMuchRepayment,
+    TooMuchLiquidation,
+}
@@ -1,13 +1,24 @@
 pub const ERROR_NON_WHITELISTED_MARKET: &[u8] = b"not a whitelisted money market";
 pub const ERROR_INVALID_MONEY_MARKET_SC: &[u8] = b"invalid money market smart contract";
 pub const ERROR_INVALID_ORACLE_SC: &[u8] = b"invalid price oracle smart contract";
+pub const ERROR_MARKET_PRICE_ORACLE_UNSET: &[u8] = b"market price oracle unset";
 pub const ERROR_ALREADY_SUPPORTED_MARKET: &[u8] = b"the provided money market has been already supported";
 pub const ERROR_MISSING_CLOSE_FACTOR: &[u8] = b"missing close factor";
 pub const ERROR_MISSING_LIQUIDATION_INCENTIVE: &[u8] = b"missing liquidation incentive";
+pub const ERROR_MISSING_INITIAL_SEED: &[u8] = b"missing initial seed deposit";
+pub const ERROR_INVALID_SEED_PAYMENT: &[u8] = b"invalid seed payment, must be the money market underlying";
 pub const ERROR_REACHED_LIQUIDITY_CAP: &[u8] = b"reached market liquidity cap";
 pub const ERROR_REACHED_BORROW_CAP: &[u8] = b"reached market borrow cap";
+pub const ERROR_REACHED_COLLATERAL_CAP: &[u8] = b"reached market collateral cap";
+pub const ERROR_EXCEEDED_MAX_BORROW_PER_TX: &[u8] = b"borrow amount exceeds market maximum borrow per transaction";
+pub const ERROR_REACHED_ACCOUNT_BORROW_CAP: &[u8] = b"reached account borrow cap";
+pub const ERROR_MISSING_ACCOUNT_BORROW_CAP: &[u8] = b"missing account borrow cap";
+pub const ERROR_MISSING_MAX_BORROW_PER_TX: &[u8] = b"missing maximum borrow per transaction";
+pub const ERROR_REACHED_CAP_GROUP_BORROW_CAP: &[u8] = b"reached cap group borrow cap";
+pub const ERROR_INVALID_CAP_GROUP: &[u8] = b"invalid cap group";
+pub const ERROR_REACHED_UTILIZATION_CAP: &[u8] = b"reached market utilization cap";
+pub const ERROR_UTILIZATION_CAP_TOO_HIGH: &[u8] = b"utilization cap too high";
 pub const ERROR_ONLY_ADMIN: &[u8] = b"only admin allowed";
-pub const ERROR_ONLY_ADMIN_OR_GUARDIAN: &[u8] = b"only admin or guardian allowed";
 pub const ERROR_ONLY_ADMIN_OR_REWARDS_MANAGER: &[u8] = b"only admin or rewards manager allowed";
 pub const ERROR_ONLY_MONEY_MARKET_CAN_BURN: &[u8] = b"money market can only burn its own tokens";
 pub const ERROR_ONLY_MONEY_MARKET_CAN_TRANSFER: &[u8] = b"money market can only transfer its own tokens";
@@ -17,6 +28,8 @@ pub const ERROR_CANNOT_BE_ADDRESS_ZERO: &[u8] = b"cannot be address zero";
 pub const ERROR_TOO_MANY_MARKETS: &[u8] = b"cannot enter market, too many markets";
 pub const ERROR_MAX_MARKETS_TOO_HIGH: &[u8] = b"maximum number of markets per account too high";
 pub const ERROR_MAX_MARKETS_TOO_LOW: &[u8] = b"maximum number of markets per account too low";
+pub const ERROR_MAX_MARKETS_NOT_DECREASED: &[u8] = b"maximum number of markets per account not decreased";
+pub const ERROR_NO_NEXT_MAX_MARKETS: &[u8] = b"no scheduled maximum number of markets per account";
 pub const ERROR_COLLATERAL_FACTOR_TOO_HIGH: &[u8] = b"collateral factor too high";
 pub const ERROR_USH_BORROWER_COLLATERAL_FACTOR_TOO_HIGH: &[u8] = b"USH borrower collateral factor too high";
 pub const ERROR_COLLATERAL_FACTOR_TOO_LOW: &[u8] = b"collateral factor too low";
@@ -35,6 +48,9 @@ pub const ERROR_NOT_ENOUGH_COLLATERAL_REDEEMER: &[u8] = b"redeemer does not have
 pub const ERROR_REPAYMENT_EXCEEDS_TOTAL_BORROW: &[u8] = b"cannot repay more than the total borrow";
 pub const ERROR_TOO_MUCH_REPAYMENT: &[u8] = b"exceeded maximum repayment amount";
 pub const ERROR_ORACLE_FAILED_RETRIEVE_UNDERLYING_PRICE: &[u8] = b"oracle failed to retrieve the underlying price";
+pub const ERROR_ORACLE_FAILED_RETRIEVE_EGLD_PRICE: &[u8] = b"oracle failed to retrieve the EGLD price in USD";
+pub const ERROR_ORACLE_FAILED_RETRIEVE_REWARDS_TOKEN_PRICE: &[u8] = b"oracle failed to retrieve the rewards token price";
+pub const ERROR_ORACLE_FAILED_RETRIEVE_GOVERNANCE_TOKEN_PRICE: &[u8] = b"oracle failed to retrieve the governance token price";
 pub const ERROR_ORACLE_NOT_INITIALIZED: &[u8] = b"oracle has not been initialized";
 pub const ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO: &[u8] = b"amount has to be greater than zero";
 pub const ERROR_MAX_REWARDS_BATCHES_TOO_HIGH: &[u8] = b"maximum amount of rewards batches per money market too high";
@@ -51,16 +67,20 @@ pub const ERROR_ZERO_REWARDS_BATCH_PERIOD: &[u8] = b"rewards batch period has to
 pub const ERROR_REWARDS_BATCH_TOLERANCE_OUT_OF_RANGE: &[u8] = b"rewards batch tolerance out of range";
 pub const ERROR_REWARDS_BATCH_EXPIRED: &[u8] = b"rewards batch has already expired";
 pub const ERROR_REWARDS_BATCH_NOT_EXPIRED: &[u8] = b"rewards batch has not expired yet";
+pub const ERROR_ZERO_REWARDS_BATCH_ACCOUNT_CAP: &[u8] = b"rewards batch account cap has to be greater than zero";
 pub const ERROR_REWARDS_NOT_FULLY_DISTRIBUTED: &[u8] = b"rewards batch not fully distributed";
 pub const ERROR_BOOSTING_NOT_ACTIVE: &[u8] = b"boosting is not active";
 pub const ERROR_INVALID_SWAP_AMOUNT: &[u8] = b"invalid swap amount";
 pub const ERROR_INVALID_SWAP_PATH: &[u8] = b"invalid swap path";
 pub const ERROR_UNEXPECTED_SWAP_AMOUNT: &[u8] = b"unexpected swap amount";
 pub const ERROR_ROUTER_NOT_INITIALIZED: &[u8] = b"router has not been initialized";
+pub const ERROR_EGLD_WRAPPER_NOT_INITIALIZED: &[u8] = b"EGLD wrapper has not been initialized";
+pub const ERROR_EGLD_MARKET_NOT_FOUND: &[u8] = b"neither an EGLD nor a WEGLD money market has been found";
 pub const ERROR_REWARDS_TOKEN_ALREADY_BOOSTED: &[u8] = b"rewards token already boosted";
 pub const ERROR_MAX_SLIPPAGE_TOO_HIGH: &[u8] = b"maximum slippage too high";
 pub const ERROR_EXPECTED_SLIPPAGE: &[u8] = b"expected slippage";
 pub const ERROR_TOO_MUCH_SLIPPAGE: &[u8] = b"too much slippage";
+pub const ERROR_ORACLE_GOVERNANCE_TOKEN_UNSUPPORTED: &[u8] = b"governance token is not supported by the price oracle";
 pub const ERROR_INVALID_PREMIUM: &[u8] = b"invalid premium";
 pub const ERROR_BOOST_NOT_ALLOWED: &[u8] = b"boosting is not allowed";
 pub const ERROR_REWARDS_BATCH_BOOST_NOT_ENABLED: &[u8] = b"rewards batch boost is not enabled";
@@ -72,6 +92,9 @@ pub const ERROR_REWARDS_BOOSTER_ALREADY_SET: &[u8] = b"rewards booster already s
 pub const ERROR_LEGACY_BOOSTER_OBSERVER: &[u8] = b"legacy rewards booster observer";
 pub const ERROR_REWARDS_BOOSTER_UNSET: &[u8] = b"rewards booster unset";
 pub const ERROR_REWARDS_BOOSTER_NOT_FINALIZED: &[u8] = b"rewards booster not finalized";
+pub const ERROR_NO_FORCE_CLEAR_BOOSTER_OBSERVER: &[u8] = b"no scheduled force clear of the booster observer";
+pub const ERROR_FORCE_CLEAR_BOOSTER_OBSERVER_TIMELOCKED: &[u8] = b"force clear of the booster observer still timelocked";
+pub const ERROR_PENDING_MARKET_CHANGES: &[u8] = b"pending market changes must be notified first";
 pub const ERROR_INVALID_USH_MARKET_SC: &[u8] = b"invalid USH money market smart contract";
 pub const ERROR_USH_MARKET_OBSERVER_ALREADY_SET: &[u8] = b"USH market observer already set";
 pub const ERROR_LEGACY_USH_MARKET_OBSERVER: &[u8] = b"legacy USH market observer";
@@ -80,3 +103,54 @@ pub const ERROR_USH_MARKET_NOT_FINALIZED: &[u8] = b"ush market not finalized";
 pub const ERROR_TOKEN_NOT_BOOSTED: &[u8] = b"token is not boosted";
 pub const ERROR_INSUFFICIENT_BOOSTED_REWARDS_BALANCE_LEFT: &[u8] = b"not enough boosted rewards token balance left";
 pub const ERROR_INVALID_REWARDS_TOKEN_IDS: &[u8] = b"invalid rewards token identifiers";
+pub const ERROR_PROTECTED_TOKEN: &[u8] = b"token is protected and cannot be rescued";
+pub const ERROR_INVALID_INTEREST_FREEZE_DURATION: &[u8] = b"invalid interest accrual freeze duration";
+pub const ERROR_INTEREST_ACCRUAL_ALREADY_FROZEN: &[u8] = b"interest accrual already frozen";
+pub const ERROR_INTEREST_ACCRUAL_NOT_FROZEN: &[u8] = b"interest accrual not frozen";
+pub const ERROR_REWARDS_DISTRIBUTION_ALREADY_PAUSED: &[u8] = b"rewards distribution already paused";
+pub const ERROR_REWARDS_DISTRIBUTION_NOT_PAUSED: &[u8] = b"rewards distribution not paused";
+pub const ERROR_REWARDS_DISTRIBUTION_PAUSED: &[u8] = b"rewards distribution is paused";
+pub const ERROR_NOT_ENOUGH_REDEEMED_UNDERLYING: &[u8] = b"not enough redeemed underlying";
+pub const ERROR_UNKNOWN_REWARDS_TOKEN: &[u8] = b"unknown rewards token";
+pub const ERROR_INVALID_PRICE_DELTA: &[u8] = b"invalid price delta";
+pub const ERROR_HEARTBEAT_TOO_EARLY: &[u8] = b"heartbeat too early";
+pub const ERROR_INVALID_READER_SC: &[u8] = b"invalid reader smart contract";
+pub const ERROR_UNREGISTERED_READER: &[u8] = b"unregistered reader";
+pub const ERROR_INVALID_REPAY_SWAP_TOKEN: &[u8] = b"invalid repay swap token";
+pub const ERROR_REPAY_SWAP_TOKEN_ALREADY_SUPPORTED: &[u8] = b"repay swap token already supported";
+pub const ERROR_NOT_ENOUGH_SWAPPED_UNDERLYING: &[u8] = b"not enough swapped underlying";
+pub const ERROR_NO_REWARDS_TO_COLLATERALIZE: &[u8] = b"no rewards to collateralize";
+pub const ERROR_NOT_ENOUGH_MINTED_TOKENS: &[u8] = b"not enough minted tokens";
+pub const ERROR_INVALID_PAUSE_REASON: &[u8] = b"invalid pause reason";
+pub const ERROR_NOT_MARKET_MEMBER: &[u8] = b"account is not a market member";
+pub const ERROR_MARKET_MEMBER_NOT_EMPTY: &[u8] = b"market member has collateral or outstanding borrow";
+pub const ERROR_USD_DENOMINATED_REWARDS_BATCH: &[u8] = b"rewards batch is denominated in USD";
+pub const ERROR_NOT_USD_DENOMINATED_REWARDS_BATCH: &[u8] = b"rewards batch is not denominated in USD";
+pub const ERROR_INVALID_POSITION_MARKET_SC: &[u8] = b"invalid position market smart contract";
+pub const ERROR_ONLY_POSITION_MARKET: &[u8] = b"only position market allowed";
+pub const ERROR_UNSUPPORTED_BY_POSITION_MARKET: &[u8] = b"operation not supported by position markets";
+pub const ERROR_INVALID_POSITION_NONCE: &[u8] = b"invalid position nonce";
+pub const ERROR_INSUFFICIENT_POSITIONS: &[u8] = b"insufficient collateral positions";
+pub const ERROR_INVALID_DISPLAY_SYMBOL: &[u8] = b"invalid display symbol";
+pub const ERROR_INVALID_UNDERLYING_DECIMALS: &[u8] = b"invalid underlying decimals";
+pub const ERROR_INVALID_ICON_URI_HASH: &[u8] = b"invalid icon URI hash";
+pub const ERROR_INVALID_LISTING_TIMESTAMP: &[u8] = b"invalid listing timestamp";
+pub const ERROR_MISSING_MARKET_METADATA: &[u8] = b"missing market metadata";
+pub const ERROR_INVALID_LIQUIDATIONS_BATCH: &[u8] = b"invalid number of liquidations";
+pub const ERROR_INVALID_BORROWER: &[u8] = b"invalid borrower";
+pub const ERROR_INSUFFICIENT_LIQUIDATION_PAYMENT: &[u8] = b"insufficient payment for liquidation";
+pub const ERROR_ACCOUNT_NOT_LIQUIDATABLE: &[u8] = b"account cannot be liquidated";
+pub const ERROR_NOT_ENOUGH_COLLATERAL_TO_SEIZE: &[u8] = b"not enough collateral to seize";
+pub const ERROR_INVALID_LIQUIDATION_PRIORITY_WINDOW: &[u8] = b"invalid liquidation priority window";
+pub const ERROR_INVALID_MIN_LIQUIDATOR_BOND: &[u8] = b"invalid minimum liquidator bond";
+pub const ERROR_LIQUIDATOR_BONDS_DISABLED: &[u8] = b"liquidator bonds are not enabled";
+pub const ERROR_INSUFFICIENT_LIQUIDATOR_BOND: &[u8] = b"insufficient liquidator bond";
+pub const ERROR_LIQUIDATOR_UNBONDING: &[u8] = b"liquidator is unbonding";
+pub const ERROR_NOT_BONDED_LIQUIDATOR: &[u8] = b"not a bonded liquidator";
+pub const ERROR_LIQUIDATOR_NOT_UNBONDING: &[u8] = b"liquidator is not unbonding";
+pub const ERROR_LIQUIDATOR_BOND_LOCKED: &[u8] = b"liquidator bond still locked";
+pub const ERROR_SLASH_EXCEEDS_LIQUIDATOR_BOND: &[u8] = b"slash exceeds liquidator bond";
+pub const ERROR_LIQUIDATION_PRIORITY_WINDOW: &[u8] = b"liquidation reserved to bonded liquidators";
+pub const ERROR_INVALID_REWARDS_TOKEN_NONCE: &[u8] = b"invalid rewards token nonce";
+pub const ERROR_META_ESDT_REWARDS_NOT_SWAPPABLE: &[u8] = b"Meta-ESDT rewards cannot be swapped";
+pub const ERROR_META_ESDT_REWARDS_NOT_BOOSTABLE: &[u8] = b"Meta-ESDT rewards cannot be boosted";
@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use crate::storage::{RewardsBatch, RewardsBooster};
+use crate::storage::{LiquidationOutcome, MarketMetadata, PauseReason, RewardsBatch, RewardsBooster, RewardsTokenMetadata};
 
 #[multiversx_sc::module]
 pub trait EventModule {
@@ -8,6 +8,14 @@ pub trait EventModule {
     #[event("support_money_market_event")]
     fn support_money_market_event(&self, #[indexed] money_market: &ManagedAddress);
 
+    /// Emitted when a newly supported market is seeded with an initial deposit.
+    #[event("market_seeded_event")]
+    fn market_seeded_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] underlying_amount: &BigUint, #[indexed] tokens: &BigUint);
+
+    /// Emitted when the initial seed deposit requirement is updated.
+    #[event("new_seed_required_event")]
+    fn new_seed_required_event(&self, #[indexed] old_seed_required: bool, #[indexed] new_seed_required: bool);
+
     /// Emitted when an account enters a market, i.e. deposits tokens as collateral.
     #[event("enter_market_event")]
     fn enter_market_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] tokens: &BigUint);
@@ -20,10 +28,22 @@ pub trait EventModule {
     #[event("exit_market_and_redeem_event")]
     fn exit_market_and_redeem_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] redeemer: &ManagedAddress, #[indexed] underlying_payment: &EgldOrEsdtTokenPayment, #[indexed] token_payment: &EsdtTokenPayment);
 
+    /// Emitted when an account exits a market and redeems in one shot, sending the underlying to a different recipient.
+    #[event("exit_market_and_redeem_to_event")]
+    fn exit_market_and_redeem_to_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] redeemer: &ManagedAddress, #[indexed] recipient: &ManagedAddress, #[indexed] underlying_payment: &EgldOrEsdtTokenPayment, #[indexed] token_payment: &EsdtTokenPayment);
+
     /// Emitted when a new maximum number of markets that can be entered per account is set.
     #[event("new_max_markets_per_account_event")]
     fn new_max_markets_per_account_event(&self, #[indexed] old_max_markets_per_account: usize, #[indexed] new_max_markets_per_account: usize);
 
+    /// Emitted when a decrease of the maximum number of markets that can be entered per account is scheduled.
+    #[event("new_next_max_markets_per_account_event")]
+    fn new_next_max_markets_per_account_event(&self, #[indexed] timestamp: u64, #[indexed] next_max_markets_per_account: usize);
+
+    /// Emitted when a scheduled decrease of the maximum number of markets that can be entered per account is cleared.
+    #[event("clear_next_max_markets_per_account_event")]
+    fn clear_next_max_markets_per_account_event(&self);
+
     /// Emitted when a booster observer is set.
     #[event("set_booster_observer_event")]
     fn set_booster_observer_event(&self, #[indexed] rewards_booster: &ManagedAddress);
@@ -32,6 +52,23 @@ pub trait EventModule {
     #[event("clear_booster_observer_event")]
     fn clear_booster_observer_event(&self, #[indexed] rewards_booster: &ManagedAddress);
 
+    /// Emitted when the forced removal of the booster observer is scheduled.
+    #[event("schedule_force_clear_booster_observer_event")]
+    fn schedule_force_clear_booster_observer_event(&self, #[indexed] rewards_booster: &ManagedAddress, #[indexed] timestamp: u64);
+
+    /// Emitted when the scheduled forced removal of the booster observer is cancelled.
+    #[event("cancel_force_clear_booster_observer_event")]
+    fn cancel_force_clear_booster_observer_event(&self, #[indexed] rewards_booster: &ManagedAddress);
+
+    /// Emitted when the booster observer is forcibly cleared, together with the number of dropped market changes.
+    #[event("force_clear_booster_observer_event")]
+    fn force_clear_booster_observer_event(&self, #[indexed] rewards_booster: &ManagedAddress, #[indexed] dropped_market_changes: usize);
+
+    /// Emitted when pending market changes are notified to the booster observer in a single batch, together with the number
+    /// of market changes still pending.
+    #[event("market_changes_notified_event")]
+    fn market_changes_notified_event(&self, #[indexed] rewards_booster: &ManagedAddress, #[indexed] count: usize, #[indexed] remaining: usize);
+
     /// Emitted when a USH Market observer is set.
     #[event("set_ush_market_observer_event")]
     fn set_ush_market_observer_event(&self, #[indexed] ush_market: &ManagedAddress);
@@ -60,6 +97,14 @@ pub trait EventModule {
     #[event("new_price_oracle_event")]
     fn new_price_oracle_event(&self, #[indexed] old: &Option<ManagedAddress>, #[indexed] new: &ManagedAddress);
 
+    /// Emitted when a new price oracle is set for a given money market.
+    #[event("new_market_price_oracle_event")]
+    fn new_market_price_oracle_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: &Option<ManagedAddress>, #[indexed] new: &ManagedAddress);
+
+    /// Emitted when the price oracle of a given money market is removed, i.e. the global price oracle is used again.
+    #[event("market_price_oracle_removed_event")]
+    fn market_price_oracle_removed_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: &ManagedAddress);
+
     /// Emitted when a new liquidity cap is defined for a given money market.
     #[event("new_liquidity_cap_event")]
     fn new_liquidity_cap_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: &Option<BigUint>, #[indexed] new: &BigUint);
@@ -68,6 +113,42 @@ pub trait EventModule {
     #[event("new_borrow_cap_event")]
     fn new_borrow_cap_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: &Option<BigUint>, #[indexed] new: &BigUint);
 
+    /// Emitted when a new collateral cap is set.
+    #[event("new_collateral_cap_event")]
+    fn new_collateral_cap_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: &Option<BigUint>, #[indexed] new: &BigUint);
+
+    /// Emitted when a new maximum borrow size per transaction is set.
+    #[event("new_max_borrow_per_tx_event")]
+    fn new_max_borrow_per_tx_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: &Option<BigUint>, #[indexed] new: &BigUint);
+
+    /// Emitted when the maximum borrow size per transaction of a money market is removed.
+    #[event("remove_max_borrow_per_tx_event")]
+    fn remove_max_borrow_per_tx_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: &BigUint);
+
+    /// Emitted when a new borrow cap is set for an account at a given money market.
+    #[event("new_account_borrow_cap_event")]
+    fn new_account_borrow_cap_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] account: &ManagedAddress, #[indexed] old: &Option<BigUint>, #[indexed] new: &BigUint);
+
+    /// Emitted when the borrow cap of an account at a given money market is removed.
+    #[event("remove_account_borrow_cap_event")]
+    fn remove_account_borrow_cap_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] account: &ManagedAddress, #[indexed] old: &BigUint);
+
+    /// Emitted when a money market is flagged or unflagged as deprecated.
+    #[event("set_deprecated_event")]
+    fn set_deprecated_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] deprecated: bool);
+
+    /// Emitted when a new utilization cap is defined for a given money market.
+    #[event("new_utilization_cap_event")]
+    fn new_utilization_cap_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: &Option<BigUint>, #[indexed] new: &BigUint);
+
+    /// Emitted when a money market is assigned to a cap group or removed from it.
+    #[event("set_market_cap_group_event")]
+    fn set_market_cap_group_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old_group_id: usize, #[indexed] new_group_id: usize);
+
+    /// Emitted when a new cap group borrow cap is set.
+    #[event("new_cap_group_borrow_cap_event")]
+    fn new_cap_group_borrow_cap_event(&self, #[indexed] group_id: usize, #[indexed] old: &Option<BigUint>, #[indexed] new: &BigUint);
+
     /// Emitted when a new maximum amount of rewards batches is defined for a given money market.
     #[event("new_max_rewards_batches_event")]
     fn new_max_rewards_batches_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old: usize, #[indexed] new: usize);
@@ -76,10 +157,6 @@ pub trait EventModule {
     #[event("new_max_slippage_event")]
     fn new_max_slippage_event(&self, #[indexed] old: &BigUint, #[indexed] new: &BigUint);
 
-    /// Emitted when a new guardian is set.
-    #[event("new_pause_guardian_event")]
-    fn new_pause_guardian_event(&self, #[indexed] old: &Option<ManagedAddress>, #[indexed] new: &ManagedAddress);
-
     /// Emitted when a new rewards manager is set.
     #[event("new_rewards_manager_event")]
     fn new_rewards_manager_event(&self, #[indexed] old: &Option<ManagedAddress>, #[indexed] new: &ManagedAddress);
@@ -100,6 +177,30 @@ pub trait EventModule {
     #[event("global_seize_paused_event")]
     fn global_seize_paused_event(&self, #[indexed] paused: bool);
 
+    /// Emitted when the reason behind the mint status changes.
+    #[event("mint_pause_reason_event")]
+    fn mint_pause_reason_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] reason: PauseReason);
+
+    /// Emitted when the reason behind the borrow status changes.
+    #[event("borrow_pause_reason_event")]
+    fn borrow_pause_reason_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] reason: PauseReason);
+
+    /// Emitted when the reason behind the seize status changes.
+    #[event("seize_pause_reason_event")]
+    fn seize_pause_reason_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] reason: PauseReason);
+
+    /// Emitted when the reason behind the global seize status changes.
+    #[event("global_seize_pause_reason_event")]
+    fn global_seize_pause_reason_event(&self, #[indexed] reason: PauseReason);
+
+    /// Event emitted when interest accrual is frozen or unfrozen in all money markets.
+    #[event("interest_accrual_frozen_event")]
+    fn interest_accrual_frozen_event(&self, #[indexed] frozen: bool, #[indexed] frozen_until: u64);
+
+    /// Event emitted when rewards distribution is paused or resumed at a money market.
+    #[event("rewards_distribution_paused_event")]
+    fn rewards_distribution_paused_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] paused: bool);
+
     /// Event emitted when supplier rewards are distributed.
     #[event("supplier_rewards_distributed_event")]
     fn supplier_rewards_distributed_event(&self, #[indexed] supplier: &ManagedAddress, #[indexed] rewards_batch: &RewardsBatch<Self::Api>, #[indexed] delta_rewards: &BigUint);
@@ -116,10 +217,38 @@ pub trait EventModule {
     #[event("rewards_token_claimed_event")]
     fn rewards_token_claimed_event(&self, #[indexed] claimer: &ManagedAddress, #[indexed] rewards_token_id: &EgldOrEsdtTokenIdentifier, #[indexed] claimed_amount: &BigUint);
 
+    /// Event emitted when an account grants or revokes its consent for rewards claims initiated by third parties.
+    #[event("rewards_auto_claim_consent_event")]
+    fn rewards_auto_claim_consent_event(&self, #[indexed] account: &ManagedAddress, #[indexed] consent: bool);
+
+    /// Emitted when an account opts in or out from automatically entering markets with freshly minted Hatom tokens.
+    #[event("auto_enter_markets_event")]
+    fn auto_enter_markets_event(&self, #[indexed] account: &ManagedAddress, #[indexed] enabled: bool);
+
+    /// Emitted when an account opts in or out from receiving native EGLD instead of WEGLD from redemptions and claims.
+    #[event("native_egld_event")]
+    fn native_egld_event(&self, #[indexed] account: &ManagedAddress, #[indexed] enabled: bool);
+
+    /// Emitted when an account deposits native EGLD, which is minted and entered as collateral in a given money market.
+    #[event("enter_market_with_egld_event")]
+    fn enter_market_with_egld_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] account: &ManagedAddress, #[indexed] egld_amount: &BigUint, #[indexed] token_payment: &EsdtTokenPayment);
+
+    /// Event emitted when a rewards snapshot is taken.
+    #[event("rewards_snapshot_event")]
+    fn rewards_snapshot_event(&self, #[indexed] snapshot_id: usize, #[indexed] timestamp: u64);
+
+    /// Event emitted when the rewards batches indexes of a money market are migrated to the wad * wad * wad precision.
+    #[event("rewards_indexes_migrated_event")]
+    fn rewards_indexes_migrated_event(&self, #[indexed] money_market: &ManagedAddress);
+
     /// Event emitted when a rewards batch is set.
     #[event("set_rewards_batch_event")]
     fn set_rewards_batch_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] rewards_batch: &RewardsBatch<Self::Api>);
 
+    /// Event emitted when a per-account rewards cap is set for a rewards batch.
+    #[event("set_rewards_batch_account_cap_event")]
+    fn set_rewards_batch_account_cap_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] batch_id: usize, #[indexed] cap: &BigUint);
+
     /// Event emitted when a rewards batch adds more rewards.
     #[event("add_rewards_batch_event")]
     fn add_rewards_batch_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] rewards_batch: &RewardsBatch<Self::Api>);
@@ -132,6 +261,10 @@ pub trait EventModule {
     #[event("remove_rewards_batch_event")]
     fn remove_rewards_batch_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] batch_id: usize);
 
+    /// Event emitted when several rewards batches are merged into a single one.
+    #[event("merge_rewards_batches_event")]
+    fn merge_rewards_batches_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] rewards_batch: &RewardsBatch<Self::Api>, #[indexed] merged_batch_ids: &ManagedVec<usize>);
+
     /// Event emitted when the rewards batch speed is updated.
     #[event("update_rewards_batch_speed_event")]
     fn update_rewards_batch_speed_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] rewards_batch: &RewardsBatch<Self::Api>);
@@ -144,6 +277,10 @@ pub trait EventModule {
     #[event("claim_undistributed_rewards_event")]
     fn claim_undistributed_rewards_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] rewards_token_id: &EgldOrEsdtTokenIdentifier, #[indexed] claimed_amount: &BigUint);
 
+    /// Event emitted when tokens sent by mistake are rescued.
+    #[event("rescue_token_event")]
+    fn rescue_token_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] to: &ManagedAddress, #[indexed] token_id: &EgldOrEsdtTokenIdentifier, #[indexed] amount: &BigUint);
+
     /// Event emitted when the supply rewards batch index is updated.
     #[event("supply_rewards_batches_updated_event")]
     fn supply_rewards_batches_updated_event(&self, #[indexed] rewards_batch: &RewardsBatch<Self::Api>);
@@ -179,4 +316,137 @@ pub trait EventModule {
     /// Event emitted when boosted rewards are claimed.
     #[event("boosted_rewards_claimed_event")]
     fn boosted_rewards_claimed_event(&self, #[indexed] claimer: &ManagedAddress, #[indexed] rewards_batch_booster: &RewardsBooster<Self::Api>, #[indexed] claimed_amount: &BigUint);
+
+    /// Emitted when boosted rewards are paid out in governance tokens, along with their value in EGLD as given by the price
+    /// oracle.
+    #[event("boosted_rewards_valued_event")]
+    fn boosted_rewards_valued_event(&self, #[indexed] claimer: &ManagedAddress, #[indexed] token_id: &EgldOrEsdtTokenIdentifier, #[indexed] governance_token_amount: &BigUint, #[indexed] egld_value: &BigUint);
+
+    /// Emitted when the metadata of a rewards token is registered.
+    #[event("rewards_token_registered_event")]
+    fn rewards_token_registered_event(&self, #[indexed] metadata: &RewardsTokenMetadata<Self::Api>);
+
+    /// Emitted when the number of decimals of a rewards token is resolved.
+    #[event("rewards_token_decimals_resolved_event")]
+    fn rewards_token_decimals_resolved_event(&self, #[indexed] metadata: &RewardsTokenMetadata<Self::Api>);
+
+    /// Emitted when a new heartbeat interval is set.
+    #[event("new_heartbeat_interval_event")]
+    fn new_heartbeat_interval_event(&self, #[indexed] old_interval: u64, #[indexed] new_interval: u64);
+
+    /// Emitted for each whitelisted money market on every protocol heartbeat.
+    #[event("market_heartbeat_event")]
+    fn market_heartbeat_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] liquidity: &BigUint, #[indexed] total_borrows: &BigUint, #[indexed] total_reserves: &BigUint);
+
+    /// Emitted on every protocol heartbeat, after all the market heartbeats.
+    #[event("protocol_heartbeat_event")]
+    fn protocol_heartbeat_event(&self, #[indexed] timestamp: u64, #[indexed] markets: usize);
+
+    /// Emitted when bulk enumeration views are restricted or opened.
+    #[event("enumeration_restricted_event")]
+    fn enumeration_restricted_event(&self, #[indexed] restricted: bool);
+
+    /// Emitted when a reader of bulk enumeration views is registered.
+    #[event("register_reader_event")]
+    fn register_reader_event(&self, #[indexed] reader: &ManagedAddress);
+
+    /// Emitted when a reader of bulk enumeration views is unregistered.
+    #[event("unregister_reader_event")]
+    fn unregister_reader_event(&self, #[indexed] reader: &ManagedAddress);
+
+    /// Emitted when a token is supported for repayments with automatic swap.
+    #[event("support_repay_swap_token_event")]
+    fn support_repay_swap_token_event(&self, #[indexed] token_id: &TokenIdentifier);
+
+    /// Emitted when a token is no longer supported for repayments with automatic swap.
+    #[event("unsupport_repay_swap_token_event")]
+    fn unsupport_repay_swap_token_event(&self, #[indexed] token_id: &TokenIdentifier);
+
+    /// Emitted when a borrow is repaid with a different token, which has been swapped into the borrow market underlying.
+    #[event("repay_with_any_token_event")]
+    fn repay_with_any_token_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] payer: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] token_payment: &EsdtTokenPayment, #[indexed] repayment: &EgldOrEsdtTokenPayment, #[indexed] refund: &EgldOrEsdtTokenPayment);
+
+    /// Emitted when an account uses its rewards to mint and enter a given money market as collateral.
+    #[event("rewards_collateralized_event")]
+    fn rewards_collateralized_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] account: &ManagedAddress, #[indexed] underlying_amount: &BigUint, #[indexed] token_payment: &EsdtTokenPayment);
+
+    /// Emitted when an orphaned account is pruned from the members of a money market.
+    #[event("prune_market_member_event")]
+    fn prune_market_member_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] account: &ManagedAddress);
+
+    /// Emitted when the USD value per day of a rewards batch is set or cleared.
+    #[event("set_rewards_batch_usd_per_day_event")]
+    fn set_rewards_batch_usd_per_day_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] usd_per_day: &BigUint, #[indexed] rewards_batch: &RewardsBatch<Self::Api>);
+
+    /// Emitted when a rewards batch denominated in USD is repriced.
+    #[event("reprice_rewards_batch_event")]
+    fn reprice_rewards_batch_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] rewards_batch: &RewardsBatch<Self::Api>);
+
+    /// Emitted when a rewards batch denominated in USD cannot be repriced, such that it keeps its current speed.
+    #[event("rewards_batch_reprice_failed_event")]
+    fn rewards_batch_reprice_failed_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] batch_id: usize, #[indexed] speed: &BigUint);
+
+    /// Emitted when a new position market is supported, i.e. a supply-only market backed by meta-ESDT positions.
+    #[event("support_position_market_event")]
+    fn support_position_market_event(&self, #[indexed] money_market: &ManagedAddress);
+
+    /// Emitted when a meta-ESDT position is deposited as collateral at a position market.
+    #[event("enter_market_position_event")]
+    fn enter_market_position_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] account: &ManagedAddress, #[indexed] nonce: u64, #[indexed] amount: &BigUint);
+
+    /// Emitted when meta-ESDT positions are withdrawn from collateral at a position market.
+    #[event("exit_market_positions_event")]
+    fn exit_market_positions_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] account: &ManagedAddress, #[indexed] positions: &ManagedVec<EsdtTokenPayment>);
+
+    /// Emitted when meta-ESDT positions are seized from a borrower in favor of a liquidator at a position market.
+    #[event("seize_positions_event")]
+    fn seize_positions_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] liquidator: &ManagedAddress, #[indexed] positions: &ManagedVec<EsdtTokenPayment>);
+
+    /// Emitted when the listing metadata of a money market is set.
+    #[event("set_market_metadata_event")]
+    fn set_market_metadata_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] metadata: &MarketMetadata<Self::Api>);
+
+    /// Emitted when the listing metadata of a money market is cleared.
+    #[event("clear_market_metadata_event")]
+    fn clear_market_metadata_event(&self, #[indexed] money_market: &ManagedAddress);
+
+    /// Emitted for each item of a batch liquidation, whether it has been performed or skipped.
+    #[event("batch_liquidation_event")]
+    fn batch_liquidation_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] outcome: &LiquidationOutcome<Self::Api>);
+
+    /// Emitted when the liquidation priority window is updated.
+    #[event("new_liquidation_priority_window_event")]
+    fn new_liquidation_priority_window_event(&self, #[indexed] old_window: u64, #[indexed] new_window: u64);
+
+    /// Emitted when the minimum liquidator bond is updated.
+    #[event("new_min_liquidator_bond_event")]
+    fn new_min_liquidator_bond_event(&self, #[indexed] old_min_bond: &BigUint, #[indexed] new_min_bond: &BigUint);
+
+    /// Emitted when a liquidator adds to its bond.
+    #[event("liquidator_bond_event")]
+    fn liquidator_bond_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] new_bond: &BigUint);
+
+    /// Emitted when a liquidator starts unbonding.
+    #[event("liquidator_unbond_event")]
+    fn liquidator_unbond_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] timestamp: u64);
+
+    /// Emitted when a liquidator withdraws its bond.
+    #[event("liquidator_bond_withdrawal_event")]
+    fn liquidator_bond_withdrawal_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] amount: &BigUint);
+
+    /// Emitted when the bond of a liquidator is slashed.
+    #[event("slash_liquidator_event")]
+    fn slash_liquidator_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] new_bond: &BigUint);
+
+    /// Emitted when an account is first detected as liquidatable.
+    #[event("liquidatable_account_event")]
+    fn liquidatable_account_event(&self, #[indexed] account: &ManagedAddress, #[indexed] timestamp: u64);
+
+    /// Emitted when the liquidatable detection of an account is cleared.
+    #[event("clear_liquidatable_account_event")]
+    fn clear_liquidatable_account_event(&self, #[indexed] account: &ManagedAddress);
+
+    /// Emitted when a Meta-ESDT rewards token is bound to a nonce.
+    #[event("rewards_token_nonce_event")]
+    fn rewards_token_nonce_event(&self, #[indexed] token_id: &EgldOrEsdtTokenIdentifier, #[indexed] nonce: u64);
 }
@@ -3,7 +3,7 @@ multiversx_sc::derive_imports!();
 
 use super::{constants::*, errors::*, events, guardian, policies, proxies, rewards, risk_profile, shared, storage};
 
-use crate::storage::{MarketType, RewardsBatch, RewardsBooster, State, SwapStep};
+use crate::storage::{MarketMetadata, MarketType, RewardsBatch, RewardsBooster, State, SwapStep};
 
 #[multiversx_sc::module]
 pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian::GuardianModule + policies::PolicyModule + proxies::ProxyModule + rewards::RewardsModule + risk_profile::RiskProfileModule + shared::SharedModule + storage::StorageModule {
@@ -17,16 +17,64 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// # Notes:
     ///
     /// - Can only be called by the admin.
-    /// - The provided address must be a valid money market smart contract.
+    /// - The provided address must be a valid money market smart contract, other than a position market (which must be
+    ///   supported via `supportPositionMarket`).
     /// - The money market should not has already been supported in the past.
+    /// - Can be paid with the money market underlying, in which case it is deposited as an initial seed and the minted
+    ///   Hatom tokens are locked forever in the controller (dead shares).
+    /// - If seeding is required, a non-zero underlying payment must be provided.
     ///
+    #[payable("*")]
     #[endpoint(supportMarket)]
     fn support_market(&self, money_market: &ManagedAddress) {
         self.require_admin();
 
-        // must be a money market smart contract
+        let underlying_payment = self.call_value().egld_or_single_esdt();
+        let seeded = underlying_payment.amount > BigUint::zero();
+        require!(seeded || !self.seed_required().get(), ERROR_MISSING_INITIAL_SEED);
+
+        // must be a money market smart contract, other than a position market
         require!(self.is_money_market_sc(money_market), ERROR_INVALID_MONEY_MARKET_SC);
+        require!(!self.has_position_id(money_market), ERROR_UNSUPPORTED_BY_POSITION_MARKET);
+
+        self.support_market_internal(money_market);
+
+        if seeded {
+            self.seed_market(money_market, &underlying_payment);
+        }
+
+        self.sync_market_mirror(money_market);
+    }
+
+    /// Incorporates a position market in the list of accepted money markets (a whitelist). A position market is a
+    /// supply-only market whose collateral tokens are meta-ESDT positions (e.g. staked LP positions), which are accounted
+    /// per nonce.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the position market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a valid position market smart contract.
+    /// - The position market should not has already been supported in the past.
+    /// - Position markets cannot be seeded, given that they cannot be minted.
+    ///
+    #[endpoint(supportPositionMarket)]
+    fn support_position_market(&self, money_market: &ManagedAddress) {
+        self.require_admin();
+
+        // must be a position market smart contract
+        require!(self.is_money_market_sc(money_market) && self.is_position_market_sc(money_market), ERROR_INVALID_POSITION_MARKET_SC);
+
+        self.support_market_internal(money_market);
+        self.position_market(money_market).set(true);
+
+        self.support_position_market_event(money_market);
+    }
 
+    fn support_market_internal(&self, money_market: &ManagedAddress) {
         // should not be supported
         require!(!self.is_whitelisted_money_market(money_market), ERROR_ALREADY_SUPPORTED_MARKET);
 
@@ -50,6 +98,94 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         self.support_money_market_event(money_market);
     }
 
+    /// Sets whether an initial seed deposit is required when supporting a new money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `seed_required` - Whether the initial seed deposit is required or not.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(setSeedRequired)]
+    fn set_seed_required(&self, seed_required: bool) {
+        self.require_admin();
+
+        let old_seed_required = self.seed_required().get();
+        self.seed_required().set(seed_required);
+
+        self.new_seed_required_event(old_seed_required, seed_required);
+    }
+
+    /// Sets the listing metadata of a given money market, such that frontends and aggregators can display it without
+    /// depending on an off-chain configuration.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `display_symbol` - The symbol used to display the money market.
+    /// - `underlying_decimals` - The number of decimals of the money market underlying.
+    /// - `icon_uri_hash` - The sha256 hash of the money market icon URI. Can be empty.
+    /// - `listed_at` - The listing timestamp. If zero, the current timestamp is used.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - Overrides any previous metadata.
+    ///
+    #[endpoint(setMarketMetadata)]
+    fn set_market_metadata(&self, money_market: &ManagedAddress, display_symbol: ManagedBuffer, underlying_decimals: u8, icon_uri_hash: ManagedBuffer, listed_at: u64) {
+        self.require_admin();
+        self.require_whitelisted_money_market(money_market);
+
+        let symbol_length = display_symbol.len();
+        require!(symbol_length > 0 && symbol_length <= MAX_DISPLAY_SYMBOL_LENGTH, ERROR_INVALID_DISPLAY_SYMBOL);
+        require!(underlying_decimals <= MAX_UNDERLYING_DECIMALS, ERROR_INVALID_UNDERLYING_DECIMALS);
+        require!(icon_uri_hash.is_empty() || icon_uri_hash.len() == ICON_URI_HASH_LENGTH, ERROR_INVALID_ICON_URI_HASH);
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        require!(listed_at <= timestamp, ERROR_INVALID_LISTING_TIMESTAMP);
+        let listed_at = if listed_at == 0 { timestamp } else { listed_at };
+
+        let metadata = MarketMetadata { display_symbol, underlying_decimals, icon_uri_hash, listed_at };
+        self.market_metadata(money_market).set(&metadata);
+
+        self.set_market_metadata_event(money_market, &metadata);
+    }
+
+    /// Clears the listing metadata of a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(clearMarketMetadata)]
+    fn clear_market_metadata(&self, money_market: &ManagedAddress) {
+        self.require_admin();
+        require!(!self.market_metadata(money_market).is_empty(), ERROR_MISSING_MARKET_METADATA);
+
+        self.market_metadata(money_market).clear();
+
+        self.clear_market_metadata_event(money_market);
+    }
+
+    fn seed_market(&self, money_market: &ManagedAddress, underlying_payment: &EgldOrEsdtTokenPayment) {
+        let (underlying_id, _) = self.identifiers(money_market).get();
+        require!(underlying_payment.token_identifier == underlying_id, ERROR_INVALID_SEED_PAYMENT);
+
+        // the minted tokens remain in the controller forever
+        let token_payment = self.mint(money_market, underlying_payment);
+        self.market_seed_tokens(money_market).update(|tokens| *tokens += &token_payment.amount);
+
+        self.market_seeded_event(money_market, &underlying_payment.amount, &token_payment.amount);
+    }
+
     /// Sets the maximum number of money markets that can be entered per account.
     ///
     /// # Arguments:
@@ -59,7 +195,8 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// # Notes:
     ///
     /// - Can only be called by the admin.
-    /// - Must be higher than the current maximum.
+    /// - Must be higher than the current maximum. Decreases are handled by `decreaseMaxMarketsPerAccount`.
+    /// - Clears any scheduled decrease.
     ///
     #[endpoint(setMaxMarketsPerAccount)]
     fn set_max_markets_per_account(&self, new_max_markets_per_account: usize) {
@@ -67,6 +204,48 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         self.set_max_markets_per_account_internal(new_max_markets_per_account);
     }
 
+    /// Schedules a decrease of the maximum number of money markets that can be entered per account, which applies after a
+    /// timelock.
+    ///
+    /// # Arguments:
+    ///
+    /// - `next_max_markets_per_account` - The next maximum number of money markets that can be entered per account.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Must be lower than the current maximum and higher than zero.
+    /// - Only applies to new market entries. Accounts that already entered more markets keep them, but cannot enter new
+    ///   ones until they are below the new maximum.
+    /// - Replaces any previously scheduled decrease.
+    ///
+    #[endpoint(decreaseMaxMarketsPerAccount)]
+    fn decrease_max_markets_per_account(&self, next_max_markets_per_account: usize) {
+        self.require_admin();
+
+        let max_markets_per_account = self.update_and_get_max_markets_per_account();
+        require!(next_max_markets_per_account > 0, ERROR_MAX_MARKETS_TOO_LOW);
+        require!(next_max_markets_per_account < max_markets_per_account, ERROR_MAX_MARKETS_NOT_DECREASED);
+
+        let timestamp = self.blockchain().get_block_timestamp() + TIMELOCK_MAX_MARKETS_PER_ACCOUNT_DECREASE;
+        self.next_max_markets_per_account().set((timestamp, next_max_markets_per_account));
+        self.new_next_max_markets_per_account_event(timestamp, next_max_markets_per_account);
+    }
+
+    /// Cancels a scheduled decrease of the maximum number of money markets that can be entered per account.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(cancelMaxMarketsPerAccountDecrease)]
+    fn cancel_max_markets_per_account_decrease(&self) {
+        self.require_admin();
+        require!(!self.next_max_markets_per_account().is_empty(), ERROR_NO_NEXT_MAX_MARKETS);
+        self.next_max_markets_per_account().clear();
+        self.clear_next_max_markets_per_account_event();
+    }
+
     /// Sets the collateral factors or loan to values for a given money market.
     ///
     /// # Arguments:
@@ -137,6 +316,8 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
             self.ush_borrower_collateral_factor(money_market).set(new_uf);
             self.new_ush_borrower_collateral_factor_event(money_market, &uf, new_uf);
         }
+
+        self.sync_market_mirror(money_market);
     }
 
     /// Sets the pricing Oracle smart contract address.
@@ -167,6 +348,64 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         self.new_price_oracle_event(&old_price_oracle_address, new_price_oracle);
     }
 
+    /// Sets a pricing Oracle smart contract address for a given money market, which overrides the global pricing Oracle
+    /// for that market's underlying.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `new_price_oracle` - The address of the pricing oracle smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a valid oracle smart contract.
+    /// - The new oracle must be able to price the money market underlying.
+    ///
+    #[endpoint(setMarketPriceOracle)]
+    fn set_market_price_oracle(&self, money_market: &ManagedAddress, new_price_oracle: &ManagedAddress) {
+        self.require_admin();
+        self.require_whitelisted_money_market(money_market);
+
+        require!(self.is_price_oracle_sc(new_price_oracle), ERROR_INVALID_ORACLE_SC);
+
+        let old_price_oracle_address = self.get_market_price_oracle(money_market);
+        self.market_price_oracle(money_market).set(new_price_oracle);
+
+        // make sure it can price the market
+        self.get_underlying_price(money_market);
+
+        self.new_market_price_oracle_event(money_market, &old_price_oracle_address, new_price_oracle);
+    }
+
+    /// Removes the pricing Oracle smart contract address of a given money market, such that the global pricing Oracle is
+    /// used again for that market's underlying.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The global oracle must be able to price the money market underlying.
+    ///
+    #[endpoint(removeMarketPriceOracle)]
+    fn remove_market_price_oracle(&self, money_market: &ManagedAddress) {
+        self.require_admin();
+
+        let old_price_oracle_address = match self.get_market_price_oracle(money_market) {
+            Some(address) => address,
+            None => sc_panic!(ERROR_MARKET_PRICE_ORACLE_UNSET),
+        };
+        self.market_price_oracle(money_market).clear();
+
+        // make sure the global oracle can price the market
+        self.get_underlying_price(money_market);
+
+        self.market_price_oracle_removed_event(money_market, &old_price_oracle_address);
+    }
+
     /// Sets a liquidity cap for a given money market.
     ///
     /// # Arguments:
@@ -186,6 +425,8 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         let old_liquidity_cap = self.get_liquidity_cap(money_market);
         self.liquidity_cap(money_market).set(new_liquidity_cap);
         self.new_liquidity_cap_event(money_market, &old_liquidity_cap, new_liquidity_cap);
+
+        self.sync_market_mirror(money_market);
     }
 
     /// Sets a borrow cap for a given money market.
@@ -207,6 +448,251 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         let old_borrow_cap = self.get_borrow_cap(money_market);
         self.borrow_cap(money_market).set(new_borrow_cap);
         self.new_borrow_cap_event(money_market, &old_borrow_cap, new_borrow_cap);
+
+        self.sync_market_mirror(money_market);
+    }
+
+    /// Sets a collateral cap for a given money market, i.e. the maximum amount of underlying that can be deposited as
+    /// collateral into the controller, regardless of the money market liquidity.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `new_collateral_cap` - The new collateral cap, in underlying units.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - Lowering the cap below the current collateral does not affect existing deposits, but prevents new ones.
+    ///
+    #[endpoint(setCollateralCap)]
+    fn set_collateral_cap(&self, money_market: &ManagedAddress, new_collateral_cap: &BigUint) {
+        self.require_admin();
+        self.require_whitelisted_money_market(money_market);
+        let old_collateral_cap = self.get_collateral_cap(money_market);
+        self.collateral_cap(money_market).set(new_collateral_cap);
+        self.new_collateral_cap_event(money_market, &old_collateral_cap, new_collateral_cap);
+    }
+
+    /// Sets the maximum amount of underlying that can be borrowed from a given money market in a single transaction,
+    /// regardless of the account borrowing limits. It throttles giant borrows that might stress the oracle and the market
+    /// liquidity within a single block.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `new_max_borrow_per_tx` - The new maximum borrow amount per transaction.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    ///
+    #[endpoint(setMaxBorrowPerTx)]
+    fn set_max_borrow_per_tx(&self, money_market: &ManagedAddress, new_max_borrow_per_tx: &BigUint) {
+        self.require_admin();
+        self.require_whitelisted_money_market(money_market);
+        require!(new_max_borrow_per_tx > &BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        let old_max_borrow_per_tx = self.get_max_borrow_per_tx(money_market);
+        self.max_borrow_per_tx(money_market).set(new_max_borrow_per_tx);
+        self.new_max_borrow_per_tx_event(money_market, &old_max_borrow_per_tx, new_max_borrow_per_tx);
+    }
+
+    /// Removes the maximum borrow size per transaction of a given money market, such that borrows are only bounded by the
+    /// account borrowing limits and the market caps.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(removeMaxBorrowPerTx)]
+    fn remove_max_borrow_per_tx(&self, money_market: &ManagedAddress) {
+        self.require_admin();
+        let old_max_borrow_per_tx = match self.get_max_borrow_per_tx(money_market) {
+            Some(max_borrow_per_tx) => max_borrow_per_tx,
+            None => sc_panic!(ERROR_MISSING_MAX_BORROW_PER_TX),
+        };
+        self.max_borrow_per_tx(money_market).clear();
+        self.remove_max_borrow_per_tx_event(money_market, &old_max_borrow_per_tx);
+    }
+
+    /// Sets a borrow cap for a given account at a given money market, i.e. the maximum amount of underlying the account can
+    /// owe to the money market, on top of the money market borrow cap.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The address of the account.
+    /// - `new_account_borrow_cap` - The new account borrow cap.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - Lowering the cap below the current account borrows does not affect existing borrows, but prevents new ones.
+    ///
+    #[endpoint(setAccountBorrowCap)]
+    fn set_account_borrow_cap(&self, money_market: &ManagedAddress, account: &ManagedAddress, new_account_borrow_cap: &BigUint) {
+        self.require_admin();
+        self.require_whitelisted_money_market(money_market);
+        let old_account_borrow_cap = self.get_account_borrow_cap(money_market, account);
+        self.account_borrow_cap(money_market, account).set(new_account_borrow_cap);
+        self.new_account_borrow_cap_event(money_market, account, &old_account_borrow_cap, new_account_borrow_cap);
+    }
+
+    /// Removes the borrow cap of a given account at a given money market, such that only the money market caps apply.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The address of the account.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(removeAccountBorrowCap)]
+    fn remove_account_borrow_cap(&self, money_market: &ManagedAddress, account: &ManagedAddress) {
+        self.require_admin();
+        let old_account_borrow_cap = match self.get_account_borrow_cap(money_market, account) {
+            Some(cap) => cap,
+            None => sc_panic!(ERROR_MISSING_ACCOUNT_BORROW_CAP),
+        };
+        self.account_borrow_cap(money_market, account).clear();
+        self.remove_account_borrow_cap_event(money_market, account, &old_account_borrow_cap);
+    }
+
+    /// Sets a utilization cap for a given money market, i.e. the maximum ratio between borrows and liquidity beyond which
+    /// new borrows are rejected.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `new_utilization_cap` - The new utilization cap in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - The utilization cap cannot exceed 100%.
+    ///
+    #[endpoint(setUtilizationCap)]
+    fn set_utilization_cap(&self, money_market: &ManagedAddress, new_utilization_cap: &BigUint) {
+        self.require_admin();
+        self.require_whitelisted_money_market(money_market);
+        require!(new_utilization_cap <= &BigUint::from(WAD), ERROR_UTILIZATION_CAP_TOO_HIGH);
+        let old_utilization_cap = self.get_utilization_cap(money_market);
+        self.utilization_cap(money_market).set(new_utilization_cap);
+        self.new_utilization_cap_event(money_market, &old_utilization_cap, new_utilization_cap);
+    }
+
+    /// Assigns a money market to a cap group, i.e. a group of money markets with correlated risk that share a combined
+    /// borrow cap.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `group_id` - The cap group identifier, which starts at 1. If zero, the money market is removed from its cap group.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - A money market can only belong to a single cap group.
+    ///
+    #[endpoint(setMarketCapGroup)]
+    fn set_market_cap_group(&self, money_market: &ManagedAddress, group_id: usize) {
+        self.require_admin();
+        self.require_whitelisted_money_market(money_market);
+
+        let market_cap_group_mapper = self.market_cap_group(money_market);
+        let old_group_id = market_cap_group_mapper.get();
+        require!(old_group_id != group_id, ERROR_INVALID_CAP_GROUP);
+
+        if old_group_id != 0 {
+            self.cap_group_markets(old_group_id).swap_remove(money_market);
+        }
+
+        if group_id == 0 {
+            market_cap_group_mapper.clear();
+        } else {
+            self.cap_group_markets(group_id).insert(money_market.clone());
+            market_cap_group_mapper.set(group_id);
+        }
+
+        self.set_market_cap_group_event(money_market, old_group_id, group_id);
+    }
+
+    /// Sets a combined borrow cap for a given cap group.
+    ///
+    /// # Arguments:
+    ///
+    /// - `group_id` - The cap group identifier, which starts at 1.
+    /// - `new_borrow_cap` - The new combined borrow cap in EGLD and in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrows from all the money markets in the cap group are valued using the price oracle.
+    ///
+    #[endpoint(setCapGroupBorrowCap)]
+    fn set_cap_group_borrow_cap(&self, group_id: usize, new_borrow_cap: &BigUint) {
+        self.require_admin();
+        require!(group_id > 0, ERROR_INVALID_CAP_GROUP);
+        let old_borrow_cap = self.get_cap_group_borrow_cap(group_id);
+        self.cap_group_borrow_cap(group_id).set(new_borrow_cap);
+        self.new_cap_group_borrow_cap_event(group_id, &old_borrow_cap, new_borrow_cap);
+    }
+
+    /// Flags or unflags a money market as deprecated. Temporary borrow pauses never deprecate a money market by themselves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `deprecated` - Whether the money market is deprecated.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The provided address must be a whitelisted money market.
+    /// - A flagged money market is only considered deprecated once its collateral factor is zero, its borrows are paused
+    ///   and its reserve factor is 100%.
+    ///
+    #[endpoint(setDeprecated)]
+    fn set_deprecated(&self, money_market: &ManagedAddress, deprecated: bool) {
+        self.require_admin();
+        self.require_whitelisted_money_market(money_market);
+        self.deprecated(money_market).set(deprecated);
+        self.set_deprecated_event(money_market, deprecated);
+
+        self.sync_market_mirror(money_market);
+    }
+
+    /// Pushes the Controller-derived values of the given money markets to them, e.g. to backfill money markets supported
+    /// before they mirrored the Controller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_markets` - The money market addresses to sync. If empty, all whitelisted markets will be used.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Position markets and USH money markets do not mirror the Controller and are skipped.
+    ///
+    #[endpoint(syncMarketMirrors)]
+    fn sync_market_mirrors(&self, money_markets: ManagedVec<ManagedAddress>) {
+        self.require_admin();
+
+        let markets = self.validate_money_markets(money_markets);
+        for money_market in markets.iter() {
+            self.sync_market_mirror(&money_market);
+        }
     }
 
     /// Sets the maximum amount of rewards batches per money market.
@@ -226,34 +712,80 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         self.require_admin();
         self.require_whitelisted_money_market(money_market);
 
-        let old_max_rewards_batches = self.max_rewards_batches(money_market).get();
-        require!(new_max_rewards_batches <= MAX_REWARDS_BATCHES, ERROR_MAX_REWARDS_BATCHES_TOO_HIGH);
-        require!(new_max_rewards_batches > old_max_rewards_batches, ERROR_MAX_REWARDS_BATCHES_TOO_LOW);
+        let old_max_rewards_batches = self.max_rewards_batches(money_market).get();
+        require!(new_max_rewards_batches <= MAX_REWARDS_BATCHES, ERROR_MAX_REWARDS_BATCHES_TOO_HIGH);
+        require!(new_max_rewards_batches > old_max_rewards_batches, ERROR_MAX_REWARDS_BATCHES_TOO_LOW);
+
+        self.max_rewards_batches(money_market).set(new_max_rewards_batches);
+
+        self.new_max_rewards_batches_event(money_market, old_max_rewards_batches, new_max_rewards_batches);
+    }
+
+    /// Sets the maximum slippage allowed for configuration swaps.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_max_slippage` - The new maximum slippage allowed.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(setMaxSlippage)]
+    fn set_max_slippage(&self, new_max_slippage: &BigUint) {
+        self.require_admin();
+
+        let old_max_slippage = self.max_slippage().get();
+        require!(new_max_slippage <= &BigUint::from(MAX_SLIPPAGE), ERROR_MAX_SLIPPAGE_TOO_HIGH);
+        self.max_slippage().set(new_max_slippage);
+
+        self.new_max_slippage_event(&old_max_slippage, new_max_slippage);
+    }
+
+    /// Supports a token for borrow repayments with automatic swap, i.e. a token that can be swapped into the borrow market
+    /// underlying at the `repayWithAnyToken` endpoint.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The price oracle must be able to price the token, as swaps are checked against oracle values.
+    ///
+    #[endpoint(supportRepaySwapToken)]
+    fn support_repay_swap_token(&self, token_id: &TokenIdentifier) {
+        self.require_admin();
+
+        require!(token_id.is_valid_esdt_identifier(), ERROR_INVALID_REPAY_SWAP_TOKEN);
+        require!(!self.repay_swap_tokens().contains(token_id), ERROR_REPAY_SWAP_TOKEN_ALREADY_SUPPORTED);
 
-        self.max_rewards_batches(money_market).set(new_max_rewards_batches);
+        // make sure the token can be priced
+        self.get_rewards_token_price(&EgldOrEsdtTokenIdentifier::esdt(token_id.clone()));
 
-        self.new_max_rewards_batches_event(money_market, old_max_rewards_batches, new_max_rewards_batches);
+        self.repay_swap_tokens().insert(token_id.clone());
+
+        self.support_repay_swap_token_event(token_id);
     }
 
-    /// Sets the maximum slippage allowed for configuration swaps.
+    /// Removes the support of a token for borrow repayments with automatic swap.
     ///
     /// # Arguments:
     ///
-    /// - `new_max_slippage` - The new maximum slippage allowed.
+    /// - `token_id` - The token identifier.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
     ///
-    #[endpoint(setMaxSlippage)]
-    fn set_max_slippage(&self, new_max_slippage: &BigUint) {
+    #[endpoint(unsupportRepaySwapToken)]
+    fn unsupport_repay_swap_token(&self, token_id: &TokenIdentifier) {
         self.require_admin();
 
-        let old_max_slippage = self.max_slippage().get();
-        require!(new_max_slippage <= &BigUint::from(MAX_SLIPPAGE), ERROR_MAX_SLIPPAGE_TOO_HIGH);
-        self.max_slippage().set(new_max_slippage);
+        require!(self.repay_swap_tokens().swap_remove(token_id), ERROR_INVALID_REPAY_SWAP_TOKEN);
 
-        self.new_max_slippage_event(&old_max_slippage, new_max_slippage);
+        self.unsupport_repay_swap_token_event(token_id);
     }
 
     /// Adds a rewards batch to the specified money market. EGLD or ESDT tokens are supported.
@@ -263,16 +795,23 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// - `money_market` - The address of the money market smart contract.
     /// - `market_type` - Distribute rewards for suppliers (`Supply`) or lenders (`Borrows`).
     /// - `period` - The period of time in seconds in which rewards are distributed.
+    /// - `opt_account_cap` - An optional maximum amount of rewards a single account can earn from the batch.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin or rewards manager.
     /// - The provided address must be whitelisted money market.
-    /// - Should be paid with the rewards token.
+    /// - Should be paid with the rewards token, which can be EGLD, a fungible ESDT or a Meta-ESDT.
+    /// - A Meta-ESDT rewards token is bound to the nonce of its first rewards batch, such that all its rewards batches must
+    ///   be paid with that same nonce. Meta-ESDT rewards cannot be boosted nor swapped.
+    /// - Rewards above the per-account cap are kept as undistributed rewards.
+    /// - If the number of decimals of an ESDT rewards token has not been resolved yet, it is queried asynchronously from the
+    ///   ESDT system smart contract at the end of the call. Hence, the rewards batch identifier is not returned but emitted
+    ///   at the `set_rewards_batch_event` event.
     ///
     #[payable("*")]
     #[endpoint(setRewardsBatch)]
-    fn set_rewards_batch(&self, money_market: &ManagedAddress, market_type: MarketType, period: u64) -> usize {
+    fn set_rewards_batch(&self, money_market: &ManagedAddress, market_type: MarketType, period: u64, opt_account_cap: OptionalValue<BigUint>) {
         self.require_admin_or_rewards_manager();
         self.require_whitelisted_money_market(money_market);
 
@@ -282,18 +821,22 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         let max_rewards_batches = self.max_rewards_batches(money_market).get();
         require!(rewards_batches_mapper.len() < max_rewards_batches, ERROR_TOO_MANY_REWARDS_BATCHES);
 
-        let (rewards_token_id, amount) = self.call_value().egld_or_single_fungible_esdt();
+        let (rewards_token_id, rewards_token_nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
 
         if let Some(token_id) = rewards_token_id.as_esdt_option() {
             require!(!self.is_whitelisted_token_id(&token_id), ERROR_INVALID_REWARDS_TOKEN_ID);
         }
 
+        self.try_register_rewards_token_nonce(&rewards_token_id, rewards_token_nonce);
+
         require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
 
-        let wad = BigUint::from(WAD);
+        // new rewards batches are created in the current index precision
+        self.try_migrate_rewards_indexes(money_market);
+
         let batch_id = self.get_next_rewards_batch_id(money_market);
         let timestamp = self.blockchain().get_block_timestamp();
-        let speed = &amount * &wad / period;
+        let speed = self.wad_div(&amount, &BigUint::from(period));
         require!(speed > BigUint::zero(), ERROR_ZERO_REWARDS_BATCH_SPEED);
 
         let batch = RewardsBatch {
@@ -304,23 +847,36 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
             amount,
             distributed_amount: BigUint::zero(),
             speed,
-            index: &wad * &wad,
+            index: self.get_initial_rewards_index(),
             last_time: timestamp,
             end_time: timestamp + period,
         };
 
         let pos_id = rewards_batches_mapper.push(&batch);
         self.rewards_batch_position(money_market, &batch_id).set(pos_id);
+        self.rewards_batch_nonce(money_market, &batch_id).set(rewards_token_nonce);
+        self.rewards_tokens().insert(batch.token_id.clone());
+        self.try_register_rewards_token_metadata(&batch.token_id);
+        self.track_rewards_batch_funding(&batch.token_id, &batch.amount, true);
 
         self.set_rewards_batch_event(&self.blockchain().get_caller(), &batch);
 
+        if let OptionalValue::Some(account_cap) = opt_account_cap {
+            require!(account_cap > BigUint::zero(), ERROR_ZERO_REWARDS_BATCH_ACCOUNT_CAP);
+            self.rewards_batch_account_cap(money_market, &batch_id).set(&account_cap);
+            self.set_rewards_batch_account_cap_event(money_market, batch_id, &account_cap);
+        }
+
         if market_type == MarketType::Supply {
             self.update_supply_rewards_batches_state(money_market);
         } else {
             self.update_borrow_rewards_batches_state(money_market);
         }
 
-        batch_id
+        // resolving the rewards token decimals ends the execution, so it must be done last
+        if !self.rewards_token_metadata(&batch.token_id).get().decimals_resolved {
+            self.resolve_rewards_token_decimals(&batch.token_id.clone().unwrap_esdt());
+        }
     }
 
     /// Adds an amount of reward token to an existing rewards batch maintaining the same speed.
@@ -333,12 +889,14 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// # Notes:
     ///
     /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
     ///
     #[payable("*")]
     #[endpoint(addRewardsBatch)]
     fn add_rewards_batch(&self, money_market: &ManagedAddress, batch_id: usize) {
         self.require_admin_or_rewards_manager();
         self.require_whitelisted_money_market(money_market);
+        require!(!self.is_rewards_distribution_paused(money_market), ERROR_REWARDS_DISTRIBUTION_PAUSED);
 
         let rewards_batch_position_mapper = self.rewards_batch_position(money_market, &batch_id);
         require!(!rewards_batch_position_mapper.is_empty(), ERROR_INVALID_REWARDS_BATCH_ID);
@@ -347,8 +905,9 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         let mut rewards_batches_mapper = self.rewards_batches(money_market);
         let rewards_batch = rewards_batches_mapper.get(pos_id);
 
-        let (rewards_token_id, amount) = self.call_value().egld_or_single_fungible_esdt();
+        let (rewards_token_id, rewards_token_nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
         require!(rewards_token_id == rewards_batch.token_id, ERROR_INVALID_PAYMENT);
+        require!(rewards_token_nonce == self.rewards_batch_nonce(money_market, &batch_id).get(), ERROR_INVALID_PAYMENT);
         require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
 
         // this will update all rewards batches from a given money market up to this point
@@ -362,9 +921,8 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         let mut updated_rewards_batch = rewards_batches_mapper.get(pos_id);
 
         // update
-        let wad = BigUint::from(WAD);
         let t = self.blockchain().get_block_timestamp();
-        let additional_dt = &amount * &wad / &updated_rewards_batch.speed;
+        let additional_dt = self.wad_div(&amount, &updated_rewards_batch.speed);
         let dt = match BigUint::to_u64(&additional_dt) {
             None => sc_panic!(ERROR_UNEXPECTED_REWARDS_BATCH_PERIOD),
             Some(dt) => {
@@ -380,10 +938,11 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         } else {
             updated_rewards_batch.end_time += dt;
         }
-        updated_rewards_batch.amount += amount;
+        updated_rewards_batch.amount += &amount;
 
         // store
         rewards_batches_mapper.set(pos_id, &updated_rewards_batch);
+        self.track_rewards_batch_funding(&rewards_token_id, &amount, false);
 
         self.add_rewards_batch_event(&self.blockchain().get_caller(), &updated_rewards_batch);
     }
@@ -427,8 +986,7 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         let mut updated_rewards_batch = rewards_batches_mapper.get(pos_id);
 
         // get the amount left
-        let wad = BigUint::from(WAD);
-        let amount_left = &updated_rewards_batch.speed * (&updated_rewards_batch.end_time - t) / &wad;
+        let amount_left = self.get_rewards_batch_amount_left(&updated_rewards_batch, t);
 
         // update
         updated_rewards_batch.end_time = t;
@@ -445,9 +1003,10 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         };
 
         // make sure there is balance in the contract
-        let sc_balance = self.blockchain().get_sc_balance(&updated_rewards_batch.token_id, 0);
+        let rewards_token_nonce = self.rewards_batch_nonce(money_market, &batch_id).get();
+        let sc_balance = self.get_rewards_token_balance(&updated_rewards_batch.token_id, rewards_token_nonce);
         require!(amount_left <= sc_balance, ERROR_INSUFFICIENT_BALANCE);
-        self.send().direct(&to, &updated_rewards_batch.token_id, 0, &amount_left);
+        self.send_rewards_token(&to, &updated_rewards_batch.token_id, rewards_token_nonce, &amount_left);
 
         self.cancel_rewards_batch_event(&self.blockchain().get_caller(), &updated_rewards_batch);
     }
@@ -528,8 +1087,11 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         // update last batch position id
         self.rewards_batch_position(money_market, &last_batch_id).set(pos_id);
 
-        // clear position for removed batch
+        // clear position and account cap for removed batch
         self.rewards_batch_position(money_market, &batch_id).clear();
+        self.rewards_batch_account_cap(money_market, &batch_id).clear();
+        self.rewards_batch_usd_per_day(money_market, &batch_id).clear();
+        self.rewards_batch_nonce(money_market, &batch_id).clear();
 
         self.remove_rewards_batch_event(money_market, batch_id);
     }
@@ -547,11 +1109,13 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// # Notes:
     ///
     /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
     ///
     #[endpoint(updateRewardsBatchSpeed)]
     fn update_rewards_batch_speed(&self, money_market: &ManagedAddress, batch_id: usize, new_speed: &BigUint) {
         self.require_admin_or_rewards_manager();
         self.require_whitelisted_money_market(money_market);
+        require!(!self.is_rewards_distribution_paused(money_market), ERROR_REWARDS_DISTRIBUTION_PAUSED);
 
         require!(*new_speed > BigUint::zero(), ERROR_ZERO_REWARDS_BATCH_SPEED);
 
@@ -563,6 +1127,7 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         let rewards_batch = rewards_batches_mapper.get(pos_id);
 
         require!(rewards_batch.speed != *new_speed, ERROR_UNEXPECTED_REWARDS_BATCH_SPEED);
+        require!(self.rewards_batch_usd_per_day(money_market, &batch_id).is_empty(), ERROR_USD_DENOMINATED_REWARDS_BATCH);
 
         let t = self.blockchain().get_block_timestamp();
         require!(rewards_batch.end_time > t, ERROR_REWARDS_BATCH_EXPIRED);
@@ -596,6 +1161,62 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         self.update_rewards_batch_speed_event(&self.blockchain().get_caller(), &updated_rewards_batch);
     }
 
+    /// Denominates a given rewards batch in USD, i.e. sets a USD value per day to be distributed instead of a fixed amount of
+    /// tokens per second. The speed of rewards is recomputed using the oracle price of the rewards token right away and every
+    /// time the rewards batch is repriced using `repriceRewardsBatch`, which also changes the remaining distribution time
+    /// period.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_id` - The rewards batch identifier.
+    /// - `usd_per_day` - The USD value per day in wad. If zero, the rewards batch is denominated in tokens again, keeping
+    ///   its current speed.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
+    /// - Rewards batches states updates never reprice rewards batches, such that they do not depend on the price oracle.
+    ///
+    #[endpoint(setRewardsBatchUsdPerDay)]
+    fn set_rewards_batch_usd_per_day(&self, money_market: &ManagedAddress, batch_id: usize, usd_per_day: &BigUint) {
+        self.require_admin_or_rewards_manager();
+        self.require_whitelisted_money_market(money_market);
+        require!(!self.is_rewards_distribution_paused(money_market), ERROR_REWARDS_DISTRIBUTION_PAUSED);
+
+        let rewards_batch_position_mapper = self.rewards_batch_position(money_market, &batch_id);
+        require!(!rewards_batch_position_mapper.is_empty(), ERROR_INVALID_REWARDS_BATCH_ID);
+        let pos_id = rewards_batch_position_mapper.get();
+
+        let mut rewards_batches_mapper = self.rewards_batches(money_market);
+        let rewards_batch = rewards_batches_mapper.get(pos_id);
+
+        let t = self.blockchain().get_block_timestamp();
+        require!(rewards_batch.end_time > t, ERROR_REWARDS_BATCH_EXPIRED);
+
+        // this will update all rewards batches from a given money market up to this point
+        if rewards_batch.market_type == MarketType::Supply {
+            self.update_supply_rewards_batches_state(money_market);
+        } else {
+            self.update_borrow_rewards_batches_state(money_market);
+        }
+
+        // after updating it, get it again
+        let mut updated_rewards_batch = rewards_batches_mapper.get(pos_id);
+
+        let usd_per_day_mapper = self.rewards_batch_usd_per_day(money_market, &batch_id);
+        if usd_per_day == &BigUint::zero() {
+            usd_per_day_mapper.clear();
+        } else {
+            usd_per_day_mapper.set(usd_per_day);
+            self.try_reprice_usd_rewards_batch(&mut updated_rewards_batch);
+            rewards_batches_mapper.set(pos_id, &updated_rewards_batch);
+        }
+
+        self.set_rewards_batch_usd_per_day_event(&self.blockchain().get_caller(), usd_per_day, &updated_rewards_batch);
+    }
+
     /// Updates a given rewards batch based on a new period. The new period also changes the speed of rewards.
     ///
     ///
@@ -608,11 +1229,13 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// # Notes:
     ///
     /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
     ///
     #[endpoint(updateRewardsBatchRemainingPeriod)]
     fn update_rewards_batch_remaining_period(&self, money_market: &ManagedAddress, batch_id: usize, new_dt: u64) {
         self.require_admin_or_rewards_manager();
         self.require_whitelisted_money_market(money_market);
+        require!(!self.is_rewards_distribution_paused(money_market), ERROR_REWARDS_DISTRIBUTION_PAUSED);
 
         require!(new_dt > 0u64, ERROR_ZERO_REWARDS_BATCH_PERIOD);
 
@@ -628,6 +1251,7 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
 
         let old_dt = rewards_batch.end_time - t;
         require!(old_dt != new_dt, ERROR_UNEXPECTED_REWARDS_BATCH_PERIOD);
+        require!(self.rewards_batch_usd_per_day(money_market, &batch_id).is_empty(), ERROR_USD_DENOMINATED_REWARDS_BATCH);
 
         // this will update all rewards batches from a given money market up to this point
         if rewards_batch.market_type == MarketType::Supply {
@@ -651,6 +1275,112 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         self.update_rewards_batch_remaining_period_event(&self.blockchain().get_caller(), &updated_rewards_batch);
     }
 
+    /// Merges several rewards batches from the same money market, rewards token and market type into a single rewards batch.
+    /// The remaining rewards of all merged batches are consolidated into the first batch, whose speed and end time are
+    /// recomputed such that all remaining rewards are distributed by the latest end time among the merged batches.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - the address of the money market smart contract.
+    /// - `batch_ids` - the rewards batch identifiers, where the first one is the batch that absorbs the others.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or rewards manager.
+    /// - Cannot be called while rewards distribution is paused at the money market.
+    /// - Rewards batches denominated in USD cannot be merged.
+    /// - Merged batches stop accruing rewards and are removed if they have been fully distributed. Otherwise, they are kept
+    ///   until all accounts have been distributed their rewards and can be removed with `removeRewardsBatch`.
+    ///
+    #[endpoint(mergeRewardsBatches)]
+    fn merge_rewards_batches(&self, money_market: &ManagedAddress, batch_ids: MultiValueEncoded<usize>) {
+        self.require_admin_or_rewards_manager();
+        self.require_whitelisted_money_market(money_market);
+        require!(!self.is_rewards_distribution_paused(money_market), ERROR_REWARDS_DISTRIBUTION_PAUSED);
+
+        let batch_ids = batch_ids.to_vec();
+        require!(batch_ids.len() > 1, ERROR_INVALID_REWARDS_BATCH_ID);
+
+        let target_batch_id = batch_ids.get(0);
+        let target_position_mapper = self.rewards_batch_position(money_market, &target_batch_id);
+        require!(!target_position_mapper.is_empty(), ERROR_INVALID_REWARDS_BATCH_ID);
+        require!(self.rewards_batch_usd_per_day(money_market, &target_batch_id).is_empty(), ERROR_USD_DENOMINATED_REWARDS_BATCH);
+
+        let mut rewards_batches_mapper = self.rewards_batches(money_market);
+        let target_batch = rewards_batches_mapper.get(target_position_mapper.get());
+
+        // this will update all rewards batches from a given money market up to this point
+        if target_batch.market_type == MarketType::Supply {
+            self.update_supply_rewards_batches_state(money_market);
+        } else {
+            self.update_borrow_rewards_batches_state(money_market);
+        }
+
+        let t = self.blockchain().get_block_timestamp();
+
+        // after updating it, get it again
+        let mut updated_target_batch = rewards_batches_mapper.get(target_position_mapper.get());
+        let mut merged_amount = BigUint::zero();
+        let mut end_time = updated_target_batch.end_time;
+
+        let mut merged_batch_ids = ManagedVec::new();
+        for batch_id in batch_ids.iter().skip(1) {
+            require!(batch_id != target_batch_id && !merged_batch_ids.contains(&batch_id), ERROR_INVALID_REWARDS_BATCH_ID);
+
+            let rewards_batch_position_mapper = self.rewards_batch_position(money_market, &batch_id);
+            require!(!rewards_batch_position_mapper.is_empty(), ERROR_INVALID_REWARDS_BATCH_ID);
+            let pos_id = rewards_batch_position_mapper.get();
+
+            let mut rewards_batch = rewards_batches_mapper.get(pos_id);
+            require!(rewards_batch.token_id == updated_target_batch.token_id && rewards_batch.market_type == updated_target_batch.market_type, ERROR_INVALID_REWARDS_BATCH_ID);
+            require!(self.rewards_batch_nonce(money_market, &batch_id).get() == self.rewards_batch_nonce(money_market, &target_batch_id).get(), ERROR_INVALID_REWARDS_BATCH_ID);
+            require!(self.rewards_batch_usd_per_day(money_market, &batch_id).is_empty(), ERROR_USD_DENOMINATED_REWARDS_BATCH);
+
+            // stop the batch and move its remaining rewards to the target batch
+            let batch_amount_left = self.get_rewards_batch_amount_left(&rewards_batch, t);
+            if rewards_batch.end_time > t {
+                end_time = core::cmp::max(end_time, rewards_batch.end_time);
+                rewards_batch.end_time = t;
+                rewards_batch.amount -= &batch_amount_left;
+                merged_amount += &batch_amount_left;
+            }
+
+            if rewards_batch.distributed_amount >= rewards_batch.amount {
+                self.remove_rewards_batch_internal(money_market, batch_id, pos_id);
+            } else {
+                rewards_batches_mapper.set(pos_id, &rewards_batch);
+            }
+
+            merged_batch_ids.push(batch_id);
+        }
+
+        if merged_amount > BigUint::zero() {
+            let amount_left = self.get_rewards_batch_amount_left(&updated_target_batch, t) + &merged_amount;
+            let new_speed = self.wad_div(&amount_left, &BigUint::from(end_time - t));
+            require!(new_speed > BigUint::zero(), ERROR_ZERO_REWARDS_BATCH_SPEED);
+
+            // the target batch might have already expired, in which case it is made "active" again
+            updated_target_batch.amount += &merged_amount;
+            updated_target_batch.last_time = t;
+            updated_target_batch.end_time = end_time;
+            updated_target_batch.speed = new_speed;
+        }
+
+        // store
+        rewards_batches_mapper.set(target_position_mapper.get(), &updated_target_batch);
+
+        self.merge_rewards_batches_event(&self.blockchain().get_caller(), &updated_target_batch, &merged_batch_ids);
+    }
+
+    /// Returns the amount of rewards a given rewards batch has yet to accrue from a given timestamp onwards.
+    ///
+    fn get_rewards_batch_amount_left(&self, rewards_batch: &RewardsBatch<Self::Api>, t: u64) -> BigUint {
+        if rewards_batch.end_time <= t {
+            return BigUint::zero();
+        }
+        self.wad_mul(&rewards_batch.speed, &BigUint::from(rewards_batch.end_time - t))
+    }
+
     /// Claims the undistributed rewards for a given rewards token.
     ///
     /// # Arguments:
@@ -672,11 +1402,76 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
 
         let admin = self.get_admin();
-        self.send().direct(&admin, rewards_token_id, 0, &amount);
+        let rewards_token_nonce = self.rewards_token_nonce(rewards_token_id).get();
+        self.send().direct(&admin, rewards_token_id, rewards_token_nonce, &amount);
 
         self.claim_undistributed_rewards_event(&admin, &rewards_token_id, &amount);
     }
 
+    /// Rescues tokens sent by mistake to the controller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - the identifier of the token to rescue
+    /// - `amount` - the amount of tokens to rescue
+    /// - `to` - the beneficiary address
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Hatom's tokens, rewards tokens (active or past), boosted rewards tokens and the governance token cannot be rescued.
+    /// - EGLD bonded by liquidators cannot be rescued.
+    ///
+    #[endpoint(rescueToken)]
+    fn rescue_token(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint, to: &ManagedAddress) {
+        self.require_admin();
+
+        require!(!self.is_protected_token(token_id), ERROR_PROTECTED_TOKEN);
+        require!(amount > &BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        // liquidator bonds are never rescued
+        let sc_balance = if token_id.is_egld() { self.get_unbonded_egld_balance() } else { self.blockchain().get_sc_balance(token_id, 0) };
+        require!(amount <= &sc_balance, ERROR_INSUFFICIENT_BALANCE);
+
+        self.send().direct(to, token_id, 0, amount);
+
+        self.rescue_token_event(&self.blockchain().get_caller(), to, token_id, amount);
+    }
+
+    fn is_protected_token(&self, token_id: &EgldOrEsdtTokenIdentifier) -> bool {
+        if let Some(esdt_token_id) = token_id.as_esdt_option() {
+            // collateral and seed tokens
+            if self.is_whitelisted_token_id(&esdt_token_id) {
+                return true;
+            }
+
+            // boosted rewards are paid in governance tokens
+            if !self.governance_token_id().is_empty() && self.governance_token_id().get() == *esdt_token_id {
+                return true;
+            }
+
+            // WEGLD held on behalf of EGLD rewards
+            if !self.wegld_id().is_empty() && self.wegld_id().get() == *esdt_token_id && self.wegld_buffer().get() > BigUint::zero() {
+                return true;
+            }
+        }
+
+        if self.rewards_tokens().contains(token_id) || self.undistributed_rewards(token_id).get() > BigUint::zero() || !self.rewards_booster(token_id).is_empty() {
+            return true;
+        }
+
+        // rewards batches created before rewards tokens were tracked
+        for money_market in self.whitelisted_markets().iter() {
+            for rewards_batch in self.rewards_batches(&money_market).iter() {
+                if rewards_batch.token_id == *token_id {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
     /// Adds support for boosting rewards batches by converting the rewards batch tokens into Hatom's governance tokens with
     /// a premium.
     ///
@@ -744,8 +1539,11 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// # Notes:
     ///
     /// - Can only be called by the admin or rewards manager.
-    /// - If rewards token is EGLD, swaps will add a EGLD => WEGLD step first. Also, the swap path needs to use the WEGLD
-    ///   token identifier.
+    /// - If rewards token is EGLD, swaps will use WEGLD from the buffer or add a EGLD => WEGLD step first. Also, the swap path
+    ///   needs to use the WEGLD token identifier. The resulting WEGLD is kept in the buffer until it is paid out.
+    /// - The price oracle must support the governance token. Each swap leg cannot lose more than the maximum slippage as
+    ///   compared to oracle values.
+    /// - Meta-ESDT rewards tokens cannot be boosted.
     ///
     #[payable("*")]
     #[endpoint(boostRewards)]
@@ -763,12 +1561,15 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
 
         require!(self.token_has_active_rewards_batch(&rewards_token_id), ERROR_INVALID_REWARDS_TOKEN_ID);
 
+        // Meta-ESDT rewards cannot be swapped into governance tokens
+        require!(self.rewards_token_nonce(&rewards_token_id).get() == 0, ERROR_META_ESDT_REWARDS_NOT_BOOSTABLE);
+
         let booster_mapper = self.rewards_booster(&rewards_token_id);
         require!(booster_mapper.is_empty(), ERROR_REWARDS_TOKEN_ALREADY_BOOSTED);
 
-        // if rewards token is EGLD then add a EGLD => WEGLD step first
+        // if rewards token is EGLD then use WEGLD from the buffer or add a EGLD => WEGLD step first
         let swap_token_id = if rewards_token_id.is_egld() {
-            self.wrap_egld(&fwd_swap_amount);
+            self.take_wegld(&fwd_swap_amount);
             self.wegld_id().get()
         } else {
             rewards_token_id.clone().unwrap_esdt()
@@ -777,6 +1578,13 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         // the output token
         let governance_token_id = self.governance_token_id().get();
 
+        // both swap legs are valued using the price oracle
+        let rewards_token_price = self.get_rewards_token_price(&rewards_token_id);
+        let governance_token_price = match self.try_get_governance_token_price() {
+            Some(price) => price,
+            None => sc_panic!(ERROR_ORACLE_GOVERNANCE_TOKEN_UNSUPPORTED),
+        };
+
         // swap rewards batch tokens into governance token
         let bwd_swap_amount = self.custom_swap(&fwd_swap_path, true, &swap_token_id, &fwd_swap_amount, &governance_token_id);
 
@@ -788,18 +1596,19 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         require!(fwd_swap_amount >= fwd_bwd_swap_amount, ERROR_EXPECTED_SLIPPAGE);
         let delta_amount = &fwd_swap_amount - &fwd_bwd_swap_amount;
 
-        // make sure we don't lose too much money
-        let wad = BigUint::from(WAD);
-        let max_slippage = self.max_slippage().get();
-        let max_slippage_amount = fwd_swap_amount * &max_slippage / wad;
-        require!(delta_amount <= max_slippage_amount, ERROR_TOO_MUCH_SLIPPAGE);
+        // make sure we don't lose too much money at any of the swap legs, as compared to oracle values
+        let fwd_swap_value = self.wad_mul(&fwd_swap_amount, &rewards_token_price);
+        let bwd_swap_value = self.wad_mul(&bwd_swap_amount, &governance_token_price);
+        let fwd_bwd_swap_value = self.wad_mul(&fwd_bwd_swap_amount, &rewards_token_price);
+        self.require_swap_within_max_slippage(&fwd_swap_value, &bwd_swap_value);
+        self.require_swap_within_max_slippage(&bwd_swap_value, &fwd_bwd_swap_value);
 
         // lost some tokens due to slippage
         amount -= &delta_amount;
 
-        // if rewards token is EGLD, unwrap WEGLD into EGLD
+        // if rewards token is EGLD, keep WEGLD in the buffer until it is paid out
         if rewards_token_id.is_egld() {
-            self.unwrap_egld(&fwd_bwd_swap_amount);
+            self.wegld_buffer().update(|buffer| *buffer += &fwd_bwd_swap_amount);
         }
 
         // create booster
@@ -827,7 +1636,7 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// # Notes:
     ///
     /// - Can only be called by the admin or rewards manager.
-    /// - Cannot change the swap path. That requires canceling the booster and creating a new one.
+    /// - Cannot change the swap path. Use `updateBoosterSwapPath` instead.
     ///
     #[payable("*")]
     #[endpoint(updateBooster)]
@@ -855,6 +1664,56 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         self.update_booster_event(&self.blockchain().get_caller(), &booster);
     }
 
+    /// Updates the swap path of a given booster, preserving its premium and distribution accounting.
+    ///
+    /// # Arguments:
+    ///
+    /// - `rewards_token_id` - the rewards token identifier for which we wish to update its booster swap path.
+    /// - `swap_amount` - the amount of rewards tokens used to validate the new swap path.
+    /// - `new_swap_path` - the new swap path to convert the rewards batch tokens into Hatom's governance tokens.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or rewards manager.
+    /// - Boosting must be active.
+    /// - The swap amount cannot exceed the booster amount left.
+    /// - The new swap path is dry run against the current xExchange pools reserves, such that no tokens are swapped. It
+    ///   must be healthy and its expected output cannot lose more than the maximum slippage as compared to oracle values.
+    /// - If rewards token is EGLD, the swap path needs to use the WEGLD token identifier.
+    ///
+    #[endpoint(updateBoosterSwapPath)]
+    fn update_booster_swap_path(&self, rewards_token_id: EgldOrEsdtTokenIdentifier, swap_amount: BigUint, new_swap_path: ManagedVec<SwapStep<Self::Api>>) {
+        self.require_admin_or_rewards_manager();
+
+        require!(self.boosting_state().get() == State::Active, ERROR_BOOSTING_NOT_ACTIVE);
+
+        let booster_mapper = self.rewards_booster(&rewards_token_id);
+        require!(!booster_mapper.is_empty(), ERROR_INVALID_REWARDS_TOKEN_ID);
+        let mut booster = booster_mapper.get();
+
+        require!(!new_swap_path.is_empty(), ERROR_INVALID_SWAP_PATH);
+        require!(swap_amount > BigUint::zero() && swap_amount <= booster.amount_left, ERROR_INVALID_SWAP_AMOUNT);
+
+        // the new swap path must connect the rewards token with the governance token through active pools
+        let report = self.dry_run_swap_path(rewards_token_id.clone(), &new_swap_path, swap_amount.clone());
+        require!(report.ok, ERROR_INVALID_SWAP_PATH);
+
+        // the expected output is valued using the price oracle
+        let rewards_token_price = self.get_rewards_token_price(&rewards_token_id);
+        let governance_token_price = match self.try_get_governance_token_price() {
+            Some(price) => price,
+            None => sc_panic!(ERROR_ORACLE_GOVERNANCE_TOKEN_UNSUPPORTED),
+        };
+        let swap_value_in = self.wad_mul(&swap_amount, &rewards_token_price);
+        let swap_value_out = self.wad_mul(&report.expected_amount_out, &governance_token_price);
+        self.require_swap_within_max_slippage(&swap_value_in, &swap_value_out);
+
+        booster.swap_path = new_swap_path;
+        booster_mapper.set(&booster);
+
+        self.update_booster_event(&self.blockchain().get_caller(), &booster);
+    }
+
     /// Cancels a given booster and sends the remaining tokens back to the caller.
     ///
     /// # Arguments:
@@ -884,9 +1743,10 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
 
         // make sure there is balance in the contract
         if amount_left > BigUint::zero() {
-            let sc_balance = self.blockchain().get_sc_balance(&rewards_token_id, 0);
+            // boosted rewards tokens are always fungible
+            let sc_balance = self.get_rewards_token_balance(&rewards_token_id, 0);
             require!(amount_left <= sc_balance, ERROR_INSUFFICIENT_BALANCE);
-            self.send().direct(&to, &rewards_token_id, 0, &amount_left);
+            self.send_rewards_token(&to, &rewards_token_id, 0, &amount_left);
         }
 
         booster_mapper.clear();
@@ -907,7 +1767,7 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
     /// # Notes:
     ///
     /// - Can only be called by a whitelisted money market.
-    /// - The provided address must be a whitelisted money market.
+    /// - The provided address must be a whitelisted money market, other than a position market.
     /// - Makes sure the mappers `account_markets` and `market_members` remain updated.
     ///
     #[endpoint(setAccountTokens)]
@@ -915,7 +1775,49 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         let caller = self.blockchain().get_caller();
         self.require_whitelisted_money_market(&caller);
         self.require_whitelisted_money_market(money_market);
+        self.require_not_position_market(money_market);
+
+        self.set_account_collateral_tokens_internal(money_market, account, new_tokens);
+    }
+
+    /// Seizes collateral positions from a borrower in favor of a liquidator at the calling position market. The seized
+    /// meta-ESDT positions are sent to the liquidator.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The address of the account having collateral seized.
+    /// - `liquidator` - The address of the account receiving the seized positions.
+    /// - `tokens` - The amount of collateral tokens to seize.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by a whitelisted position market.
+    /// - Positions are seized in the order they have been deposited by the borrower.
+    ///
+    #[endpoint(seizePositions)]
+    fn seize_positions(&self, borrower: &ManagedAddress, liquidator: &ManagedAddress, tokens: &BigUint) -> ManagedVec<EsdtTokenPayment> {
+        let money_market = self.blockchain().get_caller();
+        self.require_whitelisted_money_market(&money_market);
+        require!(self.position_market(&money_market).get(), ERROR_ONLY_POSITION_MARKET);
+        require!(borrower != liquidator, ERROR_ADDRESSES_MUST_DIFFER);
+
+        let old_tokens = self.get_account_collateral_tokens(&money_market, borrower);
+        require!(tokens <= &old_tokens, ERROR_INSUFFICIENT_COLLATERAL);
+
+        let new_tokens = &old_tokens - tokens;
+        self.set_account_collateral_tokens_internal(&money_market, borrower, &new_tokens);
+
+        let positions = self.take_collateral_positions(&money_market, borrower, tokens);
+        if !positions.is_empty() {
+            self.send().direct_multi(liquidator, &positions);
+        }
 
+        self.seize_positions_event(&money_market, borrower, liquidator, &positions);
+
+        positions
+    }
+
+    fn set_account_collateral_tokens_internal(&self, money_market: &ManagedAddress, account: &ManagedAddress, new_tokens: &BigUint) {
         // update total collateral tokens
         let account_collateral_tokens_mapper = self.account_collateral_tokens(money_market, account);
         let old_tokens = account_collateral_tokens_mapper.get();
@@ -965,24 +1867,6 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
         self.new_rewards_manager_event(&old_rewards_manager, new_rewards_manager);
     }
 
-    /// Sets the Guardian of the protocol.
-    ///
-    /// # Arguments:
-    ///
-    /// - `new_pause_guardian` - The address of the new Guardian.
-    ///
-    /// # Notes:
-    ///
-    /// - Can only be called by the admin.
-    ///
-    #[endpoint(setPauseGuardian)]
-    fn set_pause_guardian(&self, new_pause_guardian: &ManagedAddress) {
-        self.require_admin();
-        let old_pause_guardian = self.get_pause_guardian();
-        self.pause_guardian().set(new_pause_guardian);
-        self.new_pause_guardian_event(&old_pause_guardian, new_pause_guardian);
-    }
-
     /// Sets a Rewards Booster smart contract as an observer, i.e. as a contract that is notified when accounts deposit or
     /// withdraw collateral from markets. The name Booster Observer is used to reference the Rewards Booster smart contract.
     ///
@@ -1016,13 +1900,85 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
 
         let booster_observer_mapper = self.booster_observer();
         require!(!booster_observer_mapper.is_empty(), ERROR_REWARDS_BOOSTER_UNSET);
+        require!(self.pending_market_changes().is_empty(), ERROR_PENDING_MARKET_CHANGES);
 
         let old_booster_observer = booster_observer_mapper.take();
         require!(self.is_finalized(&old_booster_observer), ERROR_REWARDS_BOOSTER_NOT_FINALIZED);
 
+        self.next_force_clear_booster_observer().clear();
+
         self.clear_booster_observer_event(&old_booster_observer);
     }
 
+    /// Schedules the forced removal of the current booster observer, which can be performed after a timelock using
+    /// `forceClearBoosterObserver`. This is an escape hatch for booster observers that cannot be finalized anymore.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Replaces any previously scheduled forced removal.
+    ///
+    #[endpoint(scheduleForceClearBoosterObserver)]
+    fn schedule_force_clear_booster_observer(&self) {
+        self.require_admin();
+
+        let booster_observer_mapper = self.booster_observer();
+        require!(!booster_observer_mapper.is_empty(), ERROR_REWARDS_BOOSTER_UNSET);
+
+        let booster_observer = booster_observer_mapper.get();
+        let timestamp = self.blockchain().get_block_timestamp() + TIMELOCK_FORCE_CLEAR_BOOSTER_OBSERVER;
+        self.next_force_clear_booster_observer().set((timestamp, booster_observer.clone()));
+
+        self.schedule_force_clear_booster_observer_event(&booster_observer, timestamp);
+    }
+
+    /// Cancels the scheduled forced removal of the booster observer.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(cancelForceClearBoosterObserver)]
+    fn cancel_force_clear_booster_observer(&self) {
+        self.require_admin();
+
+        let next_mapper = self.next_force_clear_booster_observer();
+        require!(!next_mapper.is_empty(), ERROR_NO_FORCE_CLEAR_BOOSTER_OBSERVER);
+
+        let (_, booster_observer) = next_mapper.take();
+        self.cancel_force_clear_booster_observer_event(&booster_observer);
+    }
+
+    /// Forcibly removes the booster observer without requiring it to be finalized. From this point onwards, this smart
+    /// contract will not be notified of any market change.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Must have been scheduled for the current booster observer and its timelock must have elapsed.
+    /// - Market changes pending to be notified to the booster observer are dropped.
+    ///
+    #[endpoint(forceClearBoosterObserver)]
+    fn force_clear_booster_observer(&self) {
+        self.require_admin();
+
+        let next_mapper = self.next_force_clear_booster_observer();
+        require!(!next_mapper.is_empty(), ERROR_NO_FORCE_CLEAR_BOOSTER_OBSERVER);
+
+        let (timestamp, booster_observer) = next_mapper.take();
+        require!(self.blockchain().get_block_timestamp() >= timestamp, ERROR_FORCE_CLEAR_BOOSTER_OBSERVER_TIMELOCKED);
+
+        let booster_observer_mapper = self.booster_observer();
+        require!(!booster_observer_mapper.is_empty() && booster_observer_mapper.get() == booster_observer, ERROR_NO_FORCE_CLEAR_BOOSTER_OBSERVER);
+        booster_observer_mapper.clear();
+
+        let mut pending_market_changes = self.pending_market_changes();
+        let dropped_market_changes = pending_market_changes.len();
+        pending_market_changes.clear();
+
+        self.force_clear_booster_observer_event(&booster_observer, dropped_market_changes);
+    }
+
     /// Sets a USH Money Market smart contract as an observer, i.e. as a contract that is notified when accounts deposit or
     /// withdraw collateral from markets. The name USH Market Observer is used to reference the USH Money Market smart
     /// contract.
@@ -1064,4 +2020,76 @@ pub trait GovernanceModule: admin::AdminModule + events::EventModule + guardian:
 
         self.clear_ush_market_observer_event(&old_ush_market_observer);
     }
+
+    /// Sets the minimum amount of seconds between two consecutive protocol heartbeats.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_interval` - The new heartbeat interval in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(setHeartbeatInterval)]
+    fn set_heartbeat_interval(&self, new_interval: u64) {
+        self.require_admin();
+        let old_interval = self.heartbeat_interval().get();
+        self.heartbeat_interval().set(new_interval);
+        self.new_heartbeat_interval_event(old_interval, new_interval);
+    }
+
+    /// Restricts or opens bulk enumeration views, i.e. views that page over many accounts such as market members, accounts
+    /// joined within a time range or borrow snapshots at money markets.
+    ///
+    /// # Arguments:
+    ///
+    /// - `restricted` - Whether bulk enumeration views are restricted to registered readers.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Single account views always remain public.
+    ///
+    #[endpoint(setEnumerationRestricted)]
+    fn set_enumeration_restricted(&self, restricted: bool) {
+        self.require_admin();
+        self.enumeration_restricted().set(restricted);
+        self.enumeration_restricted_event(restricted);
+    }
+
+    /// Registers a smart contract as a reader of bulk enumeration views.
+    ///
+    /// # Arguments:
+    ///
+    /// - `reader` - The address of the reader smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(registerReader)]
+    fn register_reader(&self, reader: &ManagedAddress) {
+        self.require_admin();
+        require!(self.blockchain().is_smart_contract(reader), ERROR_INVALID_READER_SC);
+        self.registered_reader(reader).set(true);
+        self.register_reader_event(reader);
+    }
+
+    /// Unregisters a reader of bulk enumeration views.
+    ///
+    /// # Arguments:
+    ///
+    /// - `reader` - The address of the reader smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(unregisterReader)]
+    fn unregister_reader(&self, reader: &ManagedAddress) {
+        self.require_admin();
+        self.registered_reader(reader).clear();
+        self.unregister_reader_event(reader);
+    }
 }
@@ -1,23 +1,26 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use super::{events, proxies, shared, storage};
+use super::{constants::*, errors::*, events, proxies, rewards, shared, storage};
+use crate::storage::PauseReason;
 
 #[multiversx_sc::module]
-pub trait GuardianModule: admin::AdminModule + events::EventModule + proxies::ProxyModule + shared::SharedModule + storage::StorageModule {
+pub trait GuardianModule: admin::AdminModule + pausable::PausableModule + events::EventModule + proxies::ProxyModule + rewards::RewardsModule + shared::SharedModule + storage::StorageModule {
     /// Changes the minting status for a specific money market.
     ///
     /// # Arguments:
     ///
     /// - `money_market` - The address of the money market smart contract.
     /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `opt_reason` - An optional pause reason, which can only be an oracle incident. Defaults to an admin or a guardian
+    ///   pause, depending on the caller. Ignored when unpausing.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin or the Guardian.
     ///
     #[endpoint(pauseMint)]
-    fn pause_mint(&self, money_market: &ManagedAddress, pause: bool) {
+    fn pause_mint(&self, money_market: &ManagedAddress, pause: bool, opt_reason: OptionalValue<PauseReason>) {
         self.require_admin_or_guardian();
         self.require_whitelisted_money_market(money_market);
 
@@ -27,7 +30,13 @@ pub trait GuardianModule: admin::AdminModule + events::EventModule + proxies::Pr
             self.mint_status(money_market).set(storage::Status::Active);
         }
 
+        let reason = self.resolve_pause_reason(pause, opt_reason);
+        self.mint_pause_reason(money_market).set(reason);
+
         self.mint_paused_event(money_market, pause);
+        self.mint_pause_reason_event(money_market, reason);
+
+        self.sync_market_mirror(money_market);
     }
 
     /// Changes the borrowing status for a specific money market.
@@ -36,13 +45,15 @@ pub trait GuardianModule: admin::AdminModule + events::EventModule + proxies::Pr
     ///
     /// - `money_market` - The address of the money market smart contract.
     /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `opt_reason` - An optional pause reason, which can only be an oracle incident. Defaults to an admin or a guardian
+    ///   pause, depending on the caller. Ignored when unpausing.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin or the Guardian.
     ///
     #[endpoint(pauseBorrow)]
-    fn pause_borrow(&self, money_market: &ManagedAddress, pause: bool) {
+    fn pause_borrow(&self, money_market: &ManagedAddress, pause: bool, opt_reason: OptionalValue<PauseReason>) {
         self.require_admin_or_guardian();
         self.require_whitelisted_money_market(money_market);
 
@@ -52,7 +63,13 @@ pub trait GuardianModule: admin::AdminModule + events::EventModule + proxies::Pr
             self.borrow_status(money_market).set(storage::Status::Active);
         }
 
+        let reason = self.resolve_pause_reason(pause, opt_reason);
+        self.borrow_pause_reason(money_market).set(reason);
+
         self.borrow_paused_event(money_market, pause);
+        self.borrow_pause_reason_event(money_market, reason);
+
+        self.sync_market_mirror(money_market);
     }
 
     /// Changes the seizing status for a specific money market.
@@ -61,13 +78,15 @@ pub trait GuardianModule: admin::AdminModule + events::EventModule + proxies::Pr
     ///
     /// - `money_market` - The address of the money market smart contract.
     /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `opt_reason` - An optional pause reason, which can only be an oracle incident. Defaults to an admin or a guardian
+    ///   pause, depending on the caller. Ignored when unpausing.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin or the Guardian.
     ///
     #[endpoint(pauseSeize)]
-    fn pause_seize(&self, money_market: &ManagedAddress, pause: bool) {
+    fn pause_seize(&self, money_market: &ManagedAddress, pause: bool, opt_reason: OptionalValue<PauseReason>) {
         self.require_admin_or_guardian();
         self.require_whitelisted_money_market(money_market);
 
@@ -77,7 +96,13 @@ pub trait GuardianModule: admin::AdminModule + events::EventModule + proxies::Pr
             self.seize_status(money_market).set(storage::Status::Active);
         }
 
+        let reason = self.resolve_pause_reason(pause, opt_reason);
+        self.seize_pause_reason(money_market).set(reason);
+
         self.seize_paused_event(money_market, pause);
+        self.seize_pause_reason_event(money_market, reason);
+
+        self.sync_market_mirror(money_market);
     }
 
     /// Changes the seizing status (required for liquidations) for all money markets.
@@ -85,13 +110,15 @@ pub trait GuardianModule: admin::AdminModule + events::EventModule + proxies::Pr
     /// # Arguments:
     ///
     /// - `pause` - A boolean that indicates whether the protocol must be or not paused.
+    /// - `opt_reason` - An optional pause reason, which can only be an oracle incident. Defaults to an admin or a guardian
+    ///   pause, depending on the caller. Ignored when unpausing.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin or the Guardian.
     ///
     #[endpoint(pauseGlobalSeize)]
-    fn pause_global_seize(&self, pause: bool) {
+    fn pause_global_seize(&self, pause: bool, opt_reason: OptionalValue<PauseReason>) {
         self.require_admin_or_guardian();
 
         if pause {
@@ -100,6 +127,143 @@ pub trait GuardianModule: admin::AdminModule + events::EventModule + proxies::Pr
             self.global_seize_status().set(storage::Status::Active);
         }
 
+        let reason = self.resolve_pause_reason(pause, opt_reason);
+        self.global_seize_pause_reason().set(reason);
+
         self.global_seize_paused_event(pause);
+        self.global_seize_pause_reason_event(reason);
+
+        self.sync_all_market_mirrors();
+    }
+
+    /// Resolves the reason behind a status change. Unpausing always clears the reason. When pausing, the reason defaults to
+    /// an admin or a guardian pause depending on the caller, while oracle incidents must be explicit. Deprecations are
+    /// derived from the deprecated flag instead.
+    ///
+    fn resolve_pause_reason(&self, pause: bool, opt_reason: OptionalValue<PauseReason>) -> PauseReason {
+        if !pause {
+            return PauseReason::NotPaused;
+        }
+
+        match opt_reason {
+            OptionalValue::Some(reason) => {
+                require!(reason == PauseReason::OracleIncident, ERROR_INVALID_PAUSE_REASON);
+                reason
+            },
+            OptionalValue::None => {
+                if self.blockchain().get_caller() == self.get_admin() {
+                    PauseReason::AdminPause
+                } else {
+                    PauseReason::GuardianPause
+                }
+            },
+        }
+    }
+
+    /// Freezes interest accrual in all money markets for a bounded period of time. During the freeze, money markets keep
+    /// advancing their accrual timestamps but borrowers are not charged any interest.
+    ///
+    /// # Arguments:
+    ///
+    /// - `duration` - The freeze duration in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    /// - The duration cannot exceed `MAX_INTEREST_FREEZE_DURATION`.
+    /// - Interest is accrued in all money markets before the freeze starts.
+    /// - Cannot be called while another freeze is in place.
+    ///
+    #[endpoint(freezeInterestAccrual)]
+    fn freeze_interest_accrual(&self, duration: u64) {
+        self.require_admin_or_guardian();
+
+        require!(duration > 0 && duration <= MAX_INTEREST_FREEZE_DURATION, ERROR_INVALID_INTEREST_FREEZE_DURATION);
+        require!(!self.is_interest_accrual_frozen(), ERROR_INTEREST_ACCRUAL_ALREADY_FROZEN);
+
+        // settle interest up to now, such that the freeze never applies to past periods
+        for money_market in self.whitelisted_markets().iter() {
+            self.accrue_interest(&money_market);
+        }
+
+        let frozen_until = self.blockchain().get_block_timestamp() + duration;
+        self.interest_frozen_until().set(frozen_until);
+
+        self.interest_accrual_frozen_event(true, frozen_until);
+    }
+
+    /// Lifts an ongoing interest accrual freeze before its expiration.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    ///
+    #[endpoint(unfreezeInterestAccrual)]
+    fn unfreeze_interest_accrual(&self) {
+        self.require_admin_or_guardian();
+
+        require!(self.is_interest_accrual_frozen(), ERROR_INTEREST_ACCRUAL_NOT_FROZEN);
+
+        // the freeze end is kept so that money markets do not charge interest for the frozen period
+        let frozen_until = self.blockchain().get_block_timestamp();
+        self.interest_frozen_until().set(frozen_until);
+
+        self.interest_accrual_frozen_event(false, frozen_until);
+    }
+
+    /// Pauses rewards distribution at a given money market, i.e. freezes its rewards batches indexes and the claims of the
+    /// rewards distributed at the money market. Meant to be used when a mispriced rewards batch or a booster bug is
+    /// discovered.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the Guardian.
+    /// - Rewards batches are updated up to this point before pausing. While paused, neither their indexes nor their last
+    ///   update times are advanced.
+    /// - Rewards distributed to accounts at the money market while paused are held for them instead of being claimable.
+    /// - While paused, rewards batches at the money market can be created or cancelled but not otherwise modified.
+    ///
+    #[endpoint(pauseRewardsDistribution)]
+    fn pause_rewards_distribution(&self, money_market: &ManagedAddress) {
+        self.require_admin_or_guardian();
+        self.require_whitelisted_money_market(money_market);
+
+        require!(!self.is_rewards_distribution_paused(money_market), ERROR_REWARDS_DISTRIBUTION_ALREADY_PAUSED);
+
+        self.update_supply_rewards_batches_state(money_market);
+        self.update_borrow_rewards_batches_state(money_market);
+
+        self.rewards_paused_markets().insert(money_market.clone());
+
+        self.rewards_distribution_paused_event(money_market, true);
+    }
+
+    /// Resumes rewards distribution at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The paused period is not distributed, i.e. its rewards are kept as undistributed rewards.
+    /// - Rewards held for accounts while paused become claimable the next time rewards are distributed to them.
+    ///
+    #[endpoint(resumeRewardsDistribution)]
+    fn resume_rewards_distribution(&self, money_market: &ManagedAddress) {
+        self.require_admin();
+
+        require!(self.is_rewards_distribution_paused(money_market), ERROR_REWARDS_DISTRIBUTION_NOT_PAUSED);
+
+        self.rewards_paused_markets().swap_remove(money_market);
+
+        self.skip_rewards_batches_paused_period(money_market);
+
+        self.rewards_distribution_paused_event(money_market, false);
     }
 }
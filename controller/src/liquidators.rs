@@ -0,0 +1,232 @@
+multiversx_sc::imports!();
+
+use super::{constants::*, errors::*, events, proxies, risk_profile, shared, storage};
+
+#[multiversx_sc::module]
+pub trait LiquidatorsModule: admin::AdminModule + events::EventModule + proxies::ProxyModule + risk_profile::RiskProfileModule + shared::SharedModule + storage::StorageModule {
+    /// Sets the liquidation priority window, i.e. the amount of seconds after an account has been detected as liquidatable
+    /// during which only bonded liquidators can liquidate it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_window` - The new liquidation priority window in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - A zero window disables the priority window, i.e. liquidations are open to everyone.
+    /// - The window cannot exceed `MAX_LIQUIDATION_PRIORITY_WINDOW`.
+    ///
+    #[endpoint(setLiquidationPriorityWindow)]
+    fn set_liquidation_priority_window(&self, new_window: u64) {
+        self.require_admin();
+        require!(new_window <= MAX_LIQUIDATION_PRIORITY_WINDOW, ERROR_INVALID_LIQUIDATION_PRIORITY_WINDOW);
+
+        let old_window = self.liquidation_priority_window().get();
+        self.liquidation_priority_window().set(new_window);
+
+        self.new_liquidation_priority_window_event(old_window, new_window);
+    }
+
+    /// Sets the minimum EGLD bond required to become a bonded liquidator.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_min_bond` - The new minimum bond in EGLD.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Bonded liquidators whose bond falls below the new minimum keep their status until they are slashed or unbond.
+    ///
+    #[endpoint(setMinLiquidatorBond)]
+    fn set_min_liquidator_bond(&self, new_min_bond: BigUint) {
+        self.require_admin();
+        require!(new_min_bond > BigUint::zero(), ERROR_INVALID_MIN_LIQUIDATOR_BOND);
+
+        let old_min_bond = self.min_liquidator_bond().get();
+        self.min_liquidator_bond().set(&new_min_bond);
+
+        self.new_min_liquidator_bond_event(&old_min_bond, &new_min_bond);
+    }
+
+    /// Bonds EGLD in order to become a bonded liquidator, which gives exclusive access to liquidations during the liquidation
+    /// priority window. The caller can perform multiple calls to keep adding to its bond.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with EGLD.
+    /// - The resulting bond must be at least the minimum liquidator bond.
+    /// - Cannot be called while unbonding.
+    ///
+    #[payable("EGLD")]
+    #[endpoint(bondLiquidator)]
+    fn bond_liquidator(&self) {
+        let min_bond = self.min_liquidator_bond().get();
+        require!(min_bond > BigUint::zero(), ERROR_LIQUIDATOR_BONDS_DISABLED);
+
+        let liquidator = self.blockchain().get_caller();
+        require!(self.liquidator_unbonding(&liquidator).is_empty(), ERROR_LIQUIDATOR_UNBONDING);
+
+        let amount = self.call_value().egld_value().clone_value();
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let new_bond = self.liquidator_bond(&liquidator).get() + &amount;
+        require!(new_bond >= min_bond, ERROR_INSUFFICIENT_LIQUIDATOR_BOND);
+
+        self.liquidator_bond(&liquidator).set(&new_bond);
+        self.total_liquidator_bonds().update(|total| *total += &amount);
+        self.bonded_liquidators().insert(liquidator.clone());
+
+        self.liquidator_bond_event(&liquidator, &amount, &new_bond);
+    }
+
+    /// Gives up the bonded liquidator status and starts the unbonding period, after which the bond can be withdrawn.
+    ///
+    /// # Notes:
+    ///
+    /// - The bond remains slashable during the unbonding period.
+    ///
+    #[endpoint(unbondLiquidator)]
+    fn unbond_liquidator(&self) {
+        let liquidator = self.blockchain().get_caller();
+        require!(self.bonded_liquidators().swap_remove(&liquidator), ERROR_NOT_BONDED_LIQUIDATOR);
+
+        let timestamp = self.blockchain().get_block_timestamp() + LIQUIDATOR_UNBONDING_PERIOD;
+        self.liquidator_unbonding(&liquidator).set(timestamp);
+
+        self.liquidator_unbond_event(&liquidator, timestamp);
+    }
+
+    /// Withdraws the remaining bond of the caller once its unbonding period has elapsed.
+    ///
+    #[endpoint(withdrawLiquidatorBond)]
+    fn withdraw_liquidator_bond(&self) -> BigUint {
+        let liquidator = self.blockchain().get_caller();
+        require!(!self.liquidator_unbonding(&liquidator).is_empty(), ERROR_LIQUIDATOR_NOT_UNBONDING);
+        require!(self.blockchain().get_block_timestamp() >= self.liquidator_unbonding(&liquidator).get(), ERROR_LIQUIDATOR_BOND_LOCKED);
+
+        self.liquidator_unbonding(&liquidator).clear();
+        let bond = self.liquidator_bond(&liquidator).take();
+        self.total_liquidator_bonds().update(|total| *total -= &bond);
+        self.send().direct_non_zero_egld(&liquidator, &bond);
+
+        self.liquidator_bond_withdrawal_event(&liquidator, &bond);
+
+        bond
+    }
+
+    /// Slashes the bond of a liquidator that has been griefing, e.g. by holding liquidations back during the liquidation
+    /// priority window. The slashed EGLD is sent to the admin.
+    ///
+    /// # Arguments:
+    ///
+    /// - `liquidator` - The address of the liquidator.
+    /// - `amount` - The amount of EGLD to slash.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Liquidators whose bond falls below the minimum liquidator bond lose their bonded status.
+    ///
+    #[endpoint(slashLiquidator)]
+    fn slash_liquidator(&self, liquidator: &ManagedAddress, amount: &BigUint) {
+        self.require_admin();
+        require!(amount > &BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let bond = self.liquidator_bond(liquidator).get();
+        require!(amount <= &bond, ERROR_SLASH_EXCEEDS_LIQUIDATOR_BOND);
+
+        let new_bond = bond - amount;
+        self.liquidator_bond(liquidator).set(&new_bond);
+        self.total_liquidator_bonds().update(|total| *total -= amount);
+
+        if new_bond < self.min_liquidator_bond().get() {
+            self.bonded_liquidators().swap_remove(liquidator);
+        }
+
+        let admin = self.blockchain().get_caller();
+        self.send().direct_egld(&admin, amount);
+
+        self.slash_liquidator_event(liquidator, amount, &new_bond);
+    }
+
+    /// Flags a given account as liquidatable, which starts its liquidation priority window. If the account is no longer
+    /// liquidatable, a previous detection is cleared instead. Returns whether the account is liquidatable.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone, e.g. by an unbonded liquidator in order to start the priority window of an account that
+    ///   has not been detected yet.
+    /// - Detections are also recorded by liquidations and cleared whenever the account is found solvent by a borrow or a
+    ///   redeem, such that stale detections do not outlive the account shortfall.
+    ///
+    #[endpoint(flagLiquidatableAccount)]
+    fn flag_liquidatable_account(&self, account: &ManagedAddress) -> bool {
+        let risk_profile = self.simulate_risk_profile(account, &ManagedAddress::zero(), &BigUint::zero(), &BigUint::zero(), true);
+        self.update_liquidatable_detection(account, &risk_profile)
+    }
+
+    /// Records the first detection of a given account as liquidatable or clears it if the account is solvent, based on a
+    /// given risk profile. Returns whether the account is liquidatable.
+    ///
+    fn update_liquidatable_detection(&self, account: &ManagedAddress, risk_profile: &risk_profile::RiskProfile<Self::Api>) -> bool {
+        match risk_profile {
+            risk_profile::RiskProfile::RiskyOrInsolvent(_) => {
+                if self.liquidatable_since(account).is_empty() {
+                    let timestamp = self.blockchain().get_block_timestamp();
+                    self.liquidatable_since(account).set(timestamp);
+                    self.liquidatable_account_event(account, timestamp);
+                }
+                true
+            },
+            risk_profile::RiskProfile::Solvent(_) => {
+                self.clear_liquidatable_detection(account);
+                false
+            },
+        }
+    }
+
+    /// Clears a previous detection of a given account as liquidatable, if any.
+    ///
+    fn clear_liquidatable_detection(&self, account: &ManagedAddress) {
+        if !self.liquidatable_since(account).is_empty() {
+            self.liquidatable_since(account).clear();
+            self.clear_liquidatable_account_event(account);
+        }
+    }
+
+    /// Checks whether a given liquidator can liquidate a given borrower at a given borrow market, considering the liquidation
+    /// priority window. Bonded liquidators can always liquidate, while everyone else must wait for the priority window to
+    /// elapse since the borrower has been flagged as liquidatable.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `borrower` - The address of the borrower.
+    /// - `liquidator` - The address of the liquidator.
+    ///
+    /// # Notes:
+    ///
+    /// - Borrows at deprecated markets are not subject to the priority window.
+    /// - Liquidations are open to everyone while there are no bonded liquidators.
+    /// - Accounts that have not been detected yet can be flagged using `flagLiquidatableAccount`.
+    ///
+    #[view(isLiquidationOpen)]
+    fn is_liquidation_open(&self, borrow_market: &ManagedAddress, borrower: &ManagedAddress, liquidator: &ManagedAddress) -> bool {
+        let window = self.liquidation_priority_window().get();
+        if window == 0 || self.is_deprecated(borrow_market) || self.bonded_liquidators().is_empty() || self.bonded_liquidators().contains(liquidator) {
+            return true;
+        }
+
+        if self.liquidatable_since(borrower).is_empty() {
+            return false;
+        }
+
+        self.blockchain().get_block_timestamp() >= self.liquidatable_since(borrower).get() + window
+    }
+}
@@ -1,7 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use super::{errors::*, events, guardian, policies, proxies, rewards, risk_profile, shared, storage};
+use super::{constants::*, errors::*, events, guardian, policies, proxies, rewards, risk_profile, shared, storage};
+use crate::storage::{LiquidationOutcome, Status, SwapStep};
 
 pub type ExitMarketAndRedeemResultType<BigUint> = MultiValue3<EgldOrEsdtTokenPayment<BigUint>, EsdtTokenPayment<BigUint>, EsdtTokenPayment<BigUint>>;
 
@@ -17,7 +18,9 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
     ///
     /// # Notes:
     ///
-    /// - Must be paid with one or many valid ESDT Hatom tokens
+    /// - Must be paid with one or many valid ESDT Hatom tokens and/or native EGLD.
+    /// - Native EGLD is minted at the EGLD money market (or, if there is none, wrapped and minted at the WEGLD money market)
+    ///   and the resulting Hatom tokens are deposited as collateral.
     ///
     #[payable("*")]
     #[endpoint(enterMarkets)]
@@ -31,14 +34,96 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
                 account
             },
         };
-        let payments = self.call_value().all_esdt_transfers();
+        let egld_amount = self.call_value().egld_value().clone_value();
+        let mut payments = self.call_value().all_esdt_transfers().clone_value();
+        if egld_amount > BigUint::zero() {
+            let token_payment = self.mint_with_egld(&account, &egld_amount);
+            payments.push(token_payment);
+        }
+
+        let batched = payments.len() > 1 && self.start_market_changes_batch();
         for payment in payments.iter() {
             self.enter_market(&account, payment);
         }
+
+        if batched {
+            self.end_market_changes_batch();
+        }
+    }
+
+    /// Opts in or out the caller from automatically entering markets with freshly minted Hatom tokens, i.e. whenever the
+    /// caller mints at a money market, the minted tokens are deposited as collateral in the same transaction instead of
+    /// being sent to the caller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `enabled` - Whether or not minted tokens should automatically enter the market.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts are opted out by default.
+    /// - Minting fails if the account cannot enter the market, e.g. if it has reached the maximum number of markets.
+    ///
+    #[endpoint(setAutoEnterMarkets)]
+    fn set_auto_enter_markets(&self, enabled: bool) {
+        let caller = self.blockchain().get_caller();
+
+        if enabled {
+            self.auto_enter_markets(&caller).set(true);
+        } else {
+            self.auto_enter_markets(&caller).clear();
+        }
+
+        self.auto_enter_markets_event(&caller, enabled);
+    }
+
+    /// Opts in or out the caller from receiving native EGLD instead of WEGLD, i.e. whenever the caller receives the
+    /// underlying of a WEGLD money market redeemed through the controller or claims WEGLD rewards, the WEGLD is unwrapped in
+    /// the same transaction.
+    ///
+    /// # Arguments:
+    ///
+    /// - `enabled` - Whether or not WEGLD should be unwrapped into native EGLD.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts are opted out by default.
+    ///
+    #[endpoint(setNativeEgld)]
+    fn set_native_egld(&self, enabled: bool) {
+        let caller = self.blockchain().get_caller();
+
+        if enabled {
+            self.native_egld(&caller).set(true);
+        } else {
+            self.native_egld(&caller).clear();
+        }
+
+        self.native_egld_event(&caller, enabled);
+    }
+
+    /// Mints Hatom tokens with a given amount of native EGLD on behalf of a given account, wrapping it first if the EGLD
+    /// money market is a WEGLD one.
+    ///
+    fn mint_with_egld(&self, account: &ManagedAddress, egld_amount: &BigUint) -> EsdtTokenPayment {
+        let money_market = self.get_egld_money_market();
+
+        let (underlying_id, _) = self.identifiers(&money_market).get();
+        if !underlying_id.is_egld() {
+            require!(!self.egld_wrapper().is_empty(), ERROR_EGLD_WRAPPER_NOT_INITIALIZED);
+            self.wrap_egld(egld_amount);
+        }
+
+        let underlying_payment = EgldOrEsdtTokenPayment::new(underlying_id, 0, egld_amount.clone());
+        let token_payment = self.mint(&money_market, &underlying_payment);
+
+        self.enter_market_with_egld_event(&money_market, account, egld_amount, &token_payment);
+
+        token_payment
     }
 
     fn enter_market(&self, account: &ManagedAddress, payment: EsdtTokenPayment) {
-        let (token_id, _, amount) = payment.into_tuple();
+        let (token_id, nonce, amount) = payment.into_tuple();
 
         require!(self.is_whitelisted_token_id(&token_id), ERROR_NON_WHITELISTED_MARKET);
         require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
@@ -51,6 +136,11 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
         self.update_supply_rewards_batches_state(&money_market);
         self.distribute_supplier_batches_rewards(&money_market, account);
 
+        // positions are accounted per nonce, such that the very same positions can be returned
+        if self.position_market(&money_market).get() {
+            self.add_collateral_position(&money_market, account, nonce, &amount);
+        }
+
         self.enter_market_internal(&money_market, account, &amount);
     }
 
@@ -68,6 +158,7 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
     /// - The caller must have collateral in the corresponding money market.
     /// - The amount of tokens to withdraw should not exceed the current deposited amount.
     /// - The caller must be providing the necessary collateral for any outstanding borrows.
+    /// - At position markets, meta-ESDT positions are returned in the order they have been deposited.
     ///
     #[endpoint(exitMarket)]
     fn exit_market(&self, money_market: ManagedAddress, opt_tokens: OptionalValue<BigUint>) -> EsdtTokenPayment {
@@ -84,21 +175,34 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
     /// - `opt_tokens` - If given, the amount of collateral tokens to remove.
     /// - `opt_underlying_amount` - An optional amount of underlying asset to receive back in exchange for the paid Hatom's
     ///   tokens.
+    /// - `opt_to` - An optional recipient of the underlying asset. Defaults to the caller.
+    /// - `opt_min_underlying_out` - An optional minimum amount of underlying to be received, protecting the redeemer against
+    ///   unexpected exchange rate movements.
     ///
     /// # Notes:
     ///
     /// - The provided address must be a whitelisted money market.
     /// - The caller must have collateral in the corresponding money market.
     /// - The amount of tokens to withdraw should not exceed the current deposited amount.
+    /// - Remainder Hatom's tokens, if any, are always sent back to the caller.
+    /// - Not supported by position markets.
+    /// - WEGLD is unwrapped into native EGLD if the recipient has opted in via `setNativeEgld`.
     ///
     #[endpoint(exitMarketAndRedeem)]
-    fn exit_market_and_redeem(&self, money_market: &ManagedAddress, opt_tokens: Option<BigUint>, opt_underlying_amount: Option<BigUint>) -> ExitMarketAndRedeemResultType<Self::Api> {
+    fn exit_market_and_redeem(&self, money_market: &ManagedAddress, opt_tokens: Option<BigUint>, opt_underlying_amount: Option<BigUint>, opt_to: OptionalValue<ManagedAddress>, opt_min_underlying_out: OptionalValue<BigUint>) -> ExitMarketAndRedeemResultType<Self::Api> {
+        self.require_not_position_market(money_market);
+
         let redeemer = self.blockchain().get_caller();
+        let recipient = opt_to.into_option().unwrap_or_else(|| redeemer.clone());
         let token_payment_in = self.exit_market_internal(money_market, &redeemer, OptionalValue::from(opt_tokens), false);
 
         // redeem tokens
         let (underlying_payment, token_payment_burn) = self.redeem(money_market, &token_payment_in, opt_underlying_amount).into_tuple();
 
+        if let Some(min_underlying_out) = opt_min_underlying_out.into_option() {
+            require!(underlying_payment.amount >= min_underlying_out, ERROR_NOT_ENOUGH_REDEEMED_UNDERLYING);
+        }
+
         // return the remaining token payment to the caller
         if token_payment_in.amount > token_payment_burn.amount {
             let (token_id, _, amount_eff) = token_payment_burn.clone().into_tuple();
@@ -107,16 +211,431 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
             self.send().direct_esdt(&redeemer, &token_payment_out.token_identifier, 0, &token_payment_out.amount);
         }
 
-        // send underlying to redeemer
+        // send underlying to recipient, unwrapping WEGLD if the recipient has opted in
+        let underlying_payment = self.try_unwrap_wegld_payment(&recipient, underlying_payment);
         let (underlying_id, _, underlying_amount) = underlying_payment.clone().into_tuple();
-        self.send().direct(&redeemer, &underlying_id, 0, &underlying_amount);
+        self.send().direct(&recipient, &underlying_id, 0, &underlying_amount);
 
         // this event is useful because the redeemer has been registered as the controller at the money market
-        self.exit_market_and_redeem_event(money_market, &redeemer, &underlying_payment, &token_payment_burn);
+        self.emit_exit_market_and_redeem_events(money_market, &redeemer, &recipient, &underlying_payment, &token_payment_burn);
 
         (underlying_payment, token_payment_in, token_payment_burn).into()
     }
 
+    /// Emits the exit market and redeem event and, if the underlying has been sent to a different recipient, the exit market
+    /// and redeem to event.
+    ///
+    fn emit_exit_market_and_redeem_events(&self, money_market: &ManagedAddress, redeemer: &ManagedAddress, recipient: &ManagedAddress, underlying_payment: &EgldOrEsdtTokenPayment, token_payment: &EsdtTokenPayment) {
+        self.exit_market_and_redeem_event(money_market, redeemer, underlying_payment, token_payment);
+        if redeemer != recipient {
+            self.exit_market_and_redeem_to_event(money_market, redeemer, recipient, underlying_payment, token_payment);
+        }
+    }
+
+    /// Repays an outstanding borrow using a token different from the borrow market underlying. The paid tokens are swapped
+    /// into the underlying at xExchange and the resulting amount is used to repay the borrow.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the borrow money market smart contract.
+    /// - `swap_path` - The swap path used to convert the paid tokens into the underlying. If the underlying is EGLD, the swap
+    ///   path needs to use the WEGLD token identifier.
+    /// - `min_underlying_out` - The minimum amount of underlying to be obtained from the swap.
+    /// - `opt_borrower` - An optional address to repay on behalf of this account. Defaults to the caller.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with a single ESDT token supported for repayments with automatic swap.
+    /// - The provided address must be a whitelisted money market, other than a position market.
+    /// - The swap cannot lose more than the maximum slippage as compared to oracle values.
+    /// - Paying WEGLD to an EGLD market requires no swap, i.e. the swap path can be empty.
+    /// - The swapped underlying can be higher than the outstanding borrow. In such case, the remainder is returned to the
+    ///   caller in underlying.
+    ///
+    #[payable("*")]
+    #[endpoint(repayWithAnyToken)]
+    fn repay_with_any_token(&self, money_market: ManagedAddress, swap_path: ManagedVec<SwapStep<Self::Api>>, min_underlying_out: BigUint, opt_borrower: OptionalValue<ManagedAddress>) -> EgldOrEsdtTokenPayment {
+        self.require_whitelisted_money_market(&money_market);
+        self.require_not_position_market(&money_market);
+        require!(self.get_xexchange_router().is_some(), ERROR_ROUTER_NOT_INITIALIZED);
+
+        let token_payment = self.call_value().single_esdt();
+        let (token_id, _, amount) = token_payment.clone().into_tuple();
+        require!(self.repay_swap_tokens().contains(&token_id), ERROR_INVALID_REPAY_SWAP_TOKEN);
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let payer = self.blockchain().get_caller();
+        let borrower = opt_borrower.into_option().unwrap_or_else(|| payer.clone());
+        require!(!borrower.is_zero(), ERROR_CANNOT_BE_ADDRESS_ZERO);
+
+        // swaps only deal with ESDT tokens, such that EGLD markets are repaid by unwrapping WEGLD
+        let (underlying_id, _) = self.identifiers(&money_market).get();
+        let swap_token_out = if underlying_id.is_egld() { self.wegld_id().get() } else { underlying_id.clone().unwrap_esdt() };
+
+        let underlying_amount = if token_id == swap_token_out {
+            require!(underlying_id.is_egld(), ERROR_INVALID_REPAY_SWAP_TOKEN);
+            amount.clone()
+        } else {
+            require!(!swap_path.is_empty(), ERROR_INVALID_SWAP_PATH);
+            require!(swap_path.get(0).input_token_id == token_id, ERROR_INVALID_SWAP_PATH);
+            require!(swap_path.get(swap_path.len() - 1).output_token_id == swap_token_out, ERROR_INVALID_SWAP_PATH);
+            self.custom_swap(&swap_path, true, &token_id, &amount, &swap_token_out)
+        };
+
+        require!(underlying_amount >= min_underlying_out, ERROR_NOT_ENOUGH_SWAPPED_UNDERLYING);
+
+        // make sure the swap does not lose too much money, as compared to oracle values
+        let token_price = self.get_rewards_token_price(&EgldOrEsdtTokenIdentifier::esdt(token_id));
+        let underlying_price = self.get_underlying_price(&money_market);
+        let swap_value_in = self.wad_mul(&amount, &token_price);
+        let swap_value_out = self.wad_mul(&underlying_amount, &underlying_price);
+        self.require_swap_within_max_slippage(&swap_value_in, &swap_value_out);
+
+        if underlying_id.is_egld() {
+            self.unwrap_egld(&underlying_amount);
+        }
+
+        // the money market returns any remainder to the controller
+        let underlying_payment = EgldOrEsdtTokenPayment::new(underlying_id.clone(), 0, underlying_amount.clone());
+        let repayment = self.repay_borrow(&money_market, &borrower, &underlying_payment);
+
+        let refund_amount = &underlying_amount - &repayment.amount;
+        if refund_amount > BigUint::zero() {
+            self.send().direct(&payer, &underlying_id, 0, &refund_amount);
+        }
+
+        let refund = EgldOrEsdtTokenPayment::new(underlying_id, 0, refund_amount);
+        self.repay_with_any_token_event(&money_market, &payer, &borrower, &token_payment, &repayment, &refund);
+
+        repayment
+    }
+
+    /// Performs multiple liquidations in a single call. Each liquidation repays a borrow at a given borrow market and seizes
+    /// collateral at a given collateral market, with the controller acting as the liquidator on behalf of the caller.
+    /// Liquidations that cannot be performed are skipped and reported instead of reverting the whole call.
+    ///
+    /// # Arguments:
+    ///
+    /// - `liquidations` - Tuples of borrower, borrow market, collateral market and underlying repayment amount.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlyings of the borrow markets, either EGLD or one or many ESDT tokens. Repayments are
+    ///   taken from the payment of the corresponding underlying, in order.
+    /// - Repayments are clamped to the maximum allowed by the close factor.
+    /// - Skipped liquidations are those that fail the controller checks beforehand, which mirror the ones performed by the
+    ///   money markets, e.g. a solvent borrower or a missing payment.
+    /// - Any other failure, such as a missing oracle price or a failure within a money market, reverts the whole call, i.e.
+    ///   beyond the mirrored checks the batch is all-or-nothing.
+    /// - Liquidations are subject to the liquidation priority window for the caller.
+    /// - Collateral at position markets cannot be seized in batches.
+    /// - Market changes are notified at once to a booster observer that supports batched notifications.
+    /// - The seized Hatom tokens and any remaining payment are sent to the caller.
+    ///
+    #[payable("*")]
+    #[endpoint(liquidateAccounts)]
+    fn liquidate_accounts(&self, liquidations: MultiValueEncoded<MultiValue4<ManagedAddress, ManagedAddress, ManagedAddress, BigUint>>) -> MultiValueEncoded<LiquidationOutcome<Self::Api>> {
+        let n = liquidations.len();
+        require!(n > 0 && n <= MAX_LIQUIDATIONS_PER_BATCH, ERROR_INVALID_LIQUIDATIONS_BATCH);
+
+        let liquidator = self.blockchain().get_caller();
+
+        let mut budgets: ManagedVec<EgldOrEsdtTokenPayment> = ManagedVec::new();
+        match self.call_value().any_payment() {
+            EgldOrMultiEsdtPayment::Egld(amount) => budgets.push(EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, amount)),
+            EgldOrMultiEsdtPayment::MultiEsdt(payments) => {
+                for payment in payments.iter() {
+                    budgets.push(EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier), 0, payment.amount));
+                }
+            },
+        }
+
+        // market changes at the money markets are notified at once at the end of the batch
+        let batched = self.start_market_changes_batch();
+
+        let mut outcomes = MultiValueEncoded::new();
+        let mut seized_payments: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
+        for liquidation in liquidations.into_iter() {
+            let (borrower, borrow_market, collateral_market, amount) = liquidation.into_tuple();
+
+            let outcome = match self.try_liquidate_account(&liquidator, &borrower, &borrow_market, &collateral_market, &amount, &mut budgets) {
+                Ok((repayment, seized_payment)) => {
+                    let seized_tokens = seized_payment.amount.clone();
+                    seized_payments.push(seized_payment);
+                    LiquidationOutcome {
+                        borrower,
+                        borrow_market,
+                        collateral_market,
+                        liquidated: true,
+                        repayment,
+                        seized_tokens,
+                        reason: ManagedBuffer::new(),
+                    }
+                },
+                Err(reason) => LiquidationOutcome {
+                    borrower,
+                    borrow_market,
+                    collateral_market,
+                    liquidated: false,
+                    repayment: BigUint::zero(),
+                    seized_tokens: BigUint::zero(),
+                    reason: ManagedBuffer::from(reason),
+                },
+            };
+
+            self.batch_liquidation_event(&liquidator, &outcome);
+            outcomes.push(outcome);
+        }
+
+        if batched {
+            self.end_market_changes_batch();
+        }
+
+        // send seized tokens and remaining payments back to the liquidator
+        for payment in seized_payments.iter() {
+            self.send().direct_non_zero_esdt_payment(&liquidator, &payment);
+        }
+
+        for budget in budgets.iter() {
+            self.send().direct_non_zero(&liquidator, &budget.token_identifier, 0, &budget.amount);
+        }
+
+        outcomes
+    }
+
+    /// Tries to perform a single liquidation of a batch, returning the effective repayment and the seized tokens or the
+    /// reason why it has been skipped.
+    ///
+    fn try_liquidate_account(&self, liquidator: &ManagedAddress, borrower: &ManagedAddress, borrow_market: &ManagedAddress, collateral_market: &ManagedAddress, amount: &BigUint, budgets: &mut ManagedVec<EgldOrEsdtTokenPayment>) -> Result<(BigUint, EsdtTokenPayment), &'static [u8]> {
+        if !self.is_whitelisted_money_market(borrow_market) || !self.is_whitelisted_money_market(collateral_market) {
+            return Err(ERROR_NON_WHITELISTED_MARKET);
+        }
+
+        if self.position_market(collateral_market).get() {
+            return Err(ERROR_UNSUPPORTED_BY_POSITION_MARKET);
+        }
+
+        if borrower == liquidator || borrower == &self.blockchain().get_sc_address() {
+            return Err(ERROR_INVALID_BORROWER);
+        }
+
+        if amount == &BigUint::zero() {
+            return Err(ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        }
+
+        if self.get_global_seize_status() != Status::Active {
+            return Err(ERROR_GLOBAL_SEIZE_PAUSED);
+        }
+
+        for money_market in self.account_markets(borrower).iter() {
+            if self.seize_status(&money_market).get() != Status::Active {
+                return Err(ERROR_SEIZE_PAUSED);
+            }
+        }
+
+        // find a payment of the borrow market underlying
+        let (underlying_id, _) = self.identifiers(borrow_market).get();
+        let budget_index = match budgets.iter().position(|budget| budget.token_identifier == underlying_id && &budget.amount >= amount) {
+            Some(index) => index,
+            None => return Err(ERROR_INSUFFICIENT_LIQUIDATION_PAYMENT),
+        };
+
+        // checks are performed with up to date borrows
+        self.accrue_interest(borrow_market);
+        if collateral_market != borrow_market {
+            self.accrue_interest(collateral_market);
+        }
+
+        let borrow_amount = self.get_stored_account_borrow_amount(borrow_market, borrower);
+        if borrow_amount == BigUint::zero() {
+            return Err(ERROR_ACCOUNT_NOT_LIQUIDATABLE);
+        }
+
+        // as in the liquidation policy, the first detection starts the liquidation priority window
+        if !self.is_deprecated(borrow_market) {
+            let risk_profile = self.simulate_risk_profile(borrower, &ManagedAddress::zero(), &BigUint::zero(), &BigUint::zero(), true);
+            if !self.update_liquidatable_detection(borrower, &risk_profile) {
+                return Err(ERROR_ACCOUNT_NOT_LIQUIDATABLE);
+            }
+        }
+
+        // the controller is the liquidator at the money markets, so the priority window is checked for the caller here
+        if !self.is_liquidation_open(borrow_market, borrower, liquidator) {
+            return Err(ERROR_LIQUIDATION_PRIORITY_WINDOW);
+        }
+
+        let max_repayment = self.get_max_liquidation_repayment(borrow_market, borrower);
+        let repayment = core::cmp::min(amount, &max_repayment).clone();
+        if repayment == BigUint::zero() {
+            return Err(ERROR_ACCOUNT_NOT_LIQUIDATABLE);
+        }
+
+        let tokens_to_seize = self.tokens_to_seize(borrow_market, collateral_market, &repayment);
+        if tokens_to_seize > self.get_account_collateral_tokens(collateral_market, borrower) {
+            return Err(ERROR_NOT_ENOUGH_COLLATERAL_TO_SEIZE);
+        }
+
+        // the effective repayment is measured, given that the money market refunds any excess
+        let balance_before = self.blockchain().get_sc_balance(&underlying_id, 0);
+        let underlying_payment = EgldOrEsdtTokenPayment::new(underlying_id.clone(), 0, repayment);
+        let seized_payment = self.liquidate_borrow(borrow_market, borrower, collateral_market, &underlying_payment);
+        let balance_after = self.blockchain().get_sc_balance(&underlying_id, 0);
+        let repayment = balance_before - balance_after;
+
+        let budget = budgets.get(budget_index);
+        let new_budget = EgldOrEsdtTokenPayment::new(underlying_id, 0, &budget.amount - &repayment);
+        let _ = budgets.set(budget_index, &new_budget);
+
+        Ok((repayment, seized_payment))
+    }
+
+    /// Claims the caller rewards for the given rewards tokens, swaps them into the underlying of a given money market (if
+    /// needed), mints and deposits the resulting Hatom tokens as collateral, all in a single call.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract in which rewards are collateralized.
+    /// - `min_tokens_out` - The minimum amount of Hatom tokens to be deposited as collateral.
+    /// - `swaps` - Pairs of rewards token identifiers and swap paths used to convert them into the underlying. If empty, only
+    ///   rewards paid in the underlying are collateralized.
+    ///
+    /// # Notes:
+    ///
+    /// - The provided address must be a whitelisted money market, other than a position market.
+    /// - A rewards token that is the underlying, or its wrapped or unwrapped EGLD version, requires no swap, i.e. its swap
+    ///   path can be empty. Otherwise, swap paths need to use the WEGLD token identifier instead of EGLD.
+    /// - Each swap cannot lose more than the maximum slippage as compared to oracle values.
+    /// - Meta-ESDT rewards tokens cannot be swapped.
+    /// - Rewards held while rewards distribution is paused at a money market are not claimed.
+    ///
+    #[endpoint(claimRewardsAndCollateralize)]
+    fn claim_rewards_and_collateralize(&self, money_market: ManagedAddress, min_tokens_out: BigUint, mut swaps: MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, ManagedVec<SwapStep<Self::Api>>>>) -> EsdtTokenPayment {
+        self.require_whitelisted_money_market(&money_market);
+        self.require_not_position_market(&money_market);
+
+        let account = self.blockchain().get_caller();
+        let (underlying_id, _) = self.identifiers(&money_market).get();
+
+        if swaps.is_empty() {
+            swaps.push((underlying_id.clone(), ManagedVec::new()).into());
+        }
+
+        // only distribute rewards at markets with any of the rewards tokens
+        let mut markets: ManagedVec<ManagedAddress> = ManagedVec::new();
+        for market in self.get_whitelisted_markets().iter() {
+            if swaps.clone().into_iter().any(|swap| self.market_has_token_rewards_batch(&market, &swap.into_tuple().0)) {
+                markets.push(market.clone_value());
+            }
+        }
+
+        require!(!markets.is_empty(), ERROR_INVALID_REWARDS_TOKEN_IDS);
+
+        self.distribute_rewards_internal(true, true, &markets, &ManagedVec::from_single_item(account.clone()));
+
+        // swaps only deal with ESDT tokens, such that EGLD markets are minted by unwrapping WEGLD
+        let swap_token_out = if underlying_id.is_egld() { self.wegld_id().get() } else { underlying_id.clone().unwrap_esdt() };
+        let underlying_price = self.get_underlying_price(&money_market);
+
+        let mut underlying_amount = BigUint::zero();
+        let mut wegld_amount = BigUint::zero();
+        for swap in swaps.into_iter() {
+            let (rewards_token_id, swap_path) = swap.into_tuple();
+
+            let rewards = self.get_account_accrued_rewards(&account, &rewards_token_id);
+
+            // don't do anything if rewards are zero
+            if rewards == BigUint::zero() {
+                continue;
+            }
+
+            // Meta-ESDT rewards cannot be swapped
+            require!(self.rewards_token_nonce(&rewards_token_id).get() == 0, ERROR_META_ESDT_REWARDS_NOT_SWAPPABLE);
+
+            // should be enough balance left in the contract, otherwise fail (should not happen)
+            require!(rewards <= self.get_rewards_token_balance(&rewards_token_id, 0), ERROR_INSUFFICIENT_REWARDS_BALANCE);
+
+            if rewards_token_id == underlying_id {
+                self.take_rewards_token(&rewards_token_id, &rewards);
+                underlying_amount += &rewards;
+            } else {
+                // if rewards token is EGLD then use WEGLD from the buffer or add a EGLD => WEGLD step first
+                let swap_token_in = if rewards_token_id.is_egld() {
+                    self.take_wegld(&rewards);
+                    self.wegld_id().get()
+                } else {
+                    self.take_rewards_token(&rewards_token_id, &rewards);
+                    rewards_token_id.clone().unwrap_esdt()
+                };
+
+                let amount_out = if swap_token_in == swap_token_out {
+                    rewards.clone()
+                } else {
+                    require!(!swap_path.is_empty(), ERROR_INVALID_SWAP_PATH);
+                    require!(swap_path.get(0).input_token_id == swap_token_in, ERROR_INVALID_SWAP_PATH);
+                    require!(swap_path.get(swap_path.len() - 1).output_token_id == swap_token_out, ERROR_INVALID_SWAP_PATH);
+                    let amount_out = self.custom_swap(&swap_path, true, &swap_token_in, &rewards, &swap_token_out);
+
+                    // make sure the swap does not lose too much money, as compared to oracle values
+                    let rewards_token_price = self.get_rewards_token_price(&rewards_token_id);
+                    let swap_value_in = self.wad_mul(&rewards, &rewards_token_price);
+                    let swap_value_out = self.wad_mul(&amount_out, &underlying_price);
+                    self.require_swap_within_max_slippage(&swap_value_in, &swap_value_out);
+
+                    amount_out
+                };
+
+                if underlying_id.is_egld() {
+                    wegld_amount += &amount_out;
+                }
+                underlying_amount += &amount_out;
+            }
+
+            self.account_accrued_rewards(&account, &rewards_token_id).set(&BigUint::zero());
+            self.rewards_token_claimed_event(&account, &rewards_token_id, &rewards);
+        }
+
+        require!(underlying_amount > BigUint::zero(), ERROR_NO_REWARDS_TO_COLLATERALIZE);
+
+        if wegld_amount > BigUint::zero() {
+            self.unwrap_egld(&wegld_amount);
+        }
+
+        // mint on behalf of the account and deposit the Hatom tokens as collateral
+        let underlying_payment = EgldOrEsdtTokenPayment::new(underlying_id, 0, underlying_amount.clone());
+        let token_payment = self.mint(&money_market, &underlying_payment);
+        require!(token_payment.amount >= min_tokens_out, ERROR_NOT_ENOUGH_MINTED_TOKENS);
+
+        self.enter_market(&account, token_payment.clone());
+
+        self.rewards_collateralized_event(&money_market, &account, &underlying_amount, &token_payment);
+
+        token_payment
+    }
+
+    /// Registers an account as a member of the calling money market, such that a borrow imported during a code migration is
+    /// accounted for in risk checks and liquidations.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The address of the borrower.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by a whitelisted money market.
+    /// - Accounts that are already members of the money market are left untouched.
+    /// - The maximum number of markets per account is not enforced, such that an import can never be reverted by an
+    ///   account that already holds positions in many markets.
+    ///
+    #[endpoint(registerBorrower)]
+    fn register_borrower(&self, borrower: &ManagedAddress) {
+        let money_market = self.blockchain().get_caller();
+        self.require_whitelisted_money_market(&money_market);
+
+        if !self.market_members(&money_market).contains(borrower) {
+            self.record_market_entry(&money_market, borrower, &BigUint::zero());
+        }
+    }
+
     fn exit_market_internal(&self, money_market: &ManagedAddress, caller: &ManagedAddress, opt_tokens: OptionalValue<BigUint>, send: bool) -> EsdtTokenPayment {
         self.require_whitelisted_money_market(&money_market);
 
@@ -134,10 +653,13 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
             },
         };
 
-        // check protocol balance
+        // check protocol balance (positions are tracked per nonce instead)
         let (_, token_id) = self.identifiers(&money_market).get();
-        let sc_address = self.blockchain().get_sc_address();
-        require!(self.blockchain().get_esdt_balance(&sc_address, &token_id, 0) >= exit_tokens, ERROR_INSUFFICIENT_BALANCE);
+        let is_position_market = self.position_market(&money_market).get();
+        if !is_position_market {
+            let sc_address = self.blockchain().get_sc_address();
+            require!(self.blockchain().get_esdt_balance(&sc_address, &token_id, 0) >= exit_tokens, ERROR_INSUFFICIENT_BALANCE);
+        }
 
         // check risk profile
         require!(self.redeem_allowed(&money_market, &caller, &exit_tokens), ERROR_REQUESTER_RISKY_OR_INSOLVENT);
@@ -152,7 +674,13 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
         self.remove_account_market_internal(&money_market, &caller);
 
         // send tokens to caller
-        if send {
+        if is_position_market {
+            let positions = self.take_collateral_positions(&money_market, &caller, &exit_tokens);
+            if send {
+                self.send().direct_multi(&caller, &positions);
+            }
+            self.exit_market_positions_event(&money_market, &caller, &positions);
+        } else if send {
             self.send().direct_esdt(&caller, &token_id, 0, &exit_tokens);
         }
 
@@ -181,6 +709,31 @@ pub trait MarketModule: admin::AdminModule + events::EventModule + guardian::Gua
         self.remove_account_market_internal(money_market, &account);
     }
 
+    /// Prunes an orphaned account from the members of a given money market, i.e. an account that has no collateral and no
+    /// outstanding borrow in the given money market but is still tracked as a member.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The address of the account to prune.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - The provided address must be a whitelisted money market.
+    /// - Fails if the account is not a member or if it has collateral or an outstanding borrow in the given money market.
+    ///
+    #[endpoint(pruneMarketMember)]
+    fn prune_market_member(&self, money_market: &ManagedAddress, account: &ManagedAddress) {
+        self.require_whitelisted_money_market(money_market);
+        require!(self.market_members(money_market).contains(account), ERROR_NOT_MARKET_MEMBER);
+
+        self.remove_account_market_internal(money_market, account);
+        require!(!self.market_members(money_market).contains(account), ERROR_MARKET_MEMBER_NOT_EMPTY);
+
+        self.prune_market_member_event(money_market, account);
+    }
+
     fn remove_account_market_internal(&self, money_market: &ManagedAddress, account: &ManagedAddress) {
         let (underlying_owed, _) = self.get_account_snapshot(money_market, account);
         let tokens = self.get_account_collateral_tokens(money_market, account);
@@ -1,12 +1,12 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use super::{errors::*, events, guardian, proxies, rewards, risk_profile, shared, storage};
+use super::{constants::*, errors::*, events, guardian, liquidators, proxies, rewards, risk_profile, shared, storage};
 
-use crate::storage::Status;
+use crate::storage::{DryRunLiquidation, Status};
 
 #[multiversx_sc::module]
-pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::GuardianModule + proxies::ProxyModule + shared::SharedModule + rewards::RewardsModule + risk_profile::RiskProfileModule + storage::StorageModule {
+pub trait PolicyModule: admin::AdminModule + pausable::PausableModule + events::EventModule + guardian::GuardianModule + liquidators::LiquidatorsModule + proxies::ProxyModule + shared::SharedModule + rewards::RewardsModule + risk_profile::RiskProfileModule + storage::StorageModule {
     /// Checks whether minting is allowed at a specified money market.
     ///
     /// # Arguments:
@@ -20,6 +20,7 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
     ///
     #[view(mintAllowed)]
     fn mint_allowed(&self, money_market: &ManagedAddress, amount: BigUint) -> bool {
+        self.require_not_paused();
         self.require_whitelisted_money_market(money_market);
         require!(self.get_mint_status(money_market) == Status::Active, ERROR_MINT_PAUSED);
 
@@ -64,6 +65,10 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
         if !risk_profile.can_redeem() {
             return false;
         }
+
+        // the redeemer remains solvent, so any previous liquidatable detection is stale
+        self.clear_liquidatable_detection(redeemer);
+
         self.update_supply_rewards_batches_state(money_market);
         self.distribute_supplier_batches_rewards(money_market, redeemer);
         true
@@ -84,6 +89,7 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
     ///
     #[endpoint(borrowAllowed)]
     fn borrow_allowed(&self, money_market: &ManagedAddress, borrower: &ManagedAddress, amount: &BigUint) -> bool {
+        self.require_not_paused();
         self.require_whitelisted_money_market(money_market);
 
         require!(self.get_borrow_status(money_market) == Status::Active, ERROR_BORROW_PAUSED);
@@ -100,6 +106,11 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
         // check oracle pricing
         self.get_underlying_price(money_market);
 
+        // check if the borrow exceeds the maximum borrow size per transaction (if any)
+        if let Some(max_borrow_per_tx) = self.get_max_borrow_per_tx(money_market) {
+            require!(amount <= &max_borrow_per_tx, ERROR_EXCEEDED_MAX_BORROW_PER_TX);
+        }
+
         // check if the borrow cap (if any) has been reached
         if let Some(cap) = self.get_borrow_cap(money_market) {
             let total_borrows = self.get_total_borrows(money_market);
@@ -107,6 +118,33 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
             require!(new_total_borrows < cap, ERROR_REACHED_BORROW_CAP);
         }
 
+        // check if the account borrow cap (if any) has been reached
+        if let Some(cap) = self.get_account_borrow_cap(money_market, borrower) {
+            let account_borrows = self.get_stored_account_borrow_amount(money_market, borrower);
+            let new_account_borrows = account_borrows + amount;
+            require!(new_account_borrows <= cap, ERROR_REACHED_ACCOUNT_BORROW_CAP);
+        }
+
+        // check if the combined borrow cap of its cap group (if any) has been reached
+        let group_id = self.market_cap_group(money_market).get();
+        if group_id != 0 {
+            if let Some(cap) = self.get_cap_group_borrow_cap(group_id) {
+                let price = self.get_underlying_price(money_market);
+                let new_group_borrows = self.get_cap_group_borrows(group_id) + self.wad_mul(amount, &price);
+                require!(new_group_borrows < cap, ERROR_REACHED_CAP_GROUP_BORROW_CAP);
+            }
+        }
+
+        // check if the utilization cap (if any) has been reached, preserving an exit buffer for suppliers. Cash deployed or
+        // lent elsewhere by the money market is not available to suppliers, so it is not part of the buffer
+        if let Some(cap) = self.get_utilization_cap(money_market) {
+            let wad = BigUint::from(WAD);
+            let liquidity = self.get_available_liquidity(money_market);
+            let total_borrows = self.get_total_borrows(money_market);
+            let new_total_borrows = total_borrows + amount;
+            require!(new_total_borrows * wad <= cap * liquidity, ERROR_REACHED_UTILIZATION_CAP);
+        }
+
         // a risk profile is needed to confirm if the borrowing is possible
         let risk_profile = self.simulate_risk_profile(borrower, money_market, &BigUint::zero(), amount, true);
 
@@ -114,6 +152,10 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
         if !risk_profile.can_borrow() {
             return false;
         }
+
+        // the borrower remains solvent, so any previous liquidatable detection is stale
+        self.clear_liquidatable_detection(borrower);
+
         self.update_borrow_rewards_batches_state(money_market);
         self.distribute_borrower_batches_rewards(money_market, borrower);
         true
@@ -140,6 +182,63 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
         true
     }
 
+    /// Records that the caller money market has just accrued interest, which allows money markets to tell idle markets apart
+    /// from chain wide accrual gaps without iterating over all markets.
+    ///
+    /// # Arguments:
+    ///
+    /// - `abnormal_accrual_gap` - Whether the money market has found an abnormal accrual gap at this accrual.
+    ///
+    /// # Notes:
+    ///
+    /// - Calls from addresses that are not whitelisted money markets are ignored.
+    ///
+    #[endpoint(recordAccrual)]
+    fn record_accrual(&self, abnormal_accrual_gap: bool) {
+        let caller = self.blockchain().get_caller();
+        if !self.is_whitelisted_money_market(&caller) {
+            return;
+        }
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.latest_accrual_timestamp().set(timestamp);
+        if abnormal_accrual_gap {
+            self.latest_accrual_gap_timestamp().set(timestamp);
+        }
+    }
+
+    /// Computes the maximum amount of underlying that can be repaid in a single liquidation of a given borrower at a given
+    /// money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `borrower` - The address of the borrower.
+    ///
+    /// # Notes:
+    ///
+    /// - Borrows at deprecated markets can be fully repaid (the close factor does not play any role).
+    /// - Money markets use this amount to clamp liquidation repayments and refund the excess to the liquidator.
+    /// - Interest is accrued at the borrow market first, such that the amount is computed from the current borrow balance
+    ///   (bounded by the market total borrows, as repayments are).
+    ///
+    #[endpoint(getMaxLiquidationRepayment)]
+    fn get_max_liquidation_repayment(&self, borrow_market: &ManagedAddress, borrower: &ManagedAddress) -> BigUint {
+        self.require_whitelisted_money_market(borrow_market);
+
+        // make sure the borrow balance is up to date
+        self.accrue_interest(borrow_market);
+
+        let total_borrows = self.get_total_borrows(borrow_market);
+        let borrow_amount = BigUint::min(total_borrows, self.get_stored_account_borrow_amount(borrow_market, borrower));
+        if self.is_deprecated(borrow_market) {
+            return borrow_amount;
+        }
+
+        let close_factor = self.get_close_factor(borrow_market);
+        self.wad_mul(&close_factor, &borrow_amount)
+    }
+
     /// Checks whether a liquidation is allowed or not to happen, repaying a borrow at a given money market and seizing
     /// collateral at the same or another specified money market.
     ///
@@ -159,29 +258,82 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
     ///
     #[endpoint(liquidateBorrowAllowed)]
     fn liquidate_borrow_allowed(&self, borrow_market: &ManagedAddress, collateral_market: &ManagedAddress, borrower: &ManagedAddress, amount: &BigUint) -> bool {
-        self.require_whitelisted_money_market(borrow_market);
-        self.require_whitelisted_money_market(collateral_market);
+        match self.liquidate_borrow_checks(borrow_market, collateral_market, borrower, amount) {
+            DryRunLiquidation::Allowed => true,
+            DryRunLiquidation::NonWhitelistedMarket => sc_panic!(ERROR_NON_WHITELISTED_MARKET),
+            DryRunLiquidation::RepaymentExceedsTotalBorrow => sc_panic!(ERROR_REPAYMENT_EXCEEDS_TOTAL_BORROW),
+            DryRunLiquidation::TooMuchRepayment => sc_panic!(ERROR_TOO_MUCH_REPAYMENT),
+            _ => false,
+        }
+    }
+
+    /// Checks whether a liquidation performed by a given liquidator would be allowed, running the same checks as
+    /// `liquidateBorrowAllowed` and `seizeAllowed` without failing. Returns the outcome of the checks.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_market` - The money market where the borrower has borrow its underlying.
+    /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
+    /// - `borrower` - The address of the borrower.
+    /// - `liquidator` - The address of the liquidator.
+    /// - `amount` - The amount of underlying being repaid by the liquidator.
+    ///
+    /// # Notes:
+    ///
+    /// - The repayment amount is not clamped to the maximum liquidation repayment, which is up to the caller.
+    /// - As in `liquidateBorrowAllowed`, the first detection of a liquidatable borrower starts the liquidation priority
+    ///   window, which is why this is an endpoint.
+    ///
+    #[endpoint(checkLiquidateBorrow)]
+    fn check_liquidate_borrow(&self, borrow_market: &ManagedAddress, collateral_market: &ManagedAddress, borrower: &ManagedAddress, liquidator: &ManagedAddress, amount: &BigUint) -> DryRunLiquidation {
+        if amount == &BigUint::zero() {
+            return DryRunLiquidation::ZeroAmount;
+        }
+
+        if borrower == liquidator {
+            return DryRunLiquidation::SelfLiquidation;
+        }
+
+        // the liquidation policy goes first, given that it might start the liquidation priority window checked when seizing
+        let outcome = self.liquidate_borrow_checks(borrow_market, collateral_market, borrower, amount);
+        if outcome != DryRunLiquidation::Allowed {
+            return outcome;
+        }
+
+        self.seize_checks(collateral_market, borrow_market, borrower, liquidator)
+    }
+
+    /// Runs the liquidation policy checks without failing, returning their outcome.
+    ///
+    fn liquidate_borrow_checks(&self, borrow_market: &ManagedAddress, collateral_market: &ManagedAddress, borrower: &ManagedAddress, amount: &BigUint) -> DryRunLiquidation {
+        if !self.is_whitelisted_money_market(borrow_market) || !self.is_whitelisted_money_market(collateral_market) {
+            return DryRunLiquidation::NonWhitelistedMarket;
+        }
 
         // get the borrower balance
         let borrow_amount = self.get_stored_account_borrow_amount(borrow_market, borrower);
 
         // allow complete liquidation at deprecated money markets
         if self.is_deprecated(borrow_market) {
-            require!(amount <= &borrow_amount, ERROR_REPAYMENT_EXCEEDS_TOTAL_BORROW);
-            return true;
+            if amount > &borrow_amount {
+                return DryRunLiquidation::RepaymentExceedsTotalBorrow;
+            }
+            return DryRunLiquidation::Allowed;
         }
 
         // at non-deprecated markets, borrows can only be repaid if there is risk of insolvency or insolvency
         let risk_profile = self.simulate_risk_profile(borrower, &ManagedAddress::zero(), &BigUint::zero(), &BigUint::zero(), true);
 
+        // the first detection starts the liquidation priority window
+        self.update_liquidatable_detection(borrower, &risk_profile);
+
         // also, the maximum repayment amount depends on the close factor
         let close_factor = self.get_close_factor(borrow_market);
-        match risk_profile.can_be_liquidated(amount, &borrow_amount, &close_factor) {
-            risk_profile::Liquidation::Allowed => true,
-            risk_profile::Liquidation::NotAllowed => false,
-            risk_profile::Liquidation::AllowedButTooMuch => {
-                sc_panic!(ERROR_TOO_MUCH_REPAYMENT)
-            },
+        let max_close_amount = self.wad_mul(&close_factor, &borrow_amount);
+        match risk_profile.can_be_liquidated(amount, &max_close_amount) {
+            risk_profile::Liquidation::Allowed => DryRunLiquidation::Allowed,
+            risk_profile::Liquidation::NotAllowed => DryRunLiquidation::BorrowerNotLiquidatable,
+            risk_profile::Liquidation::AllowedButTooMuch => DryRunLiquidation::TooMuchRepayment,
         }
     }
 
@@ -192,21 +344,22 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
     /// - `collateral_market` - The money market where the borrower has collateral which is intended to be seized.
     /// - `borrow_market` - The money market where the borrower has borrow its underlying.
     /// - `borrower` - The address of the borrower.
-    /// - `_liquidator` - The address of the liquidator (legacy).
+    /// - `liquidator` - The address of the liquidator.
     ///
     /// # Notes:
     ///
     /// - Money markets should be whitelisted and share the same Controller.
+    /// - Only bonded liquidators can seize during the liquidation priority window. Batch liquidations, in which the
+    ///   Controller acts as the liquidator, check the priority window beforehand.
     ///
     #[endpoint(seizeAllowed)]
-    fn seize_allowed(&self, collateral_market: &ManagedAddress, borrow_market: &ManagedAddress, borrower: &ManagedAddress, _liquidator: &ManagedAddress) -> bool {
-        require!(self.get_global_seize_status() == Status::Active, ERROR_GLOBAL_SEIZE_PAUSED);
-
-        self.require_whitelisted_money_market(borrow_market);
-        self.require_whitelisted_money_market(collateral_market);
-
-        for money_market in self.account_markets(borrower).iter() {
-            require!(self.seize_status(&money_market).get() == Status::Active, ERROR_SEIZE_PAUSED);
+    fn seize_allowed(&self, collateral_market: &ManagedAddress, borrow_market: &ManagedAddress, borrower: &ManagedAddress, liquidator: &ManagedAddress) -> bool {
+        match self.seize_checks(collateral_market, borrow_market, borrower, liquidator) {
+            DryRunLiquidation::Allowed => {},
+            DryRunLiquidation::GlobalSeizePaused => sc_panic!(ERROR_GLOBAL_SEIZE_PAUSED),
+            DryRunLiquidation::NonWhitelistedMarket => sc_panic!(ERROR_NON_WHITELISTED_MARKET),
+            DryRunLiquidation::LiquidationPriorityWindow => sc_panic!(ERROR_LIQUIDATION_PRIORITY_WINDOW),
+            _ => sc_panic!(ERROR_SEIZE_PAUSED),
         }
 
         let opt_controller_a = self.get_controller(borrow_market);
@@ -224,4 +377,28 @@ pub trait PolicyModule: admin::AdminModule + events::EventModule + guardian::Gua
             _ => false,
         }
     }
+
+    /// Runs the seize policy checks without failing, returning their outcome.
+    ///
+    fn seize_checks(&self, collateral_market: &ManagedAddress, borrow_market: &ManagedAddress, borrower: &ManagedAddress, liquidator: &ManagedAddress) -> DryRunLiquidation {
+        if self.get_global_seize_status() != Status::Active {
+            return DryRunLiquidation::GlobalSeizePaused;
+        }
+
+        if !self.is_whitelisted_money_market(borrow_market) || !self.is_whitelisted_money_market(collateral_market) {
+            return DryRunLiquidation::NonWhitelistedMarket;
+        }
+
+        if liquidator != &self.blockchain().get_sc_address() && !self.is_liquidation_open(borrow_market, borrower, liquidator) {
+            return DryRunLiquidation::LiquidationPriorityWindow;
+        }
+
+        for money_market in self.account_markets(borrower).iter() {
+            if self.seize_status(&money_market).get() != Status::Active {
+                return DryRunLiquidation::SeizePaused;
+            }
+        }
+
+        DryRunLiquidation::Allowed
+    }
 }
@@ -2,9 +2,9 @@ multiversx_sc::imports!();
 
 use super::{constants::*, errors::*, storage};
 
-use oracle::{common::ProxyTrait as _, prices::ProxyTrait as _};
+use oracle::{common::ProxyTrait as _, model::PairState, prices::ProxyTrait as _};
 
-use crate::storage::SwapOperationType;
+use crate::storage::{MarketChange, MarketMirror, SwapOperationType};
 
 #[multiversx_sc::module]
 pub trait ProxyModule: storage::StorageModule {
@@ -18,10 +18,18 @@ pub trait ProxyModule: storage::StorageModule {
         self.get_money_market_proxy(sc_address).get_money_market_identifiers().execute_on_dest_context()
     }
 
+    fn sync_controller_mirror(&self, sc_address: &ManagedAddress, mirror: MarketMirror<Self::Api>) {
+        self.get_money_market_proxy(sc_address).sync_controller_mirror(mirror).execute_on_dest_context()
+    }
+
     fn get_liquidity(&self, sc_address: &ManagedAddress) -> BigUint {
         self.get_money_market_proxy(sc_address).get_liquidity().execute_on_dest_context()
     }
 
+    fn get_available_liquidity(&self, sc_address: &ManagedAddress) -> BigUint {
+        self.get_money_market_proxy(sc_address).get_available_liquidity().execute_on_dest_context()
+    }
+
     fn get_total_borrows(&self, sc_address: &ManagedAddress) -> BigUint {
         self.get_money_market_proxy(sc_address).get_total_borrows().execute_on_dest_context()
     }
@@ -50,10 +58,18 @@ pub trait ProxyModule: storage::StorageModule {
         self.get_money_market_proxy(sc_address).get_liquidation_incentive().execute_on_dest_context()
     }
 
+    fn get_total_reserves(&self, sc_address: &ManagedAddress) -> BigUint {
+        self.get_money_market_proxy(sc_address).get_total_reserves().execute_on_dest_context()
+    }
+
     fn get_reserve_factor(&self, sc_address: &ManagedAddress) -> BigUint {
         self.get_money_market_proxy(sc_address).get_reserve_factor().execute_on_dest_context()
     }
 
+    fn accrue_interest(&self, sc_address: &ManagedAddress) {
+        self.get_money_market_proxy(sc_address).accrue_interest().execute_on_dest_context()
+    }
+
     fn get_controller(&self, sc_address: &ManagedAddress) -> Option<ManagedAddress> {
         self.get_money_market_proxy(sc_address).get_controller().execute_on_dest_context()
     }
@@ -62,10 +78,24 @@ pub trait ProxyModule: storage::StorageModule {
         self.get_money_market_proxy(sc_address).get_reliable_account_snapshot(account).execute_on_dest_context()
     }
 
+    fn mint(&self, sc_address: &ManagedAddress, underlying_payment: &EgldOrEsdtTokenPayment) -> EsdtTokenPayment {
+        self.get_money_market_proxy(sc_address).mint(OptionalValue::<BigUint>::None).with_egld_or_single_esdt_transfer(underlying_payment.clone()).execute_on_dest_context()
+    }
+
     fn redeem(&self, sc_address: &ManagedAddress, token_payment: &EsdtTokenPayment, opt_underlying_amount: Option<BigUint>) -> money_market_mod::RedeemResultType<Self::Api> {
         self.get_money_market_proxy(sc_address).redeem(OptionalValue::from(opt_underlying_amount)).with_esdt_transfer(token_payment.clone()).execute_on_dest_context()
     }
 
+    fn repay_borrow(&self, sc_address: &ManagedAddress, borrower: &ManagedAddress, underlying_payment: &EgldOrEsdtTokenPayment) -> EgldOrEsdtTokenPayment {
+        self.get_money_market_proxy(sc_address).repay_borrow(OptionalValue::Some(borrower.clone())).with_egld_or_single_esdt_transfer(underlying_payment.clone()).execute_on_dest_context()
+    }
+
+    fn liquidate_borrow(&self, sc_address: &ManagedAddress, borrower: &ManagedAddress, collateral_market: &ManagedAddress, underlying_payment: &EgldOrEsdtTokenPayment) -> EsdtTokenPayment {
+        let result: money_market_mod::LiquidateBorrowResultType<Self::Api> = self.get_money_market_proxy(sc_address).liquidate_borrow(borrower, collateral_market, OptionalValue::<BigUint>::None).with_egld_or_single_esdt_transfer(underlying_payment.clone()).execute_on_dest_context();
+        let (liquidator_seize_tokens, _) = result.into_tuple();
+        liquidator_seize_tokens
+    }
+
     // Oracle calls
 
     fn is_price_oracle(&self, sc_address: &ManagedAddress) -> bool {
@@ -81,6 +111,15 @@ pub trait ProxyModule: storage::StorageModule {
         }
     }
 
+    fn get_market_price_oracle(&self, money_market: &ManagedAddress) -> Option<ManagedAddress> {
+        if self.market_price_oracle(money_market).is_empty() {
+            None
+        } else {
+            let address = self.market_price_oracle(money_market).get();
+            Some(address)
+        }
+    }
+
     fn get_underlying_price(&self, money_market: &ManagedAddress) -> BigUint {
         let (underlying_id, _) = self.identifiers(money_market).get();
 
@@ -88,12 +127,101 @@ pub trait ProxyModule: storage::StorageModule {
             return BigUint::from(WAD);
         }
 
-        let mut proxy = self.get_price_oracle_proxy();
+        // a market specific price oracle takes precedence over the global one
+        let mut proxy = match self.get_market_price_oracle(money_market) {
+            Some(market_price_oracle) => self.price_oracle_proxy(market_price_oracle),
+            None => self.get_price_oracle_proxy(),
+        };
         let price = proxy.get_price_in_egld(&underlying_id.unwrap_esdt()).execute_on_dest_context();
         require!(price > BigUint::zero(), ERROR_ORACLE_FAILED_RETRIEVE_UNDERLYING_PRICE);
         price
     }
 
+    fn get_rewards_token_price(&self, token_id: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        if token_id.is_egld() {
+            return BigUint::from(WAD);
+        }
+
+        let mut proxy = self.get_price_oracle_proxy();
+        let price = proxy.get_price_in_egld(&token_id.clone().unwrap_esdt()).execute_on_dest_context();
+        require!(price > BigUint::zero(), ERROR_ORACLE_FAILED_RETRIEVE_REWARDS_TOKEN_PRICE);
+        price
+    }
+
+    /// Returns the price of the governance token in EGLD and in wad, as long as the price oracle supports it as the governance
+    /// token and its pricing is not paused. Otherwise, returns `None`.
+    ///
+    fn try_get_governance_token_price(&self) -> Option<BigUint> {
+        let oracle = self.get_price_oracle()?;
+        let governance_token_id = self.governance_token_id().get();
+
+        let mapper = SingleValueMapper::new_from_address(oracle.clone(), StorageKey::new(b"governance_token_id"));
+        if mapper.is_empty() {
+            return None;
+        }
+
+        let oracle_governance_token_id: TokenIdentifier = mapper.get();
+        if oracle_governance_token_id != governance_token_id {
+            return None;
+        }
+
+        let mut storage_key = StorageKey::new(b"is_token_paused");
+        storage_key.append_item(&governance_token_id);
+        let paused: bool = SingleValueMapper::new_from_address(oracle.clone(), storage_key).get();
+        if paused {
+            return None;
+        }
+
+        let price = self.price_oracle_proxy(oracle).get_price_in_egld(&governance_token_id).execute_on_dest_context();
+        require!(price > BigUint::zero(), ERROR_ORACLE_FAILED_RETRIEVE_GOVERNANCE_TOKEN_PRICE);
+        Some(price)
+    }
+
+    /// Returns the price of a rewards token in USD and in wad, as long as the price oracle is not paused, the rewards token
+    /// pricing is not paused and the EGLD price in USD is available. Otherwise, returns `None`.
+    ///
+    fn try_get_rewards_token_price_in_usd(&self, token_id: &EgldOrEsdtTokenIdentifier) -> Option<BigUint> {
+        let oracle = self.get_price_oracle()?;
+
+        let paused: bool = SingleValueMapper::new_from_address(oracle.clone(), StorageKey::new(b"paused")).get();
+        if paused {
+            return None;
+        }
+
+        let price_aggregator_mapper: SingleValueMapper<Self::Api, ManagedAddress, ManagedAddress> = SingleValueMapper::new_from_address(oracle.clone(), StorageKey::new(b"price_aggregator_address"));
+        if price_aggregator_mapper.is_empty() {
+            return None;
+        }
+
+        let token_price = if token_id.is_egld() {
+            BigUint::from(WAD)
+        } else {
+            let esdt_token_id = token_id.clone().unwrap_esdt();
+            let mut storage_key = StorageKey::new(b"is_token_paused");
+            storage_key.append_item(&esdt_token_id);
+            let token_paused: bool = SingleValueMapper::new_from_address(oracle.clone(), storage_key).get();
+            if token_paused {
+                return None;
+            }
+
+            self.price_oracle_proxy(oracle.clone()).get_price_in_egld(&esdt_token_id).execute_on_dest_context()
+        };
+
+        let egld_price_in_usd: BigUint = self.price_oracle_proxy(oracle).get_egld_price_in_usd().execute_on_dest_context();
+        if token_price == BigUint::zero() || egld_price_in_usd == BigUint::zero() {
+            return None;
+        }
+
+        Some(token_price * egld_price_in_usd / BigUint::from(WAD))
+    }
+
+    fn get_egld_price_in_usd(&self) -> BigUint {
+        let mut proxy = self.get_price_oracle_proxy();
+        let price = proxy.get_egld_price_in_usd().execute_on_dest_context();
+        require!(price > BigUint::zero(), ERROR_ORACLE_FAILED_RETRIEVE_EGLD_PRICE);
+        price
+    }
+
     // xExchange calls
 
     fn get_xexchange_router(&self) -> Option<ManagedAddress> {
@@ -110,6 +238,26 @@ pub trait ProxyModule: storage::StorageModule {
         proxy.multi_pair_swap(swap_operations).with_esdt_transfer((token_in.clone(), 0, token_amount.clone())).execute_on_dest_context()
     }
 
+    fn get_xexchange_pair_state(&self, pair_address: &ManagedAddress) -> PairState {
+        self.xexchange_pair_proxy(pair_address.clone()).get_state().execute_on_dest_context()
+    }
+
+    fn get_xexchange_pair_tokens(&self, pair_address: &ManagedAddress) -> (TokenIdentifier, TokenIdentifier) {
+        let first_token_id = self.xexchange_pair_proxy(pair_address.clone()).get_first_token_id().execute_on_dest_context();
+        let second_token_id = self.xexchange_pair_proxy(pair_address.clone()).get_second_token_id().execute_on_dest_context();
+        (first_token_id, second_token_id)
+    }
+
+    fn get_xexchange_pair_reserves(&self, pair_address: &ManagedAddress) -> (BigUint, BigUint) {
+        let result: MultiValue3<BigUint, BigUint, BigUint> = self.xexchange_pair_proxy(pair_address.clone()).get_reserves_and_total_supply().execute_on_dest_context();
+        let (first_reserve, second_reserve, _) = result.into_tuple();
+        (first_reserve, second_reserve)
+    }
+
+    fn get_xexchange_pair_total_fee_percent(&self, pair_address: &ManagedAddress) -> u64 {
+        self.xexchange_pair_proxy(pair_address.clone()).get_total_fee_percent().execute_on_dest_context()
+    }
+
     // Wrapped EGLD
 
     fn get_wegld_id(&self, egld_wrapper: &ManagedAddress) -> TokenIdentifier {
@@ -151,6 +299,10 @@ pub trait ProxyModule: storage::StorageModule {
         self.rewards_booster_v2_proxy(sc_address.clone()).on_market_change(money_market, account, tokens, prev_tokens).execute_on_dest_context()
     }
 
+    fn on_market_changes_booster_v3(&self, sc_address: &ManagedAddress, market_changes: &ManagedVec<MarketChange<Self::Api>>) {
+        self.rewards_booster_v3_proxy(sc_address.clone()).on_market_changes(market_changes).execute_on_dest_context()
+    }
+
     // USH market calls
 
     fn is_ush_market(&self, sc_address: &ManagedAddress) -> bool {
@@ -161,6 +313,25 @@ pub trait ProxyModule: storage::StorageModule {
         self.get_ush_market_proxy(sc_address).on_market_change(account).execute_on_dest_context()
     }
 
+    // Position market calls
+
+    fn is_position_market(&self, sc_address: &ManagedAddress) -> bool {
+        self.position_market_proxy(sc_address.clone()).is_position_market().execute_on_dest_context()
+    }
+
+    /// Checks whether a given smart contract stores a position identifier, which only position markets do. Contrary to
+    /// `is_position_market`, it does not fail for smart contracts lacking the `isPositionMarket` view.
+    ///
+    fn has_position_id(&self, sc_address: &ManagedAddress) -> bool {
+        let mapper: SingleValueMapper<Self::Api, TokenIdentifier, ManagedAddress> = SingleValueMapper::new_from_address(sc_address.clone(), StorageKey::new(b"position_id"));
+        !mapper.is_empty()
+    }
+
+    fn has_ush_id(&self, sc_address: &ManagedAddress) -> bool {
+        let mapper: SingleValueMapper<Self::Api, TokenIdentifier, ManagedAddress> = SingleValueMapper::new_from_address(sc_address.clone(), StorageKey::new(b"ush_id"));
+        !mapper.is_empty()
+    }
+
     // Proxies
 
     #[proxy]
@@ -177,6 +348,9 @@ pub trait ProxyModule: storage::StorageModule {
         self.ush_market_proxy(sc_address.clone())
     }
 
+    #[proxy]
+    fn position_market_proxy(&self, sc_address: ManagedAddress) -> position_market_mod::ProxyTo<Self::Api>;
+
     #[proxy]
     fn price_oracle_proxy(&self, sc_address: ManagedAddress) -> oracle::ProxyTo<Self::Api>;
 
@@ -201,9 +375,15 @@ pub trait ProxyModule: storage::StorageModule {
         }
     }
 
+    #[proxy]
+    fn xexchange_pair_proxy(&self, sc_address: ManagedAddress) -> xexchange_pair_mod::ProxyTo<Self::Api>;
+
     #[proxy]
     fn egld_wrapper_proxy(&self, sc_address: ManagedAddress) -> egld_wrapper_mod::ProxyTo<Self::Api>;
 
+    #[proxy]
+    fn esdt_system_proxy(&self, sc_address: ManagedAddress) -> esdt_system_mod::ProxyTo<Self::Api>;
+
     #[proxy]
     fn market_observer_proxy(&self, sc_address: ManagedAddress) -> market_observer_mod::ProxyTo<Self::Api>;
 
@@ -215,6 +395,9 @@ pub trait ProxyModule: storage::StorageModule {
 
     #[proxy]
     fn rewards_booster_v2_proxy(&self, sc_address: ManagedAddress) -> rewards_booster_v2_mod::ProxyTo<Self::Api>;
+
+    #[proxy]
+    fn rewards_booster_v3_proxy(&self, sc_address: ManagedAddress) -> rewards_booster_v3_mod::ProxyTo<Self::Api>;
 }
 
 mod money_market_mod {
@@ -222,6 +405,8 @@ mod money_market_mod {
 
     pub type RedeemResultType<BigUint> = MultiValue2<EgldOrEsdtTokenPayment<BigUint>, EsdtTokenPayment<BigUint>>;
 
+    pub type LiquidateBorrowResultType<BigUint> = MultiValue2<EsdtTokenPayment<BigUint>, EsdtTokenPayment<BigUint>>;
+
     #[multiversx_sc::proxy]
     pub trait MoneyMarket {
         #[view(isMoneyMarket)]
@@ -233,6 +418,9 @@ mod money_market_mod {
         #[view(getLiquidity)]
         fn get_liquidity(&self) -> BigUint;
 
+        #[view(getAvailableLiquidity)]
+        fn get_available_liquidity(&self) -> BigUint;
+
         #[view(getTotalBorrows)]
         fn get_total_borrows(&self) -> BigUint;
 
@@ -254,18 +442,39 @@ mod money_market_mod {
         #[view(getLiquidationIncentive)]
         fn get_liquidation_incentive(&self) -> BigUint;
 
+        #[view(getTotalReserves)]
+        fn get_total_reserves(&self) -> BigUint;
+
         #[view(getReserveFactor)]
         fn get_reserve_factor(&self) -> BigUint;
 
         #[view(getController)]
         fn get_controller(&self) -> Option<ManagedAddress>;
 
+        #[endpoint(accrueInterest)]
+        fn accrue_interest(&self);
+
+        #[endpoint(syncControllerMirror)]
+        fn sync_controller_mirror(&self, mirror: crate::storage::MarketMirror<Self::Api>);
+
         #[endpoint(getReliableAccountSnapshot)]
         fn get_reliable_account_snapshot(&self, account: &ManagedAddress) -> (BigUint, BigUint);
 
+        #[payable("*")]
+        #[endpoint(mint)]
+        fn mint(&self, opt_min_tokens_out: OptionalValue<BigUint>) -> EsdtTokenPayment<Self::Api>;
+
         #[payable("*")]
         #[endpoint(redeem)]
         fn redeem(&self, opt_underlying_amount: OptionalValue<BigUint>) -> RedeemResultType<Self::Api>;
+
+        #[payable("*")]
+        #[endpoint(repayBorrow)]
+        fn repay_borrow(&self, opt_borrower: OptionalValue<ManagedAddress>) -> EgldOrEsdtTokenPayment<Self::Api>;
+
+        #[payable("*")]
+        #[endpoint(liquidateBorrow)]
+        fn liquidate_borrow(&self, borrower: &ManagedAddress, collateral_market: &ManagedAddress, opt_min_tokens: OptionalValue<BigUint>) -> LiquidateBorrowResultType<Self::Api>;
     }
 }
 
@@ -282,6 +491,16 @@ mod ush_market_mod {
     }
 }
 
+mod position_market_mod {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait PositionMarket {
+        #[view(isPositionMarket)]
+        fn is_position_market(&self) -> bool;
+    }
+}
+
 pub mod xexchange_mod {
     multiversx_sc::imports!();
 
@@ -295,6 +514,30 @@ pub mod xexchange_mod {
     }
 }
 
+mod xexchange_pair_mod {
+    multiversx_sc::imports!();
+
+    use oracle::model::PairState;
+
+    #[multiversx_sc::proxy]
+    pub trait Pair {
+        #[view(getState)]
+        fn get_state(&self) -> PairState;
+
+        #[view(getFirstTokenId)]
+        fn get_first_token_id(&self) -> TokenIdentifier;
+
+        #[view(getSecondTokenId)]
+        fn get_second_token_id(&self) -> TokenIdentifier;
+
+        #[view(getReservesAndTotalSupply)]
+        fn get_reserves_and_total_supply(&self) -> MultiValue3<BigUint, BigUint, BigUint>;
+
+        #[view(getTotalFeePercent)]
+        fn get_total_fee_percent(&self) -> u64;
+    }
+}
+
 mod egld_wrapper_mod {
     multiversx_sc::imports!();
 
@@ -313,6 +556,16 @@ mod egld_wrapper_mod {
     }
 }
 
+mod esdt_system_mod {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait EsdtSystem {
+        #[view(getTokenProperties)]
+        fn get_token_properties(&self, token_id: &TokenIdentifier) -> MultiValueEncoded<ManagedBuffer>;
+    }
+}
+
 mod market_observer_mod {
     multiversx_sc::imports!();
 
@@ -358,3 +611,15 @@ mod rewards_booster_v2_mod {
         fn on_market_change(&self, money_market: &ManagedAddress, account: &ManagedAddress, tokens: &BigUint, prev_tokens: &BigUint);
     }
 }
+
+mod rewards_booster_v3_mod {
+    multiversx_sc::imports!();
+
+    use crate::storage::MarketChange;
+
+    #[multiversx_sc::proxy]
+    pub trait RewardsBooster {
+        #[endpoint(onMarketChanges)]
+        fn on_market_changes(&self, market_changes: &ManagedVec<MarketChange<Self::Api>>);
+    }
+}
@@ -2,7 +2,10 @@ multiversx_sc::imports!();
 
 use super::{constants::*, errors::*, events, proxies, shared, storage};
 
-use crate::storage::{MarketType, RewardsBatch};
+use math::Rounding;
+use oracle::model::PairState;
+
+use crate::storage::{BoosterPathReport, MarketType, RewardsBatch, RewardsIndexSnapshot, RewardsTokenMetadata, SwapStep};
 
 #[multiversx_sc::module]
 pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::ProxyModule + shared::SharedModule + storage::StorageModule {
@@ -31,6 +34,144 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
         }
     }
 
+    /// Reprices a rewards batch denominated in USD using the current oracle price of its rewards token. The rewards batches
+    /// of the money market are updated up to this point first, such that the elapsed period is distributed at the previous
+    /// speed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `batch_id` - The rewards batch identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - Cannot be called while rewards distribution is paused at the money market.
+    /// - If the rewards token cannot be priced, the rewards batch keeps its current speed.
+    ///
+    #[endpoint(repriceRewardsBatch)]
+    fn reprice_rewards_batch(&self, money_market: &ManagedAddress, batch_id: usize) {
+        self.require_whitelisted_money_market(money_market);
+        require!(!self.is_rewards_distribution_paused(money_market), ERROR_REWARDS_DISTRIBUTION_PAUSED);
+
+        let rewards_batch_position_mapper = self.rewards_batch_position(money_market, &batch_id);
+        require!(!rewards_batch_position_mapper.is_empty(), ERROR_INVALID_REWARDS_BATCH_ID);
+        let pos_id = rewards_batch_position_mapper.get();
+
+        require!(!self.rewards_batch_usd_per_day(money_market, &batch_id).is_empty(), ERROR_NOT_USD_DENOMINATED_REWARDS_BATCH);
+
+        let mut rewards_batches_mapper = self.rewards_batches(money_market);
+        let rewards_batch = rewards_batches_mapper.get(pos_id);
+
+        let t = self.blockchain().get_block_timestamp();
+        require!(rewards_batch.end_time > t, ERROR_REWARDS_BATCH_EXPIRED);
+
+        // this will update all rewards batches from a given money market up to this point
+        if rewards_batch.market_type == MarketType::Supply {
+            self.update_supply_rewards_batches_state(money_market);
+        } else {
+            self.update_borrow_rewards_batches_state(money_market);
+        }
+
+        // after updating it, get it again
+        let mut updated_rewards_batch = rewards_batches_mapper.get(pos_id);
+        self.try_reprice_usd_rewards_batch(&mut updated_rewards_batch);
+        rewards_batches_mapper.set(pos_id, &updated_rewards_batch);
+
+        self.reprice_rewards_batch_event(&self.blockchain().get_caller(), &updated_rewards_batch);
+    }
+
+    /// Takes a snapshot of the supply and borrow rewards batches indexes at the given money markets, which can be used to
+    /// anchor off-chain rewards computations (such as airdrops) to on-chain verifiable values.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_markets` - The money market addresses to snapshot. If empty, all whitelisted markets will be used.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the rewards manager.
+    /// - Rewards batches states are updated before their indexes are copied, such that they are always in the current wad *
+    ///   wad * wad precision, which is stored together with the snapshot.
+    /// - Snapshot IDs start at 1.
+    ///
+    #[endpoint(takeRewardsSnapshot)]
+    fn take_rewards_snapshot(&self, money_markets: ManagedVec<ManagedAddress>) -> usize {
+        self.require_admin_or_rewards_manager();
+
+        let markets = self.validate_money_markets(money_markets);
+
+        let snapshot_id = self.last_rewards_snapshot_id().get() + 1;
+        self.last_rewards_snapshot_id().set(snapshot_id);
+
+        for money_market in markets.iter() {
+            self.update_supply_rewards_batches_state(&money_market);
+            self.update_borrow_rewards_batches_state(&money_market);
+
+            let mut rewards_snapshot = self.rewards_snapshot(snapshot_id, &money_market);
+            for rewards_batch in self.rewards_batches(&money_market).iter() {
+                let RewardsBatch { id, market_type, token_id, index, .. } = rewards_batch;
+                rewards_snapshot.push(&RewardsIndexSnapshot { batch_id: id, market_type, token_id, index });
+            }
+        }
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.rewards_snapshot_timestamp(snapshot_id).set(timestamp);
+
+        let wad = BigUint::from(WAD);
+        self.rewards_snapshot_precision(snapshot_id).set(&wad * &wad * &wad);
+
+        self.rewards_snapshot_event(snapshot_id, timestamp);
+
+        snapshot_id
+    }
+
+    /// Returns the precision of the rewards batches indexes at a given rewards snapshot, i.e. the unit of its indexes.
+    ///
+    /// # Arguments:
+    ///
+    /// - `snapshot_id` - The rewards snapshot ID.
+    ///
+    /// # Notes:
+    ///
+    /// - Snapshots taken before rewards indexes were migrated keep their legacy wad * wad precision.
+    ///
+    #[view(getRewardsSnapshotPrecision)]
+    fn get_rewards_snapshot_precision(&self, snapshot_id: usize) -> BigUint {
+        let precision_mapper = self.rewards_snapshot_precision(snapshot_id);
+        if precision_mapper.is_empty() {
+            let wad = BigUint::from(WAD);
+            &wad * &wad
+        } else {
+            precision_mapper.get()
+        }
+    }
+
+    /// Migrates the rewards batches indexes at the given money markets from the legacy wad * wad precision to the current
+    /// wad * wad * wad precision. Accounts indexes are migrated lazily, the next time rewards are distributed to them.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_markets` - The money market addresses to migrate. If empty, all whitelisted markets will be used.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the rewards manager.
+    /// - Money markets are also migrated automatically the first time their rewards batches are updated.
+    /// - Rewards snapshots taken before the migration keep their legacy precision, as reported by `getRewardsSnapshotPrecision`.
+    ///
+    #[endpoint(migrateRewardsIndexes)]
+    fn migrate_rewards_indexes(&self, money_markets: ManagedVec<ManagedAddress>) {
+        self.require_admin_or_rewards_manager();
+
+        let markets = self.validate_money_markets(money_markets);
+
+        for money_market in markets.iter() {
+            self.require_whitelisted_money_market(&money_market);
+            self.try_migrate_rewards_indexes(&money_market);
+        }
+    }
+
     /// Distributes caller or specified accounts rewards from supply and/or borrow markets, at specific money markets.
     ///
     /// # Arguments:
@@ -75,6 +216,73 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
         }
     }
 
+    /// Grants or revokes the caller consent for rewards claims initiated by third parties (i.e. the admin or the rewards
+    /// manager) on its behalf.
+    ///
+    /// # Arguments:
+    ///
+    /// - `consent` - Whether or not third parties are allowed to claim rewards on behalf of the caller.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts consent by default.
+    ///
+    #[endpoint(setRewardsAutoClaimConsent)]
+    fn set_rewards_auto_claim_consent(&self, consent: bool) {
+        let caller = self.blockchain().get_caller();
+
+        if consent {
+            self.rewards_auto_claim_opt_out(&caller).clear();
+        } else {
+            self.rewards_auto_claim_opt_out(&caller).set(true);
+        }
+
+        self.rewards_auto_claim_consent_event(&caller, consent);
+    }
+
+    /// Checks whether a given account consents to rewards claims initiated by third parties on its behalf.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    #[view(hasRewardsAutoClaimConsent)]
+    fn has_rewards_auto_claim_consent(&self, account: &ManagedAddress) -> bool {
+        !self.rewards_auto_claim_opt_out(account).get()
+    }
+
+    /// Estimates the complexity of claiming the rewards of a given account, such that wallets can set sensible gas limits.
+    /// Returns the number of markets the account belongs to, the number of rewards batches at those markets and the number
+    /// of rewards tokens with non-zero accrued rewards.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    /// # Notes:
+    ///
+    /// - Claims should be restricted to the account markets. Otherwise, all whitelisted markets are traversed.
+    /// - Rewards not yet distributed to the account are not considered in the number of rewards tokens.
+    ///
+    #[view(estimateClaimComplexity)]
+    fn estimate_claim_complexity(&self, account: &ManagedAddress) -> MultiValue3<usize, usize, usize> {
+        let mut markets = 0usize;
+        let mut batches = 0usize;
+        for money_market in self.account_markets(account).iter() {
+            markets += 1;
+            batches += self.rewards_batches(&money_market).len();
+        }
+
+        let mut rewards_tokens = 0usize;
+        for rewards_token_id in self.account_rewards_tokens(account).iter() {
+            if self.account_accrued_rewards(account, &rewards_token_id).get() > BigUint::zero() {
+                rewards_tokens += 1;
+            }
+        }
+
+        (markets, batches, rewards_tokens).into()
+    }
+
     /// Claims caller or specified accounts rewards from supply and/or borrow markets, at specific money markets.
     ///
     /// # Arguments:
@@ -86,6 +294,11 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
     /// - `accounts` - The addresses to claim rewards for. If empty, the caller will be used.
     /// - `opt_min_boosted_rewards_out`: An optional minimum amount of boosted rewards out.
     ///
+    /// # Notes:
+    ///
+    /// - Accounts that have not granted their consent for rewards claims initiated by third parties are skipped.
+    /// - WEGLD rewards are unwrapped into native EGLD for accounts that have opted in via `setNativeEgld`.
+    ///
     #[endpoint(claimRewards)]
     fn claim_rewards(&self, boost: bool, supply: bool, borrow: bool, money_markets: ManagedVec<ManagedAddress>, accounts: ManagedVec<ManagedAddress>, opt_min_boosted_rewards_out: OptionalValue<BigUint>) -> MultiValueEncoded<MultiValue2<ManagedAddress, EgldOrEsdtTokenPayment>> {
         let markets = self.validate_money_markets(money_markets);
@@ -96,7 +309,7 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
         } else {
             self.require_admin_or_rewards_manager();
             require!(!boost, ERROR_BOOST_NOT_ALLOWED);
-            accounts
+            self.filter_rewards_auto_claim_accounts(accounts)
         };
 
         self.claim_rewards_internal(boost, supply, borrow, &markets, &accounts, &opt_min_boosted_rewards_out)
@@ -121,6 +334,7 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
         // then, claim rewards to all accounts
         let mut payments_out = MultiValueEncoded::new();
         let mut boosted_rewards_eff = BigUint::zero();
+        let opt_governance_token_price = if boost { self.try_get_governance_token_price() } else { None };
         for money_market in money_markets.iter() {
             // send all rewards tokens to all accounts
             let rewards_batches = self.rewards_batches(&money_market);
@@ -128,7 +342,9 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
             for account in accounts.iter() {
                 for rewards_batch in rewards_batches.iter() {
                     let rewards_token_id = &rewards_batch.token_id;
-                    let sc_balance = self.blockchain().get_sc_balance(rewards_token_id, 0);
+                    let rewards_token_nonce = self.rewards_batch_nonce(&money_market, &rewards_batch.id).get();
+
+                    let sc_balance = self.get_rewards_token_balance(rewards_token_id, rewards_token_nonce);
                     let rewards = self.get_account_accrued_rewards(&account, rewards_token_id);
 
                     // don't do anything if rewards are zero
@@ -144,17 +360,16 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
                     if boost && !booster_mapper.is_empty() {
                         let mut booster = booster_mapper.get();
 
-                        let wad = BigUint::from(WAD);
-                        let delta_rewards = &rewards * &booster.premium / &wad;
+                        let delta_rewards = self.wad_mul(&rewards, &booster.premium);
 
                         // if there is no sufficient amount, don't boost, don't fail and send non boosted rewards
                         if delta_rewards > booster.amount_left {
                             // tracks rewards batch only
-                            self.send().direct(&account, rewards_token_id, 0, &rewards);
+                            let payment = self.send_account_rewards_token(&account, rewards_token_id, rewards_token_nonce, &rewards);
                             self.account_accrued_rewards(&account, rewards_token_id).set(&BigUint::zero());
                             self.rewards_claimed_event(&account, &rewards_batch, &rewards);
 
-                            payments_out.push((account.clone_value(), EgldOrEsdtTokenPayment::new(rewards_token_id.clone(), 0, rewards)).into());
+                            payments_out.push((account.clone_value(), payment).into());
 
                             continue;
                         }
@@ -169,11 +384,12 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
 
                         self.boosted_rewards_claimed_event(&account, &booster, &delta_rewards);
 
-                        // if rewards token is EGLD then add a EGLD => WEGLD step first
+                        // if rewards token is EGLD then use WEGLD from the buffer or add a EGLD => WEGLD step first
                         let swap_token_id = if rewards_token_id.is_egld() {
-                            self.wrap_egld(&boosted_rewards);
+                            self.take_wegld(&boosted_rewards);
                             self.wegld_id().get()
                         } else {
+                            self.take_rewards_token(rewards_token_id, &boosted_rewards);
                             rewards_token_id.clone().unwrap_esdt()
                         };
 
@@ -182,14 +398,20 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
                         let rewards_eff = self.custom_swap(&booster.swap_path, true, &swap_token_id, &boosted_rewards, &governance_token_id);
 
                         boosted_rewards_eff += &rewards_eff;
+                        self.track_boosted_governance_tokens(rewards_token_id, &rewards_eff);
+
+                        if let Some(governance_token_price) = &opt_governance_token_price {
+                            let egld_value = self.wad_mul(&rewards_eff, governance_token_price);
+                            self.boosted_rewards_valued_event(&account, rewards_token_id, &rewards_eff, &egld_value);
+                        }
 
                         self.send().direct_esdt(&account, &governance_token_id, 0, &rewards_eff);
 
                         payments_out.push((account.clone_value(), EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::esdt(governance_token_id), 0, rewards_eff)).into());
                     } else {
-                        self.send().direct(&account, rewards_token_id, 0, &rewards);
+                        let payment = self.send_account_rewards_token(&account, rewards_token_id, rewards_token_nonce, &rewards);
 
-                        payments_out.push((account.clone_value(), EgldOrEsdtTokenPayment::new(rewards_token_id.clone(), 0, rewards.clone())).into());
+                        payments_out.push((account.clone_value(), payment).into());
                     }
 
                     // tracks rewards coming from batches only, not from boosters
@@ -225,6 +447,8 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
     /// - If no money markets are specified, then all whitelisted money markets will be used.
     /// - If a provided money market does not have any batch for the rewards tokens, then it will be ignored.
     /// - If no accounts are provided, then only the caller will claim his rewards.
+    /// - Accounts that have not granted their consent for rewards claims initiated by third parties are skipped.
+    /// - WEGLD rewards are unwrapped into native EGLD for accounts that have opted in via `setNativeEgld`.
     ///
     #[endpoint(claimRewardsTokens)]
     fn claim_rewards_tokens(&self, boost: bool, supply: bool, borrow: bool, tokens: ManagedVec<EgldOrEsdtTokenIdentifier>, money_markets: ManagedVec<ManagedAddress>, accounts: ManagedVec<ManagedAddress>, opt_min_boosted_rewards_out: OptionalValue<BigUint>) -> MultiValueEncoded<MultiValue2<ManagedAddress, EgldOrEsdtTokenPayment>> {
@@ -236,7 +460,7 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
         } else {
             self.require_admin_or_rewards_manager();
             require!(!boost, ERROR_BOOST_NOT_ALLOWED);
-            accounts
+            self.filter_rewards_auto_claim_accounts(accounts)
         };
 
         self.claim_rewards_tokens_internal(boost, supply, borrow, &tokens, &markets, &accounts, &opt_min_boosted_rewards_out)
@@ -258,9 +482,12 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
 
         let mut payments_out = MultiValueEncoded::new();
         let mut boosted_rewards_eff = BigUint::zero();
+        let opt_governance_token_price = if boost { self.try_get_governance_token_price() } else { None };
         for account in accounts.iter() {
             for rewards_token_id in tokens.iter() {
-                let sc_balance = self.blockchain().get_sc_balance(&rewards_token_id, 0);
+                // all rewards batches of a given token share the same nonce
+                let rewards_token_nonce = self.rewards_token_nonce(&rewards_token_id).get();
+                let sc_balance = self.get_rewards_token_balance(&rewards_token_id, rewards_token_nonce);
                 let rewards = self.get_account_accrued_rewards(&account, &rewards_token_id);
 
                 // don't do anything if rewards are zero
@@ -280,8 +507,7 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
                     let mut booster = booster_mapper.get();
 
                     // should be enough balance left in the booster, otherwise fail
-                    let wad = BigUint::from(WAD);
-                    let delta_rewards = &rewards * &booster.premium / &wad;
+                    let delta_rewards = self.wad_mul(&rewards, &booster.premium);
                     require!(booster.amount_left >= delta_rewards, ERROR_INSUFFICIENT_BOOSTED_REWARDS_BALANCE_LEFT);
 
                     // should be enough balance left in the contract, otherwise fail (should not happen)
@@ -294,11 +520,12 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
 
                     self.boosted_rewards_claimed_event(&account, &booster, &delta_rewards);
 
-                    // if rewards token is EGLD then add a EGLD => WEGLD step first
+                    // if rewards token is EGLD then use WEGLD from the buffer or add a EGLD => WEGLD step first
                     let swap_token_id = if rewards_token_id.is_egld() {
-                        self.wrap_egld(&boosted_rewards);
+                        self.take_wegld(&boosted_rewards);
                         self.wegld_id().get()
                     } else {
+                        self.take_rewards_token(&rewards_token_id, &boosted_rewards);
                         rewards_token_id.clone().unwrap_esdt()
                     };
 
@@ -307,14 +534,20 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
                     let rewards_eff = self.custom_swap(&booster.swap_path, true, &swap_token_id, &boosted_rewards, &governance_token_id);
 
                     boosted_rewards_eff += &rewards_eff;
+                    self.track_boosted_governance_tokens(&rewards_token_id, &rewards_eff);
+
+                    if let Some(governance_token_price) = &opt_governance_token_price {
+                        let egld_value = self.wad_mul(&rewards_eff, governance_token_price);
+                        self.boosted_rewards_valued_event(&account, &rewards_token_id, &rewards_eff, &egld_value);
+                    }
 
                     self.send().direct_esdt(&account, &governance_token_id, 0, &rewards_eff);
 
                     payments_out.push((account.clone_value(), EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::esdt(governance_token_id), 0, rewards_eff)).into());
                 } else {
-                    self.send().direct(&account, &rewards_token_id, 0, &rewards);
+                    let payment = self.send_account_rewards_token(&account, &rewards_token_id, rewards_token_nonce, &rewards);
 
-                    payments_out.push((account.clone_value(), EgldOrEsdtTokenPayment::new(rewards_token_id.clone(), 0, rewards.clone())).into());
+                    payments_out.push((account.clone_value(), payment).into());
                 }
 
                 // tracks rewards coming from batches only, not from boosters
@@ -333,6 +566,179 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
         payments_out
     }
 
+    /// Resolves the number of decimals of an ESDT rewards token by querying its properties at the ESDT system smart contract.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The rewards token identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - The query is asynchronous and ends the execution, such that the metadata is updated at the callback.
+    ///
+    fn resolve_rewards_token_decimals(&self, token_id: &TokenIdentifier) {
+        let rewards_token_id = EgldOrEsdtTokenIdentifier::esdt(token_id.clone());
+        self.esdt_system_proxy(ESDTSystemSCAddress.to_managed_address()).get_token_properties(token_id).async_call().with_callback(self.callbacks().rewards_token_properties_callback(&rewards_token_id)).call_and_exit()
+    }
+
+    #[callback]
+    fn rewards_token_properties_callback(&self, rewards_token_id: &EgldOrEsdtTokenIdentifier, #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>) {
+        let properties = match result {
+            ManagedAsyncCallResult::Ok(properties) => properties,
+            ManagedAsyncCallResult::Err(_) => return,
+        };
+
+        for property in properties.into_iter() {
+            if let Some(decimals) = self.parse_num_decimals(&property) {
+                let mut metadata = self.rewards_token_metadata(rewards_token_id).get();
+                metadata.decimals = decimals;
+                metadata.decimals_resolved = true;
+                self.rewards_token_metadata(rewards_token_id).set(&metadata);
+
+                self.rewards_token_decimals_resolved_event(&metadata);
+                return;
+            }
+        }
+    }
+
+    /// Parses a `NumDecimals-<n>` token property, as returned by the ESDT system smart contract.
+    ///
+    fn parse_num_decimals(&self, property: &ManagedBuffer) -> Option<u8> {
+        let prefix_len = NUM_DECIMALS_PREFIX.len();
+        let len = property.len();
+        if len <= prefix_len || len > prefix_len + 2 {
+            return None;
+        }
+
+        let mut buffer = [0u8; 32];
+        let bytes = property.load_to_byte_array(&mut buffer);
+        if !bytes.starts_with(NUM_DECIMALS_PREFIX) {
+            return None;
+        }
+
+        let mut decimals = 0u8;
+        for byte in bytes[prefix_len..].iter() {
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            decimals = decimals * 10 + (byte - b'0');
+        }
+
+        Some(decimals)
+    }
+
+    /// Returns the metadata of all registered rewards tokens.
+    ///
+    #[view(getRewardsTokensMetadata)]
+    fn get_rewards_tokens_metadata(&self) -> MultiValueEncoded<RewardsTokenMetadata<Self::Api>> {
+        let mut metadata = MultiValueEncoded::new();
+        for token_id in self.rewards_tokens().iter() {
+            let metadata_mapper = self.rewards_token_metadata(&token_id);
+            if !metadata_mapper.is_empty() {
+                metadata.push(metadata_mapper.get());
+            }
+        }
+        metadata
+    }
+
+    /// Dry runs the swap path of a given rewards booster against the current xExchange pools reserves, such that dead or
+    /// migrated pools can be detected before boosted claims start failing.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The boosted rewards token identifier.
+    /// - `opt_amount_in` - An optional amount of rewards tokens to swap. Defaults to the amount left at the booster.
+    ///
+    /// # Notes:
+    ///
+    /// - A step is broken if its pair is not active, does not trade its input and output tokens, has no reserves or does
+    ///   not connect with the previous step. In such case, the index of the first broken step is reported.
+    /// - The price impact is the relative difference, in wad, between the output at spot prices and the expected output,
+    ///   including the pools fees.
+    ///
+    #[view(verifyBoosterPath)]
+    fn verify_booster_path(&self, token_id: EgldOrEsdtTokenIdentifier, opt_amount_in: OptionalValue<BigUint>) -> BoosterPathReport<Self::Api> {
+        let booster_mapper = self.rewards_booster(&token_id);
+        require!(!booster_mapper.is_empty(), ERROR_TOKEN_NOT_BOOSTED);
+
+        let booster = booster_mapper.get();
+        let amount_in = match opt_amount_in {
+            OptionalValue::Some(amount_in) => amount_in,
+            OptionalValue::None => booster.amount_left.clone(),
+        };
+
+        self.dry_run_swap_path(token_id, &booster.swap_path, amount_in)
+    }
+
+    /// Dry runs a swap path that converts a given amount of rewards tokens into Hatom's governance tokens, using the current
+    /// xExchange pools reserves and fees. See `verifyBoosterPath` for more details.
+    ///
+    fn dry_run_swap_path(&self, token_id: EgldOrEsdtTokenIdentifier, swap_path: &ManagedVec<SwapStep<Self::Api>>, amount_in: BigUint) -> BoosterPathReport<Self::Api> {
+        let mut token_in = if token_id.is_egld() { self.wegld_id().get() } else { token_id.clone().unwrap_esdt() };
+        let mut expected_amount_out = amount_in.clone();
+        let mut spot_amount_out = amount_in.clone();
+        let mut broken_step = None;
+
+        let max_fee_percent = BigUint::from(XEXCHANGE_MAX_FEE_PERCENT);
+        for step in 0..swap_path.len() {
+            let SwapStep { pair_address, input_token_id, output_token_id } = swap_path.get(step);
+
+            if input_token_id != token_in || self.get_xexchange_pair_state(&pair_address) != PairState::Active {
+                broken_step = Some(step);
+                break;
+            }
+
+            let (first_token_id, second_token_id) = self.get_xexchange_pair_tokens(&pair_address);
+            let (first_reserve, second_reserve) = self.get_xexchange_pair_reserves(&pair_address);
+            let (reserve_in, reserve_out) = if input_token_id == first_token_id && output_token_id == second_token_id {
+                (first_reserve, second_reserve)
+            } else if input_token_id == second_token_id && output_token_id == first_token_id {
+                (second_reserve, first_reserve)
+            } else {
+                broken_step = Some(step);
+                break;
+            };
+
+            let total_fee_percent = self.get_xexchange_pair_total_fee_percent(&pair_address);
+            if reserve_in == BigUint::zero() || reserve_out == BigUint::zero() || total_fee_percent >= XEXCHANGE_MAX_FEE_PERCENT {
+                broken_step = Some(step);
+                break;
+            }
+
+            // constant product formula, as implemented by xExchange pairs
+            let amount_in_with_fee = &expected_amount_out * &BigUint::from(XEXCHANGE_MAX_FEE_PERCENT - total_fee_percent);
+            let denominator = &reserve_in * &max_fee_percent + &amount_in_with_fee;
+            expected_amount_out = self.mul_div(&amount_in_with_fee, &reserve_out, &denominator, Rounding::Down);
+            spot_amount_out = self.mul_div(&spot_amount_out, &reserve_out, &reserve_in, Rounding::Down);
+
+            token_in = output_token_id;
+        }
+
+        if broken_step.is_none() && token_in != self.governance_token_id().get() {
+            broken_step = Some(swap_path.len());
+        }
+
+        let ok = broken_step.is_none() && expected_amount_out > BigUint::zero();
+        let (expected_amount_out, spot_amount_out, price_impact) = if ok {
+            let price_impact = if spot_amount_out > expected_amount_out { self.wad_div(&(&spot_amount_out - &expected_amount_out), &spot_amount_out) } else { BigUint::zero() };
+            (expected_amount_out, spot_amount_out, price_impact)
+        } else {
+            (BigUint::zero(), BigUint::zero(), BigUint::zero())
+        };
+        let within_max_slippage = ok && price_impact <= self.max_slippage().get();
+
+        BoosterPathReport {
+            ok,
+            token_id,
+            amount_in,
+            expected_amount_out,
+            spot_amount_out,
+            price_impact,
+            within_max_slippage,
+            broken_step,
+        }
+    }
+
     /// Updates the supply rewards batches state for the specified money market. In other words, it advances the rewards
     /// batch index (its "share price") one time step.
     ///
@@ -340,9 +746,18 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
     ///
     /// - `money_market` - The address of the money market to update the supply rewards batches state for.
     ///
+    /// # Notes:
+    ///
+    /// - Does nothing while rewards distribution is paused at the money market.
+    ///
     fn update_supply_rewards_batches_state(&self, money_market: &ManagedAddress) {
-        // for exponential math
-        let wad = BigUint::from(WAD);
+        // rewards batches are frozen while rewards distribution is paused
+        if self.is_rewards_distribution_paused(money_market) {
+            return;
+        }
+
+        // rewards indexes must be in the current precision before being advanced
+        self.try_migrate_rewards_indexes(money_market);
 
         // the amount of Hatom tokens deposited as collateral
         let total_collateral_tokens = self.get_total_collateral_tokens(money_market);
@@ -375,20 +790,11 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
             };
 
             if rewards_batch.speed > BigUint::zero() {
-                let rewards_accrued = &rewards_batch.speed * dt; // [wad]
-                if total_collateral_tokens == BigUint::zero() {
-                    let delta_rewards = rewards_accrued / &wad;
-                    rewards_batch.distributed_amount += &delta_rewards;
-                    self.undistributed_rewards(&rewards_batch.token_id).update(|rewards| *rewards += &delta_rewards);
-                } else {
-                    let delta_index = &rewards_accrued * &wad / &total_collateral_tokens; // [wad * wad]
-
-                    if delta_index != BigUint::zero() {
-                        rewards_batch.index += delta_index;
-                    } else {
-                        let delta_rewards = rewards_accrued / &wad;
-                        self.undistributed_rewards(&rewards_batch.token_id).update(|rewards| *rewards += &delta_rewards);
-                    }
+                let (delta_index, undistributed_rewards, distributed_rewards) = self.compute_rewards_index_step(&MarketType::Supply, &rewards_batch.speed, dt, &total_collateral_tokens);
+                rewards_batch.index += delta_index;
+                self.consume_rewards_batch_amount(&mut rewards_batch, &distributed_rewards);
+                if undistributed_rewards > BigUint::zero() {
+                    self.undistributed_rewards(&rewards_batch.token_id).update(|rewards| *rewards += &undistributed_rewards);
                 }
             }
 
@@ -405,9 +811,18 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
     ///
     /// - `money_market` - The address of the money market to update the borrow rewards batches state for.
     ///
+    /// # Notes:
+    ///
+    /// - Does nothing while rewards distribution is paused at the money market.
+    ///
     fn update_borrow_rewards_batches_state(&self, money_market: &ManagedAddress) {
-        // for exponential math
-        let wad = BigUint::from(WAD);
+        // rewards batches are frozen while rewards distribution is paused
+        if self.is_rewards_distribution_paused(money_market) {
+            return;
+        }
+
+        // rewards indexes must be in the current precision before being advanced
+        self.try_migrate_rewards_indexes(money_market);
 
         // in most cases, this is the total borrows discounted to the money market inception
         let base_total_borrows = self.get_base_total_borrows(money_market);
@@ -440,20 +855,11 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
             };
 
             if rewards_batch.speed > BigUint::zero() {
-                let rewards_accrued = &rewards_batch.speed * dt; // [wad]
-                if base_total_borrows == BigUint::zero() {
-                    let delta_rewards = rewards_accrued / &wad;
-                    rewards_batch.distributed_amount += &delta_rewards;
-                    self.undistributed_rewards(&rewards_batch.token_id).update(|rewards| *rewards += &delta_rewards);
-                } else {
-                    let delta_index = &rewards_accrued * &wad / (&base_total_borrows + 1u64); // [wad * wad]
-
-                    if delta_index != BigUint::zero() {
-                        rewards_batch.index += delta_index;
-                    } else {
-                        let delta_rewards = rewards_accrued / &wad;
-                        self.undistributed_rewards(&rewards_batch.token_id).update(|rewards| *rewards += &delta_rewards);
-                    }
+                let (delta_index, undistributed_rewards, distributed_rewards) = self.compute_rewards_index_step(&MarketType::Borrow, &rewards_batch.speed, dt, &base_total_borrows);
+                rewards_batch.index += delta_index;
+                self.consume_rewards_batch_amount(&mut rewards_batch, &distributed_rewards);
+                if undistributed_rewards > BigUint::zero() {
+                    self.undistributed_rewards(&rewards_batch.token_id).update(|rewards| *rewards += &undistributed_rewards);
                 }
             }
 
@@ -471,9 +877,9 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
     /// - `supplier` - The address of the supplier to distribute rewards to.
     ///
     fn distribute_supplier_batches_rewards(&self, money_market: &ManagedAddress, supplier: &ManagedAddress) {
-        // for exponential math
-        let wad = BigUint::from(WAD);
-        let wad_wad = &wad * &wad;
+        // rewards indexes must be in the current precision before being compared
+        self.try_migrate_rewards_indexes(money_market);
+        let initial_index = self.get_initial_rewards_index();
 
         // rewards are computed only based on the amount of hatom tokens that are deposited as collateral
         let account_collateral_tokens = self.get_account_collateral_tokens(money_market, supplier);
@@ -487,22 +893,24 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
                 continue;
             }
 
-            let RewardsBatch { id: batch_id, token_id: rewards_token_id, index: rewards_index, .. } = &rewards_batch;
+            let batch_id = rewards_batch.id;
+            let rewards_token_id = rewards_batch.token_id.clone();
 
-            let supplier_index = match self.get_account_batch_rewards_index(money_market, batch_id, supplier) {
-                None => &wad * &wad,
+            let supplier_index = match self.get_account_batch_rewards_index(money_market, &batch_id, supplier) {
+                None => initial_index.clone(),
                 Some(index) => index,
             };
 
-            self.account_batch_rewards_index(money_market, batch_id, supplier).set(rewards_index);
+            self.account_batch_rewards_index(money_market, &batch_id, supplier).set(&rewards_batch.index);
 
-            let delta_index = rewards_index - &supplier_index;
-            let delta_rewards = &account_collateral_tokens * &delta_index / &wad_wad;
+            // accounts never earn more than what is left to distribute in the batch, which is reduced on each credit
+            let accrued_rewards = self.compute_account_accrued_rewards(&account_collateral_tokens, &supplier_index, &rewards_batch.index);
+            let accrued_rewards = self.consume_rewards_batch_amount(&mut rewards_batch, &accrued_rewards);
+            let delta_rewards = self.cap_account_batch_rewards(money_market, &batch_id, supplier, &rewards_token_id, &accrued_rewards);
 
-            self.account_accrued_rewards(supplier, rewards_token_id).update(|rewards| *rewards += &delta_rewards);
+            self.credit_account_rewards(money_market, supplier, &rewards_token_id, &delta_rewards);
 
             // update batch state
-            rewards_batch.distributed_amount += &delta_rewards;
             rewards_batches.set(pos_id, &rewards_batch);
 
             self.supplier_rewards_distributed_event(supplier, &rewards_batch, &delta_rewards);
@@ -518,9 +926,9 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
     /// - `market_borrow_index` - The current borrow index for the money market.
     ///
     fn distribute_borrower_batches_rewards(&self, money_market: &ManagedAddress, borrower: &ManagedAddress) {
-        // for exponential math
-        let wad = BigUint::from(WAD);
-        let wad_wad = &wad * &wad;
+        // rewards indexes must be in the current precision before being compared
+        self.try_migrate_rewards_indexes(money_market);
+        let initial_index = self.get_initial_rewards_index();
 
         // in most cases, this is the account borrows discounted to the money market inception
         let base_account_borrow_amount = self.get_base_account_borrow_amount(money_market, borrower);
@@ -535,25 +943,307 @@ pub trait RewardsModule: admin::AdminModule + events::EventModule + proxies::Pro
                 continue;
             }
 
-            let RewardsBatch { id: batch_id, token_id: rewards_token_id, index: rewards_index, .. } = &rewards_batch;
+            let batch_id = rewards_batch.id;
+            let rewards_token_id = rewards_batch.token_id.clone();
 
-            let borrower_index = match self.get_account_batch_rewards_index(money_market, batch_id, borrower) {
-                None => &wad * &wad,
+            let borrower_index = match self.get_account_batch_rewards_index(money_market, &batch_id, borrower) {
+                None => initial_index.clone(),
                 Some(index) => index,
             };
 
-            self.account_batch_rewards_index(money_market, batch_id, borrower).set(rewards_index);
+            self.account_batch_rewards_index(money_market, &batch_id, borrower).set(&rewards_batch.index);
 
-            let delta_index = rewards_index - &borrower_index;
-            let delta_rewards = &base_account_borrow_amount * &delta_index / &wad_wad;
+            // accounts never earn more than what is left to distribute in the batch, which is reduced on each credit
+            let accrued_rewards = self.compute_account_accrued_rewards(&base_account_borrow_amount, &borrower_index, &rewards_batch.index);
+            let accrued_rewards = self.consume_rewards_batch_amount(&mut rewards_batch, &accrued_rewards);
+            let delta_rewards = self.cap_account_batch_rewards(money_market, &batch_id, borrower, &rewards_token_id, &accrued_rewards);
 
-            self.account_accrued_rewards(borrower, rewards_token_id).update(|rewards| *rewards += &delta_rewards);
+            self.credit_account_rewards(money_market, borrower, &rewards_token_id, &delta_rewards);
 
             // update batch state
-            rewards_batch.distributed_amount += &delta_rewards;
             rewards_batches.set(pos_id, &rewards_batch);
 
             self.borrower_rewards_distributed_event(borrower, &rewards_batch, &delta_rewards);
         }
     }
+
+    /// Credits a given amount of rewards distributed at a given money market to an account. If rewards distribution has been
+    /// paused at the money market, rewards are held for the account instead. Otherwise, any rewards previously held are
+    /// released as well.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market where rewards have been distributed.
+    /// - `account` - The address of the account.
+    /// - `rewards_token_id` - The rewards token identifier.
+    /// - `delta_rewards` - The amount of rewards distributed to the account.
+    ///
+    fn credit_account_rewards(&self, money_market: &ManagedAddress, account: &ManagedAddress, rewards_token_id: &EgldOrEsdtTokenIdentifier, delta_rewards: &BigUint) {
+        let held_rewards_mapper = self.account_held_rewards(money_market, account, rewards_token_id);
+        if self.is_rewards_distribution_paused(money_market) {
+            held_rewards_mapper.update(|rewards| *rewards += delta_rewards);
+            return;
+        }
+
+        let rewards = delta_rewards + &held_rewards_mapper.take();
+        self.account_accrued_rewards(account, rewards_token_id).update(|accrued_rewards| *accrued_rewards += &rewards);
+        if rewards > BigUint::zero() {
+            self.account_rewards_tokens(account).insert(rewards_token_id.clone());
+        }
+    }
+
+    /// Skips the period during which rewards distribution has been paused at a given money market, i.e. advances the last
+    /// update time of its rewards batches up to this point without advancing their indexes. The rewards of the skipped
+    /// period are kept as undistributed rewards.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market.
+    ///
+    fn skip_rewards_batches_paused_period(&self, money_market: &ManagedAddress) {
+        let t = self.blockchain().get_block_timestamp();
+
+        let mut rewards_batches = self.rewards_batches(money_market);
+
+        for pos_id in 1..=rewards_batches.len() {
+            let mut rewards_batch = rewards_batches.get(pos_id);
+
+            let t_end = core::cmp::min(t, rewards_batch.end_time);
+            if rewards_batch.last_time >= t_end {
+                continue;
+            }
+
+            let dt = t_end - rewards_batch.last_time;
+            rewards_batch.last_time = t_end;
+
+            let (_, undistributed_rewards, _) = self.compute_rewards_index_step(&rewards_batch.market_type, &rewards_batch.speed, dt, &BigUint::zero());
+            let undistributed_rewards = self.consume_rewards_batch_amount(&mut rewards_batch, &undistributed_rewards);
+            if undistributed_rewards > BigUint::zero() {
+                self.undistributed_rewards(&rewards_batch.token_id).update(|rewards| *rewards += &undistributed_rewards);
+            }
+
+            rewards_batches.set(pos_id, &rewards_batch);
+        }
+    }
+
+    /// Computes a single time step of a rewards batch index. Returns the increment of the index (in wad * wad * wad), the
+    /// rewards that are kept as undistributed rewards and the increment of the rewards batch distributed amount.
+    ///
+    /// # Arguments:
+    ///
+    /// - `market_type` - Whether the rewards batch rewards suppliers or borrowers.
+    /// - `speed` - The rewards batch speed, in wad.
+    /// - `dt` - The elapsed time since the last update, in seconds.
+    /// - `total` - The total collateral tokens for supply rewards batches or the base total borrows for borrow rewards
+    ///   batches.
+    ///
+    fn compute_rewards_index_step(&self, market_type: &MarketType, speed: &BigUint, dt: u64, total: &BigUint) -> (BigUint, BigUint, BigUint) {
+        // for exponential math
+        let wad = BigUint::from(WAD);
+
+        let rewards_accrued = speed * dt; // [wad]
+        if total == &BigUint::zero() {
+            let delta_rewards = rewards_accrued / &wad;
+            return (BigUint::zero(), delta_rewards.clone(), delta_rewards);
+        }
+
+        // borrow rewards round down, since the base account borrows of all borrowers might add up to more than the base total
+        // borrows
+        let delta_index = match market_type {
+            MarketType::Supply => &rewards_accrued * &wad * &wad / total,
+            MarketType::Borrow => &rewards_accrued * &wad * &wad / &(total + 1u64),
+        }; // [wad * wad * wad]
+
+        if delta_index != BigUint::zero() {
+            (delta_index, BigUint::zero(), BigUint::zero())
+        } else {
+            let delta_rewards = rewards_accrued / &wad;
+            (BigUint::zero(), delta_rewards, BigUint::zero())
+        }
+    }
+
+    /// Reprices a rewards batch denominated in USD, i.e. recomputes its speed using the current oracle price of its rewards
+    /// token such that it distributes a constant USD value per day. The end time is recomputed such that the remaining
+    /// rewards are preserved. Rewards batches denominated in tokens are left untouched.
+    ///
+    /// # Arguments:
+    ///
+    /// - `rewards_batch` - The rewards batch to reprice, which must have been updated up to this point and is not stored.
+    ///
+    /// # Notes:
+    ///
+    /// - If the rewards token cannot be priced, the current speed is kept and an event is emitted.
+    /// - Reverts if the price oracle reverts while pricing the rewards token. Hence, it is never called when rewards batches
+    ///   states are updated, which must not revert.
+    ///
+    fn try_reprice_usd_rewards_batch(&self, rewards_batch: &mut RewardsBatch<Self::Api>) {
+        let usd_per_day_mapper = self.rewards_batch_usd_per_day(&rewards_batch.money_market, &rewards_batch.id);
+        if usd_per_day_mapper.is_empty() || rewards_batch.last_time >= rewards_batch.end_time {
+            return;
+        }
+
+        // token price in USD [wad]
+        let token_price_in_usd = match self.try_get_rewards_token_price_in_usd(&rewards_batch.token_id) {
+            Some(price) => price,
+            None => {
+                self.rewards_batch_reprice_failed_event(&rewards_batch.money_market, rewards_batch.id, &rewards_batch.speed);
+                return;
+            },
+        };
+
+        let wad = BigUint::from(WAD);
+        let usd_per_day = usd_per_day_mapper.get();
+        let new_speed = usd_per_day * &wad * &wad / (token_price_in_usd * SECONDS_PER_DAY); // [wad]
+        if new_speed == BigUint::zero() || new_speed == rewards_batch.speed {
+            return;
+        }
+
+        // keep the remaining rewards, which only changes the remaining distribution time period
+        let remaining_rewards = &rewards_batch.speed * (rewards_batch.end_time - rewards_batch.last_time); // [wad]
+        let new_dt = match BigUint::to_u64(&(remaining_rewards / &new_speed)) {
+            None => return,
+            Some(dt) => dt,
+        };
+
+        if new_dt == 0u64 {
+            return;
+        }
+
+        rewards_batch.speed = new_speed;
+        rewards_batch.end_time = rewards_batch.last_time + new_dt;
+    }
+
+    /// Computes the rewards accrued by an account in a rewards batch since its last distribution.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The account collateral tokens for supply rewards batches or its base borrow amount for borrow rewards
+    ///   batches.
+    /// - `account_index` - The rewards batch index at the last distribution to the account, in wad * wad * wad.
+    /// - `rewards_index` - The current rewards batch index, in wad * wad * wad.
+    ///
+    fn compute_account_accrued_rewards(&self, amount: &BigUint, account_index: &BigUint, rewards_index: &BigUint) -> BigUint {
+        let index_scale = self.get_initial_rewards_index();
+        let delta_index = rewards_index - account_index;
+        amount * &delta_index / &index_scale
+    }
+
+    /// Returns the initial index of a rewards batch or account, which also is the unit of rewards indexes (wad * wad * wad).
+    ///
+    fn get_initial_rewards_index(&self) -> BigUint {
+        let wad = BigUint::from(WAD);
+        &wad * &wad * &wad
+    }
+
+    /// Returns the amount of rewards that are left to distribute in a given rewards batch.
+    ///
+    /// # Arguments:
+    ///
+    /// - `rewards_batch` - The rewards batch.
+    ///
+    fn get_rewards_batch_remaining_amount(&self, rewards_batch: &RewardsBatch<Self::Api>) -> BigUint {
+        if rewards_batch.amount > rewards_batch.distributed_amount {
+            &rewards_batch.amount - &rewards_batch.distributed_amount
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    /// Consumes up to a given amount of rewards from what is left to distribute in a given rewards batch, such that the total
+    /// distributed across all accounts never exceeds the rewards batch amount. Returns the consumed amount.
+    ///
+    /// # Arguments:
+    ///
+    /// - `rewards_batch` - The rewards batch, which is not stored.
+    /// - `amount` - The amount of rewards to consume.
+    ///
+    fn consume_rewards_batch_amount(&self, rewards_batch: &mut RewardsBatch<Self::Api>, amount: &BigUint) -> BigUint {
+        let amount = BigUint::min(amount.clone(), self.get_rewards_batch_remaining_amount(rewards_batch));
+        rewards_batch.distributed_amount += &amount;
+        amount
+    }
+
+    /// Migrates the rewards batches indexes of a given money market from the legacy wad * wad precision to the current wad *
+    /// wad * wad precision, if not migrated yet.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market.
+    ///
+    fn try_migrate_rewards_indexes(&self, money_market: &ManagedAddress) {
+        let migrated_mapper = self.rewards_indexes_migrated(money_market);
+        if migrated_mapper.get() {
+            return;
+        }
+
+        let wad = BigUint::from(WAD);
+        let mut rewards_batches = self.rewards_batches(money_market);
+        for pos_id in 1..=rewards_batches.len() {
+            let mut rewards_batch = rewards_batches.get(pos_id);
+            rewards_batch.index *= &wad;
+            rewards_batches.set(pos_id, &rewards_batch);
+        }
+
+        migrated_mapper.set(true);
+        self.rewards_indexes_migrated_event(money_market);
+    }
+
+    /// Exposes a single time step of a rewards batch index for a synthetic scenario, such that the index math can be
+    /// verified against independent implementations. See `compute_rewards_index_step` for more details.
+    ///
+    /// # Notes:
+    ///
+    /// - Only exported by the testing build of the contract.
+    ///
+    #[label("testing")]
+    #[view(testComputeRewardsIndexStep)]
+    fn test_compute_rewards_index_step(&self, market_type: MarketType, speed: BigUint, dt: u64, total: BigUint) -> MultiValue3<BigUint, BigUint, BigUint> {
+        self.compute_rewards_index_step(&market_type, &speed, dt, &total).into()
+    }
+
+    /// Exposes the rewards accrued by an account for a synthetic scenario, such that the index math can be verified against
+    /// independent implementations. See `compute_account_accrued_rewards` for more details.
+    ///
+    /// # Notes:
+    ///
+    /// - Only exported by the testing build of the contract.
+    ///
+    #[label("testing")]
+    #[view(testComputeAccountAccruedRewards)]
+    fn test_compute_account_accrued_rewards(&self, amount: BigUint, account_index: BigUint, rewards_index: BigUint) -> BigUint {
+        self.compute_account_accrued_rewards(&amount, &account_index, &rewards_index)
+    }
+
+    /// Caps the rewards accrued by an account in a given rewards batch to the batch per-account cap, if any. Rewards above
+    /// the cap are kept as undistributed rewards.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market.
+    /// - `batch_id` - The rewards batch identifier.
+    /// - `account` - The address of the account.
+    /// - `rewards_token_id` - The rewards token identifier.
+    /// - `accrued_rewards` - The rewards accrued by the account since its last distribution.
+    ///
+    fn cap_account_batch_rewards(&self, money_market: &ManagedAddress, batch_id: &usize, account: &ManagedAddress, rewards_token_id: &EgldOrEsdtTokenIdentifier, accrued_rewards: &BigUint) -> BigUint {
+        let cap_mapper = self.rewards_batch_account_cap(money_market, batch_id);
+        if cap_mapper.is_empty() || accrued_rewards == &BigUint::zero() {
+            return accrued_rewards.clone();
+        }
+
+        let cap = cap_mapper.get();
+        let account_rewards_mapper = self.account_batch_rewards(money_market, batch_id, account);
+        let account_rewards = account_rewards_mapper.get();
+        let allowed_rewards = if cap > account_rewards { cap - &account_rewards } else { BigUint::zero() };
+
+        let delta_rewards = BigUint::min(accrued_rewards.clone(), allowed_rewards);
+        let excess_rewards = accrued_rewards - &delta_rewards;
+        if excess_rewards > BigUint::zero() {
+            self.undistributed_rewards(rewards_token_id).update(|rewards| *rewards += &excess_rewards);
+        }
+
+        account_rewards_mapper.set(account_rewards + &delta_rewards);
+
+        delta_rewards
+    }
 }
@@ -1,7 +1,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use super::{constants::*, events, proxies, shared, storage};
+use super::{constants::*, errors::*, events, proxies, shared, storage};
+
+use crate::storage::AccountMarketData;
+use math::{constants::BPS, Rounding};
 
 #[type_abi]
 #[derive(TopEncode, TopDecode, PartialEq, Clone, Debug)]
@@ -10,6 +13,16 @@ pub enum RiskProfile<M: ManagedTypeApi> {
     RiskyOrInsolvent(BigUint<M>), // implies either risk of insolvency or insolvent
 }
 
+/// The status of an account as exposed to external protocols. An account is `Liquidatable` if its health factor is below 1,
+/// `Warning` if it is below the warning health factor and `Healthy` otherwise, including accounts without borrows.
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub enum AccountStatus {
+    Healthy,
+    Warning,
+    Liquidatable,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
 pub enum Liquidation {
@@ -24,6 +37,40 @@ pub struct AccountSnapshot<M: ManagedTypeApi> {
     money_market: ManagedAddress<M>,
     underlying_owed_amount: BigUint<M>,
     fx: BigUint<M>,
+    collateral_tokens: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Clone)]
+pub struct PriceShockReport<M: ManagedTypeApi> {
+    pub money_market: ManagedAddress<M>,
+    pub price_delta_bps: i64,
+    pub shocked_price: BigUint<M>,
+    pub accounts_checked: usize,
+    pub liquidatable_accounts: ManagedVec<M, ManagedAddress<M>>,
+    pub total_shortfall: BigUint<M>,
+}
+
+/// The health breakdown of an account at a single money market, with values expressed in EGLD and in wad.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
+pub struct MarketHealth<M: ManagedTypeApi> {
+    pub money_market: ManagedAddress<M>,
+    pub collateral_value: BigUint<M>,
+    pub borrow_value: BigUint<M>,
+    pub collateral_factor: BigUint<M>,
+}
+
+/// The health breakdown of an account across all its markets, with values expressed in EGLD and in wad. The total
+/// collateral is weighted by the effective collateral factors, and either the liquidity or the shortfall is zero.
+#[type_abi]
+#[derive(TopEncode, TopDecode, Clone)]
+pub struct AccountHealth<M: ManagedTypeApi> {
+    pub markets: ManagedVec<M, MarketHealth<M>>,
+    pub total_collateral: BigUint<M>,
+    pub total_borrow: BigUint<M>,
+    pub liquidity: BigUint<M>,
+    pub shortfall: BigUint<M>,
 }
 
 impl<M: ManagedTypeApi> RiskProfile<M> {
@@ -36,14 +83,12 @@ impl<M: ManagedTypeApi> RiskProfile<M> {
     }
 
     /// Given an account with an outstanding borrow, checks if it is possible to liquidate its position by a repayment
-    /// amount, considering the allowed closing factor
-    pub fn can_be_liquidated(&self, repay_amount: &BigUint<M>, borrow_amount: &BigUint<M>, close_factor: &BigUint<M>) -> Liquidation {
+    /// amount, considering the maximum amount allowed by the closing factor
+    pub fn can_be_liquidated(&self, repay_amount: &BigUint<M>, max_close_amount: &BigUint<M>) -> Liquidation {
         match *self {
             RiskProfile::Solvent(_) => Liquidation::NotAllowed,
             RiskProfile::RiskyOrInsolvent(_) => {
-                let wad = BigUint::from(WAD);
-                let max_close_amount = close_factor * borrow_amount / wad;
-                if repay_amount > &max_close_amount {
+                if repay_amount > max_close_amount {
                     return Liquidation::AllowedButTooMuch;
                 }
                 Liquidation::Allowed
@@ -69,6 +114,123 @@ pub trait RiskProfileModule: admin::AdminModule + events::EventModule + proxies:
         }
     }
 
+    /// Gets the status of a given account together with its health factor, i.e. its collateral value weighted by collateral
+    /// factors over its borrowed value, in wad. This is a minimal and stable interface intended for external protocols that
+    /// integrate Hatom positions.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    ///
+    /// # Notes:
+    ///
+    /// - The health factor is `None` if the account has no borrows, in which case the account is `Healthy`.
+    /// - The account is `Liquidatable` if and only if its health factor is below 1 (in wad), which matches the liquidation
+    ///   policy of the Controller.
+    /// - The account is `Warning` if its health factor is below `WARNING_HEALTH_FACTOR`.
+    ///
+    #[endpoint(getAccountStatus)]
+    fn get_account_status(&self, account: &ManagedAddress) -> MultiValue2<AccountStatus, Option<BigUint>> {
+        let (total_collateral, total_borrow) = match self.simulate_account_totals(account, &ManagedAddress::zero(), &BigUint::zero(), &BigUint::zero(), true, &None) {
+            Some(totals) => totals,
+            None => return (AccountStatus::Healthy, None).into(),
+        };
+
+        if total_borrow == BigUint::zero() {
+            return (AccountStatus::Healthy, None).into();
+        }
+
+        let health_factor = self.wad_div(&total_collateral, &total_borrow);
+        let status = if total_collateral < total_borrow {
+            AccountStatus::Liquidatable
+        } else if health_factor < BigUint::from(WARNING_HEALTH_FACTOR) {
+            AccountStatus::Warning
+        } else {
+            AccountStatus::Healthy
+        };
+
+        (status, Some(health_factor)).into()
+    }
+
+    /// Computes the leverage of a given account in wad, defined as its total supplied value over its net equity, i.e. its
+    /// total supplied value minus its total borrowed value. Supplied value is not weighted by collateral factors.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `None` if the account net equity is zero or negative.
+    /// - An account without borrows has a leverage of 1 (in wad).
+    ///
+    #[endpoint(getAccountLeverage)]
+    fn get_account_leverage(&self, account: &ManagedAddress) -> Option<BigUint> {
+        // represent the total supplied and borrowed values in a numeraire of our choice (EGLD) in wad
+        let mut total_supplied = BigUint::zero();
+        let mut total_borrow = BigUint::zero();
+
+        for money_market in self.account_markets(account).iter() {
+            let AccountMarketData { collateral_tokens, fx, borrow_amount: underlying_owed_amount, .. } = self.get_account_market_data(&money_market, account);
+
+            let underlying_price = self.get_underlying_price(&money_market);
+            let token_price = self.wad_mul(&fx, &underlying_price);
+
+            total_supplied += self.wad_mul(&token_price, &collateral_tokens);
+            total_borrow += self.wad_mul(&underlying_price, &underlying_owed_amount);
+        }
+
+        if total_supplied <= total_borrow {
+            return None;
+        }
+
+        let net_equity = &total_supplied - &total_borrow;
+        Some(self.wad_div(&total_supplied, &net_equity))
+    }
+
+    /// Gets the health breakdown of a given account, i.e. for each of its markets the collateral value, the borrow value and
+    /// the effective collateral factor, together with its aggregated liquidity or shortfall. Values are computed exactly as
+    /// in the Controller risk checks.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    ///
+    /// # Notes:
+    ///
+    /// - Collateral values are not weighted by collateral factors, whereas the total collateral is.
+    /// - The effective collateral factor is the USH borrower collateral factor if the account borrows USH.
+    /// - Prices are retrieved from the price oracle and account snapshots from the money markets, which is why this is an
+    ///   endpoint.
+    ///
+    #[endpoint(getAccountHealth)]
+    fn get_account_health(&self, account: &ManagedAddress) -> AccountHealth<Self::Api> {
+        let (snapshots, _, ush_borrower) = self.get_account_snapshots(account);
+
+        let mut markets = ManagedVec::new();
+        let mut total_collateral = BigUint::zero();
+        let mut total_borrow = BigUint::zero();
+
+        for snapshot in snapshots.iter() {
+            let AccountSnapshot { money_market, underlying_owed_amount, fx, collateral_tokens } = snapshot;
+
+            let (collateral_factor, underlying_price, token_price) = self.get_market_valuation(&money_market, &fx, ush_borrower, &None);
+            let token_price_eff = self.wad_mul(&collateral_factor, &token_price);
+
+            total_collateral += self.wad_mul(&token_price_eff, &collateral_tokens);
+
+            let collateral_value = self.wad_mul(&token_price, &collateral_tokens);
+            let borrow_value = self.wad_mul(&underlying_price, &underlying_owed_amount);
+            total_borrow += &borrow_value;
+
+            markets.push(MarketHealth { money_market, collateral_value, borrow_value, collateral_factor });
+        }
+
+        let (liquidity, shortfall) = if total_collateral >= total_borrow { (&total_collateral - &total_borrow, BigUint::zero()) } else { (BigUint::zero(), &total_borrow - &total_collateral) };
+
+        AccountHealth { markets, total_collateral, total_borrow, liquidity, shortfall }
+    }
+
     /// Performs a risk profile simulation for a given account, considering its current opened positions and simulating
     /// either redeeming or borrowing (or both) in a given money market. The money market for the simulation must be already
     /// included as an account market. Otherwise, the simulation will not be performed.
@@ -85,85 +247,205 @@ pub trait RiskProfileModule: admin::AdminModule + events::EventModule + proxies:
     ///
     #[endpoint(simulateRiskProfile)]
     fn simulate_risk_profile(&self, account: &ManagedAddress, this_money_market: &ManagedAddress, redeem_tokens: &BigUint, borrow_amount: &BigUint, lazy: bool) -> RiskProfile<Self::Api> {
-        // * Important: `account_markets` might not include `this_money_market`. If that is the case, the simulation will not
-        // * be performed and the result will not be accurate.
-        let account_markets = self.account_markets(account);
+        self.simulate_risk_profile_internal(account, this_money_market, redeem_tokens, borrow_amount, lazy, &None)
+    }
 
-        // assume the account does not have any outstanding borrow
-        let mut borrower = false;
+    /// Simulates a price shock on the underlying of a given money market and computes which of its members would be
+    /// liquidatable under the shocked price, together with their aggregated shortfall. All other prices remain constant.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The money market whose underlying price is shocked.
+    /// - `price_delta_bps` - The price change in bps, e.g. -2000 for a 20% price drop.
+    /// - `from` - The zero based position of the first market member in the page.
+    /// - `size` - The maximum number of market members in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Only members of the given money market are checked, i.e. accounts that entered it as collateral or borrowers.
+    /// - Accounts that are already liquidatable are also reported.
+    /// - Prices are retrieved from the price oracle and account snapshots from the money markets, which is why this is an
+    ///   endpoint.
+    /// - Restricted to registered readers if bulk enumeration views are restricted.
+    ///
+    #[endpoint(simulatePriceShock)]
+    fn simulate_price_shock(&self, money_market: &ManagedAddress, price_delta_bps: i64, from: usize, size: usize) -> PriceShockReport<Self::Api> {
+        self.require_enumeration_allowed();
+        self.require_whitelisted_money_market(money_market);
+        require!(price_delta_bps >= -(BPS as i64), ERROR_INVALID_PRICE_DELTA);
 
-        // assume the accounts is not a USH borrower
-        let mut ush_borrower = false;
-        let opt_ush_market = self.get_ush_market_observer();
-        let ush_market = opt_ush_market.unwrap_or_default();
+        let price = self.get_underlying_price(money_market);
+        let shocked_price = if price_delta_bps >= 0 { self.bps_mul(&price, &BigUint::from(BPS + price_delta_bps as u64), Rounding::Down) } else { self.bps_mul(&price, &BigUint::from(BPS - price_delta_bps.unsigned_abs()), Rounding::Down) };
 
-        let mut snapshots: ManagedVec<AccountSnapshot<Self::Api>> = ManagedVec::new();
-        for money_market in account_markets.iter() {
-            let (underlying_owed_amount, fx) = self.get_account_snapshot(&money_market, account);
+        let price_shock = Some((money_market.clone(), shocked_price.clone()));
 
-            if underlying_owed_amount > BigUint::zero() {
-                if money_market == ush_market {
-                    ush_borrower = true;
-                }
-                borrower = true;
+        let mut liquidatable_accounts = ManagedVec::new();
+        let mut total_shortfall = BigUint::zero();
+
+        let market_members = self.market_members(money_market);
+        let to = core::cmp::min(from.saturating_add(size), market_members.len());
+        for index in from..to {
+            let account = market_members.get_by_index(index + 1);
+            let risk_profile = self.simulate_risk_profile_internal(&account, &ManagedAddress::zero(), &BigUint::zero(), &BigUint::zero(), true, &price_shock);
+            if let RiskProfile::RiskyOrInsolvent(shortfall) = risk_profile {
+                total_shortfall += &shortfall;
+                liquidatable_accounts.push(account);
             }
+        }
+
+        PriceShockReport {
+            money_market: money_market.clone(),
+            price_delta_bps,
+            shocked_price,
+            accounts_checked: to.saturating_sub(from),
+            liquidatable_accounts,
+            total_shortfall,
+        }
+    }
+
+    /// Performs a risk profile simulation as in `simulateRiskProfile`, optionally overriding the underlying price of a
+    /// single money market, which allows simulating price shocks.
+    ///
+    fn simulate_risk_profile_internal(&self, account: &ManagedAddress, this_money_market: &ManagedAddress, redeem_tokens: &BigUint, borrow_amount: &BigUint, lazy: bool, price_shock: &Option<(ManagedAddress, BigUint)>) -> RiskProfile<Self::Api> {
+        // if it is a lazy computation and the account is not a borrower, return a solvent risk profile with a dummy liquidity
+        let (total_collateral, total_borrow) = match self.simulate_account_totals(account, this_money_market, redeem_tokens, borrow_amount, lazy, price_shock) {
+            Some(totals) => totals,
+            None => return RiskProfile::Solvent(BigUint::zero()),
+        };
 
-            snapshots.push(AccountSnapshot { money_market, underlying_owed_amount, fx });
+        if total_collateral >= total_borrow {
+            let liquidity = total_collateral - total_borrow;
+            RiskProfile::Solvent(liquidity)
+        } else {
+            let shortfall = total_borrow - total_collateral;
+            RiskProfile::RiskyOrInsolvent(shortfall)
         }
+    }
+
+    /// Computes the total collateral value, weighted by collateral factors, and the total borrowed value of a given account,
+    /// both in EGLD and in wad, simulating the effects of redeeming and borrowing at a given money market. Returns `None` if
+    /// it is a lazy computation and the account is not a borrower.
+    ///
+    fn simulate_account_totals(&self, account: &ManagedAddress, this_money_market: &ManagedAddress, redeem_tokens: &BigUint, borrow_amount: &BigUint, lazy: bool, price_shock: &Option<(ManagedAddress, BigUint)>) -> Option<(BigUint, BigUint)> {
+        // * Important: `account_markets` might not include `this_money_market`. If that is the case, the simulation will not
+        // * be performed and the result will not be accurate.
+        let (snapshots, mut borrower, mut ush_borrower) = self.get_account_snapshots(account);
 
         if borrow_amount > &BigUint::zero() {
+            let ush_market = self.get_ush_market_observer().unwrap_or_default();
             if this_money_market == &ush_market {
                 ush_borrower = true;
             }
             borrower = true;
         }
 
-        // if it is a lazy computation and the account is not a borrower, return a solvent risk profile with a dummy liquidity
+        // if it is a lazy computation and the account is not a borrower, there is nothing to compute
         if lazy && !borrower {
-            return RiskProfile::Solvent(BigUint::zero());
+            return None;
         }
 
-        // for exponential math
-        let wad = BigUint::from(WAD);
-
         // represent the total borrow and collateral expressed in a numeraire of our choice (EGLD) in wad
         let mut total_borrow = BigUint::zero();
         let mut total_collateral = BigUint::zero();
 
         for snapshot in snapshots.iter() {
-            let AccountSnapshot { money_market, underlying_owed_amount, fx } = snapshot;
+            let AccountSnapshot { money_market, underlying_owed_amount, fx, collateral_tokens } = snapshot;
 
-            // get loan to value and collateral
-            let (collateral_factor, ush_borrower_collateral_factor) = self.update_and_get_collateral_factors(&money_market);
-            let ltv = if !ush_borrower { collateral_factor } else { ush_borrower_collateral_factor };
-            let collateral_tokens = self.get_account_collateral_tokens(&money_market, account);
-
-            // get both the underlying and token prices in a numeraire of our choice (EGLD) in wad
-            let underlying_price = self.get_underlying_price(&money_market);
-            let token_price = &fx * &underlying_price / &wad;
-            let token_price_eff = &ltv * &token_price / &wad;
+            let (ltv, underlying_price, token_price) = self.get_market_valuation(&money_market, &fx, ush_borrower, price_shock);
+            let token_price_eff = self.wad_mul(&ltv, &token_price);
 
             // accumulate collateral and borrow
-            total_collateral += &token_price_eff * &collateral_tokens / &wad;
-            total_borrow += &underlying_price * &underlying_owed_amount / &wad;
+            total_collateral += self.wad_mul(&token_price_eff, &collateral_tokens);
+            total_borrow += self.wad_mul(&underlying_price, &underlying_owed_amount);
 
             // if we are trying to redeem or borrow from `this_money_market`, these are the effects: redeeming reduces
             // collateral and borrowing increases borrow
             if money_market == *this_money_market {
                 // redeem effect: notice that addition to `total_borrows` is equivalent to subtraction to `total_collateral`
-                total_borrow += token_price_eff * redeem_tokens / &wad;
+                total_borrow += self.wad_mul(&token_price_eff, redeem_tokens);
 
                 // borrow effect
-                total_borrow += underlying_price * borrow_amount / &wad;
+                total_borrow += self.wad_mul(&underlying_price, borrow_amount);
             }
         }
 
-        if total_collateral >= total_borrow {
-            let liquidity = total_collateral - total_borrow;
-            RiskProfile::Solvent(liquidity)
-        } else {
-            let shortfall = total_borrow - total_collateral;
-            RiskProfile::RiskyOrInsolvent(shortfall)
+        Some((total_collateral, total_borrow))
+    }
+
+    /// Gets the market snapshots of a given account across all its markets, together with whether the account is a
+    /// borrower and whether it is a USH borrower.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account we wish to analyze.
+    ///
+    fn get_account_snapshots(&self, account: &ManagedAddress) -> (ManagedVec<AccountSnapshot<Self::Api>>, bool, bool) {
+        // assume the account does not have any outstanding borrow
+        let mut borrower = false;
+
+        // assume the accounts is not a USH borrower
+        let mut ush_borrower = false;
+        let opt_ush_market = self.get_ush_market_observer();
+        let ush_market = opt_ush_market.unwrap_or_default();
+
+        let mut snapshots: ManagedVec<AccountSnapshot<Self::Api>> = ManagedVec::new();
+        for money_market in self.account_markets(account).iter() {
+            let AccountMarketData { collateral_tokens, fx, borrow_amount: underlying_owed_amount, .. } = self.get_account_market_data(&money_market, account);
+
+            if underlying_owed_amount > BigUint::zero() {
+                if money_market == ush_market {
+                    ush_borrower = true;
+                }
+                borrower = true;
+            }
+
+            snapshots.push(AccountSnapshot { money_market, underlying_owed_amount, fx, collateral_tokens });
         }
+
+        (snapshots, borrower, ush_borrower)
+    }
+
+    /// Gets the effective collateral factor (i.e. loan to value), the underlying price and the token price of a given money
+    /// market, with prices expressed in EGLD and in wad.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `fx` - The money market exchange rate.
+    /// - `ush_borrower` - Whether the account is a USH borrower.
+    /// - `price_shock` - An optional underlying price override for a single money market.
+    ///
+    fn get_market_valuation(&self, money_market: &ManagedAddress, fx: &BigUint, ush_borrower: bool, price_shock: &Option<(ManagedAddress, BigUint)>) -> (BigUint, BigUint, BigUint) {
+        // get loan to value and collateral
+        let (collateral_factor, ush_borrower_collateral_factor) = self.update_and_get_collateral_factors(money_market);
+        let ltv = if !ush_borrower { collateral_factor } else { ush_borrower_collateral_factor };
+
+        // get both the underlying and token prices in a numeraire of our choice (EGLD) in wad
+        let underlying_price = match price_shock {
+            Some((shocked_money_market, shocked_price)) if shocked_money_market == money_market => shocked_price.clone(),
+            _ => self.get_underlying_price(money_market),
+        };
+        let token_price = self.wad_mul(fx, &underlying_price);
+
+        (ltv, underlying_price, token_price)
+    }
+
+    /// Gets the market data of an account at a given money market, i.e. its collateral tokens, the money market exchange
+    /// rate and its borrow amount, as given by a fresh account snapshot.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The account we wish to analyze.
+    ///
+    /// # Notes:
+    ///
+    /// - Nothing is cached, given that the exchange rate and the borrow amount can change with any interaction at the money
+    ///   market without the Controller being notified.
+    ///
+    fn get_account_market_data(&self, money_market: &ManagedAddress, account: &ManagedAddress) -> AccountMarketData<Self::Api> {
+        let (borrow_amount, fx) = self.get_account_snapshot(money_market, account);
+        let collateral_tokens = self.get_account_collateral_tokens(money_market, account);
+        AccountMarketData { collateral_tokens, fx, borrow_amount }
     }
 }
@@ -2,10 +2,16 @@ multiversx_sc::imports!();
 
 use super::{constants::*, errors::*, events, proxies, storage};
 
-use crate::storage::{Status, SwapOperationType, SwapStep, SWAP_TOKENS_FIXED_INPUT_FUNC_NAME};
+use math::Rounding;
+
+use crate::storage::{DeploymentReport, MarketChange, MarketMetadata, MarketMirror, MarketType, PauseReason, RewardsTokenMetadata, Status, SwapOperationType, SwapStep, SWAP_TOKENS_FIXED_INPUT_FUNC_NAME};
+
+pub type ProtocolTotalsType<BigUint> = MultiValue6<BigUint, BigUint, BigUint, BigUint, BigUint, BigUint>;
+
+pub type EmissionRateType<M> = MultiValue3<ManagedAddress<M>, MarketType, BigUint<M>>;
 
 #[multiversx_sc::module]
-pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::ProxyModule + storage::StorageModule {
+pub trait SharedModule: admin::AdminModule + math::MathModule + events::EventModule + proxies::ProxyModule + storage::StorageModule {
     // Checks
 
     /// A utility function to highlight that this smart contract is a Controller.
@@ -89,6 +95,16 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         self.blockchain().is_smart_contract(sc_address) && self.is_ush_market(sc_address)
     }
 
+    /// Checks whether the specified smart contract address is a position market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `sc_address` - The address of the smart contract to check.
+    ///
+    fn is_position_market_sc(&self, sc_address: &ManagedAddress) -> bool {
+        self.blockchain().is_smart_contract(sc_address) && self.is_position_market(sc_address)
+    }
+
     /// Checks whether the specified smart contract address is a price oracle.
     ///
     /// # Arguments:
@@ -99,7 +115,9 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         self.blockchain().is_smart_contract(sc_address) && self.is_price_oracle(sc_address)
     }
 
-    /// Checks whether the specified money market is deprecated.
+    /// Checks whether the specified money market is deprecated. A money market is deprecated only if it has been explicitly
+    /// flagged as such by governance and, in addition, its collateral factor is zero, its borrows are paused and its reserve
+    /// factor is 100%.
     ///
     /// # Arguments:
     ///
@@ -107,12 +125,60 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
     ///
     #[endpoint(isDeprecated)]
     fn is_deprecated(&self, money_market: &ManagedAddress) -> bool {
+        if !self.deprecated(money_market).get() {
+            return false;
+        }
+
         let b0 = self.update_and_get_collateral_factor(money_market) == BigUint::zero();
         let b1 = self.get_borrow_status(money_market) == Status::Paused;
         let b2 = self.get_reserve_factor(money_market) == BigUint::from(WAD);
         b0 && b1 && b2
     }
 
+    /// Pushes the Controller-derived values of a money market to the money market, such that its mirror views remain read
+    /// only.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Position markets and USH money markets do not mirror the Controller and are skipped.
+    /// - The push is a synchronous call, such that a failing push reverts the governance change that triggered it.
+    ///
+    fn sync_market_mirror(&self, money_market: &ManagedAddress) {
+        if self.position_market(money_market).get() || self.has_ush_id(money_market) {
+            return;
+        }
+
+        let next_collateral_factors_mapper = self.next_collateral_factors(money_market);
+        let next_collateral_factors = if next_collateral_factors_mapper.is_empty() { None } else { Some(next_collateral_factors_mapper.get()) };
+
+        let mirror = MarketMirror {
+            collateral_factor: self.collateral_factor(money_market).get(),
+            ush_borrower_collateral_factor: self.ush_borrower_collateral_factor(money_market).get(),
+            next_collateral_factors,
+            liquidity_cap: self.liquidity_cap(money_market).get(),
+            borrow_cap: self.borrow_cap(money_market).get(),
+            mint_paused: self.mint_status(money_market).get() == Status::Paused,
+            borrow_paused: self.borrow_status(money_market).get() == Status::Paused,
+            // the global seize pause applies to all money markets
+            seize_paused: self.seize_status(money_market).get() == Status::Paused || self.global_seize_status().get() == Status::Paused,
+            deprecated: self.deprecated(money_market).get(),
+        };
+
+        self.sync_controller_mirror(money_market, mirror);
+    }
+
+    /// Pushes the Controller-derived values of all whitelisted money markets to them.
+    ///
+    fn sync_all_market_mirrors(&self) {
+        for money_market in self.whitelisted_markets().iter() {
+            self.sync_market_mirror(&money_market);
+        }
+    }
+
     /// Checks whether the specified money market contains a rewards batch for a given rewards token.
     ///
     /// # Arguments:
@@ -144,6 +210,69 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         false
     }
 
+    /// Checks whether rewards distribution has been paused at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market.
+    ///
+    #[view(isRewardsDistributionPaused)]
+    fn is_rewards_distribution_paused(&self, money_market: &ManagedAddress) -> bool {
+        self.rewards_paused_markets().contains(money_market)
+    }
+
+    /// Registers the metadata of a rewards token if it has not been registered yet. The ticker is taken from the token
+    /// identifier, while the number of decimals of ESDT tokens is resolved asynchronously from the ESDT system smart contract
+    /// at the end of `setRewardsBatch`.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The rewards token identifier.
+    ///
+    fn try_register_rewards_token_metadata(&self, token_id: &EgldOrEsdtTokenIdentifier) {
+        let metadata_mapper = self.rewards_token_metadata(token_id);
+        if !metadata_mapper.is_empty() {
+            return;
+        }
+
+        let (ticker, decimals, decimals_resolved) = match token_id.as_esdt_option() {
+            None => (ManagedBuffer::from(EGLD_TICKER), EGLD_DECIMALS, true),
+            Some(esdt_id) => (esdt_id.ticker(), 0u8, false),
+        };
+
+        let metadata = RewardsTokenMetadata {
+            token_id: token_id.clone(),
+            ticker,
+            decimals,
+            decimals_resolved,
+            registered_at: self.blockchain().get_block_timestamp(),
+        };
+        metadata_mapper.set(&metadata);
+
+        self.rewards_token_registered_event(&metadata);
+    }
+
+    /// Binds a Meta-ESDT rewards token to a given nonce the first time it is used as rewards. Afterwards, or for fungible
+    /// rewards tokens, it only checks that the given nonce matches the stored one.
+    ///
+    /// # Notes:
+    ///
+    /// - Each rewards batch stores its own nonce, but accrued and undistributed rewards are tracked per token identifier, so
+    ///   all rewards batches of a given token must share the same nonce.
+    ///
+    fn try_register_rewards_token_nonce(&self, token_id: &EgldOrEsdtTokenIdentifier, nonce: u64) {
+        let nonce_mapper = self.rewards_token_nonce(token_id);
+        if self.rewards_tokens().contains(token_id) {
+            require!(nonce == nonce_mapper.get(), ERROR_INVALID_REWARDS_TOKEN_NONCE);
+            return;
+        }
+
+        if nonce > 0 {
+            nonce_mapper.set(nonce);
+            self.rewards_token_nonce_event(token_id, nonce);
+        }
+    }
+
     // Requires
 
     /// Requires that the given smart contract address is a whitelisted money market.
@@ -156,19 +285,42 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         require!(self.is_whitelisted_money_market(sc_address), ERROR_NON_WHITELISTED_MARKET);
     }
 
-    /// Requires that the caller is the admin or the pause guardian, if it is set.
+    /// Requires that the given money market is not a position market, which only supports entering and exiting the market
+    /// with meta-ESDT positions.
     ///
-    fn require_admin_or_guardian(&self) {
-        let admin = self.get_admin();
-        let caller = self.blockchain().get_caller();
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    fn require_not_position_market(&self, money_market: &ManagedAddress) {
+        require!(!self.position_market(money_market).get(), ERROR_UNSUPPORTED_BY_POSITION_MARKET);
+    }
 
-        match self.get_pause_guardian() {
-            None => {
-                require!(caller == admin, ERROR_ONLY_ADMIN);
-            },
-            Some(pause_guardian) => {
-                require!(caller == admin || caller == pause_guardian, ERROR_ONLY_ADMIN_OR_GUARDIAN);
-            },
+    /// Returns the money market used to enter markets with native EGLD, i.e. the EGLD money market or, if there is none, the
+    /// WEGLD money market. Position markets are never considered.
+    ///
+    fn get_egld_money_market(&self) -> ManagedAddress {
+        let opt_wegld_id = if self.wegld_id().is_empty() { None } else { Some(EgldOrEsdtTokenIdentifier::esdt(self.wegld_id().get())) };
+
+        let mut opt_wegld_market = None;
+        for money_market in self.whitelisted_markets().iter() {
+            if self.position_market(&money_market).get() {
+                continue;
+            }
+
+            let (underlying_id, _) = self.identifiers(&money_market).get();
+            if underlying_id.is_egld() {
+                return money_market;
+            }
+
+            if opt_wegld_id.as_ref() == Some(&underlying_id) {
+                opt_wegld_market = Some(money_market);
+            }
+        }
+
+        match opt_wegld_market {
+            Some(wegld_market) => wegld_market,
+            None => sc_panic!(ERROR_EGLD_MARKET_NOT_FOUND),
         }
     }
 
@@ -213,6 +365,139 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         self.whitelisted_markets().iter().collect()
     }
 
+    /// Gets the listing metadata of all whitelisted money markets that have it, as pairs of money market addresses and
+    /// metadata.
+    ///
+    #[view(getMarketsMetadata)]
+    fn get_markets_metadata(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, MarketMetadata<Self::Api>>> {
+        let mut markets_metadata = MultiValueEncoded::new();
+        for money_market in self.whitelisted_markets().iter() {
+            let metadata_mapper = self.market_metadata(&money_market);
+            if !metadata_mapper.is_empty() {
+                markets_metadata.push((money_market, metadata_mapper.get()).into());
+            }
+        }
+        markets_metadata
+    }
+
+    /// Verifies the wiring invariants of the controller and returns a structured report, such that post-upgrade smoke tests
+    /// can be run on-chain. The following invariants are checked:
+    ///
+    /// - The price oracle has been set.
+    /// - The maximum number of markets per account has been set.
+    /// - Every whitelisted money market points back to this controller.
+    /// - The identifiers of every whitelisted money market are stored, match the ones reported by the money market and map
+    ///   back to it.
+    ///
+    #[view(verifyDeployment)]
+    fn verify_deployment(&self) -> DeploymentReport<Self::Api> {
+        let controller = self.blockchain().get_sc_address();
+        let price_oracle_set = !self.price_oracle().is_empty();
+        let max_markets_per_account_set = !self.max_markets_per_account().is_empty() && self.max_markets_per_account().get() > 0;
+
+        let mut markets_checked = 0usize;
+        let mut markets_with_wrong_controller = ManagedVec::new();
+        let mut markets_with_inconsistent_identifiers = ManagedVec::new();
+        for money_market in self.whitelisted_markets().iter() {
+            markets_checked += 1;
+
+            let points_back = match self.get_controller(&money_market) {
+                None => false,
+                Some(address) => address == controller,
+            };
+            if !points_back {
+                markets_with_wrong_controller.push(money_market.clone());
+            }
+
+            let identifiers_mapper = self.identifiers(&money_market);
+            let consistent = if identifiers_mapper.is_empty() {
+                false
+            } else {
+                let (underlying_id, token_id) = identifiers_mapper.get();
+                let (mm_underlying_id, mm_token_id) = self.get_money_market_identifiers(&money_market);
+                let money_market_mapper = self.money_markets(&token_id);
+                underlying_id == mm_underlying_id && token_id == mm_token_id && !money_market_mapper.is_empty() && money_market_mapper.get() == money_market
+            };
+            if !consistent {
+                markets_with_inconsistent_identifiers.push(money_market);
+            }
+        }
+
+        let ok = price_oracle_set && max_markets_per_account_set && markets_with_wrong_controller.is_empty() && markets_with_inconsistent_identifiers.is_empty();
+
+        DeploymentReport {
+            ok,
+            price_oracle_set,
+            max_markets_per_account_set,
+            markets_checked,
+            markets_with_wrong_controller,
+            markets_with_inconsistent_identifiers,
+        }
+    }
+
+    /// Emits a compact snapshot of the protocol, i.e. the liquidity, total borrows and total reserves of every whitelisted
+    /// money market, such that lightweight indexers can track high level statistics without processing every user event.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone, typically a keeper, but at most once per heartbeat interval.
+    /// - Money market values are the ones stored up to their last interaction that accrued interest.
+    ///
+    #[endpoint(emitHeartbeat)]
+    fn emit_heartbeat(&self) {
+        let timestamp = self.blockchain().get_block_timestamp();
+        let last_heartbeat_timestamp_mapper = self.last_heartbeat_timestamp();
+        if !last_heartbeat_timestamp_mapper.is_empty() {
+            let next_heartbeat_timestamp = last_heartbeat_timestamp_mapper.get() + self.heartbeat_interval().get();
+            require!(timestamp >= next_heartbeat_timestamp, ERROR_HEARTBEAT_TOO_EARLY);
+        }
+        last_heartbeat_timestamp_mapper.set(timestamp);
+
+        let mut markets = 0usize;
+        for money_market in self.whitelisted_markets().iter() {
+            let liquidity = self.get_liquidity(&money_market);
+            let total_borrows = self.get_total_borrows(&money_market);
+            let total_reserves = self.get_total_reserves(&money_market);
+            self.market_heartbeat_event(&money_market, &liquidity, &total_borrows, &total_reserves);
+            markets += 1;
+        }
+
+        self.protocol_heartbeat_event(timestamp, markets);
+    }
+
+    /// Gets the set of addresses that belong to a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Restricted to registered readers if bulk enumeration views are restricted.
+    ///
+    #[view(getMarketMembers)]
+    fn get_market_members(&self, money_market: &ManagedAddress) -> MultiValueEncoded<ManagedAddress> {
+        self.require_enumeration_allowed();
+        let mut members = MultiValueEncoded::new();
+        for member in self.market_members(money_market).iter() {
+            members.push(member);
+        }
+        members
+    }
+
+    /// Requires the caller to be a registered reader, only if bulk enumeration views are restricted.
+    ///
+    /// # Notes:
+    ///
+    /// - Views can be queried off-chain with any caller, so this is a gate for integrators and not a privacy guarantee.
+    ///
+    fn require_enumeration_allowed(&self) {
+        if self.enumeration_restricted().get() {
+            let caller = self.blockchain().get_caller();
+            require!(self.registered_reader(&caller).get(), ERROR_UNREGISTERED_READER);
+        }
+    }
+
     /// Gets the the set of money markets addresses in which the account has entered as an array. An account is considered to
     /// be in the market if it has deposited collateral or took a borrow. Currently, after a borrow is fully repaid, the
     /// account is still considered to be in the market.
@@ -232,6 +517,30 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         }
     }
 
+    /// Applies a scheduled decrease of the maximum number of money markets that can be entered per account if its timelock
+    /// has elapsed and returns the up to date maximum.
+    ///
+    #[endpoint(updateAndGetMaxMarketsPerAccount)]
+    fn update_and_get_max_markets_per_account(&self) -> usize {
+        let max_markets_per_account = self.get_max_markets_per_account();
+
+        let next_max_markets_per_account_mapper = self.next_max_markets_per_account();
+        if next_max_markets_per_account_mapper.is_empty() {
+            return max_markets_per_account;
+        }
+
+        let (start_timestamp, next_max_markets_per_account) = next_max_markets_per_account_mapper.get();
+        if self.blockchain().get_block_timestamp() < start_timestamp {
+            return max_markets_per_account;
+        }
+
+        self.max_markets_per_account().set(next_max_markets_per_account);
+        next_max_markets_per_account_mapper.clear();
+        self.new_max_markets_per_account_event(max_markets_per_account, next_max_markets_per_account);
+
+        next_max_markets_per_account
+    }
+
     /// Returns all whitelisted money markets if the provided money markets are empty. Otherwise, it returns the provided
     /// money markets.
     ///
@@ -246,6 +555,24 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         money_markets
     }
 
+    /// Returns the provided accounts that consent to rewards claims initiated by third parties on their behalf, i.e. accounts
+    /// that have opted out are skipped unless they are the caller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `accounts` - The addresses to claim rewards for.
+    ///
+    fn filter_rewards_auto_claim_accounts(&self, accounts: ManagedVec<ManagedAddress>) -> ManagedVec<ManagedAddress> {
+        let caller = self.blockchain().get_caller();
+        let mut consenting_accounts = ManagedVec::new();
+        for account in accounts.iter() {
+            if *account == caller || !self.rewards_auto_claim_opt_out(&account).get() {
+                consenting_accounts.push(account.clone_value());
+            }
+        }
+        consenting_accounts
+    }
+
     /// Returns the next rewards batch ID for a given money market and updates it.
     ///
     /// # Arguments:
@@ -272,6 +599,13 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         BigUint::from(MAX_COLLATERAL_FACTOR)
     }
 
+    /// Gets the maximum account leverage implied by the maximum collateral factor, in wad.
+    ///
+    #[view(getMaxLeverage)]
+    fn get_max_leverage(&self) -> BigUint {
+        BigUint::from(MAX_LEVERAGE)
+    }
+
     /// Gets the amount of Hatom tokens deposited as collateral for a given money market and account.
     ///
     /// # Arguments:
@@ -304,6 +638,92 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         self.total_collateral_tokens(money_market).get()
     }
 
+    /// Gets the meta-ESDT positions deposited as collateral by a given account into a given position market, as pairs of
+    /// position nonces and amounts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the position market smart contract.
+    /// - `account` - The account we wish to analyze.
+    ///
+    #[view(getAccountPositions)]
+    fn get_account_collateral_positions(&self, money_market: &ManagedAddress, account: &ManagedAddress) -> MultiValueEncoded<MultiValue2<u64, BigUint>> {
+        let mut positions = MultiValueEncoded::new();
+        for (nonce, amount) in self.account_collateral_positions(money_market, account).iter() {
+            positions.push((nonce, amount).into());
+        }
+        positions
+    }
+
+    /// Adds a meta-ESDT position to the collateral positions of a given account at a given position market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the position market smart contract.
+    /// - `account` - The account depositing the position.
+    /// - `nonce` - The position nonce.
+    /// - `amount` - The position amount.
+    ///
+    fn add_collateral_position(&self, money_market: &ManagedAddress, account: &ManagedAddress, nonce: u64, amount: &BigUint) {
+        require!(nonce > 0, ERROR_INVALID_POSITION_NONCE);
+
+        let mut positions_mapper = self.account_collateral_positions(money_market, account);
+        let new_amount = match positions_mapper.get(&nonce) {
+            Some(old_amount) => old_amount + amount,
+            None => amount.clone(),
+        };
+        positions_mapper.insert(nonce, new_amount);
+
+        self.enter_market_position_event(money_market, account, nonce, amount);
+    }
+
+    /// Takes a given amount of collateral tokens from the collateral positions of a given account at a given position
+    /// market, and returns the taken positions.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the position market smart contract.
+    /// - `account` - The account whose positions are taken.
+    /// - `tokens` - The amount of collateral tokens to take.
+    ///
+    /// # Notes:
+    ///
+    /// - Positions are taken in the order they have been deposited, and the last one might be taken partially.
+    ///
+    fn take_collateral_positions(&self, money_market: &ManagedAddress, account: &ManagedAddress, tokens: &BigUint) -> ManagedVec<EsdtTokenPayment> {
+        let (_, token_id) = self.identifiers(money_market).get();
+        let mut positions_mapper = self.account_collateral_positions(money_market, account);
+
+        let mut positions = ManagedVec::new();
+        let mut tokens_left = tokens.clone();
+        let mut nonces: ManagedVec<u64> = ManagedVec::new();
+        for nonce in positions_mapper.keys() {
+            nonces.push(nonce);
+        }
+
+        for nonce in nonces.iter() {
+            if tokens_left == BigUint::zero() {
+                break;
+            }
+
+            let amount = positions_mapper.get(&nonce).unwrap_or_else(BigUint::zero);
+            let amount_taken = if amount > tokens_left {
+                positions_mapper.insert(nonce, &amount - &tokens_left);
+                tokens_left.clone()
+            } else {
+                positions_mapper.remove(&nonce);
+                amount
+            };
+
+            tokens_left -= &amount_taken;
+            positions.push(EsdtTokenPayment::new(token_id.clone(), nonce, amount_taken));
+        }
+
+        require!(tokens_left == BigUint::zero(), ERROR_INSUFFICIENT_POSITIONS);
+
+        positions
+    }
+
     /// Gets the up to date collateral factor for a specified money market.
     ///
     /// # Arguments:
@@ -393,17 +813,160 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         }
     }
 
-    /// Gets the address of the pause guardian, if one has been set.
+    /// Gets the current collateral cap for a given money market, if there is one.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    fn get_collateral_cap(&self, money_market: &ManagedAddress) -> Option<BigUint> {
+        let mapper = self.collateral_cap(money_market);
+        if mapper.is_empty() {
+            None
+        } else {
+            let collateral_cap = mapper.get();
+            Some(collateral_cap)
+        }
+    }
+
+    /// Gets the current maximum borrow amount per transaction for a given money market, if there is one.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    fn get_max_borrow_per_tx(&self, money_market: &ManagedAddress) -> Option<BigUint> {
+        let mapper = self.max_borrow_per_tx(money_market);
+        if mapper.is_empty() {
+            None
+        } else {
+            let max_borrow_per_tx = mapper.get();
+            Some(max_borrow_per_tx)
+        }
+    }
+
+    /// Gets the current borrow cap of a given account at a given money market, if there is one.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The address of the account.
+    ///
+    fn get_account_borrow_cap(&self, money_market: &ManagedAddress, account: &ManagedAddress) -> Option<BigUint> {
+        let mapper = self.account_borrow_cap(money_market, account);
+        if mapper.is_empty() {
+            None
+        } else {
+            let account_borrow_cap = mapper.get();
+            Some(account_borrow_cap)
+        }
+    }
+
+    /// Gets the remaining room below the liquidity cap for a given money market, i.e. the liquidity cap minus the current
+    /// liquidity, or `None` if the money market is uncapped.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - The headroom is zero if the liquidity cap has already been reached.
+    /// - Mints succeed only if the new liquidity remains strictly below the cap.
+    ///
+    #[view(getLiquidityCapHeadroom)]
+    fn get_liquidity_cap_headroom(&self, money_market: &ManagedAddress) -> Option<BigUint> {
+        self.require_whitelisted_money_market(money_market);
+        match self.get_liquidity_cap(money_market) {
+            None => None,
+            Some(cap) => {
+                let liquidity = self.get_liquidity(money_market);
+                if liquidity >= cap {
+                    Some(BigUint::zero())
+                } else {
+                    Some(cap - liquidity)
+                }
+            },
+        }
+    }
+
+    /// Gets the remaining room below the borrow cap for a given money market, i.e. the borrow cap minus the current total
+    /// borrows, or `None` if the money market is uncapped.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - The headroom is zero if the borrow cap has already been reached.
+    /// - Borrows succeed only if the new total borrows remain strictly below the cap.
     ///
-    fn get_pause_guardian(&self) -> Option<ManagedAddress> {
-        if self.pause_guardian().is_empty() {
+    #[view(getBorrowCapHeadroom)]
+    fn get_borrow_cap_headroom(&self, money_market: &ManagedAddress) -> Option<BigUint> {
+        self.require_whitelisted_money_market(money_market);
+        match self.get_borrow_cap(money_market) {
+            None => None,
+            Some(cap) => {
+                let total_borrows = self.get_total_borrows(money_market);
+                if total_borrows >= cap {
+                    Some(BigUint::zero())
+                } else {
+                    Some(cap - total_borrows)
+                }
+            },
+        }
+    }
+
+    /// Gets the current utilization cap for a given money market, if there is one.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    fn get_utilization_cap(&self, money_market: &ManagedAddress) -> Option<BigUint> {
+        let mapper = self.utilization_cap(money_market);
+        if mapper.is_empty() {
             None
         } else {
-            let pause_guardian = self.pause_guardian().get();
-            Some(pause_guardian)
+            let utilization_cap = mapper.get();
+            Some(utilization_cap)
         }
     }
 
+    /// Gets the current combined borrow cap for a given cap group, if there is one.
+    ///
+    /// # Arguments:
+    ///
+    /// - `group_id` - The cap group identifier.
+    ///
+    fn get_cap_group_borrow_cap(&self, group_id: usize) -> Option<BigUint> {
+        let mapper = self.cap_group_borrow_cap(group_id);
+        if mapper.is_empty() {
+            None
+        } else {
+            let borrow_cap = mapper.get();
+            Some(borrow_cap)
+        }
+    }
+
+    /// Gets the combined total borrows of all the money markets in a given cap group, in EGLD and in wad.
+    ///
+    /// # Arguments:
+    ///
+    /// - `group_id` - The cap group identifier.
+    ///
+    #[view(getCapGroupBorrows)]
+    fn get_cap_group_borrows(&self, group_id: usize) -> BigUint {
+        let mut group_borrows = BigUint::zero();
+        for money_market in self.cap_group_markets(group_id).iter() {
+            let total_borrows = self.get_total_borrows(&money_market);
+            let price = self.get_underlying_price(&money_market);
+            group_borrows += self.wad_mul(&total_borrows, &price);
+        }
+        group_borrows
+    }
+
     /// Gets the address of the rewards manager, if one has been set.
     ///
     fn get_rewards_manager(&self) -> Option<ManagedAddress> {
@@ -474,6 +1037,99 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         self.global_seize_status().get()
     }
 
+    /// Gets the reason behind the current minting status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `NotPaused` if mint is active.
+    /// - Pauses set before reasons were tracked are reported as admin pauses.
+    /// - Pauses at a money market flagged as deprecated are reported as deprecations, unless they are guardian pauses or
+    ///   oracle incidents.
+    ///
+    #[view(getMintPauseReason)]
+    fn get_mint_pause_reason(&self, money_market: &ManagedAddress) -> PauseReason {
+        let status = self.get_mint_status(money_market);
+        let deprecated = self.deprecated(money_market).get();
+        self.get_effective_pause_reason(status, self.mint_pause_reason(money_market).get(), deprecated)
+    }
+
+    /// Gets the reason behind the current borrowing status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `NotPaused` if borrow is active.
+    /// - Pauses set before reasons were tracked are reported as admin pauses.
+    /// - Pauses at a money market flagged as deprecated are reported as deprecations, unless they are guardian pauses or
+    ///   oracle incidents.
+    ///
+    #[view(getBorrowPauseReason)]
+    fn get_borrow_pause_reason(&self, money_market: &ManagedAddress) -> PauseReason {
+        let status = self.get_borrow_status(money_market);
+        let deprecated = self.deprecated(money_market).get();
+        self.get_effective_pause_reason(status, self.borrow_pause_reason(money_market).get(), deprecated)
+    }
+
+    /// Gets the reason behind the current seizing status at a given money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `NotPaused` if seize is active.
+    /// - Pauses set before reasons were tracked are reported as admin pauses.
+    /// - Pauses at a money market flagged as deprecated are reported as deprecations, unless they are guardian pauses or
+    ///   oracle incidents.
+    ///
+    #[view(getSeizePauseReason)]
+    fn get_seize_pause_reason(&self, money_market: &ManagedAddress) -> PauseReason {
+        let status = self.get_seize_status(money_market);
+        let deprecated = self.deprecated(money_market).get();
+        self.get_effective_pause_reason(status, self.seize_pause_reason(money_market).get(), deprecated)
+    }
+
+    /// Gets the reason behind the current global seizing status.
+    ///
+    /// # Notes:
+    ///
+    /// - Returns `NotPaused` if global seize is active.
+    /// - Pauses set before reasons were tracked are reported as admin pauses.
+    ///
+    #[view(getGlobalSeizePauseReason)]
+    fn get_global_seize_pause_reason(&self) -> PauseReason {
+        let status = self.get_global_seize_status();
+        self.get_effective_pause_reason(status, self.global_seize_pause_reason().get(), false)
+    }
+
+    /// Gets the effective reason behind a status, where guardian pauses and oracle incidents take priority over deprecations,
+    /// and any other pause at a money market flagged as deprecated is reported as a deprecation.
+    ///
+    fn get_effective_pause_reason(&self, status: Status, reason: PauseReason, deprecated: bool) -> PauseReason {
+        match (status, reason) {
+            (Status::Active, _) => PauseReason::NotPaused,
+            (Status::Paused, PauseReason::GuardianPause | PauseReason::OracleIncident) => reason,
+            (Status::Paused, _) if deprecated => PauseReason::Deprecation,
+            (Status::Paused, PauseReason::NotPaused) => PauseReason::AdminPause,
+            (Status::Paused, reason) => reason,
+        }
+    }
+
+    /// Checks whether interest accrual is currently frozen in all money markets.
+    ///
+    #[view(isInterestAccrualFrozen)]
+    fn is_interest_accrual_frozen(&self) -> bool {
+        self.blockchain().get_block_timestamp() < self.interest_frozen_until().get()
+    }
+
     /// Gets the accrued rewards for a given account's address and rewards token ID.
     ///
     /// # Arguments:
@@ -486,7 +1142,193 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         self.account_accrued_rewards(supplier, rewards_token_id).get()
     }
 
-    /// Gets the rewards index for a given money market, batch ID, and account.
+    /// Gets the accrued rewards for a given account's address for all the rewards tokens ever earned by the account.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The address of the account.
+    ///
+    /// # Notes:
+    ///
+    /// - Rewards tokens are tracked from the first distribution to the account after this view was introduced.
+    ///
+    #[view(getAccountAllAccruedRewards)]
+    fn get_account_all_accrued_rewards(&self, account: &ManagedAddress) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for rewards_token_id in self.account_rewards_tokens(account).iter() {
+            let rewards = self.account_accrued_rewards(account, &rewards_token_id).get();
+            result.push((rewards_token_id, rewards).into());
+        }
+        result
+    }
+
+    /// Gets a page of the accrued rewards for a given account's address, in the same order as `getAccountRewardsTokens`.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The address of the account.
+    /// - `from` - The zero based position of the first rewards token in the page.
+    /// - `size` - The maximum number of rewards tokens in the page.
+    ///
+    #[view(getAccountAccruedRewardsPage)]
+    fn get_account_accrued_rewards_page(&self, account: &ManagedAddress, from: usize, size: usize) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        let rewards_tokens = self.account_rewards_tokens(account);
+        let to = core::cmp::min(from.saturating_add(size), rewards_tokens.len());
+        for index in from..to {
+            let rewards_token_id = rewards_tokens.get_by_index(index + 1);
+            let rewards = self.account_accrued_rewards(account, &rewards_token_id).get();
+            result.push((rewards_token_id, rewards).into());
+        }
+        result
+    }
+
+    /// Returns the number of accounts that have entered any market since first interactions are recorded.
+    ///
+    #[view(getJoinedAccountsCount)]
+    fn get_joined_accounts_count(&self) -> usize {
+        self.joined_accounts().len()
+    }
+
+    /// Gets a page of the accounts that entered any market for the first time within a given time range, together with
+    /// their first interaction timestamp and in chronological order.
+    ///
+    /// # Arguments:
+    ///
+    /// - `start` - The start of the time range (inclusive).
+    /// - `end` - The end of the time range (exclusive).
+    /// - `from` - The zero based position of the first account in the page, relative to the first account in the range.
+    /// - `size` - The maximum number of accounts in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Accounts that entered a market before first interactions were recorded are not included.
+    /// - Restricted to registered readers if bulk enumeration views are restricted.
+    ///
+    #[view(getAccountsJoinedBetween)]
+    fn get_accounts_joined_between(&self, start: u64, end: u64, from: usize, size: usize) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        self.require_enumeration_allowed();
+
+        let mut result = MultiValueEncoded::new();
+        let joined_accounts = self.joined_accounts();
+        let len = joined_accounts.len();
+
+        // accounts are sorted by first interaction, so binary search the first account in the range
+        let mut lo = 0usize;
+        let mut hi = len;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let account = joined_accounts.get(mid + 1);
+            if self.account_first_interaction(&account).get() < start {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        let first = lo.saturating_add(from);
+        let to = core::cmp::min(first.saturating_add(size), len);
+        for index in first..to {
+            let account = joined_accounts.get(index + 1);
+            let timestamp = self.account_first_interaction(&account).get();
+            if timestamp >= end {
+                break;
+            }
+            result.push((account, timestamp).into());
+        }
+        result
+    }
+
+    /// Gets the price of the underlying of a given money market in EGLD and in wad, as reported by the price oracle.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    ///
+    #[endpoint(getUnderlyingPrice)]
+    fn get_money_market_underlying_price(&self, money_market: &ManagedAddress) -> BigUint {
+        self.require_whitelisted_money_market(money_market);
+        self.get_underlying_price(money_market)
+    }
+
+    /// Gets the aggregated supplied value, borrowed value and reserves across all whitelisted money markets, first expressed
+    /// in EGLD and then in USD, all of them in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - The supplied value of a money market is its liquidity, i.e. cash plus borrows minus reserves.
+    /// - Prices are retrieved from the price oracle, which is why this is an endpoint.
+    ///
+    #[endpoint(getProtocolTotals)]
+    fn get_protocol_totals(&self) -> ProtocolTotalsType<Self::Api> {
+        let mut total_supplied = BigUint::zero();
+        let mut total_borrowed = BigUint::zero();
+        let mut total_reserves = BigUint::zero();
+
+        for money_market in self.whitelisted_markets().iter() {
+            let underlying_price = self.get_underlying_price(&money_market);
+            total_supplied += self.wad_mul(&underlying_price, &self.get_liquidity(&money_market));
+            total_borrowed += self.wad_mul(&underlying_price, &self.get_total_borrows(&money_market));
+            total_reserves += self.wad_mul(&underlying_price, &self.get_total_reserves(&money_market));
+        }
+
+        let egld_price_in_usd = self.get_egld_price_in_usd();
+        let total_supplied_usd = self.wad_mul(&total_supplied, &egld_price_in_usd);
+        let total_borrowed_usd = self.wad_mul(&total_borrowed, &egld_price_in_usd);
+        let total_reserves_usd = self.wad_mul(&total_reserves, &egld_price_in_usd);
+
+        (total_supplied, total_borrowed, total_reserves, total_supplied_usd, total_borrowed_usd, total_reserves_usd).into()
+    }
+
+    /// Gets the rewards emission rate of all active rewards batches in USD per day (in wad), broken down by money market and
+    /// market type (supply or borrow).
+    ///
+    /// # Notes:
+    ///
+    /// - A rewards batch is active if it has already started and has not ended yet.
+    /// - Money markets and market types without active rewards batches are omitted.
+    /// - Prices are retrieved from the price oracle, which is why this is an endpoint.
+    ///
+    #[endpoint(getGlobalEmissionRate)]
+    fn get_global_emission_rate(&self) -> MultiValueEncoded<EmissionRateType<Self::Api>> {
+        let wad = BigUint::from(WAD);
+        let t = self.blockchain().get_block_timestamp();
+        let egld_price_in_usd = self.get_egld_price_in_usd();
+
+        let mut emission_rates = MultiValueEncoded::new();
+        for money_market in self.whitelisted_markets().iter() {
+            let mut supply_emission_rate = BigUint::zero();
+            let mut borrow_emission_rate = BigUint::zero();
+
+            for rewards_batch in self.rewards_batches(&money_market).iter() {
+                if rewards_batch.last_time > t || t >= rewards_batch.end_time {
+                    continue;
+                }
+
+                // speed is in wad, price is in EGLD
+                let token_price = self.get_rewards_token_price(&rewards_batch.token_id);
+                let emission_rate = rewards_batch.speed * SECONDS_PER_DAY * &token_price / &wad * &egld_price_in_usd / &wad / &wad;
+
+                match rewards_batch.market_type {
+                    MarketType::Supply => supply_emission_rate += emission_rate,
+                    MarketType::Borrow => borrow_emission_rate += emission_rate,
+                }
+            }
+
+            if supply_emission_rate > BigUint::zero() {
+                emission_rates.push((money_market.clone(), MarketType::Supply, supply_emission_rate).into());
+            }
+
+            if borrow_emission_rate > BigUint::zero() {
+                emission_rates.push((money_market, MarketType::Borrow, borrow_emission_rate).into());
+            }
+        }
+
+        emission_rates
+    }
+
+    /// Gets the rewards index for a given money market, batch ID, and account, in wad * wad * wad. A legacy account index
+    /// (in wad * wad) is migrated to the current precision and removed.
     ///
     /// # Arguments:
     ///
@@ -496,11 +1338,17 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
     ///
     fn get_account_batch_rewards_index(&self, money_market: &ManagedAddress, batch_id: &usize, account: &ManagedAddress) -> Option<BigUint> {
         let mapper = self.account_batch_rewards_index(money_market, batch_id, account);
-        if mapper.is_empty() {
+        if !mapper.is_empty() {
+            let account_index = mapper.get();
+            return Some(account_index);
+        }
+
+        let legacy_mapper = self.legacy_account_batch_rewards_index(money_market, batch_id, account);
+        if legacy_mapper.is_empty() {
             None
         } else {
-            let account_index = mapper.get();
-            Some(account_index)
+            let legacy_account_index = legacy_mapper.take();
+            Some(legacy_account_index * BigUint::from(WAD))
         }
     }
 
@@ -552,13 +1400,19 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
     ///
     /// - Requires that the new maximum number of markets per account is greater than the current maximum number of markets
     ///   per account.
+    /// - Clears any scheduled decrease of the maximum number of markets per account.
     ///
     fn set_max_markets_per_account_internal(&self, new_max_markets_per_account: usize) {
-        let old_max_markets_per_account = self.get_max_markets_per_account();
+        let old_max_markets_per_account = self.update_and_get_max_markets_per_account();
         require!(new_max_markets_per_account <= MAX_MARKETS_PER_ACCOUNT, ERROR_MAX_MARKETS_TOO_HIGH);
         require!(new_max_markets_per_account > old_max_markets_per_account, ERROR_MAX_MARKETS_TOO_LOW);
         self.max_markets_per_account().set(new_max_markets_per_account);
         self.new_max_markets_per_account_event(old_max_markets_per_account, new_max_markets_per_account);
+
+        if !self.next_max_markets_per_account().is_empty() {
+            self.next_max_markets_per_account().clear();
+            self.clear_next_max_markets_per_account_event();
+        }
     }
 
     // Market related methods
@@ -577,7 +1431,7 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         if account_markets_mapper.contains(money_market) {
             return;
         }
-        require!(account_markets_mapper.len() < self.get_max_markets_per_account(), ERROR_TOO_MANY_MARKETS);
+        require!(account_markets_mapper.len() < self.update_and_get_max_markets_per_account(), ERROR_TOO_MANY_MARKETS);
     }
 
     /// Handles internal logic for entering a market by updating the collateral and market information for a given account.
@@ -594,11 +1448,35 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
     /// - Updates the total amount of collateral tokens held by the given money market.
     /// - Adds the given money market to the list of assets deposited as collateral by the given account.
     /// - Adds the given account to the list of members for the given money market.
+    /// - Fails if the new total collateral exceeds the collateral cap of the given money market (if any).
     ///
     fn enter_market_internal(&self, money_market: &ManagedAddress, account: &ManagedAddress, tokens: &BigUint) {
         // check if the account is allowed to enter the market
         self.enter_market_allowed(money_market, account);
 
+        // check if the collateral cap (if any) has been reached, in underlying terms
+        if tokens > &BigUint::zero() {
+            if let Some(cap) = self.get_collateral_cap(money_market) {
+                let fx = self.get_stored_exchange_rate(money_market);
+                let new_total_collateral_tokens = self.total_collateral_tokens(money_market).get() + tokens;
+                let new_total_collateral = self.wad_mul(&new_total_collateral_tokens, &fx);
+                require!(new_total_collateral <= cap, ERROR_REACHED_COLLATERAL_CAP);
+            }
+        }
+
+        self.record_market_entry(money_market, account, tokens);
+    }
+
+    /// Records an account entering a market, i.e. updates the collateral and membership information for a given account
+    /// without any further checks.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The address of the money market smart contract.
+    /// - `account` - The account we wish to add to the market.
+    /// - `tokens` - The amount of collateral tokens to add to the market.
+    ///
+    fn record_market_entry(&self, money_market: &ManagedAddress, account: &ManagedAddress, tokens: &BigUint) {
         // update account collateral tokens
         let account_collateral_tokens_mapper = self.account_collateral_tokens(money_market, account);
         let old_tokens = account_collateral_tokens_mapper.get();
@@ -613,6 +1491,13 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         // we also track market members, i.e. accounts that belong to a given market
         self.market_members(money_market).insert(account.clone());
 
+        // record the first time the account enters any market
+        let first_interaction_mapper = self.account_first_interaction(account);
+        if first_interaction_mapper.is_empty() {
+            first_interaction_mapper.set(self.blockchain().get_block_timestamp());
+            self.joined_accounts().push(account);
+        }
+
         // notify observers there has been a change in this market
         self.notify_market_observers(money_market, account, &old_tokens);
 
@@ -676,6 +1561,11 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
 
         require!(self.money_markets(&token_payment.token_identifier).get() == caller, ERROR_ONLY_MONEY_MARKET_CAN_TRANSFER);
 
+        // the controller is the liquidator at batch liquidations, in which case the tokens are already held
+        if to == &self.blockchain().get_sc_address() {
+            return;
+        }
+
         self.send().direct_non_zero_esdt_payment(to, token_payment);
     }
 
@@ -709,14 +1599,160 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         let li = self.get_liquidation_incentive(collateral_market);
 
         let num = &li * &borrow_price; // [wad ^ 2]
-        let den = &collateral_price * &fx / &wad; // [wad]
-        let ratio = &num / &den; // [wad]
+        let den = self.wad_mul(&collateral_price, &fx); // [wad]
+        let ratio = self.div(num, &den, Rounding::Down); // [wad]
 
-        let seized_tokens = amount * &ratio / &wad;
+        let seized_tokens = self.wad_mul(amount, &ratio);
 
         seized_tokens
     }
 
+    /// Tracks the amount of governance tokens paid out by boosting a given rewards token, in total and per epoch.
+    ///
+    fn track_boosted_governance_tokens(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        let epoch = self.blockchain().get_block_epoch();
+        self.boosted_governance_tokens(token_id).update(|total| *total += amount);
+        self.boosted_governance_tokens_at(token_id, epoch).update(|total| *total += amount);
+    }
+
+    /// Tracks the amount of a given rewards token funded into rewards batches, in total and per epoch. New rewards batches
+    /// are also counted.
+    ///
+    fn track_rewards_batch_funding(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint, new_batch: bool) {
+        let epoch = self.blockchain().get_block_epoch();
+        if new_batch {
+            self.rewards_batches_funded_count(token_id).update(|count| *count += 1);
+        }
+        self.rewards_batches_funded_amount(token_id).update(|total| *total += amount);
+        self.rewards_batches_funded_amount_at(token_id, epoch).update(|total| *total += amount);
+    }
+
+    /// Returns the balance of a given rewards token and nonce held by the controller. For EGLD, it includes the WEGLD buffer
+    /// and excludes the liquidator bonds. For WEGLD, it includes the WEGLD buffer as well, given that WEGLD payouts can draw
+    /// from it.
+    ///
+    fn get_rewards_token_balance(&self, token_id: &EgldOrEsdtTokenIdentifier, nonce: u64) -> BigUint {
+        if token_id.is_egld() {
+            self.get_unbonded_egld_balance() + self.wegld_buffer().get()
+        } else if self.is_wegld(token_id) {
+            self.get_unbuffered_wegld_balance() + self.wegld_buffer().get()
+        } else {
+            self.blockchain().get_sc_balance(token_id, nonce)
+        }
+    }
+
+    /// Checks whether a given token identifier is the WEGLD token identifier.
+    ///
+    fn is_wegld(&self, token_id: &EgldOrEsdtTokenIdentifier) -> bool {
+        !self.wegld_id().is_empty() && *token_id == EgldOrEsdtTokenIdentifier::esdt(self.wegld_id().get())
+    }
+
+    /// Makes a given amount of WEGLD available for swaps, taking it from the WEGLD buffer first and only wrapping the
+    /// remaining amount of EGLD.
+    ///
+    fn take_wegld(&self, amount: &BigUint) {
+        let buffer = self.wegld_buffer().get();
+        let from_buffer = core::cmp::min(&buffer, amount).clone();
+        if &from_buffer < amount {
+            self.wrap_egld(&(amount - &from_buffer));
+        }
+        self.wegld_buffer().set(buffer - from_buffer);
+    }
+
+    /// Makes sure a given amount of a rewards token can be paid out. For EGLD, WEGLD from the buffer is only unwrapped if
+    /// the EGLD balance is not enough. For WEGLD, the WEGLD buffer is only drawn if the WEGLD balance outside of it is not
+    /// enough.
+    ///
+    fn take_rewards_token(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        if token_id.is_egld() {
+            self.take_egld(amount);
+        } else if self.is_wegld(token_id) {
+            self.take_buffered_wegld(amount);
+        }
+    }
+
+    /// Sends a given amount of a rewards token and nonce, drawing from the WEGLD buffer if needed.
+    ///
+    fn send_rewards_token(&self, to: &ManagedAddress, token_id: &EgldOrEsdtTokenIdentifier, nonce: u64, amount: &BigUint) {
+        self.take_rewards_token(token_id, amount);
+        self.send().direct(to, token_id, nonce, amount);
+    }
+
+    /// Sends a given amount of a rewards token to a given account and returns the sent payment. WEGLD is unwrapped into
+    /// native EGLD if the account has opted in.
+    ///
+    fn send_account_rewards_token(&self, account: &ManagedAddress, token_id: &EgldOrEsdtTokenIdentifier, nonce: u64, amount: &BigUint) -> EgldOrEsdtTokenPayment {
+        self.take_rewards_token(token_id, amount);
+        let payment = self.try_unwrap_wegld_payment(account, EgldOrEsdtTokenPayment::new(token_id.clone(), nonce, amount.clone()));
+        self.send().direct(account, &payment.token_identifier, payment.token_nonce, &payment.amount);
+        payment
+    }
+
+    /// Unwraps a given WEGLD payment into native EGLD if the given account has opted in to receive native EGLD. Otherwise,
+    /// or if the payment is not made in WEGLD, the payment is returned as is.
+    ///
+    fn try_unwrap_wegld_payment(&self, account: &ManagedAddress, payment: EgldOrEsdtTokenPayment) -> EgldOrEsdtTokenPayment {
+        if !self.native_egld(account).get() || self.wegld_id().is_empty() || payment.amount == BigUint::zero() {
+            return payment;
+        }
+
+        let wegld_id = EgldOrEsdtTokenIdentifier::esdt(self.wegld_id().get());
+        if payment.token_identifier != wegld_id {
+            return payment;
+        }
+
+        self.unwrap_egld(&payment.amount);
+        EgldOrEsdtTokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), 0, payment.amount)
+    }
+
+    /// Makes sure a given amount of EGLD rewards is held as EGLD, unwrapping WEGLD from the buffer only if the EGLD balance
+    /// is not enough.
+    ///
+    fn take_egld(&self, amount: &BigUint) {
+        let egld_balance = self.get_unbonded_egld_balance();
+        if &egld_balance < amount {
+            let shortfall = amount - &egld_balance;
+            self.unwrap_egld(&shortfall);
+            self.wegld_buffer().update(|buffer| *buffer -= &shortfall);
+        }
+    }
+
+    /// Makes sure a given amount of WEGLD rewards is available, drawing the shortfall from the WEGLD buffer only if the WEGLD
+    /// balance outside of the buffer is not enough.
+    ///
+    fn take_buffered_wegld(&self, amount: &BigUint) {
+        let wegld_balance = self.get_unbuffered_wegld_balance();
+        if &wegld_balance < amount {
+            let shortfall = amount - &wegld_balance;
+            self.wegld_buffer().update(|buffer| *buffer -= &shortfall);
+        }
+    }
+
+    /// Returns the WEGLD balance held by the controller that does not belong to the WEGLD buffer.
+    ///
+    fn get_unbuffered_wegld_balance(&self) -> BigUint {
+        let wegld_id = EgldOrEsdtTokenIdentifier::esdt(self.wegld_id().get());
+        let wegld_balance = self.blockchain().get_sc_balance(&wegld_id, 0);
+        let buffer = self.wegld_buffer().get();
+        if wegld_balance > buffer {
+            wegld_balance - buffer
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    /// Returns the EGLD balance held by the controller that does not belong to liquidator bonds.
+    ///
+    fn get_unbonded_egld_balance(&self) -> BigUint {
+        let egld_balance = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::egld(), 0);
+        let total_bonds = self.total_liquidator_bonds().get();
+        if egld_balance > total_bonds {
+            egld_balance - total_bonds
+        } else {
+            BigUint::zero()
+        }
+    }
+
     /// Swaps a given amount of tokens using a given swap path and returns the amount of resulting tokens. The path can be
     /// traversed in forward or backward mode.
     ///
@@ -745,6 +1781,118 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
         token_out_post - token_out_prev
     }
 
+    /// Requires that the value received from a swap is not below the value given to it by more than the maximum slippage.
+    ///
+    /// # Arguments:
+    ///
+    /// - `value_in` - The oracle value of the swap input, in EGLD and in wad.
+    /// - `value_out` - The oracle value of the swap output, in EGLD and in wad.
+    ///
+    fn require_swap_within_max_slippage(&self, value_in: &BigUint, value_out: &BigUint) {
+        if value_out >= value_in {
+            return;
+        }
+
+        let max_slippage = self.max_slippage().get();
+        let max_slippage_value = self.wad_mul(value_in, &max_slippage);
+        require!(value_in - value_out <= max_slippage_value, ERROR_TOO_MUCH_SLIPPAGE);
+    }
+
+    /// Notifies pending market changes to the booster observer in a single batch, as supported by its batched notifications
+    /// interface (version 3).
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_max_changes` - An optional maximum number of market changes to notify. Defaults to
+    ///   `MAX_MARKET_CHANGES_PER_NOTIFICATION`.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - Market changes are notified in the same order they happened.
+    /// - Only bulk operations queue market changes, which are notified at their end up to the maximum per notification.
+    /// - Returns the number of notified market changes and the number of market changes still pending, such that callers
+    ///   know whether they need to notify again.
+    ///
+    #[endpoint(notifyPendingMarketChanges)]
+    fn notify_pending_market_changes(&self, opt_max_changes: OptionalValue<usize>) -> MultiValue2<usize, usize> {
+        let booster_observer = match self.get_booster_observer() {
+            Some(booster_observer) => booster_observer,
+            None => sc_panic!(ERROR_REWARDS_BOOSTER_UNSET),
+        };
+
+        let max_changes = match opt_max_changes {
+            OptionalValue::Some(max_changes) => core::cmp::min(max_changes, MAX_MARKET_CHANGES_PER_NOTIFICATION),
+            OptionalValue::None => MAX_MARKET_CHANGES_PER_NOTIFICATION,
+        };
+
+        self.notify_pending_market_changes_internal(&booster_observer, max_changes).into()
+    }
+
+    /// Notifies up to a given number of pending market changes to the booster observer. Returns the number of notified market
+    /// changes and the number of market changes still pending.
+    ///
+    /// # Arguments:
+    ///
+    /// - `booster_observer` - The booster observer address.
+    /// - `max_changes` - The maximum number of market changes to notify.
+    ///
+    fn notify_pending_market_changes_internal(&self, booster_observer: &ManagedAddress, max_changes: usize) -> (usize, usize) {
+        let mut pending_market_changes = self.pending_market_changes();
+        let mut market_changes = ManagedVec::new();
+        while market_changes.len() < max_changes {
+            match pending_market_changes.pop_front() {
+                Some(market_change) => market_changes.push(market_change),
+                None => break,
+            }
+        }
+
+        let count = market_changes.len();
+        let remaining = pending_market_changes.len();
+        if count > 0 {
+            self.on_market_changes_booster_v3(booster_observer, &market_changes);
+            self.market_changes_notified_event(booster_observer, count, remaining);
+        }
+
+        (count, remaining)
+    }
+
+    /// Starts batching market changes, such that a bulk operation notifies a booster observer that supports batched
+    /// notifications once instead of once per market change. Returns whether batching has been started by this call, i.e.
+    /// whether the caller is in charge of ending it.
+    ///
+    fn start_market_changes_batch(&self) -> bool {
+        let batching_mapper = self.market_changes_batching();
+        if batching_mapper.get() {
+            return false;
+        }
+
+        batching_mapper.set(true);
+
+        true
+    }
+
+    /// Ends batching market changes and notifies the queued ones to the booster observer, if it supports batched
+    /// notifications. Market changes beyond the maximum per notification are kept pending, as reported by the
+    /// `market_changes_notified_event`, and must be notified using `notifyPendingMarketChanges`.
+    ///
+    fn end_market_changes_batch(&self) {
+        self.market_changes_batching().clear();
+
+        if let Some(booster_observer) = self.get_booster_observer() {
+            if self.get_rewards_booster_version(&booster_observer) == 3 {
+                self.notify_pending_market_changes_internal(&booster_observer, MAX_MARKET_CHANGES_PER_NOTIFICATION);
+            }
+        }
+    }
+
+    /// Returns the number of market changes pending to be notified to the booster observer.
+    ///
+    #[view(getPendingMarketChangesCount)]
+    fn get_pending_market_changes_count(&self) -> usize {
+        self.pending_market_changes().len()
+    }
+
     /// Notifies market changes to all market observers.
     ///
     /// # Arguments
@@ -765,6 +1913,22 @@ pub trait SharedModule: admin::AdminModule + events::EventModule + proxies::Prox
                 2 => {
                     self.on_market_change_booster_v2(&booster_observer, money_market, account, &tokens, &prev_tokens);
                 },
+                3 => {
+                    let market_change = MarketChange {
+                        money_market: money_market.clone(),
+                        account: account.clone(),
+                        tokens: tokens.clone(),
+                        prev_tokens: prev_tokens.clone(),
+                    };
+                    if self.market_changes_batching().get() {
+                        // bulk operations queue their market changes and notify them at once when they end
+                        self.pending_market_changes().push_back(market_change);
+                    } else {
+                        let mut market_changes = ManagedVec::new();
+                        market_changes.push(market_change);
+                        self.on_market_changes_booster_v3(&booster_observer, &market_changes);
+                    }
+                },
                 _ => sc_panic!(ERROR_INVALID_BOOSTER_VERSION),
             }
         }
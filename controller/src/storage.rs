@@ -12,6 +12,37 @@ pub enum Status {
     Paused,
 }
 
+/// The reason behind a paused status. Admin and guardian pauses are temporary, whereas deprecation pauses are expected to be
+/// permanent since the money market is being wound down. Deprecation pauses are derived from the deprecated flag of the
+/// money market and cannot be set explicitly.
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub enum PauseReason {
+    NotPaused,
+    AdminPause,
+    GuardianPause,
+    OracleIncident,
+    Deprecation,
+}
+
+/// The Controller-derived values of a money market, as pushed to the money market whenever governance changes them.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MarketMirror<M>
+where
+    M: ManagedTypeApi,
+{
+    pub collateral_factor: BigUint<M>,
+    pub ush_borrower_collateral_factor: BigUint<M>,
+    pub next_collateral_factors: Option<(u64, BigUint<M>, BigUint<M>)>,
+    pub liquidity_cap: BigUint<M>,
+    pub borrow_cap: BigUint<M>,
+    pub mint_paused: bool,
+    pub borrow_paused: bool,
+    pub seize_paused: bool,
+    pub deprecated: bool,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
 pub enum State {
@@ -54,6 +85,19 @@ where
     pub end_time: u64,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RewardsTokenMetadata<M>
+where
+    M: ManagedTypeApi,
+{
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub ticker: ManagedBuffer<M>,
+    pub decimals: u8,
+    pub decimals_resolved: bool,
+    pub registered_at: u64,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
 pub struct RewardsBooster<M>
@@ -78,13 +122,117 @@ where
     pub output_token_id: TokenIdentifier<M>,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
+pub struct MarketChange<M>
+where
+    M: ManagedTypeApi,
+{
+    pub money_market: ManagedAddress<M>,
+    pub account: ManagedAddress<M>,
+    pub tokens: BigUint<M>,
+    pub prev_tokens: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
+pub struct RewardsIndexSnapshot<M>
+where
+    M: ManagedTypeApi,
+{
+    pub batch_id: usize,
+    pub market_type: MarketType,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub index: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct AccountMarketData<M>
+where
+    M: ManagedTypeApi,
+{
+    pub collateral_tokens: BigUint<M>,
+    pub fx: BigUint<M>,
+    pub borrow_amount: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct DeploymentReport<M>
+where
+    M: ManagedTypeApi,
+{
+    pub ok: bool,
+    pub price_oracle_set: bool,
+    pub max_markets_per_account_set: bool,
+    pub markets_checked: usize,
+    pub markets_with_wrong_controller: ManagedVec<M, ManagedAddress<M>>,
+    pub markets_with_inconsistent_identifiers: ManagedVec<M, ManagedAddress<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BoosterPathReport<M>
+where
+    M: ManagedTypeApi,
+{
+    pub ok: bool,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub amount_in: BigUint<M>,
+    pub expected_amount_out: BigUint<M>,
+    pub spot_amount_out: BigUint<M>,
+    pub price_impact: BigUint<M>,
+    pub within_max_slippage: bool,
+    pub broken_step: Option<usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MarketMetadata<M>
+where
+    M: ManagedTypeApi,
+{
+    pub display_symbol: ManagedBuffer<M>,
+    pub underlying_decimals: u8,
+    pub icon_uri_hash: ManagedBuffer<M>,
+    pub listed_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct LiquidationOutcome<M>
+where
+    M: ManagedTypeApi,
+{
+    pub borrower: ManagedAddress<M>,
+    pub borrow_market: ManagedAddress<M>,
+    pub collateral_market: ManagedAddress<M>,
+    pub liquidated: bool,
+    pub repayment: BigUint<M>,
+    pub seized_tokens: BigUint<M>,
+    pub reason: ManagedBuffer<M>,
+}
+
+/// The outcome of a liquidation check, i.e. whether the liquidation would succeed or the reason why it would fail.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum DryRunLiquidation {
+    Allowed,
+    ZeroAmount,
+    SelfLiquidation,
+    NonWhitelistedMarket,
+    GlobalSeizePaused,
+    SeizePaused,
+    LiquidationPriorityWindow,
+    RepaymentExceedsTotalBorrow,
+    BorrowerNotLiquidatable,
+    TooMuchRepayment,
+    TooMuchLiquidation,
+}
+
 #[multiversx_sc::module]
 pub trait StorageModule {
-    /// Stores the guardian address.
-    #[view(getPauseGuardian)]
-    #[storage_mapper("pause_guardian")]
-    fn pause_guardian(&self) -> SingleValueMapper<ManagedAddress>;
-
     /// Stores the rewards manager address.
     #[view(getRewardsManager)]
     #[storage_mapper("rewards_manager")]
@@ -104,26 +252,55 @@ pub trait StorageModule {
     #[storage_mapper("identifiers")]
     fn identifiers(&self, money_market: &ManagedAddress) -> SingleValueMapper<(EgldOrEsdtTokenIdentifier, TokenIdentifier)>;
 
+    /// Stores whether an initial seed deposit is required when supporting a new money market.
+    #[view(isSeedRequired)]
+    #[storage_mapper("seed_required")]
+    fn seed_required(&self) -> SingleValueMapper<bool>;
+
+    /// Stores the amount of Hatom tokens minted as initial seed for a given money market, which are locked forever in the
+    /// controller.
+    #[view(getMarketSeedTokens)]
+    #[storage_mapper("market_seed_tokens")]
+    fn market_seed_tokens(&self, money_market: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
     /// Stores the set of money markets addresses in which an account has entered, i.e. deposited collateral or took a
     /// borrow.
     #[storage_mapper("account_markets")]
     fn account_markets(&self, account: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
 
     /// Stores the set of addresses that belong to a given money market.
-    #[view(getMarketMembers)]
     #[storage_mapper("market_members")]
     fn market_members(&self, money_market: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
 
+    /// Stores the timestamp at which a given account entered any market for the first time.
+    #[view(getAccountFirstInteraction)]
+    #[storage_mapper("account_first_interaction")]
+    fn account_first_interaction(&self, account: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Stores the accounts in the order in which they entered any market for the first time.
+    #[storage_mapper("joined_accounts")]
+    fn joined_accounts(&self) -> VecMapper<ManagedAddress>;
+
     /// Stores the maximum amount of markets an account can enter at any given point in time.
     #[view(getMaxMarketsPerAccount)]
     #[storage_mapper("max_markets_per_account")]
     fn max_markets_per_account(&self) -> SingleValueMapper<usize>;
 
+    /// Stores the next maximum amount of markets an account can enter, together with the timestamp from which it applies.
+    #[view(getNextMaxMarketsPerAccount)]
+    #[storage_mapper("next_max_markets_per_account")]
+    fn next_max_markets_per_account(&self) -> SingleValueMapper<(u64, usize)>;
+
     /// Stores the price oracle smart contract address.
     #[view(getPriceOracle)]
     #[storage_mapper("price_oracle")]
     fn price_oracle(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Stores the price oracle smart contract address that overrides the global price oracle for a given money market.
+    #[view(getMarketPriceOracle)]
+    #[storage_mapper("market_price_oracle")]
+    fn market_price_oracle(&self, money_market: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
     /// Stores the collateral factor for each money market.
     #[view(getCollateralFactor)]
     #[storage_mapper("collateral_factor")]
@@ -143,10 +320,30 @@ pub trait StorageModule {
     #[storage_mapper("account_collateral_tokens")]
     fn account_collateral_tokens(&self, money_market: &ManagedAddress, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    /// Stores whether a given account has opted in to automatically enter markets with freshly minted Hatom tokens.
+    #[view(hasAutoEnterMarkets)]
+    #[storage_mapper("auto_enter_markets")]
+    fn auto_enter_markets(&self, account: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Stores whether a given account has opted in to receive native EGLD instead of WEGLD from redemptions and claims.
+    #[view(hasNativeEgld)]
+    #[storage_mapper("native_egld")]
+    fn native_egld(&self, account: &ManagedAddress) -> SingleValueMapper<bool>;
+
     /// Stores the total collateral amount deposited into a given money market.
     #[storage_mapper("total_collateral_tokens")]
     fn total_collateral_tokens(&self, money_market: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    /// Stores whether a given money market is a position market, i.e. a supply-only market whose collateral tokens are
+    /// meta-ESDT positions.
+    #[view(isPositionMarket)]
+    #[storage_mapper("position_market")]
+    fn position_market(&self, money_market: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Stores the amount deposited as collateral by a given account into a given position market, for each position nonce.
+    #[storage_mapper("account_collateral_positions")]
+    fn account_collateral_positions(&self, money_market: &ManagedAddress, account: &ManagedAddress) -> MapMapper<u64, BigUint>;
+
     /// A supported money market might have a liquidity cap, which is stored here.
     #[view(getLiquidityCap)]
     #[storage_mapper("liquidity_cap")]
@@ -157,6 +354,44 @@ pub trait StorageModule {
     #[storage_mapper("borrow_cap")]
     fn borrow_cap(&self, money_market: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    /// A supported money market might have a collateral cap, i.e. a maximum amount of underlying that can be deposited as
+    /// collateral, which is stored here.
+    #[view(getCollateralCap)]
+    #[storage_mapper("collateral_cap")]
+    fn collateral_cap(&self, money_market: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// A supported money market might have a maximum borrow size, i.e. a maximum amount of underlying that can be borrowed
+    /// in a single transaction, which is stored here.
+    #[view(getMaxBorrowPerTx)]
+    #[storage_mapper("max_borrow_per_tx")]
+    fn max_borrow_per_tx(&self, money_market: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// An account might have a borrow cap at a given money market, i.e. a maximum amount of underlying it can owe, which is
+    /// stored here.
+    #[view(getAccountBorrowCap)]
+    #[storage_mapper("account_borrow_cap")]
+    fn account_borrow_cap(&self, money_market: &ManagedAddress, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// A supported money market might have a utilization cap in wad, beyond which new borrows are rejected.
+    #[view(getUtilizationCap)]
+    #[storage_mapper("utilization_cap")]
+    fn utilization_cap(&self, money_market: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Stores the cap group a given money market belongs to, if any. Cap groups start at 1.
+    #[view(getMarketCapGroup)]
+    #[storage_mapper("market_cap_group")]
+    fn market_cap_group(&self, money_market: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    /// Stores the money markets that belong to a given cap group.
+    #[view(getCapGroupMarkets)]
+    #[storage_mapper("cap_group_markets")]
+    fn cap_group_markets(&self, group_id: usize) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// A cap group might have a combined borrow cap in EGLD and in wad, which is stored here.
+    #[view(getCapGroupBorrowCap)]
+    #[storage_mapper("cap_group_borrow_cap")]
+    fn cap_group_borrow_cap(&self, group_id: usize) -> SingleValueMapper<BigUint>;
+
     /// Stores the mint status.
     #[storage_mapper("mint_status")]
     fn mint_status(&self, money_market: &ManagedAddress) -> SingleValueMapper<Status>;
@@ -165,6 +400,11 @@ pub trait StorageModule {
     #[storage_mapper("borrow_status")]
     fn borrow_status(&self, money_market: &ManagedAddress) -> SingleValueMapper<Status>;
 
+    /// Stores whether a money market has been explicitly flagged as deprecated by governance.
+    #[view(getDeprecatedFlag)]
+    #[storage_mapper("deprecated")]
+    fn deprecated(&self, money_market: &ManagedAddress) -> SingleValueMapper<bool>;
+
     /// Stores the seize status.
     #[storage_mapper("seize_status")]
     fn seize_status(&self, money_market: &ManagedAddress) -> SingleValueMapper<Status>;
@@ -173,15 +413,91 @@ pub trait StorageModule {
     #[storage_mapper("global_seize_status")]
     fn global_seize_status(&self) -> SingleValueMapper<Status>;
 
+    /// Stores the reason behind the mint status.
+    #[storage_mapper("mint_pause_reason")]
+    fn mint_pause_reason(&self, money_market: &ManagedAddress) -> SingleValueMapper<PauseReason>;
+
+    /// Stores the reason behind the borrow status.
+    #[storage_mapper("borrow_pause_reason")]
+    fn borrow_pause_reason(&self, money_market: &ManagedAddress) -> SingleValueMapper<PauseReason>;
+
+    /// Stores the reason behind the seize status.
+    #[storage_mapper("seize_pause_reason")]
+    fn seize_pause_reason(&self, money_market: &ManagedAddress) -> SingleValueMapper<PauseReason>;
+
+    /// Stores the reason behind the global seize status.
+    #[storage_mapper("global_seize_pause_reason")]
+    fn global_seize_pause_reason(&self) -> SingleValueMapper<PauseReason>;
+
+    /// Stores the timestamp until which interest accrual is frozen in all money markets.
+    #[view(getInterestFrozenUntil)]
+    #[storage_mapper("interest_frozen_until")]
+    fn interest_frozen_until(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the latest timestamp at which any money market has accrued interest.
+    #[view(getLatestAccrualTimestamp)]
+    #[storage_mapper("latest_accrual_timestamp")]
+    fn latest_accrual_timestamp(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the latest timestamp at which any money market has found an abnormal accrual gap.
+    #[view(getLatestAccrualGapTimestamp)]
+    #[storage_mapper("latest_accrual_gap_timestamp")]
+    fn latest_accrual_gap_timestamp(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the set of money markets at which rewards distribution has been paused.
+    #[view(getRewardsPausedMarkets)]
+    #[storage_mapper("rewards_paused_markets")]
+    fn rewards_paused_markets(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Stores the amount of rewards of a given rewards token distributed to a given account at a given money market while
+    /// rewards distribution was paused, which are held until it is resumed.
+    #[view(getAccountHeldRewards)]
+    #[storage_mapper("account_held_rewards")]
+    fn account_held_rewards(&self, money_market: &ManagedAddress, account: &ManagedAddress, rewards_token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
     /// Stores the amount of rewards accrued by a given account for a given rewards token.
     #[storage_mapper("account_accrued_rewards")]
     fn account_accrued_rewards(&self, account: &ManagedAddress, rewards_token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
-    /// Stores the rewards index for a given account and rewards token in the specified money market.
+    /// Stores the set of rewards token identifiers ever earned by a given account.
+    #[view(getAccountRewardsTokens)]
+    #[storage_mapper("account_rewards_tokens")]
+    fn account_rewards_tokens(&self, account: &ManagedAddress) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    /// Stores whether a given account has opted out from rewards claims initiated by third parties on its behalf.
+    #[storage_mapper("rewards_auto_claim_opt_out")]
+    fn rewards_auto_claim_opt_out(&self, account: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Stores the rewards index for a given account and rewards batch in the specified money market, in wad * wad * wad.
     #[view(getAccountRewardsIndex)]
-    #[storage_mapper("account_rewards_index")]
+    #[storage_mapper("account_rewards_index_v2")]
     fn account_batch_rewards_index(&self, money_market: &ManagedAddress, batch_id: &usize, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
 
+    /// Stores the legacy rewards index for a given account and rewards batch in the specified money market, in wad * wad.
+    /// Legacy indexes are migrated lazily, the next time rewards are distributed to the account.
+    #[storage_mapper("account_rewards_index")]
+    fn legacy_account_batch_rewards_index(&self, money_market: &ManagedAddress, batch_id: &usize, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Stores whether the rewards batches indexes of a given money market have been migrated to the current precision.
+    #[view(areRewardsIndexesMigrated)]
+    #[storage_mapper("rewards_indexes_migrated")]
+    fn rewards_indexes_migrated(&self, money_market: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Stores the maximum amount of rewards a single account can earn from a given rewards batch, if any.
+    #[view(getRewardsBatchAccountCap)]
+    #[storage_mapper("rewards_batch_account_cap")]
+    fn rewards_batch_account_cap(&self, money_market: &ManagedAddress, batch_id: &usize) -> SingleValueMapper<BigUint>;
+
+    /// Stores the USD value per day in wad distributed by a given rewards batch, if it is denominated in USD.
+    #[view(getRewardsBatchUsdPerDay)]
+    #[storage_mapper("rewards_batch_usd_per_day")]
+    fn rewards_batch_usd_per_day(&self, money_market: &ManagedAddress, batch_id: &usize) -> SingleValueMapper<BigUint>;
+
+    /// Stores the amount of rewards earned by a given account from a capped rewards batch.
+    #[view(getAccountBatchRewards)]
+    #[storage_mapper("account_batch_rewards")]
+    fn account_batch_rewards(&self, money_market: &ManagedAddress, batch_id: &usize, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
     /// Stores the ID of the next rewards batch in the specified money market.
     #[view(getNextRewardsBatchId)]
     #[storage_mapper("next_rewards_batch_id")]
@@ -202,11 +518,71 @@ pub trait StorageModule {
     #[storage_mapper("rewards_batches")]
     fn rewards_batches(&self, money_market: &ManagedAddress) -> VecMapper<RewardsBatch<Self::Api>>;
 
+    /// Stores the ID of the last rewards snapshot taken, if any (zero otherwise).
+    #[view(getLastRewardsSnapshotId)]
+    #[storage_mapper("last_rewards_snapshot_id")]
+    fn last_rewards_snapshot_id(&self) -> SingleValueMapper<usize>;
+
+    /// Stores the timestamp at which a given rewards snapshot was taken.
+    #[view(getRewardsSnapshotTimestamp)]
+    #[storage_mapper("rewards_snapshot_timestamp")]
+    fn rewards_snapshot_timestamp(&self, snapshot_id: usize) -> SingleValueMapper<u64>;
+
+    /// Stores the precision of the rewards batches indexes at a given rewards snapshot. Snapshots taken before rewards
+    /// indexes were migrated have no precision stored.
+    #[storage_mapper("rewards_snapshot_precision")]
+    fn rewards_snapshot_precision(&self, snapshot_id: usize) -> SingleValueMapper<BigUint>;
+
+    /// Stores the rewards batches indexes of a given money market at a given rewards snapshot.
+    #[view(getRewardsSnapshot)]
+    #[storage_mapper("rewards_snapshot")]
+    fn rewards_snapshot(&self, snapshot_id: usize, money_market: &ManagedAddress) -> VecMapper<RewardsIndexSnapshot<Self::Api>>;
+
+    /// Stores the metadata of a given rewards token, registered when it is first used in a rewards batch.
+    #[view(getRewardsTokenMetadata)]
+    #[storage_mapper("rewards_token_metadata")]
+    fn rewards_token_metadata(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<RewardsTokenMetadata<Self::Api>>;
+
+    /// Stores the listing metadata of a given money market, used by frontends and aggregators.
+    #[view(getMarketMetadata)]
+    #[storage_mapper("market_metadata")]
+    fn market_metadata(&self, money_market: &ManagedAddress) -> SingleValueMapper<MarketMetadata<Self::Api>>;
+
+    /// Stores the set of tokens that have ever been used as rewards, which might still be owed to accounts.
+    #[view(getRewardsTokens)]
+    #[storage_mapper("rewards_tokens")]
+    fn rewards_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    /// Stores the nonce of a given Meta-ESDT rewards token. Fungible rewards tokens have nonce zero.
+    #[view(getRewardsTokenNonce)]
+    #[storage_mapper("rewards_token_nonce")]
+    fn rewards_token_nonce(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<u64>;
+
+    /// Stores the number of rewards batches ever funded with a given rewards token.
+    #[view(getRewardsBatchesFundedCount)]
+    #[storage_mapper("rewards_batches_funded_count")]
+    fn rewards_batches_funded_count(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<usize>;
+
+    /// Stores the total amount of a given rewards token ever funded into rewards batches.
+    #[view(getRewardsBatchesFundedAmount)]
+    #[storage_mapper("rewards_batches_funded_amount")]
+    fn rewards_batches_funded_amount(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Stores the amount of a given rewards token funded into rewards batches at a given epoch.
+    #[view(getRewardsBatchesFundedAmountAt)]
+    #[storage_mapper("rewards_batches_funded_amount_at")]
+    fn rewards_batches_funded_amount_at(&self, token_id: &EgldOrEsdtTokenIdentifier, epoch: u64) -> SingleValueMapper<BigUint>;
+
     /// Stores the undistributed rewards for a given rewards token identifier.
     #[view(getUndistributedRewards)]
     #[storage_mapper("undistributed_rewards")]
     fn undistributed_rewards(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
+    /// Stores the nonce of the rewards token of a given rewards batch, which is only non-zero for Meta-ESDT rewards tokens.
+    #[view(getRewardsBatchNonce)]
+    #[storage_mapper("rewards_batch_nonce")]
+    fn rewards_batch_nonce(&self, money_market: &ManagedAddress, batch_id: &usize) -> SingleValueMapper<u64>;
+
     /// Stores the current position of a rewards batch in the specified money market at the corresponding VecMapper.
     #[view(getRewardsBatchPosition)]
     #[storage_mapper("rewards_batch_position")]
@@ -217,6 +593,16 @@ pub trait StorageModule {
     #[storage_mapper("rewards_booster")]
     fn rewards_booster(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<RewardsBooster<Self::Api>>;
 
+    /// Stores the total amount of governance tokens ever paid out by boosting a given rewards token.
+    #[view(getBoostedGovernanceTokens)]
+    #[storage_mapper("boosted_governance_tokens")]
+    fn boosted_governance_tokens(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Stores the amount of governance tokens paid out by boosting a given rewards token at a given epoch.
+    #[view(getBoostedGovernanceTokensAt)]
+    #[storage_mapper("boosted_governance_tokens_at")]
+    fn boosted_governance_tokens_at(&self, token_id: &EgldOrEsdtTokenIdentifier, epoch: u64) -> SingleValueMapper<BigUint>;
+
     /// Stores wrapped EGLD smart contract address.
     #[view(getEgldWrapper)]
     #[storage_mapper("egld_wrapper")]
@@ -227,6 +613,11 @@ pub trait StorageModule {
     #[storage_mapper("wegld_id")]
     fn wegld_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
+    /// Stores the amount of WEGLD held by the controller on behalf of EGLD rewards, which is only unwrapped on payouts.
+    #[view(getWegldBuffer)]
+    #[storage_mapper("wegld_buffer")]
+    fn wegld_buffer(&self) -> SingleValueMapper<BigUint>;
+
     /// Stores the governance token identifier.
     #[view(getGovernanceTokenId)]
     #[storage_mapper("governance_token_id")]
@@ -237,6 +628,11 @@ pub trait StorageModule {
     #[storage_mapper("router")]
     fn router(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Stores the set of tokens that can be swapped into a borrow market underlying to repay a borrow.
+    #[view(getRepaySwapTokens)]
+    #[storage_mapper("repay_swap_tokens")]
+    fn repay_swap_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
     /// Stores the boosting state.
     #[view(getBoostingState)]
     #[storage_mapper("boosting_state")]
@@ -252,6 +648,20 @@ pub trait StorageModule {
     #[storage_mapper("booster_observer")]
     fn booster_observer(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Stores the market changes pending to be notified to a booster observer that supports batched notifications.
+    #[storage_mapper("pending_market_changes")]
+    fn pending_market_changes(&self) -> QueueMapper<Self::Api, MarketChange<Self::Api>>;
+
+    /// Stores whether market changes are being batched, i.e. queued instead of notified one by one to a booster observer
+    /// that supports batched notifications.
+    #[storage_mapper("market_changes_batching")]
+    fn market_changes_batching(&self) -> SingleValueMapper<bool>;
+
+    /// Stores the timestamp from which the given booster observer can be forcibly cleared, if scheduled.
+    #[view(getNextForceClearBoosterObserver)]
+    #[storage_mapper("next_force_clear_booster_observer")]
+    fn next_force_clear_booster_observer(&self) -> SingleValueMapper<(u64, ManagedAddress)>;
+
     /// Stores the USH Money Market observer.
     #[view(getUshMarketObserver)]
     #[storage_mapper("ush_market_observer")]
@@ -260,4 +670,60 @@ pub trait StorageModule {
     /// Stores historical observers smart contract addresses.
     #[storage_mapper("historical_observers")]
     fn historical_observers(&self, observer: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Stores the minimum amount of seconds between two consecutive protocol heartbeats.
+    #[view(getHeartbeatInterval)]
+    #[storage_mapper("heartbeat_interval")]
+    fn heartbeat_interval(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the timestamp of the last protocol heartbeat.
+    #[view(getLastHeartbeatTimestamp)]
+    #[storage_mapper("last_heartbeat_timestamp")]
+    fn last_heartbeat_timestamp(&self) -> SingleValueMapper<u64>;
+
+    /// Stores whether bulk enumeration views are restricted to registered readers.
+    #[view(isEnumerationRestricted)]
+    #[storage_mapper("enumeration_restricted")]
+    fn enumeration_restricted(&self) -> SingleValueMapper<bool>;
+
+    /// Stores whether a given smart contract is a registered reader of bulk enumeration views.
+    #[view(isRegisteredReader)]
+    #[storage_mapper("registered_reader")]
+    fn registered_reader(&self, reader: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Stores the amount of seconds after an account has been detected as liquidatable during which only bonded liquidators
+    /// can liquidate it.
+    #[view(getLiquidationPriorityWindow)]
+    #[storage_mapper("liquidation_priority_window")]
+    fn liquidation_priority_window(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the minimum EGLD bond required to become a bonded liquidator.
+    #[view(getMinLiquidatorBond)]
+    #[storage_mapper("min_liquidator_bond")]
+    fn min_liquidator_bond(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the EGLD bond of a given liquidator.
+    #[view(getLiquidatorBond)]
+    #[storage_mapper("liquidator_bond")]
+    fn liquidator_bond(&self, liquidator: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Stores the total EGLD bonded by liquidators, which is never available as EGLD rewards or for rescue.
+    #[view(getTotalLiquidatorBonds)]
+    #[storage_mapper("total_liquidator_bonds")]
+    fn total_liquidator_bonds(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the set of bonded liquidators.
+    #[view(getBondedLiquidators)]
+    #[storage_mapper("bonded_liquidators")]
+    fn bonded_liquidators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Stores the timestamp from which the bond of an unbonding liquidator can be withdrawn.
+    #[view(getLiquidatorUnbondingTimestamp)]
+    #[storage_mapper("liquidator_unbonding")]
+    fn liquidator_unbonding(&self, liquidator: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Stores the timestamp at which a given account has been first detected as liquidatable.
+    #[view(getLiquidatableSince)]
+    #[storage_mapper("liquidatable_since")]
+    fn liquidatable_since(&self, account: &ManagedAddress) -> SingleValueMapper<u64>;
 }
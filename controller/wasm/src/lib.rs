@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                          103
-// Async Callback (empty):               1
-// Total number of exported functions: 106
+// Endpoints:                          251
+// Async Callback:                       1
+// Total number of exported functions: 254
 
 #![no_std]
 
@@ -24,106 +24,254 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPendingAdmin => get_pending_admin
         setPendingAdmin => set_pending_admin
         acceptAdmin => accept_admin
+        pause => pause
+        unpause => unpause
+        setPauseGuardian => set_pause_guardian
+        getPauseGuardian => get_pause_guardian_view
+        getPauseGuardianSource => pause_guardian_source
+        isPaused => is_paused
         supportMarket => support_market
+        supportPositionMarket => support_position_market
+        setSeedRequired => set_seed_required
+        setMarketMetadata => set_market_metadata
+        clearMarketMetadata => clear_market_metadata
         setMaxMarketsPerAccount => set_max_markets_per_account
+        decreaseMaxMarketsPerAccount => decrease_max_markets_per_account
+        cancelMaxMarketsPerAccountDecrease => cancel_max_markets_per_account_decrease
         setCollateralFactors => set_collateral_factors
         setPriceOracle => set_price_oracle
+        setMarketPriceOracle => set_market_price_oracle
+        removeMarketPriceOracle => remove_market_price_oracle
         setLiquidityCap => set_liquidity_cap
         setBorrowCap => set_borrow_cap
+        setCollateralCap => set_collateral_cap
+        setMaxBorrowPerTx => set_max_borrow_per_tx
+        removeMaxBorrowPerTx => remove_max_borrow_per_tx
+        setAccountBorrowCap => set_account_borrow_cap
+        removeAccountBorrowCap => remove_account_borrow_cap
+        setUtilizationCap => set_utilization_cap
+        setMarketCapGroup => set_market_cap_group
+        setCapGroupBorrowCap => set_cap_group_borrow_cap
+        setDeprecated => set_deprecated
+        syncMarketMirrors => sync_market_mirrors
         setMaxRewardsBatches => set_max_rewards_batches
         setMaxSlippage => set_max_slippage
+        supportRepaySwapToken => support_repay_swap_token
+        unsupportRepaySwapToken => unsupport_repay_swap_token
         setRewardsBatch => set_rewards_batch
         addRewardsBatch => add_rewards_batch
         cancelRewardsBatch => cancel_rewards_batch
         removeRewardsBatch => remove_rewards_batch
         adminRemoveRewardsBatch => admin_remove_rewards_batch
         updateRewardsBatchSpeed => update_rewards_batch_speed
+        setRewardsBatchUsdPerDay => set_rewards_batch_usd_per_day
         updateRewardsBatchRemainingPeriod => update_rewards_batch_remaining_period
+        mergeRewardsBatches => merge_rewards_batches
         claimUndistributedRewards => claim_undistributed_rewards
+        rescueToken => rescue_token
         supportRewardsBatchBoosting => support_rewards_batch_boosting
         enableRewardsBatchBoosting => enable_rewards_batch_boosting
         disableRewardsBatchBoosting => disable_rewards_batch_boosting
         boostRewards => boost_rewards
         updateBooster => update_booster
+        updateBoosterSwapPath => update_booster_swap_path
         cancelBooster => cancel_booster
         setAccountTokens => set_account_collateral_tokens
+        seizePositions => seize_positions
         setRewardsManager => set_rewards_manager
-        setPauseGuardian => set_pause_guardian
         setBoosterObserver => set_booster_observer
         clearBoosterObserver => clear_booster_observer
+        scheduleForceClearBoosterObserver => schedule_force_clear_booster_observer
+        cancelForceClearBoosterObserver => cancel_force_clear_booster_observer
+        forceClearBoosterObserver => force_clear_booster_observer
         setUshMarketObserver => set_ush_market_observer
         clearUshMarketObserver => clear_ush_market_observer
+        setHeartbeatInterval => set_heartbeat_interval
+        setEnumerationRestricted => set_enumeration_restricted
+        registerReader => register_reader
+        unregisterReader => unregister_reader
         pauseMint => pause_mint
         pauseBorrow => pause_borrow
         pauseSeize => pause_seize
         pauseGlobalSeize => pause_global_seize
+        freezeInterestAccrual => freeze_interest_accrual
+        unfreezeInterestAccrual => unfreeze_interest_accrual
+        pauseRewardsDistribution => pause_rewards_distribution
+        resumeRewardsDistribution => resume_rewards_distribution
+        setLiquidationPriorityWindow => set_liquidation_priority_window
+        setMinLiquidatorBond => set_min_liquidator_bond
+        bondLiquidator => bond_liquidator
+        unbondLiquidator => unbond_liquidator
+        withdrawLiquidatorBond => withdraw_liquidator_bond
+        slashLiquidator => slash_liquidator
+        flagLiquidatableAccount => flag_liquidatable_account
+        isLiquidationOpen => is_liquidation_open
         enterMarkets => enter_markets
+        setAutoEnterMarkets => set_auto_enter_markets
+        setNativeEgld => set_native_egld
         exitMarket => exit_market
         exitMarketAndRedeem => exit_market_and_redeem
+        repayWithAnyToken => repay_with_any_token
+        liquidateAccounts => liquidate_accounts
+        claimRewardsAndCollateralize => claim_rewards_and_collateralize
+        registerBorrower => register_borrower
         removeAccountMarket => remove_account_market
+        pruneMarketMember => prune_market_member
         mintAllowed => mint_allowed
         redeemAllowed => redeem_allowed
         borrowAllowed => borrow_allowed
         repayBorrowAllowed => repay_borrow_allowed
+        recordAccrual => record_accrual
+        getMaxLiquidationRepayment => get_max_liquidation_repayment
         liquidateBorrowAllowed => liquidate_borrow_allowed
+        checkLiquidateBorrow => check_liquidate_borrow
         seizeAllowed => seize_allowed
         updateRewardsBatchesState => update_rewards_batches_state
+        repriceRewardsBatch => reprice_rewards_batch
+        takeRewardsSnapshot => take_rewards_snapshot
+        getRewardsSnapshotPrecision => get_rewards_snapshot_precision
+        migrateRewardsIndexes => migrate_rewards_indexes
         distributeRewards => distribute_rewards
+        setRewardsAutoClaimConsent => set_rewards_auto_claim_consent
+        hasRewardsAutoClaimConsent => has_rewards_auto_claim_consent
+        estimateClaimComplexity => estimate_claim_complexity
         claimRewards => claim_rewards
         claimRewardsTokens => claim_rewards_tokens
+        getRewardsTokensMetadata => get_rewards_tokens_metadata
+        verifyBoosterPath => verify_booster_path
         isRisky => is_risky
+        getAccountStatus => get_account_status
+        getAccountLeverage => get_account_leverage
+        getAccountHealth => get_account_health
         simulateRiskProfile => simulate_risk_profile
+        simulatePriceShock => simulate_price_shock
         isController => is_controller
         isWhitelistedMoneyMarket => is_whitelisted_money_market
         isWhitelistedTokenId => is_whitelisted_token_id
         isBoosterObserver => is_booster_observer
         isUshMarketObserver => is_ush_market_observer
         isDeprecated => is_deprecated
+        isRewardsDistributionPaused => is_rewards_distribution_paused
         getWhitelistedMarkets => get_whitelisted_markets
+        getMarketsMetadata => get_markets_metadata
+        verifyDeployment => verify_deployment
+        emitHeartbeat => emit_heartbeat
+        getMarketMembers => get_market_members
         getAccountMarkets => get_account_markets
+        updateAndGetMaxMarketsPerAccount => update_and_get_max_markets_per_account
         getMaxCollateralFactor => get_max_collateral_factor
+        getMaxLeverage => get_max_leverage
         getAccountTokens => get_account_collateral_tokens
         getTotalCollateralTokens => get_total_collateral_tokens
+        getAccountPositions => get_account_collateral_positions
         updateAndGetCollateralFactor => update_and_get_collateral_factor
         updateAndGetUshBorrowerCollateralFactor => update_and_get_ush_borrower_collateral_factor
         updateAndGetCollateralFactors => update_and_get_collateral_factors
+        getLiquidityCapHeadroom => get_liquidity_cap_headroom
+        getBorrowCapHeadroom => get_borrow_cap_headroom
+        getCapGroupBorrows => get_cap_group_borrows
         getMintStatus => get_mint_status
         getBorrowStatus => get_borrow_status
         getSeizeStatus => get_seize_status
         getGlobalSeizeStatus => get_global_seize_status
+        getMintPauseReason => get_mint_pause_reason
+        getBorrowPauseReason => get_borrow_pause_reason
+        getSeizePauseReason => get_seize_pause_reason
+        getGlobalSeizePauseReason => get_global_seize_pause_reason
+        isInterestAccrualFrozen => is_interest_accrual_frozen
         getAccountAccruedRewards => get_account_accrued_rewards
+        getAccountAllAccruedRewards => get_account_all_accrued_rewards
+        getAccountAccruedRewardsPage => get_account_accrued_rewards_page
+        getJoinedAccountsCount => get_joined_accounts_count
+        getAccountsJoinedBetween => get_accounts_joined_between
+        getUnderlyingPrice => get_money_market_underlying_price
+        getProtocolTotals => get_protocol_totals
+        getGlobalEmissionRate => get_global_emission_rate
         burnTokens => burn_tokens
         transferTokens => transfer_tokens
         tokensToSeize => tokens_to_seize
-        getPauseGuardian => pause_guardian
+        notifyPendingMarketChanges => notify_pending_market_changes
+        getPendingMarketChangesCount => get_pending_market_changes_count
         getRewardsManager => rewards_manager
         getMoneyMarketByTokenId => money_markets
         getIdentifiersByMoneyMarket => identifiers
-        getMarketMembers => market_members
+        isSeedRequired => seed_required
+        getMarketSeedTokens => market_seed_tokens
+        getAccountFirstInteraction => account_first_interaction
         getMaxMarketsPerAccount => max_markets_per_account
+        getNextMaxMarketsPerAccount => next_max_markets_per_account
         getPriceOracle => price_oracle
+        getMarketPriceOracle => market_price_oracle
         getCollateralFactor => collateral_factor
         getUshBorrowerCollateralFactor => ush_borrower_collateral_factor
         getNextCollateralFactor => next_collateral_factors
+        hasAutoEnterMarkets => auto_enter_markets
+        hasNativeEgld => native_egld
+        isPositionMarket => position_market
         getLiquidityCap => liquidity_cap
         getBorrowCap => borrow_cap
+        getCollateralCap => collateral_cap
+        getMaxBorrowPerTx => max_borrow_per_tx
+        getAccountBorrowCap => account_borrow_cap
+        getUtilizationCap => utilization_cap
+        getMarketCapGroup => market_cap_group
+        getCapGroupMarkets => cap_group_markets
+        getCapGroupBorrowCap => cap_group_borrow_cap
+        getDeprecatedFlag => deprecated
+        getInterestFrozenUntil => interest_frozen_until
+        getLatestAccrualTimestamp => latest_accrual_timestamp
+        getLatestAccrualGapTimestamp => latest_accrual_gap_timestamp
+        getRewardsPausedMarkets => rewards_paused_markets
+        getAccountHeldRewards => account_held_rewards
+        getAccountRewardsTokens => account_rewards_tokens
         getAccountRewardsIndex => account_batch_rewards_index
+        areRewardsIndexesMigrated => rewards_indexes_migrated
+        getRewardsBatchAccountCap => rewards_batch_account_cap
+        getRewardsBatchUsdPerDay => rewards_batch_usd_per_day
+        getAccountBatchRewards => account_batch_rewards
         getNextRewardsBatchId => next_rewards_batch_id
         getMaxRewardsBatchesPerMarket => max_rewards_batches
         getMaxSlippage => max_slippage
         getRewardsBatches => rewards_batches
+        getLastRewardsSnapshotId => last_rewards_snapshot_id
+        getRewardsSnapshotTimestamp => rewards_snapshot_timestamp
+        getRewardsSnapshot => rewards_snapshot
+        getRewardsTokenMetadata => rewards_token_metadata
+        getMarketMetadata => market_metadata
+        getRewardsTokens => rewards_tokens
+        getRewardsTokenNonce => rewards_token_nonce
+        getRewardsBatchesFundedCount => rewards_batches_funded_count
+        getRewardsBatchesFundedAmount => rewards_batches_funded_amount
+        getRewardsBatchesFundedAmountAt => rewards_batches_funded_amount_at
         getUndistributedRewards => undistributed_rewards
         getRewardsBatchPosition => rewards_batch_position
+        getRewardsBatchNonce => rewards_batch_nonce
         getRewardsBooster => rewards_booster
+        getBoostedGovernanceTokens => boosted_governance_tokens
+        getBoostedGovernanceTokensAt => boosted_governance_tokens_at
         getEgldWrapper => egld_wrapper
         getWegldId => wegld_id
+        getWegldBuffer => wegld_buffer
         getGovernanceTokenId => governance_token_id
         getRouter => router
+        getRepaySwapTokens => repay_swap_tokens
         getBoostingState => boosting_state
         isRewardsBatchBoostingSupported => rewards_batch_boosting_supported
         getBoosterObserver => booster_observer
+        getNextForceClearBoosterObserver => next_force_clear_booster_observer
         getUshMarketObserver => ush_market_observer
+        getHeartbeatInterval => heartbeat_interval
+        getLastHeartbeatTimestamp => last_heartbeat_timestamp
+        isEnumerationRestricted => enumeration_restricted
+        isRegisteredReader => registered_reader
+        getLiquidationPriorityWindow => liquidation_priority_window
+        getMinLiquidatorBond => min_liquidator_bond
+        getLiquidatorBond => liquidator_bond
+        getTotalLiquidatorBonds => total_liquidator_bonds
+        getBondedLiquidators => bonded_liquidators
+        getLiquidatorUnbondingTimestamp => liquidator_unbonding
+        getLiquidatableSince => liquidatable_since
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { controller }
@@ -72,6 +72,30 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         }
     }
 
+    /// Refreshes the cached exchange rate of a money market used when computing discounts with the `Cached` method.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - Address of the money market contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - The money market must have discount data.
+    /// - There is no cooldown, given that any update of the exchange rate refreshes the cached one as well.
+    ///
+    #[endpoint(refreshDiscountExchangeRate)]
+    fn refresh_discount_exchange_rate(&self, money_market: ManagedAddress) -> BigUint {
+        require!(self.has_discount_data(&money_market).get(), ERROR_DISCOUNT_DATA_UNSET);
+
+        let old_exchange_rate = self.last_exchange_rate(&money_market).get();
+        let new_exchange_rate = self.get_exchange_rate(&money_market, ExchangeRateType::Updated);
+
+        self.refresh_exchange_rate_event(&money_market, &old_exchange_rate, &new_exchange_rate);
+
+        new_exchange_rate
+    }
+
     /// Fetches the Controller smart contract address from the given USH Money Market smart contract and sets both the USH
     /// Money Market and Controller addresses into the storage. The USH Money Market is required when setting the discount
     /// rate model at the USH Money Market.
@@ -27,8 +27,10 @@ pub trait DiscountModule: admin::AdminModule + commons::CommonsModule + events::
         let controller = self.controller().get();
         let oracle = self.get_price_oracle();
 
+        let ush_money_market = self.ush_money_market().get();
+        let ush_oracle = self.get_market_price_oracle(&controller, &ush_money_market).unwrap_or_else(|| oracle.clone());
         let ush_token_id = EgldOrEsdtTokenIdentifier::esdt(self.ush_token_id().get());
-        let p_ush = self.get_underlying_price(&oracle, ush_token_id);
+        let p_ush = self.get_underlying_price(&ush_oracle, ush_token_id);
 
         for node in self.discounts_data_list().iter() {
             let discount_data = node.into_value();
@@ -41,7 +43,8 @@ pub trait DiscountModule: admin::AdminModule + commons::CommonsModule + events::
 
             // money market parameters
             let fx = self.get_exchange_rate(&money_market, fx_type);
-            let pi = self.get_underlying_price(&oracle, underlying_id);
+            let market_oracle = self.get_market_price_oracle(&controller, &money_market).unwrap_or_else(|| oracle.clone());
+            let pi = self.get_underlying_price(&market_oracle, underlying_id);
             let ltv = self.get_ush_borrower_collateral_factor(&controller, &money_market);
 
             // the amount subject to a discount
@@ -211,6 +211,31 @@ where
             .original_result()
     }
 
+    /// Refreshes the cached exchange rate of a money market used when computing discounts with the `Cached` method.
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - Address of the money market contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - The money market must have discount data.
+    /// - There is no cooldown, given that any update of the exchange rate refreshes the cached one as well.
+    ///
+    pub fn refresh_discount_exchange_rate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        money_market: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refreshDiscountExchangeRate")
+            .argument(&money_market)
+            .original_result()
+    }
+
     /// Gets the effective discount rate for the specified borrower and borrow amount in WAD units.
     ///
     /// # Arguments:
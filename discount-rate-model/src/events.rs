@@ -13,4 +13,8 @@ pub trait EventsModule {
     /// Event emitted when discount data is removed.
     #[event("remove_discount_data_event")]
     fn remove_discount_data_event(&self, #[indexed] money_market: &ManagedAddress);
+
+    /// Event emitted when the cached exchange rate of a money market is refreshed.
+    #[event("refresh_exchange_rate_event")]
+    fn refresh_exchange_rate_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] old_exchange_rate: &BigUint, #[indexed] new_exchange_rate: &BigUint);
 }
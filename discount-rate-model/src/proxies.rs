@@ -51,6 +51,17 @@ pub trait ProxyModule: storage::StorageModule {
         SingleValueMapper::new_from_address(controller.clone(), storage_key).get()
     }
 
+    fn get_market_price_oracle(&self, controller: &ManagedAddress, money_market: &ManagedAddress) -> Option<ManagedAddress> {
+        let mut storage_key = StorageKey::new(b"market_price_oracle");
+        storage_key.append_item(money_market);
+        let mapper = SingleValueMapper::new_from_address(controller.clone(), storage_key);
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
     // Oracle calls
 
     fn get_underlying_price(&self, oracle: &ManagedAddress, underlying_id: EgldOrEsdtTokenIdentifier) -> BigUint {
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           17
+// Endpoints:                           18
 // Async Callback (empty):               1
-// Total number of exported functions:  20
+// Total number of exported functions:  21
 
 #![no_std]
 
@@ -28,6 +28,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getDiscountsData => get_discounts_data
         getDiscountsCount => get_discounts_count
         getExchangeRate => get_exchange_rate
+        refreshDiscountExchangeRate => refresh_discount_exchange_rate
         getAccountDiscount => get_account_discount
         setDiscountData => set_discount_data
         removeDiscountData => remove_discount_data
@@ -0,0 +1,5 @@
+/// The WAD unit
+pub const WAD: u64 = 1_000_000_000_000_000_000;
+
+/// The maximum fixed borrow rate per second that can be offered (100% per year)
+pub const MAX_FIXED_RATE: u64 = 31_688_738_506;
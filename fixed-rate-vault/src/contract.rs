@@ -0,0 +1,60 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod fixed_rate_vault_proxy;
+
+pub mod constants;
+pub mod errors;
+pub mod events;
+pub mod governance;
+pub mod proxies;
+pub mod storage;
+pub mod vault;
+
+use crate::errors::*;
+
+#[multiversx_sc::contract]
+pub trait FixedRateVault: admin::AdminModule + events::EventsModule + governance::GovernanceModule + proxies::ProxyModule + storage::StorageModule + vault::VaultModule {
+    /// Initializes the Fixed Rate Vault, which sells fixed rate borrow capacity on top of a money market. The vault borrows
+    /// at a variable rate against its borrowers' collateral and hedges the rate difference with a reserve buffer.
+    ///
+    /// # Arguments:
+    ///
+    /// - `controller` - The Controller smart contract address.
+    /// - `collateral_market` - The money market whose Hatom tokens are accepted as collateral.
+    /// - `borrow_market` - The money market from which the vault borrows.
+    /// - `fixed_rate` - The fixed borrow rate per second in wad offered to new loans.
+    /// - `collateral_factor` - The collateral factor in wad applied to the borrowers' collateral.
+    /// - `opt_admin` - An optional admin address for the contract.
+    ///
+    /// Notes:
+    ///
+    /// - If the admin address is not provided, the admin will be set as the deployer.
+    /// - The vault collateral is held at the Controller on behalf of the vault and accounted per borrower.
+    ///
+    #[init]
+    fn init(&self, controller: ManagedAddress, collateral_market: ManagedAddress, borrow_market: ManagedAddress, fixed_rate: BigUint, collateral_factor: BigUint, opt_admin: OptionalValue<ManagedAddress>) {
+        require!(self.blockchain().is_smart_contract(&controller) && self.is_controller(&controller), ERROR_NON_VALID_CONTROLLER_SC);
+        require!(self.blockchain().is_smart_contract(&collateral_market) && self.is_money_market(&collateral_market), ERROR_NON_VALID_MONEY_MARKET_SC);
+        require!(self.blockchain().is_smart_contract(&borrow_market) && self.is_money_market(&borrow_market), ERROR_NON_VALID_MONEY_MARKET_SC);
+        require!(collateral_market != borrow_market, ERROR_MARKETS_MUST_DIFFER);
+
+        self.controller().set(&controller);
+
+        let (_, collateral_token_id) = self.get_money_market_identifiers(&collateral_market);
+        self.collateral_market().set(&collateral_market);
+        self.collateral_token_id().set(collateral_token_id);
+
+        let (underlying_id, _) = self.get_money_market_identifiers(&borrow_market);
+        self.borrow_market().set(&borrow_market);
+        self.underlying_id().set(underlying_id);
+
+        self.set_fixed_rate_internal(&fixed_rate);
+        self.set_collateral_factor_internal(&collateral_factor);
+        self.try_set_admin(opt_admin);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
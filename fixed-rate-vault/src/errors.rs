@@ -0,0 +1,22 @@
+pub const ERROR_NON_VALID_CONTROLLER_SC: &[u8] = b"not a valid controller smart contract";
+pub const ERROR_NON_VALID_MONEY_MARKET_SC: &[u8] = b"not a valid money market smart contract";
+pub const ERROR_MARKETS_MUST_DIFFER: &[u8] = b"collateral and borrow markets must be different";
+pub const ERROR_FIXED_RATE_TOO_HIGH: &[u8] = b"fixed rate too high";
+pub const ERROR_COLLATERAL_FACTOR_TOO_HIGH: &[u8] = b"collateral factor cannot exceed the Controller collateral factor";
+pub const ERROR_INVALID_MIN_BUFFER_RATIO: &[u8] = b"invalid minimum reserve buffer ratio";
+pub const ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO: &[u8] = b"amount has to be greater than zero";
+pub const ERROR_INVALID_COLLATERAL_PAYMENT: &[u8] = b"invalid collateral payment";
+pub const ERROR_INVALID_UNDERLYING_PAYMENT: &[u8] = b"invalid underlying payment";
+pub const ERROR_NOT_ENOUGH_COLLATERAL: &[u8] = b"not enough collateral";
+pub const ERROR_LOAN_ALREADY_OPEN: &[u8] = b"account already has an open fixed rate loan";
+pub const ERROR_LOAN_NOT_FOUND: &[u8] = b"fixed rate loan not found";
+pub const ERROR_REACHED_TOTAL_BORROW_CAP: &[u8] = b"total borrow cap reached";
+pub const ERROR_REACHED_ACCOUNT_BORROW_CAP: &[u8] = b"account borrow cap reached";
+pub const ERROR_INSUFFICIENT_RESERVE_BUFFER: &[u8] = b"insufficient reserve buffer";
+pub const ERROR_FIXED_RATE_BELOW_VARIABLE_RATE: &[u8] = b"fixed rate is below the variable borrow rate";
+pub const ERROR_INSUFFICIENT_REPAYMENT: &[u8] = b"repayment does not cover the fixed rate debt";
+pub const ERROR_LOAN_NOT_LIQUIDATABLE: &[u8] = b"fixed rate loan is not liquidatable";
+pub const ERROR_ADDRESSES_MUST_DIFFER: &[u8] = b"addresses must be different";
+pub const ERROR_VAULT_NOT_TRUSTED_MINTER: &[u8] = b"vault is not a trusted minter at the borrow market";
+pub const ERROR_INVALID_VAULT_BORROW_CAP: &[u8] = b"vault account borrow cap at the Controller is undefined or exceeded";
+pub const ERROR_SOCIALIZED_DEBT_OUTSTANDING: &[u8] = b"socialized debt must be repaid first";
@@ -0,0 +1,58 @@
+multiversx_sc::imports!();
+
+use crate::storage::FixedRateLoan;
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    /// Event emitted when the fixed rate is updated.
+    #[event("new_fixed_rate_event")]
+    fn new_fixed_rate_event(&self, #[indexed] old_fixed_rate: &BigUint, #[indexed] new_fixed_rate: &BigUint);
+
+    /// Event emitted when the vault collateral factor is updated.
+    #[event("new_collateral_factor_event")]
+    fn new_collateral_factor_event(&self, #[indexed] old_collateral_factor: &BigUint, #[indexed] new_collateral_factor: &BigUint);
+
+    /// Event emitted when the borrow caps are updated.
+    #[event("new_borrow_caps_event")]
+    fn new_borrow_caps_event(&self, #[indexed] total_borrow_cap: &BigUint, #[indexed] account_borrow_cap: &BigUint);
+
+    /// Event emitted when the minimum reserve buffer ratio is updated.
+    #[event("new_min_buffer_ratio_event")]
+    fn new_min_buffer_ratio_event(&self, #[indexed] old_ratio: &BigUint, #[indexed] new_ratio: &BigUint);
+
+    /// Event emitted when the reserve buffer is funded.
+    #[event("reserve_buffer_funded_event")]
+    fn reserve_buffer_funded_event(&self, #[indexed] funder: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] new_reserve_buffer: &BigUint);
+
+    /// Event emitted when funds are withdrawn from the reserve buffer.
+    #[event("reserve_buffer_withdrawn_event")]
+    fn reserve_buffer_withdrawn_event(&self, #[indexed] to: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] new_reserve_buffer: &BigUint);
+
+    /// Event emitted when an account deposits collateral.
+    #[event("deposit_collateral_event")]
+    fn deposit_collateral_event(&self, #[indexed] account: &ManagedAddress, #[indexed] tokens: &BigUint, #[indexed] new_account_tokens: &BigUint);
+
+    /// Event emitted when an account withdraws collateral.
+    #[event("withdraw_collateral_event")]
+    fn withdraw_collateral_event(&self, #[indexed] account: &ManagedAddress, #[indexed] tokens: &BigUint, #[indexed] new_account_tokens: &BigUint);
+
+    /// Event emitted when an account opens a fixed rate loan.
+    #[event("fixed_rate_borrow_event")]
+    fn fixed_rate_borrow_event(&self, #[indexed] account: &ManagedAddress, #[indexed] loan: &FixedRateLoan<Self::Api>);
+
+    /// Event emitted when a fixed rate loan is repaid, either fully or partially.
+    #[event("fixed_rate_repay_event")]
+    fn fixed_rate_repay_event(&self, #[indexed] payer: &ManagedAddress, #[indexed] account: &ManagedAddress, #[indexed] fixed_debt: &BigUint, #[indexed] variable_debt: &BigUint, #[indexed] new_reserve_buffer: &BigUint);
+
+    /// Event emitted when a fixed rate loan is liquidated.
+    #[event("fixed_rate_liquidation_event")]
+    fn fixed_rate_liquidation_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] account: &ManagedAddress, #[indexed] fixed_debt: &BigUint, #[indexed] seized_tokens: &BigUint);
+
+    /// Event emitted when variable debt not covered by the reserve buffer is socialized across the vault.
+    #[event("socialized_debt_event")]
+    fn socialized_debt_event(&self, #[indexed] account: &ManagedAddress, #[indexed] shortfall: &BigUint, #[indexed] socialized_debt: &BigUint);
+
+    /// Event emitted when socialized debt is repaid.
+    #[event("socialized_debt_repaid_event")]
+    fn socialized_debt_repaid_event(&self, #[indexed] payer: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] socialized_debt: &BigUint);
+}
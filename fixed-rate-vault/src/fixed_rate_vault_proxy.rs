@@ -0,0 +1,640 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct FixedRateVaultProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for FixedRateVaultProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = FixedRateVaultProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        FixedRateVaultProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct FixedRateVaultProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> FixedRateVaultProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Initializes the Fixed Rate Vault, which sells fixed rate borrow capacity on top of a money market. The vault borrows
+    /// at a variable rate against its borrowers' collateral and hedges the rate difference with a reserve buffer.
+    ///
+    /// # Arguments:
+    ///
+    /// - `controller` - The Controller smart contract address.
+    /// - `collateral_market` - The money market whose Hatom tokens are accepted as collateral.
+    /// - `borrow_market` - The money market from which the vault borrows.
+    /// - `fixed_rate` - The fixed borrow rate per second in wad offered to new loans.
+    /// - `collateral_factor` - The collateral factor in wad applied to the borrowers' collateral.
+    /// - `opt_admin` - An optional admin address for the contract.
+    ///
+    /// Notes:
+    ///
+    /// - If the admin address is not provided, the admin will be set as the deployer.
+    /// - The vault collateral is held at the Controller on behalf of the vault and accounted per borrower.
+    ///
+    pub fn init<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        controller: Arg0,
+        collateral_market: Arg1,
+        borrow_market: Arg2,
+        fixed_rate: Arg3,
+        collateral_factor: Arg4,
+        opt_admin: Arg5,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&controller)
+            .argument(&collateral_market)
+            .argument(&borrow_market)
+            .argument(&fixed_rate)
+            .argument(&collateral_factor)
+            .argument(&opt_admin)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> FixedRateVaultProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> FixedRateVaultProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Returns the current admin address.
+    ///
+    pub fn get_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdmin")
+            .original_result()
+    }
+
+    /// Returns the current pending admin address, if there is one.
+    ///
+    pub fn get_pending_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingAdmin")
+            .original_result()
+    }
+
+    /// Sets the pending admin address to the given address.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_pending_admin` - The new pending admin address.
+    ///
+    pub fn set_pending_admin<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pending_admin: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPendingAdmin")
+            .argument(&pending_admin)
+            .original_result()
+    }
+
+    /// Attempts to accept the pending admin, which must be set first using the `set_pending_admin` endpoint.
+    pub fn accept_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptAdmin")
+            .original_result()
+    }
+
+    /// Sets the fixed borrow rate offered to new loans.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_fixed_rate` - The new fixed borrow rate per second in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The fixed rate cannot exceed `MAX_FIXED_RATE`.
+    /// - Already opened loans keep the fixed rate they were opened with.
+    ///
+    pub fn set_fixed_rate<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_fixed_rate: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFixedRate")
+            .argument(&new_fixed_rate)
+            .original_result()
+    }
+
+    /// Sets the collateral factor applied by the vault to its borrowers' collateral.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_collateral_factor` - The new collateral factor in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The collateral factor cannot exceed the Controller collateral factor of the collateral market, such that the vault
+    ///   account at the Controller remains solvent as long as its borrowers are.
+    ///
+    pub fn set_collateral_factor<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_collateral_factor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCollateralFactor")
+            .argument(&new_collateral_factor)
+            .original_result()
+    }
+
+    /// Sets the explicit caps on the principal lent at a fixed rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `total_borrow_cap` - The maximum principal across all accounts. Zero means no cap.
+    /// - `account_borrow_cap` - The maximum principal of a single account. Zero means no cap.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The vault borrows at the Controller as any other account, such that governance is expected to bound it with an
+    ///   account borrow cap at the Controller as well.
+    ///
+    pub fn set_borrow_caps<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        total_borrow_cap: Arg0,
+        account_borrow_cap: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBorrowCaps")
+            .argument(&total_borrow_cap)
+            .argument(&account_borrow_cap)
+            .original_result()
+    }
+
+    /// Sets the minimum ratio between the reserve buffer and the total principal required to open new loans.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_ratio` - The new minimum reserve buffer ratio in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The ratio cannot exceed one.
+    ///
+    pub fn set_min_buffer_ratio<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_ratio: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinBufferRatio")
+            .argument(&new_ratio)
+            .original_result()
+    }
+
+    /// Adds funds to the reserve buffer. Socialized debt, if any, is repaid first.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - Must be paid with the underlying of the borrow market.
+    ///
+    pub fn fund_reserve_buffer(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundReserveBuffer")
+            .original_result()
+    }
+
+    /// Withdraws funds from the reserve buffer to the admin.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to withdraw.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The remaining reserve buffer must satisfy the minimum reserve buffer ratio.
+    /// - Cannot be called while there is socialized debt.
+    ///
+    pub fn withdraw_reserve_buffer<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawReserveBuffer")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Returns the current socialized debt, i.e. the variable debt that could not be covered by the reserve buffer when
+    /// settling loans, grown with the borrow index of the borrow market.
+    ///
+    pub fn get_socialized_debt(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSocializedDebt")
+            .original_result()
+    }
+
+    /// Stores the Controller address.
+    pub fn controller(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getController")
+            .original_result()
+    }
+
+    /// Stores the money market whose Hatom tokens are accepted as collateral.
+    pub fn collateral_market(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCollateralMarket")
+            .original_result()
+    }
+
+    /// Stores the Hatom token identifier of the collateral market.
+    pub fn collateral_token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCollateralTokenId")
+            .original_result()
+    }
+
+    /// Stores the money market from which the vault borrows at a variable rate.
+    pub fn borrow_market(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowMarket")
+            .original_result()
+    }
+
+    /// Stores the underlying identifier of the borrow market.
+    pub fn underlying_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnderlyingId")
+            .original_result()
+    }
+
+    /// Stores the fixed borrow rate per second in wad offered to new loans.
+    pub fn fixed_rate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFixedRate")
+            .original_result()
+    }
+
+    /// Stores the collateral factor in wad applied by the vault to its borrowers' collateral.
+    pub fn collateral_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCollateralFactor")
+            .original_result()
+    }
+
+    /// Stores the maximum amount of principal that can be lent at a fixed rate across all accounts, if non-zero.
+    pub fn total_borrow_cap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalBorrowCap")
+            .original_result()
+    }
+
+    /// Stores the maximum amount of principal that can be lent at a fixed rate to a single account, if non-zero.
+    pub fn account_borrow_cap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowCap")
+            .original_result()
+    }
+
+    /// Stores the minimum ratio in wad between the reserve buffer and the total principal required to open new loans.
+    pub fn min_buffer_ratio(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinBufferRatio")
+            .original_result()
+    }
+
+    /// Stores the reserve buffer, i.e. the amount of underlying that absorbs the difference between the fixed and the
+    /// variable debts.
+    pub fn reserve_buffer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReserveBuffer")
+            .original_result()
+    }
+
+    /// Stores the variable debt that could not be covered by the reserve buffer when settling loans, as of the borrow index
+    /// stored at `socialized_debt_borrow_index`. It remains owed by the vault and is backed by its aggregate collateral.
+    pub fn socialized_debt(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStoredSocializedDebt")
+            .original_result()
+    }
+
+    /// Stores the total principal of all open fixed rate loans.
+    pub fn total_principal(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalPrincipal")
+            .original_result()
+    }
+
+    /// Stores the amount of collateral tokens deposited by a given account.
+    pub fn account_collateral_tokens<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountCollateralTokens")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Stores the open fixed rate loan of a given account.
+    pub fn loans<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, FixedRateLoan<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLoan")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Deposits Hatom tokens of the collateral market as collateral. The tokens are deposited at the Controller on behalf of
+    /// the vault and accounted to the caller.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with Hatom tokens of the collateral market.
+    ///
+    pub fn deposit_collateral(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositCollateral")
+            .original_result()
+    }
+
+    /// Withdraws collateral deposited by the caller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `tokens` - The amount of Hatom tokens to withdraw.
+    ///
+    /// # Notes:
+    ///
+    /// - The remaining collateral must cover the caller's fixed rate loan, if any.
+    /// - Cannot be called while there is socialized debt, which is backed by the aggregate collateral of the vault.
+    ///
+    pub fn withdraw_collateral<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        tokens: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawCollateral")
+            .argument(&tokens)
+            .original_result()
+    }
+
+    /// Borrows underlying at the current fixed rate. The vault borrows the same amount at a variable rate from the borrow
+    /// market and the reserve buffer absorbs the difference between both debts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `underlying_amount` - The amount of underlying to borrow.
+    ///
+    /// # Notes:
+    ///
+    /// - An account can only have one open fixed rate loan.
+    /// - The loan must be covered by the caller's collateral, weighted by the vault collateral factor.
+    /// - The fixed rate must not be below the current variable borrow rate.
+    /// - The total and account borrow caps, if set, must not be exceeded.
+    /// - The reserve buffer must satisfy the minimum reserve buffer ratio including the new loan.
+    /// - The vault must be wired at the borrow market, i.e. whitelisted as a trusted minter and bounded by an explicit
+    ///   account borrow cap at the Controller that covers the total principal.
+    ///
+    pub fn borrow_fixed<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        underlying_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("borrowFixed")
+            .argument(&underlying_amount)
+            .original_result()
+    }
+
+    /// Repays the caller's fixed rate loan in full.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlying of the borrow market, covering at least the fixed rate debt.
+    /// - The remainder, if any, is returned.
+    /// - Repayments never fail because of the reserve buffer: a shortfall between the fixed and the variable debts that the
+    ///   reserve buffer cannot cover is socialized across the vault.
+    ///
+    pub fn repay_fixed(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("repayFixed")
+            .original_result()
+    }
+
+    /// Liquidates a fixed rate loan that is no longer covered by its collateral. The liquidator repays part of the fixed
+    /// rate debt and receives collateral of the borrower worth the repaid amount plus the liquidation incentive.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The borrower address.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlying of the borrow market.
+    /// - The repayment is capped by the close factor of the borrow market and the liquidation incentive of the collateral
+    ///   market is applied, such that the vault mirrors the Controller liquidation terms.
+    /// - The remainder, if any, is returned.
+    ///
+    pub fn liquidate_fixed<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("liquidateFixed")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Returns whether this contract is a trusted minter, which allows money markets to whitelist it.
+    ///
+    pub fn is_trusted_minter(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isTrustedMinter")
+            .original_result()
+    }
+
+    /// Returns the current fixed rate debt of a given account, i.e. its principal plus the simple interest accrued at its
+    /// fixed rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    pub fn get_account_fixed_debt<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFixedDebt")
+            .argument(&account)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct FixedRateLoan<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub principal: BigUint<Api>,
+    pub fixed_rate: BigUint<Api>,
+    pub start: u64,
+    pub borrow_index: BigUint<Api>,
+}
@@ -0,0 +1,216 @@
+multiversx_sc::imports!();
+
+use super::{constants::*, errors::*, events, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait GovernanceModule: admin::AdminModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Sets the fixed borrow rate offered to new loans.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_fixed_rate` - The new fixed borrow rate per second in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The fixed rate cannot exceed `MAX_FIXED_RATE`.
+    /// - Already opened loans keep the fixed rate they were opened with.
+    ///
+    #[endpoint(setFixedRate)]
+    fn set_fixed_rate(&self, new_fixed_rate: BigUint) {
+        self.require_admin();
+        self.set_fixed_rate_internal(&new_fixed_rate);
+    }
+
+    /// Sets the collateral factor applied by the vault to its borrowers' collateral.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_collateral_factor` - The new collateral factor in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The collateral factor cannot exceed the Controller collateral factor of the collateral market, such that the vault
+    ///   account at the Controller remains solvent as long as its borrowers are.
+    ///
+    #[endpoint(setCollateralFactor)]
+    fn set_collateral_factor(&self, new_collateral_factor: BigUint) {
+        self.require_admin();
+        self.set_collateral_factor_internal(&new_collateral_factor);
+    }
+
+    /// Sets the explicit caps on the principal lent at a fixed rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `total_borrow_cap` - The maximum principal across all accounts. Zero means no cap.
+    /// - `account_borrow_cap` - The maximum principal of a single account. Zero means no cap.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The vault borrows at the Controller as any other account, such that governance is expected to bound it with an
+    ///   account borrow cap at the Controller as well.
+    ///
+    #[endpoint(setBorrowCaps)]
+    fn set_borrow_caps(&self, total_borrow_cap: BigUint, account_borrow_cap: BigUint) {
+        self.require_admin();
+
+        self.total_borrow_cap().set(&total_borrow_cap);
+        self.account_borrow_cap().set(&account_borrow_cap);
+
+        self.new_borrow_caps_event(&total_borrow_cap, &account_borrow_cap);
+    }
+
+    /// Sets the minimum ratio between the reserve buffer and the total principal required to open new loans.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_ratio` - The new minimum reserve buffer ratio in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The ratio cannot exceed one.
+    ///
+    #[endpoint(setMinBufferRatio)]
+    fn set_min_buffer_ratio(&self, new_ratio: BigUint) {
+        self.require_admin();
+
+        require!(new_ratio <= BigUint::from(WAD), ERROR_INVALID_MIN_BUFFER_RATIO);
+
+        let old_ratio = self.min_buffer_ratio().get();
+        self.min_buffer_ratio().set(&new_ratio);
+
+        self.new_min_buffer_ratio_event(&old_ratio, &new_ratio);
+    }
+
+    /// Adds funds to the reserve buffer. Socialized debt, if any, is repaid first.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - Must be paid with the underlying of the borrow market.
+    ///
+    #[payable("*")]
+    #[endpoint(fundReserveBuffer)]
+    fn fund_reserve_buffer(&self) {
+        let (underlying_id, amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(underlying_id == self.underlying_id().get(), ERROR_INVALID_UNDERLYING_PAYMENT);
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let funder = self.blockchain().get_caller();
+
+        // socialized debt keeps accruing at the variable rate, so it is repaid before anything else
+        let borrow_market = self.borrow_market().get();
+        self.accrue_interest(&borrow_market);
+        let socialized_debt = self.get_socialized_debt();
+        let repaid_debt = BigUint::min(socialized_debt.clone(), amount.clone());
+        if repaid_debt > BigUint::zero() {
+            let new_socialized_debt = socialized_debt - &repaid_debt;
+            self.set_socialized_debt(&new_socialized_debt);
+
+            let underlying_payment = EgldOrEsdtTokenPayment::new(underlying_id, 0, repaid_debt.clone());
+            self.repay_borrow(&borrow_market, &underlying_payment);
+
+            self.socialized_debt_repaid_event(&funder, &repaid_debt, &new_socialized_debt);
+        }
+
+        let new_reserve_buffer = self.reserve_buffer().get() + &amount - &repaid_debt;
+        self.reserve_buffer().set(&new_reserve_buffer);
+
+        self.reserve_buffer_funded_event(&funder, &amount, &new_reserve_buffer);
+    }
+
+    /// Withdraws funds from the reserve buffer to the admin.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to withdraw.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The remaining reserve buffer must satisfy the minimum reserve buffer ratio.
+    /// - Cannot be called while there is socialized debt.
+    ///
+    #[endpoint(withdrawReserveBuffer)]
+    fn withdraw_reserve_buffer(&self, amount: BigUint) {
+        self.require_admin();
+
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(self.socialized_debt().get() == BigUint::zero(), ERROR_SOCIALIZED_DEBT_OUTSTANDING);
+
+        let reserve_buffer = self.reserve_buffer().get();
+        require!(amount <= reserve_buffer, ERROR_INSUFFICIENT_RESERVE_BUFFER);
+
+        let new_reserve_buffer = reserve_buffer - &amount;
+        self.require_min_buffer_ratio(&new_reserve_buffer, &self.total_principal().get());
+        self.reserve_buffer().set(&new_reserve_buffer);
+
+        let admin = self.get_admin();
+        let underlying_id = self.underlying_id().get();
+        self.send().direct(&admin, &underlying_id, 0, &amount);
+
+        self.reserve_buffer_withdrawn_event(&admin, &amount, &new_reserve_buffer);
+    }
+
+    /// Returns the current socialized debt, i.e. the variable debt that could not be covered by the reserve buffer when
+    /// settling loans, grown with the borrow index of the borrow market.
+    ///
+    #[view(getSocializedDebt)]
+    fn get_socialized_debt(&self) -> BigUint {
+        let socialized_debt = self.socialized_debt().get();
+        if socialized_debt == BigUint::zero() {
+            return socialized_debt;
+        }
+
+        let borrow_market = self.borrow_market().get();
+        socialized_debt * self.get_borrow_index(&borrow_market) / self.socialized_debt_borrow_index().get()
+    }
+
+    fn set_socialized_debt(&self, socialized_debt: &BigUint) {
+        let borrow_market = self.borrow_market().get();
+        self.socialized_debt().set(socialized_debt);
+        self.socialized_debt_borrow_index().set(self.get_borrow_index(&borrow_market));
+    }
+
+    /// Requires the vault to be wired at a given borrow market, i.e. whitelisted as a trusted minter and bounded by an
+    /// explicit account borrow cap at the Controller that covers a given total principal.
+    ///
+    fn require_wired(&self, borrow_market: &ManagedAddress, total_principal: &BigUint) {
+        let vault = self.blockchain().get_sc_address();
+        require!(self.is_whitelisted_trusted_minter(borrow_market, &vault), ERROR_VAULT_NOT_TRUSTED_MINTER);
+
+        let vault_borrow_cap = self.get_controller_account_borrow_cap(borrow_market, &vault);
+        require!(vault_borrow_cap > BigUint::zero() && total_principal <= &vault_borrow_cap, ERROR_INVALID_VAULT_BORROW_CAP);
+    }
+
+    fn set_fixed_rate_internal(&self, new_fixed_rate: &BigUint) {
+        require!(new_fixed_rate <= &BigUint::from(MAX_FIXED_RATE), ERROR_FIXED_RATE_TOO_HIGH);
+
+        let old_fixed_rate = self.fixed_rate().get();
+        self.fixed_rate().set(new_fixed_rate);
+
+        self.new_fixed_rate_event(&old_fixed_rate, new_fixed_rate);
+    }
+
+    fn set_collateral_factor_internal(&self, new_collateral_factor: &BigUint) {
+        let collateral_market = self.collateral_market().get();
+        let max_collateral_factor = self.get_controller_collateral_factor(&collateral_market);
+        require!(new_collateral_factor <= &max_collateral_factor, ERROR_COLLATERAL_FACTOR_TOO_HIGH);
+
+        let old_collateral_factor = self.collateral_factor().get();
+        self.collateral_factor().set(new_collateral_factor);
+
+        self.new_collateral_factor_event(&old_collateral_factor, new_collateral_factor);
+    }
+
+    /// Requires the reserve buffer to be at least the minimum reserve buffer ratio of the total principal.
+    ///
+    fn require_min_buffer_ratio(&self, reserve_buffer: &BigUint, total_principal: &BigUint) {
+        let wad = BigUint::from(WAD);
+        require!(reserve_buffer * &wad >= self.min_buffer_ratio().get() * total_principal, ERROR_INSUFFICIENT_RESERVE_BUFFER);
+    }
+}
@@ -0,0 +1,100 @@
+multiversx_sc::imports!();
+
+use super::storage;
+
+use controller::{market::ProxyTrait as _, shared::ProxyTrait as _, storage::ProxyTrait as _};
+use money_market::{borrow::ProxyTrait as _, common::ProxyTrait as _, repay_borrow::ProxyTrait as _};
+use multiversx_sc::storage::StorageKey;
+
+#[multiversx_sc::module]
+pub trait ProxyModule: storage::StorageModule {
+    // Controller calls
+
+    fn is_controller(&self, sc_address: &ManagedAddress) -> bool {
+        self.controller_proxy(sc_address.clone()).is_controller().execute_on_dest_context()
+    }
+
+    fn get_controller_collateral_factor(&self, money_market: &ManagedAddress) -> BigUint {
+        self.get_controller_proxy().collateral_factor(money_market).execute_on_dest_context()
+    }
+
+    fn get_controller_account_borrow_cap(&self, money_market: &ManagedAddress, account: &ManagedAddress) -> BigUint {
+        self.get_controller_proxy().account_borrow_cap(money_market, account).execute_on_dest_context()
+    }
+
+    fn get_underlying_price(&self, money_market: &ManagedAddress) -> BigUint {
+        self.get_controller_proxy().get_money_market_underlying_price(money_market).execute_on_dest_context()
+    }
+
+    fn enter_market(&self, token_payment: &EsdtTokenPayment) {
+        self.get_controller_proxy().enter_markets(OptionalValue::<ManagedAddress>::None).with_esdt_transfer(token_payment.clone()).execute_on_dest_context::<()>()
+    }
+
+    fn exit_market(&self, money_market: &ManagedAddress, tokens: &BigUint) -> EsdtTokenPayment {
+        self.get_controller_proxy().exit_market(money_market, OptionalValue::Some(tokens.clone())).execute_on_dest_context()
+    }
+
+    // Money Market calls
+
+    fn is_money_market(&self, sc_address: &ManagedAddress) -> bool {
+        self.money_market_proxy(sc_address.clone()).is_money_market().execute_on_dest_context()
+    }
+
+    fn get_money_market_identifiers(&self, sc_address: &ManagedAddress) -> (EgldOrEsdtTokenIdentifier, TokenIdentifier) {
+        self.money_market_proxy(sc_address.clone()).get_money_market_identifiers().execute_on_dest_context()
+    }
+
+    fn get_current_exchange_rate(&self, sc_address: &ManagedAddress) -> BigUint {
+        self.money_market_proxy(sc_address.clone()).get_current_exchange_rate().execute_on_dest_context()
+    }
+
+    fn accrue_interest(&self, sc_address: &ManagedAddress) {
+        self.money_market_proxy(sc_address.clone()).accrue_interest().execute_on_dest_context::<()>()
+    }
+
+    fn get_borrow_index(&self, sc_address: &ManagedAddress) -> BigUint {
+        self.money_market_proxy(sc_address.clone()).get_borrow_index().execute_on_dest_context()
+    }
+
+    fn get_close_factor(&self, sc_address: &ManagedAddress) -> BigUint {
+        self.money_market_proxy(sc_address.clone()).get_close_factor().execute_on_dest_context()
+    }
+
+    fn get_liquidation_incentive(&self, sc_address: &ManagedAddress) -> BigUint {
+        self.money_market_proxy(sc_address.clone()).get_liquidation_incentive().execute_on_dest_context()
+    }
+
+    fn is_whitelisted_trusted_minter(&self, sc_address: &ManagedAddress, trusted_minter: &ManagedAddress) -> bool {
+        let trusted_minters_list: WhitelistMapper<Self::Api, ManagedAddress, ManagedAddress> = WhitelistMapper::new_from_address(sc_address.clone(), StorageKey::new(b"trusted_minters_list"));
+        trusted_minters_list.contains(trusted_minter)
+    }
+
+    fn get_borrow_rate_per_second(&self, sc_address: &ManagedAddress) -> BigUint {
+        self.money_market_proxy(sc_address.clone()).borrow_rate_per_second().execute_on_dest_context()
+    }
+
+    fn get_current_account_borrow_amount(&self, sc_address: &ManagedAddress, account: &ManagedAddress) -> BigUint {
+        self.money_market_proxy(sc_address.clone()).current_account_borrow_amount(account).execute_on_dest_context()
+    }
+
+    fn borrow(&self, sc_address: &ManagedAddress, underlying_amount: &BigUint) -> EgldOrEsdtTokenPayment {
+        self.money_market_proxy(sc_address.clone()).borrow(underlying_amount).execute_on_dest_context()
+    }
+
+    fn repay_borrow(&self, sc_address: &ManagedAddress, underlying_payment: &EgldOrEsdtTokenPayment) -> EgldOrEsdtTokenPayment {
+        self.money_market_proxy(sc_address.clone()).repay_borrow(OptionalValue::<ManagedAddress>::None).with_egld_or_single_esdt_transfer(underlying_payment.clone()).execute_on_dest_context()
+    }
+
+    // Proxies
+
+    #[proxy]
+    fn controller_proxy(&self, sc_address: ManagedAddress) -> controller::ProxyTo<Self::Api>;
+
+    #[proxy]
+    fn money_market_proxy(&self, sc_address: ManagedAddress) -> money_market::ProxyTo<Self::Api>;
+
+    fn get_controller_proxy(&self) -> controller::ProxyTo<Self::Api> {
+        let controller_address = self.controller().get();
+        self.controller_proxy(controller_address)
+    }
+}
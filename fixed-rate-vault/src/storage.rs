@@ -0,0 +1,100 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Represents a fixed rate loan, i.e. an amount of underlying borrowed at a fixed rate per second with simple interest,
+/// together with the borrow index of the borrow market at opening used to track the variable debt that backs it.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct FixedRateLoan<M>
+where
+    M: ManagedTypeApi,
+{
+    pub principal: BigUint<M>,
+    pub fixed_rate: BigUint<M>,
+    pub start: u64,
+    pub borrow_index: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait StorageModule {
+    /// Stores the Controller address.
+    #[view(getController)]
+    #[storage_mapper("controller")]
+    fn controller(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the money market whose Hatom tokens are accepted as collateral.
+    #[view(getCollateralMarket)]
+    #[storage_mapper("collateral_market")]
+    fn collateral_market(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the Hatom token identifier of the collateral market.
+    #[view(getCollateralTokenId)]
+    #[storage_mapper("collateral_token_id")]
+    fn collateral_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Stores the money market from which the vault borrows at a variable rate.
+    #[view(getBorrowMarket)]
+    #[storage_mapper("borrow_market")]
+    fn borrow_market(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the underlying identifier of the borrow market.
+    #[view(getUnderlyingId)]
+    #[storage_mapper("underlying_id")]
+    fn underlying_id(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    /// Stores the fixed borrow rate per second in wad offered to new loans.
+    #[view(getFixedRate)]
+    #[storage_mapper("fixed_rate")]
+    fn fixed_rate(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the collateral factor in wad applied by the vault to its borrowers' collateral.
+    #[view(getCollateralFactor)]
+    #[storage_mapper("collateral_factor")]
+    fn collateral_factor(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the maximum amount of principal that can be lent at a fixed rate across all accounts, if non-zero.
+    #[view(getTotalBorrowCap)]
+    #[storage_mapper("total_borrow_cap")]
+    fn total_borrow_cap(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the maximum amount of principal that can be lent at a fixed rate to a single account, if non-zero.
+    #[view(getAccountBorrowCap)]
+    #[storage_mapper("account_borrow_cap")]
+    fn account_borrow_cap(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the minimum ratio in wad between the reserve buffer and the total principal required to open new loans.
+    #[view(getMinBufferRatio)]
+    #[storage_mapper("min_buffer_ratio")]
+    fn min_buffer_ratio(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the reserve buffer, i.e. the amount of underlying that absorbs the difference between the fixed and the
+    /// variable debts.
+    #[view(getReserveBuffer)]
+    #[storage_mapper("reserve_buffer")]
+    fn reserve_buffer(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the variable debt that could not be covered by the reserve buffer when settling loans, as of the borrow index
+    /// stored at `socialized_debt_borrow_index`. It remains owed by the vault and is backed by its aggregate collateral.
+    #[view(getStoredSocializedDebt)]
+    #[storage_mapper("socialized_debt")]
+    fn socialized_debt(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the borrow index of the borrow market at which the socialized debt was last updated.
+    #[storage_mapper("socialized_debt_borrow_index")]
+    fn socialized_debt_borrow_index(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the total principal of all open fixed rate loans.
+    #[view(getTotalPrincipal)]
+    #[storage_mapper("total_principal")]
+    fn total_principal(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the amount of collateral tokens deposited by a given account.
+    #[view(getAccountCollateralTokens)]
+    #[storage_mapper("account_collateral_tokens")]
+    fn account_collateral_tokens(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Stores the open fixed rate loan of a given account.
+    #[view(getLoan)]
+    #[storage_mapper("loans")]
+    fn loans(&self, account: &ManagedAddress) -> SingleValueMapper<FixedRateLoan<Self::Api>>;
+}
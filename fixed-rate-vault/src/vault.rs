@@ -0,0 +1,331 @@
+multiversx_sc::imports!();
+
+use super::{
+    constants::*,
+    errors::*,
+    events, governance, proxies,
+    storage::{self, FixedRateLoan},
+};
+
+#[multiversx_sc::module]
+pub trait VaultModule: admin::AdminModule + events::EventsModule + governance::GovernanceModule + proxies::ProxyModule + storage::StorageModule {
+    /// Deposits Hatom tokens of the collateral market as collateral. The tokens are deposited at the Controller on behalf of
+    /// the vault and accounted to the caller.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with Hatom tokens of the collateral market.
+    ///
+    #[payable("*")]
+    #[endpoint(depositCollateral)]
+    fn deposit_collateral(&self) {
+        let token_payment = self.call_value().single_esdt();
+        require!(token_payment.token_identifier == self.collateral_token_id().get(), ERROR_INVALID_COLLATERAL_PAYMENT);
+        require!(token_payment.amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        self.enter_market(&token_payment);
+
+        let account = self.blockchain().get_caller();
+        let new_account_tokens = self.account_collateral_tokens(&account).get() + &token_payment.amount;
+        self.account_collateral_tokens(&account).set(&new_account_tokens);
+
+        self.deposit_collateral_event(&account, &token_payment.amount, &new_account_tokens);
+    }
+
+    /// Withdraws collateral deposited by the caller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `tokens` - The amount of Hatom tokens to withdraw.
+    ///
+    /// # Notes:
+    ///
+    /// - The remaining collateral must cover the caller's fixed rate loan, if any.
+    /// - Cannot be called while there is socialized debt, which is backed by the aggregate collateral of the vault.
+    ///
+    #[endpoint(withdrawCollateral)]
+    fn withdraw_collateral(&self, tokens: BigUint) -> EsdtTokenPayment {
+        require!(tokens > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        // socialized debt is owed by the vault as a whole, so collateral cannot leave until it has been repaid
+        require!(self.socialized_debt().get() == BigUint::zero(), ERROR_SOCIALIZED_DEBT_OUTSTANDING);
+
+        let account = self.blockchain().get_caller();
+        let account_tokens = self.account_collateral_tokens(&account).get();
+        require!(tokens <= account_tokens, ERROR_NOT_ENOUGH_COLLATERAL);
+
+        let new_account_tokens = account_tokens - &tokens;
+        if !self.loans(&account).is_empty() {
+            let fixed_debt = self.get_fixed_debt(&self.loans(&account).get());
+            require!(self.is_healthy(&new_account_tokens, &fixed_debt), ERROR_NOT_ENOUGH_COLLATERAL);
+        }
+
+        self.account_collateral_tokens(&account).set(&new_account_tokens);
+
+        let collateral_market = self.collateral_market().get();
+        let token_payment = self.exit_market(&collateral_market, &tokens);
+        self.send().direct_esdt(&account, &token_payment.token_identifier, 0, &token_payment.amount);
+
+        self.withdraw_collateral_event(&account, &tokens, &new_account_tokens);
+
+        token_payment
+    }
+
+    /// Borrows underlying at the current fixed rate. The vault borrows the same amount at a variable rate from the borrow
+    /// market and the reserve buffer absorbs the difference between both debts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `underlying_amount` - The amount of underlying to borrow.
+    ///
+    /// # Notes:
+    ///
+    /// - An account can only have one open fixed rate loan.
+    /// - The loan must be covered by the caller's collateral, weighted by the vault collateral factor.
+    /// - The fixed rate must not be below the current variable borrow rate.
+    /// - The total and account borrow caps, if set, must not be exceeded.
+    /// - The reserve buffer must satisfy the minimum reserve buffer ratio including the new loan.
+    /// - The vault must be wired at the borrow market, i.e. whitelisted as a trusted minter and bounded by an explicit
+    ///   account borrow cap at the Controller that covers the total principal.
+    ///
+    #[endpoint(borrowFixed)]
+    fn borrow_fixed(&self, underlying_amount: BigUint) -> EgldOrEsdtTokenPayment {
+        require!(underlying_amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let account = self.blockchain().get_caller();
+        require!(self.loans(&account).is_empty(), ERROR_LOAN_ALREADY_OPEN);
+
+        let account_borrow_cap = self.account_borrow_cap().get();
+        require!(account_borrow_cap == BigUint::zero() || underlying_amount <= account_borrow_cap, ERROR_REACHED_ACCOUNT_BORROW_CAP);
+
+        let new_total_principal = self.total_principal().get() + &underlying_amount;
+        let total_borrow_cap = self.total_borrow_cap().get();
+        require!(total_borrow_cap == BigUint::zero() || new_total_principal <= total_borrow_cap, ERROR_REACHED_TOTAL_BORROW_CAP);
+
+        self.require_min_buffer_ratio(&self.reserve_buffer().get(), &new_total_principal);
+
+        let borrow_market = self.borrow_market().get();
+        self.require_wired(&borrow_market, &new_total_principal);
+
+        let account_tokens = self.account_collateral_tokens(&account).get();
+        require!(self.is_healthy(&account_tokens, &underlying_amount), ERROR_NOT_ENOUGH_COLLATERAL);
+
+        // selling below the variable rate would drain the reserve buffer from the very beginning
+        self.accrue_interest(&borrow_market);
+        let fixed_rate = self.fixed_rate().get();
+        require!(fixed_rate >= self.get_borrow_rate_per_second(&borrow_market), ERROR_FIXED_RATE_BELOW_VARIABLE_RATE);
+
+        let loan = FixedRateLoan {
+            principal: underlying_amount.clone(),
+            fixed_rate,
+            start: self.blockchain().get_block_timestamp(),
+            borrow_index: self.get_borrow_index(&borrow_market),
+        };
+
+        self.loans(&account).set(&loan);
+        self.total_principal().set(&new_total_principal);
+
+        let underlying_payment = self.borrow(&borrow_market, &underlying_amount);
+        self.send().direct(&account, &underlying_payment.token_identifier, 0, &underlying_payment.amount);
+
+        self.fixed_rate_borrow_event(&account, &loan);
+
+        underlying_payment
+    }
+
+    /// Repays the caller's fixed rate loan in full.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlying of the borrow market, covering at least the fixed rate debt.
+    /// - The remainder, if any, is returned.
+    /// - Repayments never fail because of the reserve buffer: a shortfall between the fixed and the variable debts that the
+    ///   reserve buffer cannot cover is socialized across the vault.
+    ///
+    #[payable("*")]
+    #[endpoint(repayFixed)]
+    fn repay_fixed(&self) -> EgldOrEsdtTokenPayment {
+        let (underlying_id, paid_amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(underlying_id == self.underlying_id().get(), ERROR_INVALID_UNDERLYING_PAYMENT);
+
+        let payer = self.blockchain().get_caller();
+        require!(!self.loans(&payer).is_empty(), ERROR_LOAN_NOT_FOUND);
+
+        let fixed_debt = self.get_fixed_debt(&self.loans(&payer).get());
+        require!(paid_amount >= fixed_debt, ERROR_INSUFFICIENT_REPAYMENT);
+
+        self.repay_fixed_internal(&payer, &payer, &fixed_debt);
+
+        let remainder = EgldOrEsdtTokenPayment::new(underlying_id, 0, paid_amount - fixed_debt);
+        self.send().direct_non_zero(&payer, &remainder.token_identifier, 0, &remainder.amount);
+        remainder
+    }
+
+    /// Liquidates a fixed rate loan that is no longer covered by its collateral. The liquidator repays part of the fixed
+    /// rate debt and receives collateral of the borrower worth the repaid amount plus the liquidation incentive.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The borrower address.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlying of the borrow market.
+    /// - The repayment is capped by the close factor of the borrow market and the liquidation incentive of the collateral
+    ///   market is applied, such that the vault mirrors the Controller liquidation terms.
+    /// - The remainder, if any, is returned.
+    ///
+    #[payable("*")]
+    #[endpoint(liquidateFixed)]
+    fn liquidate_fixed(&self, account: ManagedAddress) -> EsdtTokenPayment {
+        let (underlying_id, paid_amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(underlying_id == self.underlying_id().get(), ERROR_INVALID_UNDERLYING_PAYMENT);
+        require!(paid_amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let liquidator = self.blockchain().get_caller();
+        require!(liquidator != account, ERROR_ADDRESSES_MUST_DIFFER);
+        require!(!self.loans(&account).is_empty(), ERROR_LOAN_NOT_FOUND);
+
+        let account_tokens = self.account_collateral_tokens(&account).get();
+        let fixed_debt = self.get_fixed_debt(&self.loans(&account).get());
+        require!(!self.is_healthy(&account_tokens, &fixed_debt), ERROR_LOAN_NOT_LIQUIDATABLE);
+
+        let wad = BigUint::from(WAD);
+        let collateral_market = self.collateral_market().get();
+        let borrow_market = self.borrow_market().get();
+
+        // the repayment is bounded by the close factor
+        let max_repayment = self.get_close_factor(&borrow_market) * &fixed_debt / &wad;
+        let repayment = BigUint::min(paid_amount.clone(), max_repayment);
+        require!(repayment > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        // the seized collateral is worth the repayment plus the liquidation incentive, bounded by the borrower collateral
+        let repayment_value = self.get_underlying_price(&borrow_market) * &repayment / &wad;
+        let seize_value = self.get_liquidation_incentive(&collateral_market) * &repayment_value / &wad;
+        let token_price = self.get_current_exchange_rate(&collateral_market) * self.get_underlying_price(&collateral_market) / &wad;
+        let seize_tokens = BigUint::min(seize_value * &wad / token_price, account_tokens.clone());
+
+        self.repay_fixed_internal(&liquidator, &account, &repayment);
+
+        let new_account_tokens = account_tokens - &seize_tokens;
+        self.account_collateral_tokens(&account).set(&new_account_tokens);
+
+        let remainder = paid_amount - &repayment;
+        self.send().direct_non_zero(&liquidator, &underlying_id, 0, &remainder);
+
+        let token_payment = self.exit_market(&collateral_market, &seize_tokens);
+        self.send().direct_esdt(&liquidator, &token_payment.token_identifier, 0, &token_payment.amount);
+
+        self.fixed_rate_liquidation_event(&liquidator, &account, &repayment, &seize_tokens);
+
+        token_payment
+    }
+
+    /// Returns whether this contract is a trusted minter, which allows money markets to whitelist it.
+    ///
+    #[view(isTrustedMinter)]
+    fn is_trusted_minter(&self) -> bool {
+        true
+    }
+
+    /// Returns the current fixed rate debt of a given account, i.e. its principal plus the simple interest accrued at its
+    /// fixed rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    #[view(getFixedDebt)]
+    fn get_account_fixed_debt(&self, account: &ManagedAddress) -> BigUint {
+        if self.loans(account).is_empty() {
+            BigUint::zero()
+        } else {
+            self.get_fixed_debt(&self.loans(account).get())
+        }
+    }
+
+    /// Repays a given amount of the fixed rate debt of a given account, repaying the proportional backing variable debt at
+    /// the borrow market and settling the difference against the reserve buffer. Any shortfall the reserve buffer cannot
+    /// cover is socialized, such that repayments never fail.
+    ///
+    fn repay_fixed_internal(&self, payer: &ManagedAddress, account: &ManagedAddress, repayment: &BigUint) {
+        let mut loan = self.loans(account).get();
+        let fixed_debt = self.get_fixed_debt(&loan);
+
+        // the variable debt backing the loan grows with the borrow index, bounded by the vault debt because of truncations
+        let borrow_market = self.borrow_market().get();
+        let vault = self.blockchain().get_sc_address();
+        self.accrue_interest(&borrow_market);
+        let vault_debt = self.get_current_account_borrow_amount(&borrow_market, &vault);
+        let loan_variable_debt = BigUint::min(&loan.principal * &self.get_borrow_index(&borrow_market) / &loan.borrow_index, vault_debt);
+
+        // partial repayments settle the same share of the principal and of the variable debt
+        let (repaid_principal, variable_debt) = if repayment >= &fixed_debt {
+            (loan.principal.clone(), loan_variable_debt)
+        } else {
+            (&loan.principal * repayment / &fixed_debt, loan_variable_debt * repayment / &fixed_debt)
+        };
+
+        // the reserve buffer earns the spread or covers the shortfall as much as it can
+        let available = self.reserve_buffer().get() + repayment;
+        let repaid_variable_debt = BigUint::min(variable_debt.clone(), available.clone());
+        let new_reserve_buffer = available - &repaid_variable_debt;
+        self.reserve_buffer().set(&new_reserve_buffer);
+
+        let shortfall = variable_debt - &repaid_variable_debt;
+        if shortfall > BigUint::zero() {
+            let socialized_debt = self.get_socialized_debt() + &shortfall;
+            self.set_socialized_debt(&socialized_debt);
+            self.socialized_debt_event(account, &shortfall, &socialized_debt);
+        }
+
+        if repaid_principal == loan.principal {
+            self.loans(account).clear();
+        } else {
+            loan.principal -= &repaid_principal;
+            self.loans(account).set(&loan);
+        }
+        self.total_principal().update(|amount| *amount -= &repaid_principal);
+
+        if repaid_variable_debt > BigUint::zero() {
+            let underlying_id = self.underlying_id().get();
+            let underlying_payment = EgldOrEsdtTokenPayment::new(underlying_id, 0, repaid_variable_debt.clone());
+            self.repay_borrow(&borrow_market, &underlying_payment);
+        }
+
+        self.fixed_rate_repay_event(payer, account, repayment, &repaid_variable_debt, &new_reserve_buffer);
+    }
+
+    /// Computes the fixed rate debt of a loan, i.e. its principal plus the simple interest accrued at its fixed rate.
+    ///
+    fn get_fixed_debt(&self, loan: &FixedRateLoan<Self::Api>) -> BigUint {
+        let dt = self.blockchain().get_block_timestamp() - loan.start;
+        let interest = &loan.principal * &loan.fixed_rate * dt / BigUint::from(WAD);
+        &loan.principal + &interest
+    }
+
+    /// Checks whether an amount of collateral tokens, weighted by the vault collateral factor clamped to the Controller
+    /// collateral factor, covers a given debt. Both are valued in EGLD using the Controller price oracle.
+    ///
+    fn is_healthy(&self, collateral_tokens: &BigUint, debt: &BigUint) -> bool {
+        if debt == &BigUint::zero() {
+            return true;
+        }
+
+        let wad = BigUint::from(WAD);
+        let collateral_market = self.collateral_market().get();
+        let borrow_market = self.borrow_market().get();
+
+        // the vault never lends against more than the Controller does, even if the Controller factor has been lowered
+        let collateral_factor = BigUint::min(self.collateral_factor().get(), self.get_controller_collateral_factor(&collateral_market));
+
+        let fx = self.get_current_exchange_rate(&collateral_market);
+        let token_price = fx * self.get_underlying_price(&collateral_market) / &wad;
+        let token_price_eff = collateral_factor * &token_price / &wad;
+        let collateral = token_price_eff * collateral_tokens / &wad;
+
+        let borrow = self.get_underlying_price(&borrow_market) * debt / &wad;
+
+        collateral >= borrow
+    }
+}
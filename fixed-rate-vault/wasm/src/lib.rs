@@ -0,0 +1,59 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           33
+// Async Callback (empty):               1
+// Total number of exported functions:  36
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    fixed_rate_vault
+    (
+        init => init
+        upgrade => upgrade
+        getAdmin => get_admin
+        getPendingAdmin => get_pending_admin
+        setPendingAdmin => set_pending_admin
+        acceptAdmin => accept_admin
+        setFixedRate => set_fixed_rate
+        setCollateralFactor => set_collateral_factor
+        setBorrowCaps => set_borrow_caps
+        setMinBufferRatio => set_min_buffer_ratio
+        fundReserveBuffer => fund_reserve_buffer
+        withdrawReserveBuffer => withdraw_reserve_buffer
+        getSocializedDebt => get_socialized_debt
+        getController => controller
+        getCollateralMarket => collateral_market
+        getCollateralTokenId => collateral_token_id
+        getBorrowMarket => borrow_market
+        getUnderlyingId => underlying_id
+        getFixedRate => fixed_rate
+        getCollateralFactor => collateral_factor
+        getTotalBorrowCap => total_borrow_cap
+        getAccountBorrowCap => account_borrow_cap
+        getMinBufferRatio => min_buffer_ratio
+        getReserveBuffer => reserve_buffer
+        getStoredSocializedDebt => socialized_debt
+        getTotalPrincipal => total_principal
+        getAccountCollateralTokens => account_collateral_tokens
+        getLoan => loans
+        depositCollateral => deposit_collateral
+        withdrawCollateral => withdraw_collateral
+        borrowFixed => borrow_fixed
+        repayFixed => repay_fixed
+        liquidateFixed => liquidate_fixed
+        isTrustedMinter => is_trusted_minter
+        getFixedDebt => get_account_fixed_debt
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
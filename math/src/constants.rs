@@ -0,0 +1,5 @@
+/// The WAD unit
+pub const WAD: u64 = 1_000_000_000_000_000_000;
+
+/// The BPS unit
+pub const BPS: u64 = 10_000;
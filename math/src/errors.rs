@@ -0,0 +1 @@
+pub const ERROR_DIVISION_BY_ZERO: &[u8] = b"division by zero";
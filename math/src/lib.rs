@@ -0,0 +1,89 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod constants;
+pub mod errors;
+pub mod model;
+
+pub use model::Rounding;
+
+use constants::*;
+use errors::*;
+
+/// Fixed point math shared by all Hatom contracts, with an explicit rounding direction for every division. Amounts in wad
+/// are scaled by 1e18 and amounts in bps are scaled by 1e4.
+///
+#[multiversx_sc::module]
+pub trait MathModule {
+    /// Computes `x * y / z` using the given rounding direction.
+    ///
+    /// # Arguments:
+    ///
+    /// - `x` - The first factor.
+    /// - `y` - The second factor.
+    /// - `z` - The denominator, which cannot be zero.
+    /// - `rounding` - The rounding direction.
+    ///
+    fn mul_div(&self, x: &BigUint, y: &BigUint, z: &BigUint, rounding: Rounding) -> BigUint {
+        self.div(x * y, z, rounding)
+    }
+
+    /// Computes `num / den` using the given rounding direction.
+    ///
+    /// # Arguments:
+    ///
+    /// - `num` - The numerator.
+    /// - `den` - The denominator, which cannot be zero.
+    /// - `rounding` - The rounding direction.
+    ///
+    fn div(&self, num: BigUint, den: &BigUint, rounding: Rounding) -> BigUint {
+        require!(den > &BigUint::zero(), ERROR_DIVISION_BY_ZERO);
+        match rounding {
+            Rounding::Down => num / den,
+            Rounding::Up => (num + den - 1u64) / den,
+        }
+    }
+
+    /// Takes a numerator and denominator and returns the smallest integer greater than or equal to the quotient.
+    ///
+    fn ceil_div(&self, num: BigUint, den: &BigUint) -> BigUint {
+        self.div(num, den, Rounding::Up)
+    }
+
+    /// Multiplies an amount by a wad scaled factor, i.e. computes `x * y / wad`, rounding down.
+    ///
+    fn wad_mul(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        self.mul_div(x, y, &BigUint::from(WAD), Rounding::Down)
+    }
+
+    /// Multiplies an amount by a wad scaled factor, i.e. computes `x * y / wad`, rounding up.
+    ///
+    fn wad_mul_up(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        self.mul_div(x, y, &BigUint::from(WAD), Rounding::Up)
+    }
+
+    /// Divides an amount by a wad scaled factor, i.e. computes `x * wad / y`, rounding down.
+    ///
+    fn wad_div(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        self.mul_div(x, &BigUint::from(WAD), y, Rounding::Down)
+    }
+
+    /// Divides an amount by a wad scaled factor, i.e. computes `x * wad / y`, rounding up.
+    ///
+    fn wad_div_up(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        self.mul_div(x, &BigUint::from(WAD), y, Rounding::Up)
+    }
+
+    /// Multiplies an amount by a bps scaled factor, i.e. computes `x * y / bps`, using the given rounding direction.
+    ///
+    fn bps_mul(&self, x: &BigUint, y: &BigUint, rounding: Rounding) -> BigUint {
+        self.mul_div(x, y, &BigUint::from(BPS), rounding)
+    }
+
+    /// Converts a bps scaled factor into a wad scaled factor. The conversion is exact.
+    ///
+    fn bps_to_wad(&self, x: &BigUint) -> BigUint {
+        x * WAD / BPS
+    }
+}
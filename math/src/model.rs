@@ -0,0 +1,8 @@
+/// The rounding direction of a division.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Rounding {
+    /// Rounds towards zero, i.e. truncates.
+    Down,
+    /// Rounds away from zero, i.e. the smallest integer greater than or equal to the quotient.
+    Up,
+}
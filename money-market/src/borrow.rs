@@ -1,9 +1,16 @@
 multiversx_sc::imports!();
 
-use super::{common, errors::*, events, proxies, storage};
+use super::{
+    common,
+    constants::*,
+    errors::*,
+    events, hedging, proxies,
+    storage::{self, BorrowOrder},
+    token_controls,
+};
 
 #[multiversx_sc::module]
-pub trait BorrowModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait BorrowModule: pausable::PausableModule + common::CommonModule + events::EventsModule + hedging::HedgingModule + proxies::ProxyModule + storage::StorageModule + token_controls::TokenControlsModule {
     /// A borrower requests underlying from the money market.
     ///
     /// # Arguments:
@@ -20,6 +27,9 @@ pub trait BorrowModule: common::CommonModule + events::EventsModule + proxies::P
     }
 
     fn borrow_internal(&self, borrower: ManagedAddress, underlying_amount: BigUint) -> EgldOrEsdtTokenPayment {
+        self.require_not_paused();
+        self.require_no_underlying_protection();
+
         let money_market = self.blockchain().get_sc_address();
         let borrow_allowed = self.borrow_allowed(&money_market, &borrower, &underlying_amount);
         require!(borrow_allowed, ERROR_CONTROLLER_REJECTED_BORROW);
@@ -27,6 +37,9 @@ pub trait BorrowModule: common::CommonModule + events::EventsModule + proxies::P
         // check if accrual has been updated
         self.require_market_fresh();
 
+        // borrows are restricted for a while after an abnormal accrual gap
+        self.require_no_accrual_gap_restriction();
+
         self.try_ensure_staking_rewards(&underlying_amount);
 
         // update account borrowed amount
@@ -34,6 +47,7 @@ pub trait BorrowModule: common::CommonModule + events::EventsModule + proxies::P
         let borrower_current_borrow_amount = self.get_account_borrow_amount(&borrower);
         let new_borrower_borrow_amount = &borrower_current_borrow_amount + &underlying_amount;
         self.set_account_borrow_snapshot(&borrower, &new_borrower_borrow_amount, &borrow_index);
+        self.track_borrowed_principal(&borrower, &borrower_current_borrow_amount, &underlying_amount);
 
         // update cash
         self.cash().update(|amount| *amount -= &underlying_amount);
@@ -43,12 +57,117 @@ pub trait BorrowModule: common::CommonModule + events::EventsModule + proxies::P
         self.total_borrows().set(&new_total_borrows);
 
         // send underlying to borrower
-        let underlying_id = self.underlying_id().get();
-        self.send().direct(&borrower, &underlying_id, 0, &underlying_amount);
+        self.send_underlying(&borrower, &underlying_amount);
+
+        self.try_notify_hedging_hook(&borrower, &new_borrower_borrow_amount);
 
         self.emit_updated_rates();
         self.borrow_event(&borrower, &underlying_amount, &new_borrower_borrow_amount, &new_total_borrows, &borrow_index);
 
-        EgldOrEsdtTokenPayment::new(underlying_id, 0, underlying_amount)
+        EgldOrEsdtTokenPayment::new(self.underlying_id().get(), 0, underlying_amount)
+    }
+
+    /// Places a borrow order, i.e. a borrow intent that can be executed by anyone (typically a keeper) as long as the
+    /// market borrow rate remains below a given threshold and the order has not expired.
+    ///
+    /// # Arguments:
+    ///
+    /// - `underlying_amount` - The amount of underlying asset the borrower requests.
+    /// - `max_borrow_rate` - The maximum borrow rate per second in wad the borrower is willing to pay.
+    /// - `expiration` - The timestamp after which the order can no longer be executed.
+    ///
+    /// # Notes:
+    ///
+    /// - The borrowed underlying is always sent to the borrower, never to the executor.
+    /// - The borrow is subject to the same Controller checks as a regular borrow at execution time.
+    ///
+    #[endpoint(placeBorrowOrder)]
+    fn place_borrow_order(&self, underlying_amount: BigUint, max_borrow_rate: BigUint, expiration: u64) -> u64 {
+        self.require_active();
+
+        require!(underlying_amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(max_borrow_rate > BigUint::zero(), ERROR_INVALID_BORROW_ORDER_RATE);
+        require!(expiration > self.blockchain().get_block_timestamp(), ERROR_INVALID_BORROW_ORDER_EXPIRATION);
+
+        let borrower = self.blockchain().get_caller();
+        require!(self.account_borrow_orders(&borrower).len() < MAX_BORROW_ORDERS_PER_ACCOUNT, ERROR_TOO_MANY_BORROW_ORDERS);
+
+        let id = self.next_borrow_order_id().get();
+        self.next_borrow_order_id().set(id + 1);
+
+        let borrow_order = BorrowOrder { id, borrower: borrower.clone(), amount: underlying_amount, max_borrow_rate, expiration };
+
+        self.borrow_orders(id).set(&borrow_order);
+        self.account_borrow_orders(&borrower).insert(id);
+
+        self.borrow_order_placed_event(&borrow_order);
+
+        id
+    }
+
+    /// Cancels a borrow order.
+    ///
+    /// # Arguments:
+    ///
+    /// - `order_id` - The borrow order identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the borrower, unless the order has already expired, in which case anyone can remove it.
+    ///
+    #[endpoint(cancelBorrowOrder)]
+    fn cancel_borrow_order(&self, order_id: u64) {
+        let borrow_order = self.get_borrow_order(order_id);
+
+        let caller = self.blockchain().get_caller();
+        let expired = self.blockchain().get_block_timestamp() > borrow_order.expiration;
+        require!(caller == borrow_order.borrower || expired, ERROR_ONLY_BORROW_ORDER_OWNER);
+
+        self.remove_borrow_order(&borrow_order);
+
+        self.borrow_order_cancelled_event(&caller, &borrow_order);
+    }
+
+    /// Executes a borrow order on behalf of its borrower.
+    ///
+    /// # Arguments:
+    ///
+    /// - `order_id` - The borrow order identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - The borrow rate resulting from the borrow must not exceed the borrower's threshold.
+    ///
+    #[endpoint(executeBorrowOrder)]
+    fn execute_borrow_order(&self, order_id: u64) -> EgldOrEsdtTokenPayment {
+        self.require_active();
+        self.accrue_interest();
+
+        let borrow_order = self.get_borrow_order(order_id);
+        require!(self.blockchain().get_block_timestamp() <= borrow_order.expiration, ERROR_BORROW_ORDER_EXPIRED);
+
+        self.remove_borrow_order(&borrow_order);
+
+        let payment = self.borrow_internal(borrow_order.borrower.clone(), borrow_order.amount.clone());
+
+        // the order threshold must hold after the borrow has taken place
+        let borrow_rate = self.borrow_rate_per_second();
+        require!(borrow_rate <= borrow_order.max_borrow_rate, ERROR_BORROW_RATE_ABOVE_ORDER_THRESHOLD);
+
+        let executor = self.blockchain().get_caller();
+        self.borrow_order_executed_event(&executor, &borrow_order, &borrow_rate);
+
+        payment
+    }
+
+    fn get_borrow_order(&self, order_id: u64) -> BorrowOrder<Self::Api> {
+        require!(!self.borrow_orders(order_id).is_empty(), ERROR_BORROW_ORDER_NOT_FOUND);
+        self.borrow_orders(order_id).get()
+    }
+
+    fn remove_borrow_order(&self, borrow_order: &BorrowOrder<Self::Api>) {
+        self.borrow_orders(borrow_order.id).clear();
+        self.account_borrow_orders(&borrow_order.borrower).swap_remove(&borrow_order.id);
     }
 }
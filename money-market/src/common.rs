@@ -1,10 +1,10 @@
 multiversx_sc::imports!();
 
 use super::{constants::*, errors::*, events, proxies, storage};
-use crate::storage::State;
+use crate::storage::{BorrowIndexAudit, RateSnapshot, State, TrustedMinterMetadata};
 
 #[multiversx_sc::module]
-pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait CommonModule: math::MathModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
     // Checks
 
     /// A utility function to highlight that this smart contract is a Money Market.
@@ -61,6 +61,30 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         self.blockchain().is_smart_contract(sc_address) && self.is_trusted_minter(sc_address)
     }
 
+    /// Returns a page of the trusted minters with metadata, together with their metadata.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The zero based position of the first trusted minter in the page.
+    /// - `size` - The maximum number of trusted minters in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Legacy trusted minters are only enumerated once their metadata has been set.
+    ///
+    #[view(getTrustedMinters)]
+    fn get_trusted_minters(&self, from: usize, size: usize) -> MultiValueEncoded<MultiValue2<ManagedAddress, TrustedMinterMetadata<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        let trusted_minters = self.trusted_minters();
+        let to = core::cmp::min(from.saturating_add(size), trusted_minters.len());
+        for index in from..to {
+            let trusted_minter = trusted_minters.get_by_index(index + 1);
+            let metadata = self.trusted_minter_metadata(&trusted_minter).get();
+            result.push((trusted_minter, metadata).into());
+        }
+        result
+    }
+
     // Requires
 
     /// Requires that the money market has already accrued interest.
@@ -69,6 +93,12 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         require!(self.blockchain().get_block_timestamp() == self.accrual_timestamp().get(), ERROR_MARKET_NOT_FRESH);
     }
 
+    /// Requires that risk increasing actions are not restricted because of a recent abnormal accrual gap.
+    ///
+    fn require_no_accrual_gap_restriction(&self) {
+        require!(!self.has_abnormal_accrual_gap(), ERROR_ACCRUAL_GAP_RESTRICTION);
+    }
+
     /// Requires that the money market is already active.
     ///
     fn require_active(&self) {
@@ -108,28 +138,90 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
 
     /// Makes a requirement that tries to ensure that staking rewards are available if a specified amount of underlying is
     /// withdrawn from the money market by a borrow, redeem or reduce of reserves. However, it tries but cannot 100%
-    /// guarantee that staking rewards will be available at a following interaction with the protocol.
+    /// guarantee that staking rewards will be available at a following interaction with the protocol. Only on-hand cash is
+    /// considered, i.e. cash deployed into the reserve strategy or lent to the USH peg-stability module must be recalled
+    /// first.
     ///
     /// # Arguments:
     ///
     /// - `underlying_amount` - The amount of underlying that will be withdrawn from the protocol.
     ///
     fn try_ensure_staking_rewards(&self, underlying_amount: &BigUint) {
-        let cash = self.cash().get();
+        let cash = self.get_on_hand_cash();
         let staking_rewards = self.staking_rewards().get();
         require!(cash >= staking_rewards && *underlying_amount <= cash - staking_rewards, ERROR_INSUFFICIENT_BALANCE);
     }
 
     // Accrue Interest
 
+    /// Checks whether the time elapsed between the given accrual timestamp and the given timestamp is abnormal, i.e. the
+    /// timestamp went backwards, an abnormal accrual gap has been recorded at the Controller since the given accrual
+    /// timestamp, or the elapsed time since the latest accrual across all markets exceeds the maximum accrual gap, if set. A
+    /// market that has been idle is not abnormal as long as other markets have accrued recently.
+    ///
+    /// # Arguments:
+    ///
+    /// - `t_prev` - The last accrual timestamp.
+    /// - `t` - The current timestamp.
+    ///
+    fn is_abnormal_accrual_gap(&self, t_prev: u64, t: u64) -> bool {
+        if t < t_prev {
+            return true;
+        }
+
+        let max_accrual_gap = self.max_accrual_gap().get();
+        if max_accrual_gap == 0 || t - t_prev <= max_accrual_gap {
+            return false;
+        }
+
+        let (latest_accrual_timestamp, latest_accrual_gap_timestamp) = self.get_latest_accrual_timestamps();
+        if latest_accrual_gap_timestamp > t_prev {
+            return true;
+        }
+
+        let t_latest = core::cmp::max(t_prev, latest_accrual_timestamp);
+        t_latest > t || t - t_latest > max_accrual_gap
+    }
+
+    /// Checks whether borrows are restricted because of an abnormal accrual gap, either pending governance resolution or
+    /// about to be recorded at the next accrual.
+    ///
+    #[view(hasAbnormalAccrualGap)]
+    fn has_abnormal_accrual_gap(&self) -> bool {
+        if !self.accrual_gap_timestamp().is_empty() {
+            return true;
+        }
+
+        let t = self.blockchain().get_block_timestamp();
+        let t_prev = self.accrual_timestamp().get();
+        t != t_prev && self.is_abnormal_accrual_gap(t_prev, t)
+    }
+
+    /// Records an abnormal accrual gap as pending, accumulating it with any other pending gap. Its interest is not charged
+    /// until governance resolves it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `t_prev` - The last accrual timestamp.
+    /// - `t` - The current timestamp.
+    /// - `borrow_rate` - The borrow rate per second in place during the gap.
+    ///
+    fn record_accrual_gap(&self, t_prev: u64, t: u64, borrow_rate: &BigUint) {
+        let dt = if t > t_prev { t - t_prev } else { 0 };
+
+        self.accrual_gap_timestamp().set(t);
+        self.accrual_gap_duration().update(|duration| *duration += dt);
+        self.accrual_gap_interest_factor().update(|factor| *factor += borrow_rate * dt);
+
+        self.abnormal_accrual_gap_event(t_prev, t);
+    }
+
     /// This method is one of the most important methods of the protocol, as it accrues the borrows interest and distributes
     /// that amount into reserves (including revenue and staking rewards). In order to do that, it solves the money market
     /// dynamics using an Euler scheme.
     ///
     #[endpoint(accrueInterest)]
     fn accrue_interest(&self) {
-        let wad = BigUint::from(WAD);
-
         let t = self.blockchain().get_block_timestamp();
         let t_prev = self.accrual_timestamp().get();
 
@@ -138,35 +230,73 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
             return ();
         }
 
-        // get borrow rate from interest rate model
+        let abnormal_accrual_gap = self.is_abnormal_accrual_gap(t_prev, t);
+
+        // get borrow and supply rates from interest rate model
         let cash_prev = self.cash().get();
         let borrows_prev = self.total_borrows().get();
-        let reserves_prev = self.total_reserves().get();
         let liquidity_prev = self.get_liquidity();
-        let borrow_rate_prev = self.get_borrow_rate(&borrows_prev, &liquidity_prev);
+        let fr = self.reserve_factor().get();
+        let (borrow_rate_prev, supply_rate_prev) = self.get_rates(&borrows_prev, &liquidity_prev, &fr);
+        let utilization_prev = if liquidity_prev == BigUint::zero() { BigUint::zero() } else { self.wad_div(&borrows_prev, &liquidity_prev) };
+
+        // abnormal timestamp jumps (e.g. after a chain halt) are kept pending and restrict borrows until governance decides
+        // whether their interest is charged
+        if abnormal_accrual_gap {
+            self.record_accrual_gap(t_prev, t, &borrow_rate_prev);
+        }
+
+        // no interest is accumulated while interest accrual is frozen at the controller
+        let frozen_until = self.get_interest_frozen_until();
+        let t_start = if abnormal_accrual_gap { t } else { core::cmp::max(t_prev, core::cmp::min(frozen_until, t)) };
+
+        // accumulate interest since the start
+        let dt = t - t_start;
+        let borrow_rate_dt = &borrow_rate_prev * dt;
+        let (delta_borrows, new_borrows, new_index, delta_reserves, delta_rewards, delta_revenue) = self.accumulate_interest(&borrow_rate_dt);
+
+        // update timestamp
+        self.accrual_timestamp().set(t);
+        self.record_accrual(abnormal_accrual_gap);
+
+        // keep track of the rates that have been in place, once per day
+        self.try_take_rate_snapshot(t, &borrow_rate_prev, &supply_rate_prev, &utilization_prev);
+
+        self.accrue_interest_event(&cash_prev, &delta_borrows, &new_index, &new_borrows, &utilization_prev, &borrow_rate_prev, &supply_rate_prev, &delta_reserves, &delta_rewards, &delta_revenue);
+    }
+
+    /// Accumulates interest into total borrows, distributes a fraction of it into reserves (including revenue and staking
+    /// rewards) and updates the borrow index. Returns the delta borrows, the new total borrows, the new borrow index, the
+    /// delta reserves, the delta staking rewards and the delta revenue.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_rate_dt` - The borrow rate per second times the elapsed time, in wad.
+    ///
+    fn accumulate_interest(&self, borrow_rate_dt: &BigUint) -> (BigUint, BigUint, BigUint, BigUint, BigUint, BigUint) {
+        let borrows_prev = self.total_borrows().get();
+        let reserves_prev = self.total_reserves().get();
         let rewards_prev = self.staking_rewards().get();
         let revenue_prev = self.revenue().get();
         let index_prev = self.get_borrow_index();
+        let fr = self.reserve_factor().get();
 
         // update total borrows
-        let dt = t - t_prev;
-        let borrow_rate_dt = &borrow_rate_prev * dt;
-        let delta_borrows = &borrow_rate_dt * &borrows_prev / &wad;
+        let delta_borrows = self.wad_mul(borrow_rate_dt, &borrows_prev);
         let new_borrows = &borrows_prev + &delta_borrows;
         self.total_borrows().set(&new_borrows);
 
         // a fraction of the accumulated interest go to the reserves
-        let fr = self.reserve_factor().get();
-        let delta_reserves = &fr * &delta_borrows / &wad;
+        let delta_reserves = self.wad_mul(&fr, &delta_borrows);
         let new_reserves = reserves_prev + &delta_reserves;
 
         // but reserves are divided into staking rewards and revenue
         let fs = self.stake_factor().get();
-        let delta_rewards = fs * &delta_reserves / &wad;
+        let delta_rewards = self.wad_mul(&fs, &delta_reserves);
         let new_rewards = rewards_prev + &delta_rewards;
 
         let delta_revenue = &delta_reserves - &delta_rewards;
-        let new_revenue = revenue_prev + delta_revenue;
+        let new_revenue = revenue_prev + &delta_revenue;
 
         self.total_reserves().set(&new_reserves);
         self.staking_rewards().set(&new_rewards);
@@ -176,13 +306,46 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         self.historical_staking_rewards().update(|amount| *amount += &delta_rewards);
 
         // update borrow index
-        let new_index = borrow_rate_dt * &index_prev / &wad + &index_prev;
+        let new_index = self.wad_mul(borrow_rate_dt, &index_prev) + &index_prev;
         self.borrow_index().set(&new_index);
 
-        // update timestamp
-        self.accrual_timestamp().set(t);
+        (delta_borrows, new_borrows, new_index, delta_reserves, delta_rewards, delta_revenue)
+    }
 
-        self.accrue_interest_event(&cash_prev, &delta_borrows, &new_index, &new_borrows);
+    /// Takes a snapshot of the interest rates if none has been taken yet in the current day. Snapshots are stored in a ring
+    /// buffer, i.e. once the maximum number of snapshots is reached, the oldest one is overwritten.
+    ///
+    /// # Arguments:
+    ///
+    /// - `t` - The current timestamp.
+    /// - `borrow_rate` - The borrow rate per second.
+    /// - `supply_rate` - The supply rate per second.
+    /// - `utilization` - The utilization in wad.
+    ///
+    fn try_take_rate_snapshot(&self, t: u64, borrow_rate: &BigUint, supply_rate: &BigUint, utilization: &BigUint) {
+        let day = t / RATE_SNAPSHOT_INTERVAL;
+        if !self.last_rate_snapshot_day().is_empty() && self.last_rate_snapshot_day().get() >= day {
+            return;
+        }
+        self.last_rate_snapshot_day().set(day);
+
+        let rate_snapshot = RateSnapshot {
+            timestamp: t,
+            borrow_rate: borrow_rate.clone(),
+            supply_rate: supply_rate.clone(),
+            utilization: utilization.clone(),
+        };
+
+        let mut rate_snapshots = self.rate_snapshots();
+        if rate_snapshots.len() < MAX_RATE_SNAPSHOTS {
+            rate_snapshots.push(&rate_snapshot);
+        } else {
+            let position = self.get_next_rate_snapshot_position();
+            rate_snapshots.set(position, &rate_snapshot);
+            self.next_rate_snapshot_position().set(position % MAX_RATE_SNAPSHOTS + 1);
+        }
+
+        self.rate_snapshot_event(&rate_snapshot);
     }
 
     /// Accrues interest if a sufficient amount of time has elapsed since the last accrual.
@@ -240,6 +403,40 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         self.reserves_added_event(&donor, &underlying_amount, &new_total_reserves);
     }
 
+    /// Audits the internal cash accounting against the actual underlying balance of the money market. Any surplus (i.e.
+    /// underlying sent directly to the money market) is quarantined into the donations bucket, such that it can never be
+    /// used to inflate the exchange rate.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - Returns the newly quarantined amount.
+    ///
+    #[endpoint(auditCash)]
+    fn audit_cash(&self) -> BigUint {
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let underlying_id = self.underlying_id().get();
+        let balance = self.blockchain().get_sc_balance(&underlying_id, 0);
+        let cash = self.get_on_hand_cash();
+        let donations = self.donations().get();
+        let pending_underlying = self.total_pending_underlying().get();
+
+        let accounted = &cash + &donations + &pending_underlying;
+        if balance <= accounted {
+            return BigUint::zero();
+        }
+
+        let surplus = &balance - &accounted;
+        let new_donations = donations + &surplus;
+        self.donations().set(&new_donations);
+
+        self.donations_quarantined_event(&balance, &cash, &surplus, &new_donations);
+
+        surplus
+    }
+
     // Conversions
 
     /// Translates an underlying amount to tokens.
@@ -250,9 +447,8 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
     ///
     #[view(underlyingAmountToTokens)]
     fn underlying_amount_to_tokens(&self, underlying_amount: &BigUint) -> BigUint {
-        let wad = BigUint::from(WAD);
         let fx = self.get_exchange_rate();
-        let tokens = underlying_amount * &wad / fx;
+        let tokens = self.wad_div(underlying_amount, &fx);
         tokens
     }
 
@@ -264,9 +460,8 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
     ///
     #[view(tokensToUnderlyingAmount)]
     fn tokens_to_underlying_amount(&self, tokens: &BigUint) -> BigUint {
-        let wad = BigUint::from(WAD);
         let fx = self.get_exchange_rate();
-        let underlying_amount = fx * tokens / wad;
+        let underlying_amount = self.wad_mul(&fx, tokens);
         underlying_amount
     }
 
@@ -402,6 +597,63 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
     fn set_account_borrow_snapshot(&self, borrower: &ManagedAddress, new_account_borrows: &BigUint, borrow_index: &BigUint) {
         let account_snapshot = storage::AccountSnapshot { borrow_amount: new_account_borrows.clone(), borrow_index: borrow_index.clone() };
         self.account_borrow_snapshot(borrower).set(&account_snapshot);
+        self.account_borrow_index_rebases(borrower).set(self.borrow_index_rebases().len());
+        self.account_borrow_snapshot_timestamp(borrower).set(self.blockchain().get_block_timestamp());
+
+        if new_account_borrows > &BigUint::zero() {
+            self.borrowers().insert(borrower.clone());
+        } else {
+            self.borrowers().swap_remove(borrower);
+        }
+    }
+
+    /// Updates the borrow principal of a given borrower after a borrow. The principal is only tracked for borrowers whose
+    /// principal is known, i.e. borrowers opening a position from scratch or with an imported principal.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The borrower address.
+    /// - `prev_borrow_amount` - The borrower borrow amount before the borrow.
+    /// - `underlying_amount` - The borrowed amount.
+    ///
+    fn track_borrowed_principal(&self, borrower: &ManagedAddress, prev_borrow_amount: &BigUint, underlying_amount: &BigUint) {
+        let principal_mapper = self.account_borrow_principal(borrower);
+        if !principal_mapper.is_empty() {
+            principal_mapper.update(|principal| *principal += underlying_amount);
+        } else if prev_borrow_amount == &BigUint::zero() {
+            principal_mapper.set(underlying_amount);
+        }
+    }
+
+    /// Updates the borrow principal of a given borrower after a repayment. Repayments are applied to the accrued interest
+    /// first and then to the principal.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The borrower address.
+    /// - `new_borrow_amount` - The borrower borrow amount after the repayment.
+    ///
+    fn track_repaid_principal(&self, borrower: &ManagedAddress, new_borrow_amount: &BigUint) {
+        let principal_mapper = self.account_borrow_principal(borrower);
+        if new_borrow_amount == &BigUint::zero() {
+            principal_mapper.clear();
+        } else if !principal_mapper.is_empty() {
+            principal_mapper.update(|principal| *principal = BigUint::min(principal.clone(), new_borrow_amount.clone()));
+        }
+    }
+
+    /// Chains a borrower and its borrow amount into a running hash commitment, used to verify borrow snapshots migrations.
+    ///
+    /// # Arguments:
+    ///
+    /// - `hash` - The previous running hash, empty if none.
+    /// - `borrower` - The borrower address.
+    /// - `borrow_amount` - The borrower borrow amount.
+    ///
+    fn chain_borrow_snapshot_hash(&self, hash: &ManagedBuffer, borrower: &ManagedAddress, borrow_amount: &BigUint) -> ManagedBuffer {
+        let mut data = hash.clone();
+        data.append(&ManagedSerializer::new().top_encode_to_managed_buffer(&(borrower, borrow_amount)));
+        self.crypto().keccak256(data).as_managed_buffer().clone()
     }
 
     // Gets
@@ -429,10 +681,10 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
     ///
     #[view(getBaseTotalBorrows)]
     fn get_base_total_borrows(&self) -> BigUint {
-        let wad = BigUint::from(WAD);
         let total_borrows_t = self.total_borrows().get();
         let market_borrow_index = self.get_borrow_index();
-        total_borrows_t * wad / market_borrow_index
+        let rebase_factor = self.get_borrow_index_rebase_factor();
+        self.wad_div(&total_borrows_t, &market_borrow_index) / rebase_factor
     }
 
     /// Returns the updated amount of reserves.
@@ -475,7 +727,8 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         self.get_liquidity()
     }
 
-    /// Returns the amount of liquidity up to the last interaction that accrued interest.
+    /// Returns the amount of liquidity up to the last interaction that accrued interest. Cash deployed into the reserve
+    /// strategy or lent to the USH peg-stability module is still accounted as liquidity.
     ///
     #[view(getLiquidity)]
     fn get_liquidity(&self) -> BigUint {
@@ -486,6 +739,20 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         cash + borrows - reserves
     }
 
+    /// Returns the amount of liquidity that is available to suppliers and borrowers, i.e. the liquidity without the cash
+    /// deployed into the reserve strategy or lent to the USH peg-stability module, which must be recalled first.
+    ///
+    #[view(getAvailableLiquidity)]
+    fn get_available_liquidity(&self) -> BigUint {
+        let liquidity = self.get_liquidity();
+        let away = self.deployed_cash().get() + self.credit_line_principal().get();
+        if liquidity > away {
+            liquidity - away
+        } else {
+            BigUint::zero()
+        }
+    }
+
     /// Returns the reserve factor, i.e. the percentage of interest that is redirected to the reserves. We keep this method
     /// so that it matches with USH Money Market interface.
     ///
@@ -534,6 +801,98 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         }
     }
 
+    /// Returns the address of the reserve strategy if set.
+    ///
+    #[view(getReserveStrategy)]
+    fn get_reserve_strategy(&self) -> Option<ManagedAddress> {
+        if self.reserve_strategy().is_empty() {
+            None
+        } else {
+            let reserve_strategy = self.reserve_strategy().get();
+            Some(reserve_strategy)
+        }
+    }
+
+    /// Returns the amount of cash that is held by the money market, i.e. the cash that has not been deployed into the
+    /// reserve strategy nor lent to the USH peg-stability module.
+    ///
+    #[view(getOnHandCash)]
+    fn get_on_hand_cash(&self) -> BigUint {
+        let cash = self.cash().get();
+        let away = self.deployed_cash().get() + self.credit_line_principal().get();
+        if cash > away {
+            cash - away
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    /// Returns the amount of deployed cash that should be recalled from the reserve strategy, i.e. the deployed cash above the
+    /// maximum deployed share of cash or the on-hand cash missing to cover the staking rewards, whichever is larger.
+    ///
+    #[view(getStrategyDeficit)]
+    fn get_strategy_deficit(&self) -> BigUint {
+        let deployed_cash = self.deployed_cash().get();
+        let max_deployed_cash = self.wad_mul(&self.max_deployed_share().get(), &self.cash().get());
+        let excess_deployed_cash = if deployed_cash > max_deployed_cash { &deployed_cash - &max_deployed_cash } else { BigUint::zero() };
+
+        let on_hand_cash = self.get_on_hand_cash();
+        let staking_rewards = self.staking_rewards().get();
+        let staking_rewards_shortfall = if staking_rewards > on_hand_cash { staking_rewards - on_hand_cash } else { BigUint::zero() };
+
+        BigUint::min(BigUint::max(excess_deployed_cash, staking_rewards_shortfall), deployed_cash)
+    }
+
+    /// Realizes a loss of cash that has been deployed into the reserve strategy or lent to the USH peg-stability module and
+    /// will never be returned. The loss is absorbed by the reserves first, up to the protocol revenue, and the remainder by
+    /// suppliers through a lower exchange rate. Returns the amounts absorbed by the reserves and by suppliers as a tuple.
+    ///
+    /// # Arguments:
+    ///
+    /// - `loss` - The amount of underlying that has been lost.
+    ///
+    /// # Notes:
+    ///
+    /// - Staking rewards and the insurance fund balance are never used to absorb losses.
+    ///
+    fn realize_loss(&self, loss: &BigUint) -> (BigUint, BigUint) {
+        let revenue = self.revenue().get();
+        let reserves_loss = BigUint::min(loss.clone(), revenue);
+        let supply_loss = loss - &reserves_loss;
+
+        self.cash().update(|cash| *cash -= loss);
+        self.total_reserves().update(|reserves| *reserves -= &reserves_loss);
+        self.revenue().update(|revenue| *revenue -= &reserves_loss);
+
+        self.emit_updated_rates();
+
+        (reserves_loss, supply_loss)
+    }
+
+    /// Returns the address of the PSM adapter if set.
+    ///
+    #[view(getPsmAdapter)]
+    fn get_psm_adapter(&self) -> Option<ManagedAddress> {
+        if self.psm_adapter().is_empty() {
+            None
+        } else {
+            let psm_adapter = self.psm_adapter().get();
+            Some(psm_adapter)
+        }
+    }
+
+    /// Returns the address of the insurance fund if set.
+    ///
+    #[view(getInsuranceFund)]
+    fn get_insurance_fund(&self) -> Option<ManagedAddress> {
+        if self.insurance_fund().is_empty() {
+            None
+        } else {
+            let insurance_fund = self.insurance_fund().get();
+            Some(insurance_fund)
+        }
+    }
+
     /// Returns the updated borrow amount of the given account.
     ///
     #[endpoint(getCurrentAccountBorrowAmount)]
@@ -563,10 +922,10 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
     ///
     #[view(getBaseAccountBorrowAmount)]
     fn base_account_borrow_amount(&self, account: &ManagedAddress) -> BigUint {
-        let wad = BigUint::from(WAD);
         let borrow_amount_t = self.get_account_borrow_amount(account);
         let market_borrow_index = self.get_borrow_index();
-        borrow_amount_t * wad / market_borrow_index
+        let rebase_factor = self.get_borrow_index_rebase_factor();
+        self.wad_div(&borrow_amount_t, &market_borrow_index) / rebase_factor
     }
 
     /// Returns the account borrow using the market borrow index and the account snapshot up to the last interaction that
@@ -587,14 +946,42 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
     }
 
     /// Returns the account borrow snapshot, which includes the borrow amount and the borrow index updated up to the last
-    /// time the user interacted with the protocol.
+    /// time the user interacted with the protocol. If the borrow index has been rebased since the snapshot was taken, the
+    /// snapshot is brought to the current borrow index without changing the effective debt.
     ///
     fn get_account_borrow_snapshot(&self, account: &ManagedAddress) -> Option<storage::AccountSnapshot<Self::Api>> {
         if self.account_borrow_snapshot(account).is_empty() {
             None
         } else {
             let account_borrow_snapshot = self.account_borrow_snapshot(account).get();
-            Some(account_borrow_snapshot)
+
+            let rebases = self.borrow_index_rebases();
+            let account_rebases = self.account_borrow_index_rebases(account).get();
+            if account_rebases >= rebases.len() {
+                return Some(account_borrow_snapshot);
+            }
+
+            // the snapshot borrow index is expressed in the scale previous to the pending rebases
+            let mut divisor = BigUint::from(1u64);
+            for position in account_rebases + 1..=rebases.len() {
+                divisor *= rebases.get(position);
+            }
+
+            let borrow_index = self.get_borrow_index();
+            let borrow_amount = account_borrow_snapshot.borrow_amount * &borrow_index * divisor / account_borrow_snapshot.borrow_index;
+            Some(storage::AccountSnapshot { borrow_amount, borrow_index })
+        }
+    }
+
+    /// Returns the product of all the divisors applied to the borrow index by borrow index rebases, or one if the borrow
+    /// index has never been rebased.
+    ///
+    #[view(getBorrowIndexRebaseFactor)]
+    fn get_borrow_index_rebase_factor(&self) -> BigUint {
+        if self.borrow_index_rebase_factor().is_empty() {
+            BigUint::from(1u64)
+        } else {
+            self.borrow_index_rebase_factor().get()
         }
     }
 
@@ -612,6 +999,50 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         (borrow_amount, fx)
     }
 
+    /// Returns the number of accounts with an outstanding borrow.
+    ///
+    #[view(getBorrowersCount)]
+    fn get_borrowers_count(&self) -> usize {
+        self.borrowers().len()
+    }
+
+    /// Gets a page of the borrow snapshots of this money market, i.e. tuples of borrower address, borrow amount (principal
+    /// at the borrow index of the snapshot), borrow index and last update timestamp.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The zero based position of the first borrower in the page.
+    /// - `size` - The maximum number of borrowers in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Borrowers that fully repay are removed by swapping them with the last borrower, so pages are only consistent within
+    ///   the same block.
+    /// - Borrowers that have not interacted since borrowers were indexed might be missing until they are indexed.
+    /// - Borrow amounts are not up to date with accrued interest, which can be computed using the current borrow index.
+    /// - Snapshots taken before a borrow index rebase are brought to the rebased borrow index.
+    /// - Restricted to readers registered at the Controller if bulk enumeration views are restricted.
+    ///
+    #[view(getBorrowSnapshotsPage)]
+    fn get_borrow_snapshots_page(&self, from: usize, size: usize) -> MultiValueEncoded<MultiValue4<ManagedAddress, BigUint, BigUint, u64>> {
+        let caller = self.blockchain().get_caller();
+        require!(self.is_enumeration_allowed(&caller), ERROR_UNREGISTERED_READER);
+
+        let mut result = MultiValueEncoded::new();
+        let borrowers = self.borrowers();
+        let to = core::cmp::min(from.saturating_add(size), borrowers.len());
+        for index in from..to {
+            let borrower = borrowers.get_by_index(index + 1);
+            let storage::AccountSnapshot { borrow_amount, borrow_index } = match self.get_account_borrow_snapshot(&borrower) {
+                Some(snapshot) => snapshot,
+                None => continue,
+            };
+            let timestamp = self.account_borrow_snapshot_timestamp(&borrower).get();
+            result.push((borrower, borrow_amount, borrow_index, timestamp).into());
+        }
+        result
+    }
+
     /// Returns the money market exchange rate and the borrow amount of the given account up to the last interaction that
     /// accrued interest or up to the current time if a sufficient amount of time has elapsed since the last accrual, in one
     /// shot.
@@ -640,6 +1071,58 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         }
     }
 
+    /// Returns an audit of the borrow index, which can be used to plan an index rebase long before precision becomes a
+    /// problem. It includes the borrow index magnitude in bits, an estimation of the number of years until the borrow index
+    /// exceeds `MAX_BORROW_INDEX_BITS` at the current borrow rate, and the rounding drift between the total borrows and the
+    /// sum of the borrows of a sampled page of accounts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `accounts` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - The estimation is `None` if the borrow rate is zero.
+    /// - The rounding drift is the absolute difference between the total borrows and the sampled borrows. Hence, it only
+    ///   represents the accumulated rounding drift if the page includes all borrowers.
+    /// - It does not accrue interest.
+    ///
+    #[view(getBorrowIndexAudit)]
+    fn get_borrow_index_audit(&self, accounts: MultiValueEncoded<ManagedAddress>) -> BorrowIndexAudit<Self::Api> {
+        let borrow_index = self.get_borrow_index();
+        let borrow_index_bits = borrow_index.log2();
+
+        // the borrow index grows exponentially with the borrow rate, i.e. each bit requires ln(2) / rate seconds
+        let borrow_rate = self.borrow_rate_per_second();
+        let years_to_overflow = if borrow_rate == BigUint::zero() {
+            None
+        } else {
+            let headroom_bits = MAX_BORROW_INDEX_BITS.saturating_sub(borrow_index_bits);
+            let years = BigUint::from(headroom_bits) * LN_2 / (borrow_rate * SECONDS_PER_YEAR);
+            Some(years.to_u64().unwrap_or(u64::MAX))
+        };
+
+        let total_borrows = self.total_borrows().get();
+        let mut sampled_borrows = BigUint::zero();
+        let mut sampled_accounts = 0usize;
+        for account in accounts.into_iter() {
+            sampled_borrows += self.get_account_borrow_amount(&account);
+            sampled_accounts += 1;
+        }
+
+        let rounding_drift = if total_borrows >= sampled_borrows { &total_borrows - &sampled_borrows } else { &sampled_borrows - &total_borrows };
+
+        BorrowIndexAudit {
+            borrow_index,
+            borrow_index_bits,
+            years_to_overflow,
+            total_borrows,
+            sampled_borrows,
+            sampled_accounts,
+            rounding_drift,
+        }
+    }
+
     /// Returns the current money market exchange rate between underlying and tokens.
     ///
     #[endpoint(getCurrentExchangeRate)]
@@ -661,8 +1144,6 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
     /// the initial condition.
     ///
     fn get_exchange_rate(&self) -> BigUint {
-        let wad = BigUint::from(WAD);
-
         let total_supply = self.total_supply().get();
         if total_supply == BigUint::zero() {
             return self.initial_exchange_rate().get();
@@ -670,7 +1151,7 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
 
         let liquidity = self.get_liquidity();
 
-        liquidity * wad / total_supply
+        self.wad_div(&liquidity, &total_supply)
     }
 
     /// Returns the borrow rate per second up to the last interaction that accrued interest.
@@ -705,6 +1186,40 @@ pub trait CommonModule: events::EventsModule + proxies::ProxyModule + storage::S
         self.get_rates(&prev_borrows, &prev_liquidity, &reserve_factor)
     }
 
+    /// Returns a page of the interest rate snapshots history, in chronological order.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The zero based position of the first snapshot in the page, where zero is the oldest snapshot available.
+    /// - `size` - The maximum number of snapshots in the page.
+    ///
+    #[view(getRateSnapshots)]
+    fn get_rate_snapshots(&self, from: usize, size: usize) -> MultiValueEncoded<RateSnapshot<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let rate_snapshots = self.rate_snapshots();
+        let len = rate_snapshots.len();
+
+        // once the ring buffer is full, the oldest snapshot is the next one to be overwritten
+        let oldest = if len < MAX_RATE_SNAPSHOTS { 1 } else { self.get_next_rate_snapshot_position() };
+
+        let to = core::cmp::min(from.saturating_add(size), len);
+        for index in from..to {
+            let position = (oldest - 1 + index) % len + 1;
+            result.push(rate_snapshots.get(position));
+        }
+        result
+    }
+
+    /// Returns the position of the next interest rate snapshot to be overwritten in the ring buffer.
+    ///
+    fn get_next_rate_snapshot_position(&self) -> usize {
+        if self.next_rate_snapshot_position().is_empty() {
+            1usize
+        } else {
+            self.next_rate_snapshot_position().get()
+        }
+    }
+
     /// Returns the close factor, used to determine the maximum amount of a borrow that can be repaid during a liquidation.
     /// If not set, it returns the minimum allowed close factor.
     ///
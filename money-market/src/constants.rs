@@ -1,5 +1,5 @@
-/// A WAD equals 1e18
-pub const WAD: u64 = 1_000_000_000_000_000_000;
+/// The WAD and BPS units, defined at the math crate
+pub use math::constants::{BPS, WAD};
 
 /// The amount of tokens to be minted at money market configuration
 pub const MIN_INITIAL_SUPPLY: u64 = 1_000;
@@ -7,8 +7,68 @@ pub const MIN_INITIAL_SUPPLY: u64 = 1_000;
 /// The maximum accrual time threshold allowed (1 day)
 pub const MAX_ACCRUAL_TIME_THRESHOLD: u64 = 86400;
 
+/// The minimum non-zero maximum accrual gap allowed (1 hour)
+pub const MIN_MAX_ACCRUAL_GAP: u64 = 3600;
+
+/// The maximum redeem fee allowed in bps (1%)
+pub const MAX_REDEEM_FEE: u64 = 100;
+
+/// The interval between two consecutive interest rate snapshots (1 day)
+pub const RATE_SNAPSHOT_INTERVAL: u64 = 86400;
+
+/// The maximum number of interest rate snapshots kept in history (1 year)
+pub const MAX_RATE_SNAPSHOTS: usize = 365;
+
+/// The borrow index magnitude (in bits) beyond which an index rebase should be planned
+pub const MAX_BORROW_INDEX_BITS: u32 = 128;
+
+/// The natural logarithm of 2 in wad
+pub const LN_2: u64 = 693_147_180_559_945_309;
+
+/// The number of seconds in a year
+pub const SECONDS_PER_YEAR: u64 = 31_556_926;
+
+/// The maximum share of cash that can be deployed into a reserve strategy (50%)
+pub const MAX_DEPLOYED_SHARE: u64 = 500_000_000_000_000_000;
+
+/// The maximum share of cash that can be lent to the USH peg-stability module (25%)
+pub const MAX_CREDIT_LINE_SHARE: u64 = 250_000_000_000_000_000;
+
+/// The maximum interest rate per second that can be charged on the credit line (100% per year)
+pub const MAX_CREDIT_LINE_RATE: u64 = 31_688_738_506;
+
 /// The minimum close factor allowed (20%)
 pub const MIN_CLOSE_FACTOR: u64 = 200_000_000_000_000_000;
 
 /// The minimum liquidation incentive allowed (101%)
 pub const MIN_LIQUIDATION_INCENTIVE: u64 = 1_010_000_000_000_000_000;
+
+/// The maximum number of open borrow orders per account
+pub const MAX_BORROW_ORDERS_PER_ACCOUNT: usize = 10;
+
+/// The maximum gas limit an account can allocate to its hedging hook notifications
+pub const MAX_HEDGING_HOOK_GAS_LIMIT: u64 = 20_000_000;
+
+/// The gas kept aside for the remaining execution when notifying a hedging hook
+pub const HEDGING_HOOK_GAS_RESERVE: u64 = 5_000_000;
+
+/// The maximum length of the purpose tag of a reserves withdrawal
+pub const MAX_RESERVES_PURPOSE_LENGTH: usize = 32;
+
+/// The maximum number of deposit terms available for term deposits
+pub const MAX_DEPOSIT_TERMS: usize = 10;
+
+/// The maximum annualized supply-rate bonus of a deposit term (10%)
+pub const MAX_DEPOSIT_TERM_BONUS_RATE: u64 = 100_000_000_000_000_000;
+
+/// The maximum early withdrawal penalty of term deposits (10%)
+pub const MAX_EARLY_WITHDRAWAL_PENALTY: u64 = 100_000_000_000_000_000;
+
+/// The maximum number of open term deposits per account
+pub const MAX_TERM_DEPOSITS_PER_ACCOUNT: usize = 10;
+
+/// The minimum timelock between proposing and setting a new Interest Rate Model (1 day)
+pub const MIN_INTEREST_RATE_MODEL_TIMELOCK: u64 = 24 * 60 * 60;
+
+/// The maximum timelock between proposing and setting a new Interest Rate Model (30 days)
+pub const MAX_INTEREST_RATE_MODEL_TIMELOCK: u64 = 30 * 24 * 60 * 60;
@@ -5,26 +5,59 @@ multiversx_sc::imports!();
 pub mod money_market_proxy;
 
 pub use admin;
+pub use math;
+pub use pausable;
 
 pub mod borrow;
 pub mod common;
 pub mod constants;
+pub mod credit_line;
 pub mod errors;
 pub mod events;
+pub mod forgiveness;
 pub mod governance;
+pub mod hedging;
 pub mod liquidate;
 pub mod mint;
+pub mod mirror;
 pub mod proxies;
 pub mod redeem;
 pub mod repay_borrow;
 pub mod seize;
 pub mod staking;
 pub mod storage;
+pub mod strategy;
+pub mod term_deposit;
+pub mod token_controls;
 
 use crate::{constants::*, errors::*, storage::State};
 
 #[multiversx_sc::contract]
-pub trait MoneyMarket: admin::AdminModule + borrow::BorrowModule + common::CommonModule + events::EventsModule + governance::GovernanceModule + liquidate::LiquidateModule + mint::MintModule + proxies::ProxyModule + redeem::RedeemModule + repay_borrow::RepayBorrowModule + seize::SeizeModule + storage::StorageModule + staking::StakingModule {
+pub trait MoneyMarket:
+    admin::AdminModule
+    + math::MathModule
+    + pausable::PausableModule
+    + pausable::PausableViewModule
+    + borrow::BorrowModule
+    + common::CommonModule
+    + credit_line::CreditLineModule
+    + events::EventsModule
+    + forgiveness::ForgivenessModule
+    + governance::GovernanceModule
+    + hedging::HedgingModule
+    + liquidate::LiquidateModule
+    + mint::MintModule
+    + mirror::MirrorModule
+    + proxies::ProxyModule
+    + redeem::RedeemModule
+    + repay_borrow::RepayBorrowModule
+    + seize::SeizeModule
+    + storage::StorageModule
+    + staking::StakingModule
+    + strategy::StrategyModule
+    + term_deposit::TermDepositModule
+    + token_controls::TokenControlsModule
+{
     /// Initialize the Money Market.
     ///
     /// # Arguments:
@@ -41,6 +74,7 @@ pub trait MoneyMarket: admin::AdminModule + borrow::BorrowModule + common::Commo
     /// - If the admin address is not provided, the admin will be set as the deployer.
     /// - If the contract is being upgraded, the admin address will not be overwritten.
     /// - Upgrades won't change the underlying token identifier, initial exchange rate, accrual timestamp or the admin.
+    /// - The pause guardian is read live from the Controller.
     ///
     #[init]
     fn init(&self, underlying_id: EgldOrEsdtTokenIdentifier, controller: ManagedAddress, interest_rate_model: ManagedAddress, initial_exchange_rate: BigUint, opt_admin: OptionalValue<ManagedAddress>) {
@@ -53,6 +87,9 @@ pub trait MoneyMarket: admin::AdminModule + borrow::BorrowModule + common::Commo
         // try set controller
         self.try_set_controller(&controller);
 
+        // the pause guardian is the one of the Controller
+        self.pause_guardian_source().set(self.controller().get());
+
         // try set initialize timestamp
         self.try_set_accrual_timestamp();
 
@@ -66,8 +103,17 @@ pub trait MoneyMarket: admin::AdminModule + borrow::BorrowModule + common::Commo
         self.try_set_market_state(&State::Inactive);
     }
 
+    /// Upgrades the Money Market.
+    ///
+    /// # Notes:
+    ///
+    /// - From now on, the pause guardian is read live from the Controller.
+    ///
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.pause_guardian().clear();
+        self.pause_guardian_source().set(self.controller().get());
+    }
 
     /// Issue the ESDT Hatom Token.
     ///
@@ -133,6 +179,11 @@ pub trait MoneyMarket: admin::AdminModule + borrow::BorrowModule + common::Commo
             },
         }
         self.ongoing_issuance().set(false);
+
+        // callbacks never revert because of issuer controls, so they are a safe place to persist the protective state
+        if !self.underlying_id().get().is_egld() {
+            self.update_underlying_protection();
+        }
     }
 
     /// Sets minting and burning roles for the Money Market smart contract with respect to the Hatom Token.
@@ -161,6 +212,8 @@ pub trait MoneyMarket: admin::AdminModule + borrow::BorrowModule + common::Commo
     /// - Can only be called once.
     /// - The initial supply is equal to the minimum initial supply.
     /// - The remainder tokens are sent back to the caller.
+    /// - If borrow snapshots have been committed, the running import hash must match the committed export hash, i.e. the
+    ///   market cannot be activated with a partial or tampered import.
     ///
     #[payable("*")]
     #[endpoint(mintInitialSupply)]
@@ -171,6 +224,9 @@ pub trait MoneyMarket: admin::AdminModule + borrow::BorrowModule + common::Commo
 
         require!(!self.minted_initial_supply().get(), ERROR_INITIAL_SUPPLY_ALREADY_MINTED);
 
+        let commitment = self.borrow_snapshots_commitment().get();
+        require!(commitment.is_empty() || self.borrow_snapshots_import_hash().get() == commitment, ERROR_BORROW_SNAPSHOTS_IMPORT_INCOMPLETE);
+
         self.accrue_interest();
         self.require_market_fresh();
 
@@ -0,0 +1,278 @@
+multiversx_sc::imports!();
+
+use super::{common, constants::*, errors::*, events, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait CreditLineModule: admin::AdminModule + common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Sets the PSM adapter, i.e. the contract through which idle cash can be lent to the USH peg-stability module.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_psm_adapter` - The PSM adapter smart contract address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The PSM adapter must accept the same underlying as this money market.
+    /// - Changing the PSM adapter requires the credit line to be fully recalled first.
+    ///
+    #[endpoint(setPsmAdapter)]
+    fn set_psm_adapter(&self, new_psm_adapter: &ManagedAddress) {
+        self.require_admin();
+
+        require!(self.is_psm_adapter(new_psm_adapter), ERROR_INVALID_PSM_ADAPTER);
+        require!(self.get_psm_adapter_underlying_id(new_psm_adapter) == self.underlying_id().get(), ERROR_INVALID_PSM_ADAPTER);
+
+        let old_psm_adapter = self.get_psm_adapter();
+        if let Some(ref old_psm_adapter) = old_psm_adapter {
+            require!(old_psm_adapter == new_psm_adapter || self.get_credit_line_debt() == BigUint::zero(), ERROR_PSM_ADAPTER_HAS_OUTSTANDING_CREDIT);
+        }
+
+        self.psm_adapter().set(new_psm_adapter);
+
+        self.new_psm_adapter_event(&old_psm_adapter, new_psm_adapter);
+    }
+
+    /// Sets the credit line cap and interest rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_cap` - The maximum amount of cash that can be lent to the USH peg-stability module.
+    /// - `new_rate` - The interest rate per second charged on the credit line principal in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The rate cannot exceed `MAX_CREDIT_LINE_RATE`.
+    /// - Interest is accrued at the old rate before the new rate takes effect.
+    /// - Lowering the cap below the current principal only prevents new lending.
+    ///
+    #[endpoint(setCreditLine)]
+    fn set_credit_line(&self, new_cap: &BigUint, new_rate: &BigUint) {
+        self.require_admin();
+
+        require!(new_rate <= &BigUint::from(MAX_CREDIT_LINE_RATE), ERROR_CREDIT_LINE_RATE_TOO_HIGH);
+
+        self.accrue_credit_line_interest();
+
+        let old_cap = self.credit_line_cap().get();
+        let old_rate = self.credit_line_rate().get();
+
+        self.credit_line_cap().set(new_cap);
+        self.credit_line_rate().set(new_rate);
+
+        self.new_credit_line_event(&old_cap, new_cap, &old_rate, new_rate);
+    }
+
+    /// Lends an amount of idle cash to the USH peg-stability module through the PSM adapter.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to lend.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The principal cannot exceed the credit line cap nor the `MAX_CREDIT_LINE_SHARE` of cash.
+    /// - Lent cash is still accounted as cash, so the exchange rate and liquidity remain unchanged.
+    ///
+    #[endpoint(lendToPsm)]
+    fn lend_to_psm(&self, amount: &BigUint) {
+        self.require_admin();
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let psm_adapter = match self.get_psm_adapter() {
+            None => sc_panic!(ERROR_UNDEFINED_PSM_ADAPTER),
+            Some(address) => address,
+        };
+
+        require!(amount > &BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        self.try_ensure_staking_rewards(amount);
+        self.accrue_credit_line_interest();
+
+        let wad = BigUint::from(WAD);
+        let cash = self.cash().get();
+        let new_principal = self.credit_line_principal().get() + amount;
+        require!(new_principal <= self.credit_line_cap().get(), ERROR_CREDIT_LINE_CAP_EXCEEDED);
+        require!(&new_principal * &wad <= BigUint::from(MAX_CREDIT_LINE_SHARE) * &cash, ERROR_CREDIT_LINE_CAP_EXCEEDED);
+
+        self.credit_line_principal().set(&new_principal);
+
+        let underlying_id = self.underlying_id().get();
+        self.psm_adapter_lend(&underlying_id, amount);
+
+        self.credit_line_lend_event(&psm_adapter, amount, &new_principal);
+    }
+
+    /// Recalls an amount of the credit line debt from the PSM adapter. Repayments are applied to the accrued interest first
+    /// and then to the principal. The interest is booked into the reserves and split between staking rewards and revenue.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_amount` - The amount of underlying to recall. If not provided, the whole debt is recalled, up to the amount
+    ///   held by the PSM adapter, i.e. not drawn by the PSM.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The PSM adapter must return at least the requested amount.
+    ///
+    #[endpoint(recallFromPsm)]
+    fn recall_from_psm(&self, opt_amount: OptionalValue<BigUint>) {
+        self.require_admin();
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let psm_adapter = match self.get_psm_adapter() {
+            None => sc_panic!(ERROR_UNDEFINED_PSM_ADAPTER),
+            Some(address) => address,
+        };
+
+        self.accrue_credit_line_interest();
+
+        let debt = self.credit_line_principal().get() + self.credit_line_interest().get();
+        let amount = match opt_amount.into_option() {
+            Some(amount) => amount,
+            None => BigUint::min(debt.clone(), self.get_psm_adapter_available()),
+        };
+
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(amount <= debt, ERROR_AMOUNT_EXCEEDS_CREDIT_LINE_DEBT);
+
+        self.recall_from_psm_internal(&psm_adapter, &amount);
+    }
+
+    /// Recalls the whole credit line debt from the PSM adapter, writing off whatever the PSM adapter cannot return because it
+    /// has been drawn by the PSM, e.g. when the PSM defaults or the credit line must be unwound urgently.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - All the underlying held by the PSM adapter is recalled first, applied to the accrued interest and then to the
+    ///   principal.
+    /// - The unpaid interest is forgiven, while the unpaid principal is realized as a loss, which is absorbed by the
+    ///   reserves first, up to the protocol revenue, and the remainder by suppliers.
+    /// - The written off principal is also written off from the amount drawn at the PSM adapter, up to that amount.
+    ///
+    #[endpoint(forceRecallFromPsm)]
+    fn force_recall_from_psm(&self) {
+        self.require_admin();
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let psm_adapter = match self.get_psm_adapter() {
+            None => sc_panic!(ERROR_UNDEFINED_PSM_ADAPTER),
+            Some(address) => address,
+        };
+
+        self.accrue_credit_line_interest();
+
+        let debt = self.credit_line_principal().get() + self.credit_line_interest().get();
+        let amount = BigUint::min(debt, self.get_psm_adapter_available());
+        if amount > BigUint::zero() {
+            self.recall_from_psm_internal(&psm_adapter, &amount);
+        }
+
+        self.credit_line_interest().clear();
+
+        let loss = self.credit_line_principal().get();
+        if loss == BigUint::zero() {
+            return;
+        }
+
+        self.credit_line_principal().clear();
+
+        let drawn = BigUint::min(loss.clone(), self.get_psm_adapter_drawn());
+        if drawn > BigUint::zero() {
+            self.psm_adapter_write_off(&drawn);
+        }
+
+        let (reserves_loss, supply_loss) = self.realize_loss(&loss);
+        self.credit_line_loss_event(&psm_adapter, &loss, &reserves_loss, &supply_loss);
+    }
+
+    fn recall_from_psm_internal(&self, psm_adapter: &ManagedAddress, amount: &BigUint) {
+        let principal = self.credit_line_principal().get();
+        let interest = self.credit_line_interest().get();
+
+        let underlying_id = self.underlying_id().get();
+        let balance_before = self.blockchain().get_sc_balance(&underlying_id, 0);
+        self.psm_adapter_repay(amount);
+        let balance_after = self.blockchain().get_sc_balance(&underlying_id, 0);
+
+        require!(&(balance_after - balance_before) >= amount, ERROR_PSM_ADAPTER_SHORTFALL);
+
+        let interest_paid = BigUint::min(amount.clone(), interest.clone());
+        let principal_paid = amount - &interest_paid;
+
+        let new_principal = principal - &principal_paid;
+        self.credit_line_principal().set(&new_principal);
+        self.credit_line_interest().set(&(interest - &interest_paid));
+
+        // the interest is added to cash and booked into the reserves, split between staking rewards and revenue
+        if interest_paid > BigUint::zero() {
+            let fs = self.stake_factor().get();
+            let delta_rewards = fs * &interest_paid / BigUint::from(WAD);
+            let delta_revenue = &interest_paid - &delta_rewards;
+
+            self.cash().update(|cash| *cash += &interest_paid);
+            self.total_reserves().update(|reserves| *reserves += &interest_paid);
+            self.revenue().update(|revenue| *revenue += &delta_revenue);
+            self.staking_rewards().update(|rewards| *rewards += &delta_rewards);
+            self.historical_staking_rewards().update(|rewards| *rewards += &delta_rewards);
+
+            self.emit_updated_rates();
+        }
+
+        self.credit_line_recall_event(psm_adapter, &interest_paid, &principal_paid, &new_principal);
+    }
+
+    /// Returns the current credit line debt, i.e. the principal lent to the USH peg-stability module plus the interest
+    /// accrued up to the current timestamp.
+    ///
+    #[view(getCreditLineDebt)]
+    fn get_credit_line_debt(&self) -> BigUint {
+        let principal = self.credit_line_principal().get();
+        principal + self.credit_line_interest().get() + self.get_pending_credit_line_interest()
+    }
+
+    /// Returns the credit line interest accrued since the last credit line accrual.
+    ///
+    fn get_pending_credit_line_interest(&self) -> BigUint {
+        let t = self.blockchain().get_block_timestamp();
+        let t_prev = self.credit_line_timestamp().get();
+        let principal = self.credit_line_principal().get();
+
+        if t <= t_prev || principal == BigUint::zero() {
+            return BigUint::zero();
+        }
+
+        self.credit_line_rate().get() * (t - t_prev) * principal / BigUint::from(WAD)
+    }
+
+    /// Accrues simple interest on the credit line principal up to the current timestamp.
+    ///
+    fn accrue_credit_line_interest(&self) {
+        let t = self.blockchain().get_block_timestamp();
+        if t == self.credit_line_timestamp().get() {
+            return;
+        }
+
+        let delta_interest = self.get_pending_credit_line_interest();
+        self.credit_line_timestamp().set(t);
+
+        if delta_interest == BigUint::zero() {
+            return;
+        }
+
+        let interest = self.credit_line_interest().get() + &delta_interest;
+        self.credit_line_interest().set(&interest);
+
+        self.credit_line_accrue_event(&delta_interest, &interest);
+    }
+}
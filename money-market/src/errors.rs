@@ -10,17 +10,24 @@ pub const ERROR_UNDEFINED_INTEREST_RATE_MODEL: &[u8] = b"undefined Interest Rate
 pub const ERROR_UNDEFINED_STAKING_SC: &[u8] = b"undefined Staking smart contract";
 pub const ERROR_RESERVE_FACTOR_TOO_HIGH: &[u8] = b"reserve factor too high";
 pub const ERROR_STAKE_FACTOR_TOO_HIGH: &[u8] = b"stake factor too high";
+pub const ERROR_REDEEM_FEE_TOO_HIGH: &[u8] = b"redeem fee too high";
 pub const ERROR_PROTOCOL_SEIZE_SHARE_TOO_HIGH: &[u8] = b"protocol seize share too high";
+pub const ERROR_INSURANCE_FUND_SHARE_TOO_HIGH: &[u8] = b"insurance fund share too high";
+pub const ERROR_INSURANCE_FUND_NOT_SET: &[u8] = b"insurance fund not set";
 pub const ERROR_LIQUIDATION_INCENTIVE_TOO_HIGH: &[u8] = b"liquidity incentive too high";
 pub const ERROR_LIQUIDATION_INCENTIVE_TOO_LOW: &[u8] = b"liquidity incentive too low";
 pub const ERROR_CLOSE_FACTOR_TOO_LOW: &[u8] = b"close factor too low";
 pub const ERROR_CLOSE_FACTOR_TOO_HIGH: &[u8] = b"close factor too high";
 pub const ERROR_ACCRUAL_TIME_THRESHOLD_TOO_HIGH: &[u8] = b"accrual time threshold too high";
+pub const ERROR_MAX_ACCRUAL_GAP_TOO_LOW: &[u8] = b"maximum accrual gap too low";
+pub const ERROR_NO_ABNORMAL_ACCRUAL_GAP: &[u8] = b"no abnormal accrual gap";
+pub const ERROR_ACCRUAL_GAP_RESTRICTION: &[u8] = b"restricted after an abnormal accrual gap";
 pub const ERROR_NON_VALID_INTEREST_RATE_MODEL_SC: &[u8] = b"not a valid interest rate model smart contract";
 pub const ERROR_NON_VALID_CONTROLLER_SC: &[u8] = b"not a valid controller smart contract";
 pub const ERROR_NON_VALID_STAKING_SC: &[u8] = b"not a valid staking smart contract";
 pub const ERROR_NON_VALID_TRUSTED_MINTER_SC: &[u8] = b"not a valid trusted minter smart contract";
 pub const ERROR_AMOUNT_EXCEEDS_REVENUE: &[u8] = b"amount to withdraw exceeds money market revenue part of the reserves";
+pub const ERROR_AMOUNT_EXCEEDS_DONATIONS: &[u8] = b"amount to withdraw exceeds money market donations";
 pub const ERROR_CANNOT_BE_ADDRESS_ZERO: &[u8] = b"cannot be address zero";
 pub const ERROR_INVALID_UNDERLYING_ID: &[u8] = b"invalid underlying identifier";
 pub const ERROR_HATOM_TOKEN_ALREADY_ISSUED: &[u8] = b"Hatom token has been already issued";
@@ -36,9 +43,70 @@ pub const ERROR_INITIAL_FX_MUST_BE_GREATER_THAN_ZERO: &[u8] = b"initial exchange
 pub const ERROR_CANNOT_LIQUIDATE_YOURSELF: &[u8] = b"cannot liquidate yourself";
 pub const ERROR_TOO_MUCH_LIQUIDATION: &[u8] = b"too much liquidation, the borrower does not have enough collateral to seize";
 pub const ERROR_NOT_ENOUGH_SEIZED_TOKENS: &[u8] = b"not enough seized tokens";
+pub const ERROR_NOT_ENOUGH_MINTED_TOKENS: &[u8] = b"not enough minted tokens";
+pub const ERROR_NOT_ENOUGH_REDEEMED_UNDERLYING: &[u8] = b"not enough redeemed underlying";
 pub const ERROR_ADDRESSES_MUST_DIFFER: &[u8] = b"addresses must be different";
 pub const ERROR_NOT_A_TRUSTED_MINTER: &[u8] = b"not a trusted minter";
 pub const ERROR_ALREADY_TRUSTED_MINTER: &[u8] = b"minter has already been trusted";
 pub const ERROR_MARKET_SHOULD_BE_ACTIVE: &[u8] = b"market should be active";
 pub const ERROR_MARKET_SHOULD_BE_INACTIVE: &[u8] = b"market should be inactive";
+pub const ERROR_MARKET_SHOULD_NOT_BE_ACTIVE: &[u8] = b"market should not be active";
+pub const ERROR_MARKET_SHOULD_BE_DEPRECATED: &[u8] = b"market should be deprecated";
+pub const ERROR_BORROW_SNAPSHOT_ALREADY_SET: &[u8] = b"borrow snapshot already set";
+pub const ERROR_INVALID_BORROW_INDEX_REBASE: &[u8] = b"invalid borrow index rebase";
 pub const ERROR_INVALID_MARKET_STATE: &[u8] = b"invalid market state";
+pub const ERROR_BORROW_ORDER_NOT_FOUND: &[u8] = b"borrow order not found";
+pub const ERROR_BORROW_ORDER_EXPIRED: &[u8] = b"borrow order has expired";
+pub const ERROR_INVALID_BORROW_ORDER_EXPIRATION: &[u8] = b"invalid borrow order expiration";
+pub const ERROR_INVALID_BORROW_ORDER_RATE: &[u8] = b"invalid borrow order rate";
+pub const ERROR_TOO_MANY_BORROW_ORDERS: &[u8] = b"too many open borrow orders";
+pub const ERROR_ONLY_BORROW_ORDER_OWNER: &[u8] = b"only the borrower can cancel a non expired borrow order";
+pub const ERROR_BORROW_RATE_ABOVE_ORDER_THRESHOLD: &[u8] = b"borrow rate is above the borrow order threshold";
+pub const ERROR_UNDEFINED_RESERVE_STRATEGY: &[u8] = b"undefined reserve strategy";
+pub const ERROR_INVALID_RESERVE_STRATEGY: &[u8] = b"invalid reserve strategy";
+pub const ERROR_RESERVE_STRATEGY_HAS_DEPLOYED_CASH: &[u8] = b"reserve strategy still has deployed cash";
+pub const ERROR_MAX_DEPLOYED_SHARE_TOO_HIGH: &[u8] = b"max deployed share too high";
+pub const ERROR_DEPLOYED_CASH_CAP_EXCEEDED: &[u8] = b"deployed cash cap exceeded";
+pub const ERROR_AMOUNT_EXCEEDS_DEPLOYED_CASH: &[u8] = b"amount exceeds deployed cash";
+pub const ERROR_ENOUGH_ON_HAND_CASH: &[u8] = b"enough on-hand cash, only the admin can recall";
+pub const ERROR_UNDEFINED_PSM_ADAPTER: &[u8] = b"undefined PSM adapter";
+pub const ERROR_INVALID_PSM_ADAPTER: &[u8] = b"invalid PSM adapter";
+pub const ERROR_PSM_ADAPTER_HAS_OUTSTANDING_CREDIT: &[u8] = b"PSM adapter still has outstanding credit";
+pub const ERROR_CREDIT_LINE_RATE_TOO_HIGH: &[u8] = b"credit line rate too high";
+pub const ERROR_CREDIT_LINE_CAP_EXCEEDED: &[u8] = b"credit line cap exceeded";
+pub const ERROR_AMOUNT_EXCEEDS_CREDIT_LINE_DEBT: &[u8] = b"amount exceeds credit line debt";
+pub const ERROR_PSM_ADAPTER_SHORTFALL: &[u8] = b"PSM adapter returned less than requested";
+pub const ERROR_INVALID_HEDGING_HOOK: &[u8] = b"invalid hedging hook";
+pub const ERROR_INVALID_HEDGING_HOOK_GAS_LIMIT: &[u8] = b"invalid hedging hook gas limit";
+pub const ERROR_UNDEFINED_HEDGING_HOOK: &[u8] = b"undefined hedging hook";
+pub const ERROR_UNDEFINED_INTEREST_FORGIVENESS_THRESHOLD: &[u8] = b"undefined interest forgiveness threshold";
+pub const ERROR_BORROW_PRINCIPAL_ALREADY_SET: &[u8] = b"borrow principal already set";
+pub const ERROR_BORROW_PRINCIPAL_EXCEEDS_BORROW_AMOUNT: &[u8] = b"borrow principal exceeds borrow amount";
+pub const ERROR_FORGIVEN_INTEREST_EXCEEDS_REVENUE: &[u8] = b"forgiven interest exceeds money market revenue";
+pub const ERROR_UNREGISTERED_READER: &[u8] = b"unregistered reader";
+pub const ERROR_INVALID_RESERVES_DESTINATION: &[u8] = b"invalid reserves destination";
+pub const ERROR_RESERVES_DESTINATION_ALREADY_SET: &[u8] = b"reserves destination already set";
+pub const ERROR_INVALID_RESERVES_PURPOSE: &[u8] = b"invalid reserves purpose";
+pub const ERROR_NO_RESERVES_WITHDRAWALS: &[u8] = b"no reserves withdrawals";
+pub const ERROR_INVALID_DEPOSIT_TERM: &[u8] = b"invalid deposit term";
+pub const ERROR_TOO_MANY_DEPOSIT_TERMS: &[u8] = b"too many deposit terms";
+pub const ERROR_DEPOSIT_TERM_BONUS_RATE_TOO_HIGH: &[u8] = b"deposit term bonus rate too high";
+pub const ERROR_EARLY_WITHDRAWAL_PENALTY_TOO_HIGH: &[u8] = b"early withdrawal penalty too high";
+pub const ERROR_TOO_MANY_TERM_DEPOSITS: &[u8] = b"too many open term deposits";
+pub const ERROR_TERM_DEPOSIT_NOT_FOUND: &[u8] = b"term deposit not found";
+pub const ERROR_ONLY_TERM_DEPOSIT_OWNER: &[u8] = b"only the owner can withdraw a term deposit";
+pub const ERROR_TERM_DEPOSIT_BONUS_EXCEEDS_REVENUE: &[u8] = b"term deposit bonus exceeds money market revenue";
+pub const ERROR_UNDERLYING_IS_EGLD: &[u8] = b"underlying is EGLD";
+pub const ERROR_UNDERLYING_RESTRICTED: &[u8] = b"underlying has been paused or frozen by its issuer";
+pub const ERROR_UNDERLYING_PROTECTION_ACTIVE: &[u8] = b"underlying protection is active";
+pub const ERROR_NO_PENDING_UNDERLYING: &[u8] = b"no pending underlying";
+pub const ERROR_NO_NEXT_INTEREST_RATE_MODEL: &[u8] = b"no proposed interest rate model";
+pub const ERROR_INTEREST_RATE_MODEL_TIMELOCKED: &[u8] = b"proposed interest rate model still timelocked";
+pub const ERROR_INVALID_INTEREST_RATE_MODEL_TIMELOCK: &[u8] = b"invalid interest rate model timelock";
+pub const ERROR_BORROW_SNAPSHOTS_NOT_COMMITTED: &[u8] = b"borrow snapshots export hash not committed";
+pub const ERROR_BORROW_SNAPSHOTS_IMPORT_STARTED: &[u8] = b"borrow snapshots import already started";
+pub const ERROR_BORROW_SNAPSHOTS_IMPORT_COMPLETED: &[u8] = b"borrow snapshots import already completed";
+pub const ERROR_INVALID_BORROW_SNAPSHOTS_HASH: &[u8] = b"invalid borrow snapshots hash";
+pub const ERROR_BORROW_SNAPSHOTS_IMPORT_INCOMPLETE: &[u8] = b"borrow snapshots import hash does not match the committed export hash";
+pub const ERROR_ONLY_CONTROLLER: &[u8] = b"only the Controller can call this endpoint";
+pub const ERROR_CONTROLLER_MIRROR_NOT_SYNCED: &[u8] = b"Controller mirror not synced yet";
@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use crate::storage::State;
+use crate::storage::{BorrowOrder, HedgingHook, RateSnapshot, State, TermDeposit, TrustedMinterMetadata, UnderlyingTokenControls};
 
 #[multiversx_sc::module]
 pub trait EventsModule {
@@ -16,6 +16,10 @@ pub trait EventsModule {
     #[event("redeem_event")]
     fn redeem_event(&self, #[indexed] redeemer: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] tokens: &BigUint);
 
+    /// Emitted when a user redeems Hatom's tokens and the underlying is sent to a different recipient.
+    #[event("redeem_to_event")]
+    fn redeem_to_event(&self, #[indexed] redeemer: &ManagedAddress, #[indexed] recipient: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] tokens: &BigUint);
+
     /// Event emitted when a user borrows underlying.
     #[event("borrow_event")]
     fn borrow_event(&self, #[indexed] borrower: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] new_account_borrow: &BigUint, #[indexed] new_total_borrows: &BigUint, #[indexed] new_borrower_index: &BigUint);
@@ -24,13 +28,22 @@ pub trait EventsModule {
     #[event("liquidate_borrow_event")]
     fn liquidate_borrow_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] collateral_market: &ManagedAddress, #[indexed] tokens: &BigUint);
 
+    /// Event emitted when a liquidation repayment exceeds the maximum allowed by the close factor and the excess is refunded
+    /// to the liquidator.
+    #[event("liquidation_repayment_refund_event")]
+    fn liquidation_repayment_refund_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] refund_amount: &BigUint);
+
     /// Event emitted when a borrower repays some borrowed underlying.
     #[event("repay_borrow_event")]
     fn repay_borrow_event(&self, #[indexed] payer: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] new_account_borrow: &BigUint, #[indexed] new_total_borrows: &BigUint);
 
     /// Event emitted when interest is accrued on the money market.
     #[event("accrue_interest_event")]
-    fn accrue_interest_event(&self, #[indexed] prev_cash: &BigUint, #[indexed] accumulated_interest: &BigUint, #[indexed] new_borrow_index: &BigUint, #[indexed] new_total_borrows: &BigUint);
+    fn accrue_interest_event(&self, #[indexed] prev_cash: &BigUint, #[indexed] accumulated_interest: &BigUint, #[indexed] new_borrow_index: &BigUint, #[indexed] new_total_borrows: &BigUint, #[indexed] utilization: &BigUint, #[indexed] borrow_rate: &BigUint, #[indexed] supply_rate: &BigUint, #[indexed] delta_reserves: &BigUint, #[indexed] delta_rewards: &BigUint, #[indexed] delta_revenue: &BigUint);
+
+    /// Emitted when a daily interest rate snapshot is taken.
+    #[event("rate_snapshot_event")]
+    fn rate_snapshot_event(&self, #[indexed] rate_snapshot: &RateSnapshot<Self::Api>);
 
     /// Event emitted when market borrow and supply rates are updated.
     #[event("updated_rates_event")]
@@ -44,6 +57,14 @@ pub trait EventsModule {
     #[event("new_stake_factor_event")]
     fn new_stake_factor_event(&self, #[indexed] old_stake_factor: &BigUint, #[indexed] new_stake_factor: &BigUint);
 
+    /// Event emitted when the redeem fee is updated.
+    #[event("new_redeem_fee_event")]
+    fn new_redeem_fee_event(&self, #[indexed] old_redeem_fee: &BigUint, #[indexed] new_redeem_fee: &BigUint);
+
+    /// Event emitted when a redeem fee is charged and credited to reserves.
+    #[event("redeem_fee_event")]
+    fn redeem_fee_event(&self, #[indexed] redeemer: &ManagedAddress, #[indexed] fee: &BigUint, #[indexed] new_total_reserves: &BigUint);
+
     /// Event emitted when the controller address is updated.
     #[event("new_controller_event")]
     fn new_controller_event(&self, #[indexed] old_address: &Option<ManagedAddress>, #[indexed] new_address: &ManagedAddress);
@@ -80,6 +101,14 @@ pub trait EventsModule {
     #[event("reserves_reduced_event")]
     fn reserves_reduced_event(&self, #[indexed] admin: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] new: &BigUint);
 
+    /// Event emitted when the underlying balance surplus over the internal cash is quarantined as donations.
+    #[event("donations_quarantined_event")]
+    fn donations_quarantined_event(&self, #[indexed] balance: &BigUint, #[indexed] cash: &BigUint, #[indexed] amount: &BigUint, #[indexed] new: &BigUint);
+
+    /// Event emitted when donations are claimed.
+    #[event("donations_claimed_event")]
+    fn donations_claimed_event(&self, #[indexed] admin: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] new: &BigUint);
+
     /// Event emitted when staking rewards are claimed.
     #[event("staking_rewards_claimed_event")]
     fn staking_rewards_claimed_event(&self, #[indexed] staking_sc: &ManagedAddress, #[indexed] amount: &BigUint);
@@ -96,6 +125,58 @@ pub trait EventsModule {
     #[event("new_protocol_seize_share_event")]
     fn new_protocol_seize_share_event(&self, #[indexed] old_protocol_seize_share: &BigUint, #[indexed] new_protocol_seize_share: &BigUint);
 
+    /// Event emitted when the insurance fund or its share of the protocol seized amount is updated.
+    #[event("new_insurance_fund_event")]
+    fn new_insurance_fund_event(&self, #[indexed] old_address: &Option<ManagedAddress>, #[indexed] new_address: &ManagedAddress, #[indexed] old_share: &BigUint, #[indexed] new_share: &BigUint);
+
+    /// Event emitted when the reserve strategy or its maximum deployed share is updated.
+    #[event("new_reserve_strategy_event")]
+    fn new_reserve_strategy_event(&self, #[indexed] old_address: &Option<ManagedAddress>, #[indexed] new_address: &ManagedAddress, #[indexed] old_max_share: &BigUint, #[indexed] new_max_share: &BigUint);
+
+    /// Event emitted when cash is deployed into the reserve strategy.
+    #[event("cash_deployed_event")]
+    fn cash_deployed_event(&self, #[indexed] strategy: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] deployed_cash: &BigUint);
+
+    /// Event emitted when cash is recalled from the reserve strategy.
+    #[event("cash_recalled_event")]
+    fn cash_recalled_event(&self, #[indexed] strategy: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] strategy_yield: &BigUint, #[indexed] deployed_cash: &BigUint);
+
+    /// Event emitted when a loss of deployed cash is realized.
+    #[event("strategy_loss_event")]
+    fn strategy_loss_event(&self, #[indexed] strategy: &ManagedAddress, #[indexed] loss: &BigUint, #[indexed] reserves_loss: &BigUint, #[indexed] supply_loss: &BigUint);
+
+    /// Event emitted when the PSM adapter is updated.
+    #[event("new_psm_adapter_event")]
+    fn new_psm_adapter_event(&self, #[indexed] old_address: &Option<ManagedAddress>, #[indexed] new_address: &ManagedAddress);
+
+    /// Event emitted when the credit line cap or rate is updated.
+    #[event("new_credit_line_event")]
+    fn new_credit_line_event(&self, #[indexed] old_cap: &BigUint, #[indexed] new_cap: &BigUint, #[indexed] old_rate: &BigUint, #[indexed] new_rate: &BigUint);
+
+    /// Event emitted when cash is lent to the USH peg-stability module.
+    #[event("credit_line_lend_event")]
+    fn credit_line_lend_event(&self, #[indexed] psm_adapter: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] principal: &BigUint);
+
+    /// Event emitted when credit line interest is accrued.
+    #[event("credit_line_accrue_event")]
+    fn credit_line_accrue_event(&self, #[indexed] delta_interest: &BigUint, #[indexed] interest: &BigUint);
+
+    /// Event emitted when cash is recalled from the USH peg-stability module.
+    #[event("credit_line_recall_event")]
+    fn credit_line_recall_event(&self, #[indexed] psm_adapter: &ManagedAddress, #[indexed] interest_paid: &BigUint, #[indexed] principal_paid: &BigUint, #[indexed] principal: &BigUint);
+
+    /// Event emitted when the unpaid credit line principal is written off as a loss.
+    #[event("credit_line_loss_event")]
+    fn credit_line_loss_event(&self, #[indexed] psm_adapter: &ManagedAddress, #[indexed] loss: &BigUint, #[indexed] reserves_loss: &BigUint, #[indexed] supply_loss: &BigUint);
+
+    /// Event emitted when the protocol seized amount is split between the insurance fund and the reserves.
+    #[event("protocol_seize_split_event")]
+    fn protocol_seize_split_event(&self, #[indexed] insurance_fund: &ManagedAddress, #[indexed] insurance_fund_amount: &BigUint, #[indexed] reserves_amount: &BigUint);
+
+    /// Event emitted when the accrued insurance fund balance is paid out.
+    #[event("insurance_fund_claimed_event")]
+    fn insurance_fund_claimed_event(&self, #[indexed] insurance_fund: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] remaining_balance: &BigUint);
+
     /// Event emitted when underlying id is set.
     #[event("set_underlying_id_event")]
     fn set_underlying_id_event(&self, #[indexed] underlying_id: &EgldOrEsdtTokenIdentifier);
@@ -112,6 +193,18 @@ pub trait EventsModule {
     #[event("set_accrual_time_threshold_event")]
     fn set_accrual_time_threshold_event(&self, #[indexed] old_accrual_time_threshold: u64, #[indexed] new_accrual_time_threshold: u64);
 
+    /// Event emitted when the maximum accrual gap is updated.
+    #[event("set_max_accrual_gap_event")]
+    fn set_max_accrual_gap_event(&self, #[indexed] old_max_accrual_gap: u64, #[indexed] new_max_accrual_gap: u64);
+
+    /// Event emitted when an abnormal gap between the last accrual and the current block timestamp is detected.
+    #[event("abnormal_accrual_gap_event")]
+    fn abnormal_accrual_gap_event(&self, #[indexed] accrual_timestamp: u64, #[indexed] timestamp: u64);
+
+    /// Event emitted when governance resolves the pending abnormal accrual gap, either charging or skipping its interest.
+    #[event("accrual_gap_acknowledged_event")]
+    fn accrual_gap_acknowledged_event(&self, #[indexed] accrual_gap_timestamp: u64, #[indexed] accrual_gap_duration: u64, #[indexed] charge: bool, delta_borrows: &BigUint);
+
     /// Emitted when a trusted minter is added.
     #[event("add_trusted_minter_event")]
     fn add_trusted_minter_event(&self, #[indexed] minter: &ManagedAddress);
@@ -119,4 +212,124 @@ pub trait EventsModule {
     /// Emitted when a trusted minter is removed.
     #[event("remove_trusted_minter_event")]
     fn remove_trusted_minter_event(&self, #[indexed] minter: &ManagedAddress);
+
+    /// Emitted when the metadata of a trusted minter is set.
+    #[event("trusted_minter_metadata_event")]
+    fn trusted_minter_metadata_event(&self, #[indexed] minter: &ManagedAddress, #[indexed] metadata: &TrustedMinterMetadata<Self::Api>);
+
+    /// Emitted when a borrow order is placed.
+    #[event("borrow_order_placed_event")]
+    fn borrow_order_placed_event(&self, #[indexed] borrow_order: &BorrowOrder<Self::Api>);
+
+    /// Emitted when a borrow order is cancelled.
+    #[event("borrow_order_cancelled_event")]
+    fn borrow_order_cancelled_event(&self, #[indexed] caller: &ManagedAddress, #[indexed] borrow_order: &BorrowOrder<Self::Api>);
+
+    /// Emitted when a borrow order is executed.
+    #[event("borrow_order_executed_event")]
+    fn borrow_order_executed_event(&self, #[indexed] executor: &ManagedAddress, #[indexed] borrow_order: &BorrowOrder<Self::Api>, #[indexed] borrow_rate: &BigUint);
+
+    /// Emitted when a page of borrow snapshots is exported.
+    #[event("borrow_snapshots_exported_event")]
+    fn borrow_snapshots_exported_event(&self, #[indexed] count: usize, #[indexed] hash: &ManagedBuffer);
+
+    /// Emitted when the final export hash of the borrow snapshots to be imported is committed.
+    #[event("borrow_snapshots_committed_event")]
+    fn borrow_snapshots_committed_event(&self, #[indexed] export_hash: &ManagedBuffer);
+
+    /// Emitted when a page of borrow snapshots is imported.
+    #[event("borrow_snapshots_imported_event")]
+    fn borrow_snapshots_imported_event(&self, #[indexed] count: usize, #[indexed] hash: &ManagedBuffer);
+
+    /// Emitted when the borrow index is rebased.
+    #[event("borrow_index_rebased_event")]
+    fn borrow_index_rebased_event(&self, #[indexed] divisor: &BigUint, #[indexed] old_index: &BigUint, #[indexed] new_index: &BigUint);
+
+    /// Emitted when a page of borrow snapshots is migrated to the latest borrow index rebase.
+    #[event("borrow_snapshots_rebased_event")]
+    fn borrow_snapshots_rebased_event(&self, #[indexed] count: usize);
+
+    /// Emitted when a page of borrowers is indexed.
+    #[event("borrowers_indexed_event")]
+    fn borrowers_indexed_event(&self, #[indexed] count: usize);
+
+    /// Emitted when an account sets its hedging hook.
+    #[event("set_hedging_hook_event")]
+    fn set_hedging_hook_event(&self, #[indexed] account: &ManagedAddress, #[indexed] hedging_hook: &HedgingHook<Self::Api>);
+
+    /// Emitted when an account removes its hedging hook.
+    #[event("remove_hedging_hook_event")]
+    fn remove_hedging_hook_event(&self, #[indexed] account: &ManagedAddress);
+
+    /// Emitted when a hedging hook is notified of an account borrow amount change.
+    #[event("hedging_hook_notified_event")]
+    fn hedging_hook_notified_event(&self, #[indexed] account: &ManagedAddress, #[indexed] hook: &ManagedAddress, #[indexed] old_borrow_amount: &BigUint, #[indexed] new_borrow_amount: &BigUint);
+
+    /// Emitted when the interest forgiveness threshold is updated.
+    #[event("set_interest_forgiveness_threshold_event")]
+    fn set_interest_forgiveness_threshold_event(&self, #[indexed] old_threshold: &BigUint, #[indexed] new_threshold: &BigUint);
+
+    /// Emitted when a page of borrow principals is imported.
+    #[event("borrow_principals_imported_event")]
+    fn borrow_principals_imported_event(&self, #[indexed] count: usize);
+
+    /// Emitted when the accrued interest of a borrower is forgiven.
+    #[event("interest_forgiven_event")]
+    fn interest_forgiven_event(&self, #[indexed] borrower: &ManagedAddress, #[indexed] interest: &BigUint, #[indexed] new_account_borrow: &BigUint, #[indexed] new_total_borrows: &BigUint);
+
+    /// Emitted when a reserves destination is added.
+    #[event("add_reserves_destination_event")]
+    fn add_reserves_destination_event(&self, #[indexed] destination: &ManagedAddress);
+
+    /// Emitted when a reserves destination is removed.
+    #[event("remove_reserves_destination_event")]
+    fn remove_reserves_destination_event(&self, #[indexed] destination: &ManagedAddress);
+
+    /// Emitted when reserves are withdrawn to a configured destination with a purpose tag.
+    #[event("reserves_withdrawn_with_purpose_event")]
+    fn reserves_withdrawn_with_purpose_event(&self, #[indexed] destination: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] purpose: &ManagedBuffer);
+
+    /// Emitted when a deposit term is added or its bonus rate is updated.
+    #[event("set_deposit_term_event")]
+    fn set_deposit_term_event(&self, #[indexed] term: u64, #[indexed] old_bonus_rate: &BigUint, #[indexed] new_bonus_rate: &BigUint);
+
+    /// Emitted when a deposit term is removed.
+    #[event("remove_deposit_term_event")]
+    fn remove_deposit_term_event(&self, #[indexed] term: u64);
+
+    /// Emitted when the early withdrawal penalty of term deposits is updated.
+    #[event("new_early_withdrawal_penalty_event")]
+    fn new_early_withdrawal_penalty_event(&self, #[indexed] old_penalty: &BigUint, #[indexed] new_penalty: &BigUint);
+
+    /// Emitted when a term deposit is opened.
+    #[event("term_deposit_opened_event")]
+    fn term_deposit_opened_event(&self, #[indexed] term_deposit: &TermDeposit<Self::Api>);
+
+    /// Emitted when a term deposit is withdrawn, either at or before maturity.
+    #[event("term_deposit_withdrawn_event")]
+    fn term_deposit_withdrawn_event(&self, #[indexed] term_deposit: &TermDeposit<Self::Api>, #[indexed] underlying_amount: &BigUint, #[indexed] bonus: &BigUint, #[indexed] penalty: &BigUint);
+
+    /// Emitted when the underlying protection is activated or lifted because of issuer controls on the underlying token.
+    #[event("underlying_protection_event")]
+    fn underlying_protection_event(&self, #[indexed] active: bool, #[indexed] controls: &UnderlyingTokenControls);
+
+    /// Emitted when an underlying transfer is deferred because of issuer controls on the underlying token.
+    #[event("underlying_transfer_deferred_event")]
+    fn underlying_transfer_deferred_event(&self, #[indexed] to: &ManagedAddress, #[indexed] amount: &BigUint);
+
+    /// Emitted when an account claims its pending underlying.
+    #[event("pending_underlying_claimed_event")]
+    fn pending_underlying_claimed_event(&self, #[indexed] account: &ManagedAddress, #[indexed] amount: &BigUint);
+
+    /// Emitted when a new Interest Rate Model is proposed.
+    #[event("new_next_interest_rate_model_event")]
+    fn new_next_interest_rate_model_event(&self, #[indexed] timestamp: u64, #[indexed] next_interest_rate_model: &ManagedAddress);
+
+    /// Emitted when the proposed Interest Rate Model is cleared, either because it has been set or cancelled.
+    #[event("clear_next_interest_rate_model_event")]
+    fn clear_next_interest_rate_model_event(&self);
+
+    /// Emitted when the interest rate model timelock is updated.
+    #[event("new_interest_rate_model_timelock_event")]
+    fn new_interest_rate_model_timelock_event(&self, #[indexed] old_timelock: u64, #[indexed] new_timelock: u64);
 }
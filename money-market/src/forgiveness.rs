@@ -0,0 +1,138 @@
+multiversx_sc::imports!();
+
+use super::{common, errors::*, events, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait ForgivenessModule: admin::AdminModule + common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Sets the interest forgiveness threshold, i.e. the maximum debt a borrower can have to get its accrued interest
+    /// forgiven.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_threshold` - The new threshold in underlying. Zero disables the interest forgiveness program.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(setInterestForgivenessThreshold)]
+    fn set_interest_forgiveness_threshold(&self, new_threshold: &BigUint) {
+        self.require_admin();
+
+        let old_threshold = self.interest_forgiveness_threshold().get();
+        self.interest_forgiveness_threshold().set(new_threshold);
+
+        self.set_interest_forgiveness_threshold_event(&old_threshold, new_threshold);
+    }
+
+    /// Imports the borrow principals of borrowers that opened their positions before principals were tracked, such that
+    /// they become eligible for interest forgiveness.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_principals` - A page of borrower addresses and borrow principals.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should be deprecated at the Controller.
+    /// - Borrowers cannot have a borrow principal already.
+    /// - The borrow principal cannot exceed the current borrow amount.
+    ///
+    #[endpoint(importBorrowPrincipals)]
+    fn import_borrow_principals(&self, borrow_principals: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>) {
+        self.require_admin();
+
+        let money_market = self.blockchain().get_sc_address();
+        require!(self.is_deprecated(&money_market), ERROR_MARKET_SHOULD_BE_DEPRECATED);
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let mut count = 0usize;
+        for borrow_principal in borrow_principals.into_iter() {
+            let (borrower, principal) = borrow_principal.into_tuple();
+            require!(self.account_borrow_principal(&borrower).is_empty(), ERROR_BORROW_PRINCIPAL_ALREADY_SET);
+            require!(principal <= self.get_account_borrow_amount(&borrower), ERROR_BORROW_PRINCIPAL_EXCEEDS_BORROW_AMOUNT);
+
+            self.account_borrow_principal(&borrower).set(&principal);
+            count += 1;
+        }
+
+        self.borrow_principals_imported_event(count);
+    }
+
+    /// Forgives the accrued interest (not the principal) of borrowers whose debt is below the interest forgiveness
+    /// threshold. The forgiven interest is funded from the revenue part of the reserves, such that the exchange rate remains
+    /// unchanged.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should be deprecated at the Controller.
+    /// - Borrowers without a tracked borrow principal, without accrued interest or above the threshold are skipped.
+    /// - The total forgiven interest of the page cannot exceed the revenue.
+    ///
+    #[endpoint(forgiveInterest)]
+    fn forgive_interest(&self, borrowers: MultiValueEncoded<ManagedAddress>) -> BigUint {
+        self.require_admin();
+
+        let money_market = self.blockchain().get_sc_address();
+        require!(self.is_deprecated(&money_market), ERROR_MARKET_SHOULD_BE_DEPRECATED);
+
+        let threshold = self.interest_forgiveness_threshold().get();
+        require!(threshold > BigUint::zero(), ERROR_UNDEFINED_INTEREST_FORGIVENESS_THRESHOLD);
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let borrow_index = self.get_borrow_index();
+        let mut total_borrows = self.total_borrows().get();
+        let mut forgiven_interest = BigUint::zero();
+        for borrower in borrowers.into_iter() {
+            if self.account_borrow_principal(&borrower).is_empty() {
+                continue;
+            }
+
+            let borrow_amount = self.get_account_borrow_amount(&borrower);
+            if borrow_amount == BigUint::zero() || borrow_amount > threshold {
+                continue;
+            }
+
+            let principal = self.account_borrow_principal(&borrower).get();
+            if borrow_amount <= principal {
+                continue;
+            }
+
+            // total borrows might be slightly below the sum of all account borrows because of truncation errors
+            let interest = BigUint::min(&borrow_amount - &principal, total_borrows.clone());
+            total_borrows -= &interest;
+            forgiven_interest += &interest;
+
+            let new_borrow_amount = &borrow_amount - &interest;
+            self.set_account_borrow_snapshot(&borrower, &new_borrow_amount, &borrow_index);
+            self.track_repaid_principal(&borrower, &new_borrow_amount);
+
+            if new_borrow_amount == BigUint::zero() {
+                self.try_remove_account_market(&money_market, &borrower);
+            }
+
+            self.interest_forgiven_event(&borrower, &interest, &new_borrow_amount, &total_borrows);
+        }
+
+        require!(forgiven_interest <= self.revenue().get(), ERROR_FORGIVEN_INTEREST_EXCEEDS_REVENUE);
+
+        self.total_borrows().set(&total_borrows);
+        self.total_reserves().update(|amount| *amount -= &forgiven_interest);
+        self.revenue().update(|amount| *amount -= &forgiven_interest);
+        self.total_forgiven_interest().update(|amount| *amount += &forgiven_interest);
+
+        self.emit_updated_rates();
+
+        forgiven_interest
+    }
+}
@@ -1,9 +1,9 @@
 multiversx_sc::imports!();
 
-use super::{common, constants::*, errors::*, events, proxies, storage};
+use super::{common, constants::*, errors::*, events, proxies, storage, token_controls};
 
 #[multiversx_sc::module]
-pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::EventsModule + storage::StorageModule + proxies::ProxyModule {
+pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::EventsModule + storage::StorageModule + proxies::ProxyModule + token_controls::TokenControlsModule {
     /// Sets the staking smart contract address.
     ///
     /// # Arguments:
@@ -75,6 +75,29 @@ pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::
         self.new_stake_factor_event(&old_stake_factor, new_stake_factor);
     }
 
+    /// Sets a new redeem fee, i.e. the portion of the redeemed underlying that is credited to reserves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_redeem_fee` - The new redeem fee in bps.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The new redeem fee must not exceed the maximum allowed.
+    ///
+    #[endpoint(setRedeemFee)]
+    fn set_redeem_fee(&self, new_redeem_fee: &BigUint) {
+        self.require_admin();
+
+        require!(new_redeem_fee <= &BigUint::from(MAX_REDEEM_FEE), ERROR_REDEEM_FEE_TOO_HIGH);
+
+        let old_redeem_fee = self.redeem_fee().get();
+        self.redeem_fee().set(new_redeem_fee);
+
+        self.new_redeem_fee_event(&old_redeem_fee, new_redeem_fee);
+    }
+
     /// Sets a new close factor used at liquidations.
     ///
     /// # Arguments:
@@ -156,7 +179,76 @@ pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::
         self.new_protocol_seize_share_event(&old_protocol_seize_share, new_protocol_seize_share);
     }
 
-    /// Sets a new Interest Rate Model.
+    /// Sets the insurance fund address and the share of the protocol seized amount that is routed to it, i.e. instead of
+    /// staying in the money market reserves.
+    ///
+    /// # Arguments
+    ///
+    /// - `new_insurance_fund` - the insurance fund address
+    /// - `new_share` - the share of the protocol seized amount routed to the insurance fund in wad
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - the share cannot exceed 100%
+    ///
+    #[endpoint(setInsuranceFund)]
+    fn set_insurance_fund(&self, new_insurance_fund: &ManagedAddress, new_share: &BigUint) {
+        self.require_admin();
+
+        require!(!new_insurance_fund.is_zero(), ERROR_CANNOT_BE_ADDRESS_ZERO);
+        require!(new_share <= &BigUint::from(WAD), ERROR_INSURANCE_FUND_SHARE_TOO_HIGH);
+
+        let old_insurance_fund = self.get_insurance_fund();
+        let old_share = self.insurance_fund_share().get();
+
+        self.insurance_fund().set(new_insurance_fund);
+        self.insurance_fund_share().set(new_share);
+
+        self.new_insurance_fund_event(&old_insurance_fund, new_insurance_fund, &old_share, new_share);
+    }
+
+    /// Pays out the underlying amount accrued for the insurance fund during liquidations.
+    ///
+    /// # Notes
+    ///
+    /// - can be called by anyone
+    /// - the payout is capped to the money market cash, such that any remainder stays accrued for a later claim
+    ///
+    #[endpoint(claimInsuranceFund)]
+    fn claim_insurance_fund(&self) -> BigUint {
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let insurance_fund = match self.get_insurance_fund() {
+            Some(insurance_fund) => insurance_fund,
+            None => sc_panic!(ERROR_INSURANCE_FUND_NOT_SET),
+        };
+
+        let balance = self.insurance_fund_balance().get();
+        let cash = self.get_on_hand_cash();
+        let amount = BigUint::min(balance, cash);
+
+        if amount == BigUint::zero() {
+            return amount;
+        }
+
+        self.insurance_fund_balance().update(|balance| *balance -= &amount);
+        self.total_reserves().update(|reserves| *reserves -= &amount);
+        self.cash().update(|cash| *cash -= &amount);
+
+        self.send_underlying(&insurance_fund, &amount);
+
+        let remaining_balance = self.insurance_fund_balance().get();
+        self.insurance_fund_claimed_event(&insurance_fund, &amount, &remaining_balance);
+
+        self.emit_updated_rates();
+
+        amount
+    }
+
+    /// Proposes a new Interest Rate Model, which can be set after the interest rate model timelock using
+    /// `executeInterestRateModel`. This protects lenders against sudden rate regime changes.
     ///
     /// # Arguments:
     ///
@@ -166,11 +258,84 @@ pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::
     ///
     /// - Can only be called by the admin.
     /// - The provided address must be a valid Interest Rate Model smart contract.
+    /// - Replaces any previously proposed Interest Rate Model.
     ///
-    #[endpoint(setInterestRateModel)]
-    fn set_interest_rate_model(&self, new_interest_rate_model: &ManagedAddress) {
+    #[endpoint(proposeInterestRateModel)]
+    fn propose_interest_rate_model(&self, new_interest_rate_model: &ManagedAddress) {
         self.require_admin();
-        self.set_interest_rate_model_internal(new_interest_rate_model);
+        require!(self.is_interest_rate_model_sc(new_interest_rate_model), ERROR_NON_VALID_INTEREST_RATE_MODEL_SC);
+
+        let timestamp = self.blockchain().get_block_timestamp() + self.get_interest_rate_model_timelock();
+        self.next_interest_rate_model().set((timestamp, new_interest_rate_model.clone()));
+        self.new_next_interest_rate_model_event(timestamp, new_interest_rate_model);
+    }
+
+    /// Sets the proposed Interest Rate Model once its timelock has elapsed.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    ///
+    #[endpoint(executeInterestRateModel)]
+    fn execute_interest_rate_model(&self) {
+        require!(!self.next_interest_rate_model().is_empty(), ERROR_NO_NEXT_INTEREST_RATE_MODEL);
+
+        let (timestamp, new_interest_rate_model) = self.next_interest_rate_model().get();
+        require!(self.blockchain().get_block_timestamp() >= timestamp, ERROR_INTEREST_RATE_MODEL_TIMELOCKED);
+
+        self.next_interest_rate_model().clear();
+        self.clear_next_interest_rate_model_event();
+
+        self.set_interest_rate_model_internal(&new_interest_rate_model);
+    }
+
+    /// Cancels the proposed Interest Rate Model.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(cancelInterestRateModel)]
+    fn cancel_interest_rate_model(&self) {
+        self.require_admin();
+        require!(!self.next_interest_rate_model().is_empty(), ERROR_NO_NEXT_INTEREST_RATE_MODEL);
+        self.next_interest_rate_model().clear();
+        self.clear_next_interest_rate_model_event();
+    }
+
+    /// Sets the timelock that must elapse between proposing and setting a new Interest Rate Model.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_timelock` - The new timelock in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Must be between `MIN_INTEREST_RATE_MODEL_TIMELOCK` and `MAX_INTEREST_RATE_MODEL_TIMELOCK`.
+    /// - Does not affect an already proposed Interest Rate Model.
+    ///
+    #[endpoint(setInterestRateModelTimelock)]
+    fn set_interest_rate_model_timelock(&self, new_timelock: u64) {
+        self.require_admin();
+        require!((MIN_INTEREST_RATE_MODEL_TIMELOCK..=MAX_INTEREST_RATE_MODEL_TIMELOCK).contains(&new_timelock), ERROR_INVALID_INTEREST_RATE_MODEL_TIMELOCK);
+
+        let old_timelock = self.get_interest_rate_model_timelock();
+        self.interest_rate_model_timelock().set(new_timelock);
+
+        self.new_interest_rate_model_timelock_event(old_timelock, new_timelock);
+    }
+
+    /// Returns the timelock that must elapse between proposing and setting a new Interest Rate Model, which defaults to
+    /// `MIN_INTEREST_RATE_MODEL_TIMELOCK`.
+    ///
+    #[view(getInterestRateModelTimelock)]
+    fn get_interest_rate_model_timelock(&self) -> u64 {
+        if self.interest_rate_model_timelock().is_empty() {
+            MIN_INTEREST_RATE_MODEL_TIMELOCK
+        } else {
+            self.interest_rate_model_timelock().get()
+        }
     }
 
     /// Withdraws an specified amount of underlying from the money market reserves (revenue part) to the admin account.
@@ -191,27 +356,137 @@ pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::
         self.accrue_interest();
         self.require_market_fresh();
 
+        let underlying_amount = opt_underlying_amount.into_option().unwrap_or_else(|| self.revenue().get());
+
+        let admin = self.get_admin();
+        self.reduce_reserves_internal(&admin, &underlying_amount);
+
+        self.emit_updated_rates();
+    }
+
+    /// Withdraws several amounts of underlying from the money market reserves (revenue part) to configured destinations,
+    /// each of them tagged with a purpose, such that a single governance action leaves an auditable purpose trail.
+    ///
+    /// # Arguments:
+    ///
+    /// - `withdrawals` - The (destination, underlying amount, purpose tag) entries.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Each destination must have been configured with `addReservesDestination`.
+    /// - Each purpose tag must be non-empty and not longer than `MAX_RESERVES_PURPOSE_LENGTH`.
+    ///
+    #[endpoint(reduceReservesTo)]
+    fn reduce_reserves_to(&self, withdrawals: MultiValueEncoded<MultiValue3<ManagedAddress, BigUint, ManagedBuffer>>) {
+        self.require_admin();
+        require!(!withdrawals.is_empty(), ERROR_NO_RESERVES_WITHDRAWALS);
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        for withdrawal in withdrawals.into_iter() {
+            let (destination, underlying_amount, purpose) = withdrawal.into_tuple();
+            self.require_valid_reserves_withdrawal(&destination, &purpose);
+            self.reduce_reserves_internal(&destination, &underlying_amount);
+            self.reserves_withdrawn_with_purpose_event(&destination, &underlying_amount, &purpose);
+        }
+
+        self.emit_updated_rates();
+    }
+
+    fn reduce_reserves_internal(&self, to: &ManagedAddress, underlying_amount: &BigUint) {
+        require!(underlying_amount > &BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        self.try_ensure_staking_rewards(underlying_amount);
+
         let revenue = self.revenue().get();
-        let underlying_amount = opt_underlying_amount.into_option().unwrap_or_else(|| revenue.clone());
+        require!(underlying_amount <= &revenue, ERROR_AMOUNT_EXCEEDS_REVENUE);
 
-        require!(underlying_amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        self.total_reserves().update(|amount| *amount -= underlying_amount);
+        self.revenue().update(|amount| *amount -= underlying_amount);
+        self.cash().update(|amount| *amount -= underlying_amount);
+
+        let new_total_reserves = self.total_reserves().get();
 
-        self.try_ensure_staking_rewards(&underlying_amount);
+        self.send_underlying(to, underlying_amount);
 
-        require!(underlying_amount <= revenue, ERROR_AMOUNT_EXCEEDS_REVENUE);
+        self.reserves_reduced_event(to, underlying_amount, &new_total_reserves);
+    }
 
-        self.total_reserves().update(|amount| *amount -= &underlying_amount);
-        self.revenue().update(|amount| *amount -= &underlying_amount);
-        self.cash().update(|amount| *amount -= &underlying_amount);
+    /// Adds a destination to which reserves can be withdrawn with a purpose tag, such as the insurance pool, the treasury or
+    /// a buyback program.
+    ///
+    /// # Arguments:
+    ///
+    /// - `destination` - The destination address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(addReservesDestination)]
+    fn add_reserves_destination(&self, destination: &ManagedAddress) {
+        self.require_admin();
+        require!(!destination.is_zero(), ERROR_INVALID_RESERVES_DESTINATION);
+        require!(self.reserves_destinations().insert(destination.clone()), ERROR_RESERVES_DESTINATION_ALREADY_SET);
+        self.add_reserves_destination_event(destination);
+    }
+
+    /// Removes a destination to which reserves can be withdrawn with a purpose tag.
+    ///
+    /// # Arguments:
+    ///
+    /// - `destination` - The destination address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(removeReservesDestination)]
+    fn remove_reserves_destination(&self, destination: &ManagedAddress) {
+        self.require_admin();
+        require!(self.reserves_destinations().swap_remove(destination), ERROR_INVALID_RESERVES_DESTINATION);
+        self.remove_reserves_destination_event(destination);
+    }
+
+    /// Requires that a reserves withdrawal is directed to a configured destination and is tagged with a valid purpose.
+    ///
+    fn require_valid_reserves_withdrawal(&self, destination: &ManagedAddress, purpose: &ManagedBuffer) {
+        require!(self.reserves_destinations().contains(destination), ERROR_INVALID_RESERVES_DESTINATION);
+        require!(!purpose.is_empty() && purpose.len() <= MAX_RESERVES_PURPOSE_LENGTH, ERROR_INVALID_RESERVES_PURPOSE);
+    }
+
+    /// Withdraws an specified amount of underlying from the donations bucket to the admin account.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_underlying_amount` - The amount of underlying to withdraw. If not provided, all donations are withdrawn.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The underlying amount is directed to the admin account.
+    /// - Donations never belong to cash, so withdrawing them does not change the exchange rate.
+    ///
+    #[endpoint(claimDonations)]
+    fn claim_donations(&self, opt_underlying_amount: OptionalValue<BigUint>) {
+        self.require_admin();
+
+        let donations = self.donations().get();
+        let underlying_amount = opt_underlying_amount.into_option().unwrap_or_else(|| donations.clone());
+
+        require!(underlying_amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(underlying_amount <= donations, ERROR_AMOUNT_EXCEEDS_DONATIONS);
+
+        let new_donations = donations - &underlying_amount;
+        self.donations().set(&new_donations);
 
         let admin = self.get_admin();
-        let underlying_id = self.underlying_id().get();
-        let new_total_reserves = self.total_reserves().get();
 
-        self.send().direct(&admin, &underlying_id, 0, &underlying_amount);
+        self.send_underlying(&admin, &underlying_amount);
 
-        self.emit_updated_rates();
-        self.reserves_reduced_event(&admin, &underlying_amount, &new_total_reserves);
+        self.donations_claimed_event(&admin, &underlying_amount, &new_donations);
     }
 
     /// Sets a new accrual time threshold.
@@ -239,6 +514,67 @@ pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::
         self.set_accrual_time_threshold_event(old_accrual_time_threshold, new_accrual_time_threshold);
     }
 
+    /// Sets a new maximum accrual gap, i.e. the maximum time allowed between accruals before the gap is considered abnormal,
+    /// in which case no interest is charged for it and borrows are restricted for a while.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_max_accrual_gap` - The new maximum accrual gap in seconds. Zero disables the check.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(setMaxAccrualGap)]
+    fn set_max_accrual_gap(&self, new_max_accrual_gap: u64) {
+        self.require_admin();
+
+        require!(new_max_accrual_gap == 0 || new_max_accrual_gap >= MIN_MAX_ACCRUAL_GAP, ERROR_MAX_ACCRUAL_GAP_TOO_LOW);
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let old_max_accrual_gap = self.max_accrual_gap().get();
+        self.max_accrual_gap().set(new_max_accrual_gap);
+
+        self.set_max_accrual_gap_event(old_max_accrual_gap, new_max_accrual_gap);
+    }
+
+    /// Resolves the pending abnormal accrual gap, which lifts the borrows restriction. Governance explicitly decides whether
+    /// the interest of the gap, computed with the borrow rate in place when it was recorded, is charged or skipped.
+    ///
+    /// # Arguments:
+    ///
+    /// - `charge` - Whether the interest of the pending gap is charged to borrowers.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrows remain restricted until the pending gap is resolved, while repayments and liquidations are not.
+    ///
+    #[endpoint(acknowledgeAccrualGap)]
+    fn acknowledge_accrual_gap(&self, charge: bool) {
+        self.require_admin();
+
+        // a pending gap is recorded first
+        self.accrue_interest();
+
+        require!(!self.accrual_gap_timestamp().is_empty(), ERROR_NO_ABNORMAL_ACCRUAL_GAP);
+
+        let accrual_gap_timestamp = self.accrual_gap_timestamp().take();
+        let accrual_gap_duration = self.accrual_gap_duration().take();
+        let accrual_gap_interest_factor = self.accrual_gap_interest_factor().take();
+
+        let delta_borrows = if charge {
+            let (delta_borrows, ..) = self.accumulate_interest(&accrual_gap_interest_factor);
+            delta_borrows
+        } else {
+            BigUint::zero()
+        };
+
+        self.accrual_gap_acknowledged_event(accrual_gap_timestamp, accrual_gap_duration, charge, &delta_borrows);
+    }
+
     /// Whitelists a trusted minter contract, i.e. a contract that can mint and enter market in the name of someone else.
     ///
     /// # Arguments:
@@ -254,10 +590,70 @@ pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::
     #[endpoint(addTrustedMinter)]
     fn add_trusted_minter(&self, trusted_minter: ManagedAddress) {
         self.require_admin();
-        self.require_not_trusted_minter(&trusted_minter);
-        require!(self.is_trusted_minter_sc(&trusted_minter), ERROR_NON_VALID_TRUSTED_MINTER_SC);
-        self.trusted_minters_list().add(&trusted_minter);
-        self.add_trusted_minter_event(&trusted_minter);
+        self.add_trusted_minter_internal(&trusted_minter, &ManagedBuffer::new(), 0u8);
+    }
+
+    /// Whitelists a batch of trusted minter contracts, together with their metadata.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minters` - the new trusted minters to whitelist, each one with its name hash and interface version
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - each trusted minter must be a trusted smart contract
+    /// - each trusted minter must not be already trusted
+    ///
+    #[endpoint(addTrustedMinters)]
+    fn add_trusted_minters(&self, trusted_minters: MultiValueEncoded<MultiValue3<ManagedAddress, ManagedBuffer, u8>>) {
+        self.require_admin();
+        for trusted_minter in trusted_minters.into_iter() {
+            let (trusted_minter, name_hash, version) = trusted_minter.into_tuple();
+            self.add_trusted_minter_internal(&trusted_minter, &name_hash, version);
+        }
+    }
+
+    fn add_trusted_minter_internal(&self, trusted_minter: &ManagedAddress, name_hash: &ManagedBuffer, version: u8) {
+        self.require_not_trusted_minter(trusted_minter);
+        require!(self.is_trusted_minter_sc(trusted_minter), ERROR_NON_VALID_TRUSTED_MINTER_SC);
+        self.trusted_minters_list().add(trusted_minter);
+        self.add_trusted_minter_event(trusted_minter);
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.set_trusted_minter_metadata_internal(trusted_minter, name_hash, version, timestamp);
+    }
+
+    /// Sets the metadata of an already trusted minter, which is useful for trusted minters whitelisted before metadata was
+    /// tracked.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minter` - the trusted minter
+    /// - `name_hash` - the trusted minter name hash
+    /// - `version` - the trusted minter interface version
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `trusted_minter` must has been already trusted
+    /// - the added timestamp is kept if already tracked, otherwise it is set to zero
+    ///
+    #[endpoint(setTrustedMinterMetadata)]
+    fn set_trusted_minter_metadata(&self, trusted_minter: ManagedAddress, name_hash: ManagedBuffer, version: u8) {
+        self.require_admin();
+        self.require_trusted_minter(&trusted_minter);
+
+        let metadata_mapper = self.trusted_minter_metadata(&trusted_minter);
+        let timestamp = if metadata_mapper.is_empty() { 0u64 } else { metadata_mapper.get().timestamp };
+        self.set_trusted_minter_metadata_internal(&trusted_minter, &name_hash, version, timestamp);
+    }
+
+    fn set_trusted_minter_metadata_internal(&self, trusted_minter: &ManagedAddress, name_hash: &ManagedBuffer, version: u8, timestamp: u64) {
+        let metadata = storage::TrustedMinterMetadata { name_hash: name_hash.clone(), version, timestamp };
+        self.trusted_minter_metadata(trusted_minter).set(&metadata);
+        self.trusted_minters().insert(trusted_minter.clone());
+        self.trusted_minter_metadata_event(trusted_minter, &metadata);
     }
 
     /// Removes a trusted minter contract address from the whitelist of trusted minters contracts.
@@ -274,8 +670,276 @@ pub trait GovernanceModule: admin::AdminModule + common::CommonModule + events::
     #[endpoint(removeTrustedMinter)]
     fn remove_trusted_minter(&self, trusted_minter: ManagedAddress) {
         self.require_admin();
-        self.require_trusted_minter(&trusted_minter);
-        self.trusted_minters_list().remove(&trusted_minter);
-        self.remove_trusted_minter_event(&trusted_minter);
+        self.remove_trusted_minter_internal(&trusted_minter);
+    }
+
+    /// Removes a batch of trusted minter contract addresses from the whitelist of trusted minters contracts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minters` - the trusted minters to remove
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - each trusted minter must has been already trusted
+    ///
+    #[endpoint(removeTrustedMinters)]
+    fn remove_trusted_minters(&self, trusted_minters: MultiValueEncoded<ManagedAddress>) {
+        self.require_admin();
+        for trusted_minter in trusted_minters.into_iter() {
+            self.remove_trusted_minter_internal(&trusted_minter);
+        }
+    }
+
+    fn remove_trusted_minter_internal(&self, trusted_minter: &ManagedAddress) {
+        self.require_trusted_minter(trusted_minter);
+        self.trusted_minters_list().remove(trusted_minter);
+        self.trusted_minters().swap_remove(trusted_minter);
+        self.trusted_minter_metadata(trusted_minter).clear();
+        self.remove_trusted_minter_event(trusted_minter);
+    }
+
+    /// Exports the current borrow amounts of the given borrowers, such that they can be imported into a new money market
+    /// instance during a code migration. Each exported entry is chained into a running hash commitment.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should not be active.
+    /// - Borrowers without borrows are skipped.
+    /// - Exported borrows are cleared from this money market, i.e. account borrows are zeroed and the total borrows are
+    ///   decreased accordingly.
+    ///
+    #[endpoint(exportBorrowSnapshots)]
+    fn export_borrow_snapshots(&self, borrowers: MultiValueEncoded<ManagedAddress>) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        self.require_admin();
+
+        require!(self.market_state().get() != storage::State::Active, ERROR_MARKET_SHOULD_NOT_BE_ACTIVE);
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let borrow_index = self.get_borrow_index();
+        let mut hash = self.borrow_snapshots_export_hash().get();
+        let mut count = 0usize;
+        let mut exported_amount = BigUint::zero();
+        let mut exported = MultiValueEncoded::new();
+        for borrower in borrowers.into_iter() {
+            let borrow_amount = self.get_account_borrow_amount(&borrower);
+            if borrow_amount == BigUint::zero() {
+                continue;
+            }
+
+            // the exported debt leaves this money market
+            self.set_account_borrow_snapshot(&borrower, &BigUint::zero(), &borrow_index);
+
+            hash = self.chain_borrow_snapshot_hash(&hash, &borrower, &borrow_amount);
+            exported_amount += &borrow_amount;
+            exported.push((borrower, borrow_amount).into());
+            count += 1;
+        }
+
+        // account borrows are rounded down, such that they might slightly exceed the total borrows
+        let total_borrows = self.total_borrows().get();
+        self.total_borrows().set(if total_borrows > exported_amount { total_borrows - exported_amount } else { BigUint::zero() });
+
+        self.borrow_snapshots_export_hash().set(&hash);
+        self.borrow_snapshots_exported_event(count, &hash);
+
+        exported
+    }
+
+    /// Commits the final export hash of the borrow snapshots to be imported into this money market, such that imports can
+    /// be verified against it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `export_hash` - The final export hash reported by the exporting money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should not be active.
+    /// - Cannot be changed once the import has started.
+    ///
+    #[endpoint(commitBorrowSnapshots)]
+    fn commit_borrow_snapshots(&self, export_hash: ManagedBuffer) {
+        self.require_admin();
+
+        require!(self.market_state().get() != storage::State::Active, ERROR_MARKET_SHOULD_NOT_BE_ACTIVE);
+        require!(self.borrow_snapshots_import_hash().is_empty(), ERROR_BORROW_SNAPSHOTS_IMPORT_STARTED);
+        require!(!export_hash.is_empty(), ERROR_INVALID_BORROW_SNAPSHOTS_HASH);
+
+        self.borrow_snapshots_commitment().set(&export_hash);
+        self.borrow_snapshots_committed_event(&export_hash);
+    }
+
+    /// Imports borrow amounts previously exported from another money market instance during a code migration. Each
+    /// imported entry is chained into a running import hash, which must match the committed export hash before the market
+    /// can be activated.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_snapshots` - A page of borrower addresses and borrow amounts, in the exported order.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should not be active.
+    /// - The final export hash must have been committed and cannot be exceeded, i.e. no pages can be imported once the
+    ///   import hash matches it.
+    /// - Borrowers cannot have a borrow snapshot already.
+    /// - Borrowers are registered as members of this money market at the Controller.
+    /// - Imported borrows are added to the total borrows and booked against reserves, such that the exchange rate remains
+    ///   unchanged for existing suppliers. Reserves are split between staking rewards and revenue using the stake factor.
+    ///
+    #[endpoint(importBorrowSnapshots)]
+    fn import_borrow_snapshots(&self, borrow_snapshots: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>) {
+        self.require_admin();
+
+        require!(self.market_state().get() != storage::State::Active, ERROR_MARKET_SHOULD_NOT_BE_ACTIVE);
+
+        let commitment = self.borrow_snapshots_commitment().get();
+        require!(!commitment.is_empty(), ERROR_BORROW_SNAPSHOTS_NOT_COMMITTED);
+
+        let mut hash = self.borrow_snapshots_import_hash().get();
+        require!(hash != commitment, ERROR_BORROW_SNAPSHOTS_IMPORT_COMPLETED);
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let borrow_index = self.get_borrow_index();
+        let mut count = 0usize;
+        let mut imported_amount = BigUint::zero();
+        for borrow_snapshot in borrow_snapshots.into_iter() {
+            let (borrower, borrow_amount) = borrow_snapshot.into_tuple();
+            require!(self.account_borrow_snapshot(&borrower).is_empty(), ERROR_BORROW_SNAPSHOT_ALREADY_SET);
+
+            hash = self.chain_borrow_snapshot_hash(&hash, &borrower, &borrow_amount);
+
+            self.set_account_borrow_snapshot(&borrower, &borrow_amount, &borrow_index);
+            self.register_borrower(&borrower);
+
+            imported_amount += &borrow_amount;
+            count += 1;
+        }
+
+        // the imported borrows have no matching cash in this money market, so they are booked as reserves and split between
+        // staking rewards and revenue as accrued interest is
+        let delta_rewards = self.wad_mul(&self.stake_factor().get(), &imported_amount);
+        let delta_revenue = &imported_amount - &delta_rewards;
+
+        self.total_borrows().update(|amount| *amount += &imported_amount);
+        self.total_reserves().update(|amount| *amount += &imported_amount);
+        self.staking_rewards().update(|amount| *amount += &delta_rewards);
+        self.historical_staking_rewards().update(|amount| *amount += &delta_rewards);
+        self.revenue().update(|amount| *amount += &delta_revenue);
+
+        self.borrow_snapshots_import_hash().set(&hash);
+        self.borrow_snapshots_imported_event(count, &hash);
+    }
+
+    /// Indexes borrowers that opened their positions before borrowers were indexed, such that their borrow snapshots are
+    /// included in the borrow snapshots export.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Accounts without an outstanding borrow or already indexed are skipped.
+    ///
+    #[endpoint(indexBorrowers)]
+    fn index_borrowers(&self, borrowers: MultiValueEncoded<ManagedAddress>) {
+        self.require_admin();
+
+        let mut count = 0usize;
+        for borrower in borrowers.into_iter() {
+            let opt_snapshot = self.get_account_borrow_snapshot(&borrower);
+            let has_borrow = match opt_snapshot {
+                Some(snapshot) => snapshot.borrow_amount > BigUint::zero(),
+                None => false,
+            };
+
+            if has_borrow && self.borrowers().insert(borrower) {
+                count += 1;
+            }
+        }
+
+        self.borrowers_indexed_event(count);
+    }
+
+    /// Rebases the borrow index, i.e. divides it by a given divisor in order to restore numerical headroom. Account borrow
+    /// snapshots are lazily brought to the rebased borrow index, such that effective debts remain unchanged.
+    ///
+    /// # Arguments:
+    ///
+    /// - `divisor` - The divisor applied to the borrow index.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The divisor must be greater than one and the rebased borrow index cannot be lower than one (in wad).
+    /// - Account borrow snapshots can be migrated in pages using `rebaseBorrowSnapshots`.
+    ///
+    #[endpoint(rebaseBorrowIndex)]
+    fn rebase_borrow_index(&self, divisor: &BigUint) {
+        self.require_admin();
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let wad = BigUint::from(WAD);
+        let old_index = self.get_borrow_index();
+        require!(divisor > &BigUint::from(1u64) && &old_index / divisor >= wad, ERROR_INVALID_BORROW_INDEX_REBASE);
+
+        let new_index = &old_index / divisor;
+        self.borrow_index().set(&new_index);
+
+        self.borrow_index_rebases().push(divisor);
+        let rebase_factor = self.get_borrow_index_rebase_factor() * divisor;
+        self.borrow_index_rebase_factor().set(&rebase_factor);
+
+        self.borrow_index_rebased_event(divisor, &old_index, &new_index);
+    }
+
+    /// Migrates a page of account borrow snapshots to the latest borrow index rebase, such that they no longer need to be
+    /// lazily brought to the rebased borrow index.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrowers without borrow snapshots or with up to date borrow snapshots are skipped.
+    ///
+    #[endpoint(rebaseBorrowSnapshots)]
+    fn rebase_borrow_snapshots(&self, borrowers: MultiValueEncoded<ManagedAddress>) {
+        self.require_admin();
+
+        let rebases = self.borrow_index_rebases().len();
+        let mut count = 0usize;
+        for borrower in borrowers.into_iter() {
+            if self.account_borrow_index_rebases(&borrower).get() >= rebases {
+                continue;
+            }
+
+            let opt_snapshot = self.get_account_borrow_snapshot(&borrower);
+            if let Some(snapshot) = opt_snapshot {
+                self.set_account_borrow_snapshot(&borrower, &snapshot.borrow_amount, &snapshot.borrow_index);
+                count += 1;
+            }
+        }
+
+        self.borrow_snapshots_rebased_event(count);
     }
 }
@@ -0,0 +1,88 @@
+multiversx_sc::imports!();
+
+use super::{
+    common,
+    constants::*,
+    errors::*,
+    events, proxies,
+    storage::{self, HedgingHook},
+};
+
+#[multiversx_sc::module]
+pub trait HedgingModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Sets a hedging hook for the caller, i.e. an external contract (typically a perpetuals adapter) that gets notified
+    /// whenever the caller's borrow amount in this money market changes beyond a given threshold.
+    ///
+    /// # Arguments:
+    ///
+    /// - `hook` - The hedging hook smart contract address.
+    /// - `threshold` - The minimum change in the borrow amount, in underlying, that triggers a notification.
+    /// - `gas_limit` - The gas limit forwarded to each notification.
+    ///
+    /// # Notes:
+    ///
+    /// - The hook must implement the `onBorrowChanged` endpoint.
+    /// - Notifications are asynchronous and failure-isolated: a failing hook never reverts the borrow change.
+    /// - A notification is skipped if there is not enough gas left to forward the gas limit.
+    /// - The gas limit cannot exceed `MAX_HEDGING_HOOK_GAS_LIMIT`.
+    ///
+    #[endpoint(setHedgingHook)]
+    fn set_hedging_hook(&self, hook: ManagedAddress, threshold: BigUint, gas_limit: u64) {
+        require!(self.blockchain().is_smart_contract(&hook), ERROR_INVALID_HEDGING_HOOK);
+        require!(threshold > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(gas_limit > 0 && gas_limit <= MAX_HEDGING_HOOK_GAS_LIMIT, ERROR_INVALID_HEDGING_HOOK_GAS_LIMIT);
+
+        let account = self.blockchain().get_caller();
+        let hedging_hook = HedgingHook { hook, threshold, gas_limit };
+
+        self.hedging_hook(&account).set(&hedging_hook);
+        self.hedging_hook_last_notified(&account).set(self.get_account_borrow_amount(&account));
+
+        self.set_hedging_hook_event(&account, &hedging_hook);
+    }
+
+    /// Removes the hedging hook of the caller.
+    ///
+    #[endpoint(removeHedgingHook)]
+    fn remove_hedging_hook(&self) {
+        let account = self.blockchain().get_caller();
+        require!(!self.hedging_hook(&account).is_empty(), ERROR_UNDEFINED_HEDGING_HOOK);
+
+        self.hedging_hook(&account).clear();
+        self.hedging_hook_last_notified(&account).clear();
+
+        self.remove_hedging_hook_event(&account);
+    }
+
+    /// Notifies the account hedging hook, if any, whenever the account borrow amount has moved by at least the hook
+    /// threshold since the last notification.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account whose borrow amount has changed.
+    /// - `new_borrow_amount` - The new account borrow amount.
+    ///
+    fn try_notify_hedging_hook(&self, account: &ManagedAddress, new_borrow_amount: &BigUint) {
+        if self.hedging_hook(account).is_empty() {
+            return;
+        }
+
+        let hedging_hook = self.hedging_hook(account).get();
+        let old_borrow_amount = self.hedging_hook_last_notified(account).get();
+
+        let delta = if new_borrow_amount >= &old_borrow_amount { new_borrow_amount - &old_borrow_amount } else { &old_borrow_amount - new_borrow_amount };
+        if delta < hedging_hook.threshold {
+            return;
+        }
+
+        // never let a hook consume the gas required to complete the borrow change, which could block liquidations
+        if self.blockchain().get_gas_left() < hedging_hook.gas_limit + HEDGING_HOOK_GAS_RESERVE {
+            return;
+        }
+
+        self.hedging_hook_last_notified(account).set(new_borrow_amount);
+        self.notify_hedging_hook(&hedging_hook, account, new_borrow_amount);
+
+        self.hedging_hook_notified_event(account, &hedging_hook.hook, &old_borrow_amount, new_borrow_amount);
+    }
+}
@@ -14,6 +14,11 @@ pub trait LiquidateModule: borrow::BorrowModule + common::CommonModule + events:
     /// - `collateral_market ` - The money market in which to seize collateral from the borrower.
     /// - `opt_min_tokens` - The minimum amount of tokens to be seized from the borrower.
     ///
+    /// # Notes:
+    ///
+    /// - If the payment exceeds the maximum repayment allowed by the close factor, only the allowed amount is used and the
+    ///   excess is refunded to the liquidator.
+    ///
     #[payable("*")]
     #[endpoint(liquidateBorrow)]
     fn liquidate_borrow(&self, borrower: ManagedAddress, collateral_market: ManagedAddress, opt_min_tokens: OptionalValue<BigUint>) -> LiquidateBorrowResultType<Self::Api> {
@@ -24,6 +29,17 @@ pub trait LiquidateModule: borrow::BorrowModule + common::CommonModule + events:
         let (underlying_id, underlying_amount) = self.call_value().egld_or_single_fungible_esdt();
         self.require_valid_underlying_payment(&underlying_id, &underlying_amount);
 
+        // clamp the repayment to the maximum allowed and refund the excess
+        let max_repayment = self.get_max_liquidation_repayment(&self.blockchain().get_sc_address(), &borrower);
+        let underlying_amount = if max_repayment > BigUint::zero() && underlying_amount > max_repayment {
+            let refund_amount = &underlying_amount - &max_repayment;
+            self.send_underlying(&liquidator, &refund_amount);
+            self.liquidation_repayment_refund_event(&liquidator, &borrower, &refund_amount);
+            max_repayment
+        } else {
+            underlying_amount
+        };
+
         self.liquidate_borrow_internal(&liquidator, &borrower, &underlying_amount, &collateral_market, opt_min_tokens)
     }
 
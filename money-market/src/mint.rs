@@ -1,15 +1,25 @@
 multiversx_sc::imports!();
 
-use super::{common, errors::*, events, proxies, storage};
+use super::{common, errors::*, events, proxies, storage, token_controls};
 
 #[multiversx_sc::module]
-pub trait MintModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait MintModule: pausable::PausableModule + common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule + token_controls::TokenControlsModule {
     /// Supply underlying to the money market, providing liquidity and accruing interest in exchange. In exchange, minted
     /// Hatom tokens are directed to the caller, which can be redeemed for underlying at a given point in the future.
     ///
+    /// # Arguments:
+    ///
+    /// - `opt_min_tokens_out` - An optional minimum amount of Hatom tokens to be received, protecting the minter against
+    ///   unexpected exchange rate movements.
+    ///
+    /// # Notes:
+    ///
+    /// - If the minter has opted in to automatically enter markets at the Controller, the minted tokens are deposited as
+    ///   collateral on its name instead and an empty payment is returned.
+    ///
     #[payable("*")]
     #[endpoint(mint)]
-    fn mint(&self) -> EsdtTokenPayment {
+    fn mint(&self, opt_min_tokens_out: OptionalValue<BigUint>) -> EsdtTokenPayment {
         self.require_active();
         self.accrue_interest();
 
@@ -17,7 +27,19 @@ pub trait MintModule: common::CommonModule + events::EventsModule + proxies::Pro
         self.require_valid_underlying_payment(&underlying_id, &underlying_amount);
 
         let minter = self.blockchain().get_caller();
-        self.mint_internal(&minter, &underlying_amount, true)
+        let auto_enter = self.has_auto_enter_markets(&minter);
+        let token_payment = self.mint_internal(&minter, &underlying_amount, !auto_enter);
+
+        if let Some(min_tokens_out) = opt_min_tokens_out.into_option() {
+            require!(token_payment.amount >= min_tokens_out, ERROR_NOT_ENOUGH_MINTED_TOKENS);
+        }
+
+        if auto_enter {
+            self.enter_market(OptionalValue::Some(minter), &token_payment);
+            return EsdtTokenPayment::new(token_payment.token_identifier, 0, BigUint::zero());
+        }
+
+        token_payment
     }
 
     /// Mints Hatom's tokens and enters the market in a single transaction.
@@ -25,38 +47,49 @@ pub trait MintModule: common::CommonModule + events::EventsModule + proxies::Pro
     /// # Arguments:
     ///
     /// - `opt_account` - If given, the collateral will be deposited on the name of this account. Can only be performed by a
-    ///   trusted minter.
+    ///   trusted minter, unless the account is the caller itself.
+    /// - `opt_min_tokens_out` - An optional minimum amount of Hatom tokens to be deposited as collateral, protecting the
+    ///   minter against unexpected exchange rate movements.
     ///
     /// # Notes:
     ///
     /// - Must be paid with the underlying asset.
+    /// - Returns the minted tokens payment, although the minted tokens are kept as collateral.
     ///
     #[payable("*")]
     #[endpoint(mintAndEnterMarket)]
-    fn mint_and_enter_market(&self, opt_account: OptionalValue<ManagedAddress>) -> EsdtTokenPayment {
+    fn mint_and_enter_market(&self, opt_account: OptionalValue<ManagedAddress>, opt_min_tokens_out: OptionalValue<BigUint>) -> EsdtTokenPayment {
         self.require_active();
         self.accrue_interest();
 
+        // the caller might pass its own address in order to provide a minimum amount of tokens out
+        let caller = self.blockchain().get_caller();
         let account = match opt_account {
-            OptionalValue::None => self.blockchain().get_caller(),
-            OptionalValue::Some(account) => {
-                let caller = self.blockchain().get_caller();
-                require!(caller != account, ERROR_ADDRESSES_MUST_DIFFER);
+            OptionalValue::Some(account) if account != caller => {
                 self.require_trusted_minter(&caller);
                 account
             },
+            _ => caller,
         };
 
         let (underlying_id, underlying_amount) = self.call_value().egld_or_single_fungible_esdt();
         self.require_valid_underlying_payment(&underlying_id, &underlying_amount);
 
         let token_payment = self.mint_internal(&account, &underlying_amount, false);
+
+        if let Some(min_tokens_out) = opt_min_tokens_out.into_option() {
+            require!(token_payment.amount >= min_tokens_out, ERROR_NOT_ENOUGH_MINTED_TOKENS);
+        }
+
         self.enter_market(OptionalValue::Some(account), &token_payment);
 
         token_payment
     }
 
     fn mint_internal(&self, minter: &ManagedAddress, underlying_amount: &BigUint, send: bool) -> EsdtTokenPayment {
+        self.require_not_paused();
+        self.require_no_underlying_protection();
+
         // compute the amount of Hatom's tokens to be minted
         let tokens = self.underlying_amount_to_tokens(underlying_amount);
         require!(tokens > BigUint::zero(), ERROR_NOT_ENOUGH_UNDERLYING);
@@ -0,0 +1,79 @@
+multiversx_sc::imports!();
+
+use super::{
+    common, constants::*, errors::*, events, proxies,
+    storage::{self, ControllerMirror},
+};
+use controller::storage::MarketMirror;
+
+#[multiversx_sc::module]
+pub trait MirrorModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Stores the Controller-derived values of this money market, as pushed by the Controller whenever governance changes
+    /// any of them.
+    ///
+    /// # Arguments:
+    ///
+    /// - `mirror` - The Controller-derived values of this money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the Controller.
+    ///
+    #[endpoint(syncControllerMirror)]
+    fn sync_controller_mirror(&self, mirror: MarketMirror<Self::Api>) {
+        require!(!self.controller().is_empty(), ERROR_UNDEFINED_CONTROLLER);
+        require!(self.blockchain().get_caller() == self.controller().get(), ERROR_ONLY_CONTROLLER);
+
+        self.market_mirror().set(mirror);
+    }
+
+    /// Returns the Controller-derived values of this money market in one place, i.e. its collateral factors, its liquidity
+    /// and borrow caps, its mint, borrow and seize statuses and whether it has been deprecated.
+    ///
+    /// # Notes:
+    ///
+    /// - Values are the ones last pushed by the Controller, which does so from every governance change that affects them.
+    /// - Scheduled collateral factor decreases are applied once their timelock has elapsed.
+    /// - A zero cap means there is no cap.
+    ///
+    #[view(getControllerMirror)]
+    fn get_controller_mirror(&self) -> ControllerMirror<Self::Api> {
+        require!(!self.market_mirror().is_empty(), ERROR_CONTROLLER_MIRROR_NOT_SYNCED);
+
+        let mirror = self.market_mirror().get();
+
+        let (collateral_factor, ush_borrower_collateral_factor) = match mirror.next_collateral_factors {
+            Some((start_timestamp, next_cf, next_uf)) if self.blockchain().get_block_timestamp() >= start_timestamp => (next_cf, next_uf),
+            _ => (mirror.collateral_factor, mirror.ush_borrower_collateral_factor),
+        };
+
+        // a flagged money market is only considered deprecated once its collateral factor is zero, its borrows are paused
+        // and its reserve factor is 100%
+        let deprecated = mirror.deprecated && collateral_factor == BigUint::zero() && mirror.borrow_paused && self.get_reserve_factor() == BigUint::from(WAD);
+
+        ControllerMirror {
+            collateral_factor,
+            ush_borrower_collateral_factor,
+            liquidity_cap: mirror.liquidity_cap,
+            borrow_cap: mirror.borrow_cap,
+            mint_paused: mirror.mint_paused,
+            borrow_paused: mirror.borrow_paused,
+            seize_paused: mirror.seize_paused,
+            deprecated,
+        }
+    }
+
+    /// Returns the up to date collateral factor of this money market, as given by the Controller.
+    ///
+    #[view(getMarketCollateralFactor)]
+    fn get_market_collateral_factor(&self) -> BigUint {
+        self.get_controller_mirror().collateral_factor
+    }
+
+    /// Returns whether this money market has been deprecated, as given by the Controller.
+    ///
+    #[view(isMarketDeprecated)]
+    fn is_market_deprecated(&self) -> bool {
+        self.get_controller_mirror().deprecated
+    }
+}
@@ -59,6 +59,7 @@ where
     /// - If the admin address is not provided, the admin will be set as the deployer.
     /// - If the contract is being upgraded, the admin address will not be overwritten.
     /// - Upgrades won't change the underlying token identifier, initial exchange rate, accrual timestamp or the admin.
+    /// - The pause guardian is read live from the Controller.
     ///
     pub fn init<
         Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
@@ -165,6 +166,8 @@ where
     /// - Can only be called once.
     /// - The initial supply is equal to the minimum initial supply.
     /// - The remainder tokens are sent back to the caller.
+    /// - If borrow snapshots have been committed, the running import hash must match the committed export hash, i.e. the
+    ///   market cannot be activated with a partial or tampered import.
     ///
     pub fn mint_initial_supply(
         self,
@@ -225,6 +228,94 @@ where
             .original_result()
     }
 
+    /// Pauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the pause guardian.
+    /// - Pausing an already paused contract has no effect.
+    ///
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Unpausing a contract that is not paused has no effect.
+    ///
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    /// Sets the pause guardian of the contract.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_pause_guardian` - The address of the new pause guardian.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Cannot be called if the pause guardian is read from another contract.
+    ///
+    pub fn set_pause_guardian<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_pause_guardian: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPauseGuardian")
+            .argument(&new_pause_guardian)
+            .original_result()
+    }
+
+    /// Returns the address of the pause guardian, if one has been set.
+    ///
+    pub fn get_pause_guardian_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseGuardian")
+            .original_result()
+    }
+
+    /// Stores the address of the contract from which the pause guardian is read, if any.
+    pub fn pause_guardian_source(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseGuardianSource")
+            .original_result()
+    }
+
+    /// Returns whether the contract is paused.
+    ///
+    pub fn is_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
     /// A borrower requests underlying from the money market.
     ///
     /// # Arguments:
@@ -244,6 +335,86 @@ where
             .original_result()
     }
 
+    /// Places a borrow order, i.e. a borrow intent that can be executed by anyone (typically a keeper) as long as the
+    /// market borrow rate remains below a given threshold and the order has not expired.
+    ///
+    /// # Arguments:
+    ///
+    /// - `underlying_amount` - The amount of underlying asset the borrower requests.
+    /// - `max_borrow_rate` - The maximum borrow rate per second in wad the borrower is willing to pay.
+    /// - `expiration` - The timestamp after which the order can no longer be executed.
+    ///
+    /// # Notes:
+    ///
+    /// - The borrowed underlying is always sent to the borrower, never to the executor.
+    /// - The borrow is subject to the same Controller checks as a regular borrow at execution time.
+    ///
+    pub fn place_borrow_order<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        underlying_amount: Arg0,
+        max_borrow_rate: Arg1,
+        expiration: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("placeBorrowOrder")
+            .argument(&underlying_amount)
+            .argument(&max_borrow_rate)
+            .argument(&expiration)
+            .original_result()
+    }
+
+    /// Cancels a borrow order.
+    ///
+    /// # Arguments:
+    ///
+    /// - `order_id` - The borrow order identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the borrower, unless the order has already expired, in which case anyone can remove it.
+    ///
+    pub fn cancel_borrow_order<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelBorrowOrder")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Executes a borrow order on behalf of its borrower.
+    ///
+    /// # Arguments:
+    ///
+    /// - `order_id` - The borrow order identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - The borrow rate resulting from the borrow must not exceed the borrower's threshold.
+    ///
+    pub fn execute_borrow_order<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeBorrowOrder")
+            .argument(&order_id)
+            .original_result()
+    }
+
     /// A utility function to highlight that this smart contract is a Money Market.
     ///
     pub fn is_money_market(
@@ -266,6 +437,45 @@ where
             .original_result()
     }
 
+    /// Returns a page of the trusted minters with metadata, together with their metadata.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The zero based position of the first trusted minter in the page.
+    /// - `size` - The maximum number of trusted minters in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Legacy trusted minters are only enumerated once their metadata has been set.
+    ///
+    pub fn get_trusted_minters<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, TrustedMinterMetadata<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrustedMinters")
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Checks whether borrows are restricted because of an abnormal accrual gap, either pending governance resolution or
+    /// about to be recorded at the next accrual.
+    ///
+    pub fn has_abnormal_accrual_gap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasAbnormalAccrualGap")
+            .original_result()
+    }
+
     /// This method is one of the most important methods of the protocol, as it accrues the borrows interest and distributes
     /// that amount into reserves (including revenue and staking rewards). In order to do that, it solves the money market
     /// dynamics using an Euler scheme.
@@ -306,6 +516,24 @@ where
             .original_result()
     }
 
+    /// Audits the internal cash accounting against the actual underlying balance of the money market. Any surplus (i.e.
+    /// underlying sent directly to the money market) is quarantined into the donations bucket, such that it can never be
+    /// used to inflate the exchange rate.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - Returns the newly quarantined amount.
+    ///
+    pub fn audit_cash(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("auditCash")
+            .original_result()
+    }
+
     /// Translates an underlying amount to tokens.
     ///
     /// # Arguments:
@@ -471,7 +699,8 @@ where
             .original_result()
     }
 
-    /// Returns the amount of liquidity up to the last interaction that accrued interest.
+    /// Returns the amount of liquidity up to the last interaction that accrued interest. Cash deployed into the reserve
+    /// strategy or lent to the USH peg-stability module is still accounted as liquidity.
     ///
     pub fn get_liquidity(
         self,
@@ -482,6 +711,18 @@ where
             .original_result()
     }
 
+    /// Returns the amount of liquidity that is available to suppliers and borrowers, i.e. the liquidity without the cash
+    /// deployed into the reserve strategy or lent to the USH peg-stability module, which must be recalled first.
+    ///
+    pub fn get_available_liquidity(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAvailableLiquidity")
+            .original_result()
+    }
+
     /// Returns the reserve factor, i.e. the percentage of interest that is redirected to the reserves. We keep this method
     /// so that it matches with USH Money Market interface.
     ///
@@ -527,6 +768,63 @@ where
             .original_result()
     }
 
+    /// Returns the address of the reserve strategy if set.
+    ///
+    pub fn get_reserve_strategy(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReserveStrategy")
+            .original_result()
+    }
+
+    /// Returns the amount of cash that is held by the money market, i.e. the cash that has not been deployed into the
+    /// reserve strategy nor lent to the USH peg-stability module.
+    ///
+    pub fn get_on_hand_cash(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOnHandCash")
+            .original_result()
+    }
+
+    /// Returns the amount of deployed cash that should be recalled from the reserve strategy, i.e. the deployed cash above the
+    /// maximum deployed share of cash or the on-hand cash missing to cover the staking rewards, whichever is larger.
+    ///
+    pub fn get_strategy_deficit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyDeficit")
+            .original_result()
+    }
+
+    /// Returns the address of the PSM adapter if set.
+    ///
+    pub fn get_psm_adapter(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPsmAdapter")
+            .original_result()
+    }
+
+    /// Returns the address of the insurance fund if set.
+    ///
+    pub fn get_insurance_fund(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceFund")
+            .original_result()
+    }
+
     /// Returns the updated borrow amount of the given account.
     ///
     pub fn current_account_borrow_amount<
@@ -589,6 +887,18 @@ where
             .original_result()
     }
 
+    /// Returns the product of all the divisors applied to the borrow index by borrow index rebases, or one if the borrow
+    /// index has never been rebased.
+    ///
+    pub fn get_borrow_index_rebase_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowIndexRebaseFactor")
+            .original_result()
+    }
+
     /// Returns the money market exchange rate and the borrow amount of the given account up to the last interaction that
     /// accrued interest, in one shot.
     ///
@@ -609,6 +919,50 @@ where
             .original_result()
     }
 
+    /// Returns the number of accounts with an outstanding borrow.
+    ///
+    pub fn get_borrowers_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowersCount")
+            .original_result()
+    }
+
+    /// Gets a page of the borrow snapshots of this money market, i.e. tuples of borrower address, borrow amount (principal
+    /// at the borrow index of the snapshot), borrow index and last update timestamp.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The zero based position of the first borrower in the page.
+    /// - `size` - The maximum number of borrowers in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Borrowers that fully repay are removed by swapping them with the last borrower, so pages are only consistent within
+    ///   the same block.
+    /// - Borrowers that have not interacted since borrowers were indexed might be missing until they are indexed.
+    /// - Borrow amounts are not up to date with accrued interest, which can be computed using the current borrow index.
+    /// - Snapshots taken before a borrow index rebase are brought to the rebased borrow index.
+    /// - Restricted to readers registered at the Controller if bulk enumeration views are restricted.
+    ///
+    pub fn get_borrow_snapshots_page<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue4<ManagedAddress<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, u64>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowSnapshotsPage")
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
     /// Returns the money market exchange rate and the borrow amount of the given account up to the last interaction that
     /// accrued interest or up to the current time if a sufficient amount of time has elapsed since the last accrual, in one
     /// shot.
@@ -643,6 +997,35 @@ where
             .original_result()
     }
 
+    /// Returns an audit of the borrow index, which can be used to plan an index rebase long before precision becomes a
+    /// problem. It includes the borrow index magnitude in bits, an estimation of the number of years until the borrow index
+    /// exceeds `MAX_BORROW_INDEX_BITS` at the current borrow rate, and the rounding drift between the total borrows and the
+    /// sum of the borrows of a sampled page of accounts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `accounts` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - The estimation is `None` if the borrow rate is zero.
+    /// - The rounding drift is the absolute difference between the total borrows and the sampled borrows. Hence, it only
+    ///   represents the accumulated rounding drift if the page includes all borrowers.
+    /// - It does not accrue interest.
+    ///
+    pub fn get_borrow_index_audit<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        accounts: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BorrowIndexAudit<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowIndexAudit")
+            .argument(&accounts)
+            .original_result()
+    }
+
     /// Returns the current money market exchange rate between underlying and tokens.
     ///
     pub fn get_current_exchange_rate(
@@ -699,6 +1082,29 @@ where
             .original_result()
     }
 
+    /// Returns a page of the interest rate snapshots history, in chronological order.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The zero based position of the first snapshot in the page, where zero is the oldest snapshot available.
+    /// - `size` - The maximum number of snapshots in the page.
+    ///
+    pub fn get_rate_snapshots<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, RateSnapshot<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRateSnapshots")
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
     /// Returns the close factor, used to determine the maximum amount of a borrow that can be repaid during a liquidation.
     /// If not set, it returns the minimum allowed close factor.
     ///
@@ -723,17 +1129,235 @@ where
             .original_result()
     }
 
-    /// Sets the staking smart contract address.
+    /// Sets the PSM adapter, i.e. the contract through which idle cash can be lent to the USH peg-stability module.
     ///
     /// # Arguments:
     ///
-    /// - `new_staking` - The Staking smart contract address.
+    /// - `new_psm_adapter` - The PSM adapter smart contract address.
     ///
-    pub fn set_staking_contract<
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The PSM adapter must accept the same underlying as this money market.
+    /// - Changing the PSM adapter requires the credit line to be fully recalled first.
+    ///
+    pub fn set_psm_adapter<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        new_staking: Arg0,
+        new_psm_adapter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPsmAdapter")
+            .argument(&new_psm_adapter)
+            .original_result()
+    }
+
+    /// Sets the credit line cap and interest rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_cap` - The maximum amount of cash that can be lent to the USH peg-stability module.
+    /// - `new_rate` - The interest rate per second charged on the credit line principal in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The rate cannot exceed `MAX_CREDIT_LINE_RATE`.
+    /// - Interest is accrued at the old rate before the new rate takes effect.
+    /// - Lowering the cap below the current principal only prevents new lending.
+    ///
+    pub fn set_credit_line<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_cap: Arg0,
+        new_rate: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCreditLine")
+            .argument(&new_cap)
+            .argument(&new_rate)
+            .original_result()
+    }
+
+    /// Lends an amount of idle cash to the USH peg-stability module through the PSM adapter.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to lend.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The principal cannot exceed the credit line cap nor the `MAX_CREDIT_LINE_SHARE` of cash.
+    /// - Lent cash is still accounted as cash, so the exchange rate and liquidity remain unchanged.
+    ///
+    pub fn lend_to_psm<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("lendToPsm")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Recalls an amount of the credit line debt from the PSM adapter. Repayments are applied to the accrued interest first
+    /// and then to the principal. The interest is booked into the reserves and split between staking rewards and revenue.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_amount` - The amount of underlying to recall. If not provided, the whole debt is recalled, up to the amount
+    ///   held by the PSM adapter, i.e. not drawn by the PSM.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The PSM adapter must return at least the requested amount.
+    ///
+    pub fn recall_from_psm<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        opt_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("recallFromPsm")
+            .argument(&opt_amount)
+            .original_result()
+    }
+
+    /// Recalls the whole credit line debt from the PSM adapter, writing off whatever the PSM adapter cannot return because it
+    /// has been drawn by the PSM, e.g. when the PSM defaults or the credit line must be unwound urgently.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - All the underlying held by the PSM adapter is recalled first, applied to the accrued interest and then to the
+    ///   principal.
+    /// - The unpaid interest is forgiven, while the unpaid principal is realized as a loss, which is absorbed by the
+    ///   reserves first, up to the protocol revenue, and the remainder by suppliers.
+    /// - The written off principal is also written off from the amount drawn at the PSM adapter, up to that amount.
+    ///
+    pub fn force_recall_from_psm(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("forceRecallFromPsm")
+            .original_result()
+    }
+
+    /// Returns the current credit line debt, i.e. the principal lent to the USH peg-stability module plus the interest
+    /// accrued up to the current timestamp.
+    ///
+    pub fn get_credit_line_debt(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCreditLineDebt")
+            .original_result()
+    }
+
+    /// Sets the interest forgiveness threshold, i.e. the maximum debt a borrower can have to get its accrued interest
+    /// forgiven.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_threshold` - The new threshold in underlying. Zero disables the interest forgiveness program.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn set_interest_forgiveness_threshold<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_threshold: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setInterestForgivenessThreshold")
+            .argument(&new_threshold)
+            .original_result()
+    }
+
+    /// Imports the borrow principals of borrowers that opened their positions before principals were tracked, such that
+    /// they become eligible for interest forgiveness.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_principals` - A page of borrower addresses and borrow principals.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should be deprecated at the Controller.
+    /// - Borrowers cannot have a borrow principal already.
+    /// - The borrow principal cannot exceed the current borrow amount.
+    ///
+    pub fn import_borrow_principals<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        borrow_principals: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("importBorrowPrincipals")
+            .argument(&borrow_principals)
+            .original_result()
+    }
+
+    /// Forgives the accrued interest (not the principal) of borrowers whose debt is below the interest forgiveness
+    /// threshold. The forgiven interest is funded from the revenue part of the reserves, such that the exchange rate remains
+    /// unchanged.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should be deprecated at the Controller.
+    /// - Borrowers without a tracked borrow principal, without accrued interest or above the threshold are skipped.
+    /// - The total forgiven interest of the page cannot exceed the revenue.
+    ///
+    pub fn forgive_interest<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        borrowers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("forgiveInterest")
+            .argument(&borrowers)
+            .original_result()
+    }
+
+    /// Sets the staking smart contract address.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_staking` - The Staking smart contract address.
+    ///
+    pub fn set_staking_contract<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_staking: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -790,6 +1414,30 @@ where
             .original_result()
     }
 
+    /// Sets a new redeem fee, i.e. the portion of the redeemed underlying that is credited to reserves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_redeem_fee` - The new redeem fee in bps.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The new redeem fee must not exceed the maximum allowed.
+    ///
+    pub fn set_redeem_fee<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_redeem_fee: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRedeemFee")
+            .argument(&new_redeem_fee)
+            .original_result()
+    }
+
     /// Sets a new close factor used at liquidations.
     ///
     /// # Arguments:
@@ -863,7 +1511,53 @@ where
             .original_result()
     }
 
-    /// Sets a new Interest Rate Model.
+    /// Sets the insurance fund address and the share of the protocol seized amount that is routed to it, i.e. instead of
+    /// staying in the money market reserves.
+    ///
+    /// # Arguments
+    ///
+    /// - `new_insurance_fund` - the insurance fund address
+    /// - `new_share` - the share of the protocol seized amount routed to the insurance fund in wad
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - the share cannot exceed 100%
+    ///
+    pub fn set_insurance_fund<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_insurance_fund: Arg0,
+        new_share: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setInsuranceFund")
+            .argument(&new_insurance_fund)
+            .argument(&new_share)
+            .original_result()
+    }
+
+    /// Pays out the underlying amount accrued for the insurance fund during liquidations.
+    ///
+    /// # Notes
+    ///
+    /// - can be called by anyone
+    /// - the payout is capped to the money market cash, such that any remainder stays accrued for a later claim
+    ///
+    pub fn claim_insurance_fund(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimInsuranceFund")
+            .original_result()
+    }
+
+    /// Proposes a new Interest Rate Model, which can be set after the interest rate model timelock using
+    /// `executeInterestRateModel`. This protects lenders against sudden rate regime changes.
     ///
     /// # Arguments:
     ///
@@ -873,8 +1567,9 @@ where
     ///
     /// - Can only be called by the admin.
     /// - The provided address must be a valid Interest Rate Model smart contract.
+    /// - Replaces any previously proposed Interest Rate Model.
     ///
-    pub fn set_interest_rate_model<
+    pub fn propose_interest_rate_model<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
@@ -882,435 +1577,1832 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setInterestRateModel")
+            .raw_call("proposeInterestRateModel")
             .argument(&new_interest_rate_model)
             .original_result()
     }
 
-    /// Withdraws an specified amount of underlying from the money market reserves (revenue part) to the admin account.
+    /// Sets the proposed Interest Rate Model once its timelock has elapsed.
     ///
-    /// # Arguments:
+    /// # Notes:
     ///
-    /// - `underlying_amount` - The amount of underlying to withdraw.
+    /// - Can be called by anyone.
+    ///
+    pub fn execute_interest_rate_model(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeInterestRateModel")
+            .original_result()
+    }
+
+    /// Cancels the proposed Interest Rate Model.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
-    /// - The underlying amount is directed to the admin account.
     ///
-    pub fn reduce_reserves<
-        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
-    >(
+    pub fn cancel_interest_rate_model(
         self,
-        opt_underlying_amount: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("reduceReserves")
-            .argument(&opt_underlying_amount)
+            .raw_call("cancelInterestRateModel")
             .original_result()
     }
 
-    /// Sets a new accrual time threshold.
+    /// Sets the timelock that must elapse between proposing and setting a new Interest Rate Model.
     ///
     /// # Arguments:
     ///
-    /// - `new_accrual_time_threshold` - The new accrual time threshold in seconds.
+    /// - `new_timelock` - The new timelock in seconds.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
+    /// - Must be between `MIN_INTEREST_RATE_MODEL_TIMELOCK` and `MAX_INTEREST_RATE_MODEL_TIMELOCK`.
+    /// - Does not affect an already proposed Interest Rate Model.
     ///
-    pub fn set_accrual_time_threshold<
+    pub fn set_interest_rate_model_timelock<
         Arg0: ProxyArg<u64>,
     >(
         self,
-        new_accrual_time_threshold: Arg0,
+        new_timelock: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setAccrualTimeThreshold")
-            .argument(&new_accrual_time_threshold)
+            .raw_call("setInterestRateModelTimelock")
+            .argument(&new_timelock)
             .original_result()
     }
 
-    /// Whitelists a trusted minter contract, i.e. a contract that can mint and enter market in the name of someone else.
+    /// Returns the timelock that must elapse between proposing and setting a new Interest Rate Model, which defaults to
+    /// `MIN_INTEREST_RATE_MODEL_TIMELOCK`.
+    ///
+    pub fn get_interest_rate_model_timelock(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInterestRateModelTimelock")
+            .original_result()
+    }
+
+    /// Withdraws an specified amount of underlying from the money market reserves (revenue part) to the admin account.
     ///
     /// # Arguments:
     ///
-    /// - `trusted_minter` - the new trusted minter to whitelist
+    /// - `underlying_amount` - The amount of underlying to withdraw.
     ///
-    /// # Notes
+    /// # Notes:
     ///
-    /// - can only be called by the admin
-    /// - `trusted_minter` must be a trusted smart contract
-    /// - `trusted_minter` must not be already trusted
+    /// - Can only be called by the admin.
+    /// - The underlying amount is directed to the admin account.
     ///
-    pub fn add_trusted_minter<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    pub fn reduce_reserves<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
-        trusted_minter: Arg0,
+        opt_underlying_amount: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("addTrustedMinter")
-            .argument(&trusted_minter)
+            .raw_call("reduceReserves")
+            .argument(&opt_underlying_amount)
             .original_result()
     }
 
-    /// Removes a trusted minter contract address from the whitelist of trusted minters contracts.
+    /// Withdraws several amounts of underlying from the money market reserves (revenue part) to configured destinations,
+    /// each of them tagged with a purpose, such that a single governance action leaves an auditable purpose trail.
     ///
     /// # Arguments:
     ///
-    /// - `trusted_minter` - the trusted minter to remove
+    /// - `withdrawals` - The (destination, underlying amount, purpose tag) entries.
     ///
-    /// # Notes
+    /// # Notes:
     ///
-    /// - can only be called by the admin
-    /// - `trusted_minter` must has been already trusted
+    /// - Can only be called by the admin.
+    /// - Each destination must have been configured with `addReservesDestination`.
+    /// - Each purpose tag must be non-empty and not longer than `MAX_RESERVES_PURPOSE_LENGTH`.
     ///
-    pub fn remove_trusted_minter<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    pub fn reduce_reserves_to<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, BigUint<Env::Api>, ManagedBuffer<Env::Api>>>>,
     >(
         self,
-        trusted_minter: Arg0,
+        withdrawals: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("removeTrustedMinter")
-            .argument(&trusted_minter)
+            .raw_call("reduceReservesTo")
+            .argument(&withdrawals)
             .original_result()
     }
 
-    /// Liquidate a risky borrower by taking her Hatom's tokens deposited as collateral at a specified money market
+    /// Adds a destination to which reserves can be withdrawn with a purpose tag, such as the insurance pool, the treasury or
+    /// a buyback program.
     ///
     /// # Arguments:
     ///
-    /// - `borrower` - The account to be liquidated.
-    /// - `collateral_market ` - The money market in which to seize collateral from the borrower.
-    /// - `opt_min_tokens` - The minimum amount of tokens to be seized from the borrower.
+    /// - `destination` - The destination address.
     ///
-    pub fn liquidate_borrow<
-        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn add_reserves_destination<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        borrower: Arg0,
-        collateral_market: Arg1,
-        opt_min_tokens: Arg2,
-    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue2<EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        destination: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("liquidateBorrow")
-            .argument(&borrower)
-            .argument(&collateral_market)
-            .argument(&opt_min_tokens)
+            .payment(NotPayable)
+            .raw_call("addReservesDestination")
+            .argument(&destination)
             .original_result()
     }
 
-    /// Supply underlying to the money market, providing liquidity and accruing interest in exchange. In exchange, minted
-    /// Hatom tokens are directed to the caller, which can be redeemed for underlying at a given point in the future.
+    /// Removes a destination to which reserves can be withdrawn with a purpose tag.
+    ///
+    /// # Arguments:
+    ///
+    /// - `destination` - The destination address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
     ///
-    pub fn mint(
+    pub fn remove_reserves_destination<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        destination: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("mint")
+            .payment(NotPayable)
+            .raw_call("removeReservesDestination")
+            .argument(&destination)
             .original_result()
     }
 
-    /// Mints Hatom's tokens and enters the market in a single transaction.
+    /// Withdraws an specified amount of underlying from the donations bucket to the admin account.
     ///
     /// # Arguments:
     ///
-    /// - `opt_account` - If given, the collateral will be deposited on the name of this account. Can only be performed by a
-    ///   trusted minter.
+    /// - `opt_underlying_amount` - The amount of underlying to withdraw. If not provided, all donations are withdrawn.
     ///
     /// # Notes:
     ///
-    /// - Must be paid with the underlying asset.
+    /// - Can only be called by the admin.
+    /// - The underlying amount is directed to the admin account.
+    /// - Donations never belong to cash, so withdrawing them does not change the exchange rate.
     ///
-    pub fn mint_and_enter_market<
-        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    pub fn claim_donations<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
     >(
         self,
-        opt_account: Arg0,
-    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        opt_underlying_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("mintAndEnterMarket")
-            .argument(&opt_account)
+            .payment(NotPayable)
+            .raw_call("claimDonations")
+            .argument(&opt_underlying_amount)
             .original_result()
     }
 
-    /// Exchanges caller paid Hatom's tokens back for her underlying asset.
+    /// Sets a new accrual time threshold.
     ///
     /// # Arguments:
     ///
-    /// - `opt_underlying_amount` - An optional amount of underlying asset to receive back in exchange for the paid Hatom's
-    ///   tokens.
+    /// - `new_accrual_time_threshold` - The new accrual time threshold in seconds.
     ///
-    pub fn redeem<
-        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn set_accrual_time_threshold<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        opt_underlying_amount: Arg0,
-    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue2<EgldOrEsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        new_accrual_time_threshold: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("redeem")
-            .argument(&opt_underlying_amount)
+            .payment(NotPayable)
+            .raw_call("setAccrualTimeThreshold")
+            .argument(&new_accrual_time_threshold)
             .original_result()
     }
 
-    /// Repays an outstanding borrow to the money market.
+    /// Sets a new maximum accrual gap, i.e. the maximum time allowed between accruals before the gap is considered abnormal,
+    /// in which case no interest is charged for it and borrows are restricted for a while.
     ///
     /// # Arguments:
     ///
-    /// - `opt_borrower` - An optional address to repay on behalf of this account.
+    /// - `new_max_accrual_gap` - The new maximum accrual gap in seconds. Zero disables the check.
     ///
-    /// Notes:
+    /// # Notes:
     ///
-    /// - The repayment amount can be higher than the outstanding borrow. In such case, the remainder is returned.
+    /// - Can only be called by the admin.
     ///
-    pub fn repay_borrow<
-        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    pub fn set_max_accrual_gap<
+        Arg0: ProxyArg<u64>,
     >(
         self,
-        opt_borrower: Arg0,
-    ) -> TxTypedCall<Env, From, To, (), Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        new_max_accrual_gap: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
-            .raw_call("repayBorrow")
-            .argument(&opt_borrower)
+            .payment(NotPayable)
+            .raw_call("setMaxAccrualGap")
+            .argument(&new_max_accrual_gap)
             .original_result()
     }
 
-    /// Handler for `seize_internal` via smart contract to smart contract calls.
+    /// Resolves the pending abnormal accrual gap, which lifts the borrows restriction. Governance explicitly decides whether
+    /// the interest of the gap, computed with the borrow rate in place when it was recorded, is charged or skipped.
     ///
     /// # Arguments:
     ///
-    /// - `liquidator` - The account retrieving the seized collateral.
-    /// - `borrower` - The account having collateral seized.
-    /// - `tokens_to_seize` - The tokens to seize.
+    /// - `charge` - Whether the interest of the pending gap is charged to borrowers.
     ///
-    pub fn seize<
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrows remain restricted until the pending gap is resolved, while repayments and liquidations are not.
+    ///
+    pub fn acknowledge_accrual_gap<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        charge: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acknowledgeAccrualGap")
+            .argument(&charge)
+            .original_result()
+    }
+
+    /// Whitelists a trusted minter contract, i.e. a contract that can mint and enter market in the name of someone else.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minter` - the new trusted minter to whitelist
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `trusted_minter` must be a trusted smart contract
+    /// - `trusted_minter` must not be already trusted
+    ///
+    pub fn add_trusted_minter<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
-        Arg2: ProxyArg<BigUint<Env::Api>>,
     >(
         self,
-        liquidator: Arg0,
-        borrower: Arg1,
-        tokens_to_seize: Arg2,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        trusted_minter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("seize")
-            .argument(&liquidator)
-            .argument(&borrower)
-            .argument(&tokens_to_seize)
+            .raw_call("addTrustedMinter")
+            .argument(&trusted_minter)
             .original_result()
     }
 
-    /// Stores the money market state.
-    pub fn market_state(
+    /// Whitelists a batch of trusted minter contracts, together with their metadata.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minters` - the new trusted minters to whitelist, each one with its name hash and interface version
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - each trusted minter must be a trusted smart contract
+    /// - each trusted minter must not be already trusted
+    ///
+    pub fn add_trusted_minters<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, ManagedBuffer<Env::Api>, u8>>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, State> {
+        trusted_minters: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getState")
+            .raw_call("addTrustedMinters")
+            .argument(&trusted_minters)
             .original_result()
     }
 
-    /// Stores the underlying identifier.
-    pub fn underlying_id(
+    /// Sets the metadata of an already trusted minter, which is useful for trusted minters whitelisted before metadata was
+    /// tracked.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minter` - the trusted minter
+    /// - `name_hash` - the trusted minter name hash
+    /// - `version` - the trusted minter interface version
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `trusted_minter` must has been already trusted
+    /// - the added timestamp is kept if already tracked, otherwise it is set to zero
+    ///
+    pub fn set_trusted_minter_metadata<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u8>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        trusted_minter: Arg0,
+        name_hash: Arg1,
+        version: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUnderlyingId")
+            .raw_call("setTrustedMinterMetadata")
+            .argument(&trusted_minter)
+            .argument(&name_hash)
+            .argument(&version)
             .original_result()
     }
 
-    /// Stores the token identifier.
-    pub fn token_id(
+    /// Removes a trusted minter contract address from the whitelist of trusted minters contracts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minter` - the trusted minter to remove
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `trusted_minter` must has been already trusted
+    ///
+    pub fn remove_trusted_minter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        trusted_minter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTokenId")
+            .raw_call("removeTrustedMinter")
+            .argument(&trusted_minter)
             .original_result()
     }
 
-    /// Stores whether the token is being issued or not.
-    pub fn ongoing_issuance(
+    /// Removes a batch of trusted minter contract addresses from the whitelist of trusted minters contracts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minters` - the trusted minters to remove
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - each trusted minter must has been already trusted
+    ///
+    pub fn remove_trusted_minters<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        trusted_minters: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeTrustedMinters")
+            .argument(&trusted_minters)
+            .original_result()
+    }
+
+    /// Exports the current borrow amounts of the given borrowers, such that they can be imported into a new money market
+    /// instance during a code migration. Each exported entry is chained into a running hash commitment.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should not be active.
+    /// - Borrowers without borrows are skipped.
+    /// - Exported borrows are cleared from this money market, i.e. account borrows are zeroed and the total borrows are
+    ///   decreased accordingly.
+    ///
+    pub fn export_borrow_snapshots<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        borrowers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("exportBorrowSnapshots")
+            .argument(&borrowers)
+            .original_result()
+    }
+
+    /// Commits the final export hash of the borrow snapshots to be imported into this money market, such that imports can
+    /// be verified against it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `export_hash` - The final export hash reported by the exporting money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should not be active.
+    /// - Cannot be changed once the import has started.
+    ///
+    pub fn commit_borrow_snapshots<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        export_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("commitBorrowSnapshots")
+            .argument(&export_hash)
+            .original_result()
+    }
+
+    /// Imports borrow amounts previously exported from another money market instance during a code migration. Each
+    /// imported entry is chained into a running import hash, which must match the committed export hash before the market
+    /// can be activated.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_snapshots` - A page of borrower addresses and borrow amounts, in the exported order.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The market should not be active.
+    /// - The final export hash must have been committed and cannot be exceeded, i.e. no pages can be imported once the
+    ///   import hash matches it.
+    /// - Borrowers cannot have a borrow snapshot already.
+    /// - Borrowers are registered as members of this money market at the Controller.
+    /// - Imported borrows are added to the total borrows and booked against reserves, such that the exchange rate remains
+    ///   unchanged for existing suppliers. Reserves are split between staking rewards and revenue using the stake factor.
+    ///
+    pub fn import_borrow_snapshots<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        borrow_snapshots: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("importBorrowSnapshots")
+            .argument(&borrow_snapshots)
+            .original_result()
+    }
+
+    /// Indexes borrowers that opened their positions before borrowers were indexed, such that their borrow snapshots are
+    /// included in the borrow snapshots export.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Accounts without an outstanding borrow or already indexed are skipped.
+    ///
+    pub fn index_borrowers<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        borrowers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("indexBorrowers")
+            .argument(&borrowers)
+            .original_result()
+    }
+
+    /// Rebases the borrow index, i.e. divides it by a given divisor in order to restore numerical headroom. Account borrow
+    /// snapshots are lazily brought to the rebased borrow index, such that effective debts remain unchanged.
+    ///
+    /// # Arguments:
+    ///
+    /// - `divisor` - The divisor applied to the borrow index.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The divisor must be greater than one and the rebased borrow index cannot be lower than one (in wad).
+    /// - Account borrow snapshots can be migrated in pages using `rebaseBorrowSnapshots`.
+    ///
+    pub fn rebase_borrow_index<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        divisor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rebaseBorrowIndex")
+            .argument(&divisor)
+            .original_result()
+    }
+
+    /// Migrates a page of account borrow snapshots to the latest borrow index rebase, such that they no longer need to be
+    /// lazily brought to the rebased borrow index.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrowers without borrow snapshots or with up to date borrow snapshots are skipped.
+    ///
+    pub fn rebase_borrow_snapshots<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        borrowers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rebaseBorrowSnapshots")
+            .argument(&borrowers)
+            .original_result()
+    }
+
+    /// Sets a hedging hook for the caller, i.e. an external contract (typically a perpetuals adapter) that gets notified
+    /// whenever the caller's borrow amount in this money market changes beyond a given threshold.
+    ///
+    /// # Arguments:
+    ///
+    /// - `hook` - The hedging hook smart contract address.
+    /// - `threshold` - The minimum change in the borrow amount, in underlying, that triggers a notification.
+    /// - `gas_limit` - The gas limit forwarded to each notification.
+    ///
+    /// # Notes:
+    ///
+    /// - The hook must implement the `onBorrowChanged` endpoint.
+    /// - Notifications are asynchronous and failure-isolated: a failing hook never reverts the borrow change.
+    /// - A notification is skipped if there is not enough gas left to forward the gas limit.
+    /// - The gas limit cannot exceed `MAX_HEDGING_HOOK_GAS_LIMIT`.
+    ///
+    pub fn set_hedging_hook<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        hook: Arg0,
+        threshold: Arg1,
+        gas_limit: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setHedgingHook")
+            .argument(&hook)
+            .argument(&threshold)
+            .argument(&gas_limit)
+            .original_result()
+    }
+
+    /// Removes the hedging hook of the caller.
+    ///
+    pub fn remove_hedging_hook(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeHedgingHook")
+            .original_result()
+    }
+
+    /// Liquidate a risky borrower by taking her Hatom's tokens deposited as collateral at a specified money market
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The account to be liquidated.
+    /// - `collateral_market ` - The money market in which to seize collateral from the borrower.
+    /// - `opt_min_tokens` - The minimum amount of tokens to be seized from the borrower.
+    ///
+    /// # Notes:
+    ///
+    /// - If the payment exceeds the maximum repayment allowed by the close factor, only the allowed amount is used and the
+    ///   excess is refunded to the liquidator.
+    ///
+    pub fn liquidate_borrow<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        borrower: Arg0,
+        collateral_market: Arg1,
+        opt_min_tokens: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue2<EsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("liquidateBorrow")
+            .argument(&borrower)
+            .argument(&collateral_market)
+            .argument(&opt_min_tokens)
+            .original_result()
+    }
+
+    /// Supply underlying to the money market, providing liquidity and accruing interest in exchange. In exchange, minted
+    /// Hatom tokens are directed to the caller, which can be redeemed for underlying at a given point in the future.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_min_tokens_out` - An optional minimum amount of Hatom tokens to be received, protecting the minter against
+    ///   unexpected exchange rate movements.
+    ///
+    /// # Notes:
+    ///
+    /// - If the minter has opted in to automatically enter markets at the Controller, the minted tokens are deposited as
+    ///   collateral on its name instead and an empty payment is returned.
+    ///
+    pub fn mint<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        opt_min_tokens_out: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("mint")
+            .argument(&opt_min_tokens_out)
+            .original_result()
+    }
+
+    /// Mints Hatom's tokens and enters the market in a single transaction.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_account` - If given, the collateral will be deposited on the name of this account. Can only be performed by a
+    ///   trusted minter, unless the account is the caller itself.
+    /// - `opt_min_tokens_out` - An optional minimum amount of Hatom tokens to be deposited as collateral, protecting the
+    ///   minter against unexpected exchange rate movements.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlying asset.
+    /// - Returns the minted tokens payment, although the minted tokens are kept as collateral.
+    ///
+    pub fn mint_and_enter_market<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        opt_account: Arg0,
+        opt_min_tokens_out: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("mintAndEnterMarket")
+            .argument(&opt_account)
+            .argument(&opt_min_tokens_out)
+            .original_result()
+    }
+
+    /// Stores the Controller-derived values of this money market, as pushed by the Controller whenever governance changes
+    /// any of them.
+    ///
+    /// # Arguments:
+    ///
+    /// - `mirror` - The Controller-derived values of this money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the Controller.
+    ///
+    pub fn sync_controller_mirror<
+        Arg0: ProxyArg<MarketMirror<Env::Api>>,
+    >(
+        self,
+        mirror: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("syncControllerMirror")
+            .argument(&mirror)
+            .original_result()
+    }
+
+    /// Returns the Controller-derived values of this money market in one place, i.e. its collateral factors, its liquidity
+    /// and borrow caps, its mint, borrow and seize statuses and whether it has been deprecated.
+    ///
+    /// # Notes:
+    ///
+    /// - Values are the ones last pushed by the Controller, which does so from every governance change that affects them.
+    /// - Scheduled collateral factor decreases are applied once their timelock has elapsed.
+    /// - A zero cap means there is no cap.
+    ///
+    pub fn get_controller_mirror(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ControllerMirror<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getControllerMirror")
+            .original_result()
+    }
+
+    /// Returns the up to date collateral factor of this money market, as given by the Controller.
+    ///
+    pub fn get_market_collateral_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketCollateralFactor")
+            .original_result()
+    }
+
+    /// Returns whether this money market has been deprecated, as given by the Controller.
+    ///
+    pub fn is_market_deprecated(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isMarketDeprecated")
+            .original_result()
+    }
+
+    /// Exchanges caller paid Hatom's tokens back for her underlying asset.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_underlying_amount` - An optional amount of underlying asset to receive back in exchange for the paid Hatom's
+    ///   tokens. If not given or zero, all the paid Hatom's tokens are redeemed.
+    /// - `opt_min_underlying_out` - An optional minimum amount of underlying asset to be received, protecting the redeemer
+    ///   against unexpected exchange rate movements.
+    /// - `opt_to` - An optional recipient of the underlying asset. Defaults to the caller.
+    ///
+    /// # Notes:
+    ///
+    /// - If a redeem fee has been set, it is deducted from the underlying amount and credited to reserves.
+    /// - Remainder Hatom's tokens, if any, are always sent back to the caller.
+    ///
+    pub fn redeem<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg1: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+        Arg2: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_underlying_amount: Arg0,
+        opt_min_underlying_out: Arg1,
+        opt_to: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue2<EgldOrEsdtTokenPayment<Env::Api>, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .raw_call("redeem")
+            .argument(&opt_underlying_amount)
+            .argument(&opt_min_underlying_out)
+            .argument(&opt_to)
+            .original_result()
+    }
+
+    /// Repays an outstanding borrow to the money market.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_borrower` - An optional address to repay on behalf of this account.
+    ///
+    /// Notes:
+    ///
+    /// - The repayment amount can be higher than the outstanding borrow. In such case, the remainder is returned.
+    ///
+    pub fn repay_borrow<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_borrower: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("repayBorrow")
+            .argument(&opt_borrower)
+            .original_result()
+    }
+
+    /// Handler for `seize_internal` via smart contract to smart contract calls.
+    ///
+    /// # Arguments:
+    ///
+    /// - `liquidator` - The account retrieving the seized collateral.
+    /// - `borrower` - The account having collateral seized.
+    /// - `tokens_to_seize` - The tokens to seize.
+    ///
+    pub fn seize<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        liquidator: Arg0,
+        borrower: Arg1,
+        tokens_to_seize: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("seize")
+            .argument(&liquidator)
+            .argument(&borrower)
+            .argument(&tokens_to_seize)
+            .original_result()
+    }
+
+    /// Stores the money market state.
+    pub fn market_state(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, State> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getState")
+            .original_result()
+    }
+
+    /// Stores the underlying identifier.
+    pub fn underlying_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnderlyingId")
+            .original_result()
+    }
+
+    /// Stores the token identifier.
+    pub fn token_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenId")
+            .original_result()
+    }
+
+    /// Stores whether the token is being issued or not.
+    pub fn ongoing_issuance(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOngoingIssuance")
+            .original_result()
+    }
+
+    /// Stores whether the token initial supply has been minted or not.
+    pub fn minted_initial_supply(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintedInitialSupply")
+            .original_result()
+    }
+
+    /// Stores the borrow snapshot for a given borrower account.
+    pub fn account_borrow_snapshot<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrower: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AccountSnapshot<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowSnapshot")
+            .argument(&borrower)
+            .original_result()
+    }
+
+    /// Stores the timestamp at which the borrow snapshot of a given borrower was last updated.
+    pub fn account_borrow_snapshot_timestamp<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrower: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowSnapshotTimestamp")
+            .argument(&borrower)
+            .original_result()
+    }
+
+    /// Stores the current balance of the underlying asset.
+    pub fn cash(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCash")
+            .original_result()
+    }
+
+    /// Stores the total amount of outstanding borrows up to the last accrue of interest.
+    pub fn total_borrows(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalBorrows")
+            .original_result()
+    }
+
+    /// Stores the current amount of reserves.
+    pub fn total_reserves(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalReserves")
+            .original_result()
+    }
+
+    /// Stores the total amount of staking rewards.
+    pub fn staking_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingRewards")
+            .original_result()
+    }
+
+    /// Stores the amount of historical staking rewards.
+    pub fn historical_staking_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHistoricalStakingRewards")
+            .original_result()
+    }
+
+    /// Stores the amount of protocol revenue.
+    pub fn revenue(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRevenue")
+            .original_result()
+    }
+
+    /// Stores the amount of underlying sent to the money market outside of its accounting, which is kept apart from cash.
+    pub fn donations(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDonations")
+            .original_result()
+    }
+
+    /// Stores the total supply of the token.
+    pub fn total_supply(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalSupply")
+            .original_result()
+    }
+
+    /// Stores the staking factor used to calculate staking rewards.
+    pub fn stake_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakeFactor")
+            .original_result()
+    }
+
+    /// Stores the redeem fee in bps, credited to reserves at each redemption.
+    pub fn redeem_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRedeemFee")
+            .original_result()
+    }
+
+    /// Stores the timestamp of the last accrual.
+    pub fn accrual_timestamp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccrualTimestamp")
+            .original_result()
+    }
+
+    /// Stores the day (i.e. timestamp over the snapshot interval) of the last interest rate snapshot.
+    pub fn last_rate_snapshot_day(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastRateSnapshotDay")
+            .original_result()
+    }
+
+    /// Stores the initial exchange rate between underlying and token, initialized at deployment.
+    pub fn initial_exchange_rate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInitialExchangeRate")
+            .original_result()
+    }
+
+    /// Stores the current protocol seize share.
+    pub fn protocol_seize_share(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolSeizeShare")
+            .original_result()
+    }
+
+    /// Stores the share of the protocol seized amount that is routed to the insurance fund.
+    pub fn insurance_fund_share(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceFundShare")
+            .original_result()
+    }
+
+    /// Stores the underlying amount accrued for the insurance fund, which is held as reserves until claimed.
+    pub fn insurance_fund_balance(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceFundBalance")
+            .original_result()
+    }
+
+    /// Stores the maximum share of cash that can be deployed into the reserve strategy.
+    pub fn max_deployed_share(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxDeployedShare")
+            .original_result()
+    }
+
+    /// Stores the amount of cash currently deployed into the reserve strategy.
+    pub fn deployed_cash(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDeployedCash")
+            .original_result()
+    }
+
+    /// Stores the maximum amount of cash that can be lent to the USH peg-stability module.
+    pub fn credit_line_cap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCreditLineCap")
+            .original_result()
+    }
+
+    /// Stores the interest rate per second charged on the credit line principal in wad.
+    pub fn credit_line_rate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCreditLineRate")
+            .original_result()
+    }
+
+    /// Stores the amount of cash currently lent to the USH peg-stability module.
+    pub fn credit_line_principal(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCreditLinePrincipal")
+            .original_result()
+    }
+
+    /// Stores the timestamp of the last credit line interest accrual.
+    pub fn credit_line_timestamp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCreditLineTimestamp")
+            .original_result()
+    }
+
+    /// Stores the accrual time threshold.
+    pub fn accrual_time_threshold(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccrualTimeThreshold")
+            .original_result()
+    }
+
+    /// Stores the maximum time allowed between accruals before the gap is considered abnormal, if non-zero.
+    pub fn max_accrual_gap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxAccrualGap")
+            .original_result()
+    }
+
+    /// Stores the timestamp at which the pending abnormal accrual gap has been recorded, if any.
+    pub fn accrual_gap_timestamp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccrualGapTimestamp")
+            .original_result()
+    }
+
+    /// Stores the accumulated duration in seconds of the pending abnormal accrual gaps.
+    pub fn accrual_gap_duration(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccrualGapDuration")
+            .original_result()
+    }
+
+    /// Stores the accumulated borrow rate times elapsed time of the pending abnormal accrual gaps, in wad.
+    pub fn accrual_gap_interest_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccrualGapInterestFactor")
+            .original_result()
+    }
+
+    /// Stores the metadata of each trusted minter.
+    pub fn trusted_minter_metadata<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        trusted_minter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TrustedMinterMetadata<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrustedMinterMetadata")
+            .argument(&trusted_minter)
+            .original_result()
+    }
+
+    /// Stores the next borrow order identifier.
+    pub fn next_borrow_order_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextBorrowOrderId")
+            .original_result()
+    }
+
+    /// Stores the borrow order for a given borrow order identifier.
+    pub fn borrow_orders<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BorrowOrder<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowOrder")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Stores the set of open borrow order identifiers for a given account.
+    pub fn account_borrow_orders<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowOrders")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Stores the running hash commitment of all the borrow snapshots exported from this money market.
+    pub fn borrow_snapshots_export_hash(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowSnapshotsExportHash")
+            .original_result()
+    }
+
+    /// Stores the final export hash committed by governance, which imported borrow snapshots must match.
+    pub fn borrow_snapshots_commitment(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowSnapshotsCommitment")
+            .original_result()
+    }
+
+    /// Stores the running hash commitment of all the borrow snapshots imported into this money market.
+    pub fn borrow_snapshots_import_hash(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowSnapshotsImportHash")
+            .original_result()
+    }
+
+    /// Stores the number of borrow index rebases already applied to the borrow snapshot of a given account.
+    pub fn account_borrow_index_rebases<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowIndexRebases")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Stores the hedging hook registered by a given account.
+    pub fn hedging_hook<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, HedgingHook<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHedgingHook")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Stores the account borrow amount included in the last hedging hook notification for a given account.
+    pub fn hedging_hook_last_notified<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getHedgingHookLastNotified")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Stores the borrow principal of a given account, i.e. its borrow amount without accrued interest. It is only
+    /// tracked for accounts whose principal is known.
+    pub fn account_borrow_principal<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowPrincipal")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Stores the maximum debt an account can have to be eligible for interest forgiveness.
+    pub fn interest_forgiveness_threshold(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInterestForgivenessThreshold")
+            .original_result()
+    }
+
+    /// Stores the total amount of interest forgiven by the interest forgiveness program.
+    pub fn total_forgiven_interest(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalForgivenInterest")
+            .original_result()
+    }
+
+    /// Stores the configured destinations to which reserves can be withdrawn with a purpose tag.
+    pub fn reserves_destinations(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReservesDestinations")
+            .original_result()
+    }
+
+    /// Stores the set of deposit terms, in seconds, available for term deposits.
+    pub fn deposit_terms(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositTerms")
+            .original_result()
+    }
+
+    /// Stores the annualized supply-rate bonus in wad paid to term deposits of a given term.
+    pub fn deposit_term_bonus_rate<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        term: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDepositTermBonusRate")
+            .argument(&term)
+            .original_result()
+    }
+
+    /// Stores the share in wad of the underlying forfeited to reserves when a term deposit is withdrawn before maturity.
+    pub fn early_withdrawal_penalty(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEarlyWithdrawalPenalty")
+            .original_result()
+    }
+
+    /// Stores the next term deposit identifier.
+    pub fn next_term_deposit_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextTermDepositId")
+            .original_result()
+    }
+
+    /// Stores the term deposit for a given term deposit identifier.
+    pub fn term_deposits<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TermDeposit<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTermDeposit")
+            .argument(&deposit_id)
+            .original_result()
+    }
+
+    /// Stores the set of open term deposit identifiers for a given account.
+    pub fn account_term_deposits<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountTermDeposits")
+            .argument(&account)
+            .original_result()
+    }
+
+    /// Stores the amount of reserves committed to pay the bonuses of open term deposits, which is kept apart from revenue.
+    pub fn term_deposit_bonuses(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getOngoingIssuance")
+            .raw_call("getTermDepositBonuses")
             .original_result()
     }
 
-    /// Stores whether the token initial supply has been minted or not.
-    pub fn minted_initial_supply(
+    /// Stores whether the money market is in a protective state because of issuer controls applied to the underlying token,
+    /// such that it can be lifted automatically once they are lifted.
+    pub fn underlying_protection(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMintedInitialSupply")
+            .raw_call("isUnderlyingProtectionActive")
             .original_result()
     }
 
-    /// Stores the borrow snapshot for a given borrower account.
-    pub fn account_borrow_snapshot<
+    /// Stores the underlying owed to a given account because its transfer was deferred while the underlying token was paused
+    /// or the money market balance was frozen.
+    pub fn pending_underlying<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        borrower: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, AccountSnapshot<Env::Api>> {
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccountBorrowSnapshot")
-            .argument(&borrower)
+            .raw_call("getPendingUnderlying")
+            .argument(&account)
             .original_result()
     }
 
-    /// Stores the current balance of the underlying asset.
-    pub fn cash(
+    /// Stores the total underlying owed to accounts because of deferred transfers, which does not belong to cash.
+    pub fn total_pending_underlying(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getCash")
+            .raw_call("getTotalPendingUnderlying")
             .original_result()
     }
 
-    /// Stores the total amount of outstanding borrows up to the last accrue of interest.
-    pub fn total_borrows(
+    /// Stores the proposed Interest Rate Model and the timestamp from which it can be set.
+    pub fn next_interest_rate_model(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (u64, ManagedAddress<Env::Api>)> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTotalBorrows")
+            .raw_call("getNextInterestRateModel")
             .original_result()
     }
 
-    /// Stores the current amount of reserves.
-    pub fn total_reserves(
+    /// Claims staking rewards from the staking contract, and sends them to the caller's account.
+    ///
+    /// This function accrues interest then retrieves the amount of staking rewards and checks if there are any rewards to
+    /// claim. If there are rewards, it transfers them from the protocol's cash balance to the caller's account, updates the
+    /// reserve and staking rewards balance.
+    ///
+    pub fn claim_staking_rewards(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTotalReserves")
+            .raw_call("claimStakingRewards")
             .original_result()
     }
 
-    /// Stores the total amount of staking rewards.
-    pub fn staking_rewards(
+    /// Sets the reserve strategy, i.e. a whitelisted yield source where idle cash can be deployed, and the maximum share of
+    /// cash that can be deployed into it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_strategy` - The reserve strategy smart contract address.
+    /// - `new_max_share` - The maximum share of cash that can be deployed in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The strategy must accept the same underlying as this money market.
+    /// - The maximum share cannot exceed `MAX_DEPLOYED_SHARE`.
+    /// - Changing the strategy requires all deployed cash to be recalled first.
+    ///
+    pub fn set_reserve_strategy<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        new_strategy: Arg0,
+        new_max_share: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getStakingRewards")
+            .raw_call("setReserveStrategy")
+            .argument(&new_strategy)
+            .argument(&new_max_share)
             .original_result()
     }
 
-    /// Stores the amount of historical staking rewards.
-    pub fn historical_staking_rewards(
+    /// Deploys an amount of idle cash into the reserve strategy.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to deploy.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The total deployed cash cannot exceed the maximum deployed share of cash.
+    /// - Deployed cash is still accounted as cash, so the exchange rate and liquidity remain unchanged.
+    ///
+    pub fn deploy_to_strategy<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getHistoricalStakingRewards")
+            .raw_call("deployToStrategy")
+            .argument(&amount)
             .original_result()
     }
 
-    /// Stores the amount of protocol revenue.
-    pub fn revenue(
+    /// Recalls an amount of deployed cash from the reserve strategy. Any amount received on top of the requested amount is
+    /// considered yield and added to the reserves, while any amount missing is realized as a loss.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_amount` - The amount of underlying to recall. If not provided, all deployed cash is recalled.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by the admin at any time, or by anyone when there is a strategy deficit, in which case the recalled
+    ///   amount is capped to the deficit (see `getStrategyDeficit`).
+    /// - A shortfall is absorbed by the reserves first, up to the protocol revenue, and the remainder by suppliers.
+    ///
+    pub fn recall_from_strategy<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        opt_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getRevenue")
+            .raw_call("recallFromStrategy")
+            .argument(&opt_amount)
             .original_result()
     }
 
-    /// Stores the total supply of the token.
-    pub fn total_supply(
+    /// Writes off an amount of deployed cash that the reserve strategy will never return, e.g. after a slashing or an
+    /// exploit of the yield source.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of deployed cash to write off.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The loss is absorbed by the reserves first, up to the protocol revenue, and the remainder by suppliers.
+    ///
+    pub fn write_off_strategy_loss<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getTotalSupply")
+            .raw_call("writeOffStrategyLoss")
+            .argument(&amount)
             .original_result()
     }
 
-    /// Stores the staking factor used to calculate staking rewards.
-    pub fn stake_factor(
+    /// Adds a deposit term or updates its bonus rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `term` - The deposit term in seconds.
+    /// - `bonus_rate` - The annualized supply-rate bonus in wad paid to term deposits of this term.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The bonus rate cannot exceed `MAX_DEPOSIT_TERM_BONUS_RATE`.
+    /// - Already opened term deposits keep the bonus computed when they were opened.
+    ///
+    pub fn set_deposit_term<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        term: Arg0,
+        bonus_rate: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getStakeFactor")
+            .raw_call("setDepositTerm")
+            .argument(&term)
+            .argument(&bonus_rate)
             .original_result()
     }
 
-    /// Stores the timestamp of the last accrual.
-    pub fn accrual_timestamp(
+    /// Removes a deposit term, such that no new term deposits can be opened for it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `term` - The deposit term in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Already opened term deposits are not affected.
+    ///
+    pub fn remove_deposit_term<
+        Arg0: ProxyArg<u64>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        term: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccrualTimestamp")
+            .raw_call("removeDepositTerm")
+            .argument(&term)
             .original_result()
     }
 
-    /// Stores the initial exchange rate between underlying and token, initialized at deployment.
-    pub fn initial_exchange_rate(
+    /// Sets the early withdrawal penalty of term deposits.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_penalty` - The share in wad of the underlying forfeited to reserves on early withdrawals.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The penalty cannot exceed `MAX_EARLY_WITHDRAWAL_PENALTY`.
+    ///
+    pub fn set_early_withdrawal_penalty<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        new_penalty: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getInitialExchangeRate")
+            .raw_call("setEarlyWithdrawalPenalty")
+            .argument(&new_penalty)
             .original_result()
     }
 
-    /// Stores the current protocol seize share.
-    pub fn protocol_seize_share(
+    /// Supplies underlying to the money market and locks it for a fixed term. The minted Hatom tokens are held by the money
+    /// market until withdrawal, keep accruing the variable supply rate and, at maturity, are paid a bonus funded from the
+    /// money market revenue.
+    ///
+    /// # Arguments:
+    ///
+    /// - `term` - The deposit term in seconds, which must be one of the available deposit terms.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlying asset.
+    /// - The bonus is computed on the supplied underlying at the term bonus rate and is committed from revenue at opening,
+    ///   such that it cannot be withdrawn by governance afterwards.
+    /// - Locked tokens cannot be used as collateral.
+    ///
+    pub fn open_term_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        term: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("openTermDeposit")
+            .argument(&term)
+            .original_result()
+    }
+
+    /// Withdraws a term deposit, redeeming its locked Hatom tokens for underlying. At maturity, the committed bonus is paid
+    /// on top. Before maturity, the bonus goes back to revenue and the early withdrawal penalty is forfeited to reserves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `deposit_id` - The term deposit identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the term deposit owner.
+    /// - The redeem fee, if any, also applies.
+    /// - Requires enough cash in the money market to redeem the locked tokens.
+    ///
+    pub fn withdraw_term_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        deposit_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getProtocolSeizeShare")
+            .raw_call("withdrawTermDeposit")
+            .argument(&deposit_id)
             .original_result()
     }
 
-    /// Stores the accrual time threshold.
-    pub fn accrual_time_threshold(
+    /// Returns the issuer controls currently applied to the underlying token, i.e. whether it has been globally paused,
+    /// whether the money market balance has been frozen and whether transfers have been limited.
+    ///
+    /// # Notes:
+    ///
+    /// - All controls are reported as disabled for EGLD.
+    ///
+    pub fn get_underlying_token_controls(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UnderlyingTokenControls> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getAccrualTimeThreshold")
+            .raw_call("getUnderlyingTokenControls")
             .original_result()
     }
 
-    /// Claims staking rewards from the staking contract, and sends them to the caller's account.
+    /// Checks the issuer controls applied to the underlying token and flips the money market into a protective state if
+    /// the underlying has been paused or the money market balance has been frozen. Otherwise, a protective state previously
+    /// set by this check is lifted.
     ///
-    /// This function accrues interest then retrieves the amount of staking rewards and checks if there are any rewards to
-    /// claim. If there are rewards, it transfers them from the protocol's cash balance to the caller's account, updates the
-    /// reserve and staking rewards balance.
+    /// # Notes:
     ///
-    pub fn claim_staking_rewards(
+    /// - Can be called by anyone, such that monitoring bots can react as soon as the issuer acts, instead of having
+    ///   transfers revert in unpredictable places.
+    /// - The protective state halts mints and borrows. It is tracked separately from the admin pause, which is never
+    ///   lifted by this check.
+    ///
+    pub fn check_underlying_controls(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UnderlyingTokenControls> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("claimStakingRewards")
+            .raw_call("checkUnderlyingControls")
+            .original_result()
+    }
+
+    /// Claims the underlying owed to the caller because of transfers deferred while the underlying token was paused or the
+    /// money market balance was frozen by its issuer.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone with pending underlying.
+    /// - The issuer controls must have been lifted.
+    ///
+    pub fn claim_pending_underlying(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimPendingUnderlying")
             .original_result()
     }
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TrustedMinterMetadata<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub name_hash: ManagedBuffer<Api>,
+    pub version: u8,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BorrowIndexAudit<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub borrow_index: BigUint<Api>,
+    pub borrow_index_bits: u32,
+    pub years_to_overflow: Option<u64>,
+    pub total_borrows: BigUint<Api>,
+    pub sampled_borrows: BigUint<Api>,
+    pub sampled_accounts: usize,
+    pub rounding_drift: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
+pub struct RateSnapshot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub timestamp: u64,
+    pub borrow_rate: BigUint<Api>,
+    pub supply_rate: BigUint<Api>,
+    pub utilization: BigUint<Api>,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
 pub enum State {
@@ -1328,3 +3420,83 @@ where
     pub borrow_amount: BigUint<Api>,
     pub borrow_index: BigUint<Api>,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BorrowOrder<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub borrower: ManagedAddress<Api>,
+    pub amount: BigUint<Api>,
+    pub max_borrow_rate: BigUint<Api>,
+    pub expiration: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct HedgingHook<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub hook: ManagedAddress<Api>,
+    pub threshold: BigUint<Api>,
+    pub gas_limit: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MarketMirror<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub collateral_factor: BigUint<Api>,
+    pub ush_borrower_collateral_factor: BigUint<Api>,
+    pub next_collateral_factors: Option<(u64, BigUint<Api>, BigUint<Api>)>,
+    pub liquidity_cap: BigUint<Api>,
+    pub borrow_cap: BigUint<Api>,
+    pub mint_paused: bool,
+    pub borrow_paused: bool,
+    pub seize_paused: bool,
+    pub deprecated: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ControllerMirror<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub collateral_factor: BigUint<Api>,
+    pub ush_borrower_collateral_factor: BigUint<Api>,
+    pub liquidity_cap: BigUint<Api>,
+    pub borrow_cap: BigUint<Api>,
+    pub mint_paused: bool,
+    pub borrow_paused: bool,
+    pub seize_paused: bool,
+    pub deprecated: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TermDeposit<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub owner: ManagedAddress<Api>,
+    pub tokens: BigUint<Api>,
+    pub underlying_amount: BigUint<Api>,
+    pub bonus: BigUint<Api>,
+    pub start: u64,
+    pub maturity: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
+pub struct UnderlyingTokenControls {
+    pub paused: bool,
+    pub frozen: bool,
+    pub limited_transfer: bool,
+}
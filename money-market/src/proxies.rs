@@ -1,7 +1,12 @@
 multiversx_sc::imports!();
 
-use super::{errors::*, events, storage};
+use super::{
+    errors::*,
+    events,
+    storage::{self, HedgingHook},
+};
 use controller::{governance::ProxyTrait as _, market::ProxyTrait as _, policies::ProxyTrait as _, shared::ProxyTrait as _};
+use multiversx_sc::storage::StorageKey;
 
 #[multiversx_sc::module]
 pub trait ProxyModule: events::EventsModule + storage::StorageModule {
@@ -29,6 +34,10 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.get_controller_proxy(None).enter_markets(account).with_esdt_transfer(payment.clone()).execute_on_dest_context()
     }
 
+    fn register_borrower(&self, borrower: &ManagedAddress) {
+        self.get_controller_proxy(None).register_borrower(borrower).execute_on_dest_context()
+    }
+
     fn seize_allowed(&self, collateral_market: &ManagedAddress, borrow_market: &ManagedAddress, borrower: &ManagedAddress, liquidator: &ManagedAddress) -> bool {
         self.get_controller_proxy(None).seize_allowed(collateral_market, borrow_market, borrower, liquidator).execute_on_dest_context()
     }
@@ -65,6 +74,10 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.get_controller_proxy(None).liquidate_borrow_allowed(borrow_market, collateral_market, borrower, amount).execute_on_dest_context()
     }
 
+    fn get_max_liquidation_repayment(&self, borrow_market: &ManagedAddress, borrower: &ManagedAddress) -> BigUint {
+        self.get_controller_proxy(None).get_max_liquidation_repayment(borrow_market, borrower).execute_on_dest_context()
+    }
+
     fn controller_burn_tokens(&self, token_id: &TokenIdentifier, tokens: &BigUint) {
         self.get_controller_proxy(None).burn_tokens(token_id, tokens).execute_on_dest_context()
     }
@@ -77,6 +90,57 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.get_controller_proxy(None).remove_account_market(money_market, OptionalValue::Some(account.clone())).execute_on_dest_context()
     }
 
+    fn is_deprecated(&self, money_market: &ManagedAddress) -> bool {
+        self.get_controller_proxy(None).is_deprecated(money_market).execute_on_dest_context()
+    }
+
+    fn has_auto_enter_markets(&self, account: &ManagedAddress) -> bool {
+        let controller = self.controller().get();
+        let mut storage_key = StorageKey::new(b"auto_enter_markets");
+        storage_key.append_item(account);
+        SingleValueMapper::new_from_address(controller, storage_key).get()
+    }
+
+    fn is_enumeration_allowed(&self, reader: &ManagedAddress) -> bool {
+        if self.controller().is_empty() {
+            return true;
+        }
+        let controller = self.controller().get();
+        let restricted: bool = SingleValueMapper::new_from_address(controller.clone(), StorageKey::new(b"enumeration_restricted")).get();
+        if !restricted {
+            return true;
+        }
+        let mut storage_key = StorageKey::new(b"registered_reader");
+        storage_key.append_item(reader);
+        SingleValueMapper::new_from_address(controller, storage_key).get()
+    }
+
+    fn get_latest_accrual_timestamps(&self) -> (u64, u64) {
+        if self.controller().is_empty() {
+            return (0, 0);
+        }
+        let controller = self.controller().get();
+        let latest_accrual_timestamp: u64 = SingleValueMapper::new_from_address(controller.clone(), StorageKey::new(b"latest_accrual_timestamp")).get();
+        let latest_accrual_gap_timestamp: u64 = SingleValueMapper::new_from_address(controller, StorageKey::new(b"latest_accrual_gap_timestamp")).get();
+        (latest_accrual_timestamp, latest_accrual_gap_timestamp)
+    }
+
+    fn record_accrual(&self, abnormal_accrual_gap: bool) {
+        if self.controller().is_empty() {
+            return;
+        }
+        self.get_controller_proxy(None).record_accrual(abnormal_accrual_gap).execute_on_dest_context()
+    }
+
+    fn get_interest_frozen_until(&self) -> u64 {
+        if self.controller().is_empty() {
+            return 0;
+        }
+        let controller = self.controller().get();
+        let storage_key = StorageKey::new(b"interest_frozen_until");
+        SingleValueMapper::new_from_address(controller, storage_key).get()
+    }
+
     // Interest Rate Model calls
 
     fn is_interest_rate_model(&self, sc_address: &ManagedAddress) -> bool {
@@ -105,6 +169,61 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.get_staking_proxy(Some(sc_address.clone())).is_staking().execute_on_dest_context()
     }
 
+    // Reserve Strategy calls
+
+    fn is_reserve_strategy(&self, sc_address: &ManagedAddress) -> bool {
+        self.get_reserve_strategy_proxy(Some(sc_address.clone())).is_reserve_strategy().execute_on_dest_context()
+    }
+
+    fn get_reserve_strategy_underlying_id(&self, sc_address: &ManagedAddress) -> EgldOrEsdtTokenIdentifier {
+        self.get_reserve_strategy_proxy(Some(sc_address.clone())).get_underlying_id().execute_on_dest_context()
+    }
+
+    fn reserve_strategy_deposit(&self, underlying_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.get_reserve_strategy_proxy(None).deposit().with_egld_or_single_esdt_transfer((underlying_id.clone(), 0, amount.clone())).execute_on_dest_context::<()>()
+    }
+
+    fn reserve_strategy_withdraw(&self, amount: &BigUint) {
+        self.get_reserve_strategy_proxy(None).withdraw(amount).execute_on_dest_context::<()>()
+    }
+
+    // PSM Adapter calls
+
+    fn is_psm_adapter(&self, sc_address: &ManagedAddress) -> bool {
+        self.get_psm_adapter_proxy(Some(sc_address.clone())).is_psm_adapter().execute_on_dest_context()
+    }
+
+    fn get_psm_adapter_underlying_id(&self, sc_address: &ManagedAddress) -> EgldOrEsdtTokenIdentifier {
+        self.get_psm_adapter_proxy(Some(sc_address.clone())).get_underlying_id().execute_on_dest_context()
+    }
+
+    fn psm_adapter_lend(&self, underlying_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.get_psm_adapter_proxy(None).lend().with_egld_or_single_esdt_transfer((underlying_id.clone(), 0, amount.clone())).execute_on_dest_context::<()>()
+    }
+
+    fn psm_adapter_repay(&self, amount: &BigUint) {
+        self.get_psm_adapter_proxy(None).repay(amount).execute_on_dest_context::<()>()
+    }
+
+    fn psm_adapter_write_off(&self, amount: &BigUint) {
+        self.get_psm_adapter_proxy(None).write_off(amount).execute_on_dest_context::<()>()
+    }
+
+    fn get_psm_adapter_available(&self) -> BigUint {
+        self.get_psm_adapter_proxy(None).get_available().execute_on_dest_context()
+    }
+
+    fn get_psm_adapter_drawn(&self) -> BigUint {
+        self.get_psm_adapter_proxy(None).get_drawn().execute_on_dest_context()
+    }
+
+    // Hedging Hook calls
+
+    fn notify_hedging_hook(&self, hedging_hook: &HedgingHook<Self::Api>, account: &ManagedAddress, borrow_amount: &BigUint) {
+        let money_market = self.blockchain().get_sc_address();
+        self.hedging_hook_proxy(hedging_hook.hook.clone()).on_borrow_changed(&money_market, account, borrow_amount).with_gas_limit(hedging_hook.gas_limit).transfer_execute()
+    }
+
     // Trusted Minters calls
 
     fn is_trusted_minter(&self, trusted_minter: &ManagedAddress) -> bool {
@@ -161,6 +280,37 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         }
     }
 
+    #[proxy]
+    fn reserve_strategy_proxy(&self, sc_address: ManagedAddress) -> reserve_strategy_mod::ProxyTo<Self::Api>;
+
+    fn get_reserve_strategy_proxy(&self, sc_address: Option<ManagedAddress>) -> reserve_strategy_mod::ProxyTo<Self::Api> {
+        match sc_address {
+            Some(address) => self.reserve_strategy_proxy(address),
+            None => {
+                require!(!self.reserve_strategy().is_empty(), ERROR_UNDEFINED_RESERVE_STRATEGY);
+                let address = self.reserve_strategy().get();
+                self.reserve_strategy_proxy(address)
+            },
+        }
+    }
+
+    #[proxy]
+    fn psm_adapter_proxy(&self, sc_address: ManagedAddress) -> psm_adapter_mod::ProxyTo<Self::Api>;
+
+    fn get_psm_adapter_proxy(&self, sc_address: Option<ManagedAddress>) -> psm_adapter_mod::ProxyTo<Self::Api> {
+        match sc_address {
+            Some(address) => self.psm_adapter_proxy(address),
+            None => {
+                require!(!self.psm_adapter().is_empty(), ERROR_UNDEFINED_PSM_ADAPTER);
+                let address = self.psm_adapter().get();
+                self.psm_adapter_proxy(address)
+            },
+        }
+    }
+
+    #[proxy]
+    fn hedging_hook_proxy(&self, sc_address: ManagedAddress) -> hedging_hook_mod::ProxyTo<Self::Api>;
+
     #[proxy]
     fn trusted_minter_proxy(&self, sc_address: ManagedAddress) -> trusted_minter_mod::ProxyTo<Self::Api>;
 
@@ -193,6 +343,65 @@ mod staking_mod {
     }
 }
 
+mod reserve_strategy_mod {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait ReserveStrategy {
+        #[view(isReserveStrategy)]
+        fn is_reserve_strategy(&self) -> bool;
+
+        #[view(getUnderlyingId)]
+        fn get_underlying_id(&self) -> EgldOrEsdtTokenIdentifier;
+
+        #[payable("*")]
+        #[endpoint(deposit)]
+        fn deposit(&self);
+
+        #[endpoint(withdraw)]
+        fn withdraw(&self, amount: &BigUint);
+    }
+}
+
+mod psm_adapter_mod {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait PsmAdapter {
+        #[view(isPsmAdapter)]
+        fn is_psm_adapter(&self) -> bool;
+
+        #[view(getUnderlyingId)]
+        fn get_underlying_id(&self) -> EgldOrEsdtTokenIdentifier;
+
+        #[payable("*")]
+        #[endpoint(lend)]
+        fn lend(&self);
+
+        #[endpoint(repay)]
+        fn repay(&self, amount: &BigUint);
+
+        #[endpoint(writeOff)]
+        fn write_off(&self, amount: &BigUint);
+
+        #[view(getAvailable)]
+        fn get_available(&self) -> BigUint;
+
+        #[view(getDrawn)]
+        fn get_drawn(&self) -> BigUint;
+    }
+}
+
+mod hedging_hook_mod {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait HedgingHook {
+        #[endpoint(onBorrowChanged)]
+        fn on_borrow_changed(&self, money_market: &ManagedAddress, account: &ManagedAddress, borrow_amount: &BigUint);
+    }
+}
+
 mod trusted_minter_mod {
     multiversx_sc::imports!();
 
@@ -1,36 +1,54 @@
 multiversx_sc::imports!();
 
-use super::{common, errors::*, events, proxies, storage};
+use super::{common, errors::*, events, proxies, storage, token_controls};
+use math::Rounding;
 
 pub type RedeemResultType<BigUint> = MultiValue2<EgldOrEsdtTokenPayment<BigUint>, EsdtTokenPayment<BigUint>>;
 
 #[multiversx_sc::module]
-pub trait RedeemModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait RedeemModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule + token_controls::TokenControlsModule {
     /// Exchanges caller paid Hatom's tokens back for her underlying asset.
     ///
     /// # Arguments:
     ///
     /// - `opt_underlying_amount` - An optional amount of underlying asset to receive back in exchange for the paid Hatom's
-    ///   tokens.
+    ///   tokens. If not given or zero, all the paid Hatom's tokens are redeemed.
+    /// - `opt_min_underlying_out` - An optional minimum amount of underlying asset to be received, protecting the redeemer
+    ///   against unexpected exchange rate movements.
+    /// - `opt_to` - An optional recipient of the underlying asset. Defaults to the caller.
+    ///
+    /// # Notes:
+    ///
+    /// - If a redeem fee has been set, it is deducted from the underlying amount and credited to reserves.
+    /// - Remainder Hatom's tokens, if any, are always sent back to the caller.
     ///
     #[payable("*")]
     #[endpoint(redeem)]
-    fn redeem(&self, opt_underlying_amount: OptionalValue<BigUint>) -> RedeemResultType<Self::Api> {
+    fn redeem(&self, opt_underlying_amount: OptionalValue<BigUint>, opt_min_underlying_out: OptionalValue<BigUint>, opt_to: OptionalValue<ManagedAddress>) -> RedeemResultType<Self::Api> {
         self.accrue_interest();
 
         let redeemer = self.blockchain().get_caller();
+        let recipient = match opt_to {
+            OptionalValue::Some(to) => to,
+            OptionalValue::None => redeemer.clone(),
+        };
         let (token_id, tokens) = self.call_value().single_fungible_esdt();
 
         require!(token_id == self.token_id().get(), ERROR_INVALID_TOKEN_PAYMENT);
         require!(tokens > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
 
-        match opt_underlying_amount {
-            OptionalValue::Some(underlying_amount) => {
-                require!(underlying_amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
-                self.redeem_underlying_amount(redeemer, tokens, underlying_amount)
-            },
-            OptionalValue::None => self.redeem_tokens(redeemer, tokens),
+        let (underlying_payment, token_payment) = match opt_underlying_amount {
+            // a zero amount redeems all the paid tokens, such that the minimum output and the recipient can still be given
+            OptionalValue::Some(underlying_amount) if underlying_amount > BigUint::zero() => self.redeem_underlying_amount(redeemer, recipient, tokens, underlying_amount),
+            _ => self.redeem_tokens(redeemer, recipient, tokens),
+        }
+        .into_tuple();
+
+        if let Some(min_underlying_out) = opt_min_underlying_out.into_option() {
+            require!(underlying_payment.amount >= min_underlying_out, ERROR_NOT_ENOUGH_REDEEMED_UNDERLYING);
         }
+
+        (underlying_payment, token_payment).into()
     }
 
     /// The caller redeems Hatom's tokens in exchange for the underlying asset.
@@ -38,9 +56,10 @@ pub trait RedeemModule: common::CommonModule + events::EventsModule + proxies::P
     /// # Arguments:
     ///
     /// - `redeemer` - The address of the account which is redeeming the tokens.
+    /// - `recipient` - The address of the account which receives the underlying.
     /// - `tokens` - The amount of Hatom's tokens to redeem into underlying.
     ///
-    fn redeem_tokens(&self, redeemer: ManagedAddress, tokens: BigUint) -> RedeemResultType<Self::Api> {
+    fn redeem_tokens(&self, redeemer: ManagedAddress, recipient: ManagedAddress, tokens: BigUint) -> RedeemResultType<Self::Api> {
         // no need to check if redeeming is allowed, the redeemer has already exit market and received its Hatom's tokens
         // back if allowed.
 
@@ -50,10 +69,10 @@ pub trait RedeemModule: common::CommonModule + events::EventsModule + proxies::P
         // compute the underlying amount to be redeemed
         let underlying_amount = self.tokens_to_underlying_amount(&tokens);
 
-        self.redeem_internal(&redeemer, &tokens, &underlying_amount);
+        let underlying_amount = self.redeem_internal(&redeemer, &recipient, &tokens, &underlying_amount);
 
         self.emit_updated_rates();
-        self.redeem_event(&redeemer, &underlying_amount, &tokens);
+        self.emit_redeem_events(&redeemer, &recipient, &underlying_amount, &tokens);
 
         let underlying_payment = EgldOrEsdtTokenPayment::new(self.underlying_id().get(), 0, underlying_amount);
         let token_payment = EsdtTokenPayment::new(self.token_id().get(), 0, tokens);
@@ -66,10 +85,11 @@ pub trait RedeemModule: common::CommonModule + events::EventsModule + proxies::P
     /// # Arguments:
     ///
     /// - `redeemer` - The address of the account which is redeeming the tokens.
+    /// - `recipient` - The address of the account which receives the underlying.
     /// - `paid_tokens` - The amount of Hatom's tokens to redeem into underlying.
     /// - `underlying_amount` - The amount of underlying to receive back in exchange from the paid Hatom's tokens.
     ///
-    fn redeem_underlying_amount(&self, redeemer: ManagedAddress, paid_tokens: BigUint, underlying_amount: BigUint) -> RedeemResultType<Self::Api> {
+    fn redeem_underlying_amount(&self, redeemer: ManagedAddress, recipient: ManagedAddress, paid_tokens: BigUint, underlying_amount: BigUint) -> RedeemResultType<Self::Api> {
         // no need to check if redeeming is allowed, the redeemer has already exited market and received its Hatom's tokens
         // back if allowed.
 
@@ -81,7 +101,7 @@ pub trait RedeemModule: common::CommonModule + events::EventsModule + proxies::P
         require!(tokens > BigUint::zero(), ERROR_NOT_ENOUGH_UNDERLYING);
         require!(paid_tokens >= tokens, ERROR_NOT_ENOUGH_TOKENS_TO_REDEEM);
 
-        self.redeem_internal(&redeemer, &tokens, &underlying_amount);
+        let underlying_amount = self.redeem_internal(&redeemer, &recipient, &tokens, &underlying_amount);
 
         // send back remainder Hatom's tokens only if necessary
         if paid_tokens > tokens {
@@ -91,7 +111,7 @@ pub trait RedeemModule: common::CommonModule + events::EventsModule + proxies::P
         }
 
         self.emit_updated_rates();
-        self.redeem_event(&redeemer, &underlying_amount, &tokens);
+        self.emit_redeem_events(&redeemer, &recipient, &underlying_amount, &tokens);
 
         let underlying_payment = EgldOrEsdtTokenPayment::new(self.underlying_id().get(), 0, underlying_amount);
         let token_payment = EsdtTokenPayment::new(self.token_id().get(), 0, tokens);
@@ -99,22 +119,47 @@ pub trait RedeemModule: common::CommonModule + events::EventsModule + proxies::P
         (underlying_payment, token_payment).into()
     }
 
-    fn redeem_internal(&self, redeemer: &ManagedAddress, tokens: &BigUint, underlying_amount: &BigUint) {
-        self.try_ensure_staking_rewards(underlying_amount);
+    /// Burns the redeemed Hatom's tokens and sends the underlying to the recipient, net of the redeem fee. Returns the
+    /// underlying amount effectively sent.
+    ///
+    fn redeem_internal(&self, redeemer: &ManagedAddress, recipient: &ManagedAddress, tokens: &BigUint, underlying_amount: &BigUint) -> BigUint {
+        // the redeem fee remains in cash as part of the reserves
+        let fee = self.bps_mul(underlying_amount, &self.redeem_fee().get(), Rounding::Down);
+        let underlying_amount = underlying_amount - &fee;
+
+        self.try_ensure_staking_rewards(&underlying_amount);
 
         // update cash
-        self.cash().update(|amount| *amount -= underlying_amount);
+        self.cash().update(|amount| *amount -= &underlying_amount);
+
+        if fee > BigUint::zero() {
+            self.total_reserves().update(|amount| *amount += &fee);
+            self.revenue().update(|amount| *amount += &fee);
+
+            let new_total_reserves = self.total_reserves().get();
+            self.redeem_fee_event(redeemer, &fee, &new_total_reserves);
+        }
 
         // update total supply
         self.total_supply().update(|_tokens| *_tokens -= tokens);
 
         let token_id = self.token_id().get();
-        let underlying_id = self.underlying_id().get();
 
         // burn Hatom's tokens to redeem
         self.send().esdt_local_burn(&token_id, 0, tokens);
 
-        // send underlying to redeemer
-        self.send().direct(redeemer, &underlying_id, 0, underlying_amount);
+        // send underlying to recipient
+        self.send_underlying(recipient, &underlying_amount);
+
+        underlying_amount
+    }
+
+    /// Emits the redeem event and, if the underlying has been sent to a different recipient, the redeem to event.
+    ///
+    fn emit_redeem_events(&self, redeemer: &ManagedAddress, recipient: &ManagedAddress, underlying_amount: &BigUint, tokens: &BigUint) {
+        self.redeem_event(redeemer, underlying_amount, tokens);
+        if redeemer != recipient {
+            self.redeem_to_event(redeemer, recipient, underlying_amount, tokens);
+        }
     }
 }
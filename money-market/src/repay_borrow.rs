@@ -1,9 +1,9 @@
 multiversx_sc::imports!();
 
-use super::{borrow, common, errors::*, events, proxies, storage};
+use super::{borrow, common, errors::*, events, hedging, proxies, storage};
 
 #[multiversx_sc::module]
-pub trait RepayBorrowModule: borrow::BorrowModule + common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait RepayBorrowModule: borrow::BorrowModule + common::CommonModule + events::EventsModule + hedging::HedgingModule + proxies::ProxyModule + storage::StorageModule {
     /// Repays an outstanding borrow to the money market.
     ///
     /// # Arguments:
@@ -73,6 +73,7 @@ pub trait RepayBorrowModule: borrow::BorrowModule + common::CommonModule + event
         let borrow_index = self.get_borrow_index();
         let new_borrower_borrow_amount = &borrower_current_borrow_amount - &underlying_amount;
         self.set_account_borrow_snapshot(borrower, &new_borrower_borrow_amount, &borrow_index);
+        self.track_repaid_principal(borrower, &new_borrower_borrow_amount);
 
         // update money market borrowed amount
         let new_total_borrows = current_total_borrows - &underlying_amount;
@@ -81,16 +82,16 @@ pub trait RepayBorrowModule: borrow::BorrowModule + common::CommonModule + event
         // update cash
         self.cash().update(|amount| *amount += &underlying_amount);
 
-        let underlying_id = self.underlying_id().get();
-        if underlying_amount_left > BigUint::zero() {
-            self.send().direct(payer, &underlying_id, 0, &underlying_amount_left);
-        }
+        self.send_underlying(payer, &underlying_amount_left);
 
         self.try_remove_account_market(&money_market, borrower);
 
+        self.try_notify_hedging_hook(borrower, &new_borrower_borrow_amount);
+
         self.emit_updated_rates();
         self.repay_borrow_event(payer, borrower, &underlying_amount, &new_borrower_borrow_amount, &new_total_borrows);
 
+        let underlying_id = self.underlying_id().get();
         EgldOrEsdtTokenPayment::new(underlying_id, 0, underlying_amount)
     }
 }
@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use super::{common, constants::*, errors::*, events, proxies, storage};
+use super::{common, errors::*, events, proxies, storage};
 
 #[multiversx_sc::module]
 pub trait SeizeModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
@@ -42,22 +42,31 @@ pub trait SeizeModule: common::CommonModule + events::EventsModule + proxies::Pr
         let new_borrower_collateral_tokens = &borrower_collateral_tokens - tokens_to_seize;
         self.set_account_collateral_tokens(&collateral_market, borrower, &new_borrower_collateral_tokens);
 
-        // for exponential math
-        let wad = BigUint::from(WAD);
         let protocol_seize_share = self.protocol_seize_share().get();
 
         // seized tokens will be transferred to both liquidator and the protocol reserves (redeemed to underlying)
-        let protocol_seize_tokens = protocol_seize_share * tokens_to_seize / &wad;
+        let protocol_seize_tokens = self.wad_mul(&protocol_seize_share, tokens_to_seize);
         let liquidator_seize_tokens = tokens_to_seize - &protocol_seize_tokens;
 
         // At this point, the protocol redeems a portion of the seized Hatom's tokens for underlying, which is added to the
         // reserves. The underlying is already deposited at this money market SC so there is no need to transfer it.
-        let delta_reserves = self.tokens_to_underlying_amount(&protocol_seize_tokens);
-        self.total_reserves().update(|amount| *amount += &delta_reserves);
+        let protocol_seize_amount = self.tokens_to_underlying_amount(&protocol_seize_tokens);
+
+        // a share of the protocol seize amount might be routed to the insurance fund instead
+        let opt_insurance_fund = self.get_insurance_fund();
+        let insurance_fund_amount = match opt_insurance_fund {
+            Some(_) => self.wad_mul(&self.insurance_fund_share().get(), &protocol_seize_amount),
+            None => BigUint::zero(),
+        };
+
+        // the insurance fund share is held as reserves until claimed, such that seizing never depends on available cash
+        let delta_reserves = &protocol_seize_amount - &insurance_fund_amount;
+        self.total_reserves().update(|amount| *amount += &protocol_seize_amount);
+        self.insurance_fund_balance().update(|amount| *amount += &insurance_fund_amount);
 
         // also, update staking rewards and revenue
         let fs = self.stake_factor().get();
-        let delta_rewards = fs * &delta_reserves / &wad;
+        let delta_rewards = self.wad_mul(&fs, &delta_reserves);
         let delta_revenue = &delta_reserves - &delta_rewards;
 
         self.revenue().update(|amount| *amount += &delta_revenue);
@@ -68,6 +77,10 @@ pub trait SeizeModule: common::CommonModule + events::EventsModule + proxies::Pr
         self.total_supply().update(|tokens| *tokens -= &protocol_seize_tokens);
         self.controller_burn_tokens(&token_id, &protocol_seize_tokens);
 
+        if let Some(insurance_fund) = opt_insurance_fund {
+            self.protocol_seize_split_event(&insurance_fund, &insurance_fund_amount, &delta_reserves);
+        }
+
         // send Hatom's tokens to liquidator
         let liquidator_payment = EsdtTokenPayment::new(token_id, 0, liquidator_seize_tokens);
         self.controller_transfer_tokens(&liquidator, &liquidator_payment);
@@ -1,9 +1,9 @@
 multiversx_sc::imports!();
 
-use super::{common, errors::*, events, proxies, storage};
+use super::{common, errors::*, events, proxies, storage, token_controls};
 
 #[multiversx_sc::module]
-pub trait StakingModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait StakingModule: common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule + token_controls::TokenControlsModule {
     /// Claims staking rewards from the staking contract, and sends them to the caller's account.
     ///
     /// This function accrues interest then retrieves the amount of staking rewards and checks if there are any rewards to
@@ -20,7 +20,7 @@ pub trait StakingModule: common::CommonModule + events::EventsModule + proxies::
         self.accrue_interest();
         self.require_market_fresh();
 
-        let cash = self.cash().get();
+        let cash = self.get_on_hand_cash();
         let staking_rewards = self.staking_rewards().get();
 
         // do nothing
@@ -34,8 +34,7 @@ pub trait StakingModule: common::CommonModule + events::EventsModule + proxies::
         self.staking_rewards().update(|amount| *amount -= &staking_rewards);
         self.cash().update(|amount| *amount -= &staking_rewards);
 
-        let (underlying_id, _) = self.get_money_market_identifiers();
-        self.send().direct(&staking_sc, &underlying_id, 0, &staking_rewards);
+        self.send_underlying(&staking_sc, &staking_rewards);
 
         self.emit_updated_rates();
         self.staking_rewards_claimed_event(&staking_sc, &staking_rewards);
@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use controller::storage::MarketMirror;
+
 /// The money market state.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
@@ -21,6 +23,117 @@ where
     pub borrow_index: BigUint<M>,
 }
 
+/// Represents a borrow order, i.e. a borrow intent that can be executed by anyone as long as the borrow rate remains below
+/// the borrower's threshold and the order has not expired.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BorrowOrder<M>
+where
+    M: ManagedTypeApi,
+{
+    pub id: u64,
+    pub borrower: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+    pub max_borrow_rate: BigUint<M>,
+    pub expiration: u64,
+}
+
+/// Represents a daily snapshot of the money market interest rates, taken at the first accrual of each day.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
+pub struct RateSnapshot<M>
+where
+    M: ManagedTypeApi,
+{
+    pub timestamp: u64,
+    pub borrow_rate: BigUint<M>,
+    pub supply_rate: BigUint<M>,
+    pub utilization: BigUint<M>,
+}
+
+/// Represents an audit of the borrow index magnitude and of the rounding drift accumulated by the money market borrows.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BorrowIndexAudit<M>
+where
+    M: ManagedTypeApi,
+{
+    pub borrow_index: BigUint<M>,
+    pub borrow_index_bits: u32,
+    pub years_to_overflow: Option<u64>,
+    pub total_borrows: BigUint<M>,
+    pub sampled_borrows: BigUint<M>,
+    pub sampled_accounts: usize,
+    pub rounding_drift: BigUint<M>,
+}
+
+/// Represents the metadata of a trusted minter.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TrustedMinterMetadata<M>
+where
+    M: ManagedTypeApi,
+{
+    pub name_hash: ManagedBuffer<M>,
+    pub version: u8,
+    pub timestamp: u64,
+}
+
+/// Represents the Controller-derived values of this money market, as mirrored by the money market.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ControllerMirror<M>
+where
+    M: ManagedTypeApi,
+{
+    pub collateral_factor: BigUint<M>,
+    pub ush_borrower_collateral_factor: BigUint<M>,
+    pub liquidity_cap: BigUint<M>,
+    pub borrow_cap: BigUint<M>,
+    pub mint_paused: bool,
+    pub borrow_paused: bool,
+    pub seize_paused: bool,
+    pub deprecated: bool,
+}
+
+/// Represents an account hedging hook, i.e. an external contract notified when the account borrow amount changes.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct HedgingHook<M>
+where
+    M: ManagedTypeApi,
+{
+    pub hook: ManagedAddress<M>,
+    pub threshold: BigUint<M>,
+    pub gas_limit: u64,
+}
+
+/// Represents the issuer controls currently applied to the underlying ESDT token, as reported by the protocol.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
+pub struct UnderlyingTokenControls {
+    pub paused: bool,
+    pub frozen: bool,
+    pub limited_transfer: bool,
+}
+
+/// Represents a term deposit, i.e. supplied underlying locked for a fixed term in exchange for a supply-rate bonus funded
+/// from reserves.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TermDeposit<M>
+where
+    M: ManagedTypeApi,
+{
+    pub id: u64,
+    pub owner: ManagedAddress<M>,
+    pub tokens: BigUint<M>,
+    pub underlying_amount: BigUint<M>,
+    pub bonus: BigUint<M>,
+    pub start: u64,
+    pub maturity: u64,
+}
+
 #[multiversx_sc::module]
 pub trait StorageModule {
     /// Stores the money market state.
@@ -53,6 +166,15 @@ pub trait StorageModule {
     #[storage_mapper("account_borrow_snapshot")]
     fn account_borrow_snapshot(&self, borrower: &ManagedAddress) -> SingleValueMapper<AccountSnapshot<Self::Api>>;
 
+    /// Stores the timestamp at which the borrow snapshot of a given borrower was last updated.
+    #[view(getAccountBorrowSnapshotTimestamp)]
+    #[storage_mapper("account_borrow_snapshot_timestamp")]
+    fn account_borrow_snapshot_timestamp(&self, borrower: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Stores the set of accounts with an outstanding borrow.
+    #[storage_mapper("borrowers")]
+    fn borrowers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
     /// Stores the current balance of the underlying asset.
     #[view(getCash)]
     #[storage_mapper("cash")]
@@ -83,6 +205,11 @@ pub trait StorageModule {
     #[storage_mapper("revenue")]
     fn revenue(&self) -> SingleValueMapper<BigUint>;
 
+    /// Stores the amount of underlying sent to the money market outside of its accounting, which is kept apart from cash.
+    #[view(getDonations)]
+    #[storage_mapper("donations")]
+    fn donations(&self) -> SingleValueMapper<BigUint>;
+
     /// Stores the total supply of the token.
     #[view(getTotalSupply)]
     #[storage_mapper("total_supply")]
@@ -97,11 +224,29 @@ pub trait StorageModule {
     #[storage_mapper("stake_factor")]
     fn stake_factor(&self) -> SingleValueMapper<BigUint>;
 
+    /// Stores the redeem fee in bps, credited to reserves at each redemption.
+    #[view(getRedeemFee)]
+    #[storage_mapper("redeem_fee")]
+    fn redeem_fee(&self) -> SingleValueMapper<BigUint>;
+
     /// Stores the timestamp of the last accrual.
     #[view(getAccrualTimestamp)]
     #[storage_mapper("accrual_timestamp")]
     fn accrual_timestamp(&self) -> SingleValueMapper<u64>;
 
+    /// Stores a bounded ring buffer of daily interest rate snapshots.
+    #[storage_mapper("rate_snapshots")]
+    fn rate_snapshots(&self) -> VecMapper<RateSnapshot<Self::Api>>;
+
+    /// Stores the position in the ring buffer of the next interest rate snapshot to be overwritten, once the buffer is full.
+    #[storage_mapper("next_rate_snapshot_position")]
+    fn next_rate_snapshot_position(&self) -> SingleValueMapper<usize>;
+
+    /// Stores the day (i.e. timestamp over the snapshot interval) of the last interest rate snapshot.
+    #[view(getLastRateSnapshotDay)]
+    #[storage_mapper("last_rate_snapshot_day")]
+    fn last_rate_snapshot_day(&self) -> SingleValueMapper<u64>;
+
     /// Stores the borrow index up to the last accrual of interest.
     #[storage_mapper("borrow_index")]
     fn borrow_index(&self) -> SingleValueMapper<BigUint>;
@@ -136,12 +281,236 @@ pub trait StorageModule {
     #[storage_mapper("protocol_seize_share")]
     fn protocol_seize_share(&self) -> SingleValueMapper<BigUint>;
 
+    /// Stores the address of the insurance fund.
+    #[storage_mapper("insurance_fund")]
+    fn insurance_fund(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the share of the protocol seized amount that is routed to the insurance fund.
+    #[view(getInsuranceFundShare)]
+    #[storage_mapper("insurance_fund_share")]
+    fn insurance_fund_share(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the underlying amount accrued for the insurance fund, which is held as reserves until claimed.
+    #[view(getInsuranceFundBalance)]
+    #[storage_mapper("insurance_fund_balance")]
+    fn insurance_fund_balance(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the address of the reserve strategy where idle cash can be deployed to earn yield.
+    #[storage_mapper("reserve_strategy")]
+    fn reserve_strategy(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the maximum share of cash that can be deployed into the reserve strategy.
+    #[view(getMaxDeployedShare)]
+    #[storage_mapper("max_deployed_share")]
+    fn max_deployed_share(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the amount of cash currently deployed into the reserve strategy.
+    #[view(getDeployedCash)]
+    #[storage_mapper("deployed_cash")]
+    fn deployed_cash(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the address of the PSM adapter through which idle cash can be lent to the USH peg-stability module.
+    #[storage_mapper("psm_adapter")]
+    fn psm_adapter(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the maximum amount of cash that can be lent to the USH peg-stability module.
+    #[view(getCreditLineCap)]
+    #[storage_mapper("credit_line_cap")]
+    fn credit_line_cap(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the interest rate per second charged on the credit line principal in wad.
+    #[view(getCreditLineRate)]
+    #[storage_mapper("credit_line_rate")]
+    fn credit_line_rate(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the amount of cash currently lent to the USH peg-stability module.
+    #[view(getCreditLinePrincipal)]
+    #[storage_mapper("credit_line_principal")]
+    fn credit_line_principal(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the credit line interest accrued but not yet paid up to the last credit line accrual.
+    #[storage_mapper("credit_line_interest")]
+    fn credit_line_interest(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the timestamp of the last credit line interest accrual.
+    #[view(getCreditLineTimestamp)]
+    #[storage_mapper("credit_line_timestamp")]
+    fn credit_line_timestamp(&self) -> SingleValueMapper<u64>;
+
     /// Stores the accrual time threshold.
     #[view(getAccrualTimeThreshold)]
     #[storage_mapper("accrual_time_threshold")]
     fn accrual_time_threshold(&self) -> SingleValueMapper<u64>;
 
+    /// Stores the maximum time allowed between accruals before the gap is considered abnormal, if non-zero.
+    #[view(getMaxAccrualGap)]
+    #[storage_mapper("max_accrual_gap")]
+    fn max_accrual_gap(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the timestamp at which the pending abnormal accrual gap has been recorded, if any.
+    #[view(getAccrualGapTimestamp)]
+    #[storage_mapper("accrual_gap_timestamp")]
+    fn accrual_gap_timestamp(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the accumulated duration in seconds of the pending abnormal accrual gaps.
+    #[view(getAccrualGapDuration)]
+    #[storage_mapper("accrual_gap_duration")]
+    fn accrual_gap_duration(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the accumulated borrow rate times elapsed time of the pending abnormal accrual gaps, in wad.
+    #[view(getAccrualGapInterestFactor)]
+    #[storage_mapper("accrual_gap_interest_factor")]
+    fn accrual_gap_interest_factor(&self) -> SingleValueMapper<BigUint>;
+
     /// Stores a whitelist of trusted smart contracts that can mint and enter market on behalf of users.
     #[storage_mapper("trusted_minters_list")]
     fn trusted_minters_list(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    /// Stores the set of trusted minters with metadata, used for enumeration.
+    #[storage_mapper("trusted_minters")]
+    fn trusted_minters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Stores the metadata of each trusted minter.
+    #[view(getTrustedMinterMetadata)]
+    #[storage_mapper("trusted_minter_metadata")]
+    fn trusted_minter_metadata(&self, trusted_minter: &ManagedAddress) -> SingleValueMapper<TrustedMinterMetadata<Self::Api>>;
+
+    /// Stores the next borrow order identifier.
+    #[view(getNextBorrowOrderId)]
+    #[storage_mapper("next_borrow_order_id")]
+    fn next_borrow_order_id(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the borrow order for a given borrow order identifier.
+    #[view(getBorrowOrder)]
+    #[storage_mapper("borrow_orders")]
+    fn borrow_orders(&self, order_id: u64) -> SingleValueMapper<BorrowOrder<Self::Api>>;
+
+    /// Stores the set of open borrow order identifiers for a given account.
+    #[view(getAccountBorrowOrders)]
+    #[storage_mapper("account_borrow_orders")]
+    fn account_borrow_orders(&self, account: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    /// Stores the running hash commitment of all the borrow snapshots exported from this money market.
+    #[view(getBorrowSnapshotsExportHash)]
+    #[storage_mapper("borrow_snapshots_export_hash")]
+    fn borrow_snapshots_export_hash(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Stores the final export hash committed by governance, which imported borrow snapshots must match.
+    #[view(getBorrowSnapshotsCommitment)]
+    #[storage_mapper("borrow_snapshots_commitment")]
+    fn borrow_snapshots_commitment(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Stores the running hash commitment of all the borrow snapshots imported into this money market.
+    #[view(getBorrowSnapshotsImportHash)]
+    #[storage_mapper("borrow_snapshots_import_hash")]
+    fn borrow_snapshots_import_hash(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Stores the divisor applied to the borrow index at each borrow index rebase.
+    #[storage_mapper("borrow_index_rebases")]
+    fn borrow_index_rebases(&self) -> VecMapper<BigUint>;
+
+    /// Stores the product of all the divisors applied to the borrow index by borrow index rebases.
+    #[storage_mapper("borrow_index_rebase_factor")]
+    fn borrow_index_rebase_factor(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the number of borrow index rebases already applied to the borrow snapshot of a given account.
+    #[view(getAccountBorrowIndexRebases)]
+    #[storage_mapper("account_borrow_index_rebases")]
+    fn account_borrow_index_rebases(&self, account: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    /// Stores the hedging hook registered by a given account.
+    #[view(getHedgingHook)]
+    #[storage_mapper("hedging_hook")]
+    fn hedging_hook(&self, account: &ManagedAddress) -> SingleValueMapper<HedgingHook<Self::Api>>;
+
+    /// Stores the account borrow amount included in the last hedging hook notification for a given account.
+    #[view(getHedgingHookLastNotified)]
+    #[storage_mapper("hedging_hook_last_notified")]
+    fn hedging_hook_last_notified(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Stores the borrow principal of a given account, i.e. its borrow amount without accrued interest. It is only
+    /// tracked for accounts whose principal is known.
+    #[view(getAccountBorrowPrincipal)]
+    #[storage_mapper("account_borrow_principal")]
+    fn account_borrow_principal(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Stores the maximum debt an account can have to be eligible for interest forgiveness.
+    #[view(getInterestForgivenessThreshold)]
+    #[storage_mapper("interest_forgiveness_threshold")]
+    fn interest_forgiveness_threshold(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the total amount of interest forgiven by the interest forgiveness program.
+    #[view(getTotalForgivenInterest)]
+    #[storage_mapper("total_forgiven_interest")]
+    fn total_forgiven_interest(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the Controller-derived values of this money market, as last pushed by the Controller.
+    #[storage_mapper("market_mirror")]
+    fn market_mirror(&self) -> SingleValueMapper<MarketMirror<Self::Api>>;
+
+    /// Stores the configured destinations to which reserves can be withdrawn with a purpose tag.
+    #[view(getReservesDestinations)]
+    #[storage_mapper("reserves_destinations")]
+    fn reserves_destinations(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Stores the set of deposit terms, in seconds, available for term deposits.
+    #[view(getDepositTerms)]
+    #[storage_mapper("deposit_terms")]
+    fn deposit_terms(&self) -> UnorderedSetMapper<u64>;
+
+    /// Stores the annualized supply-rate bonus in wad paid to term deposits of a given term.
+    #[view(getDepositTermBonusRate)]
+    #[storage_mapper("deposit_term_bonus_rate")]
+    fn deposit_term_bonus_rate(&self, term: u64) -> SingleValueMapper<BigUint>;
+
+    /// Stores the share in wad of the underlying forfeited to reserves when a term deposit is withdrawn before maturity.
+    #[view(getEarlyWithdrawalPenalty)]
+    #[storage_mapper("early_withdrawal_penalty")]
+    fn early_withdrawal_penalty(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the next term deposit identifier.
+    #[view(getNextTermDepositId)]
+    #[storage_mapper("next_term_deposit_id")]
+    fn next_term_deposit_id(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the term deposit for a given term deposit identifier.
+    #[view(getTermDeposit)]
+    #[storage_mapper("term_deposits")]
+    fn term_deposits(&self, deposit_id: u64) -> SingleValueMapper<TermDeposit<Self::Api>>;
+
+    /// Stores the set of open term deposit identifiers for a given account.
+    #[view(getAccountTermDeposits)]
+    #[storage_mapper("account_term_deposits")]
+    fn account_term_deposits(&self, account: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    /// Stores the amount of reserves committed to pay the bonuses of open term deposits, which is kept apart from revenue.
+    #[view(getTermDepositBonuses)]
+    #[storage_mapper("term_deposit_bonuses")]
+    fn term_deposit_bonuses(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores whether the money market is in a protective state because of issuer controls applied to the underlying token,
+    /// such that it can be lifted automatically once they are lifted.
+    #[view(isUnderlyingProtectionActive)]
+    #[storage_mapper("underlying_protection")]
+    fn underlying_protection(&self) -> SingleValueMapper<bool>;
+
+    /// Stores the underlying owed to a given account because its transfer was deferred while the underlying token was paused
+    /// or the money market balance was frozen.
+    #[view(getPendingUnderlying)]
+    #[storage_mapper("pending_underlying")]
+    fn pending_underlying(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Stores the total underlying owed to accounts because of deferred transfers, which does not belong to cash.
+    #[view(getTotalPendingUnderlying)]
+    #[storage_mapper("total_pending_underlying")]
+    fn total_pending_underlying(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the timelock that must elapse between proposing and setting a new Interest Rate Model.
+    #[storage_mapper("interest_rate_model_timelock")]
+    fn interest_rate_model_timelock(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the proposed Interest Rate Model and the timestamp from which it can be set.
+    #[view(getNextInterestRateModel)]
+    #[storage_mapper("next_interest_rate_model")]
+    fn next_interest_rate_model(&self) -> SingleValueMapper<(u64, ManagedAddress)>;
 }
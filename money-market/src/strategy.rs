@@ -0,0 +1,190 @@
+multiversx_sc::imports!();
+
+use super::{common, constants::*, errors::*, events, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait StrategyModule: admin::AdminModule + common::CommonModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Sets the reserve strategy, i.e. a whitelisted yield source where idle cash can be deployed, and the maximum share of
+    /// cash that can be deployed into it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_strategy` - The reserve strategy smart contract address.
+    /// - `new_max_share` - The maximum share of cash that can be deployed in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The strategy must accept the same underlying as this money market.
+    /// - The maximum share cannot exceed `MAX_DEPLOYED_SHARE`.
+    /// - Changing the strategy requires all deployed cash to be recalled first.
+    ///
+    #[endpoint(setReserveStrategy)]
+    fn set_reserve_strategy(&self, new_strategy: &ManagedAddress, new_max_share: &BigUint) {
+        self.require_admin();
+
+        require!(new_max_share <= &BigUint::from(MAX_DEPLOYED_SHARE), ERROR_MAX_DEPLOYED_SHARE_TOO_HIGH);
+        require!(self.is_reserve_strategy(new_strategy), ERROR_INVALID_RESERVE_STRATEGY);
+        require!(self.get_reserve_strategy_underlying_id(new_strategy) == self.underlying_id().get(), ERROR_INVALID_RESERVE_STRATEGY);
+
+        let old_strategy = self.get_reserve_strategy();
+        if let Some(ref old_strategy) = old_strategy {
+            require!(old_strategy == new_strategy || self.deployed_cash().get() == BigUint::zero(), ERROR_RESERVE_STRATEGY_HAS_DEPLOYED_CASH);
+        }
+
+        let old_max_share = self.max_deployed_share().get();
+
+        self.reserve_strategy().set(new_strategy);
+        self.max_deployed_share().set(new_max_share);
+
+        self.new_reserve_strategy_event(&old_strategy, new_strategy, &old_max_share, new_max_share);
+    }
+
+    /// Deploys an amount of idle cash into the reserve strategy.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to deploy.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The total deployed cash cannot exceed the maximum deployed share of cash.
+    /// - Deployed cash is still accounted as cash, so the exchange rate and liquidity remain unchanged.
+    ///
+    #[endpoint(deployToStrategy)]
+    fn deploy_to_strategy(&self, amount: &BigUint) {
+        self.require_admin();
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let strategy = match self.get_reserve_strategy() {
+            None => sc_panic!(ERROR_UNDEFINED_RESERVE_STRATEGY),
+            Some(address) => address,
+        };
+
+        require!(amount > &BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        self.try_ensure_staking_rewards(amount);
+
+        let wad = BigUint::from(WAD);
+        let cash = self.cash().get();
+        let new_deployed_cash = self.deployed_cash().get() + amount;
+        require!(&new_deployed_cash * &wad <= self.max_deployed_share().get() * &cash, ERROR_DEPLOYED_CASH_CAP_EXCEEDED);
+
+        self.deployed_cash().set(&new_deployed_cash);
+
+        let underlying_id = self.underlying_id().get();
+        self.reserve_strategy_deposit(&underlying_id, amount);
+
+        self.cash_deployed_event(&strategy, amount, &new_deployed_cash);
+    }
+
+    /// Recalls an amount of deployed cash from the reserve strategy. Any amount received on top of the requested amount is
+    /// considered yield and added to the reserves, while any amount missing is realized as a loss.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_amount` - The amount of underlying to recall. If not provided, all deployed cash is recalled.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by the admin at any time, or by anyone when there is a strategy deficit, in which case the recalled
+    ///   amount is capped to the deficit (see `getStrategyDeficit`).
+    /// - A shortfall is absorbed by the reserves first, up to the protocol revenue, and the remainder by suppliers.
+    ///
+    #[endpoint(recallFromStrategy)]
+    fn recall_from_strategy(&self, opt_amount: OptionalValue<BigUint>) {
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let strategy = match self.get_reserve_strategy() {
+            None => sc_panic!(ERROR_UNDEFINED_RESERVE_STRATEGY),
+            Some(address) => address,
+        };
+
+        let deployed_cash = self.deployed_cash().get();
+        let mut amount = opt_amount.into_option().unwrap_or_else(|| deployed_cash.clone());
+
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(amount <= deployed_cash, ERROR_AMOUNT_EXCEEDS_DEPLOYED_CASH);
+
+        // anyone can only recall up to the deficit computed on-chain
+        if self.blockchain().get_caller() != self.get_admin() {
+            let deficit = self.get_strategy_deficit();
+            require!(deficit > BigUint::zero(), ERROR_ENOUGH_ON_HAND_CASH);
+            amount = BigUint::min(amount, deficit);
+        }
+
+        let underlying_id = self.underlying_id().get();
+        let balance_before = self.blockchain().get_sc_balance(&underlying_id, 0);
+        self.reserve_strategy_withdraw(&amount);
+        let balance_after = self.blockchain().get_sc_balance(&underlying_id, 0);
+
+        let received = balance_after - balance_before;
+
+        let new_deployed_cash = deployed_cash - &amount;
+        self.deployed_cash().set(&new_deployed_cash);
+
+        // the shortfall is lost, while the yield is added to the reserves and split between staking rewards and revenue
+        let strategy_yield = if received >= amount {
+            &received - &amount
+        } else {
+            let loss = &amount - &received;
+            let (reserves_loss, supply_loss) = self.realize_loss(&loss);
+            self.strategy_loss_event(&strategy, &loss, &reserves_loss, &supply_loss);
+            BigUint::zero()
+        };
+
+        if strategy_yield > BigUint::zero() {
+            let fs = self.stake_factor().get();
+            let delta_rewards = fs * &strategy_yield / BigUint::from(WAD);
+            let delta_revenue = &strategy_yield - &delta_rewards;
+
+            self.cash().update(|cash| *cash += &strategy_yield);
+            self.total_reserves().update(|reserves| *reserves += &strategy_yield);
+            self.revenue().update(|revenue| *revenue += &delta_revenue);
+            self.staking_rewards().update(|rewards| *rewards += &delta_rewards);
+            self.historical_staking_rewards().update(|rewards| *rewards += &delta_rewards);
+
+            self.emit_updated_rates();
+        }
+
+        self.cash_recalled_event(&strategy, &amount, &strategy_yield, &new_deployed_cash);
+    }
+
+    /// Writes off an amount of deployed cash that the reserve strategy will never return, e.g. after a slashing or an
+    /// exploit of the yield source.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of deployed cash to write off.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The loss is absorbed by the reserves first, up to the protocol revenue, and the remainder by suppliers.
+    ///
+    #[endpoint(writeOffStrategyLoss)]
+    fn write_off_strategy_loss(&self, amount: &BigUint) {
+        self.require_admin();
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let strategy = match self.get_reserve_strategy() {
+            None => sc_panic!(ERROR_UNDEFINED_RESERVE_STRATEGY),
+            Some(address) => address,
+        };
+
+        let deployed_cash = self.deployed_cash().get();
+        require!(amount > &BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(amount <= &deployed_cash, ERROR_AMOUNT_EXCEEDS_DEPLOYED_CASH);
+
+        self.deployed_cash().set(&(deployed_cash - amount));
+
+        let (reserves_loss, supply_loss) = self.realize_loss(amount);
+        self.strategy_loss_event(&strategy, amount, &reserves_loss, &supply_loss);
+    }
+}
@@ -0,0 +1,212 @@
+multiversx_sc::imports!();
+
+use super::{
+    common,
+    constants::*,
+    errors::*,
+    events, mint, proxies, redeem,
+    storage::{self, TermDeposit},
+};
+
+#[multiversx_sc::module]
+pub trait TermDepositModule: admin::AdminModule + common::CommonModule + events::EventsModule + mint::MintModule + proxies::ProxyModule + redeem::RedeemModule + storage::StorageModule {
+    /// Adds a deposit term or updates its bonus rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `term` - The deposit term in seconds.
+    /// - `bonus_rate` - The annualized supply-rate bonus in wad paid to term deposits of this term.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The bonus rate cannot exceed `MAX_DEPOSIT_TERM_BONUS_RATE`.
+    /// - Already opened term deposits keep the bonus computed when they were opened.
+    ///
+    #[endpoint(setDepositTerm)]
+    fn set_deposit_term(&self, term: u64, bonus_rate: BigUint) {
+        self.require_admin();
+
+        require!(term > 0, ERROR_INVALID_DEPOSIT_TERM);
+        require!(bonus_rate <= BigUint::from(MAX_DEPOSIT_TERM_BONUS_RATE), ERROR_DEPOSIT_TERM_BONUS_RATE_TOO_HIGH);
+
+        if !self.deposit_terms().contains(&term) {
+            require!(self.deposit_terms().len() < MAX_DEPOSIT_TERMS, ERROR_TOO_MANY_DEPOSIT_TERMS);
+            self.deposit_terms().insert(term);
+        }
+
+        let old_bonus_rate = self.deposit_term_bonus_rate(term).get();
+        self.deposit_term_bonus_rate(term).set(&bonus_rate);
+
+        self.set_deposit_term_event(term, &old_bonus_rate, &bonus_rate);
+    }
+
+    /// Removes a deposit term, such that no new term deposits can be opened for it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `term` - The deposit term in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Already opened term deposits are not affected.
+    ///
+    #[endpoint(removeDepositTerm)]
+    fn remove_deposit_term(&self, term: u64) {
+        self.require_admin();
+
+        require!(self.deposit_terms().swap_remove(&term), ERROR_INVALID_DEPOSIT_TERM);
+        self.deposit_term_bonus_rate(term).clear();
+
+        self.remove_deposit_term_event(term);
+    }
+
+    /// Sets the early withdrawal penalty of term deposits.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_penalty` - The share in wad of the underlying forfeited to reserves on early withdrawals.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The penalty cannot exceed `MAX_EARLY_WITHDRAWAL_PENALTY`.
+    ///
+    #[endpoint(setEarlyWithdrawalPenalty)]
+    fn set_early_withdrawal_penalty(&self, new_penalty: BigUint) {
+        self.require_admin();
+
+        require!(new_penalty <= BigUint::from(MAX_EARLY_WITHDRAWAL_PENALTY), ERROR_EARLY_WITHDRAWAL_PENALTY_TOO_HIGH);
+
+        let old_penalty = self.early_withdrawal_penalty().get();
+        self.early_withdrawal_penalty().set(&new_penalty);
+
+        self.new_early_withdrawal_penalty_event(&old_penalty, &new_penalty);
+    }
+
+    /// Supplies underlying to the money market and locks it for a fixed term. The minted Hatom tokens are held by the money
+    /// market until withdrawal, keep accruing the variable supply rate and, at maturity, are paid a bonus funded from the
+    /// money market revenue.
+    ///
+    /// # Arguments:
+    ///
+    /// - `term` - The deposit term in seconds, which must be one of the available deposit terms.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the underlying asset.
+    /// - The bonus is computed on the supplied underlying at the term bonus rate and is committed from revenue at opening,
+    ///   such that it cannot be withdrawn by governance afterwards.
+    /// - Locked tokens cannot be used as collateral.
+    ///
+    #[payable("*")]
+    #[endpoint(openTermDeposit)]
+    fn open_term_deposit(&self, term: u64) -> u64 {
+        self.require_active();
+        self.accrue_interest();
+
+        require!(self.deposit_terms().contains(&term), ERROR_INVALID_DEPOSIT_TERM);
+
+        let (underlying_id, underlying_amount) = self.call_value().egld_or_single_fungible_esdt();
+        self.require_valid_underlying_payment(&underlying_id, &underlying_amount);
+
+        let owner = self.blockchain().get_caller();
+        require!(self.account_term_deposits(&owner).len() < MAX_TERM_DEPOSITS_PER_ACCOUNT, ERROR_TOO_MANY_TERM_DEPOSITS);
+
+        // the bonus is committed from revenue, keeping total reserves and thus the exchange rate unchanged
+        let bonus_rate = self.deposit_term_bonus_rate(term).get();
+        let bonus = self.wad_mul(&underlying_amount, &bonus_rate) * term / SECONDS_PER_YEAR;
+        require!(bonus <= self.revenue().get(), ERROR_TERM_DEPOSIT_BONUS_EXCEEDS_REVENUE);
+
+        self.revenue().update(|amount| *amount -= &bonus);
+        self.term_deposit_bonuses().update(|amount| *amount += &bonus);
+
+        let token_payment = self.mint_internal(&owner, &underlying_amount, false);
+
+        let id = self.next_term_deposit_id().get();
+        self.next_term_deposit_id().set(id + 1);
+
+        let start = self.blockchain().get_block_timestamp();
+        let term_deposit = TermDeposit {
+            id,
+            owner: owner.clone(),
+            tokens: token_payment.amount,
+            underlying_amount,
+            bonus,
+            start,
+            maturity: start + term,
+        };
+
+        self.term_deposits(id).set(&term_deposit);
+        self.account_term_deposits(&owner).insert(id);
+
+        self.term_deposit_opened_event(&term_deposit);
+
+        id
+    }
+
+    /// Withdraws a term deposit, redeeming its locked Hatom tokens for underlying. At maturity, the committed bonus is paid
+    /// on top. Before maturity, the bonus goes back to revenue and the early withdrawal penalty is forfeited to reserves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `deposit_id` - The term deposit identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the term deposit owner.
+    /// - The redeem fee, if any, also applies.
+    /// - Requires enough cash in the money market to redeem the locked tokens.
+    ///
+    #[endpoint(withdrawTermDeposit)]
+    fn withdraw_term_deposit(&self, deposit_id: u64) -> EgldOrEsdtTokenPayment {
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        require!(!self.term_deposits(deposit_id).is_empty(), ERROR_TERM_DEPOSIT_NOT_FOUND);
+        let term_deposit = self.term_deposits(deposit_id).get();
+
+        let owner = self.blockchain().get_caller();
+        require!(owner == term_deposit.owner, ERROR_ONLY_TERM_DEPOSIT_OWNER);
+
+        self.term_deposits(deposit_id).clear();
+        self.account_term_deposits(&owner).swap_remove(&deposit_id);
+
+        let matured = self.blockchain().get_block_timestamp() >= term_deposit.maturity;
+        let underlying_amount = self.tokens_to_underlying_amount(&term_deposit.tokens);
+        let bonus = &term_deposit.bonus;
+
+        self.term_deposit_bonuses().update(|amount| *amount -= bonus);
+
+        let (bonus, penalty) = if matured {
+            (bonus.clone(), BigUint::zero())
+        } else {
+            let penalty = self.wad_mul(&underlying_amount, &self.early_withdrawal_penalty().get());
+            self.revenue().update(|amount| *amount += bonus);
+            (BigUint::zero(), penalty)
+        };
+
+        // the penalty remains in cash as part of the reserves
+        let underlying_amount = self.redeem_internal(&owner, &owner, &term_deposit.tokens, &(underlying_amount - &penalty));
+        if penalty > BigUint::zero() {
+            self.total_reserves().update(|amount| *amount += &penalty);
+            self.revenue().update(|amount| *amount += &penalty);
+        }
+
+        // the bonus is paid from reserves
+        if bonus > BigUint::zero() {
+            self.try_ensure_staking_rewards(&bonus);
+            self.cash().update(|amount| *amount -= &bonus);
+            self.total_reserves().update(|amount| *amount -= &bonus);
+            self.send_underlying(&owner, &bonus);
+        }
+
+        self.emit_updated_rates();
+        self.emit_redeem_events(&owner, &owner, &underlying_amount, &term_deposit.tokens);
+        self.term_deposit_withdrawn_event(&term_deposit, &underlying_amount, &bonus, &penalty);
+
+        let underlying_id = self.underlying_id().get();
+        EgldOrEsdtTokenPayment::new(underlying_id, 0, underlying_amount + bonus)
+    }
+}
@@ -0,0 +1,133 @@
+multiversx_sc::imports!();
+
+use super::{
+    errors::*,
+    events,
+    storage::{self, UnderlyingTokenControls},
+};
+
+#[multiversx_sc::module]
+pub trait TokenControlsModule: pausable::PausableModule + events::EventsModule + storage::StorageModule {
+    /// Returns the issuer controls currently applied to the underlying token, i.e. whether it has been globally paused,
+    /// whether the money market balance has been frozen and whether transfers have been limited.
+    ///
+    /// # Notes:
+    ///
+    /// - All controls are reported as disabled for EGLD.
+    ///
+    #[view(getUnderlyingTokenControls)]
+    fn get_underlying_token_controls(&self) -> UnderlyingTokenControls {
+        let underlying_id = self.underlying_id().get();
+        if underlying_id.is_egld() {
+            return UnderlyingTokenControls { paused: false, frozen: false, limited_transfer: false };
+        }
+
+        let token_id = underlying_id.unwrap_esdt();
+        let money_market = self.blockchain().get_sc_address();
+
+        UnderlyingTokenControls {
+            paused: self.blockchain().is_esdt_paused(&token_id),
+            frozen: self.blockchain().is_esdt_frozen(&money_market, &token_id, 0),
+            limited_transfer: self.blockchain().is_esdt_limited_transfer(&token_id),
+        }
+    }
+
+    /// Checks the issuer controls applied to the underlying token and flips the money market into a protective state if
+    /// the underlying has been paused or the money market balance has been frozen. Otherwise, a protective state previously
+    /// set by this check is lifted.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone, such that monitoring bots can react as soon as the issuer acts, instead of having
+    ///   transfers revert in unpredictable places.
+    /// - The protective state halts mints and borrows. It is tracked separately from the admin pause, which is never
+    ///   lifted by this check.
+    ///
+    #[endpoint(checkUnderlyingControls)]
+    fn check_underlying_controls(&self) -> UnderlyingTokenControls {
+        require!(!self.underlying_id().get().is_egld(), ERROR_UNDERLYING_IS_EGLD);
+        self.update_underlying_protection()
+    }
+
+    /// Updates the protective state according to the issuer controls currently applied to the underlying token and returns
+    /// them.
+    ///
+    fn update_underlying_protection(&self) -> UnderlyingTokenControls {
+        let controls = self.get_underlying_token_controls();
+        let restricted = controls.paused || controls.frozen;
+
+        if restricted && !self.underlying_protection().get() {
+            self.underlying_protection().set(true);
+            self.underlying_protection_event(true, &controls);
+        } else if !restricted && self.underlying_protection().get() {
+            self.underlying_protection().clear();
+            self.underlying_protection_event(false, &controls);
+        }
+
+        controls
+    }
+
+    /// Claims the underlying owed to the caller because of transfers deferred while the underlying token was paused or the
+    /// money market balance was frozen by its issuer.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone with pending underlying.
+    /// - The issuer controls must have been lifted.
+    ///
+    #[endpoint(claimPendingUnderlying)]
+    fn claim_pending_underlying(&self) -> BigUint {
+        let account = self.blockchain().get_caller();
+        let underlying_amount = self.pending_underlying(&account).take();
+        require!(underlying_amount > BigUint::zero(), ERROR_NO_PENDING_UNDERLYING);
+
+        let controls = self.update_underlying_protection();
+        require!(!controls.paused && !controls.frozen, ERROR_UNDERLYING_RESTRICTED);
+
+        self.total_pending_underlying().update(|amount| *amount -= &underlying_amount);
+
+        let underlying_id = self.underlying_id().get();
+        self.send().direct(&account, &underlying_id, 0, &underlying_amount);
+
+        self.pending_underlying_claimed_event(&account, &underlying_amount);
+
+        underlying_amount
+    }
+
+    /// Sends a given amount of underlying to a given address. Before transferring an ESDT underlying, the issuer controls
+    /// are checked. If the underlying has been paused or the money market balance has been frozen, the protective state is
+    /// set and the transfer is deferred, i.e. the amount is kept as pending underlying for the recipient to claim with
+    /// `claimPendingUnderlying` once the controls are lifted. This way, the protective state is persisted instead of being
+    /// reverted together with a failed transfer.
+    ///
+    /// # Arguments:
+    ///
+    /// - `to` - The address receiving the underlying.
+    /// - `underlying_amount` - The amount of underlying to send.
+    ///
+    fn send_underlying(&self, to: &ManagedAddress, underlying_amount: &BigUint) {
+        if underlying_amount == &BigUint::zero() {
+            return;
+        }
+
+        let underlying_id = self.underlying_id().get();
+        if !underlying_id.is_egld() {
+            let controls = self.update_underlying_protection();
+            if controls.paused || controls.frozen {
+                self.pending_underlying(to).update(|amount| *amount += underlying_amount);
+                self.total_pending_underlying().update(|amount| *amount += underlying_amount);
+                self.underlying_transfer_deferred_event(to, underlying_amount);
+                return;
+            }
+        }
+
+        self.send().direct(to, &underlying_id, 0, underlying_amount);
+    }
+
+    /// Requires that the money market is not in the protective state set because of issuer controls applied to the
+    /// underlying token.
+    ///
+    fn require_no_underlying_protection(&self) {
+        require!(!self.underlying_protection().get(), ERROR_UNDERLYING_PROTECTION_ACTIVE);
+    }
+}
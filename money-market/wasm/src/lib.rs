@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           80
+// Endpoints:                          193
 // Async Callback:                       1
-// Total number of exported functions:  83
+// Total number of exported functions: 196
 
 #![no_std]
 
@@ -27,12 +27,24 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPendingAdmin => get_pending_admin
         setPendingAdmin => set_pending_admin
         acceptAdmin => accept_admin
+        pause => pause
+        unpause => unpause
+        setPauseGuardian => set_pause_guardian
+        getPauseGuardian => get_pause_guardian_view
+        getPauseGuardianSource => pause_guardian_source
+        isPaused => is_paused
         borrow => borrow
+        placeBorrowOrder => place_borrow_order
+        cancelBorrowOrder => cancel_borrow_order
+        executeBorrowOrder => execute_borrow_order
         isMoneyMarket => is_money_market
         isTokenIssued => is_token_issued
+        getTrustedMinters => get_trusted_minters
+        hasAbnormalAccrualGap => has_abnormal_accrual_gap
         accrueInterest => accrue_interest
         tryAccrueInterest => try_accrue_interest
         addReserves => add_reserves
+        auditCash => audit_cash
         underlyingAmountToTokens => underlying_amount_to_tokens
         tokensToUnderlyingAmount => tokens_to_underlying_amount
         currentUnderlyingAmountToTokens => current_underlying_amount_to_tokens
@@ -46,38 +58,86 @@ multiversx_sc_wasm_adapter::endpoints! {
         getCurrentRevenue => get_current_revenue
         getCurrentLiquidity => get_current_liquidity
         getLiquidity => get_liquidity
+        getAvailableLiquidity => get_available_liquidity
         getReserveFactor => get_reserve_factor
         getInterestRateModel => get_interest_rate_model
         getController => get_controller
         getStakingContract => get_staking_contract
+        getReserveStrategy => get_reserve_strategy
+        getOnHandCash => get_on_hand_cash
+        getStrategyDeficit => get_strategy_deficit
+        getPsmAdapter => get_psm_adapter
+        getInsuranceFund => get_insurance_fund
         getCurrentAccountBorrowAmount => current_account_borrow_amount
         getReliableAccountBorrowAmount => reliable_account_borrow_amount
         getStoredAccountBorrowAmount => stored_account_borrow_amount
         getBaseAccountBorrowAmount => base_account_borrow_amount
+        getBorrowIndexRebaseFactor => get_borrow_index_rebase_factor
         getAccountSnapshot => get_account_snapshot
+        getBorrowersCount => get_borrowers_count
+        getBorrowSnapshotsPage => get_borrow_snapshots_page
         getReliableAccountSnapshot => get_reliable_account_snapshot
         getBorrowIndex => get_borrow_index
+        getBorrowIndexAudit => get_borrow_index_audit
         getCurrentExchangeRate => get_current_exchange_rate
         getStoredExchangeRate => get_stored_exchange_rate
         getBorrowRatePerSecond => borrow_rate_per_second
         getSupplyRatePerSecond => supply_rate_per_second
         getRatesPerSecond => get_rates_per_second
+        getRateSnapshots => get_rate_snapshots
         getCloseFactor => get_close_factor
         getLiquidationIncentive => get_liquidation_incentive
+        setPsmAdapter => set_psm_adapter
+        setCreditLine => set_credit_line
+        lendToPsm => lend_to_psm
+        recallFromPsm => recall_from_psm
+        forceRecallFromPsm => force_recall_from_psm
+        getCreditLineDebt => get_credit_line_debt
+        setInterestForgivenessThreshold => set_interest_forgiveness_threshold
+        importBorrowPrincipals => import_borrow_principals
+        forgiveInterest => forgive_interest
         setStakingContract => set_staking_contract
         setReserveFactor => set_reserve_factor
         setStakeFactor => set_stake_factor
+        setRedeemFee => set_redeem_fee
         setCloseFactor => set_close_factor
         setLiquidationIncentive => set_liquidation_incentive
         setProtocolSeizeShare => set_protocol_seize_share
-        setInterestRateModel => set_interest_rate_model
+        setInsuranceFund => set_insurance_fund
+        claimInsuranceFund => claim_insurance_fund
+        proposeInterestRateModel => propose_interest_rate_model
+        executeInterestRateModel => execute_interest_rate_model
+        cancelInterestRateModel => cancel_interest_rate_model
+        setInterestRateModelTimelock => set_interest_rate_model_timelock
+        getInterestRateModelTimelock => get_interest_rate_model_timelock
         reduceReserves => reduce_reserves
+        reduceReservesTo => reduce_reserves_to
+        addReservesDestination => add_reserves_destination
+        removeReservesDestination => remove_reserves_destination
+        claimDonations => claim_donations
         setAccrualTimeThreshold => set_accrual_time_threshold
+        setMaxAccrualGap => set_max_accrual_gap
+        acknowledgeAccrualGap => acknowledge_accrual_gap
         addTrustedMinter => add_trusted_minter
+        addTrustedMinters => add_trusted_minters
+        setTrustedMinterMetadata => set_trusted_minter_metadata
         removeTrustedMinter => remove_trusted_minter
+        removeTrustedMinters => remove_trusted_minters
+        exportBorrowSnapshots => export_borrow_snapshots
+        commitBorrowSnapshots => commit_borrow_snapshots
+        importBorrowSnapshots => import_borrow_snapshots
+        indexBorrowers => index_borrowers
+        rebaseBorrowIndex => rebase_borrow_index
+        rebaseBorrowSnapshots => rebase_borrow_snapshots
+        setHedgingHook => set_hedging_hook
+        removeHedgingHook => remove_hedging_hook
         liquidateBorrow => liquidate_borrow
         mint => mint
         mintAndEnterMarket => mint_and_enter_market
+        syncControllerMirror => sync_controller_mirror
+        getControllerMirror => get_controller_mirror
+        getMarketCollateralFactor => get_market_collateral_factor
+        isMarketDeprecated => is_market_deprecated
         redeem => redeem
         repayBorrow => repay_borrow
         seize => seize
@@ -87,19 +147,72 @@ multiversx_sc_wasm_adapter::endpoints! {
         getOngoingIssuance => ongoing_issuance
         getMintedInitialSupply => minted_initial_supply
         getAccountBorrowSnapshot => account_borrow_snapshot
+        getAccountBorrowSnapshotTimestamp => account_borrow_snapshot_timestamp
         getCash => cash
         getTotalBorrows => total_borrows
         getTotalReserves => total_reserves
         getStakingRewards => staking_rewards
         getHistoricalStakingRewards => historical_staking_rewards
         getRevenue => revenue
+        getDonations => donations
         getTotalSupply => total_supply
         getStakeFactor => stake_factor
+        getRedeemFee => redeem_fee
         getAccrualTimestamp => accrual_timestamp
+        getLastRateSnapshotDay => last_rate_snapshot_day
         getInitialExchangeRate => initial_exchange_rate
         getProtocolSeizeShare => protocol_seize_share
+        getInsuranceFundShare => insurance_fund_share
+        getInsuranceFundBalance => insurance_fund_balance
+        getMaxDeployedShare => max_deployed_share
+        getDeployedCash => deployed_cash
+        getCreditLineCap => credit_line_cap
+        getCreditLineRate => credit_line_rate
+        getCreditLinePrincipal => credit_line_principal
+        getCreditLineTimestamp => credit_line_timestamp
         getAccrualTimeThreshold => accrual_time_threshold
+        getMaxAccrualGap => max_accrual_gap
+        getAccrualGapTimestamp => accrual_gap_timestamp
+        getAccrualGapDuration => accrual_gap_duration
+        getAccrualGapInterestFactor => accrual_gap_interest_factor
+        getTrustedMinterMetadata => trusted_minter_metadata
+        getNextBorrowOrderId => next_borrow_order_id
+        getBorrowOrder => borrow_orders
+        getAccountBorrowOrders => account_borrow_orders
+        getBorrowSnapshotsExportHash => borrow_snapshots_export_hash
+        getBorrowSnapshotsCommitment => borrow_snapshots_commitment
+        getBorrowSnapshotsImportHash => borrow_snapshots_import_hash
+        getAccountBorrowIndexRebases => account_borrow_index_rebases
+        getHedgingHook => hedging_hook
+        getHedgingHookLastNotified => hedging_hook_last_notified
+        getAccountBorrowPrincipal => account_borrow_principal
+        getInterestForgivenessThreshold => interest_forgiveness_threshold
+        getTotalForgivenInterest => total_forgiven_interest
+        getReservesDestinations => reserves_destinations
+        getDepositTerms => deposit_terms
+        getDepositTermBonusRate => deposit_term_bonus_rate
+        getEarlyWithdrawalPenalty => early_withdrawal_penalty
+        getNextTermDepositId => next_term_deposit_id
+        getTermDeposit => term_deposits
+        getAccountTermDeposits => account_term_deposits
+        getTermDepositBonuses => term_deposit_bonuses
+        isUnderlyingProtectionActive => underlying_protection
+        getPendingUnderlying => pending_underlying
+        getTotalPendingUnderlying => total_pending_underlying
+        getNextInterestRateModel => next_interest_rate_model
         claimStakingRewards => claim_staking_rewards
+        setReserveStrategy => set_reserve_strategy
+        deployToStrategy => deploy_to_strategy
+        recallFromStrategy => recall_from_strategy
+        writeOffStrategyLoss => write_off_strategy_loss
+        setDepositTerm => set_deposit_term
+        removeDepositTerm => remove_deposit_term
+        setEarlyWithdrawalPenalty => set_early_withdrawal_penalty
+        openTermDeposit => open_term_deposit
+        withdrawTermDeposit => withdraw_term_deposit
+        getUnderlyingTokenControls => get_underlying_token_controls
+        checkUnderlyingControls => check_underlying_controls
+        claimPendingUnderlying => claim_pending_underlying
     )
 }
 
@@ -4,7 +4,7 @@ multiversx_sc::derive_imports!();
 use crate::{constants::*, errors::*, events, model::*, proxies, storage};
 
 #[multiversx_sc::module]
-pub trait CommonModule: admin::AdminModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait CommonModule: admin::AdminModule + math::MathModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
     // Checks
 
     /// A utility function to highlight that this smart contract is a Price Oracle.
@@ -106,22 +106,6 @@ pub trait CommonModule: admin::AdminModule + events::EventsModule + proxies::Pro
         pair_state == PairState::Inactive || pair_state == PairState::PartialActive
     }
 
-    /// Requires that the caller is the admin or the guardian, if it is set.
-    ///
-    fn require_admin_or_guardian(&self) {
-        let admin = self.get_admin();
-        let caller = self.blockchain().get_caller();
-
-        match self.get_guardian() {
-            None => {
-                require!(caller == admin, ERROR_ONLY_ADMIN);
-            },
-            Some(guardian) => {
-                require!(caller == admin || caller == guardian, ERROR_ONLY_ADMIN_OR_GUARDIAN);
-            },
-        }
-    }
-
     /// Requires that the provided token identifier is a valid ESDT token identifier.
     ///
     /// # Arguments:
@@ -233,6 +217,104 @@ pub trait CommonModule: admin::AdminModule + events::EventsModule + proxies::Pro
         self.pricing_method(token_id).get()
     }
 
+    /// Returns the quote token of the xExchange pair of a given native token, if it is not quoted against WEGLD.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    ///
+    fn get_quote_token(&self, token_id: &TokenIdentifier) -> Option<TokenIdentifier> {
+        if self.quote_token(token_id).is_empty() {
+            None
+        } else {
+            let quote_token_id = self.quote_token(token_id).get();
+            Some(quote_token_id)
+        }
+    }
+
+    /// Sets or clears the quote token of the xExchange pair of a given native token. The previous quote token is removed from
+    /// the set of quote tokens as soon as no other native token is quoted against it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    /// - `opt_quote_token_id` - The new quote token, if any.
+    ///
+    fn set_quote_token(&self, token_id: &TokenIdentifier, opt_quote_token_id: Option<&TokenIdentifier>) {
+        if let Some(old_quote_token_id) = self.get_quote_token(token_id) {
+            let uses = self.quote_token_uses(&old_quote_token_id).update(|uses| {
+                *uses -= 1;
+                *uses
+            });
+            if uses == 0 {
+                self.quote_tokens().swap_remove(&old_quote_token_id);
+            }
+        }
+
+        match opt_quote_token_id {
+            None => self.quote_token(token_id).clear(),
+            Some(quote_token_id) => {
+                self.quote_token(token_id).set(quote_token_id);
+                self.quote_tokens().insert(quote_token_id.clone());
+                self.quote_token_uses(quote_token_id).update(|uses| *uses += 1);
+            },
+        }
+    }
+
+    /// Returns the minimum reserve of a token in its xExchange pool, or zero if not set.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    ///
+    fn get_min_pool_reserves(&self, token_id: &TokenIdentifier) -> BigUint {
+        if self.min_pool_reserves(token_id).is_empty() {
+            BigUint::zero()
+        } else {
+            self.min_pool_reserves(token_id).get()
+        }
+    }
+
+    /// Checks whether the xExchange pool of a given token holds at least its minimum pool reserve. If the token is quoted
+    /// against a quote token, the pool of the quote token is checked as well.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_data` - The token data.
+    ///
+    fn has_min_pool_reserves(&self, token_data: &TokenData<Self::Api>) -> bool {
+        let is_ush = self.is_ush_token(&token_data.identifier);
+
+        let TokenData { identifier: token_id, xexchange_pair: opt_xexchange_pair, .. } = if is_ush {
+            // For USH token, use the fallback token's data
+            let fallback_token_id = self.ush_fallback_token_id().get();
+            &self.get_supported_token_data(&fallback_token_id)
+        } else {
+            token_data
+        };
+
+        let xexchange_pair = match opt_xexchange_pair {
+            None => return true,
+            Some(xexchange_pair) => xexchange_pair,
+        };
+
+        if !self.min_pool_reserves(token_id).is_empty() {
+            let (reserves0, reserves1) = self.get_xexchange_reserves(&xexchange_pair.address);
+            let token_reserves = if xexchange_pair.token0 == *token_id { reserves0 } else { reserves1 };
+            if token_reserves < self.min_pool_reserves(token_id).get() {
+                return false;
+            }
+        }
+
+        match self.get_quote_token(token_id) {
+            None => true,
+            Some(quote_token_id) => {
+                let quote_token_data = self.get_supported_token_data(&quote_token_id);
+                self.has_min_pool_reserves(&quote_token_data)
+            },
+        }
+    }
+
     /// Computes and returns the upper and lower bounds for a given anchor tolerance.
     ///
     /// # Arguments:
@@ -242,7 +324,7 @@ pub trait CommonModule: admin::AdminModule + events::EventsModule + proxies::Pro
     fn get_bounds(&self, anchor_tolerance: &BigUint) -> (BigUint, BigUint) {
         let wad = BigUint::from(WAD);
         let upper_bound = &wad + anchor_tolerance;
-        let lower_bound = &wad * &wad / &upper_bound;
+        let lower_bound = self.wad_div(&wad, &upper_bound);
         (upper_bound, lower_bound)
     }
 
@@ -257,14 +339,31 @@ pub trait CommonModule: admin::AdminModule + events::EventsModule + proxies::Pro
         self.last_price(token_id).get()
     }
 
-    /// Gets the address of the pause guardian.
+    /// Returns the most recent pricing incidents, in chronological order.
     ///
-    fn get_guardian(&self) -> Option<ManagedAddress> {
-        if self.guardian().is_empty() {
-            None
+    #[view(getRecentIncidents)]
+    fn get_recent_incidents(&self) -> MultiValueEncoded<Incident<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let incidents = self.incidents();
+        let len = incidents.len();
+
+        // once the ring buffer is full, the oldest incident is the next one to be overwritten
+        let oldest = if len < MAX_INCIDENTS { 1 } else { self.get_next_incident_position() };
+
+        for index in 0..len {
+            let position = (oldest - 1 + index) % len + 1;
+            result.push(incidents.get(position));
+        }
+        result
+    }
+
+    /// Returns the position of the next incident to be overwritten in the ring buffer.
+    ///
+    fn get_next_incident_position(&self) -> usize {
+        if self.next_incident_position().is_empty() {
+            1usize
         } else {
-            let pause_guardian = self.guardian().get();
-            Some(pause_guardian)
+            self.next_incident_position().get()
         }
     }
 
@@ -334,4 +433,36 @@ pub trait CommonModule: admin::AdminModule + events::EventsModule + proxies::Pro
         self.last_price(token_id).set(price);
         self.last_price_event(token_id, price);
     }
+
+    /// Appends an incident to the incident log. Once the maximum number of incidents is reached, the oldest one is
+    /// overwritten.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    /// - `reason` - The incident reason.
+    /// - `reporter_price` - The price reported by the reporter (in EGLD), if any.
+    /// - `anchor_price` - The anchor price (in EGLD), if any.
+    ///
+    fn log_incident(&self, token_id: &TokenIdentifier, reason: IncidentReason, reporter_price: &BigUint, anchor_price: &BigUint) {
+        let timestamp = self.blockchain().get_block_timestamp();
+        let incident = Incident {
+            token_id: token_id.clone(),
+            reason,
+            reporter_price: reporter_price.clone(),
+            anchor_price: anchor_price.clone(),
+            timestamp,
+        };
+
+        let mut incidents = self.incidents();
+        if incidents.len() < MAX_INCIDENTS {
+            incidents.push(&incident);
+        } else {
+            let position = self.get_next_incident_position();
+            incidents.set(position, &incident);
+            self.next_incident_position().set(position % MAX_INCIDENTS + 1);
+        }
+
+        self.incident_event(&incident);
+    }
 }
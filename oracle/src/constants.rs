@@ -1,8 +1,5 @@
-/// The BPS unit
-pub const BPS: u64 = 10_000;
-
-/// The WAD unit
-pub const WAD: u64 = 1_000_000_000_000_000_000;
+/// The BPS and WAD units, defined at the math crate
+pub use math::constants::{BPS, WAD};
 
 /// The EGLD symbol or ticker
 pub const EGLD_SYMBOL: &[u8] = b"EGLD";
@@ -33,3 +30,15 @@ pub const MIN_LAST_ANCHOR_TOLERANCE: u64 = 10_000_000_000_000_000;
 
 /// The maximum last anchor tolerance allowed (100%)
 pub const MAX_LAST_ANCHOR_TOLERANCE: u64 = 1_000_000_000_000_000_000;
+
+/// The maximum number of incidents kept in the incident log
+pub const MAX_INCIDENTS: usize = 50;
+
+/// The maximum number of consecutive convergence checks that can be required to automatically unpause a token
+pub const MAX_AUTO_UNPAUSE_CHECKS: usize = 100;
+
+/// The minimum time between two consecutive convergence checks for the same token (10 minutes)
+pub const CONVERGENCE_CHECK_INTERVAL: u64 = 600;
+
+/// The maximum number of fallback pricing methods per token
+pub const MAX_PRICING_FALLBACKS: usize = 2;
@@ -6,6 +6,8 @@ multiversx_sc::derive_imports!();
 pub mod oracle_proxy;
 
 pub use admin;
+pub use math;
+pub use pausable;
 
 pub mod common;
 pub mod constants;
@@ -20,7 +22,7 @@ pub mod storage;
 use crate::model::ExchangePricingMethod;
 
 #[multiversx_sc::contract]
-pub trait Oracle: admin::AdminModule + common::CommonModule + events::EventsModule + governance::GovernanceModule + prices::PriceModule + proxies::ProxyModule + storage::StorageModule {
+pub trait Oracle: admin::AdminModule + math::MathModule + pausable::PausableModule + pausable::PausableViewModule + common::CommonModule + events::EventsModule + governance::GovernanceModule + prices::PriceModule + proxies::ProxyModule + storage::StorageModule {
     /// Initializes the Oracle.
     ///
     /// # Arguments:
@@ -43,6 +45,17 @@ pub trait Oracle: admin::AdminModule + common::CommonModule + events::EventsModu
         self.try_set_admin(opt_admin);
     }
 
+    /// Upgrades the Oracle.
+    ///
+    /// # Notes:
+    ///
+    /// - The legacy guardian, if any, becomes the pause guardian.
+    ///
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        if !self.legacy_guardian().is_empty() {
+            let legacy_guardian = self.legacy_guardian().take();
+            self.pause_guardian().set_if_empty(&legacy_guardian);
+        }
+    }
 }
@@ -9,8 +9,6 @@ pub const ERROR_UNEXPECTED_TOKEN_TYPE: &[u8] = b"unexpected token type";
 pub const ERROR_UNEXPECTED_PRICING_METHOD: &[u8] = b"unexpected pricing method";
 pub const ERROR_INVALID_XEXCHANGE_PAIR: &[u8] = b"provided an invalid pair";
 pub const ERROR_XEXCHANGE_SAFE_PRICE_ONLY: &[u8] = b"safe price only";
-pub const ERROR_ONLY_ADMIN: &[u8] = b"only admin allowed";
-pub const ERROR_ONLY_ADMIN_OR_GUARDIAN: &[u8] = b"only admin or guardian allowed";
 pub const ERROR_UNEXPECTED_FIRST_ANCHOR_TOLERANCE: &[u8] = b"unexpected first anchor tolerance";
 pub const ERROR_UNEXPECTED_LAST_ANCHOR_TOLERANCE: &[u8] = b"unexpected last anchor tolerance";
 pub const ERROR_UNEXPECTED_ANCHOR_TOLERANCES: &[u8] = b"unexpected anchor tolerances";
@@ -33,3 +31,13 @@ pub const ERROR_SAME_FALLBACK_TOKEN: &[u8] = b"same fallback token";
 pub const ERROR_UNSUPPORTED_USH_TOKEN: &[u8] = b"unsupported USH token";
 pub const ERROR_CANNOT_USE_PRICE_AGGREGATOR: &[u8] = b"cannot use price aggregator";
 pub const ERROR_CHANGE_FALLBACK_TOKEN: &[u8] = b"change fallback token instead";
+pub const ERROR_UNEXPECTED_AUTO_UNPAUSE_CHECKS: &[u8] = b"unexpected auto unpause checks";
+pub const ERROR_AUTO_UNPAUSE_DISABLED: &[u8] = b"auto unpause is disabled";
+pub const ERROR_PAUSE_NOT_AUTO_LIFTABLE: &[u8] = b"token pause was not triggered by an anchor breach";
+pub const ERROR_CONVERGENCE_CHECK_TOO_SOON: &[u8] = b"convergence check too soon";
+pub const ERROR_TOO_MANY_PRICING_FALLBACKS: &[u8] = b"too many pricing fallbacks";
+pub const ERROR_UNEXPECTED_PRICING_FALLBACK: &[u8] = b"unexpected pricing fallback";
+pub const ERROR_INVALID_QUOTE_TOKEN: &[u8] = b"invalid quote token";
+pub const ERROR_TOKEN_IS_QUOTE_TOKEN: &[u8] = b"token is used as quote token";
+pub const ERROR_INSUFFICIENT_POOL_RESERVES: &[u8] = b"insufficient pool reserves";
+pub const ERROR_NO_LAST_PRICE: &[u8] = b"no last price";
@@ -4,14 +4,14 @@ use crate::model::*;
 
 #[multiversx_sc::module]
 pub trait EventsModule {
-    /// Emitted when a new guardian is set.
-    #[event("new_guardian_event")]
-    fn new_guardian_event(&self, #[indexed] old: &Option<ManagedAddress>, #[indexed] new: &ManagedAddress);
-
     /// Event emitted when a new token is supported.
     #[event("support_token_event")]
     fn support_token_event(&self, #[indexed] token_data: &TokenData<Self::Api>);
 
+    /// Event emitted when a native token quoted against another supported token is supported.
+    #[event("support_quoted_token_event")]
+    fn support_quoted_token_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] quote_token_id: &TokenIdentifier);
+
     /// Event emitted when the Liquid Staked EGLD token is supported.
     #[event("support_ls_token_event")]
     fn support_ls_token_event(&self, #[indexed] token_identifier: &TokenIdentifier);
@@ -52,6 +52,22 @@ pub trait EventsModule {
     #[event("pricing_method_event")]
     fn pricing_method_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] pricing_method: &PricingMethod);
 
+    /// Event emitted when the minimum pool reserve of a token is set.
+    #[event("set_min_pool_reserves_event")]
+    fn set_min_pool_reserves_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] min_pool_reserves: &BigUint);
+
+    /// Event emitted when the chain of fallback pricing methods of a token is set.
+    #[event("pricing_fallbacks_event")]
+    fn pricing_fallbacks_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] pricing_fallbacks: &ManagedVec<PricingMethod>);
+
+    /// Event emitted when a token is priced using a fallback pricing method instead of its pricing method.
+    #[event("pricing_fallback_used_event")]
+    fn pricing_fallback_used_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] pricing_method: &PricingMethod, #[indexed] fallback_pricing_method: &PricingMethod);
+
+    /// Event emitted when a pricing method serves the price of a token.
+    #[event("pricing_method_used_event")]
+    fn pricing_method_used_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] pricing_method: &PricingMethod);
+
     /// Event emitted when the EGLD Wrapper smart contract is set.
     #[event("set_egld_wrapper_event")]
     fn set_egld_wrapper_event(&self, #[indexed] egld_wrapper: &ManagedAddress, #[indexed] wegld_id: &TokenIdentifier);
@@ -91,4 +107,20 @@ pub trait EventsModule {
     /// Event emitted when the last reported price of a token is updated.
     #[event("last_price_event")]
     fn last_price_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] price: &BigUint);
+
+    /// Event emitted when the number of consecutive convergence checks required to automatically unpause a token is set.
+    #[event("set_auto_unpause_checks_event")]
+    fn set_auto_unpause_checks_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] checks: usize);
+
+    /// Event emitted when a convergence check is performed for a paused token.
+    #[event("price_convergence_check_event")]
+    fn price_convergence_check_event(&self, #[indexed] token_id: &TokenIdentifier, #[indexed] converged: bool, #[indexed] checks: usize);
+
+    /// Event emitted when an incident is logged.
+    #[event("incident_event")]
+    fn incident_event(&self, #[indexed] incident: &Incident<Self::Api>);
+
+    /// Event emitted when the governance token is supported.
+    #[event("support_governance_token_event")]
+    fn support_governance_token_event(&self, #[indexed] token_data: &TokenData<Self::Api>, #[indexed] pricing_method: &PricingMethod);
 }
@@ -4,25 +4,7 @@ multiversx_sc::derive_imports!();
 use crate::{common, constants::*, errors::*, events, model::*, prices, proxies, storage};
 
 #[multiversx_sc::module]
-pub trait GovernanceModule: admin::AdminModule + events::EventsModule + storage::StorageModule + common::CommonModule + prices::PriceModule + proxies::ProxyModule {
-    /// Sets the Guardian of the Oracle.
-    ///
-    /// # Arguments:
-    ///
-    /// - `guardian` - The address of the new Guardian.
-    ///
-    /// # Notes:
-    ///
-    /// - Can only be called by the admin.
-    ///
-    #[endpoint(setGuardian)]
-    fn set_guardian(&self, guardian: ManagedAddress) {
-        self.require_admin();
-        let old_guardian = self.get_guardian();
-        self.guardian().set(&guardian);
-        self.new_guardian_event(&old_guardian, &guardian);
-    }
-
+pub trait GovernanceModule: admin::AdminModule + pausable::PausableModule + events::EventsModule + storage::StorageModule + common::CommonModule + prices::PriceModule + proxies::ProxyModule {
     /// Unpauses the token pricing.
     ///
     /// # Arguments:
@@ -59,8 +41,11 @@ pub trait GovernanceModule: admin::AdminModule + events::EventsModule + storage:
 
         self.check_default_pricing_method(&token_id);
         self.is_token_paused(&token_id).set(false);
+        self.paused_by_anchor_breach(&token_id).clear();
+        self.convergence_checks(&token_id).clear();
 
         self.unpause_token_event(&token_id);
+        self.log_incident(&token_id, IncidentReason::TokenUnpaused, &BigUint::zero(), &BigUint::zero());
     }
 
     /// Pauses the token pricing.
@@ -79,7 +64,97 @@ pub trait GovernanceModule: admin::AdminModule + events::EventsModule + storage:
         self.require_supported_token(&token_id);
         self.require_token_not_paused(&token_id);
         self.is_token_paused(&token_id).set(true);
+        self.paused_by_anchor_breach(&token_id).clear();
+        self.convergence_checks(&token_id).clear();
         self.pause_token_event(&token_id);
+        self.log_incident(&token_id, IncidentReason::TokenPaused, &BigUint::zero(), &BigUint::zero());
+    }
+
+    /// Sets the number of consecutive convergence checks required to automatically unpause a token, i.e. opts the token in
+    /// or out of the auto unpause mode.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    /// - `checks` - The number of consecutive convergence checks. Zero disables the auto unpause mode.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The token must have been already supported.
+    ///
+    #[endpoint(setAutoUnpauseChecks)]
+    fn set_auto_unpause_checks(&self, token_id: TokenIdentifier, checks: usize) {
+        self.require_admin();
+        self.require_supported_token(&token_id);
+        require!(checks <= MAX_AUTO_UNPAUSE_CHECKS, ERROR_UNEXPECTED_AUTO_UNPAUSE_CHECKS);
+
+        self.auto_unpause_checks(&token_id).set(checks);
+        self.convergence_checks(&token_id).clear();
+
+        self.set_auto_unpause_checks_event(&token_id, checks);
+    }
+
+    /// Checks whether the xExchange Safe price and the Price Aggregator price of a paused token are within the first anchor
+    /// tolerance. After the configured number of consecutive successful checks, the token pricing is unpaused.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone, e.g. a keeper.
+    /// - The token must be paused, use the `Default` pricing method and have opted in the auto unpause mode.
+    /// - Only pauses triggered by a last anchor breach can be lifted, i.e. pauses set by the admin or triggered by drained
+    ///   xExchange pools must be lifted by the admin or guardian.
+    /// - Consecutive checks must be at least `CONVERGENCE_CHECK_INTERVAL` seconds apart.
+    /// - A failed check resets the number of consecutive checks.
+    /// - Returns whether the token pricing has been unpaused.
+    ///
+    #[endpoint(checkPriceConvergence)]
+    fn check_price_convergence(&self, token_id: TokenIdentifier) -> bool {
+        self.require_supported_token(&token_id);
+        self.require_token_paused(&token_id);
+        require!(self.get_pricing_method(&token_id) == PricingMethod::Default, ERROR_UNEXPECTED_PRICING_METHOD);
+
+        require!(self.paused_by_anchor_breach(&token_id).get(), ERROR_PAUSE_NOT_AUTO_LIFTABLE);
+
+        let required_checks = self.auto_unpause_checks(&token_id).get();
+        require!(required_checks > 0, ERROR_AUTO_UNPAUSE_DISABLED);
+
+        let t = self.blockchain().get_block_timestamp();
+        let last_check = self.last_convergence_check(&token_id).get();
+        require!(last_check == 0 || t >= last_check + CONVERGENCE_CHECK_INTERVAL, ERROR_CONVERGENCE_CHECK_TOO_SOON);
+        self.last_convergence_check(&token_id).set(t);
+
+        let token_data = self.get_supported_token_data(&token_id);
+        if !self.is_default_price_reliable(&token_data) {
+            self.convergence_checks(&token_id).clear();
+            self.price_convergence_check_event(&token_id, false, 0);
+            return false;
+        }
+
+        let checks = self.convergence_checks(&token_id).update(|checks| {
+            *checks += 1;
+            *checks
+        });
+        self.price_convergence_check_event(&token_id, true, checks);
+
+        if checks < required_checks {
+            return false;
+        }
+
+        // the price has converged, hence it is considered reliable again
+        self.get_default_price_in_egld_internal(&token_data);
+        self.is_token_paused(&token_id).set(false);
+        self.paused_by_anchor_breach(&token_id).clear();
+        self.convergence_checks(&token_id).clear();
+
+        self.unpause_token_event(&token_id);
+        self.log_incident(&token_id, IncidentReason::TokenUnpaused, &BigUint::zero(), &BigUint::zero());
+
+        true
     }
 
     /// Allows pricing of tokens using the Price Aggregator Smart Contract as the price provider.
@@ -176,12 +251,121 @@ pub trait GovernanceModule: admin::AdminModule + events::EventsModule + storage:
         let token_data = self.get_native_token_data(&token_id, token_decimals, xexchange_pair_address, &first_anchor_tolerance, &last_anchor_tolerance);
 
         self.supported_tokens(&token_id).set(&token_data);
-        self.whitelisted_tokens().insert(token_id);
+        self.whitelisted_tokens().insert(token_id.clone());
+        self.set_quote_token(&token_id, None);
+
+        // make sure Default algorithm is working properly
+        self.set_pricing_method_internal(&token_data, &PricingMethod::Default);
+
+        self.support_token_event(&token_data);
+    }
+
+    /// Supports a native token whose xExchange pair is quoted against another supported native token instead of WEGLD, such
+    /// as USDC. Its xExchange price is routed through the quote token price, i.e. token -> quote token -> EGLD.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    /// - `token_decimals` - The token decimals.
+    /// - `xexchange_pair_address` - The xExchange Pair address.
+    /// - `first_anchor_tolerance` - The first anchor tolerance in wad.
+    /// - `last_anchor_tolerance` - The last anchor tolerance in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The pair address must be a xExchange pair contract between the token and a quote token.
+    /// - The quote token must be a supported native token quoted against WEGLD, i.e. only a single hop is allowed.
+    /// - The token cannot be used as quote token by other tokens.
+    /// - Sets the Default method as pricing method and makes sure it is working properly.
+    ///
+    #[endpoint(supportQuotedNativeToken)]
+    fn support_quoted_native_token(&self, token_id: TokenIdentifier, token_decimals: usize, xexchange_pair_address: ManagedAddress, first_anchor_tolerance: BigUint, last_anchor_tolerance: BigUint) {
+        self.require_admin();
+        self.require_valid_token_identifier_subset(&token_id);
+
+        // USH token cannot be supported nor modified as a native token
+        require!(!self.is_ush_token(&token_id), ERROR_UNEXPECTED_TOKEN_ID);
+
+        // avoid multiple hops
+        require!(!self.quote_tokens().contains(&token_id), ERROR_TOKEN_IS_QUOTE_TOKEN);
+
+        let (first_token_id, second_token_id) = self.get_xexchange_pair_tokens(&xexchange_pair_address);
+        let quote_token_id = if first_token_id == token_id {
+            second_token_id.clone()
+        } else {
+            require!(second_token_id == token_id, ERROR_INVALID_XEXCHANGE_PAIR);
+            first_token_id.clone()
+        };
+
+        // the quote token must be a native token quoted against WEGLD
+        require!(self.is_supported_token(&quote_token_id) && self.get_quote_token(&quote_token_id).is_none(), ERROR_INVALID_QUOTE_TOKEN);
+        let quote_token_data = self.get_supported_token_data(&quote_token_id);
+        require!(quote_token_data.token_type == TokenType::Native, ERROR_INVALID_QUOTE_TOKEN);
+
+        let pair = ExchangePair { address: xexchange_pair_address, token0: first_token_id, token1: second_token_id };
+        let tolerances = self.get_anchor_tolerances(&first_anchor_tolerance, &last_anchor_tolerance);
+
+        let token_data = TokenData {
+            token_type: TokenType::Native,
+            identifier: token_id.clone(),
+            unit_price: BigUint::from(WAD),
+            ticker: token_id.ticker(),
+            decimals: token_decimals,
+            exp: BigUint::from(10u32).pow(token_decimals as u32),
+            xexchange_pair: Some(pair),
+            tolerances: Some(tolerances),
+        };
+
+        self.supported_tokens(&token_id).set(&token_data);
+        self.whitelisted_tokens().insert(token_id.clone());
+        self.set_quote_token(&token_id, Some(&quote_token_id));
 
         // make sure Default algorithm is working properly
         self.set_pricing_method_internal(&token_data, &PricingMethod::Default);
 
         self.support_token_event(&token_data);
+        self.support_quoted_token_event(&token_id, &quote_token_id);
+    }
+
+    /// Supports Hatom's governance token, which needs to be priced even if it is not listed as a money market underlying, e.g.
+    /// to value boosted rewards at the Controller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The governance token identifier.
+    /// - `token_decimals` - The governance token decimals.
+    /// - `xexchange_pair_address` - The xExchange Pair address, quoted against WEGLD.
+    /// - `first_anchor_tolerance` - The first anchor tolerance in wad.
+    /// - `last_anchor_tolerance` - The last anchor tolerance in wad.
+    /// - `opt_pricing_method` - An optional pricing method. Defaults to `Safe`.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The governance token is not whitelisted, i.e. it does not need a Price Aggregator price feed unless the `Default` or
+    ///   `PriceAggregator` pricing methods are used.
+    /// - The pricing method can be later modified using `setPricingMethod`.
+    ///
+    #[endpoint(supportGovernanceToken)]
+    fn support_governance_token(&self, token_id: TokenIdentifier, token_decimals: usize, xexchange_pair_address: ManagedAddress, first_anchor_tolerance: BigUint, last_anchor_tolerance: BigUint, opt_pricing_method: OptionalValue<PricingMethod>) {
+        self.require_admin();
+        self.require_valid_token_identifier_subset(&token_id);
+
+        // USH token cannot be supported as governance token
+        require!(!self.is_ush_token(&token_id), ERROR_UNEXPECTED_TOKEN_ID);
+
+        let pricing_method = opt_pricing_method.into_option().unwrap_or(PricingMethod::Safe);
+        let token_data = self.get_native_token_data(&token_id, token_decimals, xexchange_pair_address, &first_anchor_tolerance, &last_anchor_tolerance);
+
+        self.supported_tokens(&token_id).set(&token_data);
+        self.set_quote_token(&token_id, None);
+        self.governance_token_id().set(&token_id);
+
+        // make sure the pricing method is working properly
+        self.set_pricing_method_internal(&token_data, &pricing_method);
+
+        self.support_governance_token_event(&token_data, &pricing_method);
     }
 
     /// Computes the native token data using information from xExchange and given tolerances.
@@ -382,6 +566,86 @@ pub trait GovernanceModule: admin::AdminModule + events::EventsModule + storage:
         self.pricing_method_event(&token_id, &pricing_method);
     }
 
+    /// Sets the minimum reserve of a token in its xExchange pool. Below it, xExchange based prices are considered unreliable
+    /// and the `Default` pricing algorithm pauses the token pricing.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The ESDT token identifier.
+    /// - `min_pool_reserves` - The minimum pool reserve in token units. Zero disables the check.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Only native tokens can have a minimum pool reserve.
+    ///
+    #[endpoint(setMinPoolReserves)]
+    fn set_min_pool_reserves(&self, token_id: TokenIdentifier, min_pool_reserves: BigUint) {
+        self.require_admin();
+        self.require_supported_token(&token_id);
+
+        let token_data = self.get_supported_token_data(&token_id);
+        require!(token_data.token_type == TokenType::Native, ERROR_UNEXPECTED_TOKEN_TYPE);
+
+        if min_pool_reserves == BigUint::zero() {
+            self.min_pool_reserves(&token_id).clear();
+        } else {
+            self.min_pool_reserves(&token_id).set(&min_pool_reserves);
+        }
+
+        self.set_min_pool_reserves_event(&token_id, &min_pool_reserves);
+    }
+
+    /// Sets the ordered chain of fallback pricing methods for the given token, which are walked in order whenever the pricing
+    /// method of the token cannot be used.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The ESDT token identifier.
+    /// - `pricing_fallbacks` - The fallback pricing methods, in order of preference. Empty clears the chain.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Only native tokens can have fallback pricing methods.
+    /// - Fallback pricing methods are not walked for paused tokens.
+    /// - Only the `Default`, `Safe` and `PriceAggregator` pricing methods are allowed as fallbacks, without repetitions.
+    ///
+    #[endpoint(setPricingFallbacks)]
+    fn set_pricing_fallbacks(&self, token_id: TokenIdentifier, pricing_fallbacks: MultiValueEncoded<PricingMethod>) {
+        self.require_admin();
+        self.require_supported_token(&token_id);
+
+        let token_data = self.get_supported_token_data(&token_id);
+        require!(token_data.token_type == TokenType::Native, ERROR_UNEXPECTED_TOKEN_TYPE);
+
+        let pricing_fallbacks = pricing_fallbacks.to_vec();
+        require!(pricing_fallbacks.len() <= MAX_PRICING_FALLBACKS, ERROR_TOO_MANY_PRICING_FALLBACKS);
+
+        for (i, pricing_method) in pricing_fallbacks.iter().enumerate() {
+            match pricing_method {
+                PricingMethod::Default | PricingMethod::Safe => {},
+                PricingMethod::PriceAggregator => {
+                    require!(!self.price_aggregator_address().is_empty(), ERROR_CANNOT_USE_PRICE_AGGREGATOR);
+                },
+                PricingMethod::None | PricingMethod::Instantaneous => {
+                    sc_panic!(ERROR_UNEXPECTED_PRICING_FALLBACK);
+                },
+            }
+
+            let repeated = pricing_fallbacks.iter().take(i).any(|other| other == pricing_method);
+            require!(!repeated, ERROR_UNEXPECTED_PRICING_FALLBACK);
+        }
+
+        if pricing_fallbacks.is_empty() {
+            self.pricing_fallbacks(&token_id).clear();
+        } else {
+            self.pricing_fallbacks(&token_id).set(&pricing_fallbacks);
+        }
+
+        self.pricing_fallbacks_event(&token_id, &pricing_fallbacks);
+    }
+
     fn set_pricing_method_internal(&self, token_data: &TokenData<Self::Api>, pricing_method: &PricingMethod) {
         let TokenData { identifier: token_id, xexchange_pair: opt_pair, .. } = token_data;
 
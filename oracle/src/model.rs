@@ -2,7 +2,7 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Copy, Debug)]
 pub enum PricingMethod {
     None,
     Default,
@@ -11,6 +11,13 @@ pub enum PricingMethod {
     PriceAggregator,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub struct PricingStats {
+    pub pricing_method: PricingMethod,
+    pub last_failure: u64,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
 pub enum ExchangePricingMethod {
@@ -65,3 +72,23 @@ pub struct ToleranceData<M: ManagedTypeApi> {
     pub last_upper_bound_ratio: BigUint<M>,
     pub last_lower_bound_ratio: BigUint<M>,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum IncidentReason {
+    TokenPaused,
+    TokenUnpaused,
+    FirstAnchorSurpassed,
+    LastAnchorSurpassed,
+    LowPoolReserves,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone)]
+pub struct Incident<M: ManagedTypeApi> {
+    pub token_id: TokenIdentifier<M>,
+    pub reason: IncidentReason,
+    pub reporter_price: BigUint<M>,
+    pub anchor_price: BigUint<M>,
+    pub timestamp: u64,
+}
@@ -157,37 +157,113 @@ where
             .original_result()
     }
 
-    /// A utility function to highlight that this smart contract is a Price Oracle.
+    /// Pauses the contract.
     ///
-    pub fn is_price_oracle(
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the pause guardian.
+    /// - Pausing an already paused contract has no effect.
+    ///
+    pub fn pause(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isPriceOracle")
+            .raw_call("pause")
+            .original_result()
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Unpausing a contract that is not paused has no effect.
+    ///
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
             .original_result()
     }
 
-    /// Sets the Guardian of the Oracle.
+    /// Sets the pause guardian of the contract.
     ///
     /// # Arguments:
     ///
-    /// - `guardian` - The address of the new Guardian.
+    /// - `new_pause_guardian` - The address of the new pause guardian.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
+    /// - Cannot be called if the pause guardian is read from another contract.
     ///
-    pub fn set_guardian<
+    pub fn set_pause_guardian<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        guardian: Arg0,
+        new_pause_guardian: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setGuardian")
-            .argument(&guardian)
+            .raw_call("setPauseGuardian")
+            .argument(&new_pause_guardian)
+            .original_result()
+    }
+
+    /// Returns the address of the pause guardian, if one has been set.
+    ///
+    pub fn get_pause_guardian_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseGuardian")
+            .original_result()
+    }
+
+    /// Stores the address of the contract from which the pause guardian is read, if any.
+    pub fn pause_guardian_source(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseGuardianSource")
+            .original_result()
+    }
+
+    /// A utility function to highlight that this smart contract is a Price Oracle.
+    ///
+    pub fn is_price_oracle(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPriceOracle")
+            .original_result()
+    }
+
+    /// Returns the most recent pricing incidents, in chronological order.
+    ///
+    pub fn get_recent_incidents(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Incident<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRecentIncidents")
+            .original_result()
+    }
+
+    /// Returns whether the contract is paused.
+    ///
+    pub fn is_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
             .original_result()
     }
 
@@ -247,6 +323,63 @@ where
             .original_result()
     }
 
+    /// Sets the number of consecutive convergence checks required to automatically unpause a token, i.e. opts the token in
+    /// or out of the auto unpause mode.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    /// - `checks` - The number of consecutive convergence checks. Zero disables the auto unpause mode.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The token must have been already supported.
+    ///
+    pub fn set_auto_unpause_checks<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        token_id: Arg0,
+        checks: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAutoUnpauseChecks")
+            .argument(&token_id)
+            .argument(&checks)
+            .original_result()
+    }
+
+    /// Checks whether the xExchange Safe price and the Price Aggregator price of a paused token are within the first anchor
+    /// tolerance. After the configured number of consecutive successful checks, the token pricing is unpaused.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone, e.g. a keeper.
+    /// - The token must be paused, use the `Default` pricing method and have opted in the auto unpause mode.
+    /// - Consecutive checks must be at least `CONVERGENCE_CHECK_INTERVAL` seconds apart.
+    /// - A failed check resets the number of consecutive checks.
+    /// - Returns whether the token pricing has been unpaused.
+    ///
+    pub fn check_price_convergence<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("checkPriceConvergence")
+            .argument(&token_id)
+            .original_result()
+    }
+
     /// Allows pricing of tokens using the Price Aggregator Smart Contract as the price provider.
     ///
     /// # Arguments:
@@ -350,6 +483,97 @@ where
             .original_result()
     }
 
+    /// Supports a native token whose xExchange pair is quoted against another supported native token instead of WEGLD, such
+    /// as USDC. Its xExchange price is routed through the quote token price, i.e. token -> quote token -> EGLD.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The token identifier.
+    /// - `token_decimals` - The token decimals.
+    /// - `xexchange_pair_address` - The xExchange Pair address.
+    /// - `first_anchor_tolerance` - The first anchor tolerance in wad.
+    /// - `last_anchor_tolerance` - The last anchor tolerance in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The pair address must be a xExchange pair contract between the token and a quote token.
+    /// - The quote token must be a supported native token quoted against WEGLD, i.e. only a single hop is allowed.
+    /// - The token cannot be used as quote token by other tokens.
+    /// - Sets the Default method as pricing method and makes sure it is working properly.
+    ///
+    pub fn support_quoted_native_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        token_decimals: Arg1,
+        xexchange_pair_address: Arg2,
+        first_anchor_tolerance: Arg3,
+        last_anchor_tolerance: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("supportQuotedNativeToken")
+            .argument(&token_id)
+            .argument(&token_decimals)
+            .argument(&xexchange_pair_address)
+            .argument(&first_anchor_tolerance)
+            .argument(&last_anchor_tolerance)
+            .original_result()
+    }
+
+    /// Supports Hatom's governance token, which needs to be priced even if it is not listed as a money market underlying, e.g.
+    /// to value boosted rewards at the Controller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The governance token identifier.
+    /// - `token_decimals` - The governance token decimals.
+    /// - `xexchange_pair_address` - The xExchange Pair address, quoted against WEGLD.
+    /// - `first_anchor_tolerance` - The first anchor tolerance in wad.
+    /// - `last_anchor_tolerance` - The last anchor tolerance in wad.
+    /// - `opt_pricing_method` - An optional pricing method. Defaults to `Safe`.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The governance token is not whitelisted, i.e. it does not need a Price Aggregator price feed unless the `Default` or
+    ///   `PriceAggregator` pricing methods are used.
+    /// - The pricing method can be later modified using `setPricingMethod`.
+    ///
+    pub fn support_governance_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<OptionalValue<PricingMethod>>,
+    >(
+        self,
+        token_id: Arg0,
+        token_decimals: Arg1,
+        xexchange_pair_address: Arg2,
+        first_anchor_tolerance: Arg3,
+        last_anchor_tolerance: Arg4,
+        opt_pricing_method: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("supportGovernanceToken")
+            .argument(&token_id)
+            .argument(&token_decimals)
+            .argument(&xexchange_pair_address)
+            .argument(&first_anchor_tolerance)
+            .argument(&last_anchor_tolerance)
+            .argument(&opt_pricing_method)
+            .original_result()
+    }
+
     /// Supports sEGLD pricing using the EGLD Liquid Staking smart contract as the price provider.
     ///
     /// # Arguments:
@@ -479,6 +703,66 @@ where
             .original_result()
     }
 
+    /// Sets the minimum reserve of a token in its xExchange pool. Below it, xExchange based prices are considered unreliable
+    /// and the `Default` pricing algorithm pauses the token pricing.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The ESDT token identifier.
+    /// - `min_pool_reserves` - The minimum pool reserve in token units. Zero disables the check.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Only native tokens can have a minimum pool reserve.
+    ///
+    pub fn set_min_pool_reserves<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        min_pool_reserves: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinPoolReserves")
+            .argument(&token_id)
+            .argument(&min_pool_reserves)
+            .original_result()
+    }
+
+    /// Sets the ordered chain of fallback pricing methods for the given token, which are walked in order whenever the pricing
+    /// method of the token cannot be used.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The ESDT token identifier.
+    /// - `pricing_fallbacks` - The fallback pricing methods, in order of preference. Empty clears the chain.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Only native tokens can have fallback pricing methods.
+    /// - Fallback pricing methods are not walked for paused tokens.
+    /// - Only the `Default`, `Safe` and `PriceAggregator` pricing methods are allowed as fallbacks, without repetitions.
+    ///
+    pub fn set_pricing_fallbacks<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, PricingMethod>>,
+    >(
+        self,
+        token_id: Arg0,
+        pricing_fallbacks: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPricingFallbacks")
+            .argument(&token_id)
+            .argument(&pricing_fallbacks)
+            .original_result()
+    }
+
     /// Sets a new first and last anchor tolerances for a given token.
     ///
     /// # Arguments:
@@ -525,6 +809,8 @@ where
     ///
     /// - The WEGLD price always equals to one.
     /// - The SEGLD price is retrieved from the Liquid Staking smart contract.
+    /// - While the Oracle is paused, prices are not updated and the last price of each token is returned instead.
+    /// - Paused tokens cannot be priced, i.e. the fallback pricing methods are not walked for them.
     ///
     pub fn get_price_in_egld<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
@@ -539,6 +825,32 @@ where
             .original_result()
     }
 
+    /// Returns the usage statistics of each pricing method for a given token, i.e. the timestamp of the last time it could
+    /// not be used and a fallback pricing method was walked.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The identifier of the token.
+    ///
+    /// # Notes:
+    ///
+    /// - Only prices served through the `getPrice` endpoint for supported tokens are tracked.
+    /// - Failures are recorded for the pricing method and for each skipped fallback pricing method.
+    /// - The number of times each pricing method has served the token price is tracked by `pricing_method_used_event`.
+    ///
+    pub fn get_pricing_stats<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PricingStats>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPricingStats")
+            .argument(&token_id)
+            .original_result()
+    }
+
     /// Returns the xExchange price of a token in EGLD, based on its paired liquidity pool reserves.
     ///
     /// # Arguments:
@@ -596,6 +908,17 @@ where
             .original_result()
     }
 
+    /// Returns the EGLD price in USD and in WAD units, as reported by the Price Aggregator.
+    ///
+    pub fn get_egld_price_in_usd(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEgldPriceInUsd")
+            .original_result()
+    }
+
     ///  Stores wrapped EGLD smart contract address.
     pub fn egld_wrapper(
         self,
@@ -676,32 +999,32 @@ where
             .original_result()
     }
 
-    pub fn ush_fallback_token_id(
+    /// Stores the Hatom governance token identifier.
+    pub fn governance_token_id(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getUshFallbackTokenId")
+            .raw_call("getGovernanceTokenId")
             .original_result()
     }
 
-    /// Stores the xExchange pricing method.
-    pub fn xexchange_pricing_method(
+    pub fn ush_fallback_token_id(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ExchangePricingMethod> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getXExchangePricingMethod")
+            .raw_call("getUshFallbackTokenId")
             .original_result()
     }
 
-    /// Stores the guardian address.
-    pub fn guardian(
+    /// Stores the xExchange pricing method.
+    pub fn xexchange_pricing_method(
         self,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ExchangePricingMethod> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getGuardian")
+            .raw_call("getXExchangePricingMethod")
             .original_result()
     }
 
@@ -749,6 +1072,44 @@ where
             .original_result()
     }
 
+    /// Stores the quote token of the xExchange pair of a native token, if it is not quoted against WEGLD.
+    pub fn quote_token<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuoteToken")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stores the set of tokens used as quote tokens by other native tokens.
+    pub fn quote_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, TokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuoteTokens")
+            .original_result()
+    }
+
+    /// Stores the minimum reserve of a token in its xExchange pool required to price it using xExchange, in token units.
+    pub fn min_pool_reserves<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinPoolReserves")
+            .argument(&token_id)
+            .original_result()
+    }
+
     /// Stores the pricing method for each token.
     pub fn pricing_method<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
@@ -763,6 +1124,20 @@ where
             .original_result()
     }
 
+    /// Stores the ordered chain of fallback pricing methods for each token, walked when the pricing method cannot be used.
+    pub fn pricing_fallbacks<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, PricingMethod>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPricingFallbacks")
+            .argument(&token_id)
+            .original_result()
+    }
+
     /// Stores the last reported price for each token.
     pub fn last_price<
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
@@ -800,7 +1175,63 @@ where
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("isPaused")
+            .raw_call("isTokenPaused")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stores whether the token pricing has been paused by the `Default` pricing algorithm because of a last anchor breach.
+    pub fn paused_by_anchor_breach<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPausedByAnchorBreach")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stores the number of consecutive convergence checks required to automatically unpause a token, if non-zero.
+    pub fn auto_unpause_checks<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAutoUnpauseChecks")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stores the current number of consecutive convergence checks passed by a paused token.
+    pub fn convergence_checks<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getConvergenceChecks")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    /// Stores the timestamp of the last convergence check for a token.
+    pub fn last_convergence_check<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastConvergenceCheck")
             .argument(&token_id)
             .original_result()
     }
@@ -815,6 +1246,29 @@ pub enum ExchangePricingMethod {
     All,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone)]
+pub struct Incident<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_id: TokenIdentifier<Api>,
+    pub reason: IncidentReason,
+    pub reporter_price: BigUint<Api>,
+    pub anchor_price: BigUint<Api>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub enum IncidentReason {
+    TokenPaused,
+    TokenUnpaused,
+    FirstAnchorSurpassed,
+    LastAnchorSurpassed,
+    LowPoolReserves,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq)]
 pub struct TokenData<Api>
@@ -863,7 +1317,7 @@ where
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode, PartialEq, Debug)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Copy, Debug)]
 pub enum PricingMethod {
     None,
     Default,
@@ -871,3 +1325,10 @@ pub enum PricingMethod {
     Safe,
     PriceAggregator,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug)]
+pub struct PricingStats {
+    pub pricing_method: PricingMethod,
+    pub last_failure: u64,
+}
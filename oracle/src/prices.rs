@@ -2,9 +2,10 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use super::{common, constants::*, errors::*, events, model::*, proxies, storage};
+use math::Rounding;
 
 #[multiversx_sc::module]
-pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::ProxyModule + common::CommonModule + storage::StorageModule {
+pub trait PriceModule: admin::AdminModule + pausable::PausableModule + events::EventsModule + proxies::ProxyModule + common::CommonModule + storage::StorageModule {
     /// Returns the token price in EGLD and in WAD units.
     ///
     /// # Arguments:
@@ -15,6 +16,8 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
     ///
     /// - The WEGLD price always equals to one.
     /// - The SEGLD price is retrieved from the Liquid Staking smart contract.
+    /// - While the Oracle is paused, prices are not updated and the last price of each token is returned instead.
+    /// - Paused tokens cannot be priced, i.e. the fallback pricing methods are not walked for them.
     ///
     #[endpoint(getPrice)]
     fn get_price_in_egld(&self, token_id: &TokenIdentifier) -> BigUint {
@@ -26,6 +29,13 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
             return BigUint::from(WAD);
         }
 
+        // pausing the Oracle only gates price updates, such that consumers are not reverted
+        if self.paused().get() {
+            let last_price_mapper = self.last_price(token_id);
+            require!(!last_price_mapper.is_empty(), ERROR_NO_LAST_PRICE);
+            return last_price_mapper.get();
+        }
+
         // the Oracle fetches SEGLD price from Liquid Staking
         if self.is_ls_token(token_id) {
             let price = self.get_ls_token_price();
@@ -39,16 +49,65 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
             let tao_token_id = self.get_tao_token_id();
             let tao_price = self.get_price_in_egld(&tao_token_id);
 
-            let price = fx * tao_price / BigUint::from(WAD);
+            let price = self.wad_mul(&fx, &tao_price);
             self.set_last_price(token_id, &price);
             return price;
         }
 
         // if the token is not WEGLD, SEGLD nor STAO, it must have been supported
         self.require_supported_token(token_id);
+
+        // a paused token cannot be priced, not even by its fallback pricing methods
+        require!(!self.is_token_paused(token_id).get(), ERROR_TOKEN_PRICING_PAUSED);
         let pricing_method = self.get_pricing_method(token_id);
         let token_data = self.get_supported_token_data(token_id);
 
+        // walk the fallback chain if the pricing method cannot be used
+        let served_pricing_method = self.get_usable_pricing_method(&token_data, pricing_method);
+        if served_pricing_method != pricing_method {
+            self.pricing_fallback_used_event(token_id, &pricing_method, &served_pricing_method);
+        }
+
+        // usage is tracked by events only, such that pricing does not require additional storage writes
+        self.pricing_method_used_event(token_id, &served_pricing_method);
+
+        self.get_price_in_egld_with_method(&token_data, served_pricing_method)
+    }
+
+    /// Returns the usage statistics of each pricing method for a given token, i.e. the timestamp of the last time it could
+    /// not be used and a fallback pricing method was walked.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_id` - The identifier of the token.
+    ///
+    /// # Notes:
+    ///
+    /// - Only prices served through the `getPrice` endpoint for supported tokens are tracked.
+    /// - Failures are recorded for the pricing method and for each skipped fallback pricing method.
+    /// - The number of times each pricing method has served the token price is tracked by `pricing_method_used_event`.
+    ///
+    #[view(getPricingStats)]
+    fn get_pricing_stats(&self, token_id: &TokenIdentifier) -> MultiValueEncoded<PricingStats> {
+        let mut result = MultiValueEncoded::new();
+        for pricing_method in [PricingMethod::Default, PricingMethod::Instantaneous, PricingMethod::Safe, PricingMethod::PriceAggregator] {
+            result.push(PricingStats {
+                pricing_method,
+                last_failure: self.pricing_method_last_failure(token_id, &pricing_method).get(),
+            });
+        }
+        result
+    }
+
+    /// Returns the token price in EGLD and in WAD units using a given pricing method.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_data` - The token data.
+    /// - `pricing_method` - The pricing method.
+    ///
+    fn get_price_in_egld_with_method(&self, token_data: &TokenData<Self::Api>, pricing_method: PricingMethod) -> BigUint {
+        let token_id = &token_data.identifier;
         match pricing_method {
             PricingMethod::None => {
                 sc_panic!(ERROR_CANNOT_PRICE_TOKEN);
@@ -56,22 +115,22 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
             PricingMethod::Default => {
                 // if the token pricing has been paused, it cannot be priced
                 require!(!self.is_token_paused(token_id).get(), ERROR_TOKEN_PRICING_PAUSED);
-                self.get_default_price_in_egld_internal(&token_data)
+                self.get_default_price_in_egld_internal(token_data)
             },
             PricingMethod::Instantaneous => {
-                let price = self.get_xexchange_instantaneous_price_in_egld_internal(&token_data);
+                let price = self.get_xexchange_instantaneous_price_in_egld_internal(token_data);
                 self.unreliable_pricing_method_event(token_id, &PricingMethod::Instantaneous);
                 self.set_last_price(token_id, &price);
                 price
             },
             PricingMethod::Safe => {
-                let price = self.get_xexchange_safe_price_in_egld_internal(&token_data, false);
+                let price = self.get_xexchange_safe_price_in_egld_internal(token_data, false);
                 self.unreliable_pricing_method_event(token_id, &PricingMethod::Safe);
                 self.set_last_price(token_id, &price);
                 price
             },
             PricingMethod::PriceAggregator => {
-                let price = self.get_price_aggregator_price_in_egld_internal(&token_data);
+                let price = self.get_price_aggregator_price_in_egld_internal(token_data);
                 self.unreliable_pricing_method_event(token_id, &PricingMethod::PriceAggregator);
                 self.set_last_price(token_id, &price);
                 price
@@ -79,6 +138,65 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
         }
     }
 
+    /// Returns the first pricing method that can be used for a given token, starting with its pricing method and then
+    /// walking its chain of fallback pricing methods. If none can be used, the pricing method is returned, such that the
+    /// pricing fails with its own error.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_data` - The token data.
+    /// - `pricing_method` - The pricing method of the token.
+    ///
+    /// # Notes:
+    ///
+    /// - Failed calls cannot be caught, so a pricing method is skipped whenever its known failure conditions hold.
+    /// - The last failure is recorded for the pricing method and for each skipped fallback pricing method.
+    ///
+    fn get_usable_pricing_method(&self, token_data: &TokenData<Self::Api>, pricing_method: PricingMethod) -> PricingMethod {
+        let token_id = &token_data.identifier;
+        if self.pricing_fallbacks(token_id).is_empty() || self.can_use_pricing_method(token_data, pricing_method) {
+            return pricing_method;
+        }
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.pricing_method_last_failure(token_id, &pricing_method).set(timestamp);
+
+        for fallback_pricing_method in self.pricing_fallbacks(token_id).get().iter() {
+            if self.can_use_pricing_method(token_data, fallback_pricing_method) {
+                return fallback_pricing_method;
+            }
+            self.pricing_method_last_failure(token_id, &fallback_pricing_method).set(timestamp);
+        }
+
+        pricing_method
+    }
+
+    /// Checks whether a given pricing method can be used for a given token, i.e. none of its known failure conditions hold.
+    ///
+    /// # Arguments:
+    ///
+    /// - `token_data` - The token data.
+    /// - `pricing_method` - The pricing method to check.
+    ///
+    fn can_use_pricing_method(&self, token_data: &TokenData<Self::Api>, pricing_method: PricingMethod) -> bool {
+        let TokenData { identifier: token_id, xexchange_pair: opt_pair, .. } = token_data;
+        match pricing_method {
+            PricingMethod::None => false,
+            PricingMethod::Default => !self.is_egld_wrapper_paused() && !self.price_aggregator_address().is_empty(),
+            PricingMethod::Instantaneous | PricingMethod::Safe => match opt_pair {
+                None => false,
+                Some(pair) => {
+                    let is_quote_pair_paused = match self.get_quote_token(token_id) {
+                        None => false,
+                        Some(quote_token_id) => self.get_supported_token_data(&quote_token_id).xexchange_pair.map_or(true, |quote_pair| self.is_xexchange_paused(&quote_pair.address)),
+                    };
+                    !self.is_egld_wrapper_paused() && !self.is_xexchange_paused(&pair.address) && !is_quote_pair_paused && self.has_min_pool_reserves(token_data)
+                },
+            },
+            PricingMethod::PriceAggregator => !self.price_aggregator_address().is_empty(),
+        }
+    }
+
     /// Checks if the reporter price is within the first anchor price bounds.
     ///
     /// # Arguments:
@@ -106,8 +224,7 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
     }
 
     fn is_within_anchor_internal(&self, reporter_price: &BigUint, anchor_price: &BigUint, upper_bound_ratio: &BigUint, lower_bound_ratio: &BigUint) -> bool {
-        let wad = BigUint::from(WAD);
-        let anchor_ratio = anchor_price * &wad / reporter_price;
+        let anchor_ratio = self.wad_div(anchor_price, reporter_price);
         &anchor_ratio <= upper_bound_ratio && &anchor_ratio >= lower_bound_ratio
     }
 
@@ -121,6 +238,18 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
     fn get_default_price_in_egld_internal(&self, token_data: &TokenData<Self::Api>) -> BigUint {
         let TokenData { identifier: token_id, tolerances: opt_tolerances, .. } = token_data;
 
+        // pause the token pricing if its xExchange pool has been drained
+        if !self.has_min_pool_reserves(token_data) {
+            self.is_token_paused(token_id).set(true);
+            self.paused_by_anchor_breach(token_id).clear();
+            self.convergence_checks(token_id).clear();
+
+            self.pause_token_event(token_id);
+            self.log_incident(token_id, IncidentReason::LowPoolReserves, &BigUint::zero(), &BigUint::zero());
+
+            return self.last_price(token_id).get();
+        }
+
         let anchor_price = self.get_xexchange_safe_price_in_egld_internal(token_data, true);
         let reporter_price = self.get_price_aggregator_price_in_egld_internal(token_data);
 
@@ -133,15 +262,19 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
             require!(!self.has_unreliable_price(token_id).get(), ERROR_TOKEN_HAS_UNRELIABLE_PRICE);
             self.has_unreliable_price(token_id).set(true);
             self.first_anchor_surpassed_event(token_id, &reporter_price, &anchor_price);
+            self.log_incident(token_id, IncidentReason::FirstAnchorSurpassed, &reporter_price, &anchor_price);
             return self.last_price(token_id).get();
         }
 
         // pause the token pricing if the price is not within the first nor the last anchor
         self.is_token_paused(token_id).set(true);
+        self.paused_by_anchor_breach(token_id).set(true);
+        self.convergence_checks(token_id).clear();
 
         // emit events
         self.pause_token_event(token_id);
         self.last_anchor_surpassed_event(token_id, &reporter_price, &anchor_price);
+        self.log_incident(token_id, IncidentReason::LastAnchorSurpassed, &reporter_price, &anchor_price);
 
         // retrieve last valid price
         self.last_price(token_id).get()
@@ -178,14 +311,27 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
         self.require_xexchange_not_paused(&xexchange_pair.address);
 
         let (reserves0, reserves1) = self.get_xexchange_reserves(&xexchange_pair.address);
-        let price = if self.is_wrapped_egld(&xexchange_pair.token0) { reserves0 * WAD / reserves1 } else { reserves1 * WAD / reserves0 };
+
+        // a drained pool cannot be used for pricing
+        let token_reserves = if xexchange_pair.token0 == *token_id { &reserves0 } else { &reserves1 };
+        require!(token_reserves >= &self.get_min_pool_reserves(token_id), ERROR_INSUFFICIENT_POOL_RESERVES);
+
+        let mut price = if xexchange_pair.token0 != *token_id { self.wad_div(&reserves0, &reserves1) } else { self.wad_div(&reserves1, &reserves0) };
         require!(price > BigUint::zero(), ERROR_PRICE_IS_ZERO);
 
+        // if the token is not quoted against WEGLD, route the price through the quote token
+        if let Some(quote_token_id) = self.get_quote_token(token_id) {
+            let quote_token_data = self.get_supported_token_data(&quote_token_id);
+            let quote_price = self.get_xexchange_instantaneous_price_in_egld_internal(&quote_token_data);
+            price = self.wad_mul(&price, &quote_price);
+            require!(price > BigUint::zero(), ERROR_PRICE_IS_ZERO);
+        }
+
         self.xexchange_price_fetched_event(token_id, &price);
 
         // if the token is USH, we need to convert from fallback token's units to USH's units
         if is_ush {
-            return price * exp_token / WAD;
+            return self.wad_mul(&price, exp_token);
         }
 
         price
@@ -223,14 +369,29 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
             self.require_xexchange_not_paused(&xexchange_pair.address);
         }
 
+        // a drained pool cannot be used for pricing
+        if !self.min_pool_reserves(token_id).is_empty() {
+            let (reserves0, reserves1) = self.get_xexchange_reserves(&xexchange_pair.address);
+            let token_reserves = if xexchange_pair.token0 == *token_id { reserves0 } else { reserves1 };
+            require!(token_reserves >= self.min_pool_reserves(token_id).get(), ERROR_INSUFFICIENT_POOL_RESERVES);
+        }
+
         let input = EsdtTokenPayment::new(token_id.clone(), 0, WAD.into());
-        let price = self.update_and_get_xexchange_safe_price(&xexchange_pair.address, input);
+        let mut price = self.update_and_get_xexchange_safe_price(&xexchange_pair.address, input);
+
+        // if the token is not quoted against WEGLD, route the price through the quote token
+        if let Some(quote_token_id) = self.get_quote_token(token_id) {
+            let quote_token_data = self.get_supported_token_data(&quote_token_id);
+            let quote_price = self.get_xexchange_safe_price_in_egld_internal(&quote_token_data, xexchange_pause_allowed);
+            price = self.wad_mul(&price, &quote_price);
+            require!(price > BigUint::zero(), ERROR_PRICE_IS_ZERO);
+        }
 
         self.xexchange_safe_price_fetched_event(token_id, &price);
 
         // if the token is USH, we need to convert from fallback token's units to USH's units
         if is_ush {
-            return price * exp_token / WAD;
+            return self.wad_mul(&price, exp_token);
         }
 
         price
@@ -249,6 +410,20 @@ pub trait PriceModule: admin::AdminModule + events::EventsModule + proxies::Prox
         self.get_price_aggregator_price_in_egld_internal(&token_data)
     }
 
+    /// Returns the EGLD price in USD and in WAD units, as reported by the Price Aggregator.
+    ///
+    #[endpoint(getEgldPriceInUsd)]
+    fn get_egld_price_in_usd(&self) -> BigUint {
+        require!(!self.price_aggregator_address().is_empty(), ERROR_CANNOT_USE_PRICE_AGGREGATOR);
+
+        let usd = ManagedBuffer::from(USD_SYMBOL);
+        let egld = ManagedBuffer::from(EGLD_SYMBOL);
+        let (_, _, _, _, _, decimals) = self.get_price_aggregator_latest_price_feed(&egld, &usd);
+        let egld_in_usd = self.get_price_aggregator_latest_price(&egld, &usd);
+
+        self.mul_div(&egld_in_usd, &BigUint::from(WAD), &BigUint::from(10u64).pow(decimals as u32), Rounding::Down)
+    }
+
     fn get_price_aggregator_price_in_egld_internal(&self, token_data: &TokenData<Self::Api>) -> BigUint {
         let TokenData { identifier: token_id, unit_price, ticker, exp: exp_token, .. } = token_data;
 
@@ -45,6 +45,11 @@ pub trait StorageModule {
     #[storage_mapper("ush_token_id")]
     fn ush_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
+    /// Stores the Hatom governance token identifier.
+    #[view(getGovernanceTokenId)]
+    #[storage_mapper("governance_token_id")]
+    fn governance_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
     // Stores the USH fallback token identifier.
     #[view(getUshFallbackTokenId)]
     #[storage_mapper("ush_fallback_token_id")]
@@ -55,10 +60,9 @@ pub trait StorageModule {
     #[storage_mapper("xexchange_pricing_method")]
     fn xexchange_pricing_method(&self) -> SingleValueMapper<ExchangePricingMethod>;
 
-    /// Stores the guardian address.
-    #[view(getGuardian)]
+    /// Stores the legacy guardian address, which is moved to the pause guardian on upgrade.
     #[storage_mapper("guardian")]
-    fn guardian(&self) -> SingleValueMapper<ManagedAddress>;
+    fn legacy_guardian(&self) -> SingleValueMapper<ManagedAddress>;
 
     /// Stores the address of the Price Aggregator.
     #[view(getPriceAggregatorAddress)]
@@ -80,11 +84,39 @@ pub trait StorageModule {
     #[storage_mapper("supported_tokens")]
     fn supported_tokens(&self, token_id: &TokenIdentifier) -> SingleValueMapper<TokenData<Self::Api>>;
 
+    /// Stores the quote token of the xExchange pair of a native token, if it is not quoted against WEGLD.
+    #[view(getQuoteToken)]
+    #[storage_mapper("quote_token")]
+    fn quote_token(&self, token_id: &TokenIdentifier) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Stores the set of tokens used as quote tokens by other native tokens.
+    #[view(getQuoteTokens)]
+    #[storage_mapper("quote_tokens")]
+    fn quote_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    /// Stores the number of native tokens quoted against a given quote token.
+    #[storage_mapper("quote_token_uses")]
+    fn quote_token_uses(&self, quote_token_id: &TokenIdentifier) -> SingleValueMapper<usize>;
+
+    /// Stores the minimum reserve of a token in its xExchange pool required to price it using xExchange, in token units.
+    #[view(getMinPoolReserves)]
+    #[storage_mapper("min_pool_reserves")]
+    fn min_pool_reserves(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
     /// Stores the pricing method for each token.
     #[view(getPricingMethod)]
     #[storage_mapper("pricing_method")]
     fn pricing_method(&self, token_id: &TokenIdentifier) -> SingleValueMapper<PricingMethod>;
 
+    /// Stores the ordered chain of fallback pricing methods for each token, walked when the pricing method cannot be used.
+    #[view(getPricingFallbacks)]
+    #[storage_mapper("pricing_fallbacks")]
+    fn pricing_fallbacks(&self, token_id: &TokenIdentifier) -> SingleValueMapper<ManagedVec<PricingMethod>>;
+
+    /// Stores the timestamp of the last time each pricing method could not be used to price a token.
+    #[storage_mapper("pricing_method_last_failure")]
+    fn pricing_method_last_failure(&self, token_id: &TokenIdentifier, pricing_method: &PricingMethod) -> SingleValueMapper<u64>;
+
     /// Stores the last reported price for each token.
     #[view(getLastPrice)]
     #[storage_mapper("last_price")]
@@ -96,7 +128,35 @@ pub trait StorageModule {
     fn has_unreliable_price(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
 
     /// Stores whether the token pricing is paused.
-    #[view(isPaused)]
+    #[view(isTokenPaused)]
     #[storage_mapper("is_token_paused")]
     fn is_token_paused(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
+    /// Stores whether the token pricing has been paused by the `Default` pricing algorithm because of a last anchor breach.
+    #[view(isPausedByAnchorBreach)]
+    #[storage_mapper("paused_by_anchor_breach")]
+    fn paused_by_anchor_breach(&self, token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
+
+    /// Stores the number of consecutive convergence checks required to automatically unpause a token, if non-zero.
+    #[view(getAutoUnpauseChecks)]
+    #[storage_mapper("auto_unpause_checks")]
+    fn auto_unpause_checks(&self, token_id: &TokenIdentifier) -> SingleValueMapper<usize>;
+
+    /// Stores the current number of consecutive convergence checks passed by a paused token.
+    #[view(getConvergenceChecks)]
+    #[storage_mapper("convergence_checks")]
+    fn convergence_checks(&self, token_id: &TokenIdentifier) -> SingleValueMapper<usize>;
+
+    /// Stores the timestamp of the last convergence check for a token.
+    #[view(getLastConvergenceCheck)]
+    #[storage_mapper("last_convergence_check")]
+    fn last_convergence_check(&self, token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
+
+    /// Stores a bounded ring buffer of pricing incidents, such as token pauses and anchor breaches.
+    #[storage_mapper("incidents")]
+    fn incidents(&self) -> VecMapper<Incident<Self::Api>>;
+
+    /// Stores the position in the ring buffer of the next incident to be overwritten, once the buffer is full.
+    #[storage_mapper("next_incident_position")]
+    fn next_incident_position(&self) -> SingleValueMapper<usize>;
 }
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           40
+// Endpoints:                           62
 // Async Callback (empty):               1
-// Total number of exported functions:  43
+// Total number of exported functions:  65
 
 #![no_std]
 
@@ -24,23 +24,37 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPendingAdmin => get_pending_admin
         setPendingAdmin => set_pending_admin
         acceptAdmin => accept_admin
+        pause => pause
+        unpause => unpause
+        setPauseGuardian => set_pause_guardian
+        getPauseGuardian => get_pause_guardian_view
+        getPauseGuardianSource => pause_guardian_source
         isPriceOracle => is_price_oracle
-        setGuardian => set_guardian
+        getRecentIncidents => get_recent_incidents
+        isPaused => is_paused
         unpauseToken => unpause_token
         pauseToken => pause_token
+        setAutoUnpauseChecks => set_auto_unpause_checks
+        checkPriceConvergence => check_price_convergence
         supportPriceAggregator => support_price_aggregator
         updateRoundDuration => update_round_duration
         supportNativeToken => support_native_token
+        supportQuotedNativeToken => support_quoted_native_token
+        supportGovernanceToken => support_governance_token
         supportEgldLsToken => support_egld_ls_token
         supportTaoLsToken => support_tao_ls_token
         supportUshToken => support_ush_token
         setUshFallbackToken => set_ush_fallback_token
         setPricingMethod => set_pricing_method
+        setMinPoolReserves => set_min_pool_reserves
+        setPricingFallbacks => set_pricing_fallbacks
         setAnchorTolerances => set_anchor_tolerances
         getPrice => get_price_in_egld
+        getPricingStats => get_pricing_stats
         getXExchangeInstantaneousPriceInEgld => get_xexchange_instantaneous_price_in_egld
         getXExchangeSafePriceInEgld => get_xexchange_safe_price_in_egld
         getPriceAggregatorPriceInEgld => get_price_aggregator_price_in_egld
+        getEgldPriceInUsd => get_egld_price_in_usd
         getEgldWrapper => egld_wrapper
         getWegldId => wegld_id
         getLiquidStakingAddress => liquid_staking
@@ -49,17 +63,25 @@ multiversx_sc_wasm_adapter::endpoints! {
         getLsTokenId => ls_token_id
         getStaoTokenId => stao_token_id
         getUshTokenId => ush_token_id
+        getGovernanceTokenId => governance_token_id
         getUshFallbackTokenId => ush_fallback_token_id
         getXExchangePricingMethod => xexchange_pricing_method
-        getGuardian => guardian
         getPriceAggregatorAddress => price_aggregator_address
         getRoundDuration => round_duration
         getWhitelistedTokens => whitelisted_tokens
         getSupportedTokens => supported_tokens
+        getQuoteToken => quote_token
+        getQuoteTokens => quote_tokens
+        getMinPoolReserves => min_pool_reserves
         getPricingMethod => pricing_method
+        getPricingFallbacks => pricing_fallbacks
         getLastPrice => last_price
         hasUnreliablePrice => has_unreliable_price
-        isPaused => is_token_paused
+        isTokenPaused => is_token_paused
+        isPausedByAnchorBreach => paused_by_anchor_breach
+        getAutoUnpauseChecks => auto_unpause_checks
+        getConvergenceChecks => convergence_checks
+        getLastConvergenceCheck => last_convergence_check
     )
 }
 
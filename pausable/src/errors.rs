@@ -0,0 +1,3 @@
+pub const ERROR_ONLY_ADMIN_OR_GUARDIAN: &[u8] = b"only admin or guardian allowed";
+pub const ERROR_CONTRACT_PAUSED: &[u8] = b"contract is paused";
+pub const ERROR_PAUSE_GUARDIAN_FROM_SOURCE: &[u8] = b"pause guardian is read from another contract";
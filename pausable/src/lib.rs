@@ -0,0 +1,148 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod errors;
+
+use errors::*;
+
+/// Contract-wide pause switch and pause guardian shared by all Hatom contracts, such that operational tooling can handle
+/// every contract uniformly during incidents. The pause guardian can pause a contract, but only the admin can unpause it.
+///
+/// The pause guardian can also be read live from another contract using this module, e.g. from the Controller.
+///
+#[multiversx_sc::module]
+pub trait PausableModule: admin::AdminModule {
+    /// Pauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the pause guardian.
+    /// - Pausing an already paused contract has no effect.
+    ///
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.require_admin_or_guardian();
+        if !self.paused().get() {
+            self.paused().set(true);
+            self.pause_event(&self.blockchain().get_caller());
+        }
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Unpausing a contract that is not paused has no effect.
+    ///
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.require_admin();
+        if self.paused().get() {
+            self.paused().set(false);
+            self.unpause_event(&self.blockchain().get_caller());
+        }
+    }
+
+    /// Sets the pause guardian of the contract.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_pause_guardian` - The address of the new pause guardian.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Cannot be called if the pause guardian is read from another contract.
+    ///
+    #[endpoint(setPauseGuardian)]
+    fn set_pause_guardian(&self, new_pause_guardian: &ManagedAddress) {
+        self.require_admin();
+        require!(self.pause_guardian_source().is_empty(), ERROR_PAUSE_GUARDIAN_FROM_SOURCE);
+        let old_pause_guardian = self.get_pause_guardian();
+        self.pause_guardian().set(new_pause_guardian);
+        self.new_pause_guardian_event(&old_pause_guardian, new_pause_guardian);
+    }
+
+    /// Requires that the contract is not paused.
+    ///
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), ERROR_CONTRACT_PAUSED);
+    }
+
+    /// Requires that the caller is the admin or the pause guardian, if it is set.
+    ///
+    fn require_admin_or_guardian(&self) {
+        let caller = self.blockchain().get_caller();
+        let is_guardian = match self.get_pause_guardian() {
+            None => false,
+            Some(pause_guardian) => caller == pause_guardian,
+        };
+        require!(caller == self.get_admin() || is_guardian, ERROR_ONLY_ADMIN_OR_GUARDIAN);
+    }
+
+    /// Gets the address of the pause guardian, if one has been set. If the pause guardian is read from another contract, it
+    /// is read live from its storage.
+    ///
+    fn get_pause_guardian(&self) -> Option<ManagedAddress> {
+        let pause_guardian_mapper = if self.pause_guardian_source().is_empty() {
+            self.pause_guardian()
+        } else {
+            let source = self.pause_guardian_source().get();
+            SingleValueMapper::new_from_address(source, StorageKey::new(b"pause_guardian"))
+        };
+
+        if pause_guardian_mapper.is_empty() {
+            None
+        } else {
+            let pause_guardian = pause_guardian_mapper.get();
+            Some(pause_guardian)
+        }
+    }
+
+    /// Returns the address of the pause guardian, if one has been set.
+    ///
+    #[view(getPauseGuardian)]
+    fn get_pause_guardian_view(&self) -> OptionalValue<ManagedAddress> {
+        self.get_pause_guardian().into()
+    }
+
+    /// Stores whether the contract is paused.
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    /// Stores the pause guardian address.
+    #[storage_mapper("pause_guardian")]
+    fn pause_guardian(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the address of the contract from which the pause guardian is read, if any.
+    #[view(getPauseGuardianSource)]
+    #[storage_mapper("pause_guardian_source")]
+    fn pause_guardian_source(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Emitted when the contract is paused.
+    #[event("pause_event")]
+    fn pause_event(&self, #[indexed] caller: &ManagedAddress);
+
+    /// Emitted when the contract is unpaused.
+    #[event("unpause_event")]
+    fn unpause_event(&self, #[indexed] caller: &ManagedAddress);
+
+    /// Emitted when a new pause guardian is set.
+    #[event("new_pause_guardian_event")]
+    fn new_pause_guardian_event(&self, #[indexed] old: &Option<ManagedAddress>, #[indexed] new: &ManagedAddress);
+}
+
+/// Exposes whether the contract is paused as the `isPaused` view, such that every contract answers it the same way. Token
+/// pauses at the Oracle are exposed separately via `isTokenPaused`.
+///
+#[multiversx_sc::module]
+pub trait PausableViewModule: PausableModule {
+    /// Returns whether the contract is paused.
+    ///
+    #[view(isPaused)]
+    fn is_paused(&self) -> bool {
+        self.paused().get()
+    }
+}
@@ -0,0 +1,5 @@
+/// The WAD unit
+pub const WAD: u64 = 1_000_000_000_000_000_000;
+
+/// The minimum liquidation incentive (in wad)
+pub const MIN_LIQUIDATION_INCENTIVE: u64 = 1_010_000_000_000_000_000;
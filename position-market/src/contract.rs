@@ -0,0 +1,50 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod position_market_proxy;
+
+pub mod constants;
+pub mod errors;
+pub mod events;
+pub mod governance;
+pub mod market;
+pub mod proxies;
+pub mod seize;
+pub mod storage;
+
+use crate::errors::*;
+
+#[multiversx_sc::contract]
+pub trait PositionMarket: admin::AdminModule + events::EventsModule + governance::GovernanceModule + market::MarketModule + proxies::ProxyModule + seize::SeizeModule + storage::StorageModule {
+    /// Initializes the Position Market, a supply-only money market that accepts meta-ESDT positions (e.g. staked LP
+    /// positions) as collateral at the Controller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `controller` - The Controller smart contract address.
+    /// - `position_id` - The meta-ESDT collection identifier of the positions.
+    /// - `underlying_id` - The token identifier used for pricing, i.e. each unit of position is worth one unit of it.
+    /// - `liquidation_incentive` - The liquidation incentive in wad.
+    /// - `opt_admin` - An optional admin address for the contract.
+    ///
+    /// Notes:
+    ///
+    /// - If the admin address is not provided, the admin will be set as the deployer.
+    /// - The positions are held by the Controller, which accounts them per nonce.
+    ///
+    #[init]
+    fn init(&self, controller: ManagedAddress, position_id: TokenIdentifier, underlying_id: TokenIdentifier, liquidation_incentive: BigUint, opt_admin: OptionalValue<ManagedAddress>) {
+        require!(position_id.is_valid_esdt_identifier(), ERROR_INVALID_POSITION_ID);
+        require!(underlying_id.is_valid_esdt_identifier(), ERROR_INVALID_UNDERLYING_ID);
+
+        self.try_set_controller(&controller);
+        self.position_id().set_if_empty(position_id);
+        self.underlying_id().set_if_empty(underlying_id);
+        self.set_liquidation_incentive_internal(&liquidation_incentive);
+        self.try_set_admin(opt_admin);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
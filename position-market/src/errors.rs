@@ -0,0 +1,7 @@
+pub const ERROR_NON_VALID_CONTROLLER_SC: &[u8] = b"not a valid controller smart contract";
+pub const ERROR_INVALID_POSITION_ID: &[u8] = b"invalid position token identifier";
+pub const ERROR_INVALID_UNDERLYING_ID: &[u8] = b"invalid underlying token identifier";
+pub const ERROR_LIQUIDATION_INCENTIVE_TOO_HIGH: &[u8] = b"liquidity incentive too high";
+pub const ERROR_LIQUIDATION_INCENTIVE_TOO_LOW: &[u8] = b"liquidity incentive too low";
+pub const ERROR_ADDRESSES_MUST_DIFFER: &[u8] = b"addresses must be different";
+pub const ERROR_CONTROLLER_REJECTED_LIQUIDATION_SEIZE: &[u8] = b"liquidation seize has been rejected by Controller";
@@ -0,0 +1,16 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    /// Event emitted when the controller address is updated.
+    #[event("new_controller_event")]
+    fn new_controller_event(&self, #[indexed] old_address: &Option<ManagedAddress>, #[indexed] new_address: &ManagedAddress);
+
+    /// Event emitted when the liquidation incentive is updated.
+    #[event("new_liquidation_incentive_event")]
+    fn new_liquidation_incentive_event(&self, #[indexed] old_liquidation_incentive: &BigUint, #[indexed] new_liquidation_incentive: &BigUint);
+
+    /// Event emitted when positions are seized from a borrower in favor of a liquidator.
+    #[event("seize_positions_event")]
+    fn seize_positions_event(&self, #[indexed] borrow_market: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] liquidator: &ManagedAddress, #[indexed] positions: &ManagedVec<EsdtTokenPayment>);
+}
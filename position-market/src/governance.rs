@@ -0,0 +1,37 @@
+multiversx_sc::imports!();
+
+use super::{constants::*, errors::*, events, market, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait GovernanceModule: admin::AdminModule + events::EventsModule + market::MarketModule + proxies::ProxyModule + storage::StorageModule {
+    /// Sets a new liquidation incentive for liquidations.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_liquidation_incentive` - The new liquidation incentive in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The new liquidation incentive should not be less than the amount that would yield losses for liquidators.
+    /// - The new liquidation incentive should be less than `1 / max_ltv`. Otherwise, there won't be a Risky region.
+    ///
+    #[endpoint(setLiquidationIncentive)]
+    fn set_liquidation_incentive(&self, new_liquidation_incentive: &BigUint) {
+        self.require_admin();
+        self.set_liquidation_incentive_internal(new_liquidation_incentive);
+    }
+
+    fn set_liquidation_incentive_internal(&self, new_liquidation_incentive: &BigUint) {
+        let wad = BigUint::from(WAD);
+        let max_ltv = self.get_max_collateral_factor();
+
+        require!(new_liquidation_incentive >= &BigUint::from(MIN_LIQUIDATION_INCENTIVE), ERROR_LIQUIDATION_INCENTIVE_TOO_LOW);
+        require!(new_liquidation_incentive * &max_ltv < &wad * &wad, ERROR_LIQUIDATION_INCENTIVE_TOO_HIGH);
+
+        let old_liquidation_incentive = self.liquidation_incentive().get();
+        self.liquidation_incentive().set(new_liquidation_incentive);
+
+        self.new_liquidation_incentive_event(&old_liquidation_incentive, new_liquidation_incentive);
+    }
+}
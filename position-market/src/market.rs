@@ -0,0 +1,171 @@
+multiversx_sc::imports!();
+
+use super::{constants::*, errors::*, events, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait MarketModule: events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Checks whether the current smart contract is a money market, such that it can be supported by the Controller.
+    ///
+    #[view(isMoneyMarket)]
+    fn is_money_market(&self) -> bool {
+        true
+    }
+
+    /// Checks whether the current smart contract is a position market, i.e. a supply-only money market whose collateral
+    /// tokens are meta-ESDT positions.
+    ///
+    #[view(isPositionMarket)]
+    fn is_position_market(&self) -> bool {
+        true
+    }
+
+    /// Checks whether the specified smart contract address is a controller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `sc_address` - The address of the smart contract to check.
+    ///
+    fn is_controller_sc(&self, sc_address: &ManagedAddress) -> bool {
+        self.blockchain().is_smart_contract(sc_address) && self.is_controller(sc_address)
+    }
+
+    /// Returns the money market identifiers, i.e. the underlying identifier used for pricing and the meta-ESDT collection
+    /// identifier of the positions.
+    ///
+    #[view(getMoneyMarketIdentifiers)]
+    fn get_money_market_identifiers(&self) -> (EgldOrEsdtTokenIdentifier, TokenIdentifier) {
+        let underlying_id = EgldOrEsdtTokenIdentifier::esdt(self.underlying_id().get());
+        let position_id = self.position_id().get();
+        (underlying_id, position_id)
+    }
+
+    /// Returns the Controller address, if set.
+    ///
+    #[view(getController)]
+    fn get_controller(&self) -> Option<ManagedAddress> {
+        if self.controller().is_empty() {
+            None
+        } else {
+            Some(self.controller().get())
+        }
+    }
+
+    /// Returns the money market liquidity, which is always zero given that positions are held by the Controller.
+    ///
+    #[view(getLiquidity)]
+    fn get_liquidity(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    /// Returns the money market available liquidity, which is always zero given that positions are held by the Controller.
+    ///
+    #[view(getAvailableLiquidity)]
+    fn get_available_liquidity(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    /// Returns the total borrows, which are always zero given that positions cannot be borrowed.
+    ///
+    #[view(getTotalBorrows)]
+    fn get_total_borrows(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    /// Returns the base total borrows, which are always zero given that positions cannot be borrowed.
+    ///
+    #[view(getBaseTotalBorrows)]
+    fn get_base_total_borrows(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    /// Returns the borrow amount of a given account, which is always zero given that positions cannot be borrowed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    #[view(getStoredAccountBorrowAmount)]
+    fn get_stored_account_borrow_amount(&self, _account: &ManagedAddress) -> BigUint {
+        BigUint::zero()
+    }
+
+    /// Returns the base borrow amount of a given account, which is always zero given that positions cannot be borrowed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    #[view(getBaseAccountBorrowAmount)]
+    fn get_base_account_borrow_amount(&self, _account: &ManagedAddress) -> BigUint {
+        BigUint::zero()
+    }
+
+    /// Returns the exchange rate between the underlying and the positions, which is always one given that each unit of
+    /// position is worth one unit of underlying.
+    ///
+    #[view(getStoredExchangeRate)]
+    fn get_stored_exchange_rate(&self) -> BigUint {
+        BigUint::from(WAD)
+    }
+
+    /// Returns the close factor, which is never used given that positions cannot be borrowed.
+    ///
+    #[view(getCloseFactor)]
+    fn get_close_factor(&self) -> BigUint {
+        BigUint::from(WAD)
+    }
+
+    /// Returns the liquidation incentive used when positions are seized.
+    ///
+    #[view(getLiquidationIncentive)]
+    fn get_liquidation_incentive(&self) -> BigUint {
+        self.liquidation_incentive().get()
+    }
+
+    /// Returns the total reserves, which are always zero given that positions do not accrue interest.
+    ///
+    #[view(getTotalReserves)]
+    fn get_total_reserves(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    /// Returns the reserve factor. Positions do not accrue interest for suppliers, such that the market behaves as if the
+    /// reserve factor was 100%, which also allows the Controller to deprecate it.
+    ///
+    #[view(getReserveFactor)]
+    fn get_reserve_factor(&self) -> BigUint {
+        BigUint::from(WAD)
+    }
+
+    /// Accrues interest. Positions do not accrue interest, such that this is a no-op kept for compatibility with the
+    /// Controller and other money markets.
+    ///
+    #[endpoint(accrueInterest)]
+    fn accrue_interest(&self) {}
+
+    /// Returns the account snapshot, i.e. the borrow amount (always zero) and the exchange rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    #[endpoint(getReliableAccountSnapshot)]
+    fn get_reliable_account_snapshot(&self, _account: &ManagedAddress) -> (BigUint, BigUint) {
+        (BigUint::zero(), BigUint::from(WAD))
+    }
+
+    /// Sets the controller iff not already set.
+    ///
+    /// # Arguments:
+    ///
+    /// - `controller` - The address of the controller.
+    ///
+    fn try_set_controller(&self, controller: &ManagedAddress) {
+        if self.controller().is_empty() {
+            require!(self.is_controller_sc(controller), ERROR_NON_VALID_CONTROLLER_SC);
+            let old_controller = self.get_controller();
+            self.controller().set(controller);
+            self.new_controller_event(&old_controller, controller);
+        }
+    }
+}
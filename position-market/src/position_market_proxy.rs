@@ -0,0 +1,460 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct PositionMarketProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for PositionMarketProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = PositionMarketProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        PositionMarketProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct PositionMarketProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> PositionMarketProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Initializes the Position Market, a supply-only money market that accepts meta-ESDT positions (e.g. staked LP
+    /// positions) as collateral at the Controller.
+    ///
+    /// # Arguments:
+    ///
+    /// - `controller` - The Controller smart contract address.
+    /// - `position_id` - The meta-ESDT collection identifier of the positions.
+    /// - `underlying_id` - The token identifier used for pricing, i.e. each unit of position is worth one unit of it.
+    /// - `liquidation_incentive` - The liquidation incentive in wad.
+    /// - `opt_admin` - An optional admin address for the contract.
+    ///
+    /// Notes:
+    ///
+    /// - If the admin address is not provided, the admin will be set as the deployer.
+    /// - The positions are held by the Controller, which accounts them per nonce.
+    ///
+    pub fn init<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        controller: Arg0,
+        position_id: Arg1,
+        underlying_id: Arg2,
+        liquidation_incentive: Arg3,
+        opt_admin: Arg4,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&controller)
+            .argument(&position_id)
+            .argument(&underlying_id)
+            .argument(&liquidation_incentive)
+            .argument(&opt_admin)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PositionMarketProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PositionMarketProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Returns the current admin address.
+    ///
+    pub fn get_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdmin")
+            .original_result()
+    }
+
+    /// Returns the current pending admin address, if there is one.
+    ///
+    pub fn get_pending_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingAdmin")
+            .original_result()
+    }
+
+    /// Sets the pending admin address to the given address.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_pending_admin` - The new pending admin address.
+    ///
+    pub fn set_pending_admin<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pending_admin: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPendingAdmin")
+            .argument(&pending_admin)
+            .original_result()
+    }
+
+    /// Attempts to accept the pending admin, which must be set first using the `set_pending_admin` endpoint.
+    pub fn accept_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptAdmin")
+            .original_result()
+    }
+
+    /// Sets a new liquidation incentive for liquidations.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_liquidation_incentive` - The new liquidation incentive in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The new liquidation incentive should not be less than the amount that would yield losses for liquidators.
+    /// - The new liquidation incentive should be less than `1 / max_ltv`. Otherwise, there won't be a Risky region.
+    ///
+    pub fn set_liquidation_incentive<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_liquidation_incentive: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLiquidationIncentive")
+            .argument(&new_liquidation_incentive)
+            .original_result()
+    }
+
+    /// Checks whether the current smart contract is a money market, such that it can be supported by the Controller.
+    ///
+    pub fn is_money_market(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isMoneyMarket")
+            .original_result()
+    }
+
+    /// Checks whether the current smart contract is a position market, i.e. a supply-only money market whose collateral
+    /// tokens are meta-ESDT positions.
+    ///
+    pub fn is_position_market(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPositionMarket")
+            .original_result()
+    }
+
+    /// Returns the money market identifiers, i.e. the underlying identifier used for pricing and the meta-ESDT collection
+    /// identifier of the positions.
+    ///
+    pub fn get_money_market_identifiers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (EgldOrEsdtTokenIdentifier<Env::Api>, TokenIdentifier<Env::Api>)> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMoneyMarketIdentifiers")
+            .original_result()
+    }
+
+    /// Returns the Controller address, if set.
+    ///
+    pub fn get_controller(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getController")
+            .original_result()
+    }
+
+    /// Returns the money market liquidity, which is always zero given that positions are held by the Controller.
+    ///
+    pub fn get_liquidity(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidity")
+            .original_result()
+    }
+
+    /// Returns the money market available liquidity, which is always zero given that positions are held by the Controller.
+    ///
+    pub fn get_available_liquidity(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAvailableLiquidity")
+            .original_result()
+    }
+
+    /// Returns the total borrows, which are always zero given that positions cannot be borrowed.
+    ///
+    pub fn get_total_borrows(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalBorrows")
+            .original_result()
+    }
+
+    /// Returns the base total borrows, which are always zero given that positions cannot be borrowed.
+    ///
+    pub fn get_base_total_borrows(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBaseTotalBorrows")
+            .original_result()
+    }
+
+    /// Returns the borrow amount of a given account, which is always zero given that positions cannot be borrowed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    pub fn get_stored_account_borrow_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        _account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStoredAccountBorrowAmount")
+            .argument(&_account)
+            .original_result()
+    }
+
+    /// Returns the base borrow amount of a given account, which is always zero given that positions cannot be borrowed.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    pub fn get_base_account_borrow_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        _account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBaseAccountBorrowAmount")
+            .argument(&_account)
+            .original_result()
+    }
+
+    /// Returns the exchange rate between the underlying and the positions, which is always one given that each unit of
+    /// position is worth one unit of underlying.
+    ///
+    pub fn get_stored_exchange_rate(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStoredExchangeRate")
+            .original_result()
+    }
+
+    /// Returns the close factor, which is never used given that positions cannot be borrowed.
+    ///
+    pub fn get_close_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCloseFactor")
+            .original_result()
+    }
+
+    /// Returns the liquidation incentive used when positions are seized.
+    ///
+    pub fn get_liquidation_incentive(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationIncentive")
+            .original_result()
+    }
+
+    /// Returns the total reserves, which are always zero given that positions do not accrue interest.
+    ///
+    pub fn get_total_reserves(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalReserves")
+            .original_result()
+    }
+
+    /// Returns the reserve factor. Positions do not accrue interest for suppliers, such that the market behaves as if the
+    /// reserve factor was 100%, which also allows the Controller to deprecate it.
+    ///
+    pub fn get_reserve_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReserveFactor")
+            .original_result()
+    }
+
+    /// Accrues interest. Positions do not accrue interest, such that this is a no-op kept for compatibility with the
+    /// Controller and other money markets.
+    ///
+    pub fn accrue_interest(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("accrueInterest")
+            .original_result()
+    }
+
+    /// Returns the account snapshot, i.e. the borrow amount (always zero) and the exchange rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account address.
+    ///
+    pub fn get_reliable_account_snapshot<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        _account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (BigUint<Env::Api>, BigUint<Env::Api>)> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReliableAccountSnapshot")
+            .argument(&_account)
+            .original_result()
+    }
+
+    /// Seizes positions from a borrower in favor of a liquidator. Called by the borrow market at liquidations.
+    ///
+    /// # Arguments:
+    ///
+    /// - `liquidator` - The account retrieving the seized positions.
+    /// - `borrower` - The account having positions seized.
+    /// - `tokens_to_seize` - The amount of positions to seize.
+    ///
+    /// # Notes:
+    ///
+    /// - The Controller holds the positions and sends them straight to the liquidator.
+    /// - There is no protocol seize share, given that positions cannot be redeemed into reserves.
+    /// - Liquidations that require redeeming the seized collateral are not supported.
+    ///
+    pub fn seize<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        liquidator: Arg0,
+        borrower: Arg1,
+        tokens_to_seize: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("seize")
+            .argument(&liquidator)
+            .argument(&borrower)
+            .argument(&tokens_to_seize)
+            .original_result()
+    }
+
+    /// Stores the underlying token identifier, which is used to price a single unit of position.
+    pub fn underlying_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnderlyingId")
+            .original_result()
+    }
+
+    /// Stores the meta-ESDT collection identifier of the positions accepted as collateral.
+    pub fn position_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionId")
+            .original_result()
+    }
+}
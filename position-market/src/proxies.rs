@@ -0,0 +1,41 @@
+multiversx_sc::imports!();
+
+use super::storage;
+
+use controller::{governance::ProxyTrait as _, policies::ProxyTrait as _, shared::ProxyTrait as _};
+
+#[multiversx_sc::module]
+pub trait ProxyModule: storage::StorageModule {
+    // Controller calls
+
+    fn is_controller(&self, sc_address: &ManagedAddress) -> bool {
+        self.get_controller_proxy(Some(sc_address.clone())).is_controller().execute_on_dest_context()
+    }
+
+    fn get_max_collateral_factor(&self) -> BigUint {
+        self.get_controller_proxy(None).get_max_collateral_factor().execute_on_dest_context()
+    }
+
+    fn seize_allowed(&self, collateral_market: &ManagedAddress, borrow_market: &ManagedAddress, borrower: &ManagedAddress, liquidator: &ManagedAddress) -> bool {
+        self.get_controller_proxy(None).seize_allowed(collateral_market, borrow_market, borrower, liquidator).execute_on_dest_context()
+    }
+
+    fn seize_positions(&self, borrower: &ManagedAddress, liquidator: &ManagedAddress, tokens: &BigUint) -> ManagedVec<EsdtTokenPayment> {
+        self.get_controller_proxy(None).seize_positions(borrower, liquidator, tokens).execute_on_dest_context()
+    }
+
+    // Proxies
+
+    #[proxy]
+    fn controller_proxy(&self, sc_address: ManagedAddress) -> controller::ProxyTo<Self::Api>;
+
+    fn get_controller_proxy(&self, sc_address: Option<ManagedAddress>) -> controller::ProxyTo<Self::Api> {
+        match sc_address {
+            Some(controller_address) => self.controller_proxy(controller_address),
+            None => {
+                let controller_address = self.controller().get();
+                self.controller_proxy(controller_address)
+            },
+        }
+    }
+}
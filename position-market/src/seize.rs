@@ -0,0 +1,38 @@
+multiversx_sc::imports!();
+
+use super::{errors::*, events, market, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait SeizeModule: events::EventsModule + market::MarketModule + proxies::ProxyModule + storage::StorageModule {
+    /// Seizes positions from a borrower in favor of a liquidator. Called by the borrow market at liquidations.
+    ///
+    /// # Arguments:
+    ///
+    /// - `liquidator` - The account retrieving the seized positions.
+    /// - `borrower` - The account having positions seized.
+    /// - `tokens_to_seize` - The amount of positions to seize.
+    ///
+    /// # Notes:
+    ///
+    /// - The Controller holds the positions and sends them straight to the liquidator.
+    /// - There is no protocol seize share, given that positions cannot be redeemed into reserves.
+    /// - Liquidations that require redeeming the seized collateral are not supported.
+    ///
+    #[endpoint(seize)]
+    fn seize(&self, liquidator: &ManagedAddress, borrower: &ManagedAddress, tokens_to_seize: &BigUint) -> EsdtTokenPayment {
+        require!(borrower != liquidator, ERROR_ADDRESSES_MUST_DIFFER);
+
+        let borrow_market = self.blockchain().get_caller();
+        let collateral_market = self.blockchain().get_sc_address();
+
+        let seize_allowed = self.seize_allowed(&collateral_market, &borrow_market, borrower, liquidator);
+        require!(seize_allowed, ERROR_CONTROLLER_REJECTED_LIQUIDATION_SEIZE);
+
+        let positions = self.seize_positions(borrower, liquidator, tokens_to_seize);
+
+        self.seize_positions_event(&borrow_market, borrower, liquidator, &positions);
+
+        let position_id = self.position_id().get();
+        EsdtTokenPayment::new(position_id, 0, tokens_to_seize.clone())
+    }
+}
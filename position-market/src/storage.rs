@@ -0,0 +1,22 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait StorageModule {
+    /// Stores the Controller address.
+    #[storage_mapper("controller")]
+    fn controller(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the underlying token identifier, which is used to price a single unit of position.
+    #[view(getUnderlyingId)]
+    #[storage_mapper("underlying_id")]
+    fn underlying_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Stores the meta-ESDT collection identifier of the positions accepted as collateral.
+    #[view(getPositionId)]
+    #[storage_mapper("position_id")]
+    fn position_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Stores the liquidation incentive (in wad) paid to liquidators seizing positions.
+    #[storage_mapper("liquidation_incentive")]
+    fn liquidation_incentive(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,51 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           25
+// Async Callback (empty):               1
+// Total number of exported functions:  28
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    position_market
+    (
+        init => init
+        upgrade => upgrade
+        getAdmin => get_admin
+        getPendingAdmin => get_pending_admin
+        setPendingAdmin => set_pending_admin
+        acceptAdmin => accept_admin
+        setLiquidationIncentive => set_liquidation_incentive
+        isMoneyMarket => is_money_market
+        isPositionMarket => is_position_market
+        getMoneyMarketIdentifiers => get_money_market_identifiers
+        getController => get_controller
+        getLiquidity => get_liquidity
+        getAvailableLiquidity => get_available_liquidity
+        getTotalBorrows => get_total_borrows
+        getBaseTotalBorrows => get_base_total_borrows
+        getStoredAccountBorrowAmount => get_stored_account_borrow_amount
+        getBaseAccountBorrowAmount => get_base_account_borrow_amount
+        getStoredExchangeRate => get_stored_exchange_rate
+        getCloseFactor => get_close_factor
+        getLiquidationIncentive => get_liquidation_incentive
+        getTotalReserves => get_total_reserves
+        getReserveFactor => get_reserve_factor
+        accrueInterest => accrue_interest
+        getReliableAccountSnapshot => get_reliable_account_snapshot
+        seize => seize
+        getUnderlyingId => underlying_id
+        getPositionId => position_id
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
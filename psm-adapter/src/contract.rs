@@ -0,0 +1,50 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod psm_adapter_proxy;
+
+pub mod credit;
+pub mod errors;
+pub mod events;
+pub mod storage;
+
+use crate::errors::*;
+
+#[multiversx_sc::contract]
+pub trait PsmAdapter: admin::AdminModule + credit::CreditModule + events::EventsModule + storage::StorageModule {
+    /// Initializes the PSM Adapter smart contract, i.e. the contract through which a stablecoin money market lends idle
+    /// cash to the USH peg-stability module (PSM).
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The lender money market smart contract address.
+    /// - `underlying_id` - The underlying token identifier of the lender money market.
+    /// - `psm` - The USH peg-stability module smart contract address.
+    /// - `opt_admin` - An optional admin address for the contract.
+    ///
+    /// Notes:
+    ///
+    /// - If the admin address is not provided, the admin will be set as the deployer.
+    ///
+    #[init]
+    fn init(&self, money_market: ManagedAddress, underlying_id: EgldOrEsdtTokenIdentifier, psm: ManagedAddress, opt_admin: OptionalValue<ManagedAddress>) {
+        require!(self.blockchain().is_smart_contract(&money_market), ERROR_INVALID_MONEY_MARKET_SC);
+        require!(self.blockchain().is_smart_contract(&psm), ERROR_INVALID_PSM_SC);
+        require!(underlying_id.is_valid(), ERROR_INVALID_UNDERLYING_ID);
+
+        self.money_market().set_if_empty(&money_market);
+        self.underlying_id().set_if_empty(&underlying_id);
+        self.psm().set_if_empty(&psm);
+        self.try_set_admin(opt_admin);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Returns true to indicate that this contract is a PSM adapter.
+    #[view(isPsmAdapter)]
+    fn is_psm_adapter(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,157 @@
+multiversx_sc::imports!();
+
+use super::{errors::*, events, storage};
+
+#[multiversx_sc::module]
+pub trait CreditModule: admin::AdminModule + events::EventsModule + storage::StorageModule {
+    /// Receives underlying lent by the money market, which becomes available to be drawn by the PSM.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the money market.
+    /// - Must be paid with the underlying of the money market.
+    ///
+    #[payable("*")]
+    #[endpoint(lend)]
+    fn lend(&self) {
+        let money_market = self.require_money_market();
+        let (token_id, amount) = self.call_value().egld_or_single_fungible_esdt();
+
+        require!(token_id == self.underlying_id().get(), ERROR_INVALID_PAYMENT);
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        self.total_lent().update(|lent| *lent += &amount);
+
+        self.lend_event(&money_market, &amount);
+    }
+
+    /// Repays an amount of underlying to the money market. The money market decides how the repaid amount is split between
+    /// interest and principal.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to repay.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the money market.
+    /// - Only the underlying held by the adapter can be repaid, i.e. the PSM must deposit back any drawn amount first.
+    ///
+    #[endpoint(repay)]
+    fn repay(&self, amount: BigUint) {
+        let money_market = self.require_money_market();
+
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(amount <= self.get_available(), ERROR_INSUFFICIENT_BALANCE);
+
+        self.total_repaid().update(|repaid| *repaid += &amount);
+
+        let underlying_id = self.underlying_id().get();
+        self.send().direct(&money_market, &underlying_id, 0, &amount);
+
+        self.repay_event(&money_market, &amount);
+    }
+
+    /// Writes off an amount drawn by the PSM that the money market will never recall, i.e. the PSM is no longer expected to
+    /// deposit it back.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The drawn amount to write off.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the money market.
+    /// - Cannot exceed the amount currently drawn by the PSM.
+    ///
+    #[endpoint(writeOff)]
+    fn write_off(&self, amount: BigUint) {
+        let money_market = self.require_money_market();
+
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let old_drawn = self.drawn().get();
+        require!(amount <= old_drawn, ERROR_AMOUNT_EXCEEDS_DRAWN);
+
+        let drawn = old_drawn - &amount;
+        self.drawn().set(&drawn);
+        self.total_written_off().update(|written_off| *written_off += &amount);
+
+        self.write_off_event(&money_market, &amount, &drawn);
+    }
+
+    /// Draws an amount of underlying from the adapter into the PSM.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to draw.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the PSM.
+    ///
+    #[endpoint(draw)]
+    fn draw(&self, amount: BigUint) {
+        let psm = self.require_psm();
+
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(amount <= self.get_available(), ERROR_INSUFFICIENT_BALANCE);
+
+        let drawn = self.drawn().get() + &amount;
+        self.drawn().set(&drawn);
+
+        let underlying_id = self.underlying_id().get();
+        self.send().direct(&psm, &underlying_id, 0, &amount);
+
+        self.draw_event(&psm, &amount, &drawn);
+    }
+
+    /// Deposits underlying back into the adapter. Any amount on top of the drawn amount is considered interest paid by the
+    /// PSM and becomes available to be repaid to the money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the PSM.
+    /// - Must be paid with the underlying of the money market.
+    ///
+    #[payable("*")]
+    #[endpoint(deposit)]
+    fn deposit(&self) {
+        let psm = self.require_psm();
+        let (token_id, amount) = self.call_value().egld_or_single_fungible_esdt();
+
+        require!(token_id == self.underlying_id().get(), ERROR_INVALID_PAYMENT);
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let old_drawn = self.drawn().get();
+        let drawn = if amount >= old_drawn { BigUint::zero() } else { old_drawn - &amount };
+        self.drawn().set(&drawn);
+
+        self.deposit_event(&psm, &amount, &drawn);
+    }
+
+    /// Returns the amount of underlying held by the adapter, i.e. the amount that can be drawn by the PSM or repaid to the
+    /// money market.
+    ///
+    #[view(getAvailable)]
+    fn get_available(&self) -> BigUint {
+        let underlying_id = self.underlying_id().get();
+        self.blockchain().get_sc_balance(&underlying_id, 0)
+    }
+
+    /// Requires that the caller is the money market and returns its address.
+    ///
+    fn require_money_market(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.money_market().get(), ERROR_ONLY_MONEY_MARKET);
+        caller
+    }
+
+    /// Requires that the caller is the PSM and returns its address.
+    ///
+    fn require_psm(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.psm().get(), ERROR_ONLY_PSM);
+        caller
+    }
+}
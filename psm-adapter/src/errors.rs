@@ -0,0 +1,9 @@
+pub const ERROR_INVALID_MONEY_MARKET_SC: &[u8] = b"invalid money market smart contract";
+pub const ERROR_INVALID_PSM_SC: &[u8] = b"invalid PSM smart contract";
+pub const ERROR_INVALID_UNDERLYING_ID: &[u8] = b"invalid underlying token identifier";
+pub const ERROR_INVALID_PAYMENT: &[u8] = b"invalid payment";
+pub const ERROR_ONLY_MONEY_MARKET: &[u8] = b"only the money market can call this endpoint";
+pub const ERROR_ONLY_PSM: &[u8] = b"only the PSM can call this endpoint";
+pub const ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO: &[u8] = b"amount must be greater than zero";
+pub const ERROR_INSUFFICIENT_BALANCE: &[u8] = b"insufficient balance";
+pub const ERROR_AMOUNT_EXCEEDS_DRAWN: &[u8] = b"amount exceeds the drawn amount";
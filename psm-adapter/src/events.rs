@@ -0,0 +1,24 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    /// Event emitted when the money market lends underlying to the adapter.
+    #[event("lend_event")]
+    fn lend_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] amount: &BigUint);
+
+    /// Event emitted when underlying is repaid to the money market.
+    #[event("repay_event")]
+    fn repay_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] amount: &BigUint);
+
+    /// Event emitted when the money market writes off an amount drawn by the PSM.
+    #[event("write_off_event")]
+    fn write_off_event(&self, #[indexed] money_market: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] drawn: &BigUint);
+
+    /// Event emitted when the PSM draws underlying from the adapter.
+    #[event("draw_event")]
+    fn draw_event(&self, #[indexed] psm: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] drawn: &BigUint);
+
+    /// Event emitted when the PSM deposits underlying back into the adapter.
+    #[event("deposit_event")]
+    fn deposit_event(&self, #[indexed] psm: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] drawn: &BigUint);
+}
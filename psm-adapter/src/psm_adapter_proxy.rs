@@ -0,0 +1,357 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct PsmAdapterProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for PsmAdapterProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = PsmAdapterProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        PsmAdapterProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct PsmAdapterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> PsmAdapterProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Initializes the PSM Adapter smart contract, i.e. the contract through which a stablecoin money market lends idle
+    /// cash to the USH peg-stability module (PSM).
+    ///
+    /// # Arguments:
+    ///
+    /// - `money_market` - The lender money market smart contract address.
+    /// - `underlying_id` - The underlying token identifier of the lender money market.
+    /// - `psm` - The USH peg-stability module smart contract address.
+    /// - `opt_admin` - An optional admin address for the contract.
+    ///
+    /// Notes:
+    ///
+    /// - If the admin address is not provided, the admin will be set as the deployer.
+    ///
+    pub fn init<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        money_market: Arg0,
+        underlying_id: Arg1,
+        psm: Arg2,
+        opt_admin: Arg3,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&money_market)
+            .argument(&underlying_id)
+            .argument(&psm)
+            .argument(&opt_admin)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PsmAdapterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PsmAdapterProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Returns true to indicate that this contract is a PSM adapter.
+    pub fn is_psm_adapter(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPsmAdapter")
+            .original_result()
+    }
+
+    /// Returns the current admin address.
+    ///
+    pub fn get_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdmin")
+            .original_result()
+    }
+
+    /// Returns the current pending admin address, if there is one.
+    ///
+    pub fn get_pending_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingAdmin")
+            .original_result()
+    }
+
+    /// Sets the pending admin address to the given address.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_pending_admin` - The new pending admin address.
+    ///
+    pub fn set_pending_admin<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pending_admin: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPendingAdmin")
+            .argument(&pending_admin)
+            .original_result()
+    }
+
+    /// Attempts to accept the pending admin, which must be set first using the `set_pending_admin` endpoint.
+    pub fn accept_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptAdmin")
+            .original_result()
+    }
+
+    /// Receives underlying lent by the money market, which becomes available to be drawn by the PSM.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the money market.
+    /// - Must be paid with the underlying of the money market.
+    ///
+    pub fn lend(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("lend")
+            .original_result()
+    }
+
+    /// Repays an amount of underlying to the money market. The money market decides how the repaid amount is split between
+    /// interest and principal.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to repay.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the money market.
+    /// - Only the underlying held by the adapter can be repaid, i.e. the PSM must deposit back any drawn amount first.
+    ///
+    pub fn repay<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("repay")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Writes off an amount drawn by the PSM that the money market will never recall, i.e. the PSM is no longer expected to
+    /// deposit it back.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The drawn amount to write off.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the money market.
+    /// - Cannot exceed the amount currently drawn by the PSM.
+    ///
+    pub fn write_off<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("writeOff")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Draws an amount of underlying from the adapter into the PSM.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of underlying to draw.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the PSM.
+    ///
+    pub fn draw<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("draw")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Deposits underlying back into the adapter. Any amount on top of the drawn amount is considered interest paid by the
+    /// PSM and becomes available to be repaid to the money market.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the PSM.
+    /// - Must be paid with the underlying of the money market.
+    ///
+    pub fn deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit")
+            .original_result()
+    }
+
+    /// Returns the amount of underlying held by the adapter, i.e. the amount that can be drawn by the PSM or repaid to the
+    /// money market.
+    ///
+    pub fn get_available(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAvailable")
+            .original_result()
+    }
+
+    /// Stores the lender money market address.
+    pub fn money_market(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMoneyMarket")
+            .original_result()
+    }
+
+    /// Stores the underlying token identifier of the lender money market.
+    pub fn underlying_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnderlyingId")
+            .original_result()
+    }
+
+    /// Stores the USH peg-stability module address.
+    pub fn psm(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPsm")
+            .original_result()
+    }
+
+    /// Stores the amount of underlying currently drawn by the PSM.
+    pub fn drawn(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDrawn")
+            .original_result()
+    }
+
+    /// Stores the total amount of underlying lent by the money market so far.
+    pub fn total_lent(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalLent")
+            .original_result()
+    }
+
+    /// Stores the total amount of underlying repaid to the money market so far.
+    pub fn total_repaid(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalRepaid")
+            .original_result()
+    }
+
+    /// Stores the total amount of underlying drawn by the PSM and written off by the money market so far.
+    pub fn total_written_off(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalWrittenOff")
+            .original_result()
+    }
+}
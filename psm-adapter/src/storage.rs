@@ -0,0 +1,39 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait StorageModule {
+    /// Stores the lender money market address.
+    #[view(getMoneyMarket)]
+    #[storage_mapper("money_market")]
+    fn money_market(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the underlying token identifier of the lender money market.
+    #[view(getUnderlyingId)]
+    #[storage_mapper("underlying_id")]
+    fn underlying_id(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    /// Stores the USH peg-stability module address.
+    #[view(getPsm)]
+    #[storage_mapper("psm")]
+    fn psm(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the amount of underlying currently drawn by the PSM.
+    #[view(getDrawn)]
+    #[storage_mapper("drawn")]
+    fn drawn(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the total amount of underlying lent by the money market so far.
+    #[view(getTotalLent)]
+    #[storage_mapper("total_lent")]
+    fn total_lent(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the total amount of underlying repaid to the money market so far.
+    #[view(getTotalRepaid)]
+    #[storage_mapper("total_repaid")]
+    fn total_repaid(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the total amount of underlying drawn by the PSM and written off by the money market so far.
+    #[view(getTotalWrittenOff)]
+    #[storage_mapper("total_written_off")]
+    fn total_written_off(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,44 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           18
+// Async Callback (empty):               1
+// Total number of exported functions:  21
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    psm_adapter
+    (
+        init => init
+        upgrade => upgrade
+        isPsmAdapter => is_psm_adapter
+        getAdmin => get_admin
+        getPendingAdmin => get_pending_admin
+        setPendingAdmin => set_pending_admin
+        acceptAdmin => accept_admin
+        lend => lend
+        repay => repay
+        writeOff => write_off
+        draw => draw
+        deposit => deposit
+        getAvailable => get_available
+        getMoneyMarket => money_market
+        getUnderlyingId => underlying_id
+        getPsm => psm
+        getDrawn => drawn
+        getTotalLent => total_lent
+        getTotalRepaid => total_repaid
+        getTotalWrittenOff => total_written_off
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,91 @@
+multiversx_sc::imports!();
+
+use super::{constants::*, errors::*, events, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait CommonsModule: admin::AdminModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// A utility function to highlight that this smart contract is a Peg Stability Module.
+    ///
+    #[view(isPsm)]
+    fn is_psm(&self) -> bool {
+        true
+    }
+
+    /// Returns whether the whitelisted stable is pegged according to the Price Oracle. The oracle's USH fallback token
+    /// machinery is reused: the stable must be the oracle's USH fallback token and neither its pricing nor USH pricing can
+    /// be paused, which happens whenever their prices deviate from their anchors.
+    ///
+    #[view(isPegged)]
+    fn is_pegged(&self) -> bool {
+        let stable_id = self.stable_id().get();
+        match self.get_oracle_ush_fallback_token_id() {
+            None => false,
+            Some(fallback_token_id) => fallback_token_id == stable_id && !self.is_oracle_token_paused(&stable_id) && !self.is_oracle_token_paused(&self.ush_id().get()),
+        }
+    }
+
+    /// Returns the address of the PSM adapter if set.
+    ///
+    #[view(getPsmAdapter)]
+    fn get_psm_adapter(&self) -> Option<ManagedAddress> {
+        if self.psm_adapter().is_empty() {
+            None
+        } else {
+            let psm_adapter = self.psm_adapter().get();
+            Some(psm_adapter)
+        }
+    }
+
+    /// Returns the factor that translates an amount of stable into an amount of USH.
+    ///
+    fn get_stable_scale(&self) -> BigUint {
+        let stable_decimals = self.stable_decimals().get();
+        BigUint::from(10u64).pow((USH_DECIMALS - stable_decimals) as u32)
+    }
+
+    /// Requires that swaps are enabled.
+    ///
+    fn require_active(&self) {
+        require!(self.active().get(), ERROR_PSM_INACTIVE);
+    }
+
+    /// Requires that the whitelisted stable is pegged.
+    ///
+    fn require_pegged(&self) {
+        require!(self.is_pegged(), ERROR_PSM_HALTED);
+    }
+
+    /// Sets the USH minter and the USH token identifier.
+    ///
+    /// # Arguments:
+    ///
+    /// - `ush_minter` - The USH minter smart contract address.
+    ///
+    fn set_ush_minter(&self, ush_minter: &ManagedAddress) {
+        require!(self.blockchain().is_smart_contract(ush_minter) && self.is_ush_minter(ush_minter), ERROR_INVALID_USH_MINTER_SC);
+
+        let ush_id = self.get_ush_id(ush_minter);
+
+        self.ush_minter().set(ush_minter);
+        self.ush_id().set(&ush_id);
+
+        self.set_ush_minter_event(ush_minter, &ush_id);
+    }
+
+    /// Sets the whitelisted stable.
+    ///
+    /// # Arguments:
+    ///
+    /// - `stable_id` - The stable token identifier.
+    /// - `stable_decimals` - The stable decimals.
+    ///
+    fn set_stable(&self, stable_id: &TokenIdentifier, stable_decimals: u8) {
+        require!(stable_id.is_valid_esdt_identifier(), ERROR_INVALID_STABLE_ID);
+        require!(stable_decimals <= USH_DECIMALS, ERROR_INVALID_STABLE_DECIMALS);
+
+        self.stable_id().set(stable_id);
+        self.stable_decimals().set(stable_decimals);
+
+        self.set_stable_event(stable_id, stable_decimals);
+    }
+}
@@ -0,0 +1,8 @@
+/// The WAD unit
+pub const WAD: u64 = 1_000_000_000_000_000_000;
+
+/// The USH decimals
+pub const USH_DECIMALS: u8 = 18;
+
+/// The maximum swap fee allowed (1%)
+pub const MAX_SWAP_FEE: u64 = 10_000_000_000_000_000;
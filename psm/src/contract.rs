@@ -0,0 +1,48 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod psm_proxy;
+
+pub mod commons;
+pub mod constants;
+pub mod errors;
+pub mod events;
+pub mod governance;
+pub mod proxies;
+pub mod storage;
+pub mod swap;
+
+use crate::errors::*;
+
+#[multiversx_sc::contract]
+pub trait PegStabilityModule: admin::AdminModule + commons::CommonsModule + events::EventsModule + governance::GovernanceModule + proxies::ProxyModule + storage::StorageModule + swap::SwapModule {
+    /// Initializes the Peg Stability Module (PSM) smart contract, which allows 1:1 swaps between USH and a whitelisted
+    /// stable.
+    ///
+    /// # Arguments:
+    ///
+    /// - `ush_minter` - The USH Minter smart contract address.
+    /// - `oracle` - The Price Oracle smart contract address.
+    /// - `stable_id` - The whitelisted stable token identifier, currently USDC.
+    /// - `stable_decimals` - The whitelisted stable decimals.
+    /// - `opt_admin` - An optional admin address for the contract.
+    ///
+    /// Notes:
+    ///
+    /// - If the admin address is not provided, the admin will be set as the deployer.
+    /// - Swaps are disabled until the PSM is made a USH facilitator and activated by the admin.
+    ///
+    #[init]
+    fn init(&self, ush_minter: ManagedAddress, oracle: ManagedAddress, stable_id: TokenIdentifier, stable_decimals: u8, opt_admin: OptionalValue<ManagedAddress>) {
+        require!(self.blockchain().is_smart_contract(&oracle), ERROR_INVALID_ORACLE_SC);
+
+        self.set_ush_minter(&ush_minter);
+        self.set_stable(&stable_id, stable_decimals);
+        self.oracle().set(&oracle);
+        self.try_set_admin(opt_admin);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,18 @@
+pub const ERROR_INVALID_USH_MINTER_SC: &[u8] = b"invalid USH minter smart contract";
+pub const ERROR_INVALID_ORACLE_SC: &[u8] = b"invalid oracle smart contract";
+pub const ERROR_INVALID_STABLE_ID: &[u8] = b"invalid stable token identifier";
+pub const ERROR_INVALID_STABLE_DECIMALS: &[u8] = b"invalid stable decimals";
+pub const ERROR_INVALID_PAYMENT: &[u8] = b"invalid payment";
+pub const ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO: &[u8] = b"amount must be greater than zero";
+pub const ERROR_NOT_FACILITATOR: &[u8] = b"not a facilitator";
+pub const ERROR_PSM_INACTIVE: &[u8] = b"PSM is inactive";
+pub const ERROR_PSM_HALTED: &[u8] = b"PSM is halted, stable is not pegged";
+pub const ERROR_SWAP_FEE_TOO_HIGH: &[u8] = b"swap fee too high";
+pub const ERROR_MINT_CAP_EXCEEDED: &[u8] = b"PSM mint cap exceeded";
+pub const ERROR_INSUFFICIENT_STABLE_RESERVES: &[u8] = b"insufficient stable reserves";
+pub const ERROR_NOT_ENOUGH_USH_OUT: &[u8] = b"not enough USH out";
+pub const ERROR_NOT_ENOUGH_STABLE_OUT: &[u8] = b"not enough stable out";
+pub const ERROR_UNDEFINED_PSM_ADAPTER: &[u8] = b"undefined PSM adapter";
+pub const ERROR_INVALID_PSM_ADAPTER: &[u8] = b"invalid PSM adapter";
+pub const ERROR_PSM_ADAPTER_HAS_OUTSTANDING_CREDIT: &[u8] = b"PSM adapter still has outstanding credit";
+pub const ERROR_INSUFFICIENT_REVENUE: &[u8] = b"insufficient revenue";
@@ -0,0 +1,48 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    /// Event emitted when the USH minter is set.
+    #[event("set_ush_minter_event")]
+    fn set_ush_minter_event(&self, #[indexed] ush_minter: &ManagedAddress, #[indexed] ush_id: &TokenIdentifier);
+
+    /// Event emitted when the whitelisted stable is set.
+    #[event("set_stable_event")]
+    fn set_stable_event(&self, #[indexed] stable_id: &TokenIdentifier, #[indexed] stable_decimals: u8);
+
+    /// Event emitted when swaps are enabled or disabled.
+    #[event("set_active_event")]
+    fn set_active_event(&self, #[indexed] active: bool);
+
+    /// Event emitted when the swap fees are updated.
+    #[event("set_swap_fees_event")]
+    fn set_swap_fees_event(&self, #[indexed] fee_in: &BigUint, #[indexed] fee_out: &BigUint);
+
+    /// Event emitted when the mint cap is updated.
+    #[event("set_mint_cap_event")]
+    fn set_mint_cap_event(&self, #[indexed] old_mint_cap: &BigUint, #[indexed] new_mint_cap: &BigUint);
+
+    /// Event emitted when stable is swapped for USH.
+    #[event("swap_stable_for_ush_event")]
+    fn swap_stable_for_ush_event(&self, #[indexed] account: &ManagedAddress, #[indexed] stable_in: &BigUint, #[indexed] ush_out: &BigUint, #[indexed] fee: &BigUint);
+
+    /// Event emitted when USH is swapped for stable.
+    #[event("swap_ush_for_stable_event")]
+    fn swap_ush_for_stable_event(&self, #[indexed] account: &ManagedAddress, #[indexed] ush_in: &BigUint, #[indexed] stable_out: &BigUint, #[indexed] fee: &BigUint);
+
+    /// Event emitted when the collected fees are withdrawn.
+    #[event("withdraw_revenue_event")]
+    fn withdraw_revenue_event(&self, #[indexed] to: &ManagedAddress, #[indexed] stable_revenue: &BigUint, #[indexed] ush_revenue: &BigUint);
+
+    /// Event emitted when the PSM adapter is updated.
+    #[event("new_psm_adapter_event")]
+    fn new_psm_adapter_event(&self, #[indexed] old_address: &Option<ManagedAddress>, #[indexed] new_address: &ManagedAddress);
+
+    /// Event emitted when stable is drawn from the PSM adapter.
+    #[event("draw_credit_event")]
+    fn draw_credit_event(&self, #[indexed] psm_adapter: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] credit_drawn: &BigUint);
+
+    /// Event emitted when stable is repaid to the PSM adapter.
+    #[event("repay_credit_event")]
+    fn repay_credit_event(&self, #[indexed] psm_adapter: &ManagedAddress, #[indexed] principal: &BigUint, #[indexed] interest: &BigUint, #[indexed] credit_drawn: &BigUint);
+}
@@ -0,0 +1,198 @@
+multiversx_sc::imports!();
+
+use super::{commons, constants::*, errors::*, events, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Enables or disables swaps.
+    ///
+    /// # Arguments:
+    ///
+    /// - `active` - Whether swaps are enabled.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Swaps can only be enabled if the PSM is a USH facilitator at the USH minter.
+    ///
+    #[endpoint(setActive)]
+    fn set_active(&self, active: bool) {
+        self.require_admin();
+
+        if active {
+            let sc_address = self.blockchain().get_sc_address();
+            require!(self.is_facilitator(&sc_address), ERROR_NOT_FACILITATOR);
+        }
+
+        self.active().set(active);
+        self.set_active_event(active);
+    }
+
+    /// Sets the swap fees.
+    ///
+    /// # Arguments:
+    ///
+    /// - `fee_in` - The fee charged on stable to USH swaps in wad.
+    /// - `fee_out` - The fee charged on USH to stable swaps in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Fees cannot exceed `MAX_SWAP_FEE`.
+    ///
+    #[endpoint(setSwapFees)]
+    fn set_swap_fees(&self, fee_in: BigUint, fee_out: BigUint) {
+        self.require_admin();
+
+        let max_swap_fee = BigUint::from(MAX_SWAP_FEE);
+        require!(fee_in <= max_swap_fee && fee_out <= max_swap_fee, ERROR_SWAP_FEE_TOO_HIGH);
+
+        self.swap_fee_in().set(&fee_in);
+        self.swap_fee_out().set(&fee_out);
+
+        self.set_swap_fees_event(&fee_in, &fee_out);
+    }
+
+    /// Sets the maximum amount of USH that can be outstanding after being minted by the PSM.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_mint_cap` - The new mint cap.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Lowering the cap below the outstanding USH only prevents new stable to USH swaps.
+    ///
+    #[endpoint(setMintCap)]
+    fn set_mint_cap(&self, new_mint_cap: BigUint) {
+        self.require_admin();
+
+        let old_mint_cap = self.mint_cap().get();
+        self.mint_cap().set(&new_mint_cap);
+
+        self.set_mint_cap_event(&old_mint_cap, &new_mint_cap);
+    }
+
+    /// Withdraws the fees collected by the PSM.
+    ///
+    /// # Arguments:
+    ///
+    /// - `to` - The address that receives the fees.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(withdrawRevenue)]
+    fn withdraw_revenue(&self, to: ManagedAddress) {
+        self.require_admin();
+
+        let stable_revenue = self.stable_revenue().take();
+        let ush_revenue = self.ush_revenue().take();
+
+        if stable_revenue > BigUint::zero() {
+            self.send().direct_esdt(&to, &self.stable_id().get(), 0, &stable_revenue);
+        }
+
+        if ush_revenue > BigUint::zero() {
+            self.send().direct_esdt(&to, &self.ush_id().get(), 0, &ush_revenue);
+        }
+
+        self.withdraw_revenue_event(&to, &stable_revenue, &ush_revenue);
+    }
+
+    /// Sets the PSM adapter, i.e. the contract through which stable can be borrowed from a money market credit line.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_psm_adapter` - The PSM adapter smart contract address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The PSM adapter must lend the whitelisted stable.
+    /// - Changing the PSM adapter requires all drawn credit to be repaid first.
+    ///
+    #[endpoint(setPsmAdapter)]
+    fn set_psm_adapter(&self, new_psm_adapter: ManagedAddress) {
+        self.require_admin();
+
+        require!(self.is_psm_adapter(&new_psm_adapter), ERROR_INVALID_PSM_ADAPTER);
+        require!(self.get_psm_adapter_underlying_id(&new_psm_adapter) == EgldOrEsdtTokenIdentifier::esdt(self.stable_id().get()), ERROR_INVALID_PSM_ADAPTER);
+
+        let old_psm_adapter = self.get_psm_adapter();
+        if let Some(ref old_psm_adapter) = old_psm_adapter {
+            require!(*old_psm_adapter == new_psm_adapter || self.credit_drawn().get() == BigUint::zero(), ERROR_PSM_ADAPTER_HAS_OUTSTANDING_CREDIT);
+        }
+
+        self.psm_adapter().set(&new_psm_adapter);
+
+        self.new_psm_adapter_event(&old_psm_adapter, &new_psm_adapter);
+    }
+
+    /// Draws stable from the PSM adapter into the stable reserves, increasing the capacity of USH to stable swaps.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of stable to draw.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(drawCredit)]
+    fn draw_credit(&self, amount: BigUint) {
+        self.require_admin();
+
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        self.psm_adapter_draw(&amount);
+
+        let credit_drawn = self.credit_drawn().get() + &amount;
+        self.credit_drawn().set(&credit_drawn);
+        self.stable_reserves().update(|reserves| *reserves += &amount);
+
+        let psm_adapter = self.psm_adapter().get();
+        self.draw_credit_event(&psm_adapter, &amount, &credit_drawn);
+    }
+
+    /// Repays stable to the PSM adapter. The amount is applied to the drawn credit first, taken from the stable reserves,
+    /// and any excess is considered interest, taken from the stable revenue.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of stable to repay.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(repayCredit)]
+    fn repay_credit(&self, amount: BigUint) {
+        self.require_admin();
+
+        require!(amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let credit_drawn = self.credit_drawn().get();
+        let principal = if amount >= credit_drawn { credit_drawn.clone() } else { amount.clone() };
+        let interest = &amount - &principal;
+
+        let stable_reserves = self.stable_reserves().get();
+        require!(principal <= stable_reserves, ERROR_INSUFFICIENT_STABLE_RESERVES);
+
+        let stable_revenue = self.stable_revenue().get();
+        require!(interest <= stable_revenue, ERROR_INSUFFICIENT_REVENUE);
+
+        let new_credit_drawn = credit_drawn - &principal;
+        self.credit_drawn().set(&new_credit_drawn);
+        self.stable_reserves().set(&(stable_reserves - &principal));
+        self.stable_revenue().set(&(stable_revenue - &interest));
+
+        let payment = EsdtTokenPayment::new(self.stable_id().get(), 0, amount);
+        self.psm_adapter_deposit(&payment);
+
+        let psm_adapter = self.psm_adapter().get();
+        self.repay_credit_event(&psm_adapter, &principal, &interest, &new_credit_drawn);
+    }
+}
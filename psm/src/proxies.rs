@@ -0,0 +1,96 @@
+multiversx_sc::imports!();
+
+use super::{errors::*, storage};
+
+use multiversx_sc::storage::StorageKey;
+use psm_adapter::{credit::ProxyTrait as _, storage::ProxyTrait as _, ProxyTrait as _};
+use ush_minter::{esdt::ProxyTrait as _, permissions::ProxyTrait as _};
+
+#[multiversx_sc::module]
+pub trait ProxyModule: storage::StorageModule {
+    // USH Minter calls
+
+    fn is_ush_minter(&self, sc_address: &ManagedAddress) -> bool {
+        self.ush_minter_proxy(sc_address.clone()).is_ush_minter().execute_on_dest_context()
+    }
+
+    fn get_ush_id(&self, sc_address: &ManagedAddress) -> TokenIdentifier {
+        self.ush_minter_proxy(sc_address.clone()).get_ush_id().execute_on_dest_context()
+    }
+
+    fn is_facilitator(&self, address: &ManagedAddress) -> bool {
+        self.get_ush_minter_proxy().is_facilitator(address).execute_on_dest_context()
+    }
+
+    fn ush_minter_mint(&self, amount: &BigUint, destination: OptionalValue<ManagedAddress>) -> EsdtTokenPayment {
+        self.get_ush_minter_proxy().mint(amount.clone(), destination).execute_on_dest_context()
+    }
+
+    fn ush_minter_burn(&self, ush_payment: &EsdtTokenPayment) {
+        if ush_payment.amount == BigUint::zero() {
+            return;
+        }
+        self.get_ush_minter_proxy().burn().with_esdt_transfer(ush_payment.clone()).execute_on_dest_context()
+    }
+
+    // Oracle storage reads
+
+    /// Returns the USH fallback token identifier at the Price Oracle, i.e. the stable the oracle uses as USH anchor.
+    ///
+    fn get_oracle_ush_fallback_token_id(&self) -> Option<TokenIdentifier> {
+        let oracle = self.oracle().get();
+        let storage_key = StorageKey::new(b"ush_fallback_token_id");
+        let mapper = SingleValueMapper::new_from_address(oracle, storage_key);
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
+    /// Returns whether the pricing of the given token is paused at the Price Oracle.
+    ///
+    fn is_oracle_token_paused(&self, token_id: &TokenIdentifier) -> bool {
+        let oracle = self.oracle().get();
+        let mut storage_key = StorageKey::new(b"is_token_paused");
+        storage_key.append_item(token_id);
+        SingleValueMapper::new_from_address(oracle, storage_key).get()
+    }
+
+    // PSM Adapter calls
+
+    fn is_psm_adapter(&self, sc_address: &ManagedAddress) -> bool {
+        self.psm_adapter_proxy(sc_address.clone()).is_psm_adapter().execute_on_dest_context()
+    }
+
+    fn get_psm_adapter_underlying_id(&self, sc_address: &ManagedAddress) -> EgldOrEsdtTokenIdentifier {
+        self.psm_adapter_proxy(sc_address.clone()).underlying_id().execute_on_dest_context()
+    }
+
+    fn psm_adapter_draw(&self, amount: &BigUint) {
+        self.get_psm_adapter_proxy().draw(amount).execute_on_dest_context::<()>()
+    }
+
+    fn psm_adapter_deposit(&self, payment: &EsdtTokenPayment) {
+        self.get_psm_adapter_proxy().deposit().with_esdt_transfer(payment.clone()).execute_on_dest_context::<()>()
+    }
+
+    // Proxies
+
+    #[proxy]
+    fn ush_minter_proxy(&self, sc_address: ManagedAddress) -> ush_minter::ProxyTo<Self::Api>;
+
+    fn get_ush_minter_proxy(&self) -> ush_minter::ProxyTo<Self::Api> {
+        let minter = self.ush_minter().get();
+        self.ush_minter_proxy(minter)
+    }
+
+    #[proxy]
+    fn psm_adapter_proxy(&self, sc_address: ManagedAddress) -> psm_adapter::ProxyTo<Self::Api>;
+
+    fn get_psm_adapter_proxy(&self) -> psm_adapter::ProxyTo<Self::Api> {
+        require!(!self.psm_adapter().is_empty(), ERROR_UNDEFINED_PSM_ADAPTER);
+        let psm_adapter = self.psm_adapter().get();
+        self.psm_adapter_proxy(psm_adapter)
+    }
+}
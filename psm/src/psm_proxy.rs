@@ -0,0 +1,563 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct PegStabilityModuleProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for PegStabilityModuleProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = PegStabilityModuleProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        PegStabilityModuleProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct PegStabilityModuleProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> PegStabilityModuleProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Initializes the Peg Stability Module (PSM) smart contract, which allows 1:1 swaps between USH and a whitelisted
+    /// stable.
+    ///
+    /// # Arguments:
+    ///
+    /// - `ush_minter` - The USH Minter smart contract address.
+    /// - `oracle` - The Price Oracle smart contract address.
+    /// - `stable_id` - The whitelisted stable token identifier, currently USDC.
+    /// - `stable_decimals` - The whitelisted stable decimals.
+    /// - `opt_admin` - An optional admin address for the contract.
+    ///
+    /// Notes:
+    ///
+    /// - If the admin address is not provided, the admin will be set as the deployer.
+    /// - Swaps are disabled until the PSM is made a USH facilitator and activated by the admin.
+    ///
+    pub fn init<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<u8>,
+        Arg4: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        ush_minter: Arg0,
+        oracle: Arg1,
+        stable_id: Arg2,
+        stable_decimals: Arg3,
+        opt_admin: Arg4,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&ush_minter)
+            .argument(&oracle)
+            .argument(&stable_id)
+            .argument(&stable_decimals)
+            .argument(&opt_admin)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PegStabilityModuleProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PegStabilityModuleProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Returns the current admin address.
+    ///
+    pub fn get_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdmin")
+            .original_result()
+    }
+
+    /// Returns the current pending admin address, if there is one.
+    ///
+    pub fn get_pending_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingAdmin")
+            .original_result()
+    }
+
+    /// Sets the pending admin address to the given address.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_pending_admin` - The new pending admin address.
+    ///
+    pub fn set_pending_admin<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pending_admin: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPendingAdmin")
+            .argument(&pending_admin)
+            .original_result()
+    }
+
+    /// Attempts to accept the pending admin, which must be set first using the `set_pending_admin` endpoint.
+    pub fn accept_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptAdmin")
+            .original_result()
+    }
+
+    /// A utility function to highlight that this smart contract is a Peg Stability Module.
+    ///
+    pub fn is_psm(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPsm")
+            .original_result()
+    }
+
+    /// Returns whether the whitelisted stable is pegged according to the Price Oracle. The oracle's USH fallback token
+    /// machinery is reused: the stable must be the oracle's USH fallback token and neither its pricing nor USH pricing can
+    /// be paused, which happens whenever their prices deviate from their anchors.
+    ///
+    pub fn is_pegged(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPegged")
+            .original_result()
+    }
+
+    /// Returns the address of the PSM adapter if set.
+    ///
+    pub fn get_psm_adapter(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPsmAdapter")
+            .original_result()
+    }
+
+    /// Enables or disables swaps.
+    ///
+    /// # Arguments:
+    ///
+    /// - `active` - Whether swaps are enabled.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Swaps can only be enabled if the PSM is a USH facilitator at the USH minter.
+    ///
+    pub fn set_active<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        active: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setActive")
+            .argument(&active)
+            .original_result()
+    }
+
+    /// Sets the swap fees.
+    ///
+    /// # Arguments:
+    ///
+    /// - `fee_in` - The fee charged on stable to USH swaps in wad.
+    /// - `fee_out` - The fee charged on USH to stable swaps in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Fees cannot exceed `MAX_SWAP_FEE`.
+    ///
+    pub fn set_swap_fees<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        fee_in: Arg0,
+        fee_out: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSwapFees")
+            .argument(&fee_in)
+            .argument(&fee_out)
+            .original_result()
+    }
+
+    /// Sets the maximum amount of USH that can be outstanding after being minted by the PSM.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_mint_cap` - The new mint cap.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Lowering the cap below the outstanding USH only prevents new stable to USH swaps.
+    ///
+    pub fn set_mint_cap<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_mint_cap: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMintCap")
+            .argument(&new_mint_cap)
+            .original_result()
+    }
+
+    /// Withdraws the fees collected by the PSM.
+    ///
+    /// # Arguments:
+    ///
+    /// - `to` - The address that receives the fees.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn withdraw_revenue<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        to: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawRevenue")
+            .argument(&to)
+            .original_result()
+    }
+
+    /// Sets the PSM adapter, i.e. the contract through which stable can be borrowed from a money market credit line.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_psm_adapter` - The PSM adapter smart contract address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The PSM adapter must lend the whitelisted stable.
+    /// - Changing the PSM adapter requires all drawn credit to be repaid first.
+    ///
+    pub fn set_psm_adapter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_psm_adapter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPsmAdapter")
+            .argument(&new_psm_adapter)
+            .original_result()
+    }
+
+    /// Draws stable from the PSM adapter into the stable reserves, increasing the capacity of USH to stable swaps.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of stable to draw.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn draw_credit<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("drawCredit")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Repays stable to the PSM adapter. The amount is applied to the drawn credit first, taken from the stable reserves,
+    /// and any excess is considered interest, taken from the stable revenue.
+    ///
+    /// # Arguments:
+    ///
+    /// - `amount` - The amount of stable to repay.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn repay_credit<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("repayCredit")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Stores the USH Minter address.
+    pub fn ush_minter(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUshMinter")
+            .original_result()
+    }
+
+    /// Stores the USH Token Identifier.
+    pub fn ush_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUshId")
+            .original_result()
+    }
+
+    /// Stores the Price Oracle address, used to detect whether the stable is depegged.
+    pub fn oracle(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOracle")
+            .original_result()
+    }
+
+    /// Stores the whitelisted stable Token Identifier.
+    pub fn stable_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStableId")
+            .original_result()
+    }
+
+    /// Stores the whitelisted stable decimals.
+    pub fn stable_decimals(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStableDecimals")
+            .original_result()
+    }
+
+    /// Stores whether swaps are enabled.
+    pub fn active(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isActive")
+            .original_result()
+    }
+
+    /// Stores the fee charged on stable to USH swaps in wad.
+    pub fn swap_fee_in(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSwapFeeIn")
+            .original_result()
+    }
+
+    /// Stores the fee charged on USH to stable swaps in wad.
+    pub fn swap_fee_out(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSwapFeeOut")
+            .original_result()
+    }
+
+    /// Stores the maximum amount of USH that can be outstanding after being minted by the PSM.
+    pub fn mint_cap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintCap")
+            .original_result()
+    }
+
+    /// Stores the amount of USH currently outstanding after being minted by the PSM.
+    pub fn ush_minted(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUshMinted")
+            .original_result()
+    }
+
+    /// Stores the amount of stable backing USH to stable swaps.
+    pub fn stable_reserves(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStableReserves")
+            .original_result()
+    }
+
+    /// Stores the stable fees collected by the PSM.
+    pub fn stable_revenue(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStableRevenue")
+            .original_result()
+    }
+
+    /// Stores the USH fees collected by the PSM.
+    pub fn ush_revenue(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUshRevenue")
+            .original_result()
+    }
+
+    /// Stores the amount of stable currently drawn from the PSM adapter.
+    pub fn credit_drawn(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCreditDrawn")
+            .original_result()
+    }
+
+    /// Swaps the whitelisted stable for USH at a 1:1 rate, minus the swap in fee. The USH is minted by the USH minter, for
+    /// which the PSM acts as a facilitator.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_min_ush_out` - An optional minimum amount of USH to be received.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the whitelisted stable.
+    /// - The outstanding USH minted by the PSM cannot exceed the mint cap.
+    /// - Halted whenever the stable is not pegged according to the Price Oracle.
+    ///
+    pub fn swap_stable_for_ush<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        opt_min_ush_out: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("swapStableForUsh")
+            .argument(&opt_min_ush_out)
+            .original_result()
+    }
+
+    /// Swaps USH for the whitelisted stable at a 1:1 rate, minus the swap out fee. The swapped USH is burned by the USH
+    /// minter.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_min_stable_out` - An optional minimum amount of stable to be received.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with USH.
+    /// - Limited by the stable reserves held by the PSM.
+    /// - Halted whenever the stable is not pegged according to the Price Oracle.
+    /// - Any USH dust that cannot be translated into stable is kept as revenue.
+    ///
+    pub fn swap_ush_for_stable<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        opt_min_stable_out: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .raw_call("swapUshForStable")
+            .argument(&opt_min_stable_out)
+            .original_result()
+    }
+}
@@ -0,0 +1,78 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait StorageModule {
+    /// Stores the USH Minter address.
+    #[view(getUshMinter)]
+    #[storage_mapper("ush_minter")]
+    fn ush_minter(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the USH Token Identifier.
+    #[view(getUshId)]
+    #[storage_mapper("ush_id")]
+    fn ush_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Stores the Price Oracle address, used to detect whether the stable is depegged.
+    #[view(getOracle)]
+    #[storage_mapper("oracle")]
+    fn oracle(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the whitelisted stable Token Identifier.
+    #[view(getStableId)]
+    #[storage_mapper("stable_id")]
+    fn stable_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Stores the whitelisted stable decimals.
+    #[view(getStableDecimals)]
+    #[storage_mapper("stable_decimals")]
+    fn stable_decimals(&self) -> SingleValueMapper<u8>;
+
+    /// Stores whether swaps are enabled.
+    #[view(isActive)]
+    #[storage_mapper("active")]
+    fn active(&self) -> SingleValueMapper<bool>;
+
+    /// Stores the fee charged on stable to USH swaps in wad.
+    #[view(getSwapFeeIn)]
+    #[storage_mapper("swap_fee_in")]
+    fn swap_fee_in(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the fee charged on USH to stable swaps in wad.
+    #[view(getSwapFeeOut)]
+    #[storage_mapper("swap_fee_out")]
+    fn swap_fee_out(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the maximum amount of USH that can be outstanding after being minted by the PSM.
+    #[view(getMintCap)]
+    #[storage_mapper("mint_cap")]
+    fn mint_cap(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the amount of USH currently outstanding after being minted by the PSM.
+    #[view(getUshMinted)]
+    #[storage_mapper("ush_minted")]
+    fn ush_minted(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the amount of stable backing USH to stable swaps.
+    #[view(getStableReserves)]
+    #[storage_mapper("stable_reserves")]
+    fn stable_reserves(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the stable fees collected by the PSM.
+    #[view(getStableRevenue)]
+    #[storage_mapper("stable_revenue")]
+    fn stable_revenue(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the USH fees collected by the PSM.
+    #[view(getUshRevenue)]
+    #[storage_mapper("ush_revenue")]
+    fn ush_revenue(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the address of the PSM adapter through which stable can be borrowed from a money market.
+    #[storage_mapper("psm_adapter")]
+    fn psm_adapter(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the amount of stable currently drawn from the PSM adapter.
+    #[view(getCreditDrawn)]
+    #[storage_mapper("credit_drawn")]
+    fn credit_drawn(&self) -> SingleValueMapper<BigUint>;
+}
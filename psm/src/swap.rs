@@ -0,0 +1,110 @@
+multiversx_sc::imports!();
+
+use super::{commons, constants::*, errors::*, events, proxies, storage};
+
+#[multiversx_sc::module]
+pub trait SwapModule: admin::AdminModule + commons::CommonsModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+    /// Swaps the whitelisted stable for USH at a 1:1 rate, minus the swap in fee. The USH is minted by the USH minter, for
+    /// which the PSM acts as a facilitator.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_min_ush_out` - An optional minimum amount of USH to be received.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with the whitelisted stable.
+    /// - The outstanding USH minted by the PSM cannot exceed the mint cap.
+    /// - Halted whenever the stable is not pegged according to the Price Oracle.
+    ///
+    #[payable("*")]
+    #[endpoint(swapStableForUsh)]
+    fn swap_stable_for_ush(&self, opt_min_ush_out: OptionalValue<BigUint>) -> EsdtTokenPayment {
+        self.require_active();
+        self.require_pegged();
+
+        let (stable_id, stable_in) = self.call_value().single_fungible_esdt();
+        require!(stable_id == self.stable_id().get(), ERROR_INVALID_PAYMENT);
+        require!(stable_in > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let fee = &stable_in * &self.swap_fee_in().get() / BigUint::from(WAD);
+        let stable_net = &stable_in - &fee;
+        let ush_out = &stable_net * &self.get_stable_scale();
+        require!(ush_out > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let ush_minted = self.ush_minted().get() + &ush_out;
+        require!(ush_minted <= self.mint_cap().get(), ERROR_MINT_CAP_EXCEEDED);
+
+        if let Some(min_ush_out) = opt_min_ush_out.into_option() {
+            require!(ush_out >= min_ush_out, ERROR_NOT_ENOUGH_USH_OUT);
+        }
+
+        self.ush_minted().set(&ush_minted);
+        self.stable_reserves().update(|reserves| *reserves += &stable_net);
+        self.stable_revenue().update(|revenue| *revenue += &fee);
+
+        let caller = self.blockchain().get_caller();
+        let ush_payment = self.ush_minter_mint(&ush_out, OptionalValue::Some(caller.clone()));
+
+        self.swap_stable_for_ush_event(&caller, &stable_in, &ush_out, &fee);
+
+        ush_payment
+    }
+
+    /// Swaps USH for the whitelisted stable at a 1:1 rate, minus the swap out fee. The swapped USH is burned by the USH
+    /// minter.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_min_stable_out` - An optional minimum amount of stable to be received.
+    ///
+    /// # Notes:
+    ///
+    /// - Must be paid with USH.
+    /// - Limited by the stable reserves held by the PSM.
+    /// - Halted whenever the stable is not pegged according to the Price Oracle.
+    /// - Any USH dust that cannot be translated into stable is kept as revenue.
+    ///
+    #[payable("*")]
+    #[endpoint(swapUshForStable)]
+    fn swap_ush_for_stable(&self, opt_min_stable_out: OptionalValue<BigUint>) -> EsdtTokenPayment {
+        self.require_active();
+        self.require_pegged();
+
+        let (ush_id, ush_in) = self.call_value().single_fungible_esdt();
+        require!(ush_id == self.ush_id().get(), ERROR_INVALID_PAYMENT);
+        require!(ush_in > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let scale = self.get_stable_scale();
+        let ush_fee = &ush_in * &self.swap_fee_out().get() / BigUint::from(WAD);
+        let stable_out = (&ush_in - &ush_fee) / &scale;
+        require!(stable_out > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+
+        let stable_reserves = self.stable_reserves().get();
+        require!(stable_out <= stable_reserves, ERROR_INSUFFICIENT_STABLE_RESERVES);
+
+        if let Some(min_stable_out) = opt_min_stable_out.into_option() {
+            require!(stable_out >= min_stable_out, ERROR_NOT_ENOUGH_STABLE_OUT);
+        }
+
+        let ush_burned = &stable_out * &scale;
+        let fee = &ush_in - &ush_burned;
+
+        let ush_minted = self.ush_minted().get();
+        let new_ush_minted = if ush_burned >= ush_minted { BigUint::zero() } else { ush_minted - &ush_burned };
+
+        self.ush_minted().set(&new_ush_minted);
+        self.stable_reserves().set(&(stable_reserves - &stable_out));
+        self.ush_revenue().update(|revenue| *revenue += &fee);
+
+        self.ush_minter_burn(&EsdtTokenPayment::new(ush_id, 0, ush_burned));
+
+        let caller = self.blockchain().get_caller();
+        let stable_payment = EsdtTokenPayment::new(self.stable_id().get(), 0, stable_out.clone());
+        self.send().direct_esdt(&caller, &stable_payment.token_identifier, 0, &stable_payment.amount);
+
+        self.swap_ush_for_stable_event(&caller, &ush_in, &stable_out, &fee);
+
+        stable_payment
+    }
+}
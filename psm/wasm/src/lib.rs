@@ -0,0 +1,56 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           30
+// Async Callback (empty):               1
+// Total number of exported functions:  33
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    psm
+    (
+        init => init
+        upgrade => upgrade
+        getAdmin => get_admin
+        getPendingAdmin => get_pending_admin
+        setPendingAdmin => set_pending_admin
+        acceptAdmin => accept_admin
+        isPsm => is_psm
+        isPegged => is_pegged
+        getPsmAdapter => get_psm_adapter
+        setActive => set_active
+        setSwapFees => set_swap_fees
+        setMintCap => set_mint_cap
+        withdrawRevenue => withdraw_revenue
+        setPsmAdapter => set_psm_adapter
+        drawCredit => draw_credit
+        repayCredit => repay_credit
+        getUshMinter => ush_minter
+        getUshId => ush_id
+        getOracle => oracle
+        getStableId => stable_id
+        getStableDecimals => stable_decimals
+        isActive => active
+        getSwapFeeIn => swap_fee_in
+        getSwapFeeOut => swap_fee_out
+        getMintCap => mint_cap
+        getUshMinted => ush_minted
+        getStableReserves => stable_reserves
+        getStableRevenue => stable_revenue
+        getUshRevenue => ush_revenue
+        getCreditDrawn => credit_drawn
+        swapStableForUsh => swap_stable_for_ush
+        swapUshForStable => swap_ush_for_stable
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -7,8 +7,10 @@ use super::{
     storage::{self, DiscountStrategy, InteractionType},
 };
 
+use controller::storage::Status;
+
 #[multiversx_sc::module]
-pub trait BorrowModule: commons::CommonsModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait BorrowModule: pausable::PausableModule + commons::CommonsModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
     /// A borrower requests USH from the money market.
     ///
     /// # Arguments:
@@ -25,9 +27,17 @@ pub trait BorrowModule: commons::CommonsModule + events::EventsModule + proxies:
     }
 
     fn borrow_internal(&self, borrower: ManagedAddress, ush_amount: BigUint) -> EsdtTokenPayment<Self::Api> {
+        self.require_not_paused();
+
         // check if accrual has been updated
         self.require_market_fresh();
 
+        // borrows are restricted for a while after an abnormal accrual gap
+        self.require_no_accrual_gap_restriction();
+
+        // new borrows mint USH, which can be paused independently of the market
+        require!(self.mint_status().get() == Status::Active, ERROR_MINT_PAUSED);
+
         // check if borrow repayment is allowed
         let money_market = self.blockchain().get_sc_address();
         let borrow_allowed = self.borrow_allowed(&money_market, &borrower, &ush_amount);
@@ -4,13 +4,14 @@ use super::{
     constants::*,
     errors::*,
     events, proxies,
-    storage::{self, AccountSnapshot, DiscountStrategy, InteractionType, State},
+    storage::{self, AccountSnapshot, BorrowIndexAudit, DiscountStrategy, InteractionType, State, TrustedMinterMetadata},
 };
 
 use discount_rate_model::models::ExchangeRateType;
+use math::Rounding;
 
 #[multiversx_sc::module]
-pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait CommonsModule: math::MathModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
     // Checks
 
     /// A utility function to highlight that this smart contract implements the Money Market api that Controller requires.
@@ -101,6 +102,30 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         self.blockchain().is_smart_contract(sc_address) && self.is_trusted_minter(sc_address)
     }
 
+    /// Returns a page of the trusted minters with metadata, together with their metadata.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The zero based position of the first trusted minter in the page.
+    /// - `size` - The maximum number of trusted minters in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Legacy trusted minters are only enumerated once their metadata has been set.
+    ///
+    #[view(getTrustedMinters)]
+    fn get_trusted_minters(&self, from: usize, size: usize) -> MultiValueEncoded<MultiValue2<ManagedAddress, TrustedMinterMetadata<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        let trusted_minters = self.trusted_minters();
+        let to = core::cmp::min(from.saturating_add(size), trusted_minters.len());
+        for index in from..to {
+            let trusted_minter = trusted_minters.get_by_index(index + 1);
+            let metadata = self.trusted_minter_metadata(&trusted_minter).get();
+            result.push((trusted_minter, metadata).into());
+        }
+        result
+    }
+
     /// Checks if the borrow rate change is allowed or not.
     ///
     /// # Arguments:
@@ -109,11 +134,24 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
     /// - `to` - The new borrow rate.
     ///
     fn is_borrow_rate_change_allowed(&self, from: &BigUint, to: &BigUint) -> bool {
-        let max_borrow_rate_change = from * &BigUint::from(MAX_BORROW_RATE_CHANGE) / BigUint::from(BPS);
+        let max_borrow_rate_change = self.bps_mul(from, &BigUint::from(MAX_BORROW_RATE_CHANGE), Rounding::Down);
         let delta_borrow_rate = if from < to { to - from } else { from - to };
         delta_borrow_rate <= max_borrow_rate_change
     }
 
+    /// Checks whether the stake factor change is within the allowed limits, i.e. it does not change more than the maximum
+    /// allowed step.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The current stake factor.
+    /// - `to` - The new stake factor.
+    ///
+    fn is_stake_factor_change_allowed(&self, from: &BigUint, to: &BigUint) -> bool {
+        let delta_stake_factor = if from < to { to - from } else { from - to };
+        delta_stake_factor <= BigUint::from(MAX_STAKE_FACTOR_CHANGE)
+    }
+
     // Requires
 
     /// Requires that the money market has already accrued interest.
@@ -122,6 +160,12 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         require!(self.blockchain().get_block_timestamp() == self.accrual_timestamp().get(), ERROR_MARKET_NOT_FRESH);
     }
 
+    /// Requires that risk increasing actions are not restricted because of a recent abnormal accrual gap.
+    ///
+    fn require_no_accrual_gap_restriction(&self) {
+        require!(!self.has_abnormal_accrual_gap(), ERROR_ACCRUAL_GAP_RESTRICTION);
+    }
+
     /// Requires that the money market is already active.
     ///
     fn require_active(&self) {
@@ -185,6 +229,13 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         require!(caller == self.controller().get(), ERROR_CALLER_MUST_BE_CONTROLLER_SC);
     }
 
+    /// Requires the caller to be a reader registered at the Controller, only if bulk enumeration views are restricted.
+    ///
+    fn require_enumeration_allowed(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.is_enumeration_allowed(&caller), ERROR_UNREGISTERED_READER);
+    }
+
     /// Requires that the caller is the Staking smart contract.
     ///
     fn require_staking_sc(&self) {
@@ -193,16 +244,69 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         require!(caller == self.staking_sc().get(), ERROR_CALLER_MUST_BE_STAKING_SC);
     }
 
-    // Utility
+    // Accrue Interest
 
-    /// Takes a numerator and denominator and returns the smallest integer greater than or equal to the quotient.
+    /// Checks whether the time elapsed between the given accrual timestamp and the given timestamp is abnormal, i.e. the
+    /// timestamp went backwards, an abnormal accrual gap has been recorded at the Controller since the given accrual
+    /// timestamp, or the elapsed time since the latest accrual across all markets exceeds the maximum accrual gap, if set. A
+    /// market that has been idle is not abnormal as long as other markets have accrued recently.
+    ///
+    /// # Arguments:
     ///
-    fn ceil_div(&self, num: BigUint, den: BigUint) -> BigUint {
-        require!(den > BigUint::zero(), ERROR_DIVISION_BY_ZERO);
-        return (num + (&den - 1u64)) / den;
+    /// - `t_prev` - The last accrual timestamp.
+    /// - `t` - The current timestamp.
+    ///
+    fn is_abnormal_accrual_gap(&self, t_prev: u64, t: u64) -> bool {
+        if t < t_prev {
+            return true;
+        }
+
+        let max_accrual_gap = self.max_accrual_gap().get();
+        if max_accrual_gap == 0 || t - t_prev <= max_accrual_gap {
+            return false;
+        }
+
+        let (latest_accrual_timestamp, latest_accrual_gap_timestamp) = self.get_latest_accrual_timestamps();
+        if latest_accrual_gap_timestamp > t_prev {
+            return true;
+        }
+
+        let t_latest = core::cmp::max(t_prev, latest_accrual_timestamp);
+        t_latest > t || t - t_latest > max_accrual_gap
     }
 
-    // Accrue Interest
+    /// Checks whether borrows are restricted because of an abnormal accrual gap, either pending governance resolution or
+    /// about to be recorded at the next accrual.
+    ///
+    #[view(hasAbnormalAccrualGap)]
+    fn has_abnormal_accrual_gap(&self) -> bool {
+        if !self.accrual_gap_timestamp().is_empty() {
+            return true;
+        }
+
+        let t = self.blockchain().get_block_timestamp();
+        let t_prev = self.accrual_timestamp().get();
+        t != t_prev && self.is_abnormal_accrual_gap(t_prev, t)
+    }
+
+    /// Records an abnormal accrual gap as pending, accumulating it with any other pending gap. Its interest is not charged
+    /// until governance resolves it.
+    ///
+    /// # Arguments:
+    ///
+    /// - `t_prev` - The last accrual timestamp.
+    /// - `t` - The current timestamp.
+    /// - `borrow_rate` - The borrow rate per second in place during the gap.
+    ///
+    fn record_accrual_gap(&self, t_prev: u64, t: u64, borrow_rate: &BigUint) {
+        let dt = if t > t_prev { t - t_prev } else { 0 };
+
+        self.accrual_gap_timestamp().set(t);
+        self.accrual_gap_duration().update(|duration| *duration += dt);
+        self.accrual_gap_interest_factor().update(|factor| *factor += borrow_rate * dt);
+
+        self.abnormal_accrual_gap_event(t_prev, t);
+    }
 
     /// This method is one of the most important methods of the protocol, as it accrues the borrows interest and distributes
     /// that amount into reserves (including revenue and staking rewards). In order to do that, it solves the money market
@@ -210,8 +314,6 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
     ///
     #[endpoint(accrueInterest)]
     fn accrue_interest(&self) {
-        let wad = BigUint::from(WAD);
-
         let t_prev = self.accrual_timestamp().get();
         let t = self.blockchain().get_block_timestamp();
 
@@ -221,11 +323,40 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         }
 
         let borrow_rate = self.borrow_rate().get();
-        let effective_borrows = self.effective_borrows().get();
 
-        let dt = t - t_prev;
-        let borrow_rate_dt = borrow_rate * dt;
-        let delta_borrows = &borrow_rate_dt * &effective_borrows / &wad;
+        // abnormal timestamp jumps (e.g. after a chain halt) are kept pending and restrict borrows until governance decides
+        // whether their interest is charged
+        let abnormal_accrual_gap = self.is_abnormal_accrual_gap(t_prev, t);
+        if abnormal_accrual_gap {
+            self.record_accrual_gap(t_prev, t, &borrow_rate);
+        }
+
+        // no interest is accumulated while interest accrual is frozen at the controller
+        let frozen_until = self.get_interest_frozen_until();
+        let t_start = if abnormal_accrual_gap { t } else { core::cmp::max(t_prev, core::cmp::min(frozen_until, t)) };
+
+        let dt = t - t_start;
+        let borrow_rate_dt = &borrow_rate * dt;
+        let (delta_borrows, total_borrows, borrow_index, delta_rewards, delta_revenue) = self.accumulate_interest(&borrow_rate_dt);
+
+        // update accrual timestamp
+        self.accrual_timestamp().set(t);
+        self.record_accrual(abnormal_accrual_gap);
+
+        self.accrue_interest_event(&delta_borrows, &borrow_index, &total_borrows, &borrow_rate, &delta_rewards, &delta_revenue);
+    }
+
+    /// Accumulates interest into total borrows, distributes it into reserves (including revenue and staking rewards) and
+    /// updates the borrow index. Returns the delta borrows, the new total borrows, the new borrow index, the delta staking
+    /// rewards and the delta revenue.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_rate_dt` - The borrow rate per second times the elapsed time, in wad.
+    ///
+    fn accumulate_interest(&self, borrow_rate_dt: &BigUint) -> (BigUint, BigUint, BigUint, BigUint, BigUint) {
+        let effective_borrows = self.effective_borrows().get();
+        let delta_borrows = self.wad_mul(borrow_rate_dt, &effective_borrows);
 
         let mut total_borrows = self.total_borrows().get();
         total_borrows += &delta_borrows;
@@ -234,7 +365,7 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         self.effective_borrows().update(|amount| *amount += &delta_borrows);
 
         let mut borrow_index = self.get_borrow_index();
-        borrow_index += self.ceil_div(&borrow_index * &borrow_rate_dt, wad.clone());
+        borrow_index += self.wad_mul_up(&borrow_index, borrow_rate_dt);
         self.borrow_index().set(&borrow_index);
 
         // interest goes to the reserves
@@ -242,17 +373,14 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
 
         // reserves are divided into staking rewards and revenue
         let fs = self.stake_factor().get();
-        let delta_rewards = fs * &delta_borrows / &wad;
+        let delta_rewards = self.wad_mul(&fs, &delta_borrows);
         let delta_revenue = &delta_borrows - &delta_rewards;
 
         self.revenue().update(|amount| *amount += &delta_revenue);
         self.staking_rewards().update(|amount| *amount += &delta_rewards);
         self.historical_staking_rewards().update(|amount| *amount += &delta_rewards);
 
-        // update accrual timestamp
-        self.accrual_timestamp().set(t);
-
-        self.accrue_interest_event(&delta_borrows, &borrow_index, &total_borrows);
+        (delta_borrows, total_borrows, borrow_index, delta_rewards, delta_revenue)
     }
 
     /// Accrues interest if a sufficient amount of time has elapsed since the last accrual.
@@ -319,9 +447,8 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
     ///
     #[view(ushToHush)]
     fn ush_to_hush(&self, ush_amount: &BigUint) -> BigUint {
-        let wad = BigUint::from(WAD);
         let fx = self.get_exchange_rate();
-        let tokens = ush_amount * &wad / fx;
+        let tokens = self.wad_div(ush_amount, &fx);
         tokens
     }
 
@@ -333,9 +460,8 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
     ///
     #[view(hushToUsh)]
     fn hush_to_ush(&self, tokens: &BigUint) -> BigUint {
-        let wad = BigUint::from(WAD);
         let fx = self.get_exchange_rate();
-        let underlying_amount = fx * tokens / wad;
+        let underlying_amount = self.wad_mul(&fx, tokens);
         underlying_amount
     }
 
@@ -396,6 +522,7 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
     fn set_account_borrow_snapshot(&self, borrower: &ManagedAddress, borrow_amount: &BigUint, borrow_index: &BigUint, discount: &BigUint) {
         let account_snapshot = AccountSnapshot::new(borrow_amount, borrow_index, discount);
         self.account_borrow_snapshot(borrower).set(&account_snapshot);
+        self.account_borrow_index_rebases(borrower).set(self.borrow_index_rebases().len());
     }
 
     // Gets
@@ -467,6 +594,138 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         self.hush_to_ush(&total_supply)
     }
 
+    /// Returns the amount of liquidity that is available to suppliers and borrowers, which in this market equals the
+    /// liquidity. We keep this method so that it matches with the Money Market interface.
+    ///
+    #[view(getAvailableLiquidity)]
+    fn get_available_liquidity(&self) -> BigUint {
+        self.get_liquidity()
+    }
+
+    /// Returns the accounts with an outstanding borrow.
+    ///
+    /// # Notes:
+    ///
+    /// - Restricted to readers registered at the Controller if bulk enumeration views are restricted.
+    ///
+    #[view(getMarketBorrowers)]
+    fn get_market_borrowers(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.require_enumeration_allowed();
+        let mut borrowers = MultiValueEncoded::new();
+        for borrower in self.market_borrowers().iter() {
+            borrowers.push(borrower);
+        }
+        borrowers
+    }
+
+    /// Returns the number of accounts with an outstanding borrow.
+    ///
+    #[view(getMarketBorrowersCount)]
+    fn get_market_borrowers_count(&self) -> usize {
+        self.market_borrowers().len()
+    }
+
+    /// Returns a page of the accounts with an outstanding borrow.
+    ///
+    /// # Arguments:
+    ///
+    /// - `start` - The zero based position of the first borrower in the page.
+    /// - `count` - The maximum number of borrowers in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - The order is not guaranteed to be preserved among calls if borrowers are removed in between.
+    /// - Restricted to readers registered at the Controller if bulk enumeration views are restricted.
+    ///
+    #[view(getMarketBorrowersPaged)]
+    fn get_market_borrowers_paged(&self, start: usize, count: usize) -> MultiValueEncoded<ManagedAddress> {
+        self.require_enumeration_allowed();
+
+        let mut result = MultiValueEncoded::new();
+        let market_borrowers = self.market_borrowers();
+        let end = core::cmp::min(start.saturating_add(count), market_borrowers.len());
+        for index in start..end {
+            result.push(market_borrowers.get_by_index(index + 1));
+        }
+        result
+    }
+
+    /// Returns the weighted-average discount in wad up to the last interaction that accrued interest, i.e. the share of the
+    /// total borrows that does not accrue interest because of borrowers discounts.
+    ///
+    #[view(getAverageDiscount)]
+    fn get_average_discount(&self) -> BigUint {
+        let total_borrows = self.total_borrows().get();
+        if total_borrows == BigUint::zero() {
+            return BigUint::zero();
+        }
+
+        let effective_borrows = self.effective_borrows().get();
+        let discounted_borrows = if total_borrows > effective_borrows { &total_borrows - &effective_borrows } else { BigUint::zero() };
+
+        self.wad_div(&discounted_borrows, &total_borrows)
+    }
+
+    /// Returns the number of borrowers, the effective borrows, the total principal and the weighted-average discount in one
+    /// shot, all of them up to the last interaction that accrued interest.
+    ///
+    /// # Notes:
+    ///
+    /// - Restricted to readers registered at the Controller if bulk enumeration views are restricted.
+    ///
+    #[view(getBorrowersSummary)]
+    fn get_borrowers_summary(&self) -> MultiValue4<usize, BigUint, BigUint, BigUint> {
+        self.require_enumeration_allowed();
+
+        let borrowers_count = self.get_market_borrowers_count();
+        let effective_borrows = self.effective_borrows().get();
+        let total_principal = self.total_principal().get();
+        let average_discount = self.get_average_discount();
+        (borrowers_count, effective_borrows, total_principal, average_discount).into()
+    }
+
+    /// Returns the projected change in daily interest for the current effective borrows if the borrow APR was updated to the
+    /// given value, together with a flag indicating whether the daily interest would increase or not.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_borrow_apr` - The candidate borrow APR in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Effective borrows are considered up to the last interaction that accrued interest.
+    ///
+    #[view(previewBorrowAprChange)]
+    fn preview_borrow_apr_change(&self, new_borrow_apr: BigUint) -> MultiValue2<BigUint, bool> {
+        let old_borrow_rate = self.borrow_rate().get();
+        let new_borrow_rate = new_borrow_apr / SECONDS_PER_YEAR;
+        let effective_borrows = self.effective_borrows().get();
+
+        let old_daily_interest = self.wad_mul(&(old_borrow_rate * SECONDS_PER_DAY), &effective_borrows);
+        let new_daily_interest = self.wad_mul(&(new_borrow_rate * SECONDS_PER_DAY), &effective_borrows);
+
+        if new_daily_interest >= old_daily_interest {
+            (new_daily_interest - old_daily_interest, true).into()
+        } else {
+            (old_daily_interest - new_daily_interest, false).into()
+        }
+    }
+
+    /// Computes the projected annualized revenue, i.e. the share of the interest that is not distributed as staking rewards,
+    /// for the current effective borrows at the given borrow rate.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrow_rate` - The borrow rate per second in wad.
+    ///
+    fn get_projected_annual_revenue(&self, borrow_rate: &BigUint) -> BigUint {
+        let effective_borrows = self.effective_borrows().get();
+        let fs = self.stake_factor().get();
+        let annual_interest = self.wad_mul(&(borrow_rate * &BigUint::from(SECONDS_PER_YEAR)), &effective_borrows);
+        let annual_rewards = self.wad_mul(&fs, &annual_interest);
+        annual_interest - annual_rewards
+    }
+
     /// Returns a fixed reserve factor fixed to 100%. This function is used by the Controller to verify if a money market is
     /// deprecated or not.
     ///
@@ -527,9 +786,10 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
             None => BigUint::zero(),
             Some(snapshot) => {
                 let wad = BigUint::from(WAD);
-                let market_index = self.get_borrow_index();
+                let market_index = self.get_borrow_index() * self.get_account_pending_rebase_divisor(borrower);
                 let AccountSnapshot { borrow_amount: borrow_prev, borrow_index: account_index, discount, .. } = snapshot;
-                let borrow = borrow_prev * (market_index * (&wad - &discount) / account_index + discount) / wad;
+                let borrow_factor = self.mul_div(&market_index, &(&wad - &discount), &account_index, Rounding::Down) + discount;
+                let borrow = self.wad_mul(&borrow_prev, &borrow_factor);
                 borrow
             },
         }
@@ -547,6 +807,26 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         }
     }
 
+    /// Returns the product of the divisors applied to the borrow index by the borrow index rebases that took place after the
+    /// account borrow snapshot was taken, or one if there are none. Multiplying the borrow index by this divisor expresses it
+    /// in the same scale as the account snapshot borrow index.
+    ///
+    /// # Arguments:
+    ///
+    /// - `account` - The account's address.
+    ///
+    fn get_account_pending_rebase_divisor(&self, account: &ManagedAddress) -> BigUint {
+        let rebases = self.borrow_index_rebases();
+        let account_rebases = self.account_borrow_index_rebases(account).get();
+
+        let mut divisor = BigUint::from(1u64);
+        for position in account_rebases + 1..=rebases.len() {
+            divisor *= rebases.get(position);
+        }
+
+        divisor
+    }
+
     /// Returns the market exchange rate (fixed to one) and the borrow amount of the given account up to the last interaction
     /// that accrued interest, in one shot.
     ///
@@ -589,6 +869,58 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         }
     }
 
+    /// Returns an audit of the borrow index, which can be used to plan an index rebase long before precision becomes a
+    /// problem. It includes the borrow index magnitude in bits, an estimation of the number of years until the borrow index
+    /// exceeds `MAX_BORROW_INDEX_BITS` at the current borrow rate, and the rounding drift between the total borrows and the
+    /// sum of the borrows of a sampled page of accounts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `accounts` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - The estimation is `None` if the borrow rate is zero.
+    /// - The rounding drift is the absolute difference between the total borrows and the sampled borrows. Hence, it only
+    ///   represents the accumulated rounding drift if the page includes all borrowers.
+    /// - It does not accrue interest.
+    ///
+    #[view(getBorrowIndexAudit)]
+    fn get_borrow_index_audit(&self, accounts: MultiValueEncoded<ManagedAddress>) -> BorrowIndexAudit<Self::Api> {
+        let borrow_index = self.get_borrow_index();
+        let borrow_index_bits = borrow_index.log2();
+
+        // the borrow index grows exponentially with the borrow rate, i.e. each bit requires ln(2) / rate seconds
+        let borrow_rate = self.borrow_rate().get();
+        let years_to_overflow = if borrow_rate == BigUint::zero() {
+            None
+        } else {
+            let headroom_bits = MAX_BORROW_INDEX_BITS.saturating_sub(borrow_index_bits);
+            let years = BigUint::from(headroom_bits) * LN_2 / (borrow_rate * SECONDS_PER_YEAR);
+            Some(years.to_u64().unwrap_or(u64::MAX))
+        };
+
+        let total_borrows = self.total_borrows().get();
+        let mut sampled_borrows = BigUint::zero();
+        let mut sampled_accounts = 0usize;
+        for account in accounts.into_iter() {
+            sampled_borrows += self.get_account_borrow_amount(&account);
+            sampled_accounts += 1;
+        }
+
+        let rounding_drift = if total_borrows >= sampled_borrows { &total_borrows - &sampled_borrows } else { &sampled_borrows - &total_borrows };
+
+        BorrowIndexAudit {
+            borrow_index,
+            borrow_index_bits,
+            years_to_overflow,
+            total_borrows,
+            sampled_borrows,
+            sampled_accounts,
+            rounding_drift,
+        }
+    }
+
     /// Returns the exchange rate between underlying and tokens (collateral). Since USH will be used as collateral, the
     /// exchange rate is fixed to one.
     ///
@@ -650,15 +982,19 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         // get current market index
         let market_index = self.get_borrow_index();
 
+        // the market index in the scale of the account snapshot, which might predate borrow index rebases
+        let account_market_index = &market_index * &self.get_account_pending_rebase_divisor(borrower);
+
         // compute account borrow amounts
         let opt_snapshot = self.get_account_borrow_snapshot(borrower);
         let (account_index, current_borrow, old_borrow, old_discount) = match opt_snapshot {
             Some(snapshot) => {
                 let AccountSnapshot { borrow_amount: old_borrow, borrow_index: account_index, discount: old_discount } = snapshot;
-                let current_borrow = &old_borrow * &(&market_index * &(&wad - &old_discount) / &account_index + &old_discount) / &wad;
+                let borrow_factor = self.mul_div(&account_market_index, &(&wad - &old_discount), &account_index, Rounding::Down) + &old_discount;
+                let current_borrow = self.wad_mul(&old_borrow, &borrow_factor);
                 (account_index, current_borrow, old_borrow, old_discount)
             },
-            None => (market_index.clone(), BigUint::zero(), BigUint::zero(), BigUint::zero()),
+            None => (account_market_index.clone(), BigUint::zero(), BigUint::zero(), BigUint::zero()),
         };
 
         let (ush_effective_amount, new_borrow, total_borrows) = match interaction_type {
@@ -734,12 +1070,12 @@ pub trait CommonsModule: events::EventsModule + proxies::ProxyModule + storage::
         let mut effective_borrows = self.effective_borrows().get();
 
         // positive contribution
-        effective_borrows += (&wad - &discount) * &new_borrow / &wad;
+        effective_borrows += self.wad_mul(&(&wad - &discount), &new_borrow);
 
-        // negative contribution
-        let den = account_index * &wad;
-        let num = (wad - old_discount) * market_index * old_borrow;
-        effective_borrows -= BigUint::min(effective_borrows.clone(), self.ceil_div(num, den));
+        // negative contribution, rounded up
+        let old_borrow_eff = self.mul_div(&old_borrow, &account_market_index, &account_index, Rounding::Up);
+        let old_contribution = self.wad_mul_up(&(wad - old_discount), &old_borrow_eff);
+        effective_borrows -= BigUint::min(effective_borrows.clone(), old_contribution);
 
         self.effective_borrows().set(&effective_borrows);
 
@@ -1,8 +1,5 @@
-/// The BPS unit
-pub const BPS: u64 = 10_000;
-
-/// The WAD unit
-pub const WAD: u64 = 1_000_000_000_000_000_000;
+/// The BPS and WAD units, defined at the math crate
+pub use math::constants::{BPS, WAD};
 
 /// The USH decimals
 pub const USH_DECIMALS: usize = 18;
@@ -22,9 +19,21 @@ pub const EXCHANGE_RATE: u128 = (10 as u128).pow(USH_DECIMALS as u32) * WAD as u
 /// The maximum accrual time threshold allowed (1 day)
 pub const MAX_ACCRUAL_TIME_THRESHOLD: u64 = 86400;
 
+/// The minimum non-zero maximum accrual gap allowed (1 hour)
+pub const MIN_MAX_ACCRUAL_GAP: u64 = 3600;
+
 /// The amount of seconds in a year
 pub const SECONDS_PER_YEAR: u64 = 31_556_926;
 
+/// The amount of seconds in a day
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The borrow index magnitude (in bits) beyond which an index rebase should be planned
+pub const MAX_BORROW_INDEX_BITS: u32 = 128;
+
+/// The natural logarithm of 2 in wad
+pub const LN_2: u64 = 693_147_180_559_945_309;
+
 /// The maximum initial borrow rate allowed in wad (100% APR)
 pub const MAX_INITIAL_BORROW_RATE: u64 = WAD / SECONDS_PER_YEAR;
 
@@ -34,8 +43,20 @@ pub const BORROW_RATE_DELAY: u64 = 86400;
 /// The maximum borrow rate change allowed in bps (10%)
 pub const MAX_BORROW_RATE_CHANGE: u64 = 1_000;
 
+/// The trusted minter interface version required at whitelisting, which includes the mint on behalf callback
+pub const TRUSTED_MINTER_INTERFACE_VERSION: u8 = 1;
+
+/// The minimum time that has to elapse between stake factor updates (1 day)
+pub const STAKE_FACTOR_DELAY: u64 = 86400;
+
+/// The maximum stake factor change allowed per update in wad (10%)
+pub const MAX_STAKE_FACTOR_CHANGE: u64 = 100_000_000_000_000_000;
+
 /// The minimum close factor allowed (20%)
 pub const MIN_CLOSE_FACTOR: u64 = 200_000_000_000_000_000;
 
 /// The minimum liquidation incentive allowed (101%)
 pub const MIN_LIQUIDATION_INCENTIVE: u64 = 1_010_000_000_000_000_000;
+
+/// The maximum length of the purpose tag of a reserves withdrawal
+pub const MAX_RESERVES_PURPOSE_LENGTH: usize = 32;
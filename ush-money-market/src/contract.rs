@@ -5,6 +5,8 @@ multiversx_sc::imports!();
 pub mod ush_money_market_proxy;
 
 pub use admin;
+pub use math;
+pub use pausable;
 
 pub mod borrow;
 pub mod commons;
@@ -25,7 +27,7 @@ pub mod storage;
 use crate::{constants::*, errors::*, storage::State};
 
 #[multiversx_sc::contract]
-pub trait UshMoneyMarket: admin::AdminModule + borrow::BorrowModule + commons::CommonsModule + events::EventsModule + governance::GovernanceModule + liquidate::LiquidateModule + mint::MintModule + observer::ObserverModule + proxies::ProxyModule + redeem::RedeemModule + repay_borrow::RepayBorrowModule + seize::SeizeModule + staking::StakingModule + storage::StorageModule {
+pub trait UshMoneyMarket: admin::AdminModule + math::MathModule + pausable::PausableModule + pausable::PausableViewModule + borrow::BorrowModule + commons::CommonsModule + events::EventsModule + governance::GovernanceModule + liquidate::LiquidateModule + mint::MintModule + observer::ObserverModule + proxies::ProxyModule + redeem::RedeemModule + repay_borrow::RepayBorrowModule + seize::SeizeModule + staking::StakingModule + storage::StorageModule {
     /// Initializes the USH Money Market.
     ///
     /// # Arguments:
@@ -37,12 +39,16 @@ pub trait UshMoneyMarket: admin::AdminModule + borrow::BorrowModule + commons::C
     /// Notes:
     ///
     /// - If the admin address is not provided, the admin will be set as the deployer.
+    /// - The pause guardian is read live from the Controller.
     ///
     #[init]
     fn init(&self, controller: ManagedAddress, ush_minter: ManagedAddress, opt_admin: OptionalValue<ManagedAddress>) {
         // set controller
         self.set_controller(&controller);
 
+        // the pause guardian is the one of the Controller
+        self.pause_guardian_source().set(&controller);
+
         // set USH minter
         self.set_ush_minter(&ush_minter);
 
@@ -56,8 +62,23 @@ pub trait UshMoneyMarket: admin::AdminModule + borrow::BorrowModule + commons::C
         self.set_ush_market_state_internal(State::Inactive);
     }
 
+    /// Upgrades the USH Money Market.
+    ///
+    /// # Notes:
+    ///
+    /// - From now on, the pause guardian is read live from the Controller.
+    /// - A stake factor set before the upgrade is flagged as initialized, such that its changes remain capped.
+    ///
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.pause_guardian().clear();
+        self.pause_guardian_source().set(self.controller().get());
+
+        // stake factors set before the upgrade are flagged by their last update
+        if !self.last_stake_factor_update().is_empty() {
+            self.stake_factor_initialized().set(true);
+        }
+    }
 
     /// Issues Hatom USH.
     ///
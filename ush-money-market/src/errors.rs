@@ -11,12 +11,20 @@ pub const ERROR_UNDEFINED_USH_MINTER_SC: &[u8] = b"undefined minter smart contra
 pub const ERROR_UNDEFINED_DISCOUNT_RATE_MODEL: &[u8] = b"undefined Discount Rate Model smart contract";
 pub const ERROR_UNDEFINED_STAKING_SC: &[u8] = b"undefined Staking smart contract";
 pub const ERROR_STAKE_FACTOR_TOO_HIGH: &[u8] = b"stake factor too high";
+pub const ERROR_STAKE_FACTOR_UPDATE_TOO_SOON: &[u8] = b"stake factor update too soon";
+pub const ERROR_INVALID_STAKE_FACTOR_UPDATE: &[u8] = b"invalid stake factor update";
+pub const ERROR_UNDEFINED_STAKE_FACTOR_SCHEDULE: &[u8] = b"undefined stake factor schedule";
 pub const ERROR_PROTOCOL_SEIZE_SHARE_TOO_HIGH: &[u8] = b"protocol seize share too high";
+pub const ERROR_INSURANCE_FUND_SHARE_TOO_HIGH: &[u8] = b"insurance fund share too high";
+pub const ERROR_INSURANCE_FUND_NOT_SET: &[u8] = b"insurance fund not set";
 pub const ERROR_LIQUIDATION_INCENTIVE_TOO_HIGH: &[u8] = b"liquidity incentive too high";
 pub const ERROR_LIQUIDATION_INCENTIVE_TOO_LOW: &[u8] = b"liquidity incentive too low";
 pub const ERROR_CLOSE_FACTOR_TOO_HIGH: &[u8] = b"close factor too high";
 pub const ERROR_CLOSE_FACTOR_TOO_LOW: &[u8] = b"close factor too low";
 pub const ERROR_ACCRUAL_TIME_THRESHOLD_TOO_HIGH: &[u8] = b"accrual time threshold too high";
+pub const ERROR_MAX_ACCRUAL_GAP_TOO_LOW: &[u8] = b"maximum accrual gap too low";
+pub const ERROR_NO_ABNORMAL_ACCRUAL_GAP: &[u8] = b"no abnormal accrual gap";
+pub const ERROR_ACCRUAL_GAP_RESTRICTION: &[u8] = b"restricted after an abnormal accrual gap";
 pub const ERROR_INVALID_USH_MINTER_SC: &[u8] = b"invalid USH minter smart contract";
 pub const ERROR_INVALID_DISCOUNT_RATE_MODEL_SC: &[u8] = b"invalid interest rate model smart contract";
 pub const ERROR_INVALID_CONTROLLER_SC: &[u8] = b"invalid controller smart contract";
@@ -32,7 +40,6 @@ pub const ERROR_ISSUE_HATOM_USH_FIRST: &[u8] = b"must issue Hatom USH first";
 pub const ERROR_HATOM_USH_ONGOING_ISSUANCE: &[u8] = b"Hatom USH is being issued";
 pub const ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO: &[u8] = b"amount has to be greater than zero";
 pub const ERROR_AMOUNT_MUST_BE_ZERO: &[u8] = b"amount has to be zero";
-pub const ERROR_DIVISION_BY_ZERO: &[u8] = b"division by zero";
 pub const ERROR_CANNOT_LIQUIDATE_YOURSELF: &[u8] = b"cannot liquidate yourself";
 pub const ERROR_TOO_MUCH_LIQUIDATION: &[u8] = b"too much liquidation, the borrower does not have enough collateral to seize";
 pub const ERROR_NOT_ENOUGH_SEIZED_TOKENS: &[u8] = b"not enough seized tokens";
@@ -56,4 +63,22 @@ pub const ERROR_MARKET_HAS_BORROWERS: &[u8] = b"market still has borrowers";
 pub const ERROR_ACCOUNT_NOT_BORROWER: &[u8] = b"account is not a borrower";
 pub const ERROR_NOT_A_TRUSTED_MINTER: &[u8] = b"not a trusted minter";
 pub const ERROR_ALREADY_TRUSTED_MINTER: &[u8] = b"minter has already been trusted";
+pub const ERROR_INVALID_TRUSTED_MINTER_VERSION: &[u8] = b"invalid trusted minter interface version";
 pub const ERROR_UNEXPECTED_MARKET_AT_DISCOUNT_RATE_MODEL_SC: &[u8] = b"unexpected market at Discount Rate Model smart contract";
+pub const ERROR_MINT_PAUSED: &[u8] = b"USH minting is paused";
+pub const ERROR_ONLY_PROTOCOL_LIQUIDATOR: &[u8] = b"only the protocol liquidator allowed";
+pub const ERROR_INVALID_COLLATERAL_MARKET: &[u8] = b"invalid collateral market";
+pub const ERROR_REPAYMENT_EXCEEDS_BORROW: &[u8] = b"repayment exceeds borrow amount";
+pub const ERROR_INVALID_SWAP_PATH: &[u8] = b"invalid swap path";
+pub const ERROR_UNEXPECTED_SWAP_AMOUNT: &[u8] = b"unexpected swap amount";
+pub const ERROR_NOT_ENOUGH_USH_FROM_COLLATERAL: &[u8] = b"seized collateral swapped into not enough USH";
+pub const ERROR_ROUTER_NOT_INITIALIZED: &[u8] = b"router has not been initialized";
+pub const ERROR_EGLD_WRAPPER_NOT_INITIALIZED: &[u8] = b"EGLD wrapper has not been initialized";
+pub const ERROR_PRICE_ORACLE_NOT_INITIALIZED: &[u8] = b"price oracle has not been initialized";
+pub const ERROR_ORACLE_FAILED_RETRIEVE_TOKEN_PRICE: &[u8] = b"price oracle failed to retrieve the token price";
+pub const ERROR_INVALID_RESERVES_DESTINATION: &[u8] = b"invalid reserves destination";
+pub const ERROR_RESERVES_DESTINATION_ALREADY_SET: &[u8] = b"reserves destination already set";
+pub const ERROR_INVALID_RESERVES_PURPOSE: &[u8] = b"invalid reserves purpose";
+pub const ERROR_UNREGISTERED_READER: &[u8] = b"unregistered reader";
+pub const ERROR_NO_RESERVES_WITHDRAWALS: &[u8] = b"no reserves withdrawals";
+pub const ERROR_INVALID_BORROW_INDEX_REBASE: &[u8] = b"invalid borrow index rebase";
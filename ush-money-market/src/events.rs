@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use super::storage::State;
+use super::storage::{State, TrustedMinterMetadata};
 
 #[multiversx_sc::module]
 pub trait EventsModule {
@@ -52,18 +52,28 @@ pub trait EventsModule {
     #[event("liquidate_borrow_event")]
     fn liquidate_borrow_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] collateral_market: &ManagedAddress, #[indexed] tokens: &BigUint);
 
+    /// Event emitted when a liquidation repayment exceeds the maximum allowed by the close factor and the excess is refunded
+    /// to the liquidator.
+    #[event("liquidation_repayment_refund_event")]
+    fn liquidation_repayment_refund_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] refund_amount: &BigUint);
+
     /// Event emitted when a borrower repays some borrowed underlying.
     #[event("repay_borrow_event")]
     fn repay_borrow_event(&self, #[indexed] payer: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] account_borrow: &BigUint, #[indexed] total_borrows: &BigUint);
 
-    /// Event emitted when interest is accrued on the money market.
+    /// Event emitted when interest is accrued on the money market. All the accrued interest goes to the reserves, which are
+    /// split into staking rewards and revenue. There are no suppliers, hence there is no supply rate nor utilization.
     #[event("accrue_interest_event")]
-    fn accrue_interest_event(&self, #[indexed] delta_borrows: &BigUint, #[indexed] borrow_index: &BigUint, #[indexed] total_borrows: &BigUint);
+    fn accrue_interest_event(&self, #[indexed] delta_borrows: &BigUint, #[indexed] borrow_index: &BigUint, #[indexed] total_borrows: &BigUint, #[indexed] borrow_rate: &BigUint, #[indexed] delta_rewards: &BigUint, #[indexed] delta_revenue: &BigUint);
 
     /// Event emitted when the stake factor is updated.
     #[event("set_stake_factor_event")]
     fn set_stake_factor_event(&self, #[indexed] stake_factor: &BigUint);
 
+    /// Event emitted when a gradual stake factor change is scheduled.
+    #[event("set_target_stake_factor_event")]
+    fn set_target_stake_factor_event(&self, #[indexed] target_stake_factor: &BigUint);
+
     /// Event emitted when the staking contract address is updated.
     #[event("set_staking_contract_event")]
     fn set_staking_contract_event(&self, #[indexed] staking_sc: &ManagedAddress);
@@ -92,14 +102,54 @@ pub trait EventsModule {
     #[event("set_protocol_seize_share_event")]
     fn set_protocol_seize_share_event(&self, #[indexed] protocol_seize_share: &BigUint);
 
+    /// Event emitted when the insurance fund or its share of the protocol seized amount is updated.
+    #[event("set_insurance_fund_event")]
+    fn set_insurance_fund_event(&self, #[indexed] insurance_fund: &ManagedAddress, #[indexed] insurance_fund_share: &BigUint);
+
+    /// Event emitted when the protocol seized amount is split between the insurance fund and the reserves.
+    #[event("protocol_seize_split_event")]
+    fn protocol_seize_split_event(&self, #[indexed] insurance_fund: &ManagedAddress, #[indexed] insurance_fund_amount: &BigUint, #[indexed] reserves_amount: &BigUint);
+
+    /// Event emitted when the accrued insurance fund balance is paid out.
+    #[event("insurance_fund_claimed_event")]
+    fn insurance_fund_claimed_event(&self, #[indexed] insurance_fund: &ManagedAddress, #[indexed] amount: &BigUint);
+
     /// Event emitted when accrual time threshold is updated.
     #[event("set_borrow_rate_event")]
     fn set_borrow_rate_event(&self, #[indexed] borrow_rate: &BigUint);
 
+    /// Event emitted when the borrow rate is updated, exposing the projected annualized revenue before and after the update.
+    #[event("borrow_rate_revenue_impact_event")]
+    fn borrow_rate_revenue_impact_event(&self, #[indexed] old_annual_revenue: &BigUint, #[indexed] new_annual_revenue: &BigUint);
+
     /// Event emitted when accrual time threshold is updated.
     #[event("set_accrual_time_threshold_event")]
     fn set_accrual_time_threshold_event(&self, #[indexed] accrual_time_threshold: u64);
 
+    /// Event emitted when the maximum accrual gap is updated.
+    #[event("set_max_accrual_gap_event")]
+    fn set_max_accrual_gap_event(&self, #[indexed] max_accrual_gap: u64);
+
+    /// Event emitted when an abnormal gap between the last accrual and the current block timestamp is detected.
+    #[event("abnormal_accrual_gap_event")]
+    fn abnormal_accrual_gap_event(&self, #[indexed] accrual_timestamp: u64, #[indexed] timestamp: u64);
+
+    /// Event emitted when governance resolves the pending abnormal accrual gap, either charging or skipping its interest.
+    #[event("accrual_gap_acknowledged_event")]
+    fn accrual_gap_acknowledged_event(&self, #[indexed] accrual_gap_timestamp: u64, #[indexed] accrual_gap_duration: u64, #[indexed] charge: bool, delta_borrows: &BigUint);
+
+    /// Event emitted when USH minting is paused or unpaused.
+    #[event("mint_paused_event")]
+    fn mint_paused_event(&self, #[indexed] pause: bool);
+
+    /// Event emitted when the protocol liquidator is set or removed.
+    #[event("set_protocol_liquidator_event")]
+    fn set_protocol_liquidator_event(&self, #[indexed] protocol_liquidator: &Option<ManagedAddress>);
+
+    /// Event emitted when the collateral seized by the protocol liquidator is swapped into USH and burned.
+    #[event("liquidate_borrow_and_burn_event")]
+    fn liquidate_borrow_and_burn_event(&self, #[indexed] liquidator: &ManagedAddress, #[indexed] borrower: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] collateral_market: &ManagedAddress, #[indexed] underlying_amount: &BigUint, #[indexed] ush_amount: &BigUint, #[indexed] surplus: &BigUint);
+
     /// Emitted when a trusted minter is added.
     #[event("add_trusted_minter_event")]
     fn add_trusted_minter_event(&self, #[indexed] minter: &ManagedAddress);
@@ -107,4 +157,28 @@ pub trait EventsModule {
     /// Emitted when a trusted minter is removed.
     #[event("remove_trusted_minter_event")]
     fn remove_trusted_minter_event(&self, #[indexed] minter: &ManagedAddress);
+
+    /// Emitted when the metadata of a trusted minter is set.
+    #[event("trusted_minter_metadata_event")]
+    fn trusted_minter_metadata_event(&self, #[indexed] minter: &ManagedAddress, #[indexed] metadata: &TrustedMinterMetadata<Self::Api>);
+
+    /// Emitted when a reserves destination is added.
+    #[event("add_reserves_destination_event")]
+    fn add_reserves_destination_event(&self, #[indexed] destination: &ManagedAddress);
+
+    /// Emitted when a reserves destination is removed.
+    #[event("remove_reserves_destination_event")]
+    fn remove_reserves_destination_event(&self, #[indexed] destination: &ManagedAddress);
+
+    /// Emitted when reserves are withdrawn to a configured destination with a purpose tag.
+    #[event("reserves_withdrawn_with_purpose_event")]
+    fn reserves_withdrawn_with_purpose_event(&self, #[indexed] destination: &ManagedAddress, #[indexed] amount: &BigUint, #[indexed] purpose: &ManagedBuffer);
+
+    /// Emitted when the borrow index is rebased.
+    #[event("borrow_index_rebased_event")]
+    fn borrow_index_rebased_event(&self, #[indexed] divisor: &BigUint, #[indexed] old_index: &BigUint, #[indexed] new_index: &BigUint);
+
+    /// Emitted when a page of borrow snapshots is migrated to the latest borrow index rebase.
+    #[event("borrow_snapshots_rebased_event")]
+    fn borrow_snapshots_rebased_event(&self, #[indexed] count: usize);
 }
@@ -1,9 +1,17 @@
 multiversx_sc::imports!();
 
-use super::{commons, constants::*, errors::*, events, proxies, storage, storage::State};
+use super::{
+    commons,
+    constants::*,
+    errors::*,
+    events, proxies,
+    storage::{self, DiscountStrategy, InteractionType, State},
+};
+
+use controller::storage::Status;
 
 #[multiversx_sc::module]
-pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait GovernanceModule: admin::AdminModule + pausable::PausableModule + commons::CommonsModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
     /// Activates the USH Money Market.
     ///
     /// # Notes:
@@ -75,6 +83,9 @@ pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events
     ///
     /// - Can only be called by the admin.
     /// - The new stake factor must not exceed the maximum allowed.
+    /// - The stake factor cannot increase nor decrease too much, unless it is set for the first time.
+    /// - There is a time delay between stake factor updates.
+    /// - Cancels any scheduled stake factor change.
     ///
     #[endpoint(setStakeFactor)]
     fn set_stake_factor(&self, stake_factor: BigUint) {
@@ -82,10 +93,86 @@ pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events
 
         require!(stake_factor <= BigUint::from(WAD), ERROR_STAKE_FACTOR_TOO_HIGH);
 
+        // the first stake factor is not capped
+        if self.stake_factor_initialized().get() {
+            let old_stake_factor = self.stake_factor().get();
+            require!(self.is_stake_factor_change_allowed(&old_stake_factor, &stake_factor), ERROR_INVALID_STAKE_FACTOR_UPDATE);
+        }
+
+        self.target_stake_factor().clear();
+        self.set_stake_factor_internal(stake_factor);
+    }
+
+    /// Schedules a gradual stake factor change, which is reached by steps of at most the maximum allowed stake factor change
+    /// and separated by at least the stake factor delay.
+    ///
+    /// # Arguments:
+    ///
+    /// - `target_stake_factor` - The stake factor to be reached in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The target stake factor must not exceed the maximum allowed.
+    /// - Steps are performed via `updateStakeFactor`.
+    ///
+    #[endpoint(setTargetStakeFactor)]
+    fn set_target_stake_factor(&self, target_stake_factor: BigUint) {
+        self.require_admin();
+
+        require!(target_stake_factor <= BigUint::from(WAD), ERROR_STAKE_FACTOR_TOO_HIGH);
+
+        self.target_stake_factor().set(&target_stake_factor);
+
+        self.set_target_stake_factor_event(&target_stake_factor);
+    }
+
+    /// Performs a single step towards the scheduled stake factor.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - The schedule is removed once the target stake factor is reached.
+    /// - If the stake factor has never been set, the target stake factor is reached in a single step.
+    ///
+    #[endpoint(updateStakeFactor)]
+    fn update_stake_factor(&self) {
+        require!(!self.target_stake_factor().is_empty(), ERROR_UNDEFINED_STAKE_FACTOR_SCHEDULE);
+
+        let target_stake_factor = self.target_stake_factor().get();
+        let old_stake_factor = self.stake_factor().get();
+        let max_change = BigUint::from(MAX_STAKE_FACTOR_CHANGE);
+
+        let stake_factor = if !self.stake_factor_initialized().get() {
+            // the first stake factor is not capped
+            target_stake_factor.clone()
+        } else if target_stake_factor > old_stake_factor {
+            BigUint::min(target_stake_factor.clone(), old_stake_factor + max_change)
+        } else if &old_stake_factor - &target_stake_factor > max_change {
+            old_stake_factor - max_change
+        } else {
+            target_stake_factor.clone()
+        };
+
+        if stake_factor == target_stake_factor {
+            self.target_stake_factor().clear();
+        }
+
+        self.set_stake_factor_internal(stake_factor);
+    }
+
+    fn set_stake_factor_internal(&self, stake_factor: BigUint) {
+        let timestamp = self.blockchain().get_block_timestamp();
+        if !self.last_stake_factor_update().is_empty() {
+            require!(timestamp - self.last_stake_factor_update().get() >= STAKE_FACTOR_DELAY, ERROR_STAKE_FACTOR_UPDATE_TOO_SOON);
+        }
+
         self.accrue_interest();
         self.require_market_fresh();
 
         self.stake_factor().set(&stake_factor);
+        self.stake_factor_initialized().set(true);
+        self.last_stake_factor_update().set(timestamp);
 
         self.set_stake_factor_event(&stake_factor);
     }
@@ -165,6 +252,58 @@ pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events
         self.set_protocol_seize_share_event(&protocol_seize_share);
     }
 
+    /// Updates the insurance fund address and the share of the protocol seized amount that is routed to it, i.e. instead of
+    /// staying in the market reserves.
+    ///
+    /// # Arguments
+    ///
+    /// - `insurance_fund` - The insurance fund address.
+    /// - `insurance_fund_share` - The share of the protocol seized amount routed to the insurance fund in wad.
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - the share cannot exceed 100%
+    ///
+    #[endpoint(setInsuranceFund)]
+    fn set_insurance_fund(&self, insurance_fund: ManagedAddress, insurance_fund_share: BigUint) {
+        self.require_admin();
+
+        require!(!insurance_fund.is_zero(), ERROR_CANNOT_BE_ADDRESS_ZERO);
+        require!(insurance_fund_share <= BigUint::from(WAD), ERROR_INSURANCE_FUND_SHARE_TOO_HIGH);
+
+        self.insurance_fund().set(&insurance_fund);
+        self.insurance_fund_share().set(&insurance_fund_share);
+
+        self.set_insurance_fund_event(&insurance_fund, &insurance_fund_share);
+    }
+
+    /// Transfers the USH amount accrued for the insurance fund during liquidations to the insurance fund.
+    ///
+    /// # Notes
+    ///
+    /// - can be called by anyone
+    /// - the accrued USH is held by the money market until claimed
+    ///
+    #[endpoint(claimInsuranceFund)]
+    fn claim_insurance_fund(&self) -> BigUint {
+        require!(!self.insurance_fund().is_empty(), ERROR_INSURANCE_FUND_NOT_SET);
+
+        let insurance_fund = self.insurance_fund().get();
+        let amount = self.insurance_fund_balance().take();
+
+        if amount == BigUint::zero() {
+            return amount;
+        }
+
+        let ush_id = self.ush_id().get();
+        self.send().direct_esdt(&insurance_fund, &ush_id, 0, &amount);
+
+        self.insurance_fund_claimed_event(&insurance_fund, &amount);
+
+        amount
+    }
+
     /// Updates the borrow rate.
     ///
     /// # Arguments
@@ -205,10 +344,14 @@ pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events
         self.accrue_interest();
         self.require_market_fresh();
 
+        let old_annual_revenue = self.get_projected_annual_revenue(&self.borrow_rate().get());
+        let new_annual_revenue = self.get_projected_annual_revenue(&borrow_rate);
+
         self.borrow_rate().set(&borrow_rate);
         self.last_borrow_rate_update().set(timestamp);
 
         self.set_borrow_rate_event(&borrow_rate);
+        self.borrow_rate_revenue_impact_event(&old_annual_revenue, &new_annual_revenue);
     }
 
     /// Updates the Discount Rate Model.
@@ -258,22 +401,99 @@ pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events
         self.accrue_interest();
         self.require_market_fresh();
 
-        let revenue = self.revenue().get();
-        let ush_amount = opt_ush_amount.into_option().unwrap_or_else(|| revenue.clone());
+        let ush_amount = opt_ush_amount.into_option().unwrap_or_else(|| self.revenue().get());
+
+        // mint USH to the admin
+        let admin = self.get_admin();
+        self.reduce_reserves_internal(&admin, &ush_amount);
+    }
 
-        require!(ush_amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+    /// Withdraws several amounts of USH from the money market reserves (revenue part) to configured destinations, each of
+    /// them tagged with a purpose, such that a single governance action leaves an auditable purpose trail.
+    ///
+    /// # Arguments:
+    ///
+    /// - `withdrawals` - The (destination, USH amount, purpose tag) entries.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Each destination must have been configured with `addReservesDestination`.
+    /// - Each purpose tag must be non-empty and not longer than `MAX_RESERVES_PURPOSE_LENGTH`.
+    /// - The USH amounts are minted to the destinations.
+    ///
+    #[endpoint(reduceReservesTo)]
+    fn reduce_reserves_to(&self, withdrawals: MultiValueEncoded<MultiValue3<ManagedAddress, BigUint, ManagedBuffer>>) {
+        self.require_admin();
+        require!(!withdrawals.is_empty(), ERROR_NO_RESERVES_WITHDRAWALS);
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        for withdrawal in withdrawals.into_iter() {
+            let (destination, ush_amount, purpose) = withdrawal.into_tuple();
+            self.require_valid_reserves_withdrawal(&destination, &purpose);
+            self.reduce_reserves_internal(&destination, &ush_amount);
+            self.reserves_withdrawn_with_purpose_event(&destination, &ush_amount, &purpose);
+        }
+    }
+
+    fn reduce_reserves_internal(&self, to: &ManagedAddress, ush_amount: &BigUint) {
+        require!(ush_amount > &BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
 
-        require!(ush_amount <= revenue, ERROR_AMOUNT_EXCEEDS_REVENUE);
+        let revenue = self.revenue().get();
+        require!(ush_amount <= &revenue, ERROR_AMOUNT_EXCEEDS_REVENUE);
 
         // update reserves and revenue
-        self.total_reserves().update(|amount| *amount -= &ush_amount);
-        self.revenue().update(|amount| *amount -= &ush_amount);
+        self.total_reserves().update(|amount| *amount -= ush_amount);
+        self.revenue().update(|amount| *amount -= ush_amount);
 
-        // mint USH to the admin
-        let admin = self.get_admin();
-        self.ush_minter_mint(&ush_amount, OptionalValue::Some(admin));
+        self.ush_minter_mint(ush_amount, OptionalValue::Some(to.clone()));
+
+        self.reserves_reduced_event(ush_amount);
+    }
 
-        self.reserves_reduced_event(&ush_amount);
+    /// Adds a destination to which reserves can be withdrawn with a purpose tag, such as the insurance pool, the treasury or
+    /// a buyback program.
+    ///
+    /// # Arguments:
+    ///
+    /// - `destination` - The destination address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(addReservesDestination)]
+    fn add_reserves_destination(&self, destination: &ManagedAddress) {
+        self.require_admin();
+        require!(!destination.is_zero(), ERROR_INVALID_RESERVES_DESTINATION);
+        require!(self.reserves_destinations().insert(destination.clone()), ERROR_RESERVES_DESTINATION_ALREADY_SET);
+        self.add_reserves_destination_event(destination);
+    }
+
+    /// Removes a destination to which reserves can be withdrawn with a purpose tag.
+    ///
+    /// # Arguments:
+    ///
+    /// - `destination` - The destination address.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(removeReservesDestination)]
+    fn remove_reserves_destination(&self, destination: &ManagedAddress) {
+        self.require_admin();
+        require!(self.reserves_destinations().swap_remove(destination), ERROR_INVALID_RESERVES_DESTINATION);
+        self.remove_reserves_destination_event(destination);
+    }
+
+    /// Requires that a reserves withdrawal is directed to a configured destination and is tagged with a valid purpose.
+    ///
+    fn require_valid_reserves_withdrawal(&self, destination: &ManagedAddress, purpose: &ManagedBuffer) {
+        require!(self.reserves_destinations().contains(destination), ERROR_INVALID_RESERVES_DESTINATION);
+        require!(!purpose.is_empty() && purpose.len() <= MAX_RESERVES_PURPOSE_LENGTH, ERROR_INVALID_RESERVES_PURPOSE);
     }
 
     /// Updates the accrual time threshold.
@@ -300,6 +520,66 @@ pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events
         self.set_accrual_time_threshold_event(accrual_time_threshold);
     }
 
+    /// Updates the maximum accrual gap, i.e. the maximum time allowed between accruals before the gap is considered abnormal,
+    /// in which case no interest is charged for it and borrows are restricted for a while.
+    ///
+    /// # Arguments:
+    ///
+    /// - `max_accrual_gap` - The new maximum accrual gap in seconds. Zero disables the check.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(setMaxAccrualGap)]
+    fn set_max_accrual_gap(&self, max_accrual_gap: u64) {
+        self.require_admin();
+
+        require!(max_accrual_gap == 0 || max_accrual_gap >= MIN_MAX_ACCRUAL_GAP, ERROR_MAX_ACCRUAL_GAP_TOO_LOW);
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        self.max_accrual_gap().set(max_accrual_gap);
+
+        self.set_max_accrual_gap_event(max_accrual_gap);
+    }
+
+    /// Resolves the pending abnormal accrual gap, which lifts the borrows restriction. Governance explicitly decides whether
+    /// the interest of the gap, computed with the borrow rate in place when it was recorded, is charged or skipped.
+    ///
+    /// # Arguments:
+    ///
+    /// - `charge` - Whether the interest of the pending gap is charged to borrowers.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrows remain restricted until the pending gap is resolved, while repayments and liquidations are not.
+    ///
+    #[endpoint(acknowledgeAccrualGap)]
+    fn acknowledge_accrual_gap(&self, charge: bool) {
+        self.require_admin();
+
+        // a pending gap is recorded first
+        self.accrue_interest();
+
+        require!(!self.accrual_gap_timestamp().is_empty(), ERROR_NO_ABNORMAL_ACCRUAL_GAP);
+
+        let accrual_gap_timestamp = self.accrual_gap_timestamp().take();
+        let accrual_gap_duration = self.accrual_gap_duration().take();
+        let accrual_gap_interest_factor = self.accrual_gap_interest_factor().take();
+
+        let delta_borrows = if charge {
+            let (delta_borrows, ..) = self.accumulate_interest(&accrual_gap_interest_factor);
+            delta_borrows
+        } else {
+            BigUint::zero()
+        };
+
+        self.accrual_gap_acknowledged_event(accrual_gap_timestamp, accrual_gap_duration, charge, &delta_borrows);
+    }
+
     /// Whitelists a trusted minter contract, i.e. a contract that can mint and enter market in the name of someone else.
     ///
     /// # Arguments:
@@ -311,14 +591,81 @@ pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events
     /// - can only be called by the admin
     /// - `trusted_minter` must be a trusted smart contract
     /// - `trusted_minter` must not be already trusted
+    /// - `trusted_minter` must implement the current trusted minter interface version, including the mint on behalf callback
     ///
     #[endpoint(addTrustedMinter)]
     fn add_trusted_minter(&self, trusted_minter: ManagedAddress) {
         self.require_admin();
-        self.require_not_trusted_minter(&trusted_minter);
-        require!(self.is_trusted_minter_sc(&trusted_minter), ERROR_INVALID_TRUSTED_MINTER_SC);
-        self.trusted_minters_list().add(&trusted_minter);
-        self.add_trusted_minter_event(&trusted_minter);
+        self.add_trusted_minter_internal(&trusted_minter, &ManagedBuffer::new());
+    }
+
+    /// Whitelists a batch of trusted minter contracts, together with their name hashes. Interface versions are retrieved
+    /// from the trusted minters themselves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minters` - the new trusted minters to whitelist, each one with its name hash
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - each trusted minter must be a trusted smart contract
+    /// - each trusted minter must not be already trusted
+    /// - each trusted minter must implement the current trusted minter interface version
+    ///
+    #[endpoint(addTrustedMinters)]
+    fn add_trusted_minters(&self, trusted_minters: MultiValueEncoded<MultiValue2<ManagedAddress, ManagedBuffer>>) {
+        self.require_admin();
+        for trusted_minter in trusted_minters.into_iter() {
+            let (trusted_minter, name_hash) = trusted_minter.into_tuple();
+            self.add_trusted_minter_internal(&trusted_minter, &name_hash);
+        }
+    }
+
+    fn add_trusted_minter_internal(&self, trusted_minter: &ManagedAddress, name_hash: &ManagedBuffer) {
+        self.require_not_trusted_minter(trusted_minter);
+        require!(self.is_trusted_minter_sc(trusted_minter), ERROR_INVALID_TRUSTED_MINTER_SC);
+        let version = self.get_trusted_minter_version(trusted_minter);
+        require!(version == TRUSTED_MINTER_INTERFACE_VERSION, ERROR_INVALID_TRUSTED_MINTER_VERSION);
+        self.trusted_minters_list().add(trusted_minter);
+        self.trusted_minter_version(trusted_minter).set(version);
+        self.add_trusted_minter_event(trusted_minter);
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.set_trusted_minter_metadata_internal(trusted_minter, name_hash, version, timestamp);
+    }
+
+    /// Sets the name hash of an already trusted minter, which is useful for trusted minters whitelisted before metadata was
+    /// tracked.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minter` - the trusted minter
+    /// - `name_hash` - the trusted minter name hash
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `trusted_minter` must has been already trusted
+    /// - legacy trusted minters are tracked with interface version zero
+    /// - the added timestamp is kept if already tracked, otherwise it is set to zero
+    ///
+    #[endpoint(setTrustedMinterMetadata)]
+    fn set_trusted_minter_metadata(&self, trusted_minter: ManagedAddress, name_hash: ManagedBuffer) {
+        self.require_admin();
+        self.require_trusted_minter(&trusted_minter);
+
+        let version = self.trusted_minter_version(&trusted_minter).get();
+        let metadata_mapper = self.trusted_minter_metadata(&trusted_minter);
+        let timestamp = if metadata_mapper.is_empty() { 0u64 } else { metadata_mapper.get().timestamp };
+        self.set_trusted_minter_metadata_internal(&trusted_minter, &name_hash, version, timestamp);
+    }
+
+    fn set_trusted_minter_metadata_internal(&self, trusted_minter: &ManagedAddress, name_hash: &ManagedBuffer, version: u8, timestamp: u64) {
+        let metadata = storage::TrustedMinterMetadata { name_hash: name_hash.clone(), version, timestamp };
+        self.trusted_minter_metadata(trusted_minter).set(&metadata);
+        self.trusted_minters().insert(trusted_minter.clone());
+        self.trusted_minter_metadata_event(trusted_minter, &metadata);
     }
 
     /// Removes a trusted minter contract address from the whitelist of trusted minters contracts.
@@ -335,8 +682,146 @@ pub trait GovernanceModule: admin::AdminModule + commons::CommonsModule + events
     #[endpoint(removeTrustedMinter)]
     fn remove_trusted_minter(&self, trusted_minter: ManagedAddress) {
         self.require_admin();
-        self.require_trusted_minter(&trusted_minter);
-        self.trusted_minters_list().remove(&trusted_minter);
-        self.remove_trusted_minter_event(&trusted_minter);
+        self.remove_trusted_minter_internal(&trusted_minter);
+    }
+
+    /// Removes a batch of trusted minter contract addresses from the whitelist of trusted minters contracts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minters` - the trusted minters to remove
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - each trusted minter must has been already trusted
+    ///
+    #[endpoint(removeTrustedMinters)]
+    fn remove_trusted_minters(&self, trusted_minters: MultiValueEncoded<ManagedAddress>) {
+        self.require_admin();
+        for trusted_minter in trusted_minters.into_iter() {
+            self.remove_trusted_minter_internal(&trusted_minter);
+        }
+    }
+
+    fn remove_trusted_minter_internal(&self, trusted_minter: &ManagedAddress) {
+        self.require_trusted_minter(trusted_minter);
+        self.trusted_minters_list().remove(trusted_minter);
+        self.trusted_minter_version(trusted_minter).clear();
+        self.trusted_minters().swap_remove(trusted_minter);
+        self.trusted_minter_metadata(trusted_minter).clear();
+        self.remove_trusted_minter_event(trusted_minter);
+    }
+
+    /// Pauses or unpauses USH minting, i.e. new USH borrows, while repayments, collateral operations and liquidations remain
+    /// available.
+    ///
+    /// # Arguments:
+    ///
+    /// - `pause` - Whether USH minting must be paused or not.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the pause guardian.
+    ///
+    #[endpoint(pauseMint)]
+    fn pause_mint(&self, pause: bool) {
+        self.require_admin_or_guardian();
+
+        if pause {
+            self.mint_status().set(Status::Paused);
+        } else {
+            self.mint_status().set(Status::Active);
+        }
+
+        self.mint_paused_event(pause);
+    }
+
+    /// Sets or removes the protocol-run liquidator, i.e. the only account allowed to liquidate USH borrowers by routing the
+    /// seized collateral into USH that is burned, instead of handing it to the liquidator.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_protocol_liquidator` - An optional protocol liquidator address. If not provided, routing is disabled.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    #[endpoint(setProtocolLiquidator)]
+    fn set_protocol_liquidator(&self, opt_protocol_liquidator: OptionalValue<ManagedAddress>) {
+        self.require_admin();
+
+        let opt_protocol_liquidator = opt_protocol_liquidator.into_option();
+        match opt_protocol_liquidator {
+            None => self.protocol_liquidator().clear(),
+            Some(ref protocol_liquidator) => self.protocol_liquidator().set(protocol_liquidator),
+        }
+
+        self.set_protocol_liquidator_event(&opt_protocol_liquidator);
+    }
+
+    /// Rebases the borrow index, i.e. divides it by a given divisor in order to restore numerical headroom. Account borrow
+    /// snapshots are lazily expressed in the rebased scale, such that effective debts remain unchanged.
+    ///
+    /// # Arguments:
+    ///
+    /// - `divisor` - The divisor applied to the borrow index.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The divisor must be greater than one and the rebased borrow index cannot be lower than one (in wad).
+    /// - Account borrow snapshots can be migrated in pages using `rebaseBorrowSnapshots`.
+    ///
+    #[endpoint(rebaseBorrowIndex)]
+    fn rebase_borrow_index(&self, divisor: &BigUint) {
+        self.require_admin();
+
+        self.accrue_interest();
+        self.require_market_fresh();
+
+        let wad = BigUint::from(WAD);
+        let old_index = self.get_borrow_index();
+        require!(divisor > &BigUint::from(1u64) && &old_index / divisor >= wad, ERROR_INVALID_BORROW_INDEX_REBASE);
+
+        let new_index = &old_index / divisor;
+        self.borrow_index().set(&new_index);
+        self.borrow_index_rebases().push(divisor);
+
+        self.borrow_index_rebased_event(divisor, &old_index, &new_index);
+    }
+
+    /// Migrates a page of account borrow snapshots to the latest borrow index rebase, such that they no longer need to be
+    /// lazily expressed in the rebased scale.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrowers without borrow snapshots or with up to date borrow snapshots are skipped.
+    /// - Migrated borrowers have their borrows updated up to this point, keeping their previous discount.
+    ///
+    #[endpoint(rebaseBorrowSnapshots)]
+    fn rebase_borrow_snapshots(&self, borrowers: MultiValueEncoded<ManagedAddress>) {
+        self.require_admin();
+
+        self.accrue_interest();
+
+        let rebases = self.borrow_index_rebases().len();
+        let mut count = 0usize;
+        for borrower in borrowers.into_iter() {
+            if self.account_borrow_snapshot(&borrower).is_empty() || self.account_borrow_index_rebases(&borrower).get() >= rebases {
+                continue;
+            }
+
+            self.update_borrows_data(&borrower, &BigUint::zero(), InteractionType::EnterOrExitMarket, DiscountStrategy::PreviousDiscount);
+            count += 1;
+        }
+
+        self.borrow_snapshots_rebased_event(count);
     }
 }
@@ -2,11 +2,19 @@ multiversx_sc::imports!();
 
 use super::{
     borrow, commons,
+    constants::*,
     errors::*,
     events, proxies, repay_borrow, seize,
     storage::{self, DiscountStrategy},
 };
 
+use math::Rounding;
+
+use controller::{
+    risk_profile::RiskProfile,
+    storage::{DryRunLiquidation, SwapOperationType, SwapStep, SWAP_TOKENS_FIXED_INPUT_FUNC_NAME},
+};
+
 pub type LiquidateBorrowResultType<BigUint> = MultiValue2<EsdtTokenPayment<BigUint>, EsdtTokenPayment<BigUint>>;
 
 #[multiversx_sc::module]
@@ -19,6 +27,11 @@ pub trait LiquidateModule: borrow::BorrowModule + commons::CommonsModule + event
     /// - `collateral_market ` - The money market in which to seize collateral from the borrower.
     /// - `opt_min_tokens` - The minimum amount of tokens to be seized from the borrower.
     ///
+    /// # Notes:
+    ///
+    /// - If the payment exceeds the maximum repayment allowed by the close factor, only the allowed amount is used and the
+    ///   excess is refunded to the liquidator.
+    ///
     #[payable("*")]
     #[endpoint(liquidateBorrow)]
     fn liquidate_borrow(&self, borrower: ManagedAddress, collateral_market: ManagedAddress, opt_min_tokens: OptionalValue<BigUint>) -> LiquidateBorrowResultType<Self::Api> {
@@ -29,6 +42,17 @@ pub trait LiquidateModule: borrow::BorrowModule + commons::CommonsModule + event
         let (ush_id, ush_amount) = self.call_value().single_fungible_esdt();
         self.require_valid_ush_payment(&ush_id, &ush_amount);
 
+        // clamp the repayment to the maximum allowed and refund the excess
+        let max_repayment = self.get_max_liquidation_repayment(&self.blockchain().get_sc_address(), &borrower);
+        let ush_amount = if max_repayment > BigUint::zero() && ush_amount > max_repayment {
+            let refund_amount = &ush_amount - &max_repayment;
+            self.send().direct_esdt(&liquidator, &ush_id, 0, &refund_amount);
+            self.liquidation_repayment_refund_event(&liquidator, &borrower, &refund_amount);
+            max_repayment
+        } else {
+            ush_amount
+        };
+
         self.liquidate_borrow_internal(&liquidator, &borrower, &ush_amount, &collateral_market, opt_min_tokens)
     }
 
@@ -80,4 +104,226 @@ pub trait LiquidateModule: borrow::BorrowModule + commons::CommonsModule + event
 
         (liquidator_seize_tokens, total_seize_tokens).into()
     }
+
+    /// Liquidates a risky borrower on behalf of the protocol. Instead of handing the seized collateral to the liquidator,
+    /// it is redeemed at the collateral market, swapped into USH through the xExchange router and burned, such that the
+    /// liquidation directly extinguishes the borrower's debt. Any USH obtained on top of the repaid amount is added to the
+    /// reserves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The account to be liquidated.
+    /// - `ush_amount` - The amount of USH to be repaid.
+    /// - `collateral_market` - The money market in which to seize collateral from the borrower.
+    /// - `swap_path` - The swap path that converts the collateral underlying into USH.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the protocol liquidator.
+    /// - The collateral market cannot be the USH money market.
+    /// - If the collateral underlying is EGLD, it is wrapped before swapping. Hence, the swap path must start with WEGLD.
+    /// - The seized collateral must be swapped into at least the repaid amount of USH.
+    /// - Each swap hop must output at least its oracle value, minus the Controller's maximum slippage.
+    ///
+    #[endpoint(liquidateBorrowAndBurn)]
+    fn liquidate_borrow_and_burn(&self, borrower: ManagedAddress, ush_amount: BigUint, collateral_market: ManagedAddress, swap_path: ManagedVec<SwapStep<Self::Api>>) -> BigUint {
+        let liquidator = self.blockchain().get_caller();
+        require!(!self.protocol_liquidator().is_empty() && liquidator == self.protocol_liquidator().get(), ERROR_ONLY_PROTOCOL_LIQUIDATOR);
+
+        let borrow_market = self.blockchain().get_sc_address();
+        require!(collateral_market != borrow_market, ERROR_INVALID_COLLATERAL_MARKET);
+
+        self.accrue_interest();
+        self.accrue_interest_in_other_money_market(&collateral_market);
+        self.require_market_fresh();
+
+        require!(ush_amount > BigUint::zero(), ERROR_AMOUNT_MUST_BE_GREATER_THAN_ZERO);
+        require!(borrower != liquidator && borrower != borrow_market, ERROR_CANNOT_LIQUIDATE_YOURSELF);
+        require!(ush_amount <= self.get_account_borrow_amount(&borrower), ERROR_REPAYMENT_EXCEEDS_BORROW);
+
+        let liquidation_allowed = self.liquidate_borrow_allowed(&borrow_market, &collateral_market, &borrower, &ush_amount);
+        require!(liquidation_allowed, ERROR_CONTROLLER_REJECTED_LIQUIDATION);
+
+        // compute the number of tokens to seize from the borrower's collateral and check
+        let tokens_to_seize = self.tokens_to_seize(&borrow_market, &collateral_market, &ush_amount);
+        let borrower_collateral_tokens = self.get_account_collateral_tokens(&collateral_market, &borrower);
+        require!(tokens_to_seize <= borrower_collateral_tokens, ERROR_TOO_MUCH_LIQUIDATION);
+
+        // seize the collateral into this money market and redeem it for its underlying
+        let seized_tokens = self.seize_in_other_money_market(&collateral_market, &borrow_market, &borrower, &tokens_to_seize);
+        let underlying_payment = self.redeem_in_other_money_market(&collateral_market, &seized_tokens);
+
+        let token_in = if underlying_payment.token_identifier.is_egld() { self.wrap_egld(&underlying_payment.amount) } else { underlying_payment.token_identifier.clone().unwrap_esdt() };
+
+        // swap the underlying into USH, which must cover the repayment
+        let ush_id = self.ush_id().get();
+        let ush_out = self.swap_to_ush(&swap_path, &token_in, &underlying_payment.amount, &ush_id);
+        require!(ush_out >= ush_amount, ERROR_NOT_ENOUGH_USH_FROM_COLLATERAL);
+
+        // repay the borrower's debt, which burns the repaid USH
+        let ush_repayment = self.repay_borrow_internal(&borrow_market, &borrower, &ush_amount, DiscountStrategy::PreviousDiscount);
+
+        // the remaining USH is added to the reserves and burned, as it will be minted again when the revenue is withdrawn
+        let surplus = &ush_out - &ush_repayment.amount;
+        if surplus > BigUint::zero() {
+            self.total_reserves().update(|amount| *amount += &surplus);
+            self.revenue().update(|amount| *amount += &surplus);
+            self.ush_minter_burn(&EsdtTokenPayment::new(ush_id, 0, surplus.clone()));
+        }
+
+        self.liquidate_borrow_event(&liquidator, &borrower, &ush_amount, &collateral_market, &tokens_to_seize);
+        self.liquidate_borrow_and_burn_event(&liquidator, &borrower, &ush_amount, &collateral_market, &underlying_payment.amount, &ush_out, &surplus);
+
+        ush_out
+    }
+
+    /// Swaps a given amount of tokens into USH using a given swap path and returns the amount of USH received.
+    ///
+    /// # Notes:
+    ///
+    /// - The minimum output of each hop is derived from the price oracle and the Controller's maximum slippage, taking the
+    ///   minimum output of the previous hop as its input.
+    ///
+    fn swap_to_ush(&self, path: &ManagedVec<SwapStep<Self::Api>>, token_in: &TokenIdentifier, amount_in: &BigUint, ush_id: &TokenIdentifier) -> BigUint {
+        require!(!path.is_empty(), ERROR_INVALID_SWAP_PATH);
+        require!(path.get(0).input_token_id == *token_in && path.get(path.len() - 1).output_token_id == *ush_id, ERROR_INVALID_SWAP_PATH);
+
+        let max_slippage = self.get_controller_max_slippage();
+        let mut hop_amount_in = amount_in.clone();
+        let mut hop_price_in = self.get_token_price_in_egld(token_in);
+
+        let swap_fixed_input_endpoint = ManagedBuffer::from(SWAP_TOKENS_FIXED_INPUT_FUNC_NAME);
+        let mut operations: MultiValueEncoded<SwapOperationType<Self::Api>> = MultiValueEncoded::new();
+        for step in path.iter() {
+            // the expected output is valued at oracle prices, from which the maximum slippage is discounted
+            let hop_price_out = self.get_token_price_in_egld(&step.output_token_id);
+            let expected_amount_out = &hop_amount_in * &hop_price_in / &hop_price_out;
+            let min_amount_out = &expected_amount_out - &self.wad_mul(&expected_amount_out, &max_slippage);
+            require!(min_amount_out > BigUint::zero(), ERROR_UNEXPECTED_SWAP_AMOUNT);
+
+            let swap_operation: SwapOperationType<Self::Api> = (step.pair_address.clone(), swap_fixed_input_endpoint.clone(), step.output_token_id.clone(), min_amount_out.clone()).into();
+            operations.push(swap_operation);
+
+            hop_amount_in = min_amount_out;
+            hop_price_in = hop_price_out;
+        }
+
+        let ush_balance_prev = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(ush_id.clone()), 0);
+        self.multi_pair_swap(operations, token_in, amount_in);
+        let ush_balance_post = self.blockchain().get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(ush_id.clone()), 0);
+        require!(ush_balance_post > ush_balance_prev, ERROR_UNEXPECTED_SWAP_AMOUNT);
+
+        ush_balance_post - ush_balance_prev
+    }
+
+    /// Simulates a liquidation performed by the caller, running the same policy and seize math as `liquidateBorrow` without
+    /// repaying or seizing anything. Returns the outcome and, if allowed, the total amount of collateral tokens to be seized.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The account to be liquidated.
+    /// - `ush_amount` - The amount of USH to be repaid.
+    /// - `collateral_market` - The money market in which to seize collateral from the borrower.
+    ///
+    /// # Notes:
+    ///
+    /// - As in `liquidateBorrow`, interest is accrued and the repayment is clamped to the maximum allowed by the close factor.
+    /// - The checks are those of the Controller liquidation and seize policies, including the liquidation priority window.
+    /// - The risk profile simulation at the Controller might update scheduled collateral factors and start the liquidation
+    ///   priority window, which is why this is an endpoint.
+    ///
+    #[endpoint(dryRunLiquidateBorrow)]
+    fn dry_run_liquidate_borrow(&self, borrower: &ManagedAddress, ush_amount: &BigUint, collateral_market: &ManagedAddress) -> MultiValue2<DryRunLiquidation, BigUint> {
+        self.accrue_interest();
+        self.accrue_interest_in_other_money_market(collateral_market);
+
+        // clamp the repayment to the maximum allowed
+        let borrow_market = self.blockchain().get_sc_address();
+        let max_repayment = self.get_max_liquidation_repayment(&borrow_market, borrower);
+        let ush_amount = if max_repayment > BigUint::zero() && ush_amount > &max_repayment { max_repayment } else { ush_amount.clone() };
+
+        let liquidator = self.blockchain().get_caller();
+        let outcome = self.check_liquidate_borrow(&borrow_market, collateral_market, borrower, &liquidator, &ush_amount);
+        if outcome != DryRunLiquidation::Allowed {
+            return (outcome, BigUint::zero()).into();
+        }
+
+        let tokens_to_seize = self.tokens_to_seize(&borrow_market, collateral_market, &ush_amount);
+        let borrower_collateral_tokens = self.get_account_collateral_tokens(collateral_market, borrower);
+        if tokens_to_seize > borrower_collateral_tokens {
+            return (DryRunLiquidation::TooMuchLiquidation, BigUint::zero()).into();
+        }
+
+        (DryRunLiquidation::Allowed, tokens_to_seize).into()
+    }
+
+    /// Returns the maximum amount of USH that can be repaid when liquidating a given borrower, i.e. the current borrow amount
+    /// (including its discount) times the close factor, or zero if the borrower cannot be liquidated.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The borrower's address.
+    ///
+    #[endpoint(getLiquidatableAmount)]
+    fn get_liquidatable_amount(&self, borrower: &ManagedAddress) -> BigUint {
+        self.accrue_interest();
+
+        let borrow_amount = self.get_account_borrow_amount(borrower);
+        if borrow_amount == BigUint::zero() || !self.is_risky(borrower) {
+            return BigUint::zero();
+        }
+
+        self.wad_mul(&self.get_close_factor(), &borrow_amount)
+    }
+
+    /// Returns, for each money market in which the borrower has deposited collateral, the underlying price (in EGLD and in
+    /// wad) at which the borrower becomes liquidatable, assuming all other prices remain constant.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The borrower's address.
+    ///
+    /// # Notes:
+    ///
+    /// - A liquidation price higher than the current price implies the borrower is already liquidatable.
+    /// - A liquidation price of zero implies the borrower cannot be liquidated by a price drop of that asset alone.
+    /// - The liquidation price does not account for the given asset being borrowed at the same time.
+    ///
+    #[endpoint(getLiquidationPrices)]
+    fn get_liquidation_prices(&self, borrower: &ManagedAddress) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        self.accrue_interest();
+
+        let ush_market = self.blockchain().get_sc_address();
+        let ush_borrower = self.get_account_borrow_amount(borrower) > BigUint::zero();
+        let risk_profile = self.simulate_risk_profile(borrower);
+
+        let mut result = MultiValueEncoded::new();
+        for money_market in self.get_account_markets(borrower).iter() {
+            let collateral_tokens = self.get_account_collateral_tokens(&money_market, borrower);
+            if collateral_tokens == BigUint::zero() {
+                continue;
+            }
+
+            let ltv = if ush_borrower { self.update_and_get_ush_borrower_collateral_factor(&money_market) } else { self.update_and_get_collateral_factor(&money_market) };
+            let fx = if *money_market == ush_market { self.get_exchange_rate() } else { self.get_stored_exchange_rate_in_other_money_market(&money_market) };
+            let underlying_price = self.get_underlying_price(&money_market);
+
+            // effective collateral in EGLD, computed as in the controller risk profile
+            let token_price_eff = self.wad_mul(&ltv, &self.wad_mul(&fx, &underlying_price));
+            let collateral_eff = self.wad_mul(&token_price_eff, &collateral_tokens);
+            if collateral_eff == BigUint::zero() {
+                continue;
+            }
+
+            // the collateral value is linear in the underlying price
+            let liquidation_price = match &risk_profile {
+                RiskProfile::Solvent(liquidity) if liquidity >= &collateral_eff => BigUint::zero(),
+                RiskProfile::Solvent(liquidity) => self.mul_div(&underlying_price, &(&collateral_eff - liquidity), &collateral_eff, Rounding::Down),
+                RiskProfile::RiskyOrInsolvent(shortfall) => self.mul_div(&underlying_price, &(&collateral_eff + shortfall), &collateral_eff, Rounding::Down),
+            };
+
+            result.push((money_market.clone_value(), liquidation_price).into());
+        }
+        result
+    }
 }
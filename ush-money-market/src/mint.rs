@@ -3,12 +3,15 @@ multiversx_sc::imports!();
 use super::{commons, errors::*, events, proxies, storage};
 
 #[multiversx_sc::module]
-pub trait MintModule: commons::CommonsModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
+pub trait MintModule: pausable::PausableModule + commons::CommonsModule + events::EventsModule + proxies::ProxyModule + storage::StorageModule {
     /// Mints Hatom USH and enters the market in a single transaction.
     ///
     /// # Notes:
     ///
     /// - Must be paid with USH.
+    /// - Returns the minted tokens payment, although the minted tokens are kept as collateral.
+    /// - When minting on behalf of an account, versioned trusted minters are notified via the `onMintOnBehalf` callback with
+    ///   the USH amount, the account, the minted tokens and the account resulting borrow.
     ///
     #[payable("*")]
     #[endpoint(mintAndEnterMarket)]
@@ -33,12 +36,21 @@ pub trait MintModule: commons::CommonsModule + events::EventsModule + proxies::P
         self.require_valid_ush_payment(&ush_id, &ush_payment_amount);
 
         let token_payment = self.mint_internal(&account, &ush_payment_amount);
-        self.enter_market(OptionalValue::Some(account), &token_payment);
+        self.enter_market(OptionalValue::Some(account.clone()), &token_payment);
+
+        // notify the trusted minter, unless it is a legacy one
+        let caller = self.blockchain().get_caller();
+        if caller != account && !self.trusted_minter_version(&caller).is_empty() {
+            let borrow_amount = self.get_account_borrow_amount(&account);
+            self.on_mint_on_behalf(&caller, &account, &ush_payment_amount, &token_payment.amount, &borrow_amount);
+        }
 
         token_payment
     }
 
     fn mint_internal(&self, minter: &ManagedAddress, ush_amount: &BigUint) -> EsdtTokenPayment {
+        self.require_not_paused();
+
         // compute the amount of HUSH to be minted
         let tokens = self.ush_to_hush(ush_amount);
         require!(tokens > BigUint::zero(), ERROR_NOT_ENOUGH_USH);
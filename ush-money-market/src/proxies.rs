@@ -2,9 +2,10 @@ multiversx_sc::imports!();
 
 use super::{events, storage};
 
-use controller::{governance::ProxyTrait as _, market::ProxyTrait as _, policies::ProxyTrait as _, shared::ProxyTrait as _};
+use controller::{governance::ProxyTrait as _, market::ProxyTrait as _, policies::ProxyTrait as _, proxies::xexchange_mod, risk_profile::ProxyTrait as _, risk_profile::RiskProfile, shared::ProxyTrait as _, storage::DryRunLiquidation, storage::SwapOperationType};
 use discount_rate_model::{commons::ProxyTrait as _, discount::ProxyTrait as _, models::ExchangeRateType, storage::ProxyTrait as _};
-use money_market::{common::ProxyTrait as _, seize::ProxyTrait as _};
+use money_market::{common::ProxyTrait as _, redeem::ProxyTrait as _, redeem::RedeemResultType, seize::ProxyTrait as _};
+use multiversx_sc::storage::StorageKey;
 use ush_minter::{esdt::ProxyTrait as _, permissions::ProxyTrait as _};
 
 use crate::errors::*;
@@ -17,10 +18,20 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.get_other_money_market_proxy(sc_address).accrue_interest().execute_on_dest_context()
     }
 
+    fn get_stored_exchange_rate_in_other_money_market(&self, sc_address: &ManagedAddress) -> BigUint {
+        self.get_other_money_market_proxy(sc_address).get_stored_exchange_rate().execute_on_dest_context()
+    }
+
     fn seize_in_other_money_market(&self, collateral_market: &ManagedAddress, liquidator: &ManagedAddress, borrower: &ManagedAddress, tokens: &BigUint) -> EsdtTokenPayment {
         self.get_other_money_market_proxy(collateral_market).seize(liquidator, borrower, tokens).execute_on_dest_context()
     }
 
+    fn redeem_in_other_money_market(&self, sc_address: &ManagedAddress, token_payment: &EsdtTokenPayment) -> EgldOrEsdtTokenPayment {
+        let result: RedeemResultType<Self::Api> = self.get_other_money_market_proxy(sc_address).redeem(OptionalValue::<BigUint>::None, OptionalValue::<BigUint>::None, OptionalValue::<ManagedAddress>::None).with_esdt_transfer(token_payment.clone()).execute_on_dest_context();
+        let (underlying_payment, _) = result.into_tuple();
+        underlying_payment
+    }
+
     // Controller calls
 
     fn is_controller(&self, sc_address: &ManagedAddress) -> bool {
@@ -51,6 +62,30 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.get_controller_proxy().set_account_collateral_tokens(money_market, account, tokens).execute_on_dest_context()
     }
 
+    fn get_account_markets(&self, account: &ManagedAddress) -> ManagedVec<ManagedAddress> {
+        self.get_controller_proxy().get_account_markets(account).execute_on_dest_context()
+    }
+
+    fn update_and_get_collateral_factor(&self, money_market: &ManagedAddress) -> BigUint {
+        self.get_controller_proxy().update_and_get_collateral_factor(money_market).execute_on_dest_context()
+    }
+
+    fn update_and_get_ush_borrower_collateral_factor(&self, money_market: &ManagedAddress) -> BigUint {
+        self.get_controller_proxy().update_and_get_ush_borrower_collateral_factor(money_market).execute_on_dest_context()
+    }
+
+    fn get_underlying_price(&self, money_market: &ManagedAddress) -> BigUint {
+        self.get_controller_proxy().get_money_market_underlying_price(money_market).execute_on_dest_context()
+    }
+
+    fn is_risky(&self, account: &ManagedAddress) -> bool {
+        self.get_controller_proxy().is_risky(account).execute_on_dest_context()
+    }
+
+    fn simulate_risk_profile(&self, account: &ManagedAddress) -> RiskProfile<Self::Api> {
+        self.get_controller_proxy().simulate_risk_profile(account, &ManagedAddress::zero(), &BigUint::zero(), &BigUint::zero(), false).execute_on_dest_context()
+    }
+
     fn get_account_collateral_tokens(&self, money_market_collateral: &ManagedAddress, account: &ManagedAddress) -> BigUint {
         self.get_controller_proxy().get_account_collateral_tokens(money_market_collateral, account).execute_on_dest_context()
     }
@@ -75,6 +110,14 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.get_controller_proxy().liquidate_borrow_allowed(borrow_market, collateral_market, borrower, amount).execute_on_dest_context()
     }
 
+    fn get_max_liquidation_repayment(&self, borrow_market: &ManagedAddress, borrower: &ManagedAddress) -> BigUint {
+        self.get_controller_proxy().get_max_liquidation_repayment(borrow_market, borrower).execute_on_dest_context()
+    }
+
+    fn check_liquidate_borrow(&self, borrow_market: &ManagedAddress, collateral_market: &ManagedAddress, borrower: &ManagedAddress, liquidator: &ManagedAddress, amount: &BigUint) -> DryRunLiquidation {
+        self.get_controller_proxy().check_liquidate_borrow(borrow_market, collateral_market, borrower, liquidator, amount).execute_on_dest_context()
+    }
+
     fn controller_burn_tokens(&self, token_id: &TokenIdentifier, tokens: &BigUint) {
         self.get_controller_proxy().burn_tokens(token_id, tokens).execute_on_dest_context()
     }
@@ -87,6 +130,95 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.get_controller_proxy().remove_account_market(money_market, OptionalValue::Some(account.clone())).execute_on_dest_context()
     }
 
+    fn is_enumeration_allowed(&self, reader: &ManagedAddress) -> bool {
+        if self.controller().is_empty() {
+            return true;
+        }
+        let controller = self.controller().get();
+        let restricted: bool = SingleValueMapper::new_from_address(controller.clone(), StorageKey::new(b"enumeration_restricted")).get();
+        if !restricted {
+            return true;
+        }
+        let mut storage_key = StorageKey::new(b"registered_reader");
+        storage_key.append_item(reader);
+        SingleValueMapper::new_from_address(controller, storage_key).get()
+    }
+
+    fn get_latest_accrual_timestamps(&self) -> (u64, u64) {
+        if self.controller().is_empty() {
+            return (0, 0);
+        }
+        let controller = self.controller().get();
+        let latest_accrual_timestamp: u64 = SingleValueMapper::new_from_address(controller.clone(), StorageKey::new(b"latest_accrual_timestamp")).get();
+        let latest_accrual_gap_timestamp: u64 = SingleValueMapper::new_from_address(controller, StorageKey::new(b"latest_accrual_gap_timestamp")).get();
+        (latest_accrual_timestamp, latest_accrual_gap_timestamp)
+    }
+
+    fn record_accrual(&self, abnormal_accrual_gap: bool) {
+        if self.controller().is_empty() {
+            return;
+        }
+        self.get_controller_proxy().record_accrual(abnormal_accrual_gap).execute_on_dest_context()
+    }
+
+    fn get_interest_frozen_until(&self) -> u64 {
+        if self.controller().is_empty() {
+            return 0;
+        }
+        let controller = self.controller().get();
+        let storage_key = StorageKey::new(b"interest_frozen_until");
+        SingleValueMapper::new_from_address(controller, storage_key).get()
+    }
+
+    fn get_controller_storage_address(&self, key: &[u8]) -> Option<ManagedAddress> {
+        let controller = self.controller().get();
+        let mapper = SingleValueMapper::new_from_address(controller, StorageKey::new(key));
+        if mapper.is_empty() {
+            None
+        } else {
+            Some(mapper.get())
+        }
+    }
+
+    fn get_controller_max_slippage(&self) -> BigUint {
+        let controller = self.controller().get();
+        SingleValueMapper::new_from_address(controller, StorageKey::new(b"max_slippage")).get()
+    }
+
+    // Price Oracle calls
+
+    fn get_token_price_in_egld(&self, token_id: &TokenIdentifier) -> BigUint {
+        let oracle = match self.get_controller_storage_address(b"price_oracle") {
+            None => sc_panic!(ERROR_PRICE_ORACLE_NOT_INITIALIZED),
+            Some(address) => address,
+        };
+        let price: BigUint = self.price_oracle_proxy(oracle).get_price_in_egld(token_id).execute_on_dest_context();
+        require!(price > BigUint::zero(), ERROR_ORACLE_FAILED_RETRIEVE_TOKEN_PRICE);
+        price
+    }
+
+    // xExchange calls
+
+    fn multi_pair_swap(&self, swap_operations: MultiValueEncoded<SwapOperationType<Self::Api>>, token_in: &TokenIdentifier, token_amount: &BigUint) {
+        let router = match self.get_controller_storage_address(b"router") {
+            None => sc_panic!(ERROR_ROUTER_NOT_INITIALIZED),
+            Some(address) => address,
+        };
+        self.xexchange_proxy(router).multi_pair_swap(swap_operations).with_esdt_transfer((token_in.clone(), 0, token_amount.clone())).execute_on_dest_context()
+    }
+
+    // Wrapped EGLD
+
+    fn wrap_egld(&self, amount: &BigUint) -> TokenIdentifier {
+        let egld_wrapper = match self.get_controller_storage_address(b"egld_wrapper") {
+            None => sc_panic!(ERROR_EGLD_WRAPPER_NOT_INITIALIZED),
+            Some(address) => address,
+        };
+        let wegld_id = self.egld_wrapper_proxy(egld_wrapper.clone()).get_wrapped_egld_token_id().execute_on_dest_context();
+        self.egld_wrapper_proxy(egld_wrapper).wrap_egld().with_egld_transfer(amount.clone()).execute_on_dest_context::<()>();
+        wegld_id
+    }
+
     // Discount Rate Model calls
 
     fn is_discount_rate_model(&self, sc_address: &ManagedAddress) -> bool {
@@ -113,6 +245,14 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
         self.trusted_minter_proxy(sc_address.clone()).is_trusted_minter().execute_on_dest_context()
     }
 
+    fn get_trusted_minter_version(&self, sc_address: &ManagedAddress) -> u8 {
+        self.trusted_minter_proxy(sc_address.clone()).get_version().execute_on_dest_context()
+    }
+
+    fn on_mint_on_behalf(&self, sc_address: &ManagedAddress, account: &ManagedAddress, ush_amount: &BigUint, tokens: &BigUint, borrow_amount: &BigUint) {
+        self.trusted_minter_proxy(sc_address.clone()).on_mint_on_behalf(account, ush_amount, tokens, borrow_amount).execute_on_dest_context()
+    }
+
     // USH Minter calls
 
     fn is_ush_minter(&self, sc_address: &ManagedAddress) -> bool {
@@ -172,6 +312,15 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
     #[proxy]
     fn trusted_minter_proxy(&self, sc_address: ManagedAddress) -> trusted_minter_mod::ProxyTo<Self::Api>;
 
+    #[proxy]
+    fn xexchange_proxy(&self, sc_address: ManagedAddress) -> xexchange_mod::ProxyTo<Self::Api>;
+
+    #[proxy]
+    fn price_oracle_proxy(&self, sc_address: ManagedAddress) -> price_oracle_mod::ProxyTo<Self::Api>;
+
+    #[proxy]
+    fn egld_wrapper_proxy(&self, sc_address: ManagedAddress) -> egld_wrapper_mod::ProxyTo<Self::Api>;
+
     #[proxy]
     fn ush_minter_proxy(&self, sc_address: ManagedAddress) -> ush_minter::ProxyTo<Self::Api>;
 
@@ -182,6 +331,30 @@ pub trait ProxyModule: events::EventsModule + storage::StorageModule {
     }
 }
 
+mod egld_wrapper_mod {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait EgldWrapper {
+        #[payable("EGLD")]
+        #[endpoint(wrapEgld)]
+        fn wrap_egld(&self);
+
+        #[view(getWrappedEgldTokenId)]
+        fn get_wrapped_egld_token_id(&self) -> TokenIdentifier;
+    }
+}
+
+mod price_oracle_mod {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait PriceOracle {
+        #[endpoint(getPrice)]
+        fn get_price_in_egld(&self, token_id: &TokenIdentifier) -> BigUint;
+    }
+}
+
 mod staking_mod {
     multiversx_sc::imports!();
 
@@ -199,5 +372,11 @@ mod trusted_minter_mod {
     pub trait TrustedMinter {
         #[view(isTrustedMinter)]
         fn is_trusted_minter(&self) -> bool;
+
+        #[view(getVersion)]
+        fn get_version(&self) -> u8;
+
+        #[endpoint(onMintOnBehalf)]
+        fn on_mint_on_behalf(&self, account: &ManagedAddress, ush_amount: &BigUint, tokens: &BigUint, borrow_amount: &BigUint);
     }
 }
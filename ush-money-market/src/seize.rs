@@ -42,29 +42,39 @@ pub trait SeizeModule: commons::CommonsModule + events::EventsModule + proxies::
         let new_borrower_collateral_tokens = &borrower_collateral_tokens - tokens_to_seize;
         self.set_account_collateral_tokens(&collateral_market, borrower, &new_borrower_collateral_tokens);
 
-        // for exponential math
-        let wad = BigUint::from(WAD);
         let protocol_seize_share = self.protocol_seize_share().get();
 
         // seized tokens will be transferred to both liquidator and the protocol reserves (redeemed to underlying)
-        let protocol_seize_tokens = protocol_seize_share * tokens_to_seize / &wad;
+        let protocol_seize_tokens = self.wad_mul(&protocol_seize_share, tokens_to_seize);
         let liquidator_seize_tokens = tokens_to_seize - &protocol_seize_tokens;
 
         // At this point, the protocol redeems a portion of the seized Hatom's tokens for underlying, which is added to the
         // reserves. The underlying is already deposited at this money market SC so there is no need to transfer it.
-        let delta_reserves = self.hush_to_ush(&protocol_seize_tokens);
+        let protocol_seize_amount = self.hush_to_ush(&protocol_seize_tokens);
+
+        // a share of the protocol seize amount might be routed to the insurance fund instead
+        let insurance_fund_amount = if self.insurance_fund().is_empty() { BigUint::zero() } else { self.wad_mul(&self.insurance_fund_share().get(), &protocol_seize_amount) };
+
+        let delta_reserves = &protocol_seize_amount - &insurance_fund_amount;
         self.total_reserves().update(|amount| *amount += &delta_reserves);
 
         // also, update staking rewards and revenue
         let fs = self.stake_factor().get();
-        let delta_rewards = fs * &delta_reserves / &wad;
+        let delta_rewards = self.wad_mul(&fs, &delta_reserves);
         let delta_revenue = &delta_reserves - &delta_rewards;
 
-        // Burn the USH tokens that goes to the reserves as they will be minted again when claimed.
+        // Burn the USH tokens that goes to the reserves as they will be minted again when claimed. The insurance fund share is
+        // kept by the money market until claimed.
         let ush_id = self.ush_id().get();
-        let ush_payment = EsdtTokenPayment::new(ush_id, 0, delta_reserves);
+        let ush_payment = EsdtTokenPayment::new(ush_id, 0, delta_reserves.clone());
         self.ush_minter_burn(&ush_payment);
 
+        if !self.insurance_fund().is_empty() {
+            let insurance_fund = self.insurance_fund().get();
+            self.insurance_fund_balance().update(|amount| *amount += &insurance_fund_amount);
+            self.protocol_seize_split_event(&insurance_fund, &insurance_fund_amount, &delta_reserves);
+        }
+
         self.revenue().update(|amount| *amount += &delta_revenue);
         self.staking_rewards().update(|amount| *amount += &delta_rewards);
         self.historical_staking_rewards().update(|amount| *amount += &delta_rewards);
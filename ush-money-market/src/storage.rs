@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use controller::storage::Status;
+
 /// The money market state.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
@@ -48,6 +50,34 @@ where
     }
 }
 
+/// Represents the metadata of a trusted minter.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TrustedMinterMetadata<M>
+where
+    M: ManagedTypeApi,
+{
+    pub name_hash: ManagedBuffer<M>,
+    pub version: u8,
+    pub timestamp: u64,
+}
+
+/// Represents an audit of the borrow index magnitude and of the rounding drift accumulated by the money market borrows.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BorrowIndexAudit<M>
+where
+    M: ManagedTypeApi,
+{
+    pub borrow_index: BigUint<M>,
+    pub borrow_index_bits: u32,
+    pub years_to_overflow: Option<u64>,
+    pub total_borrows: BigUint<M>,
+    pub sampled_borrows: BigUint<M>,
+    pub sampled_accounts: usize,
+    pub rounding_drift: BigUint<M>,
+}
+
 #[multiversx_sc::module]
 pub trait StorageModule {
     /// Stores the smart contract state.
@@ -149,6 +179,20 @@ pub trait StorageModule {
     #[storage_mapper("stake_factor")]
     fn stake_factor(&self) -> SingleValueMapper<BigUint>;
 
+    /// Stores whether the stake factor has ever been set, given that a zero stake factor leaves its storage empty.
+    #[storage_mapper("stake_factor_initialized")]
+    fn stake_factor_initialized(&self) -> SingleValueMapper<bool>;
+
+    /// Stores the last time the stake factor was updated.
+    #[view(getLastStakeFactorUpdate)]
+    #[storage_mapper("last_stake_factor_update")]
+    fn last_stake_factor_update(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the stake factor that is gradually being reached, if any.
+    #[view(getTargetStakeFactor)]
+    #[storage_mapper("target_stake_factor")]
+    fn target_stake_factor(&self) -> SingleValueMapper<BigUint>;
+
     /// Stores the timestamp of the last accrual.
     #[view(getAccrualTimestamp)]
     #[storage_mapper("accrual_timestamp")]
@@ -181,17 +225,89 @@ pub trait StorageModule {
     #[storage_mapper("protocol_seize_share")]
     fn protocol_seize_share(&self) -> SingleValueMapper<BigUint>;
 
+    /// Stores the address of the insurance fund.
+    #[view(getInsuranceFund)]
+    #[storage_mapper("insurance_fund")]
+    fn insurance_fund(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the share of the protocol seized amount that is routed to the insurance fund.
+    #[view(getInsuranceFundShare)]
+    #[storage_mapper("insurance_fund_share")]
+    fn insurance_fund_share(&self) -> SingleValueMapper<BigUint>;
+
+    /// Stores the USH amount accrued for the insurance fund, which is held by the money market until claimed.
+    #[view(getInsuranceFundBalance)]
+    #[storage_mapper("insurance_fund_balance")]
+    fn insurance_fund_balance(&self) -> SingleValueMapper<BigUint>;
+
     /// Stores the accrual time threshold.
     #[view(getAccrualTimeThreshold)]
     #[storage_mapper("accrual_time_threshold")]
     fn accrual_time_threshold(&self) -> SingleValueMapper<u64>;
 
+    /// Stores the maximum time allowed between accruals before the gap is considered abnormal, if non-zero.
+    #[view(getMaxAccrualGap)]
+    #[storage_mapper("max_accrual_gap")]
+    fn max_accrual_gap(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the timestamp at which the pending abnormal accrual gap has been recorded, if any.
+    #[view(getAccrualGapTimestamp)]
+    #[storage_mapper("accrual_gap_timestamp")]
+    fn accrual_gap_timestamp(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the accumulated duration in seconds of the pending abnormal accrual gaps.
+    #[view(getAccrualGapDuration)]
+    #[storage_mapper("accrual_gap_duration")]
+    fn accrual_gap_duration(&self) -> SingleValueMapper<u64>;
+
+    /// Stores the accumulated borrow rate times elapsed time of the pending abnormal accrual gaps, in wad.
+    #[view(getAccrualGapInterestFactor)]
+    #[storage_mapper("accrual_gap_interest_factor")]
+    fn accrual_gap_interest_factor(&self) -> SingleValueMapper<BigUint>;
+
     /// Stores the set of addresses with borrow.
-    #[view(getMarketBorrowers)]
     #[storage_mapper("market_borrowers")]
     fn market_borrowers(&self) -> UnorderedSetMapper<ManagedAddress>;
 
     /// Stores a whitelist of trusted smart contracts that can mint and enter market on behalf of users.
     #[storage_mapper("trusted_minters_list")]
     fn trusted_minters_list(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    /// Stores the interface version implemented by each trusted minter. Legacy trusted minters do not have a version.
+    #[view(getTrustedMinterVersion)]
+    #[storage_mapper("trusted_minter_version")]
+    fn trusted_minter_version(&self, trusted_minter: &ManagedAddress) -> SingleValueMapper<u8>;
+
+    /// Stores the set of trusted minters with metadata, used for enumeration.
+    #[storage_mapper("trusted_minters")]
+    fn trusted_minters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Stores the metadata of each trusted minter.
+    #[view(getTrustedMinterMetadata)]
+    #[storage_mapper("trusted_minter_metadata")]
+    fn trusted_minter_metadata(&self, trusted_minter: &ManagedAddress) -> SingleValueMapper<TrustedMinterMetadata<Self::Api>>;
+
+    /// Stores the USH minting status, i.e. whether new USH borrows are allowed or not.
+    #[view(getMintStatus)]
+    #[storage_mapper("mint_status")]
+    fn mint_status(&self) -> SingleValueMapper<Status>;
+
+    /// Stores the protocol-run liquidator allowed to route seized collateral into USH to be burned.
+    #[view(getProtocolLiquidator)]
+    #[storage_mapper("protocol_liquidator")]
+    fn protocol_liquidator(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Stores the configured destinations to which reserves can be withdrawn with a purpose tag.
+    #[view(getReservesDestinations)]
+    #[storage_mapper("reserves_destinations")]
+    fn reserves_destinations(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Stores the divisor applied to the borrow index at each borrow index rebase.
+    #[storage_mapper("borrow_index_rebases")]
+    fn borrow_index_rebases(&self) -> VecMapper<BigUint>;
+
+    /// Stores the number of borrow index rebases already applied to the borrow snapshot of a given account.
+    #[view(getAccountBorrowIndexRebases)]
+    #[storage_mapper("account_borrow_index_rebases")]
+    fn account_borrow_index_rebases(&self, account: &ManagedAddress) -> SingleValueMapper<usize>;
 }
@@ -179,6 +179,94 @@ where
             .original_result()
     }
 
+    /// Pauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the pause guardian.
+    /// - Pausing an already paused contract has no effect.
+    ///
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    /// Unpauses the contract.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Unpausing a contract that is not paused has no effect.
+    ///
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    /// Sets the pause guardian of the contract.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_pause_guardian` - The address of the new pause guardian.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Cannot be called if the pause guardian is read from another contract.
+    ///
+    pub fn set_pause_guardian<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_pause_guardian: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPauseGuardian")
+            .argument(&new_pause_guardian)
+            .original_result()
+    }
+
+    /// Returns the address of the pause guardian, if one has been set.
+    ///
+    pub fn get_pause_guardian_view(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseGuardian")
+            .original_result()
+    }
+
+    /// Stores the address of the contract from which the pause guardian is read, if any.
+    pub fn pause_guardian_source(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseGuardianSource")
+            .original_result()
+    }
+
+    /// Returns whether the contract is paused.
+    ///
+    pub fn is_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
     /// A borrower requests USH from the money market.
     ///
     /// # Arguments:
@@ -256,6 +344,45 @@ where
             .original_result()
     }
 
+    /// Returns a page of the trusted minters with metadata, together with their metadata.
+    ///
+    /// # Arguments:
+    ///
+    /// - `from` - The zero based position of the first trusted minter in the page.
+    /// - `size` - The maximum number of trusted minters in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - Legacy trusted minters are only enumerated once their metadata has been set.
+    ///
+    pub fn get_trusted_minters<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, TrustedMinterMetadata<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrustedMinters")
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Checks whether borrows are restricted because of an abnormal accrual gap, either pending governance resolution or
+    /// about to be recorded at the next accrual.
+    ///
+    pub fn has_abnormal_accrual_gap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasAbnormalAccrualGap")
+            .original_result()
+    }
+
     /// This method is one of the most important methods of the protocol, as it accrues the borrows interest and distributes
     /// that amount into reserves (including revenue and staking rewards). In order to do that, it solves the money market
     /// dynamics using an Euler scheme.
@@ -423,6 +550,124 @@ where
             .original_result()
     }
 
+    /// Returns the amount of liquidity that is available to suppliers and borrowers, which in this market equals the
+    /// liquidity. We keep this method so that it matches with the Money Market interface.
+    ///
+    pub fn get_available_liquidity(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAvailableLiquidity")
+            .original_result()
+    }
+
+    /// Returns the accounts with an outstanding borrow.
+    ///
+    /// # Notes:
+    ///
+    /// - Restricted to readers registered at the Controller if bulk enumeration views are restricted.
+    ///
+    pub fn get_market_borrowers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketBorrowers")
+            .original_result()
+    }
+
+    /// Returns the number of accounts with an outstanding borrow.
+    ///
+    pub fn get_market_borrowers_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketBorrowersCount")
+            .original_result()
+    }
+
+    /// Returns a page of the accounts with an outstanding borrow.
+    ///
+    /// # Arguments:
+    ///
+    /// - `start` - The zero based position of the first borrower in the page.
+    /// - `count` - The maximum number of borrowers in the page.
+    ///
+    /// # Notes:
+    ///
+    /// - The order is not guaranteed to be preserved among calls if borrowers are removed in between.
+    /// - Restricted to readers registered at the Controller if bulk enumeration views are restricted.
+    ///
+    pub fn get_market_borrowers_paged<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        start: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketBorrowersPaged")
+            .argument(&start)
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Returns the weighted-average discount in wad up to the last interaction that accrued interest, i.e. the share of the
+    /// total borrows that does not accrue interest because of borrowers discounts.
+    ///
+    pub fn get_average_discount(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAverageDiscount")
+            .original_result()
+    }
+
+    /// Returns the number of borrowers, the effective borrows, the total principal and the weighted-average discount in one
+    /// shot, all of them up to the last interaction that accrued interest.
+    ///
+    /// # Notes:
+    ///
+    /// - Restricted to readers registered at the Controller if bulk enumeration views are restricted.
+    ///
+    pub fn get_borrowers_summary(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue4<usize, BigUint<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowersSummary")
+            .original_result()
+    }
+
+    /// Returns the projected change in daily interest for the current effective borrows if the borrow APR was updated to the
+    /// given value, together with a flag indicating whether the daily interest would increase or not.
+    ///
+    /// # Arguments:
+    ///
+    /// - `new_borrow_apr` - The candidate borrow APR in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Effective borrows are considered up to the last interaction that accrued interest.
+    ///
+    pub fn preview_borrow_apr_change<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        new_borrow_apr: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, bool>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("previewBorrowAprChange")
+            .argument(&new_borrow_apr)
+            .original_result()
+    }
+
     /// Returns a fixed reserve factor fixed to 100%. This function is used by the Controller to verify if a money market is
     /// deprecated or not.
     ///
@@ -562,6 +807,35 @@ where
             .original_result()
     }
 
+    /// Returns an audit of the borrow index, which can be used to plan an index rebase long before precision becomes a
+    /// problem. It includes the borrow index magnitude in bits, an estimation of the number of years until the borrow index
+    /// exceeds `MAX_BORROW_INDEX_BITS` at the current borrow rate, and the rounding drift between the total borrows and the
+    /// sum of the borrows of a sampled page of accounts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `accounts` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - The estimation is `None` if the borrow rate is zero.
+    /// - The rounding drift is the absolute difference between the total borrows and the sampled borrows. Hence, it only
+    ///   represents the accumulated rounding drift if the page includes all borrowers.
+    /// - It does not accrue interest.
+    ///
+    pub fn get_borrow_index_audit<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        accounts: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BorrowIndexAudit<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowIndexAudit")
+            .argument(&accounts)
+            .original_result()
+    }
+
     /// Returns the exchange rate between underlying and tokens (collateral). Since USH will be used as collateral, the
     /// exchange rate is fixed to one.
     ///
@@ -683,6 +957,9 @@ where
     ///
     /// - Can only be called by the admin.
     /// - The new stake factor must not exceed the maximum allowed.
+    /// - The stake factor cannot increase nor decrease too much, unless it is set for the first time.
+    /// - There is a time delay between stake factor updates.
+    /// - Cancels any scheduled stake factor change.
     ///
     pub fn set_stake_factor<
         Arg0: ProxyArg<BigUint<Env::Api>>,
@@ -697,6 +974,49 @@ where
             .original_result()
     }
 
+    /// Schedules a gradual stake factor change, which is reached by steps of at most the maximum allowed stake factor change
+    /// and separated by at least the stake factor delay.
+    ///
+    /// # Arguments:
+    ///
+    /// - `target_stake_factor` - The stake factor to be reached in wad.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The target stake factor must not exceed the maximum allowed.
+    /// - Steps are performed via `updateStakeFactor`.
+    ///
+    pub fn set_target_stake_factor<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        target_stake_factor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTargetStakeFactor")
+            .argument(&target_stake_factor)
+            .original_result()
+    }
+
+    /// Performs a single step towards the scheduled stake factor.
+    ///
+    /// # Notes:
+    ///
+    /// - Can be called by anyone.
+    /// - The schedule is removed once the target stake factor is reached.
+    /// - If the stake factor has never been set, the target stake factor is reached in a single step.
+    ///
+    pub fn update_stake_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateStakeFactor")
+            .original_result()
+    }
+
     /// Updates the close factor used at liquidations.
     ///
     /// # Arguments:
@@ -770,6 +1090,51 @@ where
             .original_result()
     }
 
+    /// Updates the insurance fund address and the share of the protocol seized amount that is routed to it, i.e. instead of
+    /// staying in the market reserves.
+    ///
+    /// # Arguments
+    ///
+    /// - `insurance_fund` - The insurance fund address.
+    /// - `insurance_fund_share` - The share of the protocol seized amount routed to the insurance fund in wad.
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - the share cannot exceed 100%
+    ///
+    pub fn set_insurance_fund<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        insurance_fund: Arg0,
+        insurance_fund_share: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setInsuranceFund")
+            .argument(&insurance_fund)
+            .argument(&insurance_fund_share)
+            .original_result()
+    }
+
+    /// Transfers the USH amount accrued for the insurance fund during liquidations to the insurance fund.
+    ///
+    /// # Notes
+    ///
+    /// - can be called by anyone
+    /// - the accrued USH is held by the money market until claimed
+    ///
+    pub fn claim_insurance_fund(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimInsuranceFund")
+            .original_result()
+    }
+
     /// Updates the borrow rate.
     ///
     /// # Arguments
@@ -844,66 +1209,248 @@ where
             .original_result()
     }
 
-    /// Updates the accrual time threshold.
+    /// Withdraws several amounts of USH from the money market reserves (revenue part) to configured destinations, each of
+    /// them tagged with a purpose, such that a single governance action leaves an auditable purpose trail.
     ///
     /// # Arguments:
     ///
-    /// - `accrual_time_threshold` - The new accrual time threshold in seconds.
+    /// - `withdrawals` - The (destination, USH amount, purpose tag) entries.
     ///
     /// # Notes:
     ///
     /// - Can only be called by the admin.
+    /// - Each destination must have been configured with `addReservesDestination`.
+    /// - Each purpose tag must be non-empty and not longer than `MAX_RESERVES_PURPOSE_LENGTH`.
+    /// - The USH amounts are minted to the destinations.
     ///
-    pub fn set_accrual_time_threshold<
-        Arg0: ProxyArg<u64>,
+    pub fn reduce_reserves_to<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<ManagedAddress<Env::Api>, BigUint<Env::Api>, ManagedBuffer<Env::Api>>>>,
     >(
         self,
-        accrual_time_threshold: Arg0,
+        withdrawals: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("setAccrualTimeThreshold")
-            .argument(&accrual_time_threshold)
+            .raw_call("reduceReservesTo")
+            .argument(&withdrawals)
             .original_result()
     }
 
-    /// Whitelists a trusted minter contract, i.e. a contract that can mint and enter market in the name of someone else.
+    /// Adds a destination to which reserves can be withdrawn with a purpose tag, such as the insurance pool, the treasury or
+    /// a buyback program.
     ///
     /// # Arguments:
     ///
-    /// - `trusted_minter` - the new trusted minter to whitelist
+    /// - `destination` - The destination address.
     ///
-    /// # Notes
+    /// # Notes:
     ///
-    /// - can only be called by the admin
-    /// - `trusted_minter` must be a trusted smart contract
-    /// - `trusted_minter` must not be already trusted
+    /// - Can only be called by the admin.
     ///
-    pub fn add_trusted_minter<
+    pub fn add_reserves_destination<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
-        trusted_minter: Arg0,
+        destination: Arg0,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("addTrustedMinter")
-            .argument(&trusted_minter)
+            .raw_call("addReservesDestination")
+            .argument(&destination)
             .original_result()
     }
 
-    /// Removes a trusted minter contract address from the whitelist of trusted minters contracts.
+    /// Removes a destination to which reserves can be withdrawn with a purpose tag.
     ///
     /// # Arguments:
     ///
-    /// - `trusted_minter` - the trusted minter to remove
+    /// - `destination` - The destination address.
     ///
-    /// # Notes
+    /// # Notes:
     ///
-    /// - can only be called by the admin
-    /// - `trusted_minter` must has been already trusted
+    /// - Can only be called by the admin.
     ///
-    pub fn remove_trusted_minter<
+    pub fn remove_reserves_destination<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        destination: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeReservesDestination")
+            .argument(&destination)
+            .original_result()
+    }
+
+    /// Updates the accrual time threshold.
+    ///
+    /// # Arguments:
+    ///
+    /// - `accrual_time_threshold` - The new accrual time threshold in seconds.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn set_accrual_time_threshold<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        accrual_time_threshold: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAccrualTimeThreshold")
+            .argument(&accrual_time_threshold)
+            .original_result()
+    }
+
+    /// Updates the maximum accrual gap, i.e. the maximum time allowed between accruals before the gap is considered abnormal,
+    /// in which case no interest is charged for it and borrows are restricted for a while.
+    ///
+    /// # Arguments:
+    ///
+    /// - `max_accrual_gap` - The new maximum accrual gap in seconds. Zero disables the check.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn set_max_accrual_gap<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_accrual_gap: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxAccrualGap")
+            .argument(&max_accrual_gap)
+            .original_result()
+    }
+
+    /// Resolves the pending abnormal accrual gap, which lifts the borrows restriction. Governance explicitly decides whether
+    /// the interest of the gap, computed with the borrow rate in place when it was recorded, is charged or skipped.
+    ///
+    /// # Arguments:
+    ///
+    /// - `charge` - Whether the interest of the pending gap is charged to borrowers.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrows remain restricted until the pending gap is resolved, while repayments and liquidations are not.
+    ///
+    pub fn acknowledge_accrual_gap<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        charge: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acknowledgeAccrualGap")
+            .argument(&charge)
+            .original_result()
+    }
+
+    /// Whitelists a trusted minter contract, i.e. a contract that can mint and enter market in the name of someone else.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minter` - the new trusted minter to whitelist
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `trusted_minter` must be a trusted smart contract
+    /// - `trusted_minter` must not be already trusted
+    /// - `trusted_minter` must implement the current trusted minter interface version, including the mint on behalf callback
+    ///
+    pub fn add_trusted_minter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        trusted_minter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addTrustedMinter")
+            .argument(&trusted_minter)
+            .original_result()
+    }
+
+    /// Whitelists a batch of trusted minter contracts, together with their name hashes. Interface versions are retrieved
+    /// from the trusted minters themselves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minters` - the new trusted minters to whitelist, each one with its name hash
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - each trusted minter must be a trusted smart contract
+    /// - each trusted minter must not be already trusted
+    /// - each trusted minter must implement the current trusted minter interface version
+    ///
+    pub fn add_trusted_minters<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, ManagedBuffer<Env::Api>>>>,
+    >(
+        self,
+        trusted_minters: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addTrustedMinters")
+            .argument(&trusted_minters)
+            .original_result()
+    }
+
+    /// Sets the name hash of an already trusted minter, which is useful for trusted minters whitelisted before metadata was
+    /// tracked.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minter` - the trusted minter
+    /// - `name_hash` - the trusted minter name hash
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `trusted_minter` must has been already trusted
+    /// - legacy trusted minters are tracked with interface version zero
+    /// - the added timestamp is kept if already tracked, otherwise it is set to zero
+    ///
+    pub fn set_trusted_minter_metadata<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        trusted_minter: Arg0,
+        name_hash: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTrustedMinterMetadata")
+            .argument(&trusted_minter)
+            .argument(&name_hash)
+            .original_result()
+    }
+
+    /// Removes a trusted minter contract address from the whitelist of trusted minters contracts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minter` - the trusted minter to remove
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - `trusted_minter` must has been already trusted
+    ///
+    pub fn remove_trusted_minter<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
@@ -916,6 +1463,130 @@ where
             .original_result()
     }
 
+    /// Removes a batch of trusted minter contract addresses from the whitelist of trusted minters contracts.
+    ///
+    /// # Arguments:
+    ///
+    /// - `trusted_minters` - the trusted minters to remove
+    ///
+    /// # Notes
+    ///
+    /// - can only be called by the admin
+    /// - each trusted minter must has been already trusted
+    ///
+    pub fn remove_trusted_minters<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        trusted_minters: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeTrustedMinters")
+            .argument(&trusted_minters)
+            .original_result()
+    }
+
+    /// Pauses or unpauses USH minting, i.e. new USH borrows, while repayments, collateral operations and liquidations remain
+    /// available.
+    ///
+    /// # Arguments:
+    ///
+    /// - `pause` - Whether USH minting must be paused or not.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin or the pause guardian.
+    ///
+    pub fn pause_mint<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        pause: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pauseMint")
+            .argument(&pause)
+            .original_result()
+    }
+
+    /// Sets or removes the protocol-run liquidator, i.e. the only account allowed to liquidate USH borrowers by routing the
+    /// seized collateral into USH that is burned, instead of handing it to the liquidator.
+    ///
+    /// # Arguments:
+    ///
+    /// - `opt_protocol_liquidator` - An optional protocol liquidator address. If not provided, routing is disabled.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    ///
+    pub fn set_protocol_liquidator<
+        Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        opt_protocol_liquidator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProtocolLiquidator")
+            .argument(&opt_protocol_liquidator)
+            .original_result()
+    }
+
+    /// Rebases the borrow index, i.e. divides it by a given divisor in order to restore numerical headroom. Account borrow
+    /// snapshots are lazily expressed in the rebased scale, such that effective debts remain unchanged.
+    ///
+    /// # Arguments:
+    ///
+    /// - `divisor` - The divisor applied to the borrow index.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - The divisor must be greater than one and the rebased borrow index cannot be lower than one (in wad).
+    /// - Account borrow snapshots can be migrated in pages using `rebaseBorrowSnapshots`.
+    ///
+    pub fn rebase_borrow_index<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        divisor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rebaseBorrowIndex")
+            .argument(&divisor)
+            .original_result()
+    }
+
+    /// Migrates a page of account borrow snapshots to the latest borrow index rebase, such that they no longer need to be
+    /// lazily expressed in the rebased scale.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrowers` - A page of borrower addresses.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the admin.
+    /// - Borrowers without borrow snapshots or with up to date borrow snapshots are skipped.
+    /// - Migrated borrowers have their borrows updated up to this point, keeping their previous discount.
+    ///
+    pub fn rebase_borrow_snapshots<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        borrowers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rebaseBorrowSnapshots")
+            .argument(&borrowers)
+            .original_result()
+    }
+
     /// Liquidate a risky borrower by taking her Hatom's tokens deposited as collateral at a specified money market.
     ///
     /// # Arguments:
@@ -924,6 +1595,11 @@ where
     /// - `collateral_market ` - The money market in which to seize collateral from the borrower.
     /// - `opt_min_tokens` - The minimum amount of tokens to be seized from the borrower.
     ///
+    /// # Notes:
+    ///
+    /// - If the payment exceeds the maximum repayment allowed by the close factor, only the allowed amount is used and the
+    ///   excess is refunded to the liquidator.
+    ///
     pub fn liquidate_borrow<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
@@ -942,11 +1618,137 @@ where
             .original_result()
     }
 
+    /// Liquidates a risky borrower on behalf of the protocol. Instead of handing the seized collateral to the liquidator,
+    /// it is redeemed at the collateral market, swapped into USH through the xExchange router and burned, such that the
+    /// liquidation directly extinguishes the borrower's debt. Any USH obtained on top of the repaid amount is added to the
+    /// reserves.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The account to be liquidated.
+    /// - `ush_amount` - The amount of USH to be repaid.
+    /// - `collateral_market` - The money market in which to seize collateral from the borrower.
+    /// - `swap_path` - The swap path that converts the collateral underlying into USH.
+    ///
+    /// # Notes:
+    ///
+    /// - Can only be called by the protocol liquidator.
+    /// - The collateral market cannot be the USH money market.
+    /// - If the collateral underlying is EGLD, it is wrapped before swapping. Hence, the swap path must start with WEGLD.
+    /// - The seized collateral must be swapped into at least the repaid amount of USH.
+    /// - Each swap hop must output at least its oracle value, minus the Controller's maximum slippage.
+    ///
+    pub fn liquidate_borrow_and_burn<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<ManagedVec<Env::Api, SwapStep<Env::Api>>>,
+    >(
+        self,
+        borrower: Arg0,
+        ush_amount: Arg1,
+        collateral_market: Arg2,
+        swap_path: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("liquidateBorrowAndBurn")
+            .argument(&borrower)
+            .argument(&ush_amount)
+            .argument(&collateral_market)
+            .argument(&swap_path)
+            .original_result()
+    }
+
+    /// Simulates a liquidation performed by the caller, running the same policy and seize math as `liquidateBorrow` without
+    /// repaying or seizing anything. Returns the outcome and, if allowed, the total amount of collateral tokens to be seized.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The account to be liquidated.
+    /// - `ush_amount` - The amount of USH to be repaid.
+    /// - `collateral_market` - The money market in which to seize collateral from the borrower.
+    ///
+    /// # Notes:
+    ///
+    /// - As in `liquidateBorrow`, interest is accrued and the repayment is clamped to the maximum allowed by the close factor.
+    /// - The checks are those of the Controller liquidation and seize policies, including the liquidation priority window.
+    /// - The risk profile simulation at the Controller might update scheduled collateral factors and start the liquidation
+    ///   priority window, which is why this is an endpoint.
+    ///
+    pub fn dry_run_liquidate_borrow<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrower: Arg0,
+        ush_amount: Arg1,
+        collateral_market: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<DryRunLiquidation, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("dryRunLiquidateBorrow")
+            .argument(&borrower)
+            .argument(&ush_amount)
+            .argument(&collateral_market)
+            .original_result()
+    }
+
+    /// Returns the maximum amount of USH that can be repaid when liquidating a given borrower, i.e. the current borrow amount
+    /// (including its discount) times the close factor, or zero if the borrower cannot be liquidated.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The borrower's address.
+    ///
+    pub fn get_liquidatable_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrower: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidatableAmount")
+            .argument(&borrower)
+            .original_result()
+    }
+
+    /// Returns, for each money market in which the borrower has deposited collateral, the underlying price (in EGLD and in
+    /// wad) at which the borrower becomes liquidatable, assuming all other prices remain constant.
+    ///
+    /// # Arguments:
+    ///
+    /// - `borrower` - The borrower's address.
+    ///
+    /// # Notes:
+    ///
+    /// - A liquidation price higher than the current price implies the borrower is already liquidatable.
+    /// - A liquidation price of zero implies the borrower cannot be liquidated by a price drop of that asset alone.
+    /// - The liquidation price does not account for the given asset being borrowed at the same time.
+    ///
+    pub fn get_liquidation_prices<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrower: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLiquidationPrices")
+            .argument(&borrower)
+            .original_result()
+    }
+
     /// Mints Hatom USH and enters the market in a single transaction.
     ///
     /// # Notes:
     ///
     /// - Must be paid with USH.
+    /// - Returns the minted tokens payment, although the minted tokens are kept as collateral.
+    /// - When minting on behalf of an account, versioned trusted minters are notified via the `onMintOnBehalf` callback with
+    ///   the USH amount, the account, the minted tokens and the account resulting borrow.
     ///
     pub fn mint_and_enter_market<
         Arg0: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
@@ -1257,6 +2059,26 @@ where
             .original_result()
     }
 
+    /// Stores the last time the stake factor was updated.
+    pub fn last_stake_factor_update(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastStakeFactorUpdate")
+            .original_result()
+    }
+
+    /// Stores the stake factor that is gradually being reached, if any.
+    pub fn target_stake_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTargetStakeFactor")
+            .original_result()
+    }
+
     /// Stores the timestamp of the last accrual.
     pub fn accrual_timestamp(
         self,
@@ -1297,6 +2119,36 @@ where
             .original_result()
     }
 
+    /// Stores the address of the insurance fund.
+    pub fn insurance_fund(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceFund")
+            .original_result()
+    }
+
+    /// Stores the share of the protocol seized amount that is routed to the insurance fund.
+    pub fn insurance_fund_share(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceFundShare")
+            .original_result()
+    }
+
+    /// Stores the USH amount accrued for the insurance fund, which is held by the money market until claimed.
+    pub fn insurance_fund_balance(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceFundBalance")
+            .original_result()
+    }
+
     /// Stores the accrual time threshold.
     pub fn accrual_time_threshold(
         self,
@@ -1307,17 +2159,157 @@ where
             .original_result()
     }
 
-    /// Stores the set of addresses with borrow.
-    pub fn market_borrowers(
+    /// Stores the maximum time allowed between accruals before the gap is considered abnormal, if non-zero.
+    pub fn max_accrual_gap(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxAccrualGap")
+            .original_result()
+    }
+
+    /// Stores the timestamp at which the pending abnormal accrual gap has been recorded, if any.
+    pub fn accrual_gap_timestamp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccrualGapTimestamp")
+            .original_result()
+    }
+
+    /// Stores the accumulated duration in seconds of the pending abnormal accrual gaps.
+    pub fn accrual_gap_duration(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccrualGapDuration")
+            .original_result()
+    }
+
+    /// Stores the accumulated borrow rate times elapsed time of the pending abnormal accrual gaps, in wad.
+    pub fn accrual_gap_interest_factor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccrualGapInterestFactor")
+            .original_result()
+    }
+
+    /// Stores the interface version implemented by each trusted minter. Legacy trusted minters do not have a version.
+    pub fn trusted_minter_version<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        trusted_minter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrustedMinterVersion")
+            .argument(&trusted_minter)
+            .original_result()
+    }
+
+    /// Stores the metadata of each trusted minter.
+    pub fn trusted_minter_metadata<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        trusted_minter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TrustedMinterMetadata<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrustedMinterMetadata")
+            .argument(&trusted_minter)
+            .original_result()
+    }
+
+    /// Stores the USH minting status, i.e. whether new USH borrows are allowed or not.
+    pub fn mint_status(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Status> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMintStatus")
+            .original_result()
+    }
+
+    /// Stores the protocol-run liquidator allowed to route seized collateral into USH to be burned.
+    pub fn protocol_liquidator(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolLiquidator")
+            .original_result()
+    }
+
+    /// Stores the configured destinations to which reserves can be withdrawn with a purpose tag.
+    pub fn reserves_destinations(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
         self.wrapped_tx
             .payment(NotPayable)
-            .raw_call("getMarketBorrowers")
+            .raw_call("getReservesDestinations")
+            .original_result()
+    }
+
+    /// Stores the number of borrow index rebases already applied to the borrow snapshot of a given account.
+    pub fn account_borrow_index_rebases<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        account: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAccountBorrowIndexRebases")
+            .argument(&account)
             .original_result()
     }
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TrustedMinterMetadata<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub name_hash: ManagedBuffer<Api>,
+    pub version: u8,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem)]
+pub struct SwapStep<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub pair_address: ManagedAddress<Api>,
+    pub input_token_id: TokenIdentifier<Api>,
+    pub output_token_id: TokenIdentifier<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum DryRunLiquidation {
+    Allowed,
+    ZeroAmount,
+    SelfLiquidation,
+    NonWhitelistedMarket,
+    GlobalSeizePaused,
+    SeizePaused,
+    LiquidationPriorityWindow,
+    RepaymentExceedsTotalBorrow,
+    BorrowerNotLiquidatable,
+    TooMuchRepayment,
+    TooMuchLiquidation,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
 pub enum State {
@@ -1337,3 +2329,25 @@ where
     pub borrow_index: BigUint<Api>,
     pub discount: BigUint<Api>,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub enum Status {
+    Active,
+    Paused,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BorrowIndexAudit<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub borrow_index: BigUint<Api>,
+    pub borrow_index_bits: u32,
+    pub years_to_overflow: Option<u64>,
+    pub total_borrows: BigUint<Api>,
+    pub sampled_borrows: BigUint<Api>,
+    pub sampled_accounts: usize,
+    pub rounding_drift: BigUint<Api>,
+}
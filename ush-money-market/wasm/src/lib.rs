@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           84
+// Endpoints:                          134
 // Async Callback:                       1
-// Total number of exported functions:  87
+// Total number of exported functions: 137
 
 #![no_std]
 
@@ -26,12 +26,20 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPendingAdmin => get_pending_admin
         setPendingAdmin => set_pending_admin
         acceptAdmin => accept_admin
+        pause => pause
+        unpause => unpause
+        setPauseGuardian => set_pause_guardian
+        getPauseGuardian => get_pause_guardian_view
+        getPauseGuardianSource => pause_guardian_source
+        isPaused => is_paused
         borrow => borrow
         isMoneyMarket => is_money_market
         isUshMarket => is_ush_market
         isActive => is_active
         isFinalized => is_finalized
         isHushIssued => is_hush_issued
+        getTrustedMinters => get_trusted_minters
+        hasAbnormalAccrualGap => has_abnormal_accrual_gap
         accrueInterest => accrue_interest
         tryAccrueInterest => try_accrue_interest
         addReserves => add_reserves
@@ -45,6 +53,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         getCurrentHistoricalStakingRewards => get_current_historical_staking_rewards
         getCurrentRevenue => get_current_revenue
         getLiquidity => get_liquidity
+        getAvailableLiquidity => get_available_liquidity
+        getMarketBorrowers => get_market_borrowers
+        getMarketBorrowersCount => get_market_borrowers_count
+        getMarketBorrowersPaged => get_market_borrowers_paged
+        getAverageDiscount => get_average_discount
+        getBorrowersSummary => get_borrowers_summary
+        previewBorrowAprChange => preview_borrow_apr_change
         getReserveFactor => get_reserve_factor
         getController => get_controller
         getCurrentAccountBorrowAmount => current_account_borrow_amount
@@ -54,6 +69,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getAccountSnapshot => get_account_snapshot
         getReliableAccountSnapshot => get_reliable_account_snapshot
         getBorrowIndex => get_borrow_index
+        getBorrowIndexAudit => get_borrow_index_audit
         getStoredExchangeRate => get_stored_exchange_rate
         getExchangeRate => get_exchange_rate
         getCloseFactor => get_close_factor
@@ -63,16 +79,36 @@ multiversx_sc_wasm_adapter::endpoints! {
         finalize => finalize
         setStakingContract => set_staking_contract
         setStakeFactor => set_stake_factor
+        setTargetStakeFactor => set_target_stake_factor
+        updateStakeFactor => update_stake_factor
         setCloseFactor => set_close_factor
         setLiquidationIncentive => set_liquidation_incentive
         setProtocolSeizeShare => set_protocol_seize_share
+        setInsuranceFund => set_insurance_fund
+        claimInsuranceFund => claim_insurance_fund
         setBorrowApr => set_borrow_apr
         setDiscountRateModel => set_discount_rate_model
         reduceReserves => reduce_reserves
+        reduceReservesTo => reduce_reserves_to
+        addReservesDestination => add_reserves_destination
+        removeReservesDestination => remove_reserves_destination
         setAccrualTimeThreshold => set_accrual_time_threshold
+        setMaxAccrualGap => set_max_accrual_gap
+        acknowledgeAccrualGap => acknowledge_accrual_gap
         addTrustedMinter => add_trusted_minter
+        addTrustedMinters => add_trusted_minters
+        setTrustedMinterMetadata => set_trusted_minter_metadata
         removeTrustedMinter => remove_trusted_minter
+        removeTrustedMinters => remove_trusted_minters
+        pauseMint => pause_mint
+        setProtocolLiquidator => set_protocol_liquidator
+        rebaseBorrowIndex => rebase_borrow_index
+        rebaseBorrowSnapshots => rebase_borrow_snapshots
         liquidateBorrow => liquidate_borrow
+        liquidateBorrowAndBurn => liquidate_borrow_and_burn
+        dryRunLiquidateBorrow => dry_run_liquidate_borrow
+        getLiquidatableAmount => get_liquidatable_amount
+        getLiquidationPrices => get_liquidation_prices
         mintAndEnterMarket => mint_and_enter_market
         onMarketChange => on_market_change
         redeem => redeem
@@ -98,12 +134,26 @@ multiversx_sc_wasm_adapter::endpoints! {
         getBorrowRate => borrow_rate
         getLastBorrowRateUpdate => last_borrow_rate_update
         getStakeFactor => stake_factor
+        getLastStakeFactorUpdate => last_stake_factor_update
+        getTargetStakeFactor => target_stake_factor
         getAccrualTimestamp => accrual_timestamp
         getStakingSc => staking_sc
         getDiscountRateModel => discount_rate_model
         getProtocolSeizeShare => protocol_seize_share
+        getInsuranceFund => insurance_fund
+        getInsuranceFundShare => insurance_fund_share
+        getInsuranceFundBalance => insurance_fund_balance
         getAccrualTimeThreshold => accrual_time_threshold
-        getMarketBorrowers => market_borrowers
+        getMaxAccrualGap => max_accrual_gap
+        getAccrualGapTimestamp => accrual_gap_timestamp
+        getAccrualGapDuration => accrual_gap_duration
+        getAccrualGapInterestFactor => accrual_gap_interest_factor
+        getTrustedMinterVersion => trusted_minter_version
+        getTrustedMinterMetadata => trusted_minter_metadata
+        getMintStatus => mint_status
+        getProtocolLiquidator => protocol_liquidator
+        getReservesDestinations => reserves_destinations
+        getAccountBorrowIndexRebases => account_borrow_index_rebases
     )
 }
 